@@ -0,0 +1,94 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use pas::{Slice, SliceMut};
+
+const COUNT: usize = 10_000_000;
+
+/// Vertex-like layout, writing to the position only.
+type Vertex = [f32; 8];
+
+fn fill(c: &mut Criterion) {
+    let mut data: Vec<Vertex> = vec![[0.0; 8]; COUNT];
+    let mut slice: SliceMut<[f32; 3]> = SliceMut::new(&mut data, 0);
+
+    c.bench_function("fill indexed", |b| {
+        b.iter(|| {
+            for i in 0..slice.len() {
+                slice[i] = black_box([1.0, 2.0, 3.0]);
+            }
+        })
+    });
+    c.bench_function("fill", |b| {
+        b.iter(|| slice.fill(black_box([1.0, 2.0, 3.0])))
+    });
+}
+
+fn copy(c: &mut Criterion) {
+    let mut data: Vec<Vertex> = vec![[0.0; 8]; COUNT];
+    let mut slice: SliceMut<[f32; 3]> = SliceMut::new(&mut data, 0);
+    let src: Vec<[f32; 3]> = (0..COUNT).map(|i| [i as f32; 3]).collect();
+
+    c.bench_function("copy indexed", |b| {
+        b.iter(|| {
+            for (i, value) in black_box(&src).iter().enumerate() {
+                slice[i] = *value;
+            }
+        })
+    });
+    c.bench_function("copy_from_slice", |b| {
+        b.iter(|| slice.copy_from_slice(black_box(&src)))
+    });
+}
+
+fn streaming(c: &mut Criterion) {
+    // Packed, such that streaming stores are used on `x86_64`.
+    let mut data: Vec<[f32; 4]> = vec![[0.0; 4]; COUNT];
+    let mut slice: SliceMut<[f32; 4]> = SliceMut::native(&mut data);
+    let src: Vec<[f32; 4]> = (0..COUNT).map(|i| [i as f32; 4]).collect();
+
+    c.bench_function("fill packed", |b| {
+        b.iter(|| slice.fill(black_box([1.0, 2.0, 3.0, 4.0])))
+    });
+    c.bench_function("fill_streaming packed", |b| {
+        b.iter(|| slice.fill_streaming(black_box([1.0, 2.0, 3.0, 4.0])))
+    });
+    c.bench_function("copy_from_slice packed", |b| {
+        b.iter(|| slice.copy_from_slice(black_box(&src)))
+    });
+    c.bench_function("copy_from_slice_streaming packed", |b| {
+        b.iter(|| slice.copy_from_slice_streaming(black_box(&src)))
+    });
+}
+
+fn for_each(c: &mut Criterion) {
+    let packed: Vec<u32> = (0..COUNT as u32).collect();
+    let interleaved: Vec<[u32; 4]> = (0..COUNT as u32).map(|i| [i; 4]).collect();
+
+    for (name, slice) in [
+        ("packed", Slice::native(&packed)),
+        ("interleaved", Slice::<u32>::new(&interleaved, 4)),
+    ] {
+        c.bench_function(&format!("iter for_each {}", name), |b| {
+            b.iter(|| {
+                let mut acc = 0_u32;
+                slice
+                    .iter()
+                    .for_each(|v| acc = acc.wrapping_add(v.wrapping_mul(black_box(3))));
+                acc
+            })
+        });
+        c.bench_function(&format!("for_each_fast {}", name), |b| {
+            b.iter(|| {
+                let mut acc = 0_u32;
+                slice.for_each_fast(|v| acc = acc.wrapping_add(v.wrapping_mul(black_box(3))));
+                acc
+            })
+        });
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = fill, copy, streaming, for_each
+}
+criterion_main!(benches);
@@ -0,0 +1,124 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use pas::convert::kernels::{
+    f16_bits_to_f32, f32_to_f16_bits, f32_to_unorm8, f32x3_to_f32x4, unorm8_to_f32,
+};
+use pas::convert::narrow_indices;
+use pas::{Slice, SliceMut};
+
+const COUNT: usize = 10_000_000;
+
+fn unorm8(c: &mut Criterion) {
+    let floats: Vec<f32> = (0..COUNT).map(|i| (i % 256) as f32 / 255.0).collect();
+    let mut bytes: Vec<u8> = vec![0; COUNT];
+    let mut back: Vec<f32> = vec![0.0; COUNT];
+
+    c.bench_function("f32_to_unorm8 packed", |b| {
+        b.iter(|| {
+            f32_to_unorm8(
+                &Slice::native(black_box(&floats)),
+                &mut SliceMut::native(&mut bytes),
+            )
+        })
+    });
+    c.bench_function("f32_to_unorm8 map", |b| {
+        b.iter(|| {
+            for (dst, src) in bytes.iter_mut().zip(black_box(&floats)) {
+                *dst = (src.clamp(0.0, 1.0) * 255.0).round() as u8;
+            }
+        })
+    });
+    c.bench_function("unorm8_to_f32 packed", |b| {
+        b.iter(|| {
+            unorm8_to_f32(
+                &Slice::native(black_box(&bytes)),
+                &mut SliceMut::native(&mut back),
+            )
+        })
+    });
+}
+
+fn expand(c: &mut Criterion) {
+    let positions: Vec<[f32; 3]> = (0..COUNT).map(|i| [i as f32; 3]).collect();
+    let mut packed: Vec<[f32; 4]> = vec![[0.0; 4]; COUNT];
+    let mut interleaved: Vec<[f32; 8]> = vec![[0.0; 8]; COUNT];
+
+    c.bench_function("f32x3_to_f32x4 packed", |b| {
+        b.iter(|| {
+            f32x3_to_f32x4(
+                &Slice::native(black_box(&positions)),
+                &mut SliceMut::native(&mut packed),
+                1.0,
+            )
+        })
+    });
+    c.bench_function("f32x3_to_f32x4 interleaved", |b| {
+        b.iter(|| {
+            f32x3_to_f32x4(
+                &Slice::native(black_box(&positions)),
+                &mut SliceMut::new(&mut interleaved, 0),
+                1.0,
+            )
+        })
+    });
+}
+
+fn half(c: &mut Criterion) {
+    let floats: Vec<f32> = (0..COUNT).map(|i| i as f32 * 1e-3).collect();
+    let mut halves: Vec<u16> = vec![0; COUNT];
+    let mut back: Vec<f32> = vec![0.0; COUNT];
+
+    c.bench_function("f32_to_f16_bits packed", |b| {
+        b.iter(|| {
+            f32_to_f16_bits(
+                &Slice::native(black_box(&floats)),
+                &mut SliceMut::native(&mut halves),
+            )
+        })
+    });
+    c.bench_function("f16_bits_to_f32 packed", |b| {
+        b.iter(|| {
+            f16_bits_to_f32(
+                &Slice::native(black_box(&halves)),
+                &mut SliceMut::native(&mut back),
+            )
+        })
+    });
+}
+
+fn indices(c: &mut Criterion) {
+    let wide: Vec<u64> = (0..COUNT as u64).map(|i| i * 7 % COUNT as u64).collect();
+    let interleaved: Vec<[u64; 2]> = wide.iter().map(|i| [*i, 0]).collect();
+    let mut narrow: Vec<u32> = vec![0; COUNT];
+
+    c.bench_function("narrow_indices packed", |b| {
+        b.iter(|| {
+            narrow_indices(
+                &Slice::native(black_box(&wide)),
+                &mut SliceMut::native(&mut narrow),
+            )
+        })
+    });
+    c.bench_function("narrow_indices interleaved", |b| {
+        b.iter(|| {
+            narrow_indices(
+                &Slice::new(black_box(&interleaved), 0),
+                &mut SliceMut::native(&mut narrow),
+            )
+        })
+    });
+    c.bench_function("narrow_indices iter map collect", |b| {
+        b.iter(|| {
+            black_box(&wide)
+                .iter()
+                .map(|i| u32::try_from(*i))
+                .collect::<Result<Vec<u32>, _>>()
+        })
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = unorm8, expand, half, indices
+}
+criterion_main!(benches);
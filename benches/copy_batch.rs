@@ -0,0 +1,51 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use pas::{CopyBatch, JaggedView, JaggedViewMut, Slice, SliceMut};
+
+/// Number of small copies per frame.
+const COPIES: usize = 512;
+/// Elements per copy.
+const ELEMENTS: usize = 16;
+
+/// Copy `COPIES` ranges of `ELEMENTS` attributes at byte `offset` of `V`,
+/// one by one, and as a batch.
+fn copies<V: bytemuck::Pod, A: bytemuck::Pod>(c: &mut Criterion, name: &str, offset: usize) {
+    let src: Vec<V> = vec![V::zeroed(); COPIES * ELEMENTS];
+    let mut dst: Vec<V> = vec![V::zeroed(); COPIES * ELEMENTS];
+    let offsets: Vec<u32> = (0..=COPIES as u32).map(|i| i * ELEMENTS as u32).collect();
+    let offsets = Slice::native(&offsets);
+    let sources = JaggedView::new(Slice::<A>::new(&src, offset), offsets).unwrap();
+    // Copies are recorded in reverse destination order.
+    let order: Vec<usize> = (0..COPIES).rev().collect();
+
+    c.bench_function(&format!("small copies individual {}", name), |b| {
+        b.iter(|| {
+            let mut dsts =
+                JaggedViewMut::new(SliceMut::<A>::new(&mut dst, offset), offsets).unwrap();
+            for &i in &order {
+                dsts.group_mut(i)
+                    .write_mapped_from(&black_box(sources.group(i)), |v| *v);
+            }
+        })
+    });
+    c.bench_function(&format!("small copies batch {}", name), |b| {
+        b.iter(|| {
+            let mut dsts =
+                JaggedViewMut::new(SliceMut::<A>::new(&mut dst, offset), offsets).unwrap();
+            let mut groups: Vec<_> = dsts.groups_mut().map(Some).collect();
+            let mut batch = CopyBatch::new();
+            for &i in &order {
+                batch.add(groups[i].take().unwrap(), black_box(sources.group(i)));
+            }
+            batch.execute().unwrap()
+        })
+    });
+}
+
+fn small_copies(c: &mut Criterion) {
+    copies::<[f32; 4], [f32; 4]>(c, "packed", 0);
+    // Vertex-like layout, writing to the position only.
+    copies::<[f32; 8], [f32; 3]>(c, "interleaved", 0);
+}
+
+criterion_group!(benches, small_copies);
+criterion_main!(benches);
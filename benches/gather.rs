@@ -0,0 +1,59 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use pas::{Slice, SliceMut};
+
+const COUNT: usize = 1 << 20;
+
+fn check_indices(c: &mut Criterion) {
+    let data = vec![[0.0_f32; 3]; COUNT];
+    let slice: Slice<[f32; 3]> = Slice::native(&data);
+
+    let indices_u32: Vec<u32> = (0..COUNT as u32).rev().collect();
+    c.bench_function("check_indices u32", |b| {
+        b.iter(|| slice.check_indices(black_box(&indices_u32)))
+    });
+
+    let indices_u64: Vec<u64> = (0..COUNT as u64).rev().collect();
+    c.bench_function("check_indices u64", |b| {
+        b.iter(|| slice.check_indices(black_box(&indices_u64)))
+    });
+}
+
+fn gather_prefetched(c: &mut Criterion) {
+    // Larger than the last level cache, such that random accesses miss.
+    const LEN: usize = 1 << 22;
+    let data = vec![[1.0_f32; 4]; LEN];
+    let slice: Slice<[f32; 4]> = Slice::native(&data);
+
+    // xorshift32, for reproducible random indices.
+    let mut state = 0x9e37_79b9_u32;
+    let indices: Vec<u32> = (0..COUNT)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state % LEN as u32
+        })
+        .collect();
+    let mut out = vec![[0.0_f32; 4]; COUNT];
+
+    c.bench_function("gather_into random", |b| {
+        b.iter(|| slice.gather_into(black_box(&indices), &mut SliceMut::native(&mut out)))
+    });
+    for lookahead in [8, 16, 32] {
+        c.bench_function(
+            &format!("gather_into_prefetched random {}", lookahead),
+            |b| {
+                b.iter(|| {
+                    slice.gather_into_prefetched(
+                        black_box(&indices),
+                        &mut SliceMut::native(&mut out),
+                        lookahead,
+                    )
+                })
+            },
+        );
+    }
+}
+
+criterion_group!(benches, check_indices, gather_prefetched);
+criterion_main!(benches);
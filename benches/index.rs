@@ -0,0 +1,73 @@
+//! Indexed access from outside the crate.
+//!
+//! Benchmarks are compiled as separate crates, without LTO, such that this
+//! measures how well `slice[i]` inlines across the crate boundary.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use pas::{Slice, SliceMut};
+
+const COUNT: usize = 1 << 20;
+
+/// Vertex-like layout, reading the first lane of the position.
+type Vertex = [u32; 8];
+
+// Indexing is what is measured.
+#[allow(clippy::needless_range_loop)]
+fn sum(c: &mut Criterion) {
+    let data: Vec<Vertex> = (0..COUNT as u32).map(|i| [i; 8]).collect();
+    let slice: Slice<u32> = Slice::new(&data, 0);
+
+    c.bench_function("sum std indexed", |b| {
+        b.iter(|| {
+            let data = black_box(&data);
+            let mut acc = 0_u32;
+            for i in 0..data.len() {
+                acc = acc.wrapping_add(data[i][0]);
+            }
+            acc
+        })
+    });
+    c.bench_function("sum indexed", |b| {
+        b.iter(|| {
+            let slice = black_box(&slice);
+            let mut acc = 0_u32;
+            for i in 0..slice.len() {
+                acc = acc.wrapping_add(slice[i]);
+            }
+            acc
+        })
+    });
+    c.bench_function("sum get", |b| {
+        b.iter(|| {
+            let slice = black_box(&slice);
+            let mut acc = 0_u32;
+            for i in 0..slice.len() {
+                acc = acc.wrapping_add(*slice.get(i).unwrap());
+            }
+            acc
+        })
+    });
+    c.bench_function("sum iter", |b| {
+        b.iter(|| {
+            black_box(&slice)
+                .iter()
+                .fold(0_u32, |acc, v| acc.wrapping_add(*v))
+        })
+    });
+}
+
+fn scale(c: &mut Criterion) {
+    let mut data: Vec<Vertex> = (0..COUNT as u32).map(|i| [i; 8]).collect();
+    let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 4);
+
+    c.bench_function("scale indexed", |b| {
+        b.iter(|| {
+            for i in 0..slice.len() {
+                slice[i] = slice[i].wrapping_mul(black_box(3));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, sum, scale);
+criterion_main!(benches);
@@ -0,0 +1,26 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use pas::{OwnedStrided, OwnedView, Slice};
+
+fn snapshot(c: &mut Criterion) {
+    let matrix = [[1.0_f32; 4]; 4];
+    let vertices = [[0.5_f32; 8]; 2];
+
+    c.bench_function("OwnedStrided matrix inline", |b| {
+        b.iter(|| OwnedStrided::from_slice(&Slice::native(black_box(&matrix))))
+    });
+    c.bench_function("OwnedStrided matrix heap", |b| {
+        b.iter(|| OwnedStrided::new(Slice::native(black_box(&matrix)).iter().copied().collect()))
+    });
+    c.bench_function("OwnedStrided strided inline", |b| {
+        b.iter(|| OwnedStrided::<[f32; 4]>::from_slice(&Slice::new(black_box(&vertices), 16)))
+    });
+    c.bench_function("OwnedView 64 bytes inline", |b| {
+        b.iter(|| OwnedView::<()>::new(bytemuck::cast_slice(black_box(&matrix))))
+    });
+    c.bench_function("Vec 64 bytes", |b| {
+        b.iter(|| bytemuck::cast_slice::<_, u8>(black_box(&matrix)).to_vec())
+    });
+}
+
+criterion_group!(benches, snapshot);
+criterion_main!(benches);
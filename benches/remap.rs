@@ -0,0 +1,38 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use pas::{Slice, SliceMut};
+
+const COUNT: usize = 10_000_000;
+
+fn remap(c: &mut Criterion) {
+    let mut packed: Vec<u32> = (0..COUNT as u32).rev().collect();
+    let mut interleaved: Vec<[u32; 2]> = (0..COUNT as u32).rev().map(|i| [i, 0]).collect();
+    let max = COUNT as u32 / 2;
+
+    c.bench_function("count_out_of_range packed", |b| {
+        b.iter(|| Slice::native(&packed).count_out_of_range(black_box(max)))
+    });
+    c.bench_function("count_out_of_range interleaved", |b| {
+        b.iter(|| Slice::<u32>::new(&interleaved, 0).count_out_of_range(black_box(max)))
+    });
+
+    c.bench_function("clamp_indices packed", |b| {
+        b.iter(|| SliceMut::native(&mut packed).clamp_indices(black_box(max)))
+    });
+    c.bench_function("clamp_indices interleaved", |b| {
+        b.iter(|| SliceMut::<u32>::new(&mut interleaved, 0).clamp_indices(black_box(max)))
+    });
+
+    c.bench_function("wrap_indices packed", |b| {
+        b.iter(|| SliceMut::native(&mut packed).wrap_indices(black_box(max)))
+    });
+    c.bench_function("wrap_indices interleaved", |b| {
+        b.iter(|| SliceMut::<u32>::new(&mut interleaved, 0).wrap_indices(black_box(max)))
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = remap
+}
+criterion_main!(benches);
@@ -0,0 +1,23 @@
+use std::{env, process::Command};
+
+/// Minor version of the Rust compiler, e.g., `77` for `rustc 1.77.2`.
+fn rustc_minor() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = String::from_utf8(output.stdout).ok()?;
+    version.split('.').nth(1)?.parse().ok()
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let minor = rustc_minor().unwrap_or(0);
+    // Older versions of Cargo warn about `rustc-check-cfg`, stable since Rust 1.80.
+    if minor >= 80 {
+        println!("cargo:rustc-check-cfg=cfg(pas_offset_of)");
+    }
+    // `core::mem::offset_of!` is stable since Rust 1.77.
+    if minor >= 77 {
+        println!("cargo:rustc-cfg=pas_offset_of");
+    }
+}
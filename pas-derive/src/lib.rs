@@ -0,0 +1,166 @@
+//! Derive macros for the [pas](https://docs.rs/pas) crate.
+//!
+//! Use them through the `derive` feature of `pas`, rather than depending on this crate.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields};
+
+/// Derive `pas::Attributes`, and generate views on every field.
+///
+/// For a struct `Vertex`, this generates:
+/// * `VertexAttributes<'a>`, with a `Slice<'a, T>` per field of type `T`
+/// * `VertexAttributesMut<'a>`, with a `SliceMut<'a, T>` per field of type `T`
+///
+/// Both are created from a slice of `Vertex` using `of()`.
+///
+/// The struct must be `#[repr(C)]`, not generic, and have named fields.
+#[proc_macro_derive(Attributes)]
+pub fn derive_attributes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Check that the struct has a `C` representation, without packing.
+fn check_repr(input: &DeriveInput) -> syn::Result<()> {
+    let mut c = false;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") {
+                c = true;
+            } else if meta.path.is_ident("packed") {
+                return Err(meta.error(
+                    "`Attributes` can't be derived for packed structs, since fields may be unaligned",
+                ));
+            }
+            // Skip arguments, e.g., `align(16)`.
+            if meta.input.peek(syn::token::Paren) {
+                let _ = meta.input.parse::<proc_macro2::Group>()?;
+            }
+            Ok(())
+        })?;
+    }
+    if !c {
+        return Err(Error::new(
+            input.ident.span(),
+            "`Attributes` requires `#[repr(C)]`, since field offsets must be stable",
+        ));
+    }
+    Ok(())
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "`Attributes` can't be derived for generic structs",
+        ));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new(
+                    input.ident.span(),
+                    "`Attributes` requires a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                input.ident.span(),
+                "`Attributes` can only be derived for structs",
+            ))
+        }
+    };
+    if fields.is_empty() {
+        return Err(Error::new(
+            input.ident.span(),
+            "`Attributes` requires at least one field",
+        ));
+    }
+    check_repr(input)?;
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let views = format_ident!("{}Attributes", name);
+    let views_mut = format_ident!("{}AttributesMut", name);
+
+    let idents: Vec<_> = fields.iter().map(|f| f.ident.as_ref().unwrap()).collect();
+    let visibilities: Vec<_> = fields.iter().map(|f| &f.vis).collect();
+    let types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+    let names = idents.iter().map(|i| i.to_string());
+    let docs: Vec<_> = idents
+        .iter()
+        .map(|i| format!("View on the `{}` field", i))
+        .collect();
+
+    // Fields must be `Pod` to be viewed, checked with the field span for clearer errors.
+    let pod_checks = types.iter().map(|ty| {
+        quote_spanned! {ty.span()=>
+            const _: fn() = || {
+                fn assert_pod<T: ::pas::__private::Pod>() {}
+                assert_pod::<#ty>();
+            };
+        }
+    });
+
+    let views_doc = format!("Views on the fields of [`{}`].", name);
+    let views_mut_doc = format!("Mutable views on the fields of [`{}`].", name);
+    let of_doc = "Create a view per field of `data`.";
+
+    Ok(quote! {
+        #(#pod_checks)*
+
+        impl ::pas::Attributes for #name {
+            const ATTRIBUTES: &'static [::pas::AttributeLayout] = &[
+                #(::pas::AttributeLayout {
+                    name: #names,
+                    offset: ::pas::__private::offset_of!(#name, #idents),
+                    size: ::core::mem::size_of::<#types>(),
+                    align: ::core::mem::align_of::<#types>(),
+                }),*
+            ];
+        }
+
+        #[doc = #views_doc]
+        #vis struct #views<'a> {
+            #(
+                #[doc = #docs]
+                #visibilities #idents: ::pas::Slice<'a, #types>,
+            )*
+        }
+
+        impl<'a> #views<'a> {
+            #[doc = #of_doc]
+            #vis fn of(data: &'a [#name]) -> Self {
+                Self {
+                    #(#idents: ::pas::__private::field(data, ::pas::__private::offset_of!(#name, #idents)),)*
+                }
+            }
+        }
+
+        #[doc = #views_mut_doc]
+        #vis struct #views_mut<'a> {
+            #(
+                #[doc = #docs]
+                #visibilities #idents: ::pas::SliceMut<'a, #types>,
+            )*
+        }
+
+        impl<'a> #views_mut<'a> {
+            #[doc = #of_doc]
+            #vis fn of(data: &'a mut [#name]) -> Self {
+                let splitter = ::pas::__private::FieldSplitter::new(data);
+                // Safe because every view is over a distinct field.
+                unsafe {
+                    Self {
+                        #(#idents: splitter.field(::pas::__private::offset_of!(#name, #idents)),)*
+                    }
+                }
+            }
+        }
+    })
+}
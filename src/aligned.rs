@@ -0,0 +1,250 @@
+use std::alloc::Layout;
+use std::ptr::NonNull;
+
+/// Owned byte buffer, whose start is aligned to a power of two.
+///
+/// Similar to a `Vec<u8>`, but with an alignment chosen at runtime, such that the
+/// bytes can be viewed as elements of any type with this alignment. Growing the buffer
+/// preserves the alignment.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{AlignedBytes, Slice};
+///
+/// let bytes: Vec<u8> = bytemuck::cast_slice(&[1.0_f32, 2.0, 3.0, 4.0]).to_vec();
+/// // `bytes[4..]` isn't guaranteed to be aligned for `f32`.
+/// let aligned = AlignedBytes::from_slice_aligned(&bytes[4..], 16);
+/// assert_eq!(aligned.as_ptr() as usize % 16, 0);
+///
+/// let slice: Slice<f32> = Slice::raw(&aligned, 0, 4);
+/// assert_eq!(slice[2], 4.0);
+/// ```
+pub struct AlignedBytes {
+    ptr: NonNull<u8>,
+    len: usize,
+    capacity: usize,
+    align: usize,
+}
+
+// Safe because the bytes are owned, and only accessed through `&self` or `&mut self`.
+unsafe impl Send for AlignedBytes {}
+unsafe impl Sync for AlignedBytes {}
+
+impl AlignedBytes {
+    /// Create an empty buffer, able to hold `capacity` bytes without reallocating.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `align` isn't a power of two, or if the capacity exceeds `isize::MAX` bytes
+    /// once rounded up to `align`.
+    pub fn with_capacity_aligned(capacity: usize, align: usize) -> Self {
+        let mut bytes = Self {
+            ptr: Self::dangling(align),
+            len: 0,
+            capacity: 0,
+            align,
+        };
+        bytes.grow_exact(capacity);
+        bytes
+    }
+
+    /// Create a buffer of `len` zeroed bytes.
+    ///
+    /// ## Panics
+    ///
+    /// See [`Self::with_capacity_aligned`].
+    pub fn zeroed(len: usize, align: usize) -> Self {
+        let mut bytes = Self::with_capacity_aligned(len, align);
+        bytes.resize(len, 0);
+        bytes
+    }
+
+    /// Copy `data` into a new buffer.
+    ///
+    /// ## Panics
+    ///
+    /// See [`Self::with_capacity_aligned`].
+    pub fn from_slice_aligned(data: &[u8], align: usize) -> Self {
+        let mut bytes = Self::with_capacity_aligned(data.len(), align);
+        bytes.extend_from_slice(data);
+        bytes
+    }
+
+    /// Number of bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// `true` if the buffer has no byte, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of bytes the buffer can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Alignment of the start of the buffer, in **bytes**.
+    pub fn align(&self) -> usize {
+        self.align
+    }
+
+    /// Pointer to the first byte, aligned to [`Self::align`].
+    ///
+    /// The pointer is dangling, but still aligned, if the capacity is `0`.
+    pub fn as_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr()
+    }
+
+    /// Mutable version of [`Self::as_ptr`].
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+
+    /// Reserve capacity for at least `additional` more bytes.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        if required > self.capacity {
+            // Largest capacity whose layout is valid for the alignment.
+            let max = isize::MAX as usize - (self.align - 1);
+            self.grow_exact(required.max(self.capacity.saturating_mul(2).min(max)));
+        }
+    }
+
+    /// Resize the buffer to `new_len` bytes, filling new bytes with `value`.
+    ///
+    /// ## Panics
+    ///
+    /// See [`Self::reserve`].
+    pub fn resize(&mut self, new_len: usize, value: u8) {
+        if new_len > self.len {
+            self.reserve(new_len - self.len);
+            // Safe because the capacity holds `new_len` bytes.
+            unsafe {
+                self.ptr
+                    .as_ptr()
+                    .add(self.len)
+                    .write_bytes(value, new_len - self.len)
+            };
+        }
+        self.len = new_len;
+    }
+
+    /// Append the bytes of `data`.
+    ///
+    /// ## Panics
+    ///
+    /// See [`Self::reserve`].
+    pub fn extend_from_slice(&mut self, data: &[u8]) {
+        self.reserve(data.len());
+        // Safe because the capacity holds the new bytes, and `data` can't
+        // point into the buffer, which is mutably borrowed.
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                data.as_ptr(),
+                self.ptr.as_ptr().add(self.len),
+                data.len(),
+            )
+        };
+        self.len += data.len();
+    }
+
+    /// Remove every byte, keeping the capacity.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Aligned pointer for an empty allocation.
+    fn dangling(align: usize) -> NonNull<u8> {
+        assert!(align.is_power_of_two(), "alignment must be a power of two");
+        // Never dereferenced, and non-null since `align >= 1`.
+        NonNull::new(std::ptr::null_mut::<u8>().wrapping_add(align)).unwrap()
+    }
+
+    fn layout(capacity: usize, align: usize) -> Layout {
+        Layout::from_size_align(capacity, align).expect("capacity overflow")
+    }
+
+    /// Reallocate to exactly `capacity` bytes, if larger than the current capacity.
+    fn grow_exact(&mut self, capacity: usize) {
+        if capacity <= self.capacity {
+            return;
+        }
+        let layout = Self::layout(capacity, self.align);
+        // Safe because the new layout isn't zero-sized, and the old one
+        // is the layout the buffer was allocated with.
+        let ptr = unsafe {
+            match self.capacity {
+                0 => std::alloc::alloc(layout),
+                old => {
+                    std::alloc::realloc(self.ptr.as_ptr(), Self::layout(old, self.align), capacity)
+                }
+            }
+        };
+        self.ptr = NonNull::new(ptr).unwrap_or_else(|| std::alloc::handle_alloc_error(layout));
+        self.capacity = capacity;
+    }
+}
+
+impl Drop for AlignedBytes {
+    fn drop(&mut self) {
+        if self.capacity > 0 {
+            // Safe because the buffer was allocated with this layout.
+            unsafe {
+                std::alloc::dealloc(self.ptr.as_ptr(), Self::layout(self.capacity, self.align))
+            };
+        }
+    }
+}
+
+impl Default for AlignedBytes {
+    /// Empty buffer, aligned to `1` byte.
+    fn default() -> Self {
+        Self::with_capacity_aligned(0, 1)
+    }
+}
+
+impl Clone for AlignedBytes {
+    fn clone(&self) -> Self {
+        Self::from_slice_aligned(self, self.align)
+    }
+}
+
+impl std::ops::Deref for AlignedBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        // Safe because the first `len` bytes are initialized, and the pointer is
+        // non-null and aligned, even when dangling.
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl std::ops::DerefMut for AlignedBytes {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        // Safe for the same reasons as `deref`, with the buffer mutably borrowed.
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl std::fmt::Debug for AlignedBytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AlignedBytes")
+            .field("len", &self.len)
+            .field("capacity", &self.capacity)
+            .field("align", &self.align)
+            .finish()
+    }
+}
+
+impl PartialEq for AlignedBytes {
+    fn eq(&self, other: &Self) -> bool {
+        self[..] == other[..]
+    }
+}
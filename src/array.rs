@@ -0,0 +1,203 @@
+use bytemuck::Pod;
+use std::fmt::Debug;
+
+use crate::{Slice, SliceError, SliceInfo, SliceIterator, SliceMut, SliceMutIterator};
+
+/// Compile-time bounds check of [`SliceArray::get`].
+///
+/// Evaluating [`Self::OK`] fails the build if `I` isn't smaller than `N`.
+struct AssertIndex<const I: usize, const N: usize>;
+
+impl<const I: usize, const N: usize> AssertIndex<I, N> {
+    const OK: () = assert!(
+        I < N,
+        "index `I` is out of bounds, `I` must be smaller than `N`"
+    );
+}
+
+/// Check that `slice` has exactly `N` elements.
+fn check_len<T: Pod, const N: usize>(slice: &SliceInfo<T>) -> Result<(), SliceError> {
+    match slice.len() {
+        len if len == N => Ok(()),
+        len => Err(SliceError::LengthMismatch { expected: N, len }),
+    }
+}
+
+/// Pointer to the element at `index`, without bounds check.
+///
+/// ## Safety
+///
+/// `index` must be in bounds of `slice`.
+unsafe fn element<T: Pod>(slice: &SliceInfo<T>, index: usize) -> *mut T {
+    (slice.base().start as *mut u8)
+        .add(index * slice.stride())
+        .cast()
+}
+
+/// Immutable strided view with exactly `N` elements.
+///
+/// Created with [`Slice::try_into_array`]. Since the length is part of the type,
+/// [`Self::get`] checks indices at compile time.
+#[derive(Clone, Copy)]
+pub struct SliceArray<'a, T: Pod, const N: usize> {
+    slice: Slice<'a, T>,
+}
+
+impl<'a, T: Pod> Slice<'a, T> {
+    /// Convert into a view whose length `N` is known at compile time.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{Slice, SliceArray};
+    ///
+    /// let corners = [[0_u32, 10], [1, 11], [2, 12], [3, 13]];
+    /// let slice: Slice<u32> = Slice::new(&corners, 4);
+    /// let array: SliceArray<u32, 4> = slice.try_into_array().unwrap();
+    /// assert_eq!(*array.get::<2>(), 12);
+    /// assert_eq!(array.to_array(), [10, 11, 12, 13]);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::LengthMismatch`] if the slice doesn't have exactly `N` elements.
+    pub fn try_into_array<const N: usize>(self) -> Result<SliceArray<'a, T, N>, SliceError> {
+        check_len::<T, N>(&self)?;
+        Ok(SliceArray { slice: self })
+    }
+}
+
+impl<'a, T: Pod, const N: usize> SliceArray<'a, T, N> {
+    /// Get the element at index `I`, without runtime bounds check.
+    ///
+    /// Out of bounds indices don't compile:
+    ///
+    /// ```rust,compile_fail
+    /// use pas::{Slice, SliceArray};
+    ///
+    /// let data = [0_u32, 1, 2, 3];
+    /// let array: SliceArray<u32, 4> = Slice::native(&data).try_into_array().unwrap();
+    /// array.get::<4>();
+    /// ```
+    pub fn get<const I: usize>(&self) -> &'a T {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertIndex::<I, N>::OK;
+        // Safe because `I < N`, and the slice has exactly `N` elements.
+        unsafe { &*element(&self.slice, I) }
+    }
+
+    /// Copy the elements into an array.
+    pub fn to_array(&self) -> [T; N] {
+        // Safe because the slice has exactly `N` elements.
+        std::array::from_fn(|index| unsafe { *element(&self.slice, index) })
+    }
+
+    /// Create an iterator over the elements.
+    pub fn iter(&self) -> SliceIterator<'_, T> {
+        self.slice.elements()
+    }
+
+    /// Convert back into a slice, whose length is only known at runtime.
+    pub fn into_slice(self) -> Slice<'a, T> {
+        self.slice
+    }
+}
+
+impl<'a, T: Pod, const N: usize> From<SliceArray<'a, T, N>> for Slice<'a, T> {
+    fn from(array: SliceArray<'a, T, N>) -> Self {
+        array.slice
+    }
+}
+
+impl<'a, T: Pod + Debug, const N: usize> Debug for SliceArray<'a, T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.slice.fmt(f)
+    }
+}
+
+/// Mutable version of [`SliceArray`].
+///
+/// Created with [`SliceMut::try_into_array`].
+pub struct SliceArrayMut<'a, T: Pod, const N: usize> {
+    slice: SliceMut<'a, T>,
+}
+
+impl<'a, T: Pod> SliceMut<'a, T> {
+    /// Mutable version of [`Slice::try_into_array`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{SliceArrayMut, SliceMut};
+    ///
+    /// let mut weights = [[0.0_f32, 1.0], [0.0, 2.0], [0.0, 3.0], [0.0, 4.0]];
+    /// let slice: SliceMut<f32> = SliceMut::new(&mut weights, 4);
+    /// let mut array: SliceArrayMut<f32, 4> = slice.try_into_array().unwrap();
+    /// *array.get_mut::<0>() = 0.5;
+    /// assert_eq!(array.to_array(), [0.5, 2.0, 3.0, 4.0]);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::LengthMismatch`] if the slice doesn't have exactly `N` elements.
+    pub fn try_into_array<const N: usize>(self) -> Result<SliceArrayMut<'a, T, N>, SliceError> {
+        check_len::<T, N>(&self)?;
+        Ok(SliceArrayMut { slice: self })
+    }
+}
+
+impl<'a, T: Pod, const N: usize> SliceArrayMut<'a, T, N> {
+    /// Get the element at index `I`, see [`SliceArray::get`].
+    pub fn get<const I: usize>(&self) -> &T {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertIndex::<I, N>::OK;
+        // Safe because `I < N`, and the slice has exactly `N` elements.
+        unsafe { &*element(&self.slice, I) }
+    }
+
+    /// Get the element at index `I` mutably, see [`SliceArray::get`].
+    pub fn get_mut<const I: usize>(&mut self) -> &mut T {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertIndex::<I, N>::OK;
+        // Safe because `I < N`, the slice has exactly `N` elements, and is mutably borrowed.
+        unsafe { &mut *element(&self.slice, I) }
+    }
+
+    /// Copy the elements into an array.
+    pub fn to_array(&self) -> [T; N] {
+        // Safe because the slice has exactly `N` elements.
+        std::array::from_fn(|index| unsafe { *element(&self.slice, index) })
+    }
+
+    /// Copy `values` into the elements.
+    pub fn copy_from_array(&mut self, values: &[T; N]) {
+        self.slice.write_elements(values.iter().copied());
+    }
+
+    /// Create an iterator over the elements.
+    pub fn iter(&self) -> SliceIterator<'_, T> {
+        self.slice.elements()
+    }
+
+    /// Create an iterator over the elements, yielding mutable references.
+    pub fn iter_mut(&mut self) -> SliceMutIterator<'_, T> {
+        self.slice.iter()
+    }
+
+    /// Convert back into a mutable slice, whose length is only known at runtime.
+    pub fn into_slice(self) -> SliceMut<'a, T> {
+        self.slice
+    }
+}
+
+impl<'a, T: Pod, const N: usize> From<SliceArrayMut<'a, T, N>> for SliceMut<'a, T> {
+    fn from(array: SliceArrayMut<'a, T, N>) -> Self {
+        array.slice
+    }
+}
+
+impl<'a, T: Pod + Debug, const N: usize> Debug for SliceArrayMut<'a, T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.slice.fmt(f)
+    }
+}
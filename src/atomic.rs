@@ -0,0 +1,226 @@
+use bytemuck::Pod;
+use std::marker::PhantomData;
+use std::sync::atomic::Ordering;
+
+use crate::math::is_multiple_of;
+use crate::shared_impl::SliceBase;
+use crate::{private, SliceError, SliceMut};
+
+/// Integer type with an atomic counterpart of the same size, i.e., `u8`, `u16`,
+/// `u32`, or `u64` on platforms supporting 64-bit atomics.
+///
+/// This trait is sealed and can't be implemented outside of this crate.
+pub trait AtomicElement: Pod + private::Sealed {
+    /// Atomic type of the same size, e.g., [`std::sync::atomic::AtomicU32`] for `u32`.
+    type Atomic: Sync;
+
+    #[doc(hidden)]
+    fn load(atomic: &Self::Atomic, order: Ordering) -> Self;
+    #[doc(hidden)]
+    fn store(atomic: &Self::Atomic, value: Self, order: Ordering);
+    #[doc(hidden)]
+    fn swap(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self;
+    #[doc(hidden)]
+    fn fetch_add(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self;
+    #[doc(hidden)]
+    fn fetch_or(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self;
+    #[doc(hidden)]
+    fn fetch_and(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self;
+}
+
+macro_rules! impl_atomic_element {
+    ($($t: ty => $atomic: ident $(if $width: literal)?),*) => {
+        $(
+            $(#[cfg(target_has_atomic = $width)])?
+            impl AtomicElement for $t {
+                type Atomic = std::sync::atomic::$atomic;
+
+                #[inline(always)]
+                fn load(atomic: &Self::Atomic, order: Ordering) -> Self {
+                    atomic.load(order)
+                }
+                #[inline(always)]
+                fn store(atomic: &Self::Atomic, value: Self, order: Ordering) {
+                    atomic.store(value, order)
+                }
+                #[inline(always)]
+                fn swap(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self {
+                    atomic.swap(value, order)
+                }
+                #[inline(always)]
+                fn fetch_add(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self {
+                    atomic.fetch_add(value, order)
+                }
+                #[inline(always)]
+                fn fetch_or(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self {
+                    atomic.fetch_or(value, order)
+                }
+                #[inline(always)]
+                fn fetch_and(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self {
+                    atomic.fetch_and(value, order)
+                }
+            }
+        )*
+    };
+}
+
+impl_atomic_element!(
+    u8 => AtomicU8,
+    u16 => AtomicU16,
+    u32 => AtomicU32,
+    u64 => AtomicU64 if "64"
+);
+
+/// Lane of integers, shared across threads and accessed atomically.
+///
+/// Plain [`crate::Slice`] reads of elements written concurrently are data races,
+/// even for sizes that can't tear, such as `u32`. This view reads and writes each
+/// element with the atomic type of the same size instead, with an explicit
+/// [`Ordering`]. Other lanes of the buffer can't be accessed while the view exists.
+///
+/// Only [`AtomicElement`] types can be viewed atomically:
+///
+/// ```rust,compile_fail
+/// use pas::{AtomicLane, SliceMut};
+///
+/// let mut data = [[0.0_f32; 3]; 2];
+/// let lane = AtomicLane::new(SliceMut::<[f32; 3]>::native(&mut data));
+/// ```
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{AtomicLane, SliceMut};
+/// use std::sync::atomic::Ordering;
+///
+/// let mut data = [[0_u32; 4]; 8];
+/// let flags = AtomicLane::new(SliceMut::<u32>::new(&mut data, 12)).unwrap();
+/// std::thread::scope(|s| {
+///     s.spawn(|| flags.fetch_or(3, 0b10, Ordering::Release));
+/// });
+/// assert_eq!(flags.load(3, Ordering::Acquire), 0b10);
+/// ```
+pub struct AtomicLane<'a, T: AtomicElement> {
+    inner: SliceBase<T>,
+    _phantom: PhantomData<&'a T::Atomic>,
+}
+
+// Safe because elements are only accessed atomically, and the data is
+// mutably borrowed for `'a`.
+unsafe impl<'a, T: AtomicElement> Send for AtomicLane<'a, T> {}
+unsafe impl<'a, T: AtomicElement> Sync for AtomicLane<'a, T> {}
+
+impl<'a, T: AtomicElement> AtomicLane<'a, T> {
+    /// View the elements of `slice` atomically.
+    ///
+    /// ## Errors
+    ///
+    /// Atomic types can have a larger alignment than their integer type, e.g.,
+    /// `u64` on 32-bit platforms. Returns [`SliceError::AlignmentFault`] if the
+    /// first element isn't aligned for the atomic type, and [`SliceError::UnalignedStride`]
+    /// if the stride isn't a multiple of its alignment.
+    pub fn new(slice: SliceMut<'a, T>) -> Result<Self, SliceError> {
+        let align = std::mem::align_of::<T::Atomic>();
+        let type_name = std::any::type_name::<T::Atomic>();
+        let inner = *slice.base();
+        if !is_multiple_of(inner.start as usize, align) {
+            return Err(SliceError::AlignmentFault {
+                type_name,
+                offset: slice.byte_offset(),
+            });
+        }
+        if slice.len() > 1 && !is_multiple_of(slice.stride(), align) {
+            return Err(SliceError::UnalignedStride {
+                type_name,
+                stride: slice.stride(),
+            });
+        }
+        Ok(Self {
+            inner,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// `true` if the lane has no element, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Get the atomic at index `index`, e.g., for operations not exposed by this view.
+    pub fn get(&self, index: usize) -> Option<&T::Atomic> {
+        self.inner
+            .get_ptr(index)
+            // Safe because the pointer is aligned for the atomic type, which has the
+            // size of `T`, and the data is mutably borrowed for `'a`.
+            .map(|ptr| unsafe { &*ptr.cast::<T::Atomic>() })
+    }
+
+    /// Atomically load the element at index `index`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index` is out of bounds, or for orderings rejected by
+    /// [`std::sync::atomic::AtomicU32::load`].
+    pub fn load(&self, index: usize, order: Ordering) -> T {
+        T::load(self.atomic(index), order)
+    }
+
+    /// Atomically store `value` in the element at index `index`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index` is out of bounds, or for orderings rejected by
+    /// [`std::sync::atomic::AtomicU32::store`].
+    pub fn store(&self, index: usize, value: T, order: Ordering) {
+        T::store(self.atomic(index), value, order)
+    }
+
+    /// Atomically replace the element at index `index` by `value`, and return
+    /// the previous value.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn swap(&self, index: usize, value: T, order: Ordering) -> T {
+        T::swap(self.atomic(index), value, order)
+    }
+
+    /// Atomically add `value` to the element at index `index`, wrapping on
+    /// overflow, and return the previous value.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn fetch_add(&self, index: usize, value: T, order: Ordering) -> T {
+        T::fetch_add(self.atomic(index), value, order)
+    }
+
+    /// Atomically apply a bitwise or with `value` to the element at index `index`,
+    /// and return the previous value.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn fetch_or(&self, index: usize, value: T, order: Ordering) -> T {
+        T::fetch_or(self.atomic(index), value, order)
+    }
+
+    /// Atomically apply a bitwise and with `value` to the element at index `index`,
+    /// and return the previous value.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn fetch_and(&self, index: usize, value: T, order: Ordering) -> T {
+        T::fetch_and(self.atomic(index), value, order)
+    }
+
+    fn atomic(&self, index: usize) -> &T::Atomic {
+        self.get(index).expect("index ouf of bounds")
+    }
+}
@@ -0,0 +1,404 @@
+use std::any::Any;
+
+use crate::{
+    or_broadcast, ErasedSlice, MaybeOwnedSlice, Slice, SliceError, SparseAttribute, TaggedPod,
+    TypeTag,
+};
+
+/// Attribute set error
+///
+/// An error is raised when retrieving, or validating, attributes of an [`AttributeSet`],
+/// or when adding a lane with [`crate::OwnedView::add_default_lane`].
+#[derive(Clone, PartialEq)]
+pub enum AttributeError {
+    /// No attribute has the requested name.
+    Missing {
+        /// Requested name
+        name: String,
+    },
+    /// The attribute can't be viewed as the requested type.
+    TypeMismatch {
+        /// Name of the attribute
+        name: String,
+        /// Error raised by [`ErasedSlice::downcast_tagged`]
+        error: SliceError,
+    },
+    /// The attribute doesn't have the expected element count, e.g., the one
+    /// of the first attribute.
+    CountMismatch {
+        /// Name of the attribute
+        name: String,
+        /// Expected number of elements
+        expected: usize,
+        /// Number of elements of the attribute
+        count: usize,
+    },
+    /// The attribute is sparse while a view was requested, or the reverse.
+    KindMismatch {
+        /// Name of the attribute
+        name: String,
+        /// `true` if the attribute is a [`SparseAttribute`], `false` if it's a view
+        sparse: bool,
+    },
+    /// An attribute with the requested name already exists.
+    Duplicate {
+        /// Name of the attribute
+        name: String,
+    },
+}
+
+impl std::fmt::Debug for AttributeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { name } => write!(f, "No attribute named '{}'", name),
+            Self::TypeMismatch { name, error } => {
+                write!(f, "Attribute '{}' has another type: {:?}", name, error)
+            }
+            Self::CountMismatch {
+                name,
+                expected,
+                count,
+            } => write!(
+                f,
+                "Attribute '{}' has {} elements, but {} were expected",
+                name, count, expected
+            ),
+            Self::KindMismatch { name, sparse: true } => {
+                write!(f, "Attribute '{}' is sparse", name)
+            }
+            Self::KindMismatch {
+                name,
+                sparse: false,
+            } => write!(f, "Attribute '{}' isn't sparse", name),
+            Self::Duplicate { name } => write!(f, "Attribute '{}' already exists", name),
+        }
+    }
+}
+
+/// Attributes, possibly over different buffers, referred to by name.
+///
+/// Views are stored type-erased, with their [`crate::TypeTag`], and are retrieved
+/// with the type they were inserted with. Attributes are kept in insertion order.
+///
+/// Attributes can also be borrowed [`SparseAttribute`], inserted with
+/// [`Self::insert_sparse`] and retrieved with [`Self::get_sparse`].
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{AttributeSet, Slice};
+///
+/// let vertices = [[0.0_f32, 1.0, 2.0, 0.5, 0.5], [3.0, 4.0, 5.0, 1.0, 1.0]];
+/// let ids = [7_u32, 8];
+///
+/// let mut set = AttributeSet::new();
+/// set.insert::<[f32; 3]>("POSITION", Slice::new(&vertices, 0));
+/// set.insert::<[f32; 2]>("TEXCOORD_0", Slice::new(&vertices, 12));
+/// set.insert::<u32>("_ID", Slice::native(&ids));
+/// assert_eq!(set.validate_counts(), Ok(2));
+///
+/// let uvs = set.get::<[f32; 2]>("TEXCOORD_0").unwrap();
+/// assert_eq!(uvs[1], [1.0, 1.0]);
+/// assert!(set.get::<[u32; 2]>("TEXCOORD_0").is_err());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AttributeSet<'a> {
+    attributes: Vec<(String, Entry<'a>)>,
+}
+
+/// Attribute of an [`AttributeSet`].
+#[derive(Clone, Copy, Debug)]
+enum Entry<'a> {
+    Dense(ErasedSlice<'a>),
+    Sparse(ErasedSparse<'a>),
+}
+
+impl<'a> Entry<'a> {
+    fn len(&self) -> usize {
+        match self {
+            Self::Dense(slice) => slice.len(),
+            Self::Sparse(sparse) => sparse.len,
+        }
+    }
+}
+
+/// Borrowed [`SparseAttribute`], with its type erased.
+#[derive(Clone, Copy)]
+struct ErasedSparse<'a> {
+    tag: TypeTag,
+    len: usize,
+    attribute: &'a (dyn Any + Send + Sync),
+}
+
+impl<'a> std::fmt::Debug for ErasedSparse<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ErasedSparse")
+            .field("tag", &self.tag)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<'a> AttributeSet<'a> {
+    /// Create an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of attributes, sparse ones included.
+    pub fn len(&self) -> usize {
+        self.attributes.len()
+    }
+
+    /// `true` if the set has no attribute, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty()
+    }
+
+    /// Insert the attribute `slice`, named `name`.
+    ///
+    /// Returns the previous attribute with that name, if any. The new attribute
+    /// then keeps its position in the iteration order.
+    pub fn insert<T: TaggedPod>(
+        &mut self,
+        name: impl Into<String>,
+        slice: Slice<'a, T>,
+    ) -> Option<ErasedSlice<'a>> {
+        self.insert_erased(name, ErasedSlice::tagged(slice))
+    }
+
+    /// Similar to [`Self::insert`], but with an already type-erased attribute.
+    ///
+    /// Untagged attributes can only be retrieved with [`Self::get_erased`].
+    /// A replaced sparse attribute isn't returned.
+    pub fn insert_erased(
+        &mut self,
+        name: impl Into<String>,
+        slice: ErasedSlice<'a>,
+    ) -> Option<ErasedSlice<'a>> {
+        match self.insert_entry(name.into(), Entry::Dense(slice))? {
+            Entry::Dense(previous) => Some(previous),
+            Entry::Sparse(_) => None,
+        }
+    }
+
+    /// Insert the sparse attribute `attribute`, named `name`.
+    ///
+    /// Returns `true` if an attribute with that name, sparse or not, was replaced.
+    /// The new attribute then keeps its position in the iteration order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{AttributeSet, Slice, SparseAttribute};
+    ///
+    /// let positions = [[0.0_f32; 3]; 4];
+    /// let mut crease = SparseAttribute::new(4, 0_u8);
+    /// crease.set(2, 1).unwrap();
+    ///
+    /// let mut set = AttributeSet::new();
+    /// set.insert("position", Slice::native(&positions));
+    /// set.insert_sparse("crease", &crease);
+    /// assert_eq!(set.validate_counts(), Ok(4));
+    ///
+    /// let crease = set.get_sparse::<u8>("crease").unwrap();
+    /// assert_eq!(crease.materialize_to_vec(), [0, 0, 1, 0]);
+    /// assert!(set.get::<u8>("crease").is_err());
+    /// ```
+    pub fn insert_sparse<T: TaggedPod + Send + Sync>(
+        &mut self,
+        name: impl Into<String>,
+        attribute: &'a SparseAttribute<T>,
+    ) -> bool {
+        let sparse = ErasedSparse {
+            tag: T::TAG,
+            len: attribute.len(),
+            attribute,
+        };
+        self.insert_entry(name.into(), Entry::Sparse(sparse))
+            .is_some()
+    }
+
+    fn insert_entry(&mut self, name: String, entry: Entry<'a>) -> Option<Entry<'a>> {
+        match self.attributes.iter_mut().find(|(key, _)| *key == name) {
+            Some((_, previous)) => Some(std::mem::replace(previous, entry)),
+            None => {
+                self.attributes.push((name, entry));
+                None
+            }
+        }
+    }
+
+    fn entry(&self, name: &str) -> Option<&Entry<'a>> {
+        self.attributes
+            .iter()
+            .find_map(|(key, entry)| (key == name).then_some(entry))
+    }
+
+    /// Get the attribute named `name`, type-erased.
+    ///
+    /// Returns `None` for sparse attributes.
+    pub fn get_erased(&self, name: &str) -> Option<&ErasedSlice<'a>> {
+        match self.entry(name)? {
+            Entry::Dense(slice) => Some(slice),
+            Entry::Sparse(_) => None,
+        }
+    }
+
+    /// Get the attribute named `name`, as a view of `T`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`AttributeError::Missing`] if no attribute is named `name`,
+    /// [`AttributeError::KindMismatch`] if it's sparse, and
+    /// [`AttributeError::TypeMismatch`] if it wasn't inserted as a `T`.
+    pub fn get<T: TaggedPod>(&self, name: &str) -> Result<Slice<'a, T>, AttributeError> {
+        let slice = match self.try_entry(name)? {
+            Entry::Dense(slice) => slice,
+            Entry::Sparse(_) => {
+                return Err(AttributeError::KindMismatch {
+                    name: name.to_owned(),
+                    sparse: true,
+                })
+            }
+        };
+        slice
+            .downcast_tagged()
+            .map_err(|error| AttributeError::TypeMismatch {
+                name: name.to_owned(),
+                error,
+            })
+    }
+
+    /// Get the sparse attribute named `name`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`AttributeError::Missing`] if no attribute is named `name`,
+    /// [`AttributeError::KindMismatch`] if it isn't sparse, and
+    /// [`AttributeError::TypeMismatch`] if it wasn't inserted as a `T`.
+    pub fn get_sparse<T: TaggedPod>(
+        &self,
+        name: &str,
+    ) -> Result<&'a SparseAttribute<T>, AttributeError> {
+        let sparse = match self.try_entry(name)? {
+            Entry::Sparse(sparse) => sparse,
+            Entry::Dense(_) => {
+                return Err(AttributeError::KindMismatch {
+                    name: name.to_owned(),
+                    sparse: false,
+                })
+            }
+        };
+        sparse
+            .attribute
+            .downcast_ref()
+            .ok_or_else(|| AttributeError::TypeMismatch {
+                name: name.to_owned(),
+                error: SliceError::TagMismatch {
+                    expected: T::TAG,
+                    found: Some(sparse.tag),
+                },
+            })
+    }
+
+    fn try_entry(&self, name: &str) -> Result<Entry<'a>, AttributeError> {
+        self.entry(name)
+            .copied()
+            .ok_or_else(|| AttributeError::Missing {
+                name: name.to_owned(),
+            })
+    }
+
+    /// Similar to [`Self::get`], but views `len` copies of `default` if no attribute
+    /// is named `name`, see [`crate::or_broadcast`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{AttributeSet, Slice};
+    ///
+    /// let positions = [[0.0_f32; 3]; 2];
+    /// let mut set = AttributeSet::new();
+    /// set.insert("position", Slice::native(&positions));
+    ///
+    /// let count = set.validate_counts().unwrap();
+    /// let colors = set.get_or_broadcast("color", &[1.0_f32; 4], count).unwrap();
+    /// assert_eq!(colors.to_vec(), [[1.0; 4]; 2]);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`AttributeError::KindMismatch`] if the attribute is sparse,
+    /// [`AttributeError::TypeMismatch`] if it wasn't inserted as a `T`, and
+    /// [`AttributeError::CountMismatch`] if it has fewer than `len` elements.
+    pub fn get_or_broadcast<T: TaggedPod>(
+        &self,
+        name: &str,
+        default: &T,
+        len: usize,
+    ) -> Result<MaybeOwnedSlice<'a, T>, AttributeError> {
+        let slice = match self.get(name) {
+            Ok(slice) => Some(slice),
+            Err(AttributeError::Missing { .. }) => None,
+            Err(err) => return Err(err),
+        };
+        // The real view is too short, the only error of `or_broadcast`.
+        let count = slice.map_or(len, |slice| slice.len());
+        or_broadcast(slice, default, len).map_err(|_| AttributeError::CountMismatch {
+            name: name.to_owned(),
+            expected: len,
+            count,
+        })
+    }
+
+    /// Iterate over the name and view of each attribute, in insertion order.
+    ///
+    /// Sparse attributes are skipped, see [`Self::iter_sparse`].
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ErasedSlice<'a>)> {
+        self.attributes
+            .iter()
+            .filter_map(|(name, entry)| match entry {
+                Entry::Dense(slice) => Some((name.as_str(), slice)),
+                Entry::Sparse(_) => None,
+            })
+    }
+
+    /// Iterate over the name and type tag of each sparse attribute, in insertion order.
+    pub fn iter_sparse(&self) -> impl Iterator<Item = (&str, TypeTag)> + '_ {
+        self.attributes
+            .iter()
+            .filter_map(|(name, entry)| match entry {
+                Entry::Sparse(sparse) => Some((name.as_str(), sparse.tag)),
+                Entry::Dense(_) => None,
+            })
+    }
+
+    /// Check that every attribute has the same number of elements, and return it.
+    ///
+    /// An empty set has `0` elements. Sparse attributes are checked with their
+    /// logical length.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`AttributeError::CountMismatch`] for the first attribute whose count
+    /// differs from the count of the first attribute.
+    pub fn validate_counts(&self) -> Result<usize, AttributeError> {
+        let expected = match self.attributes.first() {
+            Some((_, entry)) => entry.len(),
+            None => return Ok(0),
+        };
+        match self
+            .attributes
+            .iter()
+            .find(|(_, entry)| entry.len() != expected)
+        {
+            Some((name, entry)) => Err(AttributeError::CountMismatch {
+                name: name.to_owned(),
+                expected,
+                count: entry.len(),
+            }),
+            None => Ok(expected),
+        }
+    }
+}
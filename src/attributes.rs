@@ -0,0 +1,117 @@
+use bytemuck::Pod;
+use std::{marker::PhantomData, ops::Range};
+
+use crate::shared_impl::SliceBase;
+use crate::slice_mut::mut_range;
+use crate::{Slice, SliceMut, Strict, ViewDesc};
+
+/// Layout of a field of a struct implementing [`Attributes`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AttributeLayout {
+    /// Name of the field
+    pub name: &'static str,
+    /// Byte offset of the field in the struct
+    pub offset: usize,
+    /// Size of the field, in **bytes**
+    pub size: usize,
+    /// Alignment of the field, in **bytes**
+    pub align: usize,
+}
+
+impl AttributeLayout {
+    /// Description of the field in `count` consecutive structs of `stride` bytes.
+    pub fn desc(&self, stride: usize, count: usize) -> ViewDesc {
+        ViewDesc::new(self.offset, stride, count)
+    }
+}
+
+/// Struct whose fields are viewed as attributes.
+///
+/// Implemented with `#[derive(Attributes)]`, behind the `derive` feature. The derive
+/// also generates `<Name>Attributes` and `<Name>AttributesMut` structs, with a view
+/// per field, created using `of()`. Mutable views are alive at the same time, and
+/// each one only owns the bytes of its field: see [`crate::Slice::rebase`].
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use pas::{Attributes, Slice};
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable, Attributes)]
+/// struct Vertex {
+///     position: [f32; 3],
+///     uv: [f32; 2],
+/// }
+///
+/// let mut vertices = [Vertex { position: [1.0, 2.0, 3.0], uv: [0.5, 1.0] }; 2];
+/// let views = VertexAttributes::of(&vertices);
+/// assert_eq!(views.uv[1], [0.5, 1.0]);
+///
+/// let mut views = VertexAttributesMut::of(&mut vertices);
+/// views.uv.fill([0.0, 0.0]);
+/// views.position[0] = views.position[1];
+///
+/// assert_eq!(Vertex::STRIDE, 20);
+/// assert_eq!(Vertex::ATTRIBUTES[1].offset, 12);
+/// # }
+/// ```
+pub trait Attributes: Pod {
+    /// Distance between two structs, in **bytes**
+    const STRIDE: usize = std::mem::size_of::<Self>();
+    /// Layout of every field, in declaration order
+    const ATTRIBUTES: &'static [AttributeLayout];
+}
+
+/// Create a view over the field at byte `offset` of every element of `data`.
+///
+/// Used by `#[derive(Attributes)]`.
+#[doc(hidden)]
+pub fn field<V: Pod, A: Pod>(data: &[V], offset: usize) -> Slice<'_, A> {
+    // Empty data has no byte to offset into.
+    let offset = match data.is_empty() {
+        true => 0,
+        false => offset,
+    };
+    Slice::new(data, offset)
+}
+
+/// Create mutable views over distinct fields of the same data.
+///
+/// Used by `#[derive(Attributes)]`.
+#[doc(hidden)]
+pub struct FieldSplitter<'a, V: Pod> {
+    range: Range<*const V>,
+    _phantom: PhantomData<&'a mut [V]>,
+}
+
+impl<'a, V: Pod> FieldSplitter<'a, V> {
+    pub fn new(data: &'a mut [V]) -> Self {
+        // Every view is created from this range, which keeps the write
+        // permission of the borrow.
+        Self {
+            range: mut_range(data),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Create a mutable view over the field at byte `offset`.
+    ///
+    /// The view only owns the bytes of the field, such that it can't be rebased
+    /// over another field.
+    ///
+    /// ## Safety
+    ///
+    /// Views created from the same splitter must not overlap, i.e., must
+    /// be over distinct fields.
+    pub unsafe fn field<A: Pod>(&self, offset: usize) -> SliceMut<'a, A> {
+        let offset = match self.range.is_empty() {
+            true => 0,
+            false => offset,
+        };
+        let base = SliceBase::new_typed::<_, Strict>(self.range.clone(), offset, 1).unwrap();
+        SliceMut::from_base(base.owning_attribute())
+    }
+}
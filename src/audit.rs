@@ -0,0 +1,219 @@
+use crate::StridedInfo;
+
+/// Suspicious layout found by [`audit_views`].
+///
+/// Warnings are heuristics: a layout can trigger one on purpose, e.g., two
+/// views reading the same bytes as different types.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AuditWarning {
+    /// The stride is smaller than an element, such that elements overlap.
+    ///
+    /// This is typical of a count of components, or of elements, passed where a
+    /// number of bytes is expected, e.g., a stride of `3` for `[f32; 3]`.
+    StrideSmallerThanElement {
+        /// View name
+        name: String,
+        /// Stride, in **bytes**
+        stride: usize,
+        /// Element size, in **bytes**
+        element_size: usize,
+    },
+    /// The elements of the view end past the end of the buffer.
+    ///
+    /// This is typical of a buffer length in elements passed where a number
+    /// of bytes is expected, or of a byte count passed as an element count.
+    SpanExceedsBuffer {
+        /// View name
+        name: String,
+        /// Byte offset one past the last byte of the last element, or `None`
+        /// if it overflows
+        end: Option<usize>,
+        /// Buffer length, in **bytes**
+        buffer_len: usize,
+    },
+    /// The byte offset isn't a multiple of the alignment of the element type.
+    UnalignedOffset {
+        /// View name
+        name: String,
+        /// Byte offset of the first element
+        offset: usize,
+        /// Alignment of the element type, in **bytes**
+        align: usize,
+    },
+    /// The stride isn't a multiple of the alignment of the element type.
+    UnalignedStride {
+        /// View name
+        name: String,
+        /// Stride, in **bytes**
+        stride: usize,
+        /// Alignment of the element type, in **bytes**
+        align: usize,
+    },
+    /// Two views have the same offset, stride, and element size, e.g., an
+    /// attribute registered twice.
+    Duplicate {
+        /// Name of the second view
+        name: String,
+        /// Name of the first view
+        first: String,
+        /// Byte offset of the first element
+        offset: usize,
+        /// Element size, in **bytes**
+        element_size: usize,
+    },
+}
+
+impl std::fmt::Display for AuditWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::StrideSmallerThanElement {
+                name,
+                stride,
+                element_size,
+            } => write!(
+                f,
+                "'{}': stride ({}) is smaller than the element size ({}), \
+                 possible element count passed as a byte count",
+                name, stride, element_size
+            ),
+            Self::SpanExceedsBuffer {
+                name,
+                end: Some(end),
+                buffer_len,
+            } => write!(
+                f,
+                "'{}': elements end at byte {}, past the end of the buffer ({} bytes), \
+                 possible element count passed as a byte count",
+                name, end, buffer_len
+            ),
+            Self::SpanExceedsBuffer {
+                name,
+                end: None,
+                buffer_len,
+            } => write!(
+                f,
+                "'{}': elements end past `usize::MAX`, and the buffer has {} bytes",
+                name, buffer_len
+            ),
+            Self::UnalignedOffset {
+                name,
+                offset,
+                align,
+            } => write!(
+                f,
+                "'{}': offset ({}) isn't a multiple of the element alignment ({})",
+                name, offset, align
+            ),
+            Self::UnalignedStride {
+                name,
+                stride,
+                align,
+            } => write!(
+                f,
+                "'{}': stride ({}) isn't a multiple of the element alignment ({})",
+                name, stride, align
+            ),
+            Self::Duplicate {
+                name,
+                first,
+                offset,
+                element_size,
+            } => write!(
+                f,
+                "'{}': same offset ({}) and element size ({}) as '{}', \
+                 possible duplicate registration",
+                name, offset, element_size, first
+            ),
+        }
+    }
+}
+
+/// Check the layout of views over a buffer of `buffer_len` bytes, and warn
+/// about layouts typical of integration bugs, see [`AuditWarning`].
+///
+/// Views with an unknown element size, or alignment, skip the checks that need
+/// it, see [`StridedInfo::element_size`]. Views are compared to detect duplicates
+/// if they have the same addresses, or both have no address and the same layout.
+///
+/// Warnings are returned in view order, and this function never panics.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{audit_views, Slice};
+///
+/// let vertices = [[0.0_f32; 5]; 4];
+/// let positions: Slice<[f32; 3]> = Slice::new(&vertices, 0);
+/// let uvs: Slice<[f32; 2]> = Slice::new(&vertices, 12);
+///
+/// // The buffer length is given in elements, instead of bytes.
+/// let warnings = audit_views(&[("position", &positions), ("uv", &uvs)], vertices.len());
+/// assert_eq!(warnings.len(), 2);
+/// println!("{}", warnings[0]);
+///
+/// let bytes = std::mem::size_of_val(&vertices);
+/// assert!(audit_views(&[("position", &positions), ("uv", &uvs)], bytes).is_empty());
+/// ```
+pub fn audit_views(views: &[(&str, &dyn StridedInfo)], buffer_len: usize) -> Vec<AuditWarning> {
+    let mut warnings = Vec::new();
+    for (index, (name, view)) in views.iter().enumerate() {
+        let (offset, stride) = (view.byte_offset(), view.byte_stride());
+        let (size, align) = (view.element_size(), view.element_align());
+        if size > 0 && view.len() > 1 && stride < size {
+            warnings.push(AuditWarning::StrideSmallerThanElement {
+                name: name.to_string(),
+                stride,
+                element_size: size,
+            });
+        }
+        let end = match view.len() {
+            0 => Some(offset),
+            len => (len - 1)
+                .checked_mul(stride)
+                .and_then(|v| v.checked_add(size))
+                .and_then(|v| v.checked_add(offset)),
+        };
+        if end.map_or(true, |end| end > buffer_len) {
+            warnings.push(AuditWarning::SpanExceedsBuffer {
+                name: name.to_string(),
+                end,
+                buffer_len,
+            });
+        }
+        if align > 1 && offset % align != 0 {
+            warnings.push(AuditWarning::UnalignedOffset {
+                name: name.to_string(),
+                offset,
+                align,
+            });
+        }
+        if align > 1 && view.len() > 1 && stride % align != 0 {
+            warnings.push(AuditWarning::UnalignedStride {
+                name: name.to_string(),
+                stride,
+                align,
+            });
+        }
+        let duplicate = views[..index].iter().find(|(_, other)| {
+            let (range, other_range) = (view.ptr_range(), other.ptr_range());
+            let same_memory = match range.start.is_null() || other_range.start.is_null() {
+                true => range.start.is_null() && other_range.start.is_null(),
+                false => range.start == other_range.start,
+            };
+            same_memory
+                && size > 0
+                && other.byte_offset() == offset
+                && other.byte_stride() == stride
+                && other.element_size() == size
+        });
+        if let Some((first, _)) = duplicate {
+            warnings.push(AuditWarning::Duplicate {
+                name: name.to_string(),
+                first: first.to_string(),
+                offset,
+                element_size: size,
+            });
+        }
+    }
+    warnings
+}
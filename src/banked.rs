@@ -0,0 +1,317 @@
+use bytemuck::Pod;
+use std::fmt::Debug;
+
+use crate::{Slice, SliceMut};
+
+/// How the elements of a [`SplitSlice`] are distributed over its two banks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BankPolicy {
+    /// Every element of the first bank, followed by every element of the second one
+    Concatenated,
+    /// Runs of `period` elements, alternating between the first and the second bank.
+    ///
+    /// Once a bank is exhausted, the remaining elements all come from the other one.
+    Interleaved {
+        /// Number of consecutive elements in the same bank
+        period: usize,
+    },
+}
+
+/// Bank holding an element of a [`SplitSlice`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Bank {
+    First,
+    Second,
+}
+
+/// Routing of the logical indices of a [`SplitSlice`] to its banks.
+#[derive(Clone, Copy, Debug)]
+struct Routing {
+    period: usize,
+    first: usize,
+    second: usize,
+}
+
+impl Routing {
+    fn new(policy: BankPolicy, first: usize, second: usize) -> Self {
+        let period = match policy {
+            // A single run as long as any bank.
+            BankPolicy::Concatenated => usize::MAX,
+            BankPolicy::Interleaved { period } => {
+                assert!(period != 0, "period must be non-zero");
+                period
+            }
+        };
+        Self {
+            period,
+            first,
+            second,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.first + self.second
+    }
+
+    /// Bank and index in the bank of the element at logical index `index`.
+    fn route(&self, index: usize) -> Option<(Bank, usize)> {
+        if index >= self.len() {
+            return None;
+        }
+        let p = self.period;
+        // Rounds where both banks hold a full run.
+        let rounds = (self.first / p).min(self.second / p);
+        let balanced = rounds * p;
+        if index < balanced * 2 {
+            let (round, offset) = (index / (2 * p), index % (2 * p));
+            return Some(match offset < p {
+                true => (Bank::First, round * p + offset),
+                false => (Bank::Second, round * p + offset - p),
+            });
+        }
+        // Past the seam, at least one bank has less than a run left.
+        let index = index - balanced * 2;
+        let (first, second) = (self.first - balanced, self.second - balanced);
+        let run = first.min(p);
+        if index < run {
+            return Some((Bank::First, balanced + index));
+        }
+        let index = index - run;
+        if first < p || index < second {
+            Some((Bank::Second, balanced + index))
+        } else {
+            Some((Bank::First, balanced + p + index - second))
+        }
+    }
+}
+
+/// Iterator over two banks, in logical order.
+///
+/// Each bank is read in order, so the next element of a bank is always the next
+/// one of its iterator.
+struct BankedIter<I> {
+    first: I,
+    second: I,
+    routing: Routing,
+    index: usize,
+}
+
+impl<I> BankedIter<I> {
+    fn new(first: I, second: I, routing: Routing) -> Self {
+        Self {
+            first,
+            second,
+            routing,
+            index: 0,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for BankedIter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (bank, _) = self.routing.route(self.index)?;
+        self.index += 1;
+        match bank {
+            Bank::First => self.first.next(),
+            Bank::Second => self.second.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.routing.len() - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<I: Iterator> ExactSizeIterator for BankedIter<I> {}
+
+/// One logical array, split across two slices, e.g., two memory banks.
+///
+/// Elements are distributed according to a [`BankPolicy`], and are read in
+/// logical order, whatever the bank holding them.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{BankPolicy, Slice, SplitSlice};
+///
+/// let even = [0_u32, 2, 4];
+/// let odd = [1_u32, 3];
+/// let split = SplitSlice::new(
+///     Slice::native(&even),
+///     Slice::native(&odd),
+///     BankPolicy::Interleaved { period: 1 },
+/// );
+/// assert_eq!(split.len(), 5);
+/// assert_eq!(split.get(3), Some(&3));
+/// assert_eq!(split.to_vec(), [0, 1, 2, 3, 4]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct SplitSlice<'a, T: Pod> {
+    first: Slice<'a, T>,
+    second: Slice<'a, T>,
+    policy: BankPolicy,
+    routing: Routing,
+}
+
+impl<'a, T: Pod> SplitSlice<'a, T> {
+    /// Create a view over the elements of `first` and `second`, distributed with `policy`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `policy` is [`BankPolicy::Interleaved`], with a period of `0`.
+    pub fn new(first: Slice<'a, T>, second: Slice<'a, T>, policy: BankPolicy) -> Self {
+        Self {
+            routing: Routing::new(policy, first.len(), second.len()),
+            first,
+            second,
+            policy,
+        }
+    }
+
+    /// Slice over the first bank.
+    pub fn first(&self) -> Slice<'a, T> {
+        self.first
+    }
+
+    /// Slice over the second bank.
+    pub fn second(&self) -> Slice<'a, T> {
+        self.second
+    }
+
+    /// Distribution of the elements over the banks.
+    pub fn policy(&self) -> BankPolicy {
+        self.policy
+    }
+
+    /// Number of elements, over both banks.
+    pub fn len(&self) -> usize {
+        self.routing.len()
+    }
+
+    /// `true` if both banks are empty, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the element at logical index `index`, or `None` if out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match self.routing.route(index)? {
+            (Bank::First, index) => self.first.get(index),
+            (Bank::Second, index) => self.second.get(index),
+        }
+    }
+
+    /// Iterate over the elements in logical order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &T> + '_ {
+        BankedIter::new(self.first.iter(), self.second.iter(), self.routing)
+    }
+
+    /// Copy the elements in logical order into a [`Vec`].
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().copied().collect()
+    }
+}
+
+impl<'a, T: Pod + Debug> Debug for SplitSlice<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Mutable version of [`SplitSlice`].
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{BankPolicy, SliceMut, SplitSliceMut};
+///
+/// let mut low = [0_u32; 2];
+/// let mut high = [0_u32; 3];
+/// let mut split = SplitSliceMut::new(
+///     SliceMut::native(&mut low),
+///     SliceMut::native(&mut high),
+///     BankPolicy::Concatenated,
+/// );
+/// for (i, value) in split.iter_mut().enumerate() {
+///     *value = i as u32;
+/// }
+/// assert_eq!((low, high), ([0, 1], [2, 3, 4]));
+/// ```
+pub struct SplitSliceMut<'a, T: Pod> {
+    first: SliceMut<'a, T>,
+    second: SliceMut<'a, T>,
+    policy: BankPolicy,
+    routing: Routing,
+}
+
+impl<'a, T: Pod> SplitSliceMut<'a, T> {
+    /// Mutable version of [`SplitSlice::new`].
+    pub fn new(first: SliceMut<'a, T>, second: SliceMut<'a, T>, policy: BankPolicy) -> Self {
+        Self {
+            routing: Routing::new(policy, first.len(), second.len()),
+            first,
+            second,
+            policy,
+        }
+    }
+
+    /// Immutable view over the same elements.
+    pub fn as_split_slice(&self) -> SplitSlice<'_, T> {
+        SplitSlice {
+            first: Slice::from_base(*self.first.base()),
+            second: Slice::from_base(*self.second.base()),
+            policy: self.policy,
+            routing: self.routing,
+        }
+    }
+
+    /// Distribution of the elements over the banks.
+    pub fn policy(&self) -> BankPolicy {
+        self.policy
+    }
+
+    /// Number of elements, over both banks.
+    pub fn len(&self) -> usize {
+        self.routing.len()
+    }
+
+    /// `true` if both banks are empty, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the element at logical index `index`, or `None` if out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match self.routing.route(index)? {
+            (Bank::First, index) => self.first.get(index),
+            (Bank::Second, index) => self.second.get(index),
+        }
+    }
+
+    /// Mutable version of [`Self::get`].
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match self.routing.route(index)? {
+            (Bank::First, index) => self.first.get_mut(index),
+            (Bank::Second, index) => self.second.get_mut(index),
+        }
+    }
+
+    /// Iterate mutably over the elements in logical order.
+    pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = &mut T> + '_ {
+        BankedIter::new(self.first.iter(), self.second.iter(), self.routing)
+    }
+
+    /// Copy the elements in logical order into a [`Vec`].
+    pub fn to_vec(&self) -> Vec<T> {
+        self.as_split_slice().to_vec()
+    }
+}
+
+impl<'a, T: Pod + Debug> Debug for SplitSliceMut<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_split_slice().fmt(f)
+    }
+}
@@ -0,0 +1,264 @@
+use bytemuck::Pod;
+use std::io::{Read, Write};
+
+use crate::{OwnedStrided, SliceInfo};
+
+/// Blob magic, at the start of every header.
+const MAGIC: [u8; 4] = *b"PASB";
+/// Current format version.
+const VERSION: u16 = 1;
+/// Endianness of the elements written on this target, `0` for little-endian
+/// and `1` for big-endian.
+const ENDIANNESS: u8 = cfg!(target_endian = "big") as u8;
+/// Header size, in **bytes**.
+const HEADER_SIZE: usize = 32;
+/// Maximum number of bytes read at once, to avoid trusting the header
+/// with the allocation size.
+const READ_CHUNK: usize = 1 << 16;
+
+/// Blob error
+///
+/// An error is raised when reading a blob via [`OwnedStrided::read_blob`].
+pub enum BlobError {
+    /// The reader failed.
+    Io(std::io::Error),
+    /// The data doesn't start with the blob magic.
+    BadMagic([u8; 4]),
+    /// The blob was written with an unknown version of the format.
+    UnsupportedVersion(u16),
+    /// The blob elements were written on a target of another endianness.
+    EndiannessMismatch,
+    /// The blob elements don't have the size of the requested type.
+    ElementSizeMismatch {
+        /// Size of the requested type, in **bytes**
+        expected: usize,
+        /// Size of the blob elements, in **bytes**
+        found: u64,
+    },
+    /// The blob was written with another type than the requested one.
+    TypeMismatch {
+        /// Requested type name
+        type_name: &'static str,
+    },
+    /// The header or the elements are shorter than expected.
+    Truncated {
+        /// Expected size, in **bytes**
+        expected: u64,
+        /// Number of bytes read
+        found: u64,
+    },
+    /// The blob size doesn't fit in memory.
+    Overflow,
+}
+
+impl std::fmt::Debug for BlobError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Failed to read blob: {}", err),
+            Self::BadMagic(magic) => write!(f, "Invalid blob magic {:?}", magic),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "Unsupported blob version {}", version)
+            }
+            Self::EndiannessMismatch => {
+                write!(f, "Blob was written on a target of another endianness")
+            }
+            Self::ElementSizeMismatch { expected, found } => write!(
+                f,
+                "Blob elements are {} bytes, but {} bytes were expected",
+                found, expected
+            ),
+            Self::TypeMismatch { type_name } => {
+                write!(f, "Blob wasn't written with type '{}'", type_name)
+            }
+            Self::Truncated { expected, found } => write!(
+                f,
+                "Blob is truncated, expected {} bytes but found {}",
+                expected, found
+            ),
+            Self::Overflow => write!(f, "Blob size overflows"),
+        }
+    }
+}
+
+impl From<std::io::Error> for BlobError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Stable hash of the name of `T`, using FNV-1a.
+///
+/// Type names aren't guaranteed to be stable across compiler versions,
+/// `0` is reserved for blobs without type information.
+fn type_hash<T>() -> u64 {
+    let hash = std::any::type_name::<T>()
+        .bytes()
+        .fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+    hash.max(1)
+}
+
+/// Read into `buf` until it's full or the reader is exhausted, and return
+/// the number of bytes read.
+fn read_full(r: &mut impl Read, buf: &mut [u8]) -> std::io::Result<usize> {
+    let mut read = 0;
+    while read < buf.len() {
+        match r.read(&mut buf[read..]) {
+            Ok(0) => break,
+            Ok(n) => read += n,
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(read)
+}
+
+impl<Attr: Pod> SliceInfo<Attr> {
+    /// Write the slice as a self-describing blob, read back with [`OwnedStrided::read_blob`].
+    ///
+    /// The blob is a 32 bytes header followed by the packed elements:
+    ///
+    /// | Bytes    | Content                                  |
+    /// |----------|------------------------------------------|
+    /// | `0..4`   | Magic, `PASB`                            |
+    /// | `4..6`   | Format version, `u16`                    |
+    /// | `6`      | Endianness, `0` little, `1` big          |
+    /// | `7`      | Reserved, `0`                            |
+    /// | `8..16`  | Element size, in **bytes**, `u64`        |
+    /// | `16..24` | Element count, `u64`                     |
+    /// | `24..32` | Type name hash, `u64`, `0` if unknown    |
+    ///
+    /// Header fields are little-endian. Element bytes are written as is, in the
+    /// endianness of the target, which is recorded in the header: reading the blob
+    /// on a target of another endianness fails.
+    ///
+    /// Strided slices are written element by element, prefer a buffered writer.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{OwnedStrided, Slice};
+    ///
+    /// let data = [[0_u32, 1], [2, 3], [4, 5]];
+    /// let slice: Slice<u32> = Slice::new(&data, 4);
+    ///
+    /// let mut blob = Vec::new();
+    /// slice.write_blob(&mut blob).unwrap();
+    ///
+    /// let owned = OwnedStrided::<u32>::read_blob(&mut blob.as_slice()).unwrap();
+    /// assert_eq!(owned.as_elements(), &[1, 3, 5]);
+    /// ```
+    pub fn write_blob(&self, w: &mut impl Write) -> std::io::Result<()> {
+        let mut header = [0_u8; HEADER_SIZE];
+        header[0..4].copy_from_slice(&MAGIC);
+        header[4..6].copy_from_slice(&VERSION.to_le_bytes());
+        header[6] = ENDIANNESS;
+        header[8..16].copy_from_slice(&(std::mem::size_of::<Attr>() as u64).to_le_bytes());
+        header[16..24].copy_from_slice(&(self.len() as u64).to_le_bytes());
+        header[24..32].copy_from_slice(&type_hash::<Attr>().to_le_bytes());
+        w.write_all(&header)?;
+
+        if self.stride() == std::mem::size_of::<Attr>() {
+            // Safe because the elements are packed and in bounds.
+            let bytes = unsafe {
+                std::slice::from_raw_parts(
+                    self.base().start,
+                    self.len() * std::mem::size_of::<Attr>(),
+                )
+            };
+            return w.write_all(bytes);
+        }
+        for element in self.elements() {
+            w.write_all(bytemuck::bytes_of(element))?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Pod> OwnedStrided<T> {
+    /// Read a blob written by [`SliceInfo::write_blob`].
+    ///
+    /// The header is validated against `T`. If the blob has a type name hash,
+    /// it must match the one of `T`.
+    ///
+    /// Elements are read in chunks, such that a corrupt header can't
+    /// trigger a large allocation on its own.
+    ///
+    /// ## Errors
+    ///
+    /// - [`BlobError::BadMagic`] if `r` doesn't start with a blob header
+    /// - [`BlobError::UnsupportedVersion`] if the format version is unknown
+    /// - [`BlobError::EndiannessMismatch`] if the blob was written on a target
+    ///   of another endianness
+    /// - [`BlobError::ElementSizeMismatch`] if elements don't have the size of `T`
+    /// - [`BlobError::TypeMismatch`] if the type name hash doesn't match `T`
+    /// - [`BlobError::Truncated`] if the header or the elements are incomplete
+    /// - [`BlobError::Overflow`] if the blob size doesn't fit in memory
+    /// - [`BlobError::Io`] if reading fails
+    pub fn read_blob(r: &mut impl Read) -> Result<OwnedStrided<T>, BlobError> {
+        let mut header = [0_u8; HEADER_SIZE];
+        let read = read_full(r, &mut header)?;
+        if read < HEADER_SIZE {
+            return Err(BlobError::Truncated {
+                expected: HEADER_SIZE as u64,
+                found: read as u64,
+            });
+        }
+        let field = |range: std::ops::Range<usize>| {
+            let mut bytes = [0_u8; 8];
+            bytes[..range.len()].copy_from_slice(&header[range]);
+            u64::from_le_bytes(bytes)
+        };
+
+        let magic = [header[0], header[1], header[2], header[3]];
+        if magic != MAGIC {
+            return Err(BlobError::BadMagic(magic));
+        }
+        let version = field(4..6) as u16;
+        if version != VERSION {
+            return Err(BlobError::UnsupportedVersion(version));
+        }
+        if header[6] != ENDIANNESS {
+            return Err(BlobError::EndiannessMismatch);
+        }
+        let size = field(8..16);
+        if size != std::mem::size_of::<T>() as u64 {
+            return Err(BlobError::ElementSizeMismatch {
+                expected: std::mem::size_of::<T>(),
+                found: size,
+            });
+        }
+        let hash = field(24..32);
+        if hash != 0 && hash != type_hash::<T>() {
+            return Err(BlobError::TypeMismatch {
+                type_name: std::any::type_name::<T>(),
+            });
+        }
+        let count = field(16..24);
+        let expected = count.checked_mul(size).ok_or(BlobError::Overflow)?;
+        let count = usize::try_from(count).map_err(|_| BlobError::Overflow)?;
+        if expected > isize::MAX as u64 {
+            return Err(BlobError::Overflow);
+        }
+
+        let mut data: Vec<T> = Vec::new();
+        let chunk = match size as usize {
+            0 => count,
+            size => (READ_CHUNK / size).max(1),
+        };
+        while data.len() < count {
+            let start = data.len();
+            data.resize(start + chunk.min(count - start), T::zeroed());
+            let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut data[start..]);
+            let read = read_full(r, bytes)?;
+            if read < bytes.len() {
+                return Err(BlobError::Truncated {
+                    expected,
+                    found: (start * std::mem::size_of::<T>() + read) as u64,
+                });
+            }
+        }
+        Ok(OwnedStrided::new(data))
+    }
+}
@@ -0,0 +1,186 @@
+use bytemuck::Pod;
+
+use crate::{Resume, SliceInfo, SliceMut};
+
+/// Progress of a budgeted pass, see [`SliceMut::apply_budgeted`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ApplyProgress {
+    /// Every element was processed.
+    Finished {
+        /// Number of elements processed by this call
+        processed: usize,
+    },
+    /// Elements remain to be processed, by later calls.
+    InProgress {
+        /// Number of elements processed by this call
+        processed: usize,
+        /// Number of elements left
+        remaining: usize,
+    },
+}
+
+/// A resumable state is used with a slice whose length changed.
+///
+/// Raised by [`SliceInfo::visit_budgeted`], [`SliceMut::apply_budgeted`],
+/// and [`crate::TrackedSliceMut::fingerprint_incremental`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StaleStateError {
+    /// Number of elements when the state was first used
+    pub expected: usize,
+    /// Number of elements of the slice
+    pub len: usize,
+}
+
+/// Resumable state of a budgeted pass over a slice.
+///
+/// The state is bound to the length of the slice it's first used with. Using it
+/// with a slice of another length raises a [`StaleStateError`], until
+/// [`Self::reset`] is called.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ApplyState {
+    /// Index of the next element to process
+    next: usize,
+    /// Length of the slice, set on first use
+    len: Option<usize>,
+}
+
+impl ApplyState {
+    /// Create a state starting at the first element.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Index of the next element to process.
+    pub fn next_index(&self) -> usize {
+        self.next
+    }
+
+    /// Position of the next element to process, e.g., to view the remaining
+    /// elements with [`Resume::remaining_mut`].
+    pub fn resume(&self) -> Resume {
+        Resume::at(self.next)
+    }
+
+    /// Restart from the first element, and unbind the state from its slice.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Bind the state to `len` on first use, and reserve at most `budget` elements.
+    ///
+    /// Returns the range of elements to process.
+    fn reserve(
+        &mut self,
+        len: usize,
+        budget: usize,
+    ) -> Result<std::ops::Range<usize>, StaleStateError> {
+        match self.len {
+            Some(expected) if expected != len => {
+                return Err(StaleStateError { expected, len });
+            }
+            _ => self.len = Some(len),
+        }
+        let start = self.next;
+        self.next = start + budget.min(len - start);
+        Ok(start..self.next)
+    }
+
+    fn progress(&self, processed: usize) -> ApplyProgress {
+        // `len` is always set once elements were reserved.
+        match self.len.unwrap_or(0) - self.next {
+            0 => ApplyProgress::Finished { processed },
+            remaining => ApplyProgress::InProgress {
+                processed,
+                remaining,
+            },
+        }
+    }
+}
+
+impl<Attr: Pod> SliceInfo<Attr> {
+    /// Read-only version of [`SliceMut::apply_budgeted`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{ApplyProgress, ApplyState, Slice};
+    ///
+    /// let data = [1_u32, 2, 3, 4, 5];
+    /// let slice: Slice<u32> = Slice::native(&data);
+    ///
+    /// let mut state = ApplyState::new();
+    /// let mut sum = 0;
+    /// while let ApplyProgress::InProgress { .. } =
+    ///     slice.visit_budgeted(&mut state, 2, |_, v| sum += v).unwrap()
+    /// {
+    ///     // Do something else, e.g., render a frame.
+    /// }
+    /// assert_eq!(sum, 15);
+    /// ```
+    pub fn visit_budgeted(
+        &self,
+        state: &mut ApplyState,
+        budget: usize,
+        mut f: impl FnMut(usize, &Attr),
+    ) -> Result<ApplyProgress, StaleStateError> {
+        let range = state.reserve(self.len(), budget)?;
+        let processed = range.len();
+        let mut ptr = self.base().start.wrapping_add(range.start * self.stride());
+        for index in range {
+            // Safe because `index` is in bounds.
+            f(index, unsafe { &*ptr.cast::<Attr>() });
+            // Wrapping, since the pointer past the last element can be out of bounds.
+            ptr = ptr.wrapping_add(self.stride());
+        }
+        Ok(state.progress(processed))
+    }
+}
+
+impl<'a, Attr: Pod> SliceMut<'a, Attr> {
+    /// Apply `f` to at most `budget` elements, starting where the previous call stopped.
+    ///
+    /// This allows processing a slice incrementally, e.g., across frames. `f` receives
+    /// the index of each element. Calls made once the pass is finished don't
+    /// process any element.
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`StaleStateError`] if `state` was used with a slice
+    /// of another length.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{ApplyProgress, ApplyState, SliceMut};
+    ///
+    /// let mut data = [[0_u32, 1], [0, 2], [0, 3]];
+    /// let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 4);
+    ///
+    /// let mut state = ApplyState::new();
+    /// let progress = slice.apply_budgeted(&mut state, 2, |_, v| *v *= 10);
+    /// assert_eq!(
+    ///     progress,
+    ///     Ok(ApplyProgress::InProgress { processed: 2, remaining: 1 })
+    /// );
+    /// let progress = slice.apply_budgeted(&mut state, 2, |_, v| *v *= 10);
+    /// assert_eq!(progress, Ok(ApplyProgress::Finished { processed: 1 }));
+    /// assert_eq!(data, [[0, 10], [0, 20], [0, 30]]);
+    /// ```
+    pub fn apply_budgeted(
+        &mut self,
+        state: &mut ApplyState,
+        budget: usize,
+        mut f: impl FnMut(usize, &mut Attr),
+    ) -> Result<ApplyProgress, StaleStateError> {
+        let range = state.reserve(self.len(), budget)?;
+        let processed = range.len();
+        let mut ptr = (self.base().start as *mut u8).wrapping_add(range.start * self.stride());
+        for index in range {
+            // Safe because `index` is in bounds, and the slice is mutably borrowed.
+            f(index, unsafe { &mut *ptr.cast::<Attr>() });
+            // Wrapping, since the pointer past the last element can be out of bounds.
+            ptr = ptr.wrapping_add(self.stride());
+        }
+        Ok(state.progress(processed))
+    }
+}
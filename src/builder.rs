@@ -1,6 +1,7 @@
 use bytemuck::Pod;
+use std::marker::PhantomData;
 
-use crate::{Slice, SliceMut};
+use crate::{Slice, SliceError, SliceMut, Strict, ValidationPolicy};
 
 #[doc(hidden)]
 /// Get the offset between the start of a slice and a pointer.
@@ -22,14 +23,69 @@ pub fn get_byte_offset<V: Sized>(data: &[V], start: *const u8) -> usize {
     end.checked_sub(data.as_ptr() as usize).unwrap()
 }
 
+/// Similar to [`get_byte_offset`], but returns an error instead of panicking.
+fn try_get_byte_offset<V: Sized>(data: &[V], start: *const u8) -> Result<usize, SliceError> {
+    let ptr_range = data.as_ptr_range();
+    let offset = (start as usize).wrapping_sub(ptr_range.start as usize);
+    let size = ptr_range.end as usize - ptr_range.start as usize;
+    if offset < size {
+        Ok(offset)
+    } else {
+        Err(SliceError::OffsetOutOfBounds { size, offset })
+    }
+}
+
+/// Compile-time size check of the [`crate::slice!`] and [`crate::slice_mut!`] macros.
+///
+/// Evaluating [`Self::OK`] fails the build if `T` is larger than the field `F`.
+struct AssertFieldSize<F, T>(PhantomData<(F, T)>);
+
+impl<F, T> AssertFieldSize<F, T> {
+    const OK: () = assert!(
+        std::mem::size_of::<T>() <= std::mem::size_of::<F>(),
+        "the slice element type `T` is larger than the referenced field `F`"
+    );
+}
+
+#[doc(hidden)]
+/// Create a slice of `T` starting at `field`, used by [`crate::slice!`].
+///
+/// Fails to compile if `T` is larger than the field `F`.
+pub fn field_slice<'a, V: Pod, F, T: Pod>(
+    data: &'a [V],
+    field: *const F,
+    elt_stride: usize,
+) -> Slice<'a, T> {
+    #[allow(clippy::let_unit_value)]
+    let () = AssertFieldSize::<F, T>::OK;
+    let byte_offset = get_byte_offset(data, field as *const u8);
+    Slice::strided(data, byte_offset, elt_stride)
+}
+
+#[doc(hidden)]
+/// Mutable version of [`field_slice`], used by [`crate::slice_mut!`].
+pub fn field_slice_mut<'a, V: Pod, F, T: Pod>(
+    data: &'a mut [V],
+    field: *const F,
+    elt_stride: usize,
+) -> SliceMut<'a, T> {
+    #[allow(clippy::let_unit_value)]
+    let () = AssertFieldSize::<F, T>::OK;
+    let byte_offset = get_byte_offset(data, field as *const u8);
+    SliceMut::strided(data, byte_offset, elt_stride)
+}
+
 #[doc(hidden)]
 /// Slice builder.
 ///
 /// This is used internally by the [`crate::slice_attr!`] an [`crate::slice_attr_mut!`]
 /// for type inference.
-pub struct SliceBuilder<Attr: Pod> {
+///
+/// Slices are validated using the policy `P`, see [`SliceBuilder::policy`].
+pub struct SliceBuilder<Attr: Pod, P: ValidationPolicy = Strict> {
     start: *const Attr,
     elt_stride: usize,
+    _policy: PhantomData<P>,
 }
 
 impl<Attr: Pod> SliceBuilder<Attr> {
@@ -37,14 +93,35 @@ impl<Attr: Pod> SliceBuilder<Attr> {
         Self {
             start: start as *const Attr,
             elt_stride,
+            _policy: PhantomData,
+        }
+    }
+}
+
+impl<Attr: Pod, P: ValidationPolicy> SliceBuilder<Attr, P> {
+    /// Validate slices using the policy `Q` instead of `P`.
+    pub fn policy<Q: ValidationPolicy>(self) -> SliceBuilder<Attr, Q> {
+        SliceBuilder {
+            start: self.start,
+            elt_stride: self.elt_stride,
+            _policy: PhantomData,
         }
     }
     pub fn build<'a, V: Pod>(&self, data: &'a [V]) -> Slice<'a, Attr> {
-        let byte_offset = get_byte_offset(data, self.start as *const u8);
-        Slice::strided(data, byte_offset, self.elt_stride)
+        self.try_build(data).unwrap()
     }
     pub fn build_mut<'a, V: Pod>(&self, data: &'a mut [V]) -> SliceMut<'a, Attr> {
-        let byte_offset = get_byte_offset(data, self.start as *const u8);
-        SliceMut::strided(data, byte_offset, self.elt_stride)
+        self.try_build_mut(data).unwrap()
+    }
+    pub fn try_build<'a, V: Pod>(&self, data: &'a [V]) -> Result<Slice<'a, Attr>, SliceError> {
+        let byte_offset = try_get_byte_offset(data, self.start as *const u8)?;
+        Slice::with_policy::<P>(data, byte_offset, self.elt_stride)
+    }
+    pub fn try_build_mut<'a, V: Pod>(
+        &self,
+        data: &'a mut [V],
+    ) -> Result<SliceMut<'a, Attr>, SliceError> {
+        let byte_offset = try_get_byte_offset(data, self.start as *const u8)?;
+        SliceMut::with_policy::<P>(data, byte_offset, self.elt_stride)
     }
 }
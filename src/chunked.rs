@@ -0,0 +1,186 @@
+use bytemuck::Pod;
+use std::{
+    io::{self, Read, Seek, SeekFrom},
+    marker::PhantomData,
+};
+
+use crate::{OwnedStrided, SliceError};
+
+/// Read strided elements from a file, or any other non-resident data, chunk by chunk.
+///
+/// The data is read in blocks of about `chunk_bytes` bytes. Each chunk only
+/// contains whole elements: partial elements at the end of a block are carried
+/// over to the next chunk. A chunk contains at least one element, even if a
+/// single stride is larger than `chunk_bytes`.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::ChunkedReader;
+/// use std::io::Cursor;
+///
+/// // Elements are `u32` at byte offset 4, with a stride of 8 bytes.
+/// let data: Vec<u8> = [[0_u32, 10], [1, 11], [2, 12]]
+///     .iter()
+///     .flat_map(|e| bytemuck::bytes_of(e).to_vec())
+///     .collect();
+///
+/// let mut reader = ChunkedReader::<_, u32>::new(Cursor::new(data), 4, 8, 3, 4).unwrap();
+/// let mut values = Vec::new();
+/// reader.for_each_element(|_, v| values.push(*v)).unwrap();
+/// assert_eq!(values, [10, 11, 12]);
+/// ```
+pub struct ChunkedReader<R: Read + Seek, T: Pod> {
+    reader: R,
+    /// Byte offset of the first element in the reader
+    offset: u64,
+    /// Stride, in **bytes**
+    stride: usize,
+    /// Number of elements to read
+    count: usize,
+    /// Size of each block read from the reader, in **bytes**
+    chunk_bytes: usize,
+    /// Index of the next element to read
+    next: usize,
+    /// Bytes read but not yet consumed, starting at the next element
+    pending: Vec<u8>,
+    started: bool,
+    _phantom: PhantomData<T>,
+}
+
+impl<R: Read + Seek, T: Pod> ChunkedReader<R, T> {
+    /// Create a reader for `count` elements, starting at byte `offset` of `reader`,
+    /// and spaced by `stride` bytes.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `chunk_bytes` is `0`.
+    pub fn new(
+        reader: R,
+        offset: u64,
+        stride: usize,
+        count: usize,
+        chunk_bytes: usize,
+    ) -> Result<Self, SliceError> {
+        assert!(chunk_bytes != 0, "`chunk_bytes` must be greater than 0");
+        let attr = std::mem::size_of::<T>();
+        if attr > stride {
+            return Err(SliceError::AttributeLargerThanStride {
+                type_name: std::any::type_name::<T>(),
+                attr,
+                stride,
+            });
+        }
+        Ok(Self {
+            reader,
+            offset,
+            stride,
+            count,
+            chunk_bytes,
+            next: 0,
+            pending: Vec::new(),
+            started: false,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Read the next chunk of elements.
+    ///
+    /// Returns `None` once every element is read.
+    ///
+    /// ## Errors
+    ///
+    /// Returns the errors of the reader, and an error of kind
+    /// [`io::ErrorKind::InvalidData`] if the remaining elements span more than
+    /// `usize::MAX` bytes, or if zero-sized elements have a zero stride.
+    pub fn next_chunk(&mut self) -> io::Result<Option<OwnedStrided<T>>> {
+        if self.next >= self.count {
+            return Ok(None);
+        }
+        if !self.started {
+            self.reader.seek(SeekFrom::Start(self.offset))?;
+            self.started = true;
+        }
+
+        let attr = std::mem::size_of::<T>();
+        let remaining = self.count - self.next;
+        // Never read past the last byte of the last element.
+        let total = (remaining - 1)
+            .checked_mul(self.stride)
+            .and_then(|bytes| bytes.checked_add(attr))
+            .ok_or(SliceError::Overflow)?;
+        let mut available = self.available(remaining)?;
+        // Read at least one block, and until at least one element is complete.
+        let mut read = false;
+        while (!read || available == 0) && self.pending.len() < total {
+            let size = self.chunk_bytes.min(total - self.pending.len()) as u64;
+            let mut block = self.reader.by_ref().take(size);
+            if block.read_to_end(&mut self.pending)? == 0 {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+            available = self.available(remaining)?;
+            read = true;
+        }
+
+        let elements = (0..available)
+            .map(|i| bytemuck::pod_read_unaligned(&self.pending[i * self.stride..][..attr]))
+            .collect();
+
+        // The last element might not be followed by a full stride yet.
+        let consumed = available * self.stride;
+        if consumed > self.pending.len() {
+            let skip = consumed - self.pending.len();
+            self.pending.clear();
+            if self.next + available < self.count {
+                let skip = i64::try_from(skip).map_err(|_| SliceError::Overflow)?;
+                self.reader.seek(SeekFrom::Current(skip))?;
+            }
+        } else {
+            self.pending.drain(..consumed);
+        }
+        self.next += available;
+
+        Ok(Some(OwnedStrided::new(elements)))
+    }
+
+    /// Call `f` with the index and value of every element, reading chunk by chunk.
+    pub fn for_each_element(&mut self, mut f: impl FnMut(usize, &T)) -> io::Result<()> {
+        let mut index = 0;
+        while let Some(chunk) = self.next_chunk()? {
+            for elt in chunk.as_elements() {
+                f(index, elt);
+                index += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Number of whole elements in the pending bytes, limited to `remaining`.
+    ///
+    /// The stride is only `0` if the attribute is zero-sized, in which case the
+    /// elements can't be counted.
+    fn available(&self, remaining: usize) -> io::Result<usize> {
+        let attr = std::mem::size_of::<T>();
+        let count = match self.pending.len() {
+            len if len < attr => 0,
+            len => match (len - attr).checked_div(self.stride) {
+                Some(count) => count + 1,
+                None => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "zero-sized elements with a zero stride",
+                    ))
+                }
+            },
+        };
+        Ok(count.min(remaining))
+    }
+}
+
+impl<R: Read + Seek, T: Pod> Iterator for ChunkedReader<R, T> {
+    type Item = io::Result<OwnedStrided<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_chunk().transpose()
+    }
+}
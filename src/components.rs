@@ -0,0 +1,81 @@
+use bytemuck::Pod;
+use std::fmt::Debug;
+use std::iter::Flatten;
+
+use crate::{Slice, SliceIterator};
+
+/// Immutable view over the components of an array attribute, across all elements.
+///
+/// For each element, the `N` components are visited in order, before jumping
+/// to the next element by the slice stride. The data isn't densified.
+///
+/// Created with [`Slice::components`], or the [`crate::slice_components!`] macro.
+#[derive(Clone, Copy)]
+pub struct ComponentSlice<'a, T: Pod, const N: usize> {
+    slice: Slice<'a, [T; N]>,
+}
+
+impl<'a, T: Pod, const N: usize> Slice<'a, [T; N]> {
+    /// Convert into a view over the components of each element.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[0.0_f32, 1.0, 2.0, 9.0], [3.0, 4.0, 5.0, 9.0]];
+    /// let positions: Slice<[f32; 3]> = Slice::new(&data, 0);
+    /// let components = positions.components();
+    /// assert_eq!(components.len(), 6);
+    /// assert!(components.iter().copied().eq([0.0, 1.0, 2.0, 3.0, 4.0, 5.0]));
+    /// ```
+    pub fn components(self) -> ComponentSlice<'a, T, N> {
+        ComponentSlice { slice: self }
+    }
+}
+
+impl<'a, T: Pod, const N: usize> ComponentSlice<'a, T, N> {
+    /// Number of components, i.e., `N` times the number of elements.
+    pub fn len(&self) -> usize {
+        self.slice.len() * N
+    }
+
+    /// `true` if there is no component, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the component at index `index`, i.e., the component `index % N`
+    /// of the element `index / N`.
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        match N {
+            0 => None,
+            _ => self
+                .slice
+                .get_ref(index / N)
+                .map(|element| &element[index % N]),
+        }
+    }
+
+    /// Create an iterator over the components.
+    pub fn iter(&self) -> Flatten<SliceIterator<'_, [T; N]>> {
+        self.slice.elements().flatten()
+    }
+
+    /// Convert back into a view over whole arrays.
+    pub fn into_slice(self) -> Slice<'a, [T; N]> {
+        self.slice
+    }
+}
+
+impl<'a, T: Pod, const N: usize> From<Slice<'a, [T; N]>> for ComponentSlice<'a, T, N> {
+    fn from(slice: Slice<'a, [T; N]>) -> Self {
+        slice.components()
+    }
+}
+
+impl<'a, T: Pod + Debug, const N: usize> Debug for ComponentSlice<'a, T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,99 @@
+//! Conversions between element types, e.g., to pack attributes before an upload.
+//!
+//! Bulk conversions between two slices are in [`kernels`]. Each kernel is a
+//! monomorphic function, whose inner loop vectorizes when both slices are packed.
+//!
+//! Half precision floats are stored as their bits in a `u16`, and converted with
+//! [`f32_to_f16`] and [`f16_to_f32`].
+//!
+//! Index buffers are narrowed with [`narrow_indices`], which reports the first
+//! index that doesn't fit.
+//!
+//! Whole vectors are converted to and from their bytes with [`into_bytes`] and
+//! [`from_bytes`], without copying when the allocation can be reused.
+
+mod indices;
+pub mod kernels;
+mod vec;
+
+pub use indices::{max_index, narrow_indices, narrow_indices_u16, NarrowError};
+pub use vec::{from_bytes, into_bytes, Conversion, ConversionError};
+
+/// Bits of `f32` 1.0 shifted by 113 exponents down, i.e., 2^-14, the smallest
+/// normal half float.
+const SMALLEST_NORMAL: u32 = 113 << 23;
+
+/// Convert `value` to the bits of the nearest half float, rounding ties to even.
+///
+/// Values too large for a half float become infinities, and values too small
+/// become subnormals or zeros. NaN stays NaN, with its sign, the quiet bit set,
+/// and the top bits of its payload.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::convert::f32_to_f16;
+///
+/// assert_eq!(f32_to_f16(1.0), 0x3c00);
+/// assert_eq!(f32_to_f16(-65504.0), 0xfbff);
+/// assert_eq!(f32_to_f16(1e6), 0x7c00);
+/// ```
+#[inline(always)]
+pub fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = (bits >> 16) as u16 & 0x8000;
+    let abs = bits & 0x7fff_ffff;
+    let half = if abs > 0x7f80_0000 {
+        // NaN, quiet with the top of its payload.
+        0x7e00 | (abs >> 13) as u16 & 0x3ff
+    } else if abs >= 0x4780_0000 {
+        // At least 2^16, infinity once rounded.
+        0x7c00
+    } else if abs < SMALLEST_NORMAL {
+        // Subnormal or zero: adding the magic value aligns the 10 bits of the
+        // mantissa at the bottom, rounding them to nearest even.
+        const MAGIC: u32 = (127 - 15 + 23 - 10 + 1) << 23;
+        let rounded = f32::from_bits(abs) + f32::from_bits(MAGIC);
+        (rounded.to_bits() - MAGIC) as u16
+    } else {
+        // Normal: rebias the exponent, and round the 13 dropped bits to nearest even.
+        // Overflows to infinity through the exponent bits when rounding up.
+        let odd = (abs >> 13) & 1;
+        let rebiased = abs - ((127 - 15) << 23);
+        ((rebiased + 0xfff + odd) >> 13) as u16
+    };
+    sign | half
+}
+
+/// Convert the bits of a half float to an `f32`.
+///
+/// The conversion is exact, subnormals included. NaN payloads are kept.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::convert::f16_to_f32;
+///
+/// assert_eq!(f16_to_f32(0x3c00), 1.0);
+/// assert_eq!(f16_to_f32(0x0001), 2.0_f32.powi(-24));
+/// assert_eq!(f16_to_f32(0xfc00), f32::NEG_INFINITY);
+/// ```
+#[inline(always)]
+pub fn f16_to_f32(bits: u16) -> f32 {
+    const EXPONENT: u32 = 0x7c00 << 13;
+    let sign = (bits as u32 & 0x8000) << 16;
+    let shifted = (bits as u32 & 0x7fff) << 13;
+    let exponent = shifted & EXPONENT;
+    let rebiased = shifted + ((127 - 15) << 23);
+    let abs = if exponent == EXPONENT {
+        // Infinity or NaN: move to the largest exponent.
+        rebiased + ((128 - 16) << 23)
+    } else if exponent == 0 {
+        // Subnormal or zero: renormalize with an exact subtraction.
+        let value = f32::from_bits(rebiased + (1 << 23)) - f32::from_bits(SMALLEST_NORMAL);
+        value.to_bits()
+    } else {
+        rebiased
+    };
+    f32::from_bits(sign | abs)
+}
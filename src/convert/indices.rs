@@ -0,0 +1,119 @@
+use crate::gather::VALIDATION_CHUNK;
+use crate::{IndexElement, Slice, SliceMut};
+
+use super::kernels::map_into;
+
+/// Index that doesn't fit in the destination type of [`narrow_indices`], or
+/// [`narrow_indices_u16`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NarrowError {
+    /// Position of the first index that doesn't fit
+    pub index: usize,
+    /// Value of this index
+    pub value: u64,
+}
+
+/// Largest index of `indices`, or `None` if `indices` is empty.
+///
+/// Used to pick the narrowest index type before allocating, e.g., before
+/// [`narrow_indices`].
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::convert::max_index;
+/// use pas::Slice;
+///
+/// let indices = [0_u64, 70_000, 2];
+/// assert_eq!(max_index(&Slice::native(&indices)), Some(70_000));
+/// assert!(max_index(&Slice::native(&indices)).unwrap() > u16::MAX as u64);
+/// ```
+pub fn max_index<I: IndexElement>(indices: &Slice<I>) -> Option<u64> {
+    if indices.is_empty() {
+        return None;
+    }
+    let mut max = 0;
+    indices.for_each_fast(|index| max = max.max(index.to_u64()));
+    Some(max)
+}
+
+/// Convert `src` into `dst`, checking that each index fits in `D`.
+///
+/// Indices are processed in chunks: the maximum of a chunk is checked first,
+/// and the chunk is then converted without any other branch.
+#[inline(always)]
+fn narrow<S: IndexElement, D: IndexElement>(
+    src: &Slice<S>,
+    dst: &mut SliceMut<D>,
+    limit: u64,
+    cast: impl Fn(S) -> D + Copy,
+) -> Result<(), NarrowError> {
+    let len = src.len();
+    assert_eq!(
+        dst.len(),
+        len,
+        "destination and source have different lengths"
+    );
+    let mut start = 0;
+    while start < len {
+        let count = VALIDATION_CHUNK.min(len - start);
+        let (chunk, _) = src.split_at(start).1.split_at(count);
+        let (_, mut rest) = dst.split_at_mut(start);
+        let (mut out, _) = rest.split_at_mut(count);
+        if max_index(&chunk).unwrap_or(0) > limit {
+            let position = chunk.iter().position(|i| i.to_u64() > limit).unwrap();
+            let (valid, _) = chunk.split_at(position);
+            map_into(&valid, &mut out.split_at_mut(position).0, cast);
+            return Err(NarrowError {
+                index: start + position,
+                value: chunk[position].to_u64(),
+            });
+        }
+        map_into(&chunk, &mut out, cast);
+        start += count;
+    }
+    Ok(())
+}
+
+/// Convert `u64` indices to `u32` indices, failing on the first index that
+/// doesn't fit.
+///
+/// Validation and conversion happen in a single pass, over chunks of indices.
+/// The result is deterministic: on error, the indices before [`NarrowError::index`]
+/// are converted, and the following elements of `dst` are left untouched.
+///
+/// Use [`max_index`] to know whether the indices fit before allocating `dst`.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::convert::{narrow_indices, NarrowError};
+/// use pas::{Slice, SliceMut};
+///
+/// let mut dst = [0_u32; 3];
+/// narrow_indices(&Slice::native(&[0, 1, 2]), &mut SliceMut::native(&mut dst)).unwrap();
+/// assert_eq!(dst, [0, 1, 2]);
+///
+/// let src = [3, 1 << 32, 5];
+/// assert_eq!(
+///     narrow_indices(&Slice::native(&src), &mut SliceMut::native(&mut dst)),
+///     Err(NarrowError { index: 1, value: 1 << 32 })
+/// );
+/// assert_eq!(dst, [3, 1, 2]);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `dst` doesn't have the length of `src`.
+pub fn narrow_indices(src: &Slice<u64>, dst: &mut SliceMut<u32>) -> Result<(), NarrowError> {
+    narrow(src, dst, u32::MAX as u64, |index| index as u32)
+}
+
+/// Convert `u32` indices to `u16` indices, see [`narrow_indices`].
+///
+/// ## Panics
+///
+/// Panics if `dst` doesn't have the length of `src`.
+pub fn narrow_indices_u16(src: &Slice<u32>, dst: &mut SliceMut<u16>) -> Result<(), NarrowError> {
+    narrow(src, dst, u16::MAX as u64, |index| index as u16)
+}
@@ -0,0 +1,112 @@
+//! Bulk conversions between two slices of different element types.
+//!
+//! Each kernel checks the lengths once, and then converts every element of `src`
+//! into `dst`. When both slices are packed, the inner loop runs over std slices
+//! and vectorizes. Strided slices are converted element by element.
+//!
+//! ## Errors
+//!
+//! Every kernel returns [`SliceError::LengthMismatch`] if `dst` doesn't have the
+//! length of `src`. Nothing is written in this case.
+
+use bytemuck::Pod;
+
+use super::{f16_to_f32, f32_to_f16};
+use crate::{Slice, SliceError, SliceMut};
+
+/// Convert each element of `src` with `f` into `dst`.
+#[inline(always)]
+fn convert<S: Pod, D: Pod>(
+    src: &Slice<S>,
+    dst: &mut SliceMut<D>,
+    f: impl Fn(S) -> D,
+) -> Result<(), SliceError> {
+    if dst.len() != src.len() {
+        return Err(SliceError::LengthMismatch {
+            expected: src.len(),
+            len: dst.len(),
+        });
+    }
+    map_into(src, dst, f);
+    Ok(())
+}
+
+/// Convert each element of `src` with `f` into `dst`, which must have the length of `src`.
+#[inline(always)]
+pub(super) fn map_into<S: Pod, D: Pod>(src: &Slice<S>, dst: &mut SliceMut<D>, f: impl Fn(S) -> D) {
+    let len = src.len();
+    debug_assert_eq!(dst.len(), len);
+    if let (Some(src), Some(dst)) = (src.contiguous(), dst.contiguous()) {
+        // Safe because both slices hold `len` packed, aligned, and in bounds elements,
+        // and `dst` is mutably borrowed, so `src` can't overlap it.
+        let (src, dst) = unsafe {
+            (
+                std::slice::from_raw_parts(src.cast_const(), len),
+                std::slice::from_raw_parts_mut(dst, len),
+            )
+        };
+        for (dst, src) in dst.iter_mut().zip(src) {
+            *dst = f(*src);
+        }
+        return;
+    }
+    dst.write_elements(src.iter().map(|value| f(*value)));
+}
+
+/// Convert floats in `[0, 1]` to 8-bit normalized integers, rounding to nearest.
+///
+/// Values are clamped to `[0, 1]`, and NaN becomes `0`.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::convert::kernels::f32_to_unorm8;
+/// use pas::{Slice, SliceMut};
+///
+/// let src = [0.0, 0.5, 1.0, 2.0];
+/// let mut dst = [[0_u8; 4]; 4];
+/// f32_to_unorm8(&Slice::native(&src), &mut SliceMut::new(&mut dst, 3)).unwrap();
+/// assert_eq!(dst.map(|rgba| rgba[3]), [0, 128, 255, 255]);
+/// ```
+pub fn f32_to_unorm8(src: &Slice<f32>, dst: &mut SliceMut<u8>) -> Result<(), SliceError> {
+    // Saturating casts clamp, and map NaN to `0`.
+    convert(src, dst, |value| (value * 255.0 + 0.5) as u8)
+}
+
+/// Convert 8-bit normalized integers to floats in `[0, 1]`.
+///
+/// The conversion is exact for [`f32_to_unorm8`]: converting back gives the same integers.
+pub fn unorm8_to_f32(src: &Slice<u8>, dst: &mut SliceMut<f32>) -> Result<(), SliceError> {
+    convert(src, dst, |value| value as f32 / 255.0)
+}
+
+/// Expand 3D vectors to 4D vectors, with `w` as fourth component.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::convert::kernels::f32x3_to_f32x4;
+/// use pas::{Slice, SliceMut};
+///
+/// let positions = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+/// let mut dst = [[0.0; 4]; 2];
+/// f32x3_to_f32x4(&Slice::native(&positions), &mut SliceMut::native(&mut dst), 1.0).unwrap();
+/// assert_eq!(dst, [[1.0, 2.0, 3.0, 1.0], [4.0, 5.0, 6.0, 1.0]]);
+/// ```
+pub fn f32x3_to_f32x4(
+    src: &Slice<[f32; 3]>,
+    dst: &mut SliceMut<[f32; 4]>,
+    w: f32,
+) -> Result<(), SliceError> {
+    convert(src, dst, |[x, y, z]| [x, y, z, w])
+}
+
+/// Convert floats to the bits of half floats, see [`super::f32_to_f16`].
+pub fn f32_to_f16_bits(src: &Slice<f32>, dst: &mut SliceMut<u16>) -> Result<(), SliceError> {
+    convert(src, dst, f32_to_f16)
+}
+
+/// Convert the bits of half floats to floats, see [`super::f16_to_f32`].
+pub fn f16_bits_to_f32(src: &Slice<u16>, dst: &mut SliceMut<f32>) -> Result<(), SliceError> {
+    convert(src, dst, f16_to_f32)
+}
@@ -0,0 +1,117 @@
+use bytemuck::Pod;
+
+/// How a buffer was converted by [`into_bytes`] or [`from_bytes`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Conversion {
+    /// The allocation was reused, without copying any byte
+    InPlace,
+    /// The elements were copied into a new allocation
+    Copied,
+}
+
+/// Byte buffer that can't be converted into elements by [`from_bytes`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ConversionError {
+    /// The number of bytes isn't a multiple of the size of an element
+    LengthNotMultiple {
+        /// Number of bytes
+        len: usize,
+        /// Size of an element, in **bytes**
+        size: usize,
+    },
+    /// Elements have no size, so their count can't be derived from the bytes
+    ZeroSizedElement,
+}
+
+impl std::fmt::Debug for ConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LengthNotMultiple { len, size } => write!(
+                f,
+                "Byte length {} isn't a multiple of the element size {}",
+                len, size
+            ),
+            Self::ZeroSizedElement => write!(f, "Elements have no size"),
+        }
+    }
+}
+
+/// Convert a vector of elements into its bytes, reusing the allocation when possible.
+///
+/// The allocation must be freed with the alignment it was allocated with. A `Vec<u8>`
+/// frees with an alignment of `1`, so the allocation is only reused if `V` is aligned
+/// to `1` byte, e.g., `[u8; 4]`. Otherwise, the bytes are copied into a new vector,
+/// and the returned [`Conversion`] is [`Conversion::Copied`].
+///
+/// The length of the result is `vec.len() * size_of::<V>()`. When reused, the capacity
+/// is the capacity of `vec` in bytes.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::convert::{into_bytes, Conversion};
+///
+/// let colors = vec![[255_u8, 0, 0, 255], [0, 255, 0, 255]];
+/// let (bytes, conversion) = into_bytes(colors);
+/// assert_eq!(bytes, [255, 0, 0, 255, 0, 255, 0, 255]);
+/// assert_eq!(conversion, Conversion::InPlace);
+///
+/// let (bytes, conversion) = into_bytes(vec![1_u32]);
+/// assert_eq!(bytes, 1_u32.to_ne_bytes());
+/// assert_eq!(conversion, Conversion::Copied);
+/// ```
+pub fn into_bytes<V: Pod>(vec: Vec<V>) -> (Vec<u8>, Conversion) {
+    match bytemuck::allocation::try_cast_vec(vec) {
+        Ok(bytes) => (bytes, Conversion::InPlace),
+        Err((_, vec)) => (bytemuck::cast_slice(&vec).to_vec(), Conversion::Copied),
+    }
+}
+
+/// Convert bytes into a vector of elements, reusing the allocation when possible.
+///
+/// The allocation is reused if `V` is aligned to `1` byte, as for [`into_bytes`],
+/// and if the capacity of `bytes` is a multiple of the size of `V`. Otherwise, the
+/// bytes are copied into a new, aligned, vector: the allocation of a `Vec<u8>` has
+/// no alignment guarantee, and can't be freed as elements of a larger alignment.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::convert::{from_bytes, into_bytes, ConversionError};
+///
+/// let positions = vec![[1.0_f32, 2.0, 3.0], [4.0, 5.0, 6.0]];
+/// let (bytes, _) = into_bytes(positions.clone());
+/// let (back, _) = from_bytes::<[f32; 3]>(bytes).unwrap();
+/// assert_eq!(back, positions);
+///
+/// assert_eq!(
+///     from_bytes::<[f32; 3]>(vec![0; 13]),
+///     Err(ConversionError::LengthNotMultiple { len: 13, size: 12 })
+/// );
+/// ```
+///
+/// ## Errors
+///
+/// Returns [`ConversionError::LengthNotMultiple`] if the length of `bytes` isn't a
+/// multiple of the size of `V`, and [`ConversionError::ZeroSizedElement`] if `V`
+/// has no size.
+pub fn from_bytes<V: Pod>(bytes: Vec<u8>) -> Result<(Vec<V>, Conversion), ConversionError> {
+    let size = std::mem::size_of::<V>();
+    if size == 0 {
+        return Err(ConversionError::ZeroSizedElement);
+    }
+    if bytes.len() % size != 0 {
+        return Err(ConversionError::LengthNotMultiple {
+            len: bytes.len(),
+            size,
+        });
+    }
+    Ok(match bytemuck::allocation::try_cast_vec(bytes) {
+        Ok(vec) => (vec, Conversion::InPlace),
+        Err((_, bytes)) => {
+            let mut vec = vec![V::zeroed(); bytes.len() / size];
+            bytemuck::cast_slice_mut::<V, u8>(&mut vec).copy_from_slice(&bytes);
+            (vec, Conversion::Copied)
+        }
+    })
+}
@@ -0,0 +1,328 @@
+use bytemuck::Pod;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use crate::parallel::dispatch;
+use crate::{Slice, SliceError, SliceMut};
+
+/// Copy of `count` elements of `size` bytes, with its types erased.
+#[derive(Clone, Copy)]
+struct CopyJob {
+    /// Index of the copy, in insertion order
+    index: usize,
+    dst: *mut u8,
+    dst_stride: usize,
+    /// First byte of the buffer `dst` was created from
+    dst_parent: *const u8,
+    src: *const u8,
+    src_stride: usize,
+    /// First byte of the buffer `src` was created from
+    src_parent: *const u8,
+    size: usize,
+    count: usize,
+    /// Number of source elements, checked against `count` on validation
+    src_count: usize,
+}
+
+// Safe because validated jobs never write to the same byte, and only read
+// shared data.
+unsafe impl Send for CopyJob {}
+
+impl CopyJob {
+    /// Bytes of the destination element at index `index`.
+    fn dst_range(&self, index: usize) -> Range<usize> {
+        let start = self.dst as usize + index * self.dst_stride;
+        start..start + self.size
+    }
+
+    /// Bytes from the first to the last destination element, or `None` if
+    /// nothing is copied.
+    fn dst_span(&self) -> Option<Range<usize>> {
+        match self.count == 0 || self.size == 0 {
+            true => None,
+            false => Some(self.dst as usize..self.dst_range(self.count - 1).end),
+        }
+    }
+
+    /// `true` if `self` and `other` write to the same byte, `false` otherwise
+    fn overlaps(&self, other: &CopyJob) -> bool {
+        // Elements of a job are sorted and disjoint, since they are never
+        // larger than the stride.
+        let (mut i, mut j) = (0, 0);
+        while i < self.count && j < other.count {
+            let (a, b) = (self.dst_range(i), other.dst_range(j));
+            if a.end <= b.start {
+                i += 1;
+            } else if b.end <= a.start {
+                j += 1;
+            } else {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// `true` if `next` continues `self`, in both buffers, and both can be
+    /// copied as a single job.
+    fn continued_by(&self, next: &CopyJob) -> bool {
+        self.size == next.size
+            && self.dst_stride == next.dst_stride
+            && self.src_stride == next.src_stride
+            // Buffers can be adjacent in memory, but a job never crosses buffers.
+            && self.dst_parent == next.dst_parent
+            && self.src_parent == next.src_parent
+            && self.dst as usize + self.count * self.dst_stride == next.dst as usize
+            && self.src as usize + self.count * self.src_stride == next.src as usize
+    }
+
+    /// Copy the elements.
+    ///
+    /// ## Safety
+    ///
+    /// The job must be validated, and no other job may write to its destination
+    /// at the same time.
+    unsafe fn run(&self) {
+        if self.dst_stride == self.size && self.src_stride == self.size {
+            std::ptr::copy_nonoverlapping(self.src, self.dst, self.size * self.count);
+            return;
+        }
+        // Common attribute sizes are copied with a fixed size, instead of
+        // calling `memcpy` for each element.
+        match self.size {
+            4 => self.run_strided::<4>(),
+            8 => self.run_strided::<8>(),
+            12 => self.run_strided::<12>(),
+            16 => self.run_strided::<16>(),
+            32 => self.run_strided::<32>(),
+            64 => self.run_strided::<64>(),
+            _ => {
+                for i in 0..self.count {
+                    std::ptr::copy_nonoverlapping(
+                        self.src.add(i * self.src_stride),
+                        self.dst.add(i * self.dst_stride),
+                        self.size,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Copy the elements, of `N` bytes, one by one.
+    ///
+    /// ## Safety
+    ///
+    /// See [`Self::run`].
+    #[inline(always)]
+    unsafe fn run_strided<const N: usize>(&self) {
+        let (mut src, mut dst) = (self.src, self.dst);
+        for _ in 0..self.count {
+            // Byte arrays are never misaligned.
+            dst.cast::<[u8; N]>().write(src.cast::<[u8; N]>().read());
+            // Wrapping, since the pointer past the last element can be out of bounds.
+            src = src.wrapping_add(self.src_stride);
+            dst = dst.wrapping_add(self.dst_stride);
+        }
+    }
+}
+
+/// Statistics of a [`CopyBatch`] execution.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CopyStats {
+    /// Number of copies added to the batch
+    pub copies: usize,
+    /// Number of copies merged into the previous one, in destination order
+    pub merged: usize,
+    /// Number of jobs executed, once merged, without the empty copies
+    pub jobs: usize,
+    /// Number of bytes copied
+    pub bytes: usize,
+}
+
+/// Batch of copies between slices, executed at once.
+///
+/// Copies are recorded with [`Self::add`], without any check. On execution,
+/// every copy is validated first, such that nothing is written if a copy is
+/// invalid. Copies are then sorted by destination address, and copies continuing
+/// each other in both buffers, with the same element size and strides, are merged
+/// into a single job, e.g., consecutive ranges of a packed buffer.
+///
+/// Copies are only merged if their destinations, and their sources, were split
+/// from the same slice, e.g., with [`SliceMut::split_at_mut`]. Slices created
+/// from distinct borrows can be adjacent in memory, but a pointer derived from
+/// one of them isn't allowed to access the other.
+///
+/// The order in which copies are added doesn't change the result, since
+/// destinations never overlap.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{CopyBatch, Slice, SliceMut};
+///
+/// let staging = [1_u32, 2, 3, 4];
+/// let mut persistent = [0_u32; 4];
+/// let mut dst = SliceMut::native(&mut persistent);
+/// let (low, high) = dst.split_at_mut(2);
+/// let (src_low, src_high) = Slice::native(&staging).split_at(2);
+///
+/// let mut batch = CopyBatch::new();
+/// batch.add(high, src_high);
+/// batch.add(low, src_low);
+/// let stats = batch.execute().unwrap();
+///
+/// assert_eq!(stats.merged, 1);
+/// assert_eq!(stats.bytes, 16);
+/// assert_eq!(persistent, staging);
+/// ```
+#[derive(Default)]
+pub struct CopyBatch<'a> {
+    jobs: Vec<CopyJob>,
+    _phantom: PhantomData<&'a mut [u8]>,
+}
+
+impl<'a> CopyBatch<'a> {
+    /// Create an empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the copy of `src` into `dst`, and return its index.
+    ///
+    /// Both slices stay borrowed by the batch. The copy is only validated,
+    /// and performed, on execution.
+    pub fn add<T: Pod>(&mut self, dst: SliceMut<'a, T>, src: Slice<'a, T>) -> usize {
+        let (dst, src_base) = (dst.base(), src.base());
+        self.jobs.push(CopyJob {
+            index: self.jobs.len(),
+            dst: dst.start as *mut u8,
+            dst_stride: dst.stride(),
+            dst_parent: dst.parent,
+            src: src_base.start,
+            src_stride: src_base.stride(),
+            src_parent: src_base.parent,
+            size: std::mem::size_of::<T>(),
+            count: dst.len(),
+            src_count: src.len(),
+        });
+        self.jobs.len() - 1
+    }
+
+    /// Number of recorded copies.
+    pub fn len(&self) -> usize {
+        self.jobs.len()
+    }
+
+    /// `true` if no copy is recorded, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+
+    /// Validate, merge, and perform the copies, on the calling thread.
+    ///
+    /// Once executed, the batch is empty, and can record new copies.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error naming the first invalid copy, in insertion order:
+    /// - [`SliceError::LengthMismatch`] if a source doesn't have the length of its destination
+    /// - [`SliceError::OverlappingCopies`] if two destinations share a byte
+    ///
+    /// Nothing is copied in this case, and the copies stay recorded.
+    pub fn execute(&mut self) -> Result<CopyStats, SliceError> {
+        let stats = self.prepare()?;
+        for job in &self.jobs {
+            // Safe because jobs are validated, and run one after the other.
+            unsafe { job.run() };
+        }
+        self.jobs.clear();
+        Ok(stats)
+    }
+
+    /// Similar to [`Self::execute`], but jobs are dispatched over up to `threads`
+    /// scoped threads.
+    ///
+    /// Jobs are split into runs of consecutive destinations, of about the same
+    /// number of bytes, one per thread. A job is never split between threads.
+    /// No thread is spawned if `threads <= 1`, or if there is a single job.
+    ///
+    /// ## Errors
+    ///
+    /// See [`Self::execute`].
+    pub fn execute_parallel(&mut self, threads: usize) -> Result<CopyStats, SliceError> {
+        let stats = self.prepare()?;
+        let threads = threads.max(1);
+        let share = crate::math::div_ceil(stats.bytes, threads).max(1);
+        let mut runs: Vec<Vec<CopyJob>> = vec![Vec::new()];
+        let mut bytes = 0;
+        for job in self.jobs.drain(..) {
+            if bytes >= share * runs.len() {
+                runs.push(Vec::new());
+            }
+            bytes += job.size * job.count;
+            runs.last_mut().unwrap().push(job);
+        }
+        // Safe because destinations of validated jobs never overlap.
+        dispatch(runs, threads, &|_, run: Vec<CopyJob>| {
+            run.iter().for_each(|job| unsafe { job.run() })
+        });
+        Ok(stats)
+    }
+
+    /// Validate the copies, and replace them with the jobs to run, in
+    /// destination order.
+    ///
+    /// Jobs are sorted in place, but only replaced once validated.
+    fn prepare(&mut self) -> Result<CopyStats, SliceError> {
+        // Jobs might be sorted by a previous validation.
+        if let Some(job) = self
+            .jobs
+            .iter()
+            .filter(|job| job.count != job.src_count)
+            .min_by_key(|job| job.index)
+        {
+            return Err(SliceError::LengthMismatch {
+                expected: job.count,
+                len: job.src_count,
+            });
+        }
+
+        self.jobs
+            .sort_unstable_by_key(|job| (job.dst as usize, job.index));
+        let mut overlap: Option<(usize, usize)> = None;
+        for (i, job) in self.jobs.iter().enumerate() {
+            let end = match job.dst_span() {
+                Some(span) => span.end,
+                None => continue,
+            };
+            for other in self.jobs[i + 1..]
+                .iter()
+                .take_while(|o| (o.dst as usize) < end)
+            {
+                if job.overlaps(other) {
+                    let pair = (job.index.min(other.index), job.index.max(other.index));
+                    overlap = Some(overlap.map_or(pair, |first| first.min(pair)));
+                }
+            }
+        }
+        if let Some((first, second)) = overlap {
+            return Err(SliceError::OverlappingCopies { first, second });
+        }
+
+        let copies = self.jobs.len();
+        self.jobs.retain(|job| job.dst_span().is_some());
+        let copied = self.jobs.len();
+        self.jobs.dedup_by(|next, last| {
+            let merge = last.continued_by(next);
+            if merge {
+                last.count += next.count;
+            }
+            merge
+        });
+        Ok(CopyStats {
+            copies,
+            merged: copied - self.jobs.len(),
+            jobs: self.jobs.len(),
+            bytes: self.jobs.iter().map(|job| job.size * job.count).sum(),
+        })
+    }
+}
@@ -0,0 +1,128 @@
+use bytemuck::Pod;
+
+use crate::{OwnedStrided, SliceError, SliceInfo, SliceMut};
+
+/// Size of the packed chunks compared at once by [`SliceInfo::capture_delta`], in **bytes**.
+const DELTA_CHUNK: usize = 256;
+
+/// Elements of a baseline that differ from a view, used to restore them.
+///
+/// Created with [`SliceInfo::capture_delta`], and applied with [`SliceMut::apply_delta`].
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{OwnedStrided, SliceMut};
+///
+/// let mut data = [[0_u32, 1], [0, 2], [0, 3]];
+/// let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 4);
+/// let baseline = OwnedStrided::from_slice(&slice);
+///
+/// slice[1] = 20;
+/// let undo = slice.capture_delta(&baseline);
+/// assert_eq!(undo.len(), 1);
+///
+/// slice.apply_delta(&undo).unwrap();
+/// assert_eq!(data, [[0, 1], [0, 2], [0, 3]]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Delta<T> {
+    indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T: Pod> Delta<T> {
+    /// Number of elements to restore.
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// `true` if there is no element to restore, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Number of bytes used by the indices and the elements.
+    pub fn byte_size(&self) -> usize {
+        self.len() * (std::mem::size_of::<usize>() + std::mem::size_of::<T>())
+    }
+
+    /// Iterate over the index and the baseline value of each element to restore,
+    /// in increasing index order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (usize, &T)> {
+        self.indices.iter().copied().zip(&self.values)
+    }
+}
+
+impl<Attr: Pod> SliceInfo<Attr> {
+    /// Record the elements of `baseline` that aren't bit-identical to the elements
+    /// of this view, e.g., to undo an edit with [`SliceMut::apply_delta`].
+    ///
+    /// Only the common prefix of both is compared. Packed views are compared by
+    /// chunks of bytes, strided ones element by element.
+    pub fn capture_delta(&self, baseline: &OwnedStrided<Attr>) -> Delta<Attr> {
+        let baseline = baseline.as_elements();
+        let (len, size) = (self.len().min(baseline.len()), std::mem::size_of::<Attr>());
+        let mut delta = Delta {
+            indices: vec![],
+            values: vec![],
+        };
+        let mut record = |index: usize, value: &Attr, old: &Attr| {
+            if bytemuck::bytes_of(value) != bytemuck::bytes_of(old) {
+                delta.indices.push(index);
+                delta.values.push(*old);
+            }
+        };
+        if size == 0 || self.stride() != size {
+            self.elements()
+                .zip(baseline)
+                .enumerate()
+                .for_each(|(index, (value, old))| record(index, value, old));
+            return delta;
+        }
+        // Safe because the `len` elements are packed, aligned, and in bounds.
+        let elements = unsafe { std::slice::from_raw_parts(self.base().start.cast(), len) };
+        let chunk = (DELTA_CHUNK / size).max(1);
+        for (start, (values, olds)) in elements
+            .chunks(chunk)
+            .zip(baseline.chunks(chunk))
+            .enumerate()
+        {
+            if bytemuck::cast_slice::<Attr, u8>(values) != bytemuck::cast_slice::<Attr, u8>(olds) {
+                for (offset, (value, old)) in values.iter().zip(olds).enumerate() {
+                    record(start * chunk + offset, value, old);
+                }
+            }
+        }
+        delta
+    }
+}
+
+impl<'a, Attr: Pod> SliceMut<'a, Attr> {
+    /// Write back the elements recorded in `delta`, leaving the others untouched.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::IndexOutOfBounds`] if an index of `delta` is out of
+    /// bounds. No element is written in this case.
+    pub fn apply_delta(&mut self, delta: &Delta<Attr>) -> Result<(), SliceError> {
+        let len = self.len();
+        if let Some((position, index)) = delta
+            .indices
+            .iter()
+            .enumerate()
+            .find(|(_, index)| **index >= len)
+        {
+            return Err(SliceError::IndexOutOfBounds {
+                position,
+                index: *index as u64,
+                len,
+            });
+        }
+        for (index, value) in delta.iter() {
+            self[index] = *value;
+        }
+        Ok(())
+    }
+}
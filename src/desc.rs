@@ -0,0 +1,42 @@
+use crate::SliceError;
+
+/// Layout of strided elements in a byte buffer.
+///
+/// Unlike a [`crate::Slice`], a description doesn't borrow any data, and can
+/// be stored next to the buffer it describes, e.g., in an [`crate::OwnedView`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ViewDesc {
+    /// Byte offset of the first element
+    pub offset: usize,
+    /// Distance between two elements, in **bytes**
+    pub stride: usize,
+    /// Number of elements
+    pub count: usize,
+}
+
+impl ViewDesc {
+    /// Create a description of `count` elements starting at byte `offset`,
+    /// and spaced by `stride` bytes.
+    pub fn new(offset: usize, stride: usize, count: usize) -> Self {
+        Self {
+            offset,
+            stride,
+            count,
+        }
+    }
+
+    /// Byte offset one past the last byte of the last element, for elements
+    /// of `attr` bytes.
+    ///
+    /// Returns the offset for empty descriptions.
+    pub fn byte_end(&self, attr: usize) -> Result<usize, SliceError> {
+        match self.count {
+            0 => Ok(self.offset),
+            count => (count - 1)
+                .checked_mul(self.stride)
+                .and_then(|v| v.checked_add(attr))
+                .and_then(|v| v.checked_add(self.offset))
+                .ok_or(SliceError::Overflow),
+        }
+    }
+}
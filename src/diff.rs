@@ -0,0 +1,177 @@
+use bytemuck::Pod;
+use std::fmt::Debug;
+
+use crate::{Float, FloatLanes, SliceInfo};
+
+/// Largest per-lane deviation found by [`SliceInfo::diff_approx`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Deviation {
+    /// Absolute difference between both lanes
+    pub value: f64,
+    /// Index of the element
+    pub index: usize,
+    /// Index of the lane in the element
+    pub lane: usize,
+}
+
+/// Differences between two slices, computed by [`SliceInfo::diff`] and [`SliceInfo::diff_approx`].
+///
+/// Only the common prefix of both slices is compared. A length mismatch is reported
+/// via [`Self::len_a`] and [`Self::len_b`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diff<T> {
+    /// Length of the first slice
+    pub len_a: usize,
+    /// Length of the second slice
+    pub len_b: usize,
+    /// Number of differing elements
+    pub count: usize,
+    /// Indices of the first differing elements, limited to the maximum reported count
+    pub indices: Vec<usize>,
+    /// First differing element, in the first and second slice
+    pub first: Option<(usize, T, T)>,
+    /// Largest deviation, only computed in approximate mode
+    pub max_deviation: Option<Deviation>,
+}
+
+impl<T> Diff<T> {
+    /// `true` if both slices have the same length, and no element differs.
+    pub fn is_identical(&self) -> bool {
+        self.len_a == self.len_b && self.count == 0
+    }
+}
+
+impl<Attr: Pod> SliceInfo<Attr> {
+    /// Compare each element of `self` and `other`, byte by byte.
+    ///
+    /// At most `max_reported` differing indices are stored, the others are only counted.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let a = [[1_u32, 2], [3, 4], [5, 6]];
+    /// let b = [[1_u32, 2], [3, 0], [5, 6]];
+    /// let diff = Slice::<[u32; 2]>::native(&a).diff(&Slice::native(&b), 10);
+    /// assert_eq!(diff.indices, [1]);
+    /// println!("{}", diff); // Prints `1 of 3 elements differ; first at 1: [3, 4] vs [3, 0]`
+    /// ```
+    pub fn diff(&self, other: &SliceInfo<Attr>, max_reported: usize) -> Diff<Attr> {
+        self.diff_by(other, max_reported, |a, b| {
+            (bytemuck::bytes_of(a) != bytemuck::bytes_of(b)).then_some(None)
+        })
+    }
+
+    /// Compare the common prefix of both slices with `compare`, returning
+    /// `Some` if elements differ, along with their largest deviation, if any.
+    fn diff_by(
+        &self,
+        other: &SliceInfo<Attr>,
+        max_reported: usize,
+        mut compare: impl FnMut(&Attr, &Attr) -> Option<Option<(f64, usize)>>,
+    ) -> Diff<Attr> {
+        let mut diff = Diff {
+            len_a: self.len(),
+            len_b: other.len(),
+            count: 0,
+            indices: Vec::new(),
+            first: None,
+            max_deviation: None,
+        };
+        for (i, (a, b)) in self.elements().zip(other.elements()).enumerate() {
+            let deviation = match compare(a, b) {
+                Some(deviation) => deviation,
+                None => continue,
+            };
+            if diff.first.is_none() {
+                diff.first = Some((i, *a, *b));
+            }
+            if diff.indices.len() < max_reported {
+                diff.indices.push(i);
+            }
+            diff.count += 1;
+            if let Some((value, lane)) = deviation {
+                if diff.max_deviation.map_or(true, |max| value > max.value) {
+                    diff.max_deviation = Some(Deviation {
+                        value,
+                        index: i,
+                        lane,
+                    });
+                }
+            }
+        }
+        diff
+    }
+}
+
+impl<Attr: FloatLanes> SliceInfo<Attr> {
+    /// Similar to [`Self::diff`], but lanes only differ if their absolute difference
+    /// is larger than `epsilon`.
+    ///
+    /// The largest deviation among differing elements is reported.
+    /// Two NaN lanes are considered equal.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let a = [[1.0_f32, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    /// let b = [[1.0_f32, 2.0, 3.1], [4.0, 5.0, 6.000001]];
+    /// let diff = Slice::<[f32; 3]>::native(&a).diff_approx(&Slice::native(&b), 0.001, 10);
+    /// assert_eq!(diff.indices, [0]);
+    /// assert_eq!(diff.max_deviation.unwrap().lane, 2);
+    /// ```
+    pub fn diff_approx(
+        &self,
+        other: &SliceInfo<Attr>,
+        epsilon: f64,
+        max_reported: usize,
+    ) -> Diff<Attr> {
+        self.diff_by(other, max_reported, |a, b| {
+            let mut max: Option<(f64, usize)> = None;
+            for lane in 0..Attr::LANES {
+                let (a, b) = (a.lane(lane).to_f64(), b.lane(lane).to_f64());
+                let deviation = match (a.is_nan(), b.is_nan()) {
+                    (true, true) => 0.0,
+                    (false, false) => (a - b).abs(),
+                    _ => f64::INFINITY,
+                };
+                if deviation > epsilon && max.map_or(true, |(value, _)| deviation > value) {
+                    max = Some((deviation, lane));
+                }
+            }
+            max.map(Some)
+        })
+    }
+}
+
+//
+// Traits implementation
+//
+
+impl<T: Debug> std::fmt::Display for Diff<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} of {} elements differ",
+            self.count,
+            self.len_a.min(self.len_b)
+        )?;
+        if let Some((index, a, b)) = &self.first {
+            write!(f, "; first at {}: {:?} vs {:?}", index, a, b)?;
+        }
+        if let Some(max) = &self.max_deviation {
+            write!(
+                f,
+                "; max dev {} at {} lane {}",
+                max.value, max.index, max.lane
+            )?;
+        }
+        if self.len_a != self.len_b {
+            write!(f, "; lengths differ: {} vs {}", self.len_a, self.len_b)?;
+        }
+        Ok(())
+    }
+}
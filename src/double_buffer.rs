@@ -0,0 +1,217 @@
+use bytemuck::Pod;
+use std::marker::PhantomData;
+
+use crate::{OwnedView, Slice, SliceError, SliceMut, ViewDesc};
+
+/// Two buffers of the same layout, one read while the other is written.
+///
+/// Elements of type `T` are written into the back buffer with [`Self::write_view`],
+/// while the front buffer is read with [`Self::read_view`], e.g., by a GPU upload.
+/// [`Self::swap`] then exchanges both buffers.
+///
+/// Other attributes of an interleaved layout are accessed by index, with
+/// [`Self::read_attribute`] and [`Self::write_attribute`].
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{DoubleBuffered, ViewDesc};
+///
+/// let mut buffers = DoubleBuffered::<u32>::new(ViewDesc::new(0, 4, 3)).unwrap();
+/// for frame in 1..=2 {
+///     buffers.write_view().fill(frame);
+///     buffers.swap();
+///     assert!(buffers.read_view().iter().all(|v| *v == frame));
+/// }
+/// ```
+#[derive(Clone)]
+pub struct DoubleBuffered<T> {
+    buffers: [OwnedView; 2],
+    front: usize,
+    attribute: usize,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Pod> DoubleBuffered<T> {
+    /// Create zeroed buffers, holding the elements described by `desc`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns the errors of [`OwnedView::view`] for `desc`.
+    pub fn new(desc: ViewDesc) -> Result<Self, SliceError> {
+        let len = Self::byte_len(&[desc], desc)?;
+        let mut view = OwnedView::zeroed(len);
+        view.push(desc);
+        Self::from_buffers(view, 0)
+    }
+
+    /// Create buffers holding a copy of the bytes of `view`, such that both sides
+    /// start with the same content.
+    ///
+    /// Every attribute of `view` is available by index. Elements of type `T` are
+    /// the ones of the attribute at index `attribute`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{DoubleBuffered, InterleaveBuilder};
+    ///
+    /// let positions = [[0.0_f32, 0.0, 0.0], [1.0, 0.0, 0.0]];
+    /// let colors = [[255_u8, 0, 0, 255], [0, 255, 0, 255]];
+    /// let view = InterleaveBuilder::new().push(&positions).push(&colors).build().unwrap();
+    ///
+    /// let mut buffers = DoubleBuffered::<[f32; 3]>::from_view(&view, 0).unwrap();
+    /// buffers.write_view()[1] = [2.0, 0.0, 0.0];
+    /// buffers.write_attribute::<[u8; 4]>(1).unwrap()[1] = [0, 0, 255, 255];
+    /// buffers.swap();
+    /// assert_eq!(buffers.read_view()[1], [2.0, 0.0, 0.0]);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if no attribute is registered at index `attribute`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns the errors of [`OwnedView::view`] for the attribute.
+    pub fn from_view<K>(view: &OwnedView<K>, attribute: usize) -> Result<Self, SliceError> {
+        let mut copy = OwnedView::new(view.bytes());
+        for index in 0..view.attribute_count() {
+            copy.push(*view.desc(index).unwrap());
+        }
+        Self::from_buffers(copy, attribute)
+    }
+
+    /// Validate the attribute of `view`, and use it for both sides.
+    fn from_buffers(view: OwnedView, attribute: usize) -> Result<Self, SliceError> {
+        view.view::<T>(attribute)?;
+        Ok(Self {
+            buffers: [view.clone(), view],
+            front: 0,
+            attribute,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Size of buffers holding every attribute of `descs`, and the elements of `main`.
+    fn byte_len(descs: &[ViewDesc], main: ViewDesc) -> Result<usize, SliceError> {
+        descs
+            .iter()
+            .try_fold(main.byte_end(std::mem::size_of::<T>())?, |len, desc| {
+                let end = desc
+                    .stride
+                    .checked_mul(desc.count)
+                    .ok_or(SliceError::Overflow)?;
+                Ok(len.max(end))
+            })
+    }
+
+    /// Description of the elements of type `T`.
+    pub fn desc(&self) -> ViewDesc {
+        *self.buffers[0].desc(self.attribute).unwrap()
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.desc().count
+    }
+
+    /// `true` if there is no element, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Create a slice over the elements of the front buffer.
+    pub fn read_view(&self) -> Slice<'_, T> {
+        // Validated when created, and the buffers keep the same size.
+        self.buffers[self.front].view(self.attribute).unwrap()
+    }
+
+    /// Create a mutable slice over the elements of the back buffer.
+    pub fn write_view(&mut self) -> SliceMut<'_, T> {
+        // Validated when created, and the buffers keep the same size.
+        self.buffers[1 - self.front]
+            .view_mut(self.attribute)
+            .unwrap()
+    }
+
+    /// Similar to [`Self::read_view`], but for the attribute at index `index`,
+    /// read as `A`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if no attribute is registered at index `index`.
+    ///
+    /// ## Errors
+    ///
+    /// See [`OwnedView::view`].
+    pub fn read_attribute<A: Pod>(&self, index: usize) -> Result<Slice<'_, A>, SliceError> {
+        self.buffers[self.front].view(index)
+    }
+
+    /// Similar to [`Self::write_view`], but for the attribute at index `index`,
+    /// written as `A`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if no attribute is registered at index `index`.
+    ///
+    /// ## Errors
+    ///
+    /// See [`OwnedView::view`].
+    pub fn write_attribute<A: Pod>(&mut self, index: usize) -> Result<SliceMut<'_, A>, SliceError> {
+        self.buffers[1 - self.front].view_mut(index)
+    }
+
+    /// Exchange the front and back buffers.
+    ///
+    /// The elements written since the last swap become readable, and the next
+    /// writes go to the previous front buffer.
+    pub fn swap(&mut self) {
+        self.front = 1 - self.front;
+    }
+
+    /// Resize both buffers to hold `count` elements of every attribute.
+    ///
+    /// The content of the front buffer is preserved, up to the smallest size.
+    /// The back buffer is zeroed.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::Overflow`] if the new size overflows. Buffers are
+    /// left unchanged in this case.
+    pub fn resize(&mut self, count: usize) -> Result<(), SliceError> {
+        let front = &self.buffers[self.front];
+        let descs: Vec<ViewDesc> = (0..front.attribute_count())
+            .map(|index| ViewDesc {
+                count,
+                ..*front.desc(index).unwrap()
+            })
+            .collect();
+        let len = Self::byte_len(&descs, descs[self.attribute])?;
+
+        let [mut new_front, mut new_back] = [OwnedView::zeroed(len), OwnedView::zeroed(len)];
+        let kept = len.min(front.bytes().len());
+        new_front.bytes_mut()[..kept].copy_from_slice(&front.bytes()[..kept]);
+        for desc in descs {
+            new_front.push(desc);
+            new_back.push(desc);
+        }
+        self.buffers = match self.front {
+            0 => [new_front, new_back],
+            _ => [new_back, new_front],
+        };
+        Ok(())
+    }
+}
+
+impl<T> std::fmt::Debug for DoubleBuffered<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DoubleBuffered")
+            .field("front", &self.front)
+            .field("attribute", &self.attribute)
+            .field("desc", &self.buffers[0].desc(self.attribute))
+            .field("bytes", &self.buffers[0].bytes().len())
+            .finish()
+    }
+}
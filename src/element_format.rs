@@ -0,0 +1,342 @@
+use bytemuck::Pod;
+
+use crate::{private, SliceError};
+
+/// Format of an attribute, following the shader-side conventions, e.g., `F32x3`, `Unorm8x4`.
+///
+/// Formats are ordered by component type, then by lane count: the discriminant
+/// of a format is `4 * component + lanes - 1`, and is stable.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Format {
+    /// `u8`
+    U8,
+    /// `[u8; 2]`
+    U8x2,
+    /// `[u8; 3]`
+    U8x3,
+    /// `[u8; 4]`
+    U8x4,
+    /// `i8`
+    I8,
+    /// `[i8; 2]`
+    I8x2,
+    /// `[i8; 3]`
+    I8x3,
+    /// `[i8; 4]`
+    I8x4,
+    /// `u8` normalized to `[0, 1]`
+    Unorm8,
+    /// `[u8; 2]` normalized to `[0, 1]`
+    Unorm8x2,
+    /// `[u8; 3]` normalized to `[0, 1]`
+    Unorm8x3,
+    /// `[u8; 4]` normalized to `[0, 1]`
+    Unorm8x4,
+    /// `i8` normalized to `[-1, 1]`
+    Snorm8,
+    /// `[i8; 2]` normalized to `[-1, 1]`
+    Snorm8x2,
+    /// `[i8; 3]` normalized to `[-1, 1]`
+    Snorm8x3,
+    /// `[i8; 4]` normalized to `[-1, 1]`
+    Snorm8x4,
+    /// `u16`
+    U16,
+    /// `[u16; 2]`
+    U16x2,
+    /// `[u16; 3]`
+    U16x3,
+    /// `[u16; 4]`
+    U16x4,
+    /// `i16`
+    I16,
+    /// `[i16; 2]`
+    I16x2,
+    /// `[i16; 3]`
+    I16x3,
+    /// `[i16; 4]`
+    I16x4,
+    /// `u16` normalized to `[0, 1]`
+    Unorm16,
+    /// `[u16; 2]` normalized to `[0, 1]`
+    Unorm16x2,
+    /// `[u16; 3]` normalized to `[0, 1]`
+    Unorm16x3,
+    /// `[u16; 4]` normalized to `[0, 1]`
+    Unorm16x4,
+    /// `i16` normalized to `[-1, 1]`
+    Snorm16,
+    /// `[i16; 2]` normalized to `[-1, 1]`
+    Snorm16x2,
+    /// `[i16; 3]` normalized to `[-1, 1]`
+    Snorm16x3,
+    /// `[i16; 4]` normalized to `[-1, 1]`
+    Snorm16x4,
+    /// `u32`
+    U32,
+    /// `[u32; 2]`
+    U32x2,
+    /// `[u32; 3]`
+    U32x3,
+    /// `[u32; 4]`
+    U32x4,
+    /// `i32`
+    I32,
+    /// `[i32; 2]`
+    I32x2,
+    /// `[i32; 3]`
+    I32x3,
+    /// `[i32; 4]`
+    I32x4,
+    /// `f32`
+    F32,
+    /// `[f32; 2]`
+    F32x2,
+    /// `[f32; 3]`
+    F32x3,
+    /// `[f32; 4]`
+    F32x4,
+}
+
+/// Number of component types, i.e., formats with a single lane.
+const COMPONENTS: usize = 11;
+
+impl Format {
+    /// Every format, ordered by discriminant.
+    pub const ALL: [Format; 4 * COMPONENTS] = {
+        use Format::*;
+        [
+            U8, U8x2, U8x3, U8x4, I8, I8x2, I8x3, I8x4, Unorm8, Unorm8x2, Unorm8x3, Unorm8x4,
+            Snorm8, Snorm8x2, Snorm8x3, Snorm8x4, U16, U16x2, U16x3, U16x4, I16, I16x2, I16x3,
+            I16x4, Unorm16, Unorm16x2, Unorm16x3, Unorm16x4, Snorm16, Snorm16x2, Snorm16x3,
+            Snorm16x4, U32, U32x2, U32x3, U32x4, I32, I32x2, I32x3, I32x4, F32, F32x2, F32x3,
+            F32x4,
+        ]
+    };
+
+    /// Format with the discriminant `code`, if any.
+    pub fn from_code(code: u32) -> Option<Format> {
+        Self::ALL.get(code as usize).copied()
+    }
+
+    /// Normalized version of an integer format, e.g., `U8x4` becomes `Unorm8x4`.
+    ///
+    /// Returns `None` for 32-bit and already normalized formats.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Format;
+    ///
+    /// assert_eq!(Format::I16x2.normalized(), Some(Format::Snorm16x2));
+    /// assert_eq!(Format::F32.normalized(), None);
+    /// ```
+    pub fn normalized(self) -> Option<Format> {
+        // `Unorm` and `Snorm` components come two components after `U` and `I`.
+        match self.component() {
+            0 | 1 | 4 | 5 => Some(Self::ALL[self as usize + 8]),
+            _ => None,
+        }
+    }
+
+    /// Format of the type `T`, e.g., `F32x3` for `[f32; 3]`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Format;
+    ///
+    /// assert_eq!(Format::of::<[f32; 3]>(), Format::F32x3);
+    /// assert_eq!(Format::of::<i16>(), Format::I16);
+    /// ```
+    pub fn of<T: FormatElement>() -> Format {
+        T::FORMAT
+    }
+
+    /// Index of the component type, following the order of the discriminants.
+    fn component(self) -> usize {
+        self as usize / 4
+    }
+
+    /// Number of lanes, from `1` to `4`.
+    pub fn lanes(self) -> usize {
+        self as usize % 4 + 1
+    }
+
+    /// Format of a single lane, e.g., `Unorm8` for `Unorm8x4`.
+    pub fn lane_format(self) -> Format {
+        Self::ALL[self.component() * 4]
+    }
+
+    /// `true` for `Unorm` and `Snorm` formats, `false` otherwise
+    pub fn is_normalized(self) -> bool {
+        matches!(self.component(), 2 | 3 | 6 | 7)
+    }
+
+    /// Alignment of an element, in **bytes**, i.e., the size of a lane.
+    pub fn align(self) -> usize {
+        match self.component() {
+            0..=3 => 1,
+            4..=7 => 2,
+            _ => 4,
+        }
+    }
+
+    /// Size of an element, in **bytes**.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Format;
+    ///
+    /// assert_eq!(Format::Unorm8x4.size(), 4);
+    /// assert_eq!(Format::F32x3.size(), 12);
+    /// ```
+    pub fn size(self) -> usize {
+        self.align() * self.lanes()
+    }
+
+    /// Check that elements of this format can be read as `T`.
+    ///
+    /// `T` must have the size of an element, and an alignment no stricter than
+    /// [`Self::align`]. Normalized formats are read as their integer type.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Format;
+    ///
+    /// assert!(Format::Unorm8x4.validate_against::<[u8; 4]>().is_ok());
+    /// assert!(Format::Unorm8x4.validate_against::<u32>().is_err());
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::ElementMismatch`] otherwise.
+    pub fn validate_against<T: Pod>(self) -> Result<(), SliceError> {
+        if std::mem::size_of::<T>() != self.size() || std::mem::align_of::<T>() > self.align() {
+            return Err(SliceError::ElementMismatch {
+                from: "format",
+                to: std::any::type_name::<T>(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Format of a glTF accessor, from its `componentType`, `type`, and `normalized`
+    /// properties.
+    ///
+    /// Returns `None` for matrices, and for combinations without a format, e.g.,
+    /// normalized floats.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Format;
+    ///
+    /// assert_eq!(Format::from_gltf(5126, "VEC3", false), Some(Format::F32x3));
+    /// assert_eq!(Format::from_gltf(5121, "VEC4", true), Some(Format::Unorm8x4));
+    /// assert_eq!(Format::from_gltf(5126, "MAT4", false), None);
+    /// ```
+    pub fn from_gltf(component_type: u32, ty: &str, normalized: bool) -> Option<Format> {
+        let lanes = match ty {
+            "SCALAR" => 1,
+            "VEC2" => 2,
+            "VEC3" => 3,
+            "VEC4" => 4,
+            _ => return None,
+        };
+        let component = match component_type {
+            GLTF_BYTE => 1,
+            GLTF_UNSIGNED_BYTE => 0,
+            GLTF_SHORT => 5,
+            GLTF_UNSIGNED_SHORT => 4,
+            GLTF_UNSIGNED_INT => 8,
+            GLTF_FLOAT => 10,
+            _ => return None,
+        };
+        let format = Self::ALL[component * 4 + lanes - 1];
+        match normalized {
+            true => format.normalized(),
+            false => Some(format),
+        }
+    }
+
+    /// glTF accessor properties of the format: `(componentType, type, normalized)`.
+    ///
+    /// Returns `None` for `I32` formats, which glTF doesn't support.
+    pub fn to_gltf(self) -> Option<(u32, &'static str, bool)> {
+        let component_type = match self.component() {
+            0 | 2 => GLTF_UNSIGNED_BYTE,
+            1 | 3 => GLTF_BYTE,
+            4 | 6 => GLTF_UNSIGNED_SHORT,
+            5 | 7 => GLTF_SHORT,
+            8 => GLTF_UNSIGNED_INT,
+            9 => return None,
+            _ => GLTF_FLOAT,
+        };
+        let ty = ["SCALAR", "VEC2", "VEC3", "VEC4"][self.lanes() - 1];
+        Some((component_type, ty, self.is_normalized()))
+    }
+}
+
+/// glTF `componentType` values, as OpenGL enums.
+const GLTF_BYTE: u32 = 5120;
+const GLTF_UNSIGNED_BYTE: u32 = 5121;
+const GLTF_SHORT: u32 = 5122;
+const GLTF_UNSIGNED_SHORT: u32 = 5123;
+const GLTF_UNSIGNED_INT: u32 = 5125;
+const GLTF_FLOAT: u32 = 5126;
+
+/// Rust type with a [`Format`], i.e., `u8`, `i8`, `u16`, `i16`, `u32`, `i32`,
+/// `f32`, and arrays of 2 to 4 of those.
+///
+/// This trait is sealed and can't be implemented outside of this crate.
+pub trait FormatElement: Pod + private::Sealed {
+    /// Format of the type, never normalized.
+    const FORMAT: Format;
+}
+
+// `u8`, `u16`, `u32`, `f32`, and `[f32; N]` are already sealed.
+impl private::Sealed for i8 {}
+impl private::Sealed for i16 {}
+impl private::Sealed for i32 {}
+impl<const N: usize> private::Sealed for [u8; N] {}
+impl<const N: usize> private::Sealed for [i8; N] {}
+impl<const N: usize> private::Sealed for [u16; N] {}
+impl<const N: usize> private::Sealed for [i16; N] {}
+impl<const N: usize> private::Sealed for [u32; N] {}
+impl<const N: usize> private::Sealed for [i32; N] {}
+
+macro_rules! impl_format_element {
+    ($($scalar: ty => [$x1: ident, $x2: ident, $x3: ident, $x4: ident]),*) => {
+        $(
+            impl FormatElement for $scalar {
+                const FORMAT: Format = Format::$x1;
+            }
+            impl FormatElement for [$scalar; 1] {
+                const FORMAT: Format = Format::$x1;
+            }
+            impl FormatElement for [$scalar; 2] {
+                const FORMAT: Format = Format::$x2;
+            }
+            impl FormatElement for [$scalar; 3] {
+                const FORMAT: Format = Format::$x3;
+            }
+            impl FormatElement for [$scalar; 4] {
+                const FORMAT: Format = Format::$x4;
+            }
+        )*
+    };
+}
+
+impl_format_element!(
+    u8 => [U8, U8x2, U8x3, U8x4],
+    i8 => [I8, I8x2, I8x3, I8x4],
+    u16 => [U16, U16x2, U16x3, U16x4],
+    i16 => [I16, I16x2, I16x3, I16x4],
+    u32 => [U32, U32x2, U32x3, U32x4],
+    i32 => [I32, I32x2, I32x3, I32x4],
+    f32 => [F32, F32x2, F32x3, F32x4]
+);
@@ -0,0 +1,293 @@
+use bytemuck::Pod;
+use std::marker::PhantomData;
+
+use crate::shared_impl::SliceBase;
+use crate::{Format, FormatElement, Slice, SliceError};
+
+/// Format of a [`TypeTag`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TagFormat {
+    /// Format of a standard type, e.g., `Format::F32x3` for `[f32; 3]`
+    Standard(Format),
+    /// User-supplied name, e.g., for a newtype with its own meaning
+    Named(&'static str),
+}
+
+/// Description of an element type, stable across separately compiled crates.
+///
+/// At the opposite of [`std::any::TypeId`], a tag only depends on the size,
+/// alignment, and format of the type. Two crates agreeing on these agree on the tag,
+/// whatever the compiler version or the generics they were built with.
+///
+/// See [`TaggedPod`] and [`ErasedSlice::tagged`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TypeTag {
+    /// Size, in **bytes**
+    pub size: usize,
+    /// Alignment, in **bytes**
+    pub align: usize,
+    /// Format of the type
+    pub format: TagFormat,
+}
+
+impl TypeTag {
+    /// Tag of the type `T`, with the format `format`.
+    pub const fn of<T>(format: TagFormat) -> Self {
+        Self {
+            size: std::mem::size_of::<T>(),
+            align: std::mem::align_of::<T>(),
+            format,
+        }
+    }
+}
+
+impl std::fmt::Display for TypeTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.format {
+            TagFormat::Standard(format) => write!(f, "{:?}", format)?,
+            TagFormat::Named(name) => write!(f, "'{}'", name)?,
+        }
+        write!(f, " ({} bytes, align {})", self.size, self.align)
+    }
+}
+
+/// Element type with a [`TypeTag`].
+///
+/// Implemented for every [`FormatElement`], i.e., `u8`, `i8`, `u16`, `i16`, `u32`, `i32`,
+/// `f32`, and arrays of those, with their standard format. Use [`crate::tagged_pod!`]
+/// for user types.
+///
+/// ## Safety
+///
+/// [`Self::TAG`] must have the size and alignment of the type, as created by
+/// [`TypeTag::of`]. Implementing the trait by hand is thus rejected:
+///
+/// ```rust,compile_fail,E0200
+/// use pas::{TaggedPod, TypeTag};
+///
+/// #[repr(transparent)]
+/// #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+/// struct Word(u32);
+///
+/// // Doesn't compile, since the tag must be trusted: use `tagged_pod!` instead.
+/// impl TaggedPod for Word {
+///     const TAG: TypeTag = <[u8; 4]>::TAG;
+/// }
+/// ```
+pub unsafe trait TaggedPod: Pod {
+    /// Tag of the type.
+    const TAG: TypeTag;
+}
+
+// Safe because the tag is created from the type.
+unsafe impl<T: FormatElement> TaggedPod for T {
+    const TAG: TypeTag = TypeTag::of::<T>(TagFormat::Standard(T::FORMAT));
+}
+
+/// Implement [`TaggedPod`] for user types, with either a standard [`Format`],
+/// or a name.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{tagged_pod, TagFormat, TaggedPod};
+///
+/// #[repr(transparent)]
+/// #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+/// struct Position([f32; 3]);
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+/// struct Joint {
+///     index: u32,
+///     weight: f32,
+/// }
+///
+/// tagged_pod!(Position => F32x3, Joint => "skin::Joint");
+/// assert_eq!(Position::TAG, <[f32; 3]>::TAG);
+/// assert_eq!(Joint::TAG.format, TagFormat::Named("skin::Joint"));
+/// ```
+#[macro_export]
+macro_rules! tagged_pod {
+    ($t:ty => $name:literal $(, $($rest:tt)*)?) => {
+        unsafe impl $crate::TaggedPod for $t {
+            const TAG: $crate::TypeTag =
+                $crate::TypeTag::of::<$t>($crate::TagFormat::Named($name));
+        }
+        $($crate::tagged_pod!($($rest)*);)?
+    };
+    ($t:ty => $format:ident $(, $($rest:tt)*)?) => {
+        unsafe impl $crate::TaggedPod for $t {
+            const TAG: $crate::TypeTag =
+                $crate::TypeTag::of::<$t>($crate::TagFormat::Standard($crate::Format::$format));
+        }
+        $($crate::tagged_pod!($($rest)*);)?
+    };
+    () => {};
+}
+
+/// Immutable strided view, whose element type is only known at runtime.
+///
+/// Created from a [`Slice`], with [`ErasedSlice::new`], or [`ErasedSlice::tagged`] to
+/// record a [`TypeTag`]. The typed view is recovered with [`ErasedSlice::downcast`],
+/// or [`ErasedSlice::downcast_tagged`].
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{ErasedSlice, Slice};
+///
+/// let data = [[0.0_f32, 1.0, 2.0], [3.0, 4.0, 5.0]];
+/// let erased = ErasedSlice::tagged(Slice::native(&data));
+/// assert_eq!(erased.len(), 2);
+///
+/// let positions: Slice<[f32; 3]> = erased.downcast_tagged().unwrap();
+/// assert_eq!(positions[1], [3.0, 4.0, 5.0]);
+/// assert!(erased.downcast_tagged::<[u32; 3]>().is_err());
+/// ```
+#[derive(Clone, Copy)]
+pub struct ErasedSlice<'a> {
+    inner: SliceBase<u8>,
+    len: usize,
+    size: usize,
+    align: usize,
+    type_name: &'static str,
+    tag: Option<TypeTag>,
+    _phantom: PhantomData<&'a [u8]>,
+}
+
+// Safe because the data is only read, and `Pod` types have no interior mutability.
+unsafe impl<'a> Send for ErasedSlice<'a> {}
+unsafe impl<'a> Sync for ErasedSlice<'a> {}
+
+impl<'a> ErasedSlice<'a> {
+    /// Erase the element type of `slice`, without tag.
+    pub fn new<T: Pod>(slice: Slice<'a, T>) -> Self {
+        Self {
+            inner: slice.base().cast(),
+            len: slice.len(),
+            size: std::mem::size_of::<T>(),
+            align: std::mem::align_of::<T>(),
+            type_name: std::any::type_name::<T>(),
+            tag: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Erase the element type of `slice`, recording its tag.
+    pub fn tagged<T: TaggedPod>(slice: Slice<'a, T>) -> Self {
+        Self {
+            tag: Some(T::TAG),
+            ..Self::new(slice)
+        }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// `true` if the slice has no element, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Stride, in **bytes**.
+    pub fn stride(&self) -> usize {
+        self.inner.stride()
+    }
+
+    /// Byte offset of the first element, from the start of the original buffer.
+    pub fn byte_offset(&self) -> usize {
+        self.inner.byte_offset()
+    }
+
+    /// Size of an element, in **bytes**.
+    pub fn element_size(&self) -> usize {
+        self.size
+    }
+
+    /// Tag recorded by [`Self::tagged`], if any.
+    pub fn tag(&self) -> Option<TypeTag> {
+        self.tag
+    }
+
+    /// Name of the erased type.
+    #[cfg(feature = "serde")]
+    pub(crate) fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Pointer on the first byte of the original buffer, identifying it.
+    #[cfg(feature = "serde")]
+    pub(crate) fn buffer_ptr(&self) -> *const u8 {
+        self.inner.parent
+    }
+
+    /// Similar to [`Self::new`], but recording the tag `tag`, which must have
+    /// the size and alignment of `T`.
+    #[cfg(feature = "serde")]
+    pub(crate) fn with_tag<T: Pod>(slice: Slice<'a, T>, tag: TypeTag) -> Self {
+        debug_assert!(tag == TypeTag::of::<T>(tag.format));
+        Self {
+            tag: Some(tag),
+            ..Self::new(slice)
+        }
+    }
+
+    /// Recover a typed view, reading elements as `T`.
+    ///
+    /// Only the layout is checked: `T` must have the same size as the erased type,
+    /// and an alignment lower or equal. The tag, if any, is ignored.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::ElementMismatch`] if `T` doesn't have a compatible layout.
+    pub fn downcast<T: Pod>(&self) -> Result<Slice<'a, T>, SliceError> {
+        if std::mem::size_of::<T>() != self.size || std::mem::align_of::<T>() > self.align {
+            return Err(SliceError::ElementMismatch {
+                from: self.type_name,
+                to: std::any::type_name::<T>(),
+            });
+        }
+        Ok(Slice::from_base(self.inner.cast()))
+    }
+
+    /// Recover a typed view, if the tag of `T` is the recorded tag.
+    ///
+    /// The layout of `T` is then checked, as for [`Self::downcast`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::TagMismatch`] if the slice is untagged, or tagged
+    /// with another tag, and [`SliceError::ElementMismatch`] if `T` doesn't have
+    /// a compatible layout.
+    pub fn downcast_tagged<T: TaggedPod>(&self) -> Result<Slice<'a, T>, SliceError> {
+        match self.tag {
+            // The tag already has the size and alignment of `T`, but it can't be
+            // trusted for soundness.
+            Some(tag) if tag == T::TAG => self.downcast(),
+            found => Err(SliceError::TagMismatch {
+                expected: T::TAG,
+                found,
+            }),
+        }
+    }
+}
+
+impl<'a, T: Pod> From<Slice<'a, T>> for ErasedSlice<'a> {
+    fn from(slice: Slice<'a, T>) -> Self {
+        Self::new(slice)
+    }
+}
+
+impl<'a> std::fmt::Debug for ErasedSlice<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ErasedSlice")
+            .field("type_name", &self.type_name)
+            .field("tag", &self.tag)
+            .field("len", &self.len)
+            .field("stride", &self.stride())
+            .finish()
+    }
+}
@@ -0,0 +1,214 @@
+//! Conversions of the crate errors to [`std::error::Error`] and [`std::io::Error`].
+//!
+//! Errors are displayed with the same message as their [`Debug`] representation,
+//! except errors deriving [`Debug`], which have their own message.
+
+use std::error::Error;
+use std::fmt::{Debug, Display};
+use std::io;
+
+use crate::convert::{ConversionError, NarrowError};
+use crate::{
+    AttributeError, BlobError, PrimitiveError, PrimitiveIssue, SanitizeError, ScanError,
+    SliceError, StaleStateError, TransactionError, WrapError,
+};
+#[cfg(feature = "serde")]
+use crate::{ImportError, ImportIssue};
+
+/// Category of a [`SliceError`], for programmatic matching.
+///
+/// Returned by [`SliceError::kind`]. New categories may be added, and
+/// new [`SliceError`] variants are mapped to the closest existing one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SliceErrorKind {
+    /// The attribute doesn't fit the layout, e.g., it's larger than the stride,
+    /// or overlaps with another attribute.
+    Layout,
+    /// An address, or a stride, isn't aligned to the attribute.
+    Alignment,
+    /// An offset, or an index, is out of bounds.
+    OutOfBounds,
+    /// A size or a value exceeds its maximum.
+    Overflow,
+    /// Lengths, counts, types, or tags don't match.
+    Mismatch,
+    /// An argument is invalid, e.g., decreasing offsets.
+    InvalidInput,
+}
+
+impl SliceError {
+    /// Category of the error.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{Slice, SliceErrorKind};
+    ///
+    /// let data = [0_u32; 4];
+    /// let error = Slice::<u32>::try_new(&data, 16).unwrap_err();
+    /// assert_eq!(error.kind(), SliceErrorKind::OutOfBounds);
+    /// ```
+    pub fn kind(&self) -> SliceErrorKind {
+        match self {
+            Self::AttributeLargerThanStride { .. }
+            | Self::AttributeCrossesElementBoundary { .. }
+            | Self::NegativeStride { .. }
+            | Self::NonContiguousElement { .. }
+            | Self::OverlappingAttributes { .. }
+            | Self::OverlappingSegments { .. }
+            | Self::OverlappingCopies { .. } => SliceErrorKind::Layout,
+            Self::AlignmentFault { .. } | Self::UnalignedStride { .. } => SliceErrorKind::Alignment,
+            Self::OffsetOutOfBounds { .. }
+            | Self::IndexOutOfBounds { .. }
+            | Self::NotElementStart { .. } => SliceErrorKind::OutOfBounds,
+            Self::Overflow => SliceErrorKind::Overflow,
+            Self::ElementMismatch { .. }
+            | Self::CountMismatch { .. }
+            | Self::LengthMismatch { .. }
+            | Self::TooShort { .. }
+            | Self::TagMismatch { .. } => SliceErrorKind::Mismatch,
+            Self::NoAttribute | Self::DecreasingOffset { .. } => SliceErrorKind::InvalidInput,
+        }
+    }
+}
+
+/// Implement [`Display`] with the [`Debug`] message, or with the given message
+/// for errors deriving [`Debug`], and the conversion to an [`io::Error`] of kind
+/// [`io::ErrorKind::InvalidData`].
+macro_rules! impl_display {
+    ($name:ty => |$err:ident, $f:ident| $message:expr) => {
+        impl Display for $name {
+            fn fmt(&self, $f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let $err = self;
+                $message
+            }
+        }
+
+        impl From<$name> for io::Error {
+            fn from(err: $name) -> Self {
+                io::Error::new(io::ErrorKind::InvalidData, err)
+            }
+        }
+    };
+    ($($name:ty),*) => {
+        $(impl_display!($name => |err, f| Debug::fmt(err, f));)*
+    };
+}
+
+impl_display!(
+    SliceError,
+    AttributeError,
+    TransactionError,
+    PrimitiveIssue,
+    PrimitiveError,
+    ConversionError
+);
+impl_display!(NarrowError => |err, f| write!(
+    f,
+    "Index {} at position {} doesn't fit in the destination type",
+    err.value, err.index
+));
+impl_display!(SanitizeError => |err, f| match err {
+    SanitizeError::Slice(err) => Display::fmt(err, f),
+    SanitizeError::NonFinite { index, lane } => {
+        write!(f, "Lane {} of element {} isn't finite", lane, index)
+    }
+});
+impl_display!(ScanError => |err, f| write!(
+    f,
+    "Sum overflows when adding the element at index {}",
+    err.index
+));
+impl_display!(StaleStateError => |err, f| write!(
+    f,
+    "State was created for a slice with {} elements, but slice has {} elements",
+    err.expected, err.len
+));
+impl_display!(WrapError => |_err, f| write!(
+    f,
+    "Indices can't be wrapped with a modulus of zero"
+));
+#[cfg(feature = "serde")]
+impl_display!(ImportIssue, ImportError);
+
+impl Error for SliceError {}
+
+impl Error for AttributeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::TypeMismatch { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Error for TransactionError {}
+
+impl Error for PrimitiveIssue {}
+
+impl Error for PrimitiveError {}
+
+impl Error for ConversionError {}
+
+impl Error for NarrowError {}
+
+impl Error for SanitizeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Slice(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl Error for ScanError {}
+
+impl Error for StaleStateError {}
+
+impl Error for WrapError {}
+
+impl Display for BlobError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl Error for BlobError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Blob errors keep the kind of the reader error, if any, and are
+/// [`io::ErrorKind::InvalidData`] otherwise.
+impl From<BlobError> for io::Error {
+    fn from(err: BlobError) -> Self {
+        let kind = match &err {
+            BlobError::Io(err) => err.kind(),
+            _ => io::ErrorKind::InvalidData,
+        };
+        io::Error::new(kind, err)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Error for ImportIssue {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidView { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// The source is the view error of the first issue wrapping one, if any.
+#[cfg(feature = "serde")]
+impl Error for ImportError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.issues.iter().find_map(|issue| issue.source())
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{Format, FormatElement, SliceError, SliceInfo};
+
+/// Metadata needed by shaders to fetch the elements of a slice from a storage buffer.
+///
+/// See [`SliceInfo::fetch_info`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VertexFetchInfo {
+    /// Byte offset of the first element, from the start of the original buffer
+    pub offset: usize,
+    /// Distance between two elements, in **bytes**
+    pub stride: usize,
+    /// Number of elements
+    pub count: usize,
+    /// Format of each element
+    pub format: Format,
+}
+
+impl VertexFetchInfo {
+    /// Fetch elements as normalized values, e.g., `U8x4` becomes `Unorm8x4`.
+    ///
+    /// Returns `None` if the format can't be normalized, see [`Format::normalized`].
+    pub fn normalized(self) -> Option<Self> {
+        Some(Self {
+            format: self.format.normalized()?,
+            ..self
+        })
+    }
+
+    /// Serialize into a std140 `uvec4`: `(offset, stride, count, format)`.
+    ///
+    /// The format is written using its discriminant, see [`Format`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::Overflow`] if a value doesn't fit in 32 bits.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[1.0_f32, 2.0, 3.0, 0.0], [4.0, 5.0, 6.0, 0.0]];
+    /// let slice: Slice<[f32; 3]> = Slice::new(&data, 0);
+    /// assert_eq!(
+    ///     slice.fetch_info().to_std140_words(),
+    ///     Ok([0, 16, 2, pas::Format::F32x3 as u32])
+    /// );
+    /// ```
+    pub fn to_std140_words(&self) -> Result<[u32; 4], SliceError> {
+        let word = |v: usize| u32::try_from(v).map_err(|_| SliceError::Overflow);
+        Ok([
+            word(self.offset)?,
+            word(self.stride)?,
+            word(self.count)?,
+            self.format as u32,
+        ])
+    }
+
+    /// Little-endian bytes of [`Self::to_std140_words`], for push constants.
+    pub fn to_push_constant_bytes(&self) -> Result<[u8; 16], SliceError> {
+        let words = self.to_std140_words()?;
+        let mut bytes = [0; 16];
+        for (chunk, word) in bytes.chunks_exact_mut(4).zip(words) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        Ok(bytes)
+    }
+}
+
+impl<Attr: FormatElement> SliceInfo<Attr> {
+    /// Metadata needed by shaders to fetch the elements from a storage buffer.
+    ///
+    /// The format is derived from `Attr`, use [`VertexFetchInfo::normalized`]
+    /// to fetch normalized values.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{Format, Slice};
+    ///
+    /// let data = [[0.0_f32, 1.0], [2.0, 3.0]];
+    /// let colors = [[255_u8, 0, 0, 255], [0, 255, 0, 255]];
+    /// let slice: Slice<f32> = Slice::new(&data, 4);
+    /// let info = slice.fetch_info();
+    /// assert_eq!((info.offset, info.stride, info.count), (4, 8, 2));
+    /// assert_eq!(info.format, Format::F32);
+    ///
+    /// let slice: Slice<[u8; 4]> = Slice::native(&colors);
+    /// let info = slice.fetch_info().normalized().unwrap();
+    /// assert_eq!(info.format, Format::Unorm8x4);
+    /// ```
+    pub fn fetch_info(&self) -> VertexFetchInfo {
+        VertexFetchInfo {
+            offset: self.byte_offset(),
+            stride: self.stride(),
+            count: self.len(),
+            format: Attr::FORMAT,
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use bytemuck::Pod;
+use std::ops::Range;
+
+use crate::math::div_ceil;
+use crate::{SliceInfo, StaleStateError, TrackedSliceMut};
+
+/// FNV-1a offset basis and prime, for 64-bit hashes.
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Continue the FNV-1a hash `hash` with `bytes`.
+fn fnv(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Hash of a node, from the hashes of its children.
+fn combine(left: u64, right: u64) -> u64 {
+    fnv(fnv(FNV_OFFSET, &left.to_le_bytes()), &right.to_le_bytes())
+}
+
+/// Content hash of the elements of a slice, see [`FingerprintTree`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fingerprint(u64);
+
+impl Fingerprint {
+    /// Raw hash value.
+    pub fn to_bits(self) -> u64 {
+        self.0
+    }
+}
+
+/// Hash tree over fixed-size blocks of elements, updated incrementally
+/// with [`TrackedSliceMut::fingerprint_incremental`].
+///
+/// Each block of `block_size` elements is hashed, and block hashes are combined
+/// pairwise up to a root, such that a write only rehashes its block and the
+/// ancestors of the block. The tree is opaque, and can be stored alongside the
+/// data, e.g., as a cache key.
+///
+/// Fingerprints only depend on the bytes of the elements, in order, and on the
+/// block size: bytes between elements are ignored. Hashes are 64-bit FNV-1a,
+/// which detects accidental changes, but isn't designed to resist crafted collisions.
+///
+/// Created with [`SliceInfo::fingerprint_tree`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FingerprintTree {
+    block_size: usize,
+    len: usize,
+    /// Implicit binary tree: node `i` has children `2 * i` and `2 * i + 1`, and
+    /// the leaves start at `nodes.len() / 2`. Missing leaves hash to `0`.
+    nodes: Vec<u64>,
+}
+
+impl FingerprintTree {
+    /// Fingerprint of the whole slice.
+    pub fn fingerprint(&self) -> Fingerprint {
+        let hash = fnv(FNV_OFFSET, &(self.len as u64).to_le_bytes());
+        let hash = fnv(hash, &(self.block_size as u64).to_le_bytes());
+        Fingerprint(fnv(hash, &self.nodes[1].to_le_bytes()))
+    }
+
+    /// Number of elements per block.
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Number of elements of the fingerprinted slice.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// `true` if the fingerprinted slice is empty, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Range of elements of the block at index `block`.
+    fn block(&self, block: usize) -> Range<usize> {
+        let start = block * self.block_size;
+        start..(start + self.block_size).min(self.len)
+    }
+
+    /// Replace the hash of the leaf at index `leaf`, and update its ancestors.
+    fn update(&mut self, leaf: usize, hash: u64) {
+        let mut node = self.nodes.len() / 2 + leaf;
+        self.nodes[node] = hash;
+        while node > 1 {
+            node /= 2;
+            self.nodes[node] = combine(self.nodes[2 * node], self.nodes[2 * node + 1]);
+        }
+    }
+}
+
+impl<Attr: Pod> SliceInfo<Attr> {
+    /// Hash of the bytes of the elements in `range`.
+    fn block_hash(&self, range: Range<usize>) -> u64 {
+        range
+            .filter_map(|index| self.get(index))
+            .fold(FNV_OFFSET, |hash, element| {
+                fnv(hash, bytemuck::bytes_of(element))
+            })
+    }
+
+    /// Hash every block of `block_size` elements, and build a [`FingerprintTree`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[1.0_f32, 2.0], [3.0, 4.0]];
+    /// let copy = data;
+    /// let tree = Slice::native(&data).fingerprint_tree(64);
+    /// assert_eq!(tree.fingerprint(), Slice::native(&copy).fingerprint_tree(64).fingerprint());
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `block_size` is `0`.
+    pub fn fingerprint_tree(&self, block_size: usize) -> FingerprintTree {
+        assert!(block_size > 0, "block size must be greater than zero");
+        let leaves = div_ceil(self.len(), block_size);
+        let width = leaves.next_power_of_two();
+        let mut tree = FingerprintTree {
+            block_size,
+            len: self.len(),
+            nodes: vec![0; 2 * width],
+        };
+        for leaf in 0..leaves {
+            tree.nodes[width + leaf] = self.block_hash(tree.block(leaf));
+        }
+        for node in (1..width).rev() {
+            tree.nodes[node] = combine(tree.nodes[2 * node], tree.nodes[2 * node + 1]);
+        }
+        tree
+    }
+}
+
+impl<'a, Attr: Pod> TrackedSliceMut<'a, Attr> {
+    /// Update `prev` with the elements written since it was computed, and
+    /// return the updated tree.
+    ///
+    /// Only the blocks overlapping the dirty ranges are rehashed. The result is
+    /// the same as [`SliceInfo::fingerprint_tree`] for the current content,
+    /// as long as `prev` was computed after the last [`Self::clear_dirty`].
+    /// Dirty ranges are kept, e.g., for an upload, and are cleared by the caller.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{SliceMut, TrackedSliceMut};
+    ///
+    /// let mut data = [0_u32; 1024];
+    /// let mut slice = TrackedSliceMut::new(SliceMut::native(&mut data));
+    /// let tree = slice.fingerprint_tree(64);
+    ///
+    /// *slice.get_mut(700).unwrap() = 7;
+    /// let tree = slice.fingerprint_incremental(tree).unwrap();
+    /// assert_eq!(tree, slice.fingerprint_tree(64));
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns a [`StaleStateError`] if the slice doesn't have the length
+    /// `prev` was computed with.
+    pub fn fingerprint_incremental(
+        &self,
+        prev: FingerprintTree,
+    ) -> Result<FingerprintTree, StaleStateError> {
+        if prev.len != self.len() {
+            return Err(StaleStateError {
+                expected: prev.len,
+                len: self.len(),
+            });
+        }
+        let mut tree = prev;
+        let mut next = 0;
+        for range in self.dirty_ranges() {
+            // Ranges are sorted, but two of them can share a block.
+            let first = (range.start / tree.block_size).max(next);
+            let end = div_ceil(range.end, tree.block_size);
+            for leaf in first..end {
+                let hash = self.block_hash(tree.block(leaf));
+                tree.update(leaf, hash);
+            }
+            next = next.max(end);
+        }
+        Ok(tree)
+    }
+}
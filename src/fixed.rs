@@ -0,0 +1,210 @@
+use crate::{Slice, SliceMut};
+
+/// Signed 16.16 fixed point, e.g., for positions of mobile formats.
+pub type Q16_16 = Fixed32<16>;
+
+/// Signed 2.14 fixed point, e.g., for unit vectors, or font variation coordinates.
+pub type Q2_14 = Fixed16<14>;
+
+/// Round `scaled` to the nearest integer, ties to even, and clamp it to `[min, max]`.
+///
+/// NaN becomes zero.
+fn round_saturate(scaled: f64, min: f64, max: f64) -> f64 {
+    let mut rounded = scaled.round();
+    // `round` moves ties away from zero, move them back if they land on an odd integer.
+    if (rounded - scaled).abs() == 0.5 && rounded % 2.0 != 0.0 {
+        rounded -= (rounded - scaled).signum();
+    }
+    match rounded.is_nan() {
+        true => 0.0,
+        false => rounded.clamp(min, max),
+    }
+}
+
+/// Shift `value` right by `bits`, rounding to the nearest integer, ties to even.
+fn shift_round(value: i64, bits: u32) -> i64 {
+    if bits == 0 {
+        return value;
+    }
+    let truncated = value >> bits;
+    let rest = value - (truncated << bits);
+    let half = 1 << (bits - 1);
+    match rest.cmp(&half) {
+        std::cmp::Ordering::Greater => truncated + 1,
+        std::cmp::Ordering::Equal => truncated + (truncated & 1),
+        std::cmp::Ordering::Less => truncated,
+    }
+}
+
+macro_rules! impl_fixed {
+    ($name:ident, $bits:ty, $size:literal) => {
+        #[doc = concat!("Signed fixed point number stored in an `", stringify!($bits), "`, with `FRAC_BITS` fractional bits.")]
+        ///
+        /// Usable as a slice element, to read and write fixed point attributes
+        /// without converting the buffer, see [`Slice::iter_f32`] and
+        /// [`SliceMut::write_from_f32_slice`].
+        ///
+        /// Conversions from floats round to the nearest representable value,
+        /// ties to even, and saturate at [`Self::MIN`] and [`Self::MAX`].
+        /// NaN becomes zero.
+        ///
+        #[doc = concat!("`FRAC_BITS` must be lower than ", stringify!($size), ", which is checked at compile time:")]
+        ///
+        /// ```rust,compile_fail
+        #[doc = concat!("let value = pas::", stringify!($name), "::<", stringify!($size), ">::from_f32(1.0);")]
+        /// ```
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name<const FRAC_BITS: u32>($bits);
+
+        // Safe because the type is a transparent wrapper around an integer.
+        unsafe impl<const FRAC_BITS: u32> bytemuck::Zeroable for $name<FRAC_BITS> {}
+        unsafe impl<const FRAC_BITS: u32> bytemuck::Pod for $name<FRAC_BITS> {}
+
+        impl<const FRAC_BITS: u32> $name<FRAC_BITS> {
+            /// Smallest representable value.
+            pub const MIN: Self = Self(<$bits>::MIN);
+            /// Largest representable value.
+            pub const MAX: Self = Self(<$bits>::MAX);
+            /// Smallest positive value, i.e., `2^-FRAC_BITS`.
+            pub const EPSILON: Self = Self(1);
+
+            const VALID: () = assert!(
+                FRAC_BITS < $size,
+                "`FRAC_BITS` must be lower than the number of bits of the storage"
+            );
+
+            /// Value of `1 << FRAC_BITS`, i.e., the value `1.0` as raw bits.
+            #[inline]
+            fn scale() -> f64 {
+                #[allow(clippy::let_unit_value)]
+                let () = Self::VALID;
+                (1_u64 << FRAC_BITS) as f64
+            }
+
+            /// Create a value from its raw bits.
+            pub const fn from_bits(bits: $bits) -> Self {
+                Self(bits)
+            }
+
+            /// Raw bits of the value.
+            pub const fn to_bits(self) -> $bits {
+                self.0
+            }
+
+            /// Convert to the nearest `f32`.
+            #[inline]
+            pub fn to_f32(self) -> f32 {
+                self.to_f64() as f32
+            }
+
+            /// Convert to `f64`, without loss.
+            #[inline]
+            pub fn to_f64(self) -> f64 {
+                self.0 as f64 / Self::scale()
+            }
+
+            /// Convert from `value`, rounding ties to even, and saturating.
+            #[inline]
+            pub fn from_f32(value: f32) -> Self {
+                Self::from_f64(value as f64)
+            }
+
+            /// Convert from `value`, rounding ties to even, and saturating.
+            #[inline]
+            pub fn from_f64(value: f64) -> Self {
+                let bits = round_saturate(
+                    value * Self::scale(),
+                    <$bits>::MIN as f64,
+                    <$bits>::MAX as f64,
+                );
+                Self(bits as $bits)
+            }
+
+            /// Add `rhs`, returning `None` on overflow.
+            pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                self.0.checked_add(rhs.0).map(Self)
+            }
+
+            /// Subtract `rhs`, returning `None` on overflow.
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                self.0.checked_sub(rhs.0).map(Self)
+            }
+
+            /// Multiply by `rhs`, rounding ties to even, and returning `None` on overflow.
+            pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                let product = shift_round(self.0 as i64 * rhs.0 as i64, FRAC_BITS);
+                <$bits>::try_from(product).ok().map(Self)
+            }
+
+            /// Add `rhs`, saturating at the representable range.
+            pub fn saturating_add(self, rhs: Self) -> Self {
+                Self(self.0.saturating_add(rhs.0))
+            }
+
+            /// Subtract `rhs`, saturating at the representable range.
+            pub fn saturating_sub(self, rhs: Self) -> Self {
+                Self(self.0.saturating_sub(rhs.0))
+            }
+
+            /// Multiply by `rhs`, rounding ties to even, and saturating at the
+            /// representable range.
+            pub fn saturating_mul(self, rhs: Self) -> Self {
+                let product = shift_round(self.0 as i64 * rhs.0 as i64, FRAC_BITS);
+                Self(product.clamp(<$bits>::MIN as i64, <$bits>::MAX as i64) as $bits)
+            }
+        }
+
+        impl<const FRAC_BITS: u32> std::fmt::Debug for $name<FRAC_BITS> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_tuple(stringify!($name))
+                    .field(&self.to_f64())
+                    .finish()
+            }
+        }
+
+        impl<'a, const FRAC_BITS: u32> Slice<'a, $name<FRAC_BITS>> {
+            /// Iterate over the elements, converted to `f32`.
+            ///
+            /// ## Example
+            ///
+            /// ```rust
+            #[doc = concat!("use pas::{", stringify!($name), ", Slice};")]
+            ///
+            #[doc = concat!("let data = [", stringify!($name), "::<8>::from_bits(384), ", stringify!($name), "::from_bits(-64)];")]
+            /// let values: Vec<f32> = Slice::native(&data).iter_f32().collect();
+            /// assert_eq!(values, [1.5, -0.25]);
+            /// ```
+            pub fn iter_f32(&self) -> impl Iterator<Item = f32> + '_ {
+                self.elements().map(|value| value.to_f32())
+            }
+        }
+
+        impl<'a, const FRAC_BITS: u32> SliceMut<'a, $name<FRAC_BITS>> {
+            /// Convert each element of `src`, and write it into `self`.
+            ///
+            #[doc = concat!("Values are rounded ties to even, and saturated, see [`", stringify!($name), "::from_f32`].")]
+            /// Only the common prefix of both is processed, i.e.,
+            /// `min(self.len(), src.len())` elements.
+            ///
+            /// Returns the number of elements written.
+            ///
+            /// ## Example
+            ///
+            /// ```rust
+            #[doc = concat!("use pas::{", stringify!($name), ", SliceMut};")]
+            ///
+            #[doc = concat!("let mut data = [", stringify!($name), "::<8>::default(); 2];")]
+            /// SliceMut::native(&mut data).write_from_f32_slice(&[1.5, 1e9]);
+            /// assert_eq!(data[0].to_f32(), 1.5);
+            #[doc = concat!("assert_eq!(data[1], ", stringify!($name), "::MAX);")]
+            /// ```
+            pub fn write_from_f32_slice(&mut self, src: &[f32]) -> usize {
+                self.write_elements(src.iter().map(|value| $name::from_f32(*value)))
+            }
+        }
+    };
+}
+
+impl_fixed!(Fixed32, i32, 32);
+impl_fixed!(Fixed16, i16, 16);
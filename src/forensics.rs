@@ -0,0 +1,196 @@
+//! Heuristics to guess the layout of an unknown buffer, for debugging.
+//!
+//! [`infer_stride`] ranks candidate strides, and [`infer_float_runs`] finds the
+//! bytes of an element that look like `f32` attributes, e.g., positions. Both
+//! only look at the bytes, and are deterministic, but nothing guarantees that
+//! the best guess is the real layout.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use pas::forensics::{infer_float_runs, infer_stride};
+//!
+//! // Positions and a `u32` id, interleaved.
+//! let vertices: Vec<([f32; 3], u32)> = (0..64)
+//!     .map(|i| ([i as f32 * 0.25, 1.0, -2.0], i))
+//!     .collect();
+//! let mut bytes = Vec::new();
+//! for (position, id) in &vertices {
+//!     bytes.extend_from_slice(bytemuck::bytes_of(position));
+//!     bytes.extend_from_slice(&id.to_ne_bytes());
+//! }
+//!
+//! let strides = infer_stride(&bytes, 1..33);
+//! assert_eq!(strides[0].stride, 16);
+//! for score in &strides[..3] {
+//!     println!("{}", score);
+//! }
+//!
+//! let runs = infer_float_runs(&bytes, 16);
+//! assert_eq!(runs[0].range(), 0..12);
+//! ```
+
+use std::ops::Range;
+
+/// Score ratio above which a stride is considered explained by one of its divisors.
+const MULTIPLE_TOLERANCE: f64 = 0.9;
+
+/// Smallest fraction of elements that must decode as plausible `f32` values.
+const MIN_PLAUSIBLE_FRACTION: f64 = 0.9;
+
+/// Range of magnitudes of plausible `f32` values, zero excluded.
+const PLAUSIBLE_MAGNITUDE: std::ops::RangeInclusive<f32> = 1e-6..=1e6;
+
+/// Score of a candidate stride, returned by [`infer_stride`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StrideScore {
+    /// Stride, in **bytes**
+    pub stride: usize,
+    /// Fraction of bytes equal to the byte one stride further, from `0` to `1`
+    pub score: f64,
+    /// Smallest candidate dividing the stride, with a score of at least 90% of
+    /// this score, if any
+    ///
+    /// A buffer repeating every `n` bytes also repeats every `2 * n` bytes,
+    /// such that multiples of the real stride score as high.
+    pub multiple_of: Option<usize>,
+}
+
+impl std::fmt::Display for StrideScore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "stride {:>4}: {:.3}", self.stride, self.score)?;
+        if let Some(divisor) = self.multiple_of {
+            write!(f, " (multiple of {})", divisor)?;
+        }
+        Ok(())
+    }
+}
+
+/// Consecutive `f32` values in an element, returned by [`infer_float_runs`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FloatRun {
+    /// Byte offset of the first value, in the element
+    pub offset: usize,
+    /// Number of `f32` values
+    pub count: usize,
+    /// Lowest fraction of elements with a plausible value, over the values of the run
+    pub plausible: f64,
+}
+
+impl FloatRun {
+    /// Bytes of the run, in the element.
+    pub fn range(&self) -> Range<usize> {
+        self.offset..self.offset + self.count * 4
+    }
+}
+
+impl std::fmt::Display for FloatRun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let range = self.range();
+        write!(
+            f,
+            "bytes {}..{}: {} x f32 ({:.0}% plausible)",
+            range.start,
+            range.end,
+            self.count,
+            self.plausible * 100.0
+        )
+    }
+}
+
+/// Score each stride of `candidates` by the autocorrelation of the bytes of `data`,
+/// i.e., the fraction of bytes equal to the byte one stride further.
+///
+/// Interleaved attributes repeat their structure every element: constant
+/// attributes, padding, and the high bytes of slowly varying values are equal
+/// from one element to the next. Scores are sorted in decreasing order, after
+/// the strides that are multiples of a better candidate, see
+/// [`StrideScore::multiple_of`]. Ties are ranked by increasing stride.
+///
+/// Strides of `0`, or with fewer than two elements in `data`, are skipped.
+pub fn infer_stride(data: &[u8], candidates: Range<usize>) -> Vec<StrideScore> {
+    let scores: Vec<(usize, f64)> = candidates
+        .filter(|stride| *stride > 0 && data.len() / stride >= 2)
+        .map(|stride| (stride, autocorrelation(data, stride)))
+        .collect();
+    let mut ranked: Vec<StrideScore> = scores
+        .iter()
+        .map(|(stride, score)| StrideScore {
+            stride: *stride,
+            score: *score,
+            multiple_of: scores
+                .iter()
+                .take_while(|(divisor, _)| divisor < stride)
+                .find(|(divisor, divisor_score)| {
+                    stride % divisor == 0 && *divisor_score >= score * MULTIPLE_TOLERANCE
+                })
+                .map(|(divisor, _)| *divisor),
+        })
+        .collect();
+    ranked.sort_by(|a, b| {
+        (a.multiple_of.is_some().cmp(&b.multiple_of.is_some()))
+            .then(b.score.total_cmp(&a.score))
+            .then(a.stride.cmp(&b.stride))
+    });
+    ranked
+}
+
+/// Fraction of the bytes of the full elements of `data` equal to the byte `stride` further.
+fn autocorrelation(data: &[u8], stride: usize) -> f64 {
+    let len = (data.len() / stride) * stride;
+    let pairs = len - stride;
+    let equal = data[..pairs]
+        .iter()
+        .zip(&data[stride..len])
+        .filter(|(a, b)| a == b)
+        .count();
+    equal as f64 / pairs as f64
+}
+
+/// Find the bytes of elements of `stride` bytes that decode as plausible `f32`
+/// values in most elements of `data`.
+///
+/// A value is plausible if it's zero, or if its magnitude is within `[1e-6, 1e6]`:
+/// NaN, infinities, and subnormals aren't. Values are checked at every offset
+/// multiple of 4 bytes, and are kept if at least 90% of the elements hold a
+/// plausible value. Consecutive values are merged into runs, in offset order.
+///
+/// Only full elements are checked. Returns no run if `stride` is smaller than
+/// 4 bytes, or if `data` has no full element.
+pub fn infer_float_runs(data: &[u8], stride: usize) -> Vec<FloatRun> {
+    let count = match stride {
+        0..=3 => return Vec::new(),
+        _ => data.len() / stride,
+    };
+    if count == 0 {
+        return Vec::new();
+    }
+    let mut runs: Vec<FloatRun> = Vec::new();
+    for offset in (0..=stride - 4).step_by(4) {
+        let plausible = data
+            .chunks_exact(stride)
+            .filter(|element| is_plausible(&element[offset..offset + 4]))
+            .count() as f64
+            / count as f64;
+        if plausible < MIN_PLAUSIBLE_FRACTION {
+            continue;
+        }
+        match runs.last_mut() {
+            Some(run) if run.range().end == offset => {
+                run.count += 1;
+                run.plausible = run.plausible.min(plausible);
+            }
+            _ => runs.push(FloatRun {
+                offset,
+                count: 1,
+                plausible,
+            }),
+        }
+    }
+    runs
+}
+
+fn is_plausible(bytes: &[u8]) -> bool {
+    let value = f32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    value == 0.0 || PLAUSIBLE_MAGNITUDE.contains(&value.abs())
+}
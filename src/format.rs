@@ -0,0 +1,187 @@
+use core::fmt::{Debug, Write};
+
+use crate::SliceInfo;
+
+/// Replaces elements longer than the whole budget of [`SliceInfo::fmt_truncated`].
+const PLACEHOLDER: &str = "<…>";
+
+/// Writer counting bytes, to measure an element without allocating.
+struct ByteCounter(usize);
+
+impl Write for ByteCounter {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// Number of bytes of the representation of `value`.
+fn rendered_len(value: impl core::fmt::Display) -> Result<usize, core::fmt::Error> {
+    let mut counter = ByteCounter(0);
+    write!(counter, "{}", value)?;
+    Ok(counter.0)
+}
+
+/// Number of bytes of `… (+{remaining} more)]`.
+fn suffix_len(remaining: usize) -> usize {
+    // `rendered_len` can't fail on an integer.
+    "… (+ more)]".len() + rendered_len(remaining).unwrap_or(0)
+}
+
+impl<Attr: Debug> SliceInfo<Attr> {
+    /// Write the [`Debug`] representation of the element at index `index` into `out`.
+    ///
+    /// Nothing is allocated, which allows logging elements into fixed-size buffers.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`core::fmt::Error`] if `index` is out of bounds, or if `out` fails.
+    pub fn format_element(&self, index: usize, out: &mut impl Write) -> core::fmt::Result {
+        let element = self.get(index).ok_or(core::fmt::Error)?;
+        write!(out, "{:?}", element)
+    }
+
+    /// Write the first `n` elements into `out`, as a list.
+    ///
+    /// Nothing is allocated. If the slice has more than `n` elements, the list
+    /// ends with `...`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[0_u32, 1], [2, 3], [4, 5]];
+    /// let slice: Slice<[u32; 2]> = Slice::native(&data);
+    ///
+    /// let mut out = String::new();
+    /// slice.format_prefix(2, &mut out).unwrap();
+    /// assert_eq!(out, "[[0, 1], [2, 3], ...]");
+    /// ```
+    pub fn format_prefix(&self, n: usize, out: &mut impl Write) -> core::fmt::Result {
+        let count = n.min(self.len());
+        out.write_char('[')?;
+        for index in 0..count {
+            if index > 0 {
+                out.write_str(", ")?;
+            }
+            self.format_element(index, out)?;
+        }
+        match (count < self.len(), count) {
+            (true, 0) => out.write_str("...]"),
+            (true, _) => out.write_str(", ...]"),
+            (false, _) => out.write_char(']'),
+        }
+    }
+
+    /// Write the elements into `out`, as a list of at most `max_bytes` bytes.
+    ///
+    /// Elements are written one by one, and the list stops at the first element
+    /// that doesn't fit, ending with `… (+N more)` where `N` is the number of
+    /// elements left out. Elements are never cut.
+    ///
+    /// The budget counts every byte of the output, in UTF-8: brackets, separators,
+    /// and the `… (+N more)` suffix. An element is only written if the suffix still
+    /// fits after it, unless the whole list fits. The only output exceeding `max_bytes` is the shortest one,
+    /// `[… (+N more)]`, written in full when the budget is smaller.
+    ///
+    /// An element whose representation alone is longer than `max_bytes`, e.g., a
+    /// large array, is replaced by the `<…>` placeholder.
+    ///
+    /// Nothing is allocated: each element is formatted twice, once to measure it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [10_u32, 20, 30, 40, 50, 60, 70, 80];
+    /// let slice: Slice<u32> = Slice::native(&data);
+    ///
+    /// let mut out = String::new();
+    /// slice.fmt_truncated(&mut out, 24).unwrap();
+    /// assert_eq!(out, "[10, 20, … (+6 more)]");
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`core::fmt::Error`] if `out`, or the [`Debug`] implementation, fails.
+    pub fn fmt_truncated(&self, out: &mut impl Write, max_bytes: usize) -> core::fmt::Result {
+        let len = self.len();
+        let mut written = 1;
+        out.write_char('[')?;
+        for index in 0..len {
+            let element = self.get(index).ok_or(core::fmt::Error)?;
+            let size = rendered_len(format_args!("{:?}", element))?;
+            let placeholder = size > max_bytes;
+            let size = if placeholder { PLACEHOLDER.len() } else { size };
+            let separator = if index > 0 { ", ".len() } else { 0 };
+            let remaining = len - index - 1;
+            let end = match remaining {
+                0 => "]".len(),
+                _ => ", ".len() + suffix_len(remaining),
+            };
+            if written + separator + size + end > max_bytes
+                && !self.rest_fits(index, max_bytes, max_bytes.saturating_sub(written))?
+            {
+                if index > 0 {
+                    out.write_str(", ")?;
+                }
+                return write!(out, "… (+{} more)]", len - index);
+            }
+            if index > 0 {
+                out.write_str(", ")?;
+            }
+            match placeholder {
+                true => out.write_str(PLACEHOLDER)?,
+                false => write!(out, "{:?}", element)?,
+            }
+            written += separator + size;
+        }
+        out.write_char(']')
+    }
+
+    /// Whether the elements from `start` fit in `available` bytes, closing
+    /// bracket included, without a suffix.
+    fn rest_fits(
+        &self,
+        start: usize,
+        max_bytes: usize,
+        available: usize,
+    ) -> Result<bool, core::fmt::Error> {
+        let mut needed = "]".len();
+        for index in start..self.len() {
+            let element = self.get(index).ok_or(core::fmt::Error)?;
+            let size = match rendered_len(format_args!("{:?}", element))? {
+                size if size > max_bytes => PLACEHOLDER.len(),
+                size => size,
+            };
+            needed += size + if index > 0 { ", ".len() } else { 0 };
+            if needed > available {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Render the elements as a list of at most `max_bytes` bytes.
+    ///
+    /// Allocating version of [`Self::fmt_truncated`], which describes the budget.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[0_u8; 64], [1; 64]];
+    /// let slice: Slice<[u8; 64]> = Slice::native(&data);
+    /// assert_eq!(slice.render_truncated(32), "[<…>, <…>]");
+    /// assert_eq!(slice.render_truncated(8), "[… (+2 more)]");
+    /// ```
+    pub fn render_truncated(&self, max_bytes: usize) -> String {
+        let mut out = String::new();
+        self.fmt_truncated(&mut out, max_bytes)
+            .expect("a Debug implementation returned an error unexpectedly");
+        out
+    }
+}
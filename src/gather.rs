@@ -0,0 +1,222 @@
+use bytemuck::Pod;
+
+use crate::{private, SliceError, SliceInfo, SliceMut};
+
+/// Number of indices validated at once, such that computing their maximum vectorizes.
+pub(crate) const VALIDATION_CHUNK: usize = 256;
+
+/// Integer type used in index lists, i.e., `u8`, `u16`, `u32`, `u64`, or `usize`.
+///
+/// This trait is sealed and can't be implemented outside of this crate.
+pub trait IndexElement: Pod + private::Sealed {
+    /// Widen to `u64`, used for validation.
+    fn to_u64(self) -> u64;
+
+    /// Convert to `usize`, or `None` if the index doesn't fit.
+    fn to_usize(self) -> Option<usize> {
+        usize::try_from(self.to_u64()).ok()
+    }
+
+    /// Minimum of both indices, used by [`SliceMut::clamp_indices`].
+    #[doc(hidden)]
+    fn min_index(self, other: Self) -> Self;
+
+    /// Remainder of the division by `modulus`, used by [`SliceMut::wrap_indices`].
+    ///
+    /// `modulus` must not be zero.
+    #[doc(hidden)]
+    fn rem_index(self, modulus: Self) -> Self;
+}
+
+macro_rules! impl_index_element {
+    ($($t: ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+            impl IndexElement for $t {
+                #[inline(always)]
+                fn to_u64(self) -> u64 {
+                    self as u64
+                }
+                #[inline(always)]
+                fn min_index(self, other: Self) -> Self {
+                    self.min(other)
+                }
+                #[inline(always)]
+                fn rem_index(self, modulus: Self) -> Self {
+                    self % modulus
+                }
+            }
+        )*
+    };
+}
+
+impl_index_element!(u8, u16, u32, u64, usize);
+
+/// Check that every index in `indices` is lower than `len`.
+pub(crate) fn check_indices<I: IndexElement>(indices: &[I], len: usize) -> Result<(), SliceError> {
+    // Lossless, and any index lower than `len` fits in a `usize`.
+    let max_len = len as u64;
+    for (chunk_index, chunk) in indices.chunks(VALIDATION_CHUNK).enumerate() {
+        let max = chunk.iter().fold(0, |max, i| max.max(i.to_u64()));
+        if max < max_len {
+            continue;
+        }
+        let position = chunk.iter().position(|i| i.to_u64() >= max_len).unwrap();
+        return Err(SliceError::IndexOutOfBounds {
+            position: chunk_index * VALIDATION_CHUNK + position,
+            index: chunk[position].to_u64(),
+            len,
+        });
+    }
+    Ok(())
+}
+
+impl<Attr: Pod> SliceInfo<Attr> {
+    /// Check that every index in `indices` is in bounds.
+    ///
+    /// Indices are validated in chunks, computing the maximum of each chunk,
+    /// rather than branching on each index.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{Slice, SliceError};
+    ///
+    /// let data = [0_u32, 1, 2];
+    /// let slice: Slice<u32> = Slice::native(&data);
+    /// assert!(slice.check_indices(&[2_u64, 0, 1]).is_ok());
+    /// assert_eq!(
+    ///     slice.check_indices(&[2_u64, 3, 1]),
+    ///     Err(SliceError::IndexOutOfBounds { position: 1, index: 3, len: 3 })
+    /// );
+    /// ```
+    pub fn check_indices<I: IndexElement>(&self, indices: &[I]) -> Result<(), SliceError> {
+        check_indices(indices, self.len())
+    }
+
+    /// Read the element at an index validated by [`Self::check_indices`].
+    #[inline(always)]
+    pub(crate) fn get_checked<I: IndexElement>(&self, index: I) -> Attr {
+        let offset = index.to_u64() as usize * self.stride();
+        // Safe because the index was validated, and is thus in bounds.
+        unsafe { *self.base().start.add(offset).cast::<Attr>() }
+    }
+
+    /// Copy the elements at `indices` into `dst`.
+    ///
+    /// Only the common prefix of `indices` and `dst` is processed, i.e.,
+    /// `min(indices.len(), dst.len())` elements.
+    ///
+    /// Returns the number of elements written.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{Slice, SliceMut};
+    ///
+    /// let data = [[0_u32, 10], [1, 11], [2, 12]];
+    /// let slice: Slice<u32> = Slice::new(&data, 4);
+    ///
+    /// let mut out = [0_u32; 4];
+    /// let count = slice.gather_into(&[2_u16, 0, 0, 1], &mut SliceMut::native(&mut out));
+    /// assert_eq!(count, Ok(4));
+    /// assert_eq!(out, [12, 10, 10, 11]);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::IndexOutOfBounds`] for the first out-of-bounds index,
+    /// without writing anything.
+    pub fn gather_into<I: IndexElement>(
+        &self,
+        indices: &[I],
+        dst: &mut SliceMut<'_, Attr>,
+    ) -> Result<usize, SliceError> {
+        let indices = &indices[..indices.len().min(dst.len())];
+        self.check_indices(indices)?;
+        Ok(dst.write_elements(indices.iter().map(|index| self.get_checked(*index))))
+    }
+
+    /// Collect the elements at `indices`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::IndexOutOfBounds`] for the first out-of-bounds index.
+    pub fn gather<I: IndexElement>(&self, indices: &[I]) -> Result<Vec<Attr>, SliceError> {
+        self.check_indices(indices)?;
+        Ok(indices
+            .iter()
+            .map(|index| self.get_checked(*index))
+            .collect())
+    }
+
+    /// Iterate over triangles, where each triangle is made of three consecutive indices.
+    ///
+    /// Trailing indices that don't form a triangle are ignored.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let positions = [[0.0_f32, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0]];
+    /// let slice: Slice<[f32; 2]> = Slice::native(&positions);
+    /// let indices = [0_u32, 1, 2, 2, 1, 3];
+    /// for [a, b, c] in slice.gather_triangles(&indices).unwrap() {
+    ///     println!("{:?} {:?} {:?}", a, b, c);
+    /// }
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::IndexOutOfBounds`] for the first out-of-bounds index.
+    pub fn gather_triangles<'s, I: IndexElement>(
+        &'s self,
+        indices: &'s [I],
+    ) -> Result<impl Iterator<Item = [Attr; 3]> + 's, SliceError> {
+        let indices = &indices[..indices.len() - indices.len() % 3];
+        self.check_indices(indices)?;
+        Ok(indices
+            .chunks_exact(3)
+            .map(move |t| [0, 1, 2].map(|k| self.get_checked(t[k]))))
+    }
+}
+
+impl<'a, Attr: Pod> SliceMut<'a, Attr> {
+    /// Copy each element of `src` at the index in `indices` at the same position.
+    ///
+    /// This is the inverse of [`crate::SliceInfo::gather_into`]. Only the common
+    /// prefix of `indices` and `src` is processed. When an index appears multiple
+    /// times, the last write wins.
+    ///
+    /// Returns the number of elements written.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{Slice, SliceMut};
+    ///
+    /// let mut data = [0_u32; 4];
+    /// let mut slice: SliceMut<u32> = SliceMut::native(&mut data);
+    /// let src = [7_u32, 8];
+    /// slice.scatter_from(&[3_u8, 1], &Slice::native(&src)).unwrap();
+    /// assert_eq!(data, [0, 8, 0, 7]);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::IndexOutOfBounds`] for the first out-of-bounds index,
+    /// without writing anything.
+    pub fn scatter_from<I: IndexElement>(
+        &mut self,
+        indices: &[I],
+        src: &SliceInfo<Attr>,
+    ) -> Result<usize, SliceError> {
+        let indices = &indices[..indices.len().min(src.len())];
+        self.check_indices(indices)?;
+        for (i, index) in indices.iter().enumerate() {
+            self[index.to_u64() as usize] = *src.get(i).unwrap();
+        }
+        Ok(indices.len())
+    }
+}
@@ -0,0 +1,134 @@
+use bytemuck::Pod;
+
+use crate::policy::Strict;
+use crate::shared_impl::SliceBase;
+use crate::slice_mut::mut_range;
+use crate::{Slice, SliceError, SliceMut};
+
+/// Check that `data` holds a header of `header_len` bytes.
+fn check_header(data: &[u8], header_len: usize) -> Result<(), SliceError> {
+    match data.len() {
+        size if size >= header_len => Ok(()),
+        size => Err(SliceError::OffsetOutOfBounds {
+            size,
+            offset: header_len,
+        }),
+    }
+}
+
+impl<'a, T: Pod> Slice<'a, T> {
+    /// Read a header of type `H` from the front of `data`, and create a view
+    /// over the remaining bytes.
+    ///
+    /// The header is copied, such that it doesn't need to be aligned. Similar
+    /// to [`Self::raw`], `attr_byte_offset` and `byte_stride` are in **bytes**, but the
+    /// offset is relative to the **end of the header**.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// #[repr(C)]
+    /// #[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+    /// struct Header {
+    ///     count: u32,
+    ///     stride: u32,
+    /// }
+    ///
+    /// let data: [u32; 6] = [2, 8, 10, 11, 20, 21];
+    /// let bytes: &[u8] = bytemuck::cast_slice(&data);
+    /// let (header, ids) = Slice::<u32>::after_header::<Header>(bytes, 4, 8).unwrap();
+    /// assert_eq!(header, Header { count: 2, stride: 8 });
+    /// assert!(ids.iter().eq([11, 21].iter()));
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::OffsetOutOfBounds`] if `data` is smaller than the header,
+    /// and otherwise returns the errors [`Self::raw`] panics with, for the remaining bytes.
+    pub fn after_header<H: Pod>(
+        data: &'a [u8],
+        attr_byte_offset: usize,
+        byte_stride: usize,
+    ) -> Result<(H, Self), SliceError> {
+        let (header, slice) = Self::after_opaque_header(
+            data,
+            std::mem::size_of::<H>(),
+            attr_byte_offset,
+            byte_stride,
+        )?;
+        Ok((bytemuck::pod_read_unaligned(header), slice))
+    }
+
+    /// Similar to [`Self::after_header`], but for a header of `header_len` bytes,
+    /// returned as is.
+    ///
+    /// ## Errors
+    ///
+    /// See [`Self::after_header`].
+    pub fn after_opaque_header(
+        data: &'a [u8],
+        header_len: usize,
+        attr_byte_offset: usize,
+        byte_stride: usize,
+    ) -> Result<(&'a [u8], Self), SliceError> {
+        check_header(data, header_len)?;
+        let (header, data) = data.split_at(header_len);
+        let inner = SliceBase::new_with_policy::<Strict>(
+            data.as_ptr_range(),
+            attr_byte_offset,
+            byte_stride,
+            data.len(),
+            None,
+        )?;
+        Ok((header, Self::from_base(inner)))
+    }
+}
+
+impl<'a, T: Pod> SliceMut<'a, T> {
+    /// Mutable version of [`Slice::after_header`].
+    ///
+    /// The view only covers the bytes after the header, which are left untouched.
+    ///
+    /// ## Errors
+    ///
+    /// See [`Slice::after_header`].
+    pub fn after_header<H: Pod>(
+        data: &'a mut [u8],
+        attr_byte_offset: usize,
+        byte_stride: usize,
+    ) -> Result<(H, Self), SliceError> {
+        let (header, slice) = Self::after_opaque_header(
+            data,
+            std::mem::size_of::<H>(),
+            attr_byte_offset,
+            byte_stride,
+        )?;
+        Ok((bytemuck::pod_read_unaligned(header), slice))
+    }
+
+    /// Mutable version of [`Slice::after_opaque_header`].
+    ///
+    /// ## Errors
+    ///
+    /// See [`Slice::after_header`].
+    pub fn after_opaque_header(
+        data: &'a mut [u8],
+        header_len: usize,
+        attr_byte_offset: usize,
+        byte_stride: usize,
+    ) -> Result<(&'a mut [u8], Self), SliceError> {
+        check_header(data, header_len)?;
+        let (header, data) = data.split_at_mut(header_len);
+        let len = data.len();
+        let inner = SliceBase::new_with_policy::<Strict>(
+            mut_range(data),
+            attr_byte_offset,
+            byte_stride,
+            len,
+            None,
+        )?;
+        Ok((header, Self::from_base(inner)))
+    }
+}
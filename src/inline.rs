@@ -0,0 +1,91 @@
+//! Small buffer optimization for the owned types, see [`crate::OwnedStrided`]
+//! and [`crate::OwnedView`].
+
+use crate::AlignedBytes;
+
+/// Largest number of bytes stored inline, without allocating.
+pub(crate) const INLINE_CAPACITY: usize = 64;
+
+/// Alignment of the inline bytes, in **bytes**.
+pub(crate) const INLINE_ALIGN: usize = 16;
+
+/// Bytes stored inline, aligned to [`INLINE_ALIGN`].
+#[repr(C, align(16))]
+#[derive(Clone, Copy)]
+pub(crate) struct InlineBytes(pub(crate) [u8; INLINE_CAPACITY]);
+
+// `align(16)` can't refer to the constant.
+const _: () = assert!(std::mem::align_of::<InlineBytes>() == INLINE_ALIGN);
+
+impl InlineBytes {
+    pub(crate) const fn zeroed() -> Self {
+        Self([0; INLINE_CAPACITY])
+    }
+}
+
+/// Byte buffer aligned to 16 bytes, stored inline up to [`INLINE_CAPACITY`] bytes.
+///
+/// The representation is chosen from the length: growing an inline buffer past
+/// [`INLINE_CAPACITY`] moves it to the heap, and it then stays there.
+#[derive(Clone)]
+pub(crate) enum SmallBytes {
+    Inline { bytes: InlineBytes, len: usize },
+    Heap(AlignedBytes),
+}
+
+impl SmallBytes {
+    /// Create a buffer of `len` zeroed bytes.
+    pub(crate) fn zeroed(len: usize) -> Self {
+        match len {
+            0..=INLINE_CAPACITY => Self::Inline {
+                bytes: InlineBytes::zeroed(),
+                len,
+            },
+            _ => Self::Heap(AlignedBytes::zeroed(len, INLINE_ALIGN)),
+        }
+    }
+
+    /// `true` if the bytes are stored inline, `false` otherwise
+    pub(crate) fn is_inline(&self) -> bool {
+        matches!(self, Self::Inline { .. })
+    }
+
+    /// Resize the buffer to `new_len` bytes, filling new bytes with `value`.
+    pub(crate) fn resize(&mut self, new_len: usize, value: u8) {
+        match self {
+            Self::Inline { bytes, len } if new_len <= INLINE_CAPACITY => {
+                if new_len > *len {
+                    bytes.0[*len..new_len].fill(value);
+                }
+                *len = new_len;
+            }
+            Self::Inline { bytes, len } => {
+                let mut heap = AlignedBytes::with_capacity_aligned(new_len, INLINE_ALIGN);
+                heap.extend_from_slice(&bytes.0[..*len]);
+                heap.resize(new_len, value);
+                *self = Self::Heap(heap);
+            }
+            Self::Heap(heap) => heap.resize(new_len, value),
+        }
+    }
+}
+
+impl std::ops::Deref for SmallBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Self::Inline { bytes, len } => &bytes.0[..*len],
+            Self::Heap(heap) => heap,
+        }
+    }
+}
+
+impl std::ops::DerefMut for SmallBytes {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        match self {
+            Self::Inline { bytes, len } => &mut bytes.0[..*len],
+            Self::Heap(heap) => heap,
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use bytemuck::Pod;
+
+use crate::math::checked_next_multiple_of;
+use crate::{OwnedView, SliceError, ViewDesc};
+
+/// Alignment of the buffers allocated by [`OwnedView`].
+const BUFFER_ALIGN: usize = 16;
+
+/// Attribute source registered in an [`InterleaveBuilder`].
+struct Source<'a, K> {
+    key: Option<K>,
+    bytes: &'a [u8],
+    type_name: &'static str,
+    size: usize,
+    align: usize,
+    count: usize,
+}
+
+/// Interleaved layout of attributes with the given `(size, align)`.
+///
+/// Attributes are placed in order, each one aligned, like a `#[repr(C)]` struct.
+/// Returns the offset of each attribute, and the stride.
+fn compute_interleaved(
+    attributes: impl Iterator<Item = (usize, usize)>,
+) -> Result<(Vec<usize>, usize), SliceError> {
+    let mut offsets = Vec::new();
+    let (mut end, mut max_align) = (0_usize, 1);
+    for (size, align) in attributes {
+        let offset = checked_next_multiple_of(end, align).ok_or(SliceError::Overflow)?;
+        offsets.push(offset);
+        end = offset.checked_add(size).ok_or(SliceError::Overflow)?;
+        max_align = max_align.max(align);
+    }
+    let stride = checked_next_multiple_of(end, max_align).ok_or(SliceError::Overflow)?;
+    Ok((offsets, stride))
+}
+
+/// Assemble an interleaved buffer from separate attribute arrays.
+///
+/// Attributes are laid out in registration order, each one aligned, like
+/// a `#[repr(C)]` struct. The result is an [`OwnedView`] owning the buffer, with
+/// one [`ViewDesc`] per attribute.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::InterleaveBuilder;
+///
+/// let positions = [[0.0_f32, 0.0, 0.0], [1.0, 0.0, 0.0]];
+/// let colors = [[255_u8, 0, 0, 255], [0, 255, 0, 255]];
+///
+/// let view = InterleaveBuilder::keyed()
+///     .insert("position", &positions)
+///     .insert("color", &colors)
+///     .build()
+///     .unwrap();
+/// assert_eq!(view.bytes().len(), 32);
+///
+/// let colors = view.view::<[u8; 4]>(view.find(&"color").unwrap()).unwrap();
+/// assert_eq!(colors[1], [0, 255, 0, 255]);
+/// ```
+pub struct InterleaveBuilder<'a, K = ()> {
+    sources: Vec<Source<'a, K>>,
+}
+
+impl<'a> InterleaveBuilder<'a> {
+    /// Create a builder without any attribute.
+    ///
+    /// Use [`Self::keyed`] to register attributes with keys.
+    pub fn new() -> Self {
+        Self::keyed()
+    }
+}
+
+impl<'a, K> InterleaveBuilder<'a, K> {
+    /// Create a builder without any attribute, where attributes can have a key of type `K`.
+    pub fn keyed() -> Self {
+        Self {
+            sources: Vec::new(),
+        }
+    }
+
+    /// Add an attribute without key.
+    pub fn push<T: Pod>(self, data: &'a [T]) -> Self {
+        self.add_source(None, data)
+    }
+
+    /// Add an attribute with key `key`, see [`OwnedView::find`].
+    pub fn insert<T: Pod>(self, key: K, data: &'a [T]) -> Self {
+        self.add_source(Some(key), data)
+    }
+
+    fn add_source<T: Pod>(mut self, key: Option<K>, data: &'a [T]) -> Self {
+        self.sources.push(Source {
+            key,
+            bytes: bytemuck::cast_slice(data),
+            type_name: std::any::type_name::<T>(),
+            size: std::mem::size_of::<T>(),
+            align: std::mem::align_of::<T>(),
+            count: data.len(),
+        });
+        self
+    }
+
+    /// Interleave the attributes into a new buffer.
+    ///
+    /// ## Errors
+    ///
+    /// - [`SliceError::NoAttribute`] if no attribute was added
+    /// - [`SliceError::CountMismatch`] if attributes don't have the same number of elements
+    /// - [`SliceError::AlignmentFault`] if an attribute requires an alignment larger than 16 bytes
+    /// - [`SliceError::Overflow`] if the buffer size overflows
+    pub fn build(self) -> Result<OwnedView<K>, SliceError> {
+        let count = self.sources.first().ok_or(SliceError::NoAttribute)?.count;
+        for (index, source) in self.sources.iter().enumerate() {
+            if source.count != count {
+                return Err(SliceError::CountMismatch {
+                    index,
+                    expected: count,
+                    count: source.count,
+                });
+            }
+            if source.align > BUFFER_ALIGN {
+                return Err(SliceError::AlignmentFault {
+                    type_name: source.type_name,
+                    offset: 0,
+                });
+            }
+        }
+
+        let (offsets, stride) =
+            compute_interleaved(self.sources.iter().map(|s| (s.size, s.align)))?;
+        let len = stride
+            .checked_mul(count)
+            .filter(|len| *len <= isize::MAX as usize)
+            .ok_or(SliceError::Overflow)?;
+
+        let mut view = OwnedView::zeroed(len);
+        // Write each element in turn, such that the output is written sequentially.
+        let elements = view.bytes_mut().chunks_exact_mut(stride.max(1));
+        for (i, element) in elements.enumerate() {
+            for (source, offset) in self.sources.iter().zip(&offsets) {
+                let src = &source.bytes[i * source.size..(i + 1) * source.size];
+                element[*offset..offset + source.size].copy_from_slice(src);
+            }
+        }
+
+        for (source, offset) in self.sources.into_iter().zip(offsets) {
+            let desc = ViewDesc::new(offset, stride, count);
+            match source.key {
+                Some(key) => view.insert(key, desc),
+                None => view.push(desc),
+            };
+        }
+        Ok(view)
+    }
+}
+
+//
+// Traits implementation
+//
+
+impl<'a, K> Default for InterleaveBuilder<'a, K> {
+    fn default() -> Self {
+        Self::keyed()
+    }
+}
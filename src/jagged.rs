@@ -0,0 +1,230 @@
+use bytemuck::Pod;
+use std::fmt::Debug;
+use std::ops::Range;
+
+use crate::shared_impl::SliceBase;
+use crate::{Slice, SliceError, SliceMut};
+
+/// Check that `offsets` never decreases, and that no offset exceeds `len`.
+fn validate(offsets: &Slice<u32>, len: usize) -> Result<(), SliceError> {
+    let mut previous = 0;
+    for (position, offset) in offsets.iter().enumerate() {
+        let offset = *offset as usize;
+        if offset > len {
+            return Err(SliceError::IndexOutOfBounds {
+                position,
+                index: offset as u64,
+                len,
+            });
+        }
+        if position > 0 && offset < previous {
+            return Err(SliceError::DecreasingOffset {
+                position,
+                offset,
+                previous,
+            });
+        }
+        previous = offset;
+    }
+    Ok(())
+}
+
+/// Range of elements of the group at index `index`.
+///
+/// ## Panics
+///
+/// Panics if there is no group at index `index`.
+fn group_range(offsets: &Slice<u32>, index: usize) -> Range<usize> {
+    assert!(
+        index < offsets.len().saturating_sub(1),
+        "index ouf of bounds"
+    );
+    offsets[index] as usize..offsets[index + 1] as usize
+}
+
+/// Elements of `base` in `range`.
+fn window<T: Pod>(base: &SliceBase<T>, range: Range<usize>) -> SliceBase<T> {
+    let (_, right) = base.split_at(range.start);
+    let (window, _) = right.split_at(range.end - range.start);
+    window
+}
+
+/// Read-only view over groups of consecutive elements, delimited by an offsets table.
+///
+/// Group `k` is made of the elements in `offsets[k]..offsets[k + 1]`, such
+/// that `offsets` has one more entry than there are groups, e.g., the prefix
+/// sums of the group lengths. Groups can be empty, and elements before the
+/// first offset, or after the last one, belong to no group.
+///
+/// Offsets are validated once, when the view is created.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{JaggedView, Slice};
+///
+/// let vertices = [[0.0_f32; 3], [1.0; 3], [2.0; 3], [3.0; 3], [4.0; 3]];
+/// let offsets = [0_u32, 2, 2, 5];
+/// let meshlets = JaggedView::new(Slice::native(&vertices), Slice::native(&offsets)).unwrap();
+///
+/// assert_eq!(meshlets.group_count(), 3);
+/// assert_eq!(meshlets.group_len(1), 0);
+/// assert_eq!(meshlets.group(2)[0], [2.0; 3]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct JaggedView<'a, T: Pod> {
+    data: Slice<'a, T>,
+    offsets: Slice<'a, u32>,
+}
+
+impl<'a, T: Pod> JaggedView<'a, T> {
+    /// Group the elements of `data` with the offsets table `offsets`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error naming the first invalid offset:
+    /// - [`SliceError::IndexOutOfBounds`] if an offset is larger than the length
+    ///   of `data`, with the position of the offset in the table
+    /// - [`SliceError::DecreasingOffset`] if an offset is smaller than the previous one
+    pub fn new(data: Slice<'a, T>, offsets: Slice<'a, u32>) -> Result<Self, SliceError> {
+        validate(&offsets, data.len())?;
+        Ok(Self { data, offsets })
+    }
+
+    /// Grouped elements.
+    pub fn data(&self) -> Slice<'a, T> {
+        self.data
+    }
+
+    /// Offsets table.
+    pub fn offsets(&self) -> Slice<'a, u32> {
+        self.offsets
+    }
+
+    /// Number of groups, i.e., the number of offsets minus one.
+    pub fn group_count(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    /// Number of elements of the group at index `index`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if there is no group at index `index`.
+    pub fn group_len(&self, index: usize) -> usize {
+        group_range(&self.offsets, index).len()
+    }
+
+    /// Elements of the group at index `index`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if there is no group at index `index`.
+    pub fn group(&self, index: usize) -> Slice<'a, T> {
+        let range = group_range(&self.offsets, index);
+        Slice::from_base(window(self.data.base(), range))
+    }
+
+    /// Iterate over the groups.
+    pub fn groups(&self) -> impl ExactSizeIterator<Item = Slice<'a, T>> {
+        let view = *self;
+        (0..self.group_count()).map(move |index| view.group(index))
+    }
+}
+
+impl<'a, T: Pod + Debug> Debug for JaggedView<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.groups()).finish()
+    }
+}
+
+/// Mutable view over groups of consecutive elements, delimited by an offsets table.
+///
+/// See [`JaggedView`] for the layout. Since offsets never decrease, groups never
+/// share an element, and can be written at the same time with [`Self::groups_mut`].
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{JaggedViewMut, Slice, SliceMut};
+///
+/// let mut ids = [0_u32; 5];
+/// let offsets = [0_u32, 2, 5];
+/// let mut meshlets = JaggedViewMut::new(SliceMut::native(&mut ids), Slice::native(&offsets)).unwrap();
+/// for (meshlet, mut group) in meshlets.groups_mut().enumerate() {
+///     group.fill(meshlet as u32);
+/// }
+/// assert_eq!(ids, [0, 0, 1, 1, 1]);
+/// ```
+pub struct JaggedViewMut<'a, T: Pod> {
+    data: SliceMut<'a, T>,
+    offsets: Slice<'a, u32>,
+}
+
+impl<'a, T: Pod> JaggedViewMut<'a, T> {
+    /// Group the elements of `data` with the offsets table `offsets`.
+    ///
+    /// ## Errors
+    ///
+    /// See [`JaggedView::new`].
+    pub fn new(data: SliceMut<'a, T>, offsets: Slice<'a, u32>) -> Result<Self, SliceError> {
+        validate(&offsets, data.len())?;
+        Ok(Self { data, offsets })
+    }
+
+    /// Offsets table.
+    pub fn offsets(&self) -> Slice<'a, u32> {
+        self.offsets
+    }
+
+    /// Number of groups, i.e., the number of offsets minus one.
+    pub fn group_count(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    /// Number of elements of the group at index `index`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if there is no group at index `index`.
+    pub fn group_len(&self, index: usize) -> usize {
+        group_range(&self.offsets, index).len()
+    }
+
+    /// Elements of the group at index `index`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if there is no group at index `index`.
+    pub fn group(&self, index: usize) -> Slice<'_, T> {
+        let range = group_range(&self.offsets, index);
+        Slice::from_base(window(self.data.base(), range))
+    }
+
+    /// Mutable elements of the group at index `index`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if there is no group at index `index`.
+    pub fn group_mut(&mut self, index: usize) -> SliceMut<'_, T> {
+        let range = group_range(&self.offsets, index);
+        SliceMut::from_base(window(self.data.base(), range))
+    }
+
+    /// Iterate over the mutable groups.
+    pub fn groups_mut(&mut self) -> impl ExactSizeIterator<Item = SliceMut<'_, T>> {
+        let (base, offsets) = (*self.data.base(), self.offsets);
+        // Groups are disjoint, since offsets never decrease, and are all
+        // borrowed from `self`.
+        (0..self.group_count())
+            .map(move |index| SliceMut::from_base(window(&base, group_range(&offsets, index))))
+    }
+
+    /// Convert into a read-only view.
+    pub fn into_view(self) -> JaggedView<'a, T> {
+        JaggedView {
+            data: Slice::from_base(*self.data.base()),
+            offsets: self.offsets,
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use std::fmt::Write;
+
+use crate::split::check_windows_disjoint;
+use crate::StridedInfo;
+
+/// Byte of the map not covered by any view.
+const PADDING: char = '.';
+/// Byte of the map covered by more than one view.
+const OVERLAP: char = '#';
+
+/// Render an ASCII map of the bytes of one element of `stride` bytes, showing
+/// the bytes read by each of the named `views`.
+///
+/// Each view is drawn with a letter, by default the first letter of its name.
+/// Bytes covered by no view are drawn as `.`, and bytes covered by multiple views
+/// as `#`. The map is followed by the byte offsets of each run, and a legend with
+/// a summary of each view: offset in the element, size, and type if known.
+///
+/// Views are expected to be over the same buffer. No map is drawn if `stride` is `0`.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{render_layout, Slice};
+///
+/// let data = [[0.0_f32; 6]; 2];
+/// let positions: Slice<[f32; 3]> = Slice::new(&data, 0);
+/// let uvs: Slice<[f32; 2]> = Slice::new(&data, 12);
+/// let map = render_layout(&[("position", &positions), ("uv", &uvs)], 24);
+/// assert_eq!(
+///     map,
+///     "\
+/// stride: 24 bytes
+/// |PPPPPPPPPPPP|UUUUUUUU|....|
+/// 0            12       20   24
+/// P  position  offset 0   size 12  [f32; 3]
+/// U  uv        offset 12  size 8   [f32; 2]
+/// .  padding   4 bytes
+/// "
+/// );
+/// ```
+pub fn render_layout(views: &[(&str, &dyn StridedInfo)], stride: usize) -> String {
+    let letters = letters(views);
+    let windows: Vec<(usize, usize)> = views
+        .iter()
+        .map(|(_, view)| match stride {
+            0 => (view.byte_offset(), view.element_size()),
+            _ => (view.byte_offset() % stride, view.element_size()),
+        })
+        .collect();
+
+    // Views covering each byte of the element.
+    let mut owners = vec![Vec::new(); stride];
+    for (index, (offset, size)) in windows.iter().enumerate() {
+        for byte in 0..(*size).min(stride) {
+            owners[(offset + byte) % stride].push(index);
+        }
+    }
+
+    let mut out = String::new();
+    let _ = writeln!(out, "stride: {} bytes", stride);
+    if stride > 0 {
+        let (map, ruler) = map_and_ruler(&owners, &letters);
+        let _ = writeln!(out, "{}", map);
+        let _ = writeln!(out, "{}", ruler);
+    }
+
+    let name_width = views
+        .iter()
+        .map(|(name, _)| name.len())
+        .chain(["padding".len(), "overlap".len()])
+        .max()
+        .unwrap_or(0);
+    let offset_width = windows.iter().map(|w| w.0.to_string().len()).max();
+    let size_width = windows.iter().map(|w| w.1.to_string().len()).max();
+    for (index, (name, view)) in views.iter().enumerate() {
+        let (offset, size) = windows[index];
+        let line = format!(
+            "{}  {:name$}  offset {:<offset$}  size {:<size$}  {}",
+            letters[index],
+            name,
+            offset,
+            size,
+            view.type_name().unwrap_or(""),
+            name = name_width,
+            offset = offset_width.unwrap_or(0),
+            size = size_width.unwrap_or(0),
+        );
+        let _ = writeln!(out, "{}", line.trim_end());
+    }
+
+    let padding = owners.iter().filter(|owners| owners.is_empty()).count();
+    if padding > 0 {
+        let _ = writeln!(
+            out,
+            "{}  {:name$}  {} bytes",
+            PADDING,
+            "padding",
+            padding,
+            name = name_width
+        );
+    }
+    for (i, first) in windows.iter().enumerate() {
+        for (j, second) in windows.iter().enumerate().skip(i + 1) {
+            if check_windows_disjoint(stride, *first, *second).is_err() {
+                let _ = writeln!(
+                    out,
+                    "{}  {:name$}  {} and {}",
+                    OVERLAP,
+                    "overlap",
+                    views[i].0,
+                    views[j].0,
+                    name = name_width
+                );
+            }
+        }
+    }
+    out
+}
+
+/// Pick a letter for each view: the first letter of its name if not taken yet,
+/// or the first letter available.
+fn letters(views: &[(&str, &dyn StridedInfo)]) -> Vec<char> {
+    let mut letters: Vec<char> = Vec::with_capacity(views.len());
+    for (name, _) in views {
+        let preferred = name
+            .chars()
+            .find(|c| c.is_ascii_alphanumeric())
+            .map(|c| c.to_ascii_uppercase());
+        let letter = preferred
+            .into_iter()
+            .chain('A'..='Z')
+            .chain('a'..='z')
+            .find(|c| !letters.contains(c))
+            .unwrap_or('?');
+        letters.push(letter);
+    }
+    letters
+}
+
+/// Draw the runs of bytes with the same owners, and the offset of each run below.
+fn map_and_ruler(owners: &[Vec<usize>], letters: &[char]) -> (String, String) {
+    let mut map = String::new();
+    let mut ruler = String::new();
+    let mut start = 0;
+    while start < owners.len() {
+        let end = (start..owners.len())
+            .find(|byte| owners[*byte] != owners[start])
+            .unwrap_or(owners.len());
+        let cell = match owners[start].as_slice() {
+            [] => PADDING,
+            [index] => letters[*index],
+            _ => OVERLAP,
+        };
+        label(&mut ruler, map.len(), start);
+        map.push('|');
+        map.extend(std::iter::repeat(cell).take(end - start));
+        start = end;
+    }
+    label(&mut ruler, map.len(), owners.len());
+    map.push('|');
+    (map, ruler)
+}
+
+/// Write `offset` at `column` of `ruler`.
+///
+/// Labels that would touch the previous one are skipped.
+fn label(ruler: &mut String, column: usize, offset: usize) {
+    if ruler.is_empty() || column > ruler.len() {
+        let pad = column - ruler.len();
+        ruler.extend(std::iter::repeat(' ').take(pad));
+        let _ = write!(ruler, "{}", offset);
+    }
+}
@@ -1,13 +1,168 @@
 #![doc = include_str!("../README.md")]
 #![warn(missing_docs)]
 
+mod aligned;
+mod array;
+mod atomic;
+mod attribute_set;
+mod attributes;
+mod audit;
+mod banked;
+mod blob;
+mod budget;
 mod builder;
+mod chunked;
+mod components;
+pub mod convert;
+mod copy_batch;
+mod delta;
+mod desc;
+mod diff;
+mod double_buffer;
+mod element_format;
+mod erased;
+mod error;
+mod fetch;
+mod fingerprint;
+mod fixed;
+pub mod forensics;
+mod format;
+mod gather;
+mod header;
+mod inline;
+mod interleave;
+mod jagged;
+mod layout_map;
+mod locate;
+mod lossy;
 mod macros;
+#[cfg(feature = "serde")]
+mod manifest;
+mod mask;
+mod math;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl;
+#[cfg(feature = "ndarray")]
+mod ndarray_impl;
+mod overlay;
+mod owned;
+mod packed;
+pub mod panic_free;
+mod parallel;
+mod policy;
+mod prefetch;
+mod primitive;
+pub mod profiling;
+mod remap;
+mod resample;
+mod resume;
+mod rotate;
+mod sanitize;
+mod scan;
+mod segments;
 mod shared_impl;
+mod signed;
 mod slice;
 mod slice_mut;
+mod soa;
+mod sparse;
+mod split;
+mod stats;
+mod streaming;
+mod swizzle;
+mod tail;
+#[cfg(feature = "testing")]
+mod testing_impl;
+mod tracked;
+mod transaction;
+mod unzip;
+mod versioned;
+#[cfg(feature = "wasm")]
+mod wasm_impl;
+#[cfg(feature = "wgpu")]
+mod wgpu_impl;
+mod window;
+mod zip;
 
+mod private {
+    pub trait Sealed {}
+}
+
+pub use aligned::AlignedBytes;
+pub use array::{SliceArray, SliceArrayMut};
+pub use atomic::{AtomicElement, AtomicLane};
+pub use attribute_set::{AttributeError, AttributeSet};
+pub use attributes::{AttributeLayout, Attributes};
+pub use audit::{audit_views, AuditWarning};
+pub use banked::{BankPolicy, SplitSlice, SplitSliceMut};
+pub use blob::BlobError;
+pub use budget::{ApplyProgress, ApplyState, StaleStateError};
 pub use builder::*;
-pub use shared_impl::{SliceBase, SliceError};
+pub use chunked::ChunkedReader;
+pub use components::ComponentSlice;
+pub use copy_batch::{CopyBatch, CopyStats};
+pub use delta::Delta;
+pub use desc::ViewDesc;
+pub use diff::{Deviation, Diff};
+pub use double_buffer::DoubleBuffered;
+pub use element_format::{Format, FormatElement};
+pub use erased::{ErasedSlice, TagFormat, TaggedPod, TypeTag};
+pub use error::SliceErrorKind;
+pub use fetch::VertexFetchInfo;
+pub use fingerprint::{Fingerprint, FingerprintTree};
+pub use fixed::{Fixed16, Fixed32, Q16_16, Q2_14};
+pub use gather::IndexElement;
+pub use interleave::InterleaveBuilder;
+pub use jagged::{JaggedView, JaggedViewMut};
+pub use layout_map::render_layout;
+pub use locate::{locate_in_views, ElementLocation};
+pub use lossy::PrefixReport;
+#[cfg(feature = "serde")]
+pub use manifest::{ImportError, ImportIssue, LayoutManifest, ManifestAttribute};
+pub use mask::{filter_gather, BitMask};
+pub use overlay::OverlaySlice;
+pub use owned::{or_broadcast, MaybeOwnedSlice, OwnedStrided, OwnedView, VecView};
+pub use packed::PackScratch;
+#[cfg(feature = "derive")]
+pub use pas_derive::Attributes;
+pub use policy::{LayoutInfo, Permissive, Strict, ValidationPolicy};
+pub use primitive::{
+    validate_non_indexed_primitive, validate_primitive, PrimitiveError, PrimitiveIssue, StridedInfo,
+};
+pub use remap::WrapError;
+pub use resume::Resume;
+pub use sanitize::{SanitizeError, SanitizePolicy};
+pub use scan::{OverflowMode, Scan, ScanElement, ScanError};
+pub use segments::{SegmentDesc, Segments};
+pub use shared_impl::{SliceError, SliceInfo};
 pub use slice::*;
 pub use slice_mut::*;
+pub use soa::{soa, Soa, SoaLanes};
+pub use sparse::SparseAttribute;
+pub use split::{
+    for_each_vertex, for_each_vertex2, split_attr_read_send, split_attr_read_write,
+    split_attrs_read_write, LaneGuard, SendableLane,
+};
+pub use stats::*;
+pub use swizzle::SwizzledSlice;
+pub use tail::{TailPolicy, TailPolicySlice};
+pub use tracked::{DirtyGuard, TrackedSliceMut};
+pub use transaction::{Lane, Transaction, TransactionError, TransactionView};
+pub use unzip::{write_unzipped2, write_unzipped3, write_unzipped4};
+pub use versioned::{VersionedBuffer, VersionedSlice, VersionedSliceMut};
+#[cfg(feature = "wasm")]
+pub use wasm_impl::TypedArrayElement;
+pub use window::{ByteWindowSlice, ByteWindowSliceMut};
+
+/// Items used by derive macros.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::attributes::{field, FieldSplitter};
+    pub use crate::{__offset_of as offset_of, __offset_of_fallback as offset_of_fallback};
+    pub use bytemuck::Pod;
+}
+
+// Runs the aliasing matrix as doctests.
+#[cfg(doctest)]
+#[doc = include_str!("../tests/aliasing.md")]
+struct AliasingMatrix;
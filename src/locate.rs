@@ -0,0 +1,46 @@
+use crate::StridedInfo;
+
+/// Position of an address in the layout of a view.
+///
+/// See [`StridedInfo::locate`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ElementLocation {
+    /// Index of the element, or of the element preceding the gap
+    pub index: usize,
+    /// Byte offset of the address from the start of the element
+    pub offset_within_element: usize,
+    /// `true` if the address is after the element, in the gap before the next one
+    pub in_gap: bool,
+}
+
+/// Classify the address `addr` against each of the labeled `views`.
+///
+/// Returns the label and location of every view spanning `addr`, in order. Views over
+/// attributes of the same elements usually all span the address: only one of them
+/// locates it in an element, i.e., with `in_gap == false`.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{locate_in_views, Slice, StridedInfo};
+///
+/// let data = [[0_u32, 1, 2], [3, 4, 5]];
+/// let xs: Slice<u32> = Slice::new(&data, 0);
+/// let yzs: Slice<[u32; 2]> = Slice::new(&data, 4);
+///
+/// let addr: *const u8 = (&data[0][1] as *const u32).cast();
+/// let views: [(&str, &dyn StridedInfo); 2] = [("x", &xs), ("yz", &yzs)];
+/// let found = locate_in_views(addr, &views);
+/// assert_eq!(found.len(), 2);
+/// assert_eq!((found[0].0, found[0].1.in_gap), ("x", true));
+/// assert_eq!((found[1].0, found[1].1.index, found[1].1.in_gap), ("yz", 0, false));
+/// ```
+pub fn locate_in_views<'l>(
+    addr: *const u8,
+    views: &[(&'l str, &dyn StridedInfo)],
+) -> Vec<(&'l str, ElementLocation)> {
+    views
+        .iter()
+        .filter_map(|(label, view)| view.locate(addr).map(|location| (*label, location)))
+        .collect()
+}
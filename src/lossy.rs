@@ -0,0 +1,122 @@
+use bytemuck::Pod;
+
+use crate::{Slice, SliceMut};
+
+/// Description of the bytes left out by a best-effort constructor, such as
+/// [`Slice::longest_valid_prefix`].
+///
+/// A default report means the view covers the whole buffer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PrefixReport {
+    /// Bytes of the partial element at the end of the buffer, i.e., from the start
+    /// of the first missing element to the end of the buffer
+    pub dropped_bytes: usize,
+    /// `true` if the offset was past the end of the buffer, and the view is empty
+    pub offset_clamped: bool,
+}
+
+impl PrefixReport {
+    /// `true` if no byte was left out, `false` otherwise
+    pub fn is_complete(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Report for a view of `count` elements, starting at byte `offset` of a buffer
+    /// of `bytes` bytes.
+    fn new(bytes: usize, offset: usize, stride: usize, count: usize) -> Self {
+        let missing = count.saturating_mul(stride).saturating_add(offset);
+        Self {
+            dropped_bytes: bytes.saturating_sub(missing),
+            offset_clamped: false,
+        }
+    }
+
+    /// Report for an empty view, if `offset` is past the last byte of a buffer
+    /// of `bytes` bytes.
+    fn out_of_bounds(bytes: usize, offset: usize) -> Option<Self> {
+        (offset > 0 && offset >= bytes).then_some(Self {
+            dropped_bytes: 0,
+            offset_clamped: offset > bytes,
+        })
+    }
+}
+
+impl<'a, T: Pod> Slice<'a, T> {
+    /// Best-effort version of [`Self::raw`], viewing every complete element of
+    /// a possibly truncated buffer.
+    ///
+    /// Returns the view, along with a report of the bytes left out. Offsets past
+    /// the end of the buffer give an empty view, with [`PrefixReport::offset_clamped`]
+    /// set, instead of panicking.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let bytes: Vec<u8> = bytemuck::cast_slice(&[1.0_f32, 2.0, 3.0]).to_vec();
+    /// // Truncated download, the last float misses a byte.
+    /// let (slice, report) = Slice::<f32>::longest_valid_prefix(&bytes[..11], 0, 4);
+    /// assert_eq!(slice.len(), 2);
+    /// assert_eq!(report.dropped_bytes, 3);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics in a similar way to [`Self::raw`], except for out of bounds offsets.
+    pub fn longest_valid_prefix(
+        data: &'a [u8],
+        byte_offset: usize,
+        byte_stride: usize,
+    ) -> (Self, PrefixReport) {
+        if let Some(report) = PrefixReport::out_of_bounds(data.len(), byte_offset) {
+            return (Slice::native(&[]), report);
+        }
+        let slice = Self::raw(data, byte_offset, byte_stride);
+        let report = PrefixReport::new(data.len(), byte_offset, byte_stride, slice.len());
+        (slice, report)
+    }
+
+    /// Best-effort version of [`Self::new`], see [`Self::longest_valid_prefix`].
+    ///
+    /// ## Panics
+    ///
+    /// Panics in a similar way to [`Self::new`], except for out of bounds offsets.
+    pub fn new_lossy<V: Pod>(data: &'a [V], byte_offset: usize) -> (Self, PrefixReport) {
+        let bytes = std::mem::size_of_val(data);
+        if let Some(report) = PrefixReport::out_of_bounds(bytes, byte_offset) {
+            return (Slice::native(&[]), report);
+        }
+        let slice = Self::new(data, byte_offset);
+        let report = PrefixReport::new(bytes, byte_offset, slice.stride(), slice.len());
+        (slice, report)
+    }
+}
+
+impl<'a, T: Pod> SliceMut<'a, T> {
+    /// Mutable version of [`Slice::longest_valid_prefix`].
+    pub fn longest_valid_prefix(
+        data: &'a mut [u8],
+        byte_offset: usize,
+        byte_stride: usize,
+    ) -> (Self, PrefixReport) {
+        let bytes = data.len();
+        if let Some(report) = PrefixReport::out_of_bounds(bytes, byte_offset) {
+            return (SliceMut::native(Default::default()), report);
+        }
+        let slice = Self::raw(data, byte_offset, byte_stride);
+        let report = PrefixReport::new(bytes, byte_offset, byte_stride, slice.len());
+        (slice, report)
+    }
+
+    /// Mutable version of [`Slice::new_lossy`].
+    pub fn new_lossy<V: Pod>(data: &'a mut [V], byte_offset: usize) -> (Self, PrefixReport) {
+        let bytes = std::mem::size_of_val(data);
+        if let Some(report) = PrefixReport::out_of_bounds(bytes, byte_offset) {
+            return (SliceMut::native(Default::default()), report);
+        }
+        let slice = Self::new(data, byte_offset);
+        let report = PrefixReport::new(bytes, byte_offset, slice.stride(), slice.len());
+        (slice, report)
+    }
+}
@@ -36,15 +36,13 @@
 macro_rules! slice_attr {
     ($stride:expr, $data:expr, $( $rest:tt )*) => {
         {
-            use pas::SliceBuilder;
-
             let slice = $data.as_slice();
             let r = &(slice$($rest)*);
-            SliceBuilder::new(r, $stride).build(slice)
+            $crate::SliceBuilder::new(r, $stride).build(slice)
         }
     };
     ($data:expr, $( $rest:tt )*) => {
-        slice_attr!(1, $data, $($rest)*)
+        $crate::slice_attr!(1, $data, $($rest)*)
     };
 }
 
@@ -52,6 +50,7 @@ macro_rules! slice_attr {
 ///
 /// At the opposite of [`slice_attr`], this macro doesn't infer the slice generic.
 /// This allows to get a view on a type that has a smaller size than the target attribute.
+/// A type larger than the target attribute doesn't compile.
 ///
 /// ## Example
 ///
@@ -72,20 +71,26 @@ macro_rules! slice_attr {
 /// // Only slice the x-axis positions
 /// let x_positions: Slice<f32> = slice!(vertices, [0].position[0]);
 /// ```
+///
+/// Slicing a type larger than the referenced attribute doesn't compile:
+///
+/// ```rust,compile_fail
+/// use pas::{slice, Slice};
+///
+/// let positions = [[1.0_f32, 0.5, 1.0], [1.0, 1.0, 0.5]];
+/// let y: Slice<f64> = slice!(positions, [0][1]);
+/// ```
 #[macro_export]
 macro_rules! slice {
     ($stride:expr, $data:expr, $( $rest:tt )*) => {
         {
-            use pas::{Slice, get_byte_offset};
-
             let slice = $data.as_slice();
-            let r = &(slice$($rest)*) as *const _ as *const u8;
-            let byte_offset = get_byte_offset(slice, r);
-            Slice::strided(slice, byte_offset, $stride)
+            let r: *const _ = &(slice$($rest)*);
+            $crate::field_slice(slice, r, $stride)
         }
     };
     ($data:expr, $( $rest:tt )*) => {
-        slice!(1, $data, $($rest)*)
+        $crate::slice!(1, $data, $($rest)*)
     };
 }
 
@@ -94,16 +99,13 @@ macro_rules! slice {
 macro_rules! slice_mut {
     ($stride:expr, $data:expr, $( $rest:tt )*) => {
         {
-            use pas::{get_byte_offset, SliceMut};
-
             let slice = $data.as_mut_slice();
-            let r = &(slice$($rest)*) as *const _ as *const u8;
-            let byte_offset = get_byte_offset(slice, r);
-            SliceMut::strided(slice, byte_offset, $stride)
+            let r: *const _ = &(slice$($rest)*);
+            $crate::field_slice_mut(slice, r, $stride)
         }
     };
     ($data:expr, $( $rest:tt )*) => {
-        slice_mut!(1, $data, $($rest)*)
+        $crate::slice_mut!(1, $data, $($rest)*)
     };
 }
 
@@ -112,14 +114,125 @@ macro_rules! slice_mut {
 macro_rules! slice_attr_mut {
     ($stride:expr, $data:expr, $( $rest:tt )*) => {
         {
-            use pas::SliceBuilder;
-
             let slice = $data.as_mut_slice();
             let r = &(slice$($rest)*);
-            SliceBuilder::new(r, $stride).build_mut(slice)
+            $crate::SliceBuilder::new(r, $stride).build_mut(slice)
         }
     };
     ($data:expr, $( $rest:tt )*) => {
-        slice_attr_mut!(1, $data, $($rest)*)
+        $crate::slice_attr_mut!(1, $data, $($rest)*)
+    };
+}
+
+/// Similar to [`slice_attr!`], but view the components of the referenced array attribute.
+///
+/// The result is a [`crate::ComponentSlice`], visiting every component of an element
+/// before jumping to the next element. This allows to feed a flat stream of scalars,
+/// without densifying the attribute.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::slice_components;
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy, Default, bytemuck::Pod, bytemuck::Zeroable)]
+/// struct Vertex {
+///     position: [f32; 3],
+///     uv: [f32; 2],
+/// }
+///
+/// let vertices = [
+///     Vertex {position: [1.0, 0.5, 1.0], uv: [1.0, 1.0]},
+///     Vertex {position: [1.0, 1.0, 0.5], uv: [0.0, 1.0]},
+/// ];
+/// let positions = slice_components!(vertices, [0].position);
+/// assert_eq!(positions.len(), 6);
+/// assert!(positions.iter().copied().eq([1.0, 0.5, 1.0, 1.0, 1.0, 0.5]));
+/// ```
+#[macro_export]
+macro_rules! slice_components {
+    ($stride:expr, $data:expr, $( $rest:tt )*) => {
+        $crate::slice_attr!($stride, $data, $($rest)*).components()
+    };
+    ($data:expr, $( $rest:tt )*) => {
+        $crate::slice_components!(1, $data, $($rest)*)
+    };
+}
+
+/// Derive a view on a field from a view on whole structs.
+///
+/// The field can be nested, and the slice generic type is resolved to
+/// the field type. See [`crate::Slice::rebase`].
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{rebase, Slice};
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy, Default, bytemuck::Pod, bytemuck::Zeroable)]
+/// struct Vertex {
+///     position: [f32; 3],
+///     uv: [f32; 2],
+/// }
+///
+/// let vertices = [
+///     Vertex {position: [1.0, 0.5, 1.0], uv: [1.0, 1.0]},
+///     Vertex {position: [1.0, 1.0, 0.5], uv: [0.0, 1.0]},
+/// ];
+/// let view: Slice<Vertex> = Slice::native(&vertices);
+/// let uvs = rebase!(view, uv).unwrap();
+/// let y = rebase!(view, position[1]).unwrap();
+/// println!("{:?}", uvs); // Prints `[[1.0, 1.0], [0.0, 1.0]]`
+/// println!("{:?}", y); // Prints `[0.5, 1.0]`
+/// ```
+#[macro_export]
+macro_rules! rebase {
+    ($view:expr, $( $field:tt )+) => {
+        $view.rebase_field(|v| unsafe { ::core::ptr::addr_of!((*v).$($field)+) })
+    };
+}
+
+/// Write each tuple of an iterator into mutable views, one per tuple component,
+/// at the same index.
+///
+/// Expands to [`crate::write_unzipped2`], [`crate::write_unzipped3`], or
+/// [`crate::write_unzipped4`], depending on the number of views.
+///
+/// ## Example
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use pas::{write_unzipped, Attributes};
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy, Default, bytemuck::Pod, bytemuck::Zeroable, Attributes)]
+/// struct Vertex {
+///     position: [f32; 3],
+///     uv: [f32; 2],
+///     color: [u8; 4],
+/// }
+///
+/// let mut vertices = [Vertex::default(); 4];
+/// let mut views = VertexAttributesMut::of(&mut vertices);
+///
+/// let generated = (0..4).map(|i| ([i as f32, 0.0, 0.0], [0.0, 1.0], [255, 255, 255, 255]));
+/// let count = write_unzipped!(generated, views.position, views.uv, views.color);
+/// assert_eq!(count, 4);
+/// assert_eq!(vertices[3].position, [3.0, 0.0, 0.0]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! write_unzipped {
+    ($values:expr, $a:expr, $b:expr $(,)?) => {
+        $crate::write_unzipped2($values, &mut $a, &mut $b)
+    };
+    ($values:expr, $a:expr, $b:expr, $c:expr $(,)?) => {
+        $crate::write_unzipped3($values, &mut $a, &mut $b, &mut $c)
+    };
+    ($values:expr, $a:expr, $b:expr, $c:expr, $d:expr $(,)?) => {
+        $crate::write_unzipped4($values, &mut $a, &mut $b, &mut $c, &mut $d)
     };
 }
@@ -0,0 +1,309 @@
+use bytemuck::Pod;
+
+use crate::owned::view_desc;
+use crate::{AttributeSet, ErasedSlice, Format, SliceError, TagFormat, TypeTag, ViewDesc};
+
+/// Layout of an attribute, see [`LayoutManifest`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct ManifestAttribute {
+    /// Name of the attribute
+    pub name: String,
+    /// Name of the [`Format`] variant, e.g., `"F32x3"`.
+    ///
+    /// Attributes tagged with a [`TagFormat::Named`] tag use the tag name,
+    /// and untagged ones the Rust type name.
+    pub format: String,
+    /// Size of an element, in **bytes**
+    pub element_size: usize,
+    /// Byte offset of the first element, from the start of the buffer
+    pub offset: usize,
+    /// Distance between two elements, in **bytes**
+    pub stride: usize,
+    /// Number of elements
+    pub count: usize,
+    /// Index of the buffer holding the elements
+    pub buffer: usize,
+}
+
+/// Serializable description of the views of an [`AttributeSet`], e.g., for tools
+/// written in other languages.
+///
+/// Created with [`AttributeSet::export_layout`], and turned back into views
+/// with [`AttributeSet::import_layout`]. Buffers are identified by index: views
+/// created from the same buffer share the same index, in order of first appearance.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{AttributeSet, Slice};
+///
+/// let vertices = [[0.0_f32, 1.0, 2.0, 0.5, 0.5], [3.0, 4.0, 5.0, 1.0, 1.0]];
+/// let mut set = AttributeSet::new();
+/// set.insert::<[f32; 3]>("position", Slice::new(&vertices, 0));
+/// set.insert::<[f32; 2]>("uv", Slice::new(&vertices, 12));
+///
+/// let manifest = set.export_layout();
+/// assert_eq!(manifest.attributes[1].format, "F32x2");
+/// assert_eq!(manifest.attributes[1].offset, 12);
+/// assert_eq!(manifest.attributes[1].stride, 20);
+///
+/// let bytes = bytemuck::cast_slice(&vertices);
+/// let imported = AttributeSet::import_layout(&manifest, &[bytes]).unwrap();
+/// assert_eq!(imported.get::<[f32; 2]>("uv").unwrap()[1], [1.0, 1.0]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct LayoutManifest {
+    /// Number of buffers referenced by the attributes
+    pub buffer_count: usize,
+    /// Attributes, in the iteration order of the set
+    pub attributes: Vec<ManifestAttribute>,
+}
+
+/// Problem found while importing an attribute, see [`ImportError`].
+#[derive(Clone, PartialEq)]
+pub enum ImportIssue {
+    /// The format isn't the name of a [`Format`] variant.
+    UnknownFormat {
+        /// Attribute name
+        name: String,
+        /// Format of the manifest
+        format: String,
+    },
+    /// The element size doesn't match the size of the format.
+    ElementSizeMismatch {
+        /// Attribute name
+        name: String,
+        /// Element size of the manifest
+        element_size: usize,
+        /// Size of the format
+        expected: usize,
+    },
+    /// The buffer index is out of bounds.
+    UnknownBuffer {
+        /// Attribute name
+        name: String,
+        /// Buffer index of the manifest
+        buffer: usize,
+        /// Number of buffers
+        count: usize,
+    },
+    /// The view can't be created over its buffer.
+    InvalidView {
+        /// Attribute name
+        name: String,
+        /// Error raised when creating the view
+        error: SliceError,
+    },
+    /// Another attribute has the same name.
+    Duplicate {
+        /// Attribute name
+        name: String,
+    },
+}
+
+impl std::fmt::Debug for ImportIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownFormat { name, format } => {
+                write!(f, "Attribute '{}' has unknown format '{}'", name, format)
+            }
+            Self::ElementSizeMismatch {
+                name,
+                element_size,
+                expected,
+            } => write!(
+                f,
+                "Attribute '{}' has elements of {} bytes, but its format has {} bytes",
+                name, element_size, expected
+            ),
+            Self::UnknownBuffer {
+                name,
+                buffer,
+                count,
+            } => write!(
+                f,
+                "Attribute '{}' refers to buffer {}, but only {} buffers were provided",
+                name, buffer, count
+            ),
+            Self::InvalidView { name, error } => {
+                write!(f, "Attribute '{}' has an invalid view: {:?}", name, error)
+            }
+            Self::Duplicate { name } => write!(f, "Attribute '{}' is duplicated", name),
+        }
+    }
+}
+
+/// Layout import error, listing every attribute that couldn't be imported.
+#[derive(Clone, PartialEq)]
+pub struct ImportError {
+    /// Problems, in attribute order
+    pub issues: Vec<ImportIssue>,
+}
+
+impl std::fmt::Debug for ImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, issue) in self.issues.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{:?}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> AttributeSet<'a> {
+    /// Describe the layout of every view of the set, see [`LayoutManifest`].
+    ///
+    /// Sparse attributes are skipped.
+    pub fn export_layout(&self) -> LayoutManifest {
+        let mut buffers: Vec<*const u8> = Vec::new();
+        let attributes = self
+            .iter()
+            .map(|(name, slice)| {
+                let ptr = slice.buffer_ptr();
+                let buffer = match buffers.iter().position(|b| *b == ptr) {
+                    Some(index) => index,
+                    None => {
+                        buffers.push(ptr);
+                        buffers.len() - 1
+                    }
+                };
+                ManifestAttribute {
+                    name: name.to_owned(),
+                    format: format_name(slice),
+                    element_size: slice.element_size(),
+                    offset: slice.byte_offset(),
+                    stride: slice.stride(),
+                    count: slice.len(),
+                    buffer,
+                }
+            })
+            .collect();
+        LayoutManifest {
+            buffer_count: buffers.len(),
+            attributes,
+        }
+    }
+
+    /// Create the views described by `manifest` over `buffers`, indexed by
+    /// [`ManifestAttribute::buffer`].
+    ///
+    /// Views are tagged with the standard tag of their format, and are retrieved
+    /// with [`Self::get`], e.g., as `[f32; 3]` for `F32x3`. Normalized formats
+    /// are only retrieved as a type tagged with the normalized format, see
+    /// [`crate::tagged_pod!`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`ImportError`] listing the problem of every attribute that
+    /// can't be imported, in manifest order.
+    pub fn import_layout(
+        manifest: &LayoutManifest,
+        buffers: &[&'a [u8]],
+    ) -> Result<Self, ImportError> {
+        let mut set = Self::new();
+        let mut issues = Vec::new();
+        for attribute in &manifest.attributes {
+            match import_attribute(attribute, buffers) {
+                Ok(slice) => {
+                    if set.insert_erased(attribute.name.clone(), slice).is_some() {
+                        issues.push(ImportIssue::Duplicate {
+                            name: attribute.name.clone(),
+                        });
+                    }
+                }
+                Err(issue) => issues.push(issue),
+            }
+        }
+        match issues.is_empty() {
+            true => Ok(set),
+            false => Err(ImportError { issues }),
+        }
+    }
+}
+
+/// Format string of a view, see [`ManifestAttribute::format`].
+fn format_name(slice: &ErasedSlice) -> String {
+    match slice.tag() {
+        Some(TypeTag {
+            format: TagFormat::Standard(format),
+            ..
+        }) => format!("{:?}", format),
+        Some(TypeTag {
+            format: TagFormat::Named(name),
+            ..
+        }) => name.to_owned(),
+        None => slice.type_name().to_owned(),
+    }
+}
+
+fn import_attribute<'a>(
+    attribute: &ManifestAttribute,
+    buffers: &[&'a [u8]],
+) -> Result<ErasedSlice<'a>, ImportIssue> {
+    let name = || attribute.name.clone();
+    let format = Format::ALL
+        .into_iter()
+        .find(|format| format!("{:?}", format) == attribute.format)
+        .ok_or_else(|| ImportIssue::UnknownFormat {
+            name: name(),
+            format: attribute.format.clone(),
+        })?;
+    if attribute.element_size != format.size() {
+        return Err(ImportIssue::ElementSizeMismatch {
+            name: name(),
+            element_size: attribute.element_size,
+            expected: format.size(),
+        });
+    }
+    let bytes = buffers
+        .get(attribute.buffer)
+        .ok_or_else(|| ImportIssue::UnknownBuffer {
+            name: name(),
+            buffer: attribute.buffer,
+            count: buffers.len(),
+        })?;
+    let desc = ViewDesc::new(attribute.offset, attribute.stride, attribute.count);
+    erase_format(bytes, desc, format).map_err(|error| ImportIssue::InvalidView {
+        name: name(),
+        error,
+    })
+}
+
+/// Create the view described by `desc`, with the Rust type of `format`.
+fn erase_format(
+    bytes: &[u8],
+    desc: ViewDesc,
+    format: Format,
+) -> Result<ErasedSlice<'_>, SliceError> {
+    macro_rules! by_lanes {
+        ($t:ty) => {
+            match format.lanes() {
+                1 => erase::<$t>(bytes, desc, format),
+                2 => erase::<[$t; 2]>(bytes, desc, format),
+                3 => erase::<[$t; 3]>(bytes, desc, format),
+                _ => erase::<[$t; 4]>(bytes, desc, format),
+            }
+        };
+    }
+    match format.lane_format() {
+        Format::U8 | Format::Unorm8 => by_lanes!(u8),
+        Format::I8 | Format::Snorm8 => by_lanes!(i8),
+        Format::U16 | Format::Unorm16 => by_lanes!(u16),
+        Format::I16 | Format::Snorm16 => by_lanes!(i16),
+        Format::U32 => by_lanes!(u32),
+        Format::I32 => by_lanes!(i32),
+        // Only `F32` is left.
+        _ => by_lanes!(f32),
+    }
+}
+
+fn erase<T: Pod>(
+    bytes: &[u8],
+    desc: ViewDesc,
+    format: Format,
+) -> Result<ErasedSlice<'_>, SliceError> {
+    let tag = TypeTag::of::<T>(TagFormat::Standard(format));
+    Ok(ErasedSlice::with_tag(view_desc::<T>(bytes, desc)?, tag))
+}
@@ -0,0 +1,216 @@
+use bytemuck::Pod;
+
+use crate::math::div_ceil;
+use crate::{Slice, SliceError, SliceInfo, SliceMut};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Boolean per element, packed as bits, e.g., a selection over the vertices of a mesh.
+///
+/// Bits are stored in `u64` words, using one bit per element instead of a byte.
+/// Set bits are iterated by scanning words, see [`Self::iter_ones`].
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{filter_gather, BitMask, Slice, SliceMut};
+///
+/// let heights = [0.5_f32, 3.0, 1.0, 4.0];
+/// let heights = Slice::native(&heights);
+/// let mask = BitMask::from_predicate(&heights, |h| *h > 2.0);
+/// assert_eq!(mask.iter_ones().collect::<Vec<_>>(), [1, 3]);
+///
+/// let mut selected = [0.0; 4];
+/// let count = filter_gather(&heights, &mask, &mut SliceMut::native(&mut selected));
+/// assert_eq!(selected[..count], [3.0, 4.0]);
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitMask {
+    /// Bits past `len` are always cleared.
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitMask {
+    /// Create a mask of `len` cleared bits.
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; div_ceil(len, WORD_BITS)],
+            len,
+        }
+    }
+
+    /// Create a mask with a bit per element of `slice`, set if `predicate` returns `true`.
+    pub fn from_predicate<T: Pod>(
+        slice: &SliceInfo<T>,
+        mut predicate: impl FnMut(&T) -> bool,
+    ) -> Self {
+        let mut mask = Self::new(slice.len());
+        let mut elements = slice.elements();
+        for word in mask.words.iter_mut() {
+            for (bit, value) in elements.by_ref().take(WORD_BITS).enumerate() {
+                *word |= (predicate(value) as u64) << bit;
+            }
+        }
+        mask
+    }
+
+    /// Number of bits.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// `true` if the mask has no bit, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the bit at index `index`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> bool {
+        assert!(index < self.len, "index ouf of bounds");
+        self.words[index / WORD_BITS] >> (index % WORD_BITS) & 1 == 1
+    }
+
+    /// Set the bit at index `index` to `value`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(index < self.len, "index ouf of bounds");
+        let word = &mut self.words[index / WORD_BITS];
+        let bit = 1 << (index % WORD_BITS);
+        match value {
+            true => *word |= bit,
+            false => *word &= !bit,
+        }
+    }
+
+    /// Number of set bits.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Iterate over the indices of the set bits, in increasing order.
+    ///
+    /// Each word is scanned with `trailing_zeros`, such that cleared bits are
+    /// skipped without being visited.
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(|(index, word)| SetBits {
+                word: *word,
+                base: index * WORD_BITS,
+            })
+    }
+
+    /// Bitwise and of both masks.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::LengthMismatch`] if `other` doesn't have the length of `self`.
+    pub fn and(&self, other: &BitMask) -> Result<BitMask, SliceError> {
+        self.combine(other, |a, b| a & b)
+    }
+
+    /// Bitwise or of both masks, see [`Self::and`].
+    pub fn or(&self, other: &BitMask) -> Result<BitMask, SliceError> {
+        self.combine(other, |a, b| a | b)
+    }
+
+    /// Bitwise exclusive or of both masks, see [`Self::and`].
+    pub fn xor(&self, other: &BitMask) -> Result<BitMask, SliceError> {
+        self.combine(other, |a, b| a ^ b)
+    }
+
+    /// Bitwise negation of the mask.
+    pub fn not(&self) -> BitMask {
+        let mut mask = Self {
+            words: self.words.iter().map(|word| !word).collect(),
+            len: self.len,
+        };
+        mask.clear_padding();
+        mask
+    }
+
+    fn combine(&self, other: &BitMask, f: impl Fn(u64, u64) -> u64) -> Result<BitMask, SliceError> {
+        if other.len != self.len {
+            return Err(SliceError::LengthMismatch {
+                expected: self.len,
+                len: other.len,
+            });
+        }
+        Ok(Self {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(a, b)| f(*a, *b))
+                .collect(),
+            len: self.len,
+        })
+    }
+
+    /// Clear the bits of the last word past `len`.
+    fn clear_padding(&mut self) {
+        let used = self.len % WORD_BITS;
+        if let (Some(last), true) = (self.words.last_mut(), used > 0) {
+            *last &= (1 << used) - 1;
+        }
+    }
+}
+
+impl std::fmt::Debug for BitMask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BitMask")
+            .field("len", &self.len)
+            .field("ones", &self.count_ones())
+            .finish()
+    }
+}
+
+/// Indices of the set bits of a word, offset by `base`.
+struct SetBits {
+    word: u64,
+    base: usize,
+}
+
+impl Iterator for SetBits {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        if self.word == 0 {
+            return None;
+        }
+        let bit = self.word.trailing_zeros() as usize;
+        // Clear the lowest set bit.
+        self.word &= self.word - 1;
+        Some(self.base + bit)
+    }
+}
+
+/// Copy the elements of `src` whose bit is set in `mask`, packed in order at
+/// the start of `dst`, and return the number of elements written.
+///
+/// Stops once `dst` is full. Use [`BitMask::count_ones`] to size `dst`.
+///
+/// ## Panics
+///
+/// Panics if `mask` doesn't have the length of `src`.
+pub fn filter_gather<T: Pod>(src: &Slice<T>, mask: &BitMask, dst: &mut SliceMut<T>) -> usize {
+    assert_eq!(
+        mask.len(),
+        src.len(),
+        "mask and slice have different lengths"
+    );
+    dst.write_elements(mask.iter_ones().map(|index| src[index]))
+}
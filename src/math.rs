@@ -0,0 +1,79 @@
+//! Integer helpers missing from the minimum supported Rust version.
+
+/// Same as `usize::div_ceil`, stable in Rust 1.73.
+///
+/// Panics if `rhs` is `0`.
+pub(crate) const fn div_ceil(lhs: usize, rhs: usize) -> usize {
+    let quotient = lhs / rhs;
+    if lhs % rhs == 0 {
+        quotient
+    } else {
+        quotient + 1
+    }
+}
+
+/// Same as `usize::is_multiple_of`, stable in Rust 1.87.
+///
+/// Only `0` is a multiple of `0`.
+pub(crate) const fn is_multiple_of(lhs: usize, rhs: usize) -> bool {
+    match rhs {
+        0 => lhs == 0,
+        _ => lhs % rhs == 0,
+    }
+}
+
+/// Same as `usize::checked_next_multiple_of`, stable in Rust 1.73.
+///
+/// Returns `None` if `rhs` is `0`, or on overflow.
+pub(crate) const fn checked_next_multiple_of(lhs: usize, rhs: usize) -> Option<usize> {
+    if rhs == 0 {
+        return None;
+    }
+    match lhs % rhs {
+        0 => Some(lhs),
+        r => lhs.checked_add(rhs - r),
+    }
+}
+
+/// Byte offset of a field, usable in constants.
+///
+/// Expands to `core::mem::offset_of!` when available, i.e., from Rust 1.77,
+/// and to [`__offset_of_fallback!`](crate::__offset_of_fallback) otherwise.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(pas_offset_of)]
+macro_rules! __offset_of {
+    ($ty: ty, $field: ident) => {
+        ::core::mem::offset_of!($ty, $field)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(pas_offset_of))]
+macro_rules! __offset_of {
+    ($ty: ty, $field: ident) => {
+        $crate::__offset_of_fallback!($ty, $field)
+    };
+}
+
+/// Byte offset of a field, computed from the address of the field in an
+/// uninitialized value.
+///
+/// The value is never read and no reference is created: the field address is
+/// taken with `addr_of!`, which is sound for uninitialized memory.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __offset_of_fallback {
+    ($ty: ty, $field: ident) => {{
+        let value = ::core::mem::MaybeUninit::<$ty>::uninit();
+        let base = value.as_ptr();
+        // Safe because `base` points to a live allocation of `$ty`, and
+        // the field is in bounds of that allocation.
+        #[allow(unused_unsafe)]
+        unsafe {
+            let field = ::core::ptr::addr_of!((*base).$field);
+            (field as *const u8).offset_from(base as *const u8) as usize
+        }
+    }};
+}
@@ -0,0 +1,139 @@
+use bytemuck::Pod;
+use nalgebra::{
+    Const, Dyn, MatrixView, MatrixViewMut, Point3, Scalar, Vector3, ViewStorage, ViewStorageMut, U1,
+};
+use std::ptr::NonNull;
+
+use crate::math::is_multiple_of;
+use crate::{Slice, SliceMut};
+
+/// Column stride, in **elements** of `T`, of a matrix bridging a slice whose
+/// attribute is `[T; R]`.
+///
+/// Returns `None` if the byte stride isn't a multiple of `size_of::<T>()`,
+/// since nalgebra strides are expressed in elements.
+fn column_stride<T, const R: usize>(byte_stride: usize) -> Option<usize> {
+    let size = std::mem::size_of::<T>();
+    if size == 0 || !is_multiple_of(byte_stride, size) {
+        return None;
+    }
+    // Empty slices can have a null stride, which nalgebra doesn't accept.
+    Some((byte_stride / size).max(R))
+}
+
+/// Pointer on the first column, dangling if there is none.
+fn first_column<T>(start: *const u8, len: usize) -> *const T {
+    match len {
+        0 => NonNull::dangling().as_ptr(),
+        _ => start.cast(),
+    }
+}
+
+impl<'a, T: Scalar + Pod> Slice<'a, [T; 3]> {
+    /// Iterate over the slice, yielding owned [`nalgebra::Point3`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[1.0_f32, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    /// let slice: Slice<[f32; 3]> = Slice::new(&data, 0);
+    /// let points: Vec<_> = slice.iter_points().collect();
+    /// println!("{:?}", points[1]); // Prints `[4.0, 5.0, 6.0]`
+    /// ```
+    pub fn iter_points(&self) -> impl Iterator<Item = Point3<T>> + '_ {
+        self.elements().map(|v| Point3::from(*v))
+    }
+
+    /// Iterate over the slice, yielding owned [`nalgebra::Vector3`].
+    pub fn iter_vectors(&self) -> impl Iterator<Item = Vector3<T>> + '_ {
+        self.elements().map(|v| Vector3::from(*v))
+    }
+}
+
+impl<'a, T: Scalar + Pod> SliceMut<'a, [T; 3]> {
+    /// Copies all points from `src` into `self`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the length of `src` is bigger than the length of `self`.
+    pub fn copy_from_points(&mut self, src: &[Point3<T>]) {
+        self.copy_from_vectors_iter(src.len(), src.iter().map(|p| &p.coords));
+    }
+
+    /// Copies all vectors from `src` into `self`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the length of `src` is bigger than the length of `self`.
+    pub fn copy_from_vectors(&mut self, src: &[Vector3<T>]) {
+        self.copy_from_vectors_iter(src.len(), src.iter());
+    }
+
+    fn copy_from_vectors_iter<'b>(
+        &mut self,
+        count: usize,
+        src: impl Iterator<Item = &'b Vector3<T>>,
+    ) {
+        assert!(
+            count <= self.len(),
+            "`src` too large. Found slice with {} elements, but expected at most {}",
+            count,
+            self.len()
+        );
+        self.write_elements(src.map(|v| [v.x, v.y, v.z]));
+    }
+}
+
+impl<'a, T: Scalar + Pod, const R: usize> Slice<'a, [T; R]> {
+    /// Create a `R x len` matrix view, where each column is an element of the slice.
+    ///
+    /// The matrix shares the memory of the slice: no copy occurs. The byte stride
+    /// of the slice is converted into a column stride expressed in elements of `T`.
+    ///
+    /// Returns `None` if the stride isn't a multiple of `size_of::<T>()`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [1.0_f32, 2.0, 3.0, 0.0, 4.0, 5.0, 6.0, 0.0];
+    /// let slice: Slice<[f32; 3]> = Slice::strided(&data, 0, 4);
+    /// let matrix = slice.as_matrix_view().unwrap();
+    /// assert_eq!(matrix[(1, 1)], 5.0);
+    /// ```
+    pub fn as_matrix_view(&self) -> Option<MatrixView<'a, T, Const<R>, Dyn, U1, Dyn>> {
+        let cstride = column_stride::<T, R>(self.stride())?;
+        // Safe because every column is an element of the slice, borrowed for `'a`,
+        // and aligned for `T`. The storage is created from a pointer, instead of
+        // a slice spanning every element, since the bytes between elements can
+        // be borrowed by other views.
+        let storage = unsafe {
+            ViewStorage::from_raw_parts(
+                first_column(self.base().start, self.len()),
+                (Const::<R>, Dyn(self.len())),
+                (U1, Dyn(cstride)),
+            )
+        };
+        Some(MatrixView::from_data(storage))
+    }
+}
+
+impl<'a, T: Scalar + Pod, const R: usize> SliceMut<'a, [T; R]> {
+    /// Mutable version of [`crate::Slice::as_matrix_view()`].
+    pub fn as_matrix_view_mut(&mut self) -> Option<MatrixViewMut<'_, T, Const<R>, Dyn, U1, Dyn>> {
+        let cstride = column_stride::<T, R>(self.stride())?;
+        // Safe because every column is an element of the slice, which is mutably
+        // borrowed by `self`. See `Slice::as_matrix_view`.
+        let storage = unsafe {
+            ViewStorageMut::from_raw_parts(
+                first_column::<T>(self.base().start, self.len()).cast_mut(),
+                (Const::<R>, Dyn(self.len())),
+                (U1, Dyn(cstride)),
+            )
+        };
+        Some(MatrixViewMut::from_data(storage))
+    }
+}
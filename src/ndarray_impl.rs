@@ -0,0 +1,194 @@
+use bytemuck::Pod;
+use ndarray::{ArrayView1, ArrayView2, ArrayViewMut1, ArrayViewMut2, Ix1, Ix2, ShapeBuilder};
+use std::ptr::NonNull;
+
+use crate::math::is_multiple_of;
+use crate::shared_impl::SliceBase;
+use crate::{Slice, SliceError, SliceMut, Strict};
+
+/// Stride, in **elements** of `T`, of a slice whose stride is `byte_stride`.
+///
+/// Returns `None` if the byte stride isn't a multiple of `size_of::<T>()`,
+/// since ndarray strides are expressed in elements.
+fn element_stride<T>(len: usize, byte_stride: usize, min: usize) -> Option<usize> {
+    let size = std::mem::size_of::<T>();
+    if size == 0 || !is_multiple_of(byte_stride, size) {
+        return None;
+    }
+    // Empty and single element slices can have any stride, ndarray
+    // must however never see overlapping elements.
+    match len {
+        0 | 1 => Some(min),
+        _ => Some(byte_stride / size),
+    }
+}
+
+/// Pointer to the first element, ndarray requires non-null pointers even for empty views.
+fn view_ptr<T>(start: *const u8, len: usize) -> *const T {
+    match len {
+        0 => NonNull::dangling().as_ptr(),
+        _ => start.cast(),
+    }
+}
+
+/// Create a slice over `len` attributes of `attr` bytes, spaced by `stride`
+/// elements of `T`, and starting at `ptr`.
+///
+/// The slice only owns its attributes, since the elements in between can be
+/// borrowed by other views, e.g., other columns of the same array.
+fn slice_base<T, A>(ptr: *const T, len: usize, stride: isize) -> Result<SliceBase<A>, SliceError> {
+    let attr = std::mem::size_of::<A>();
+    let byte_stride = match len {
+        0 | 1 => attr,
+        _ => usize::try_from(stride)
+            .map_err(|_| SliceError::NegativeStride { stride })?
+            .checked_mul(std::mem::size_of::<T>())
+            .ok_or(SliceError::Overflow)?,
+    };
+    let bytes = match len {
+        0 => 0,
+        len => (len - 1)
+            .checked_mul(byte_stride)
+            .and_then(|bytes| bytes.checked_add(attr))
+            .ok_or(SliceError::Overflow)?,
+    };
+    let start = ptr.cast::<u8>();
+    SliceBase::new_with_policy::<Strict>(
+        start..start.wrapping_add(bytes),
+        0,
+        byte_stride,
+        bytes,
+        None,
+    )
+    .map(SliceBase::owning_attribute)
+}
+
+/// Check that rows with `columns` values, spaced by `stride`, hold contiguous `[T; N]`.
+fn check_rows<const N: usize>(columns: usize, stride: isize) -> Result<(), SliceError> {
+    if columns != N || (N > 1 && stride != 1) {
+        return Err(SliceError::NonContiguousElement { columns, stride });
+    }
+    Ok(())
+}
+
+impl<'a, T: Pod> Slice<'a, T> {
+    /// Create a 1D [`ndarray::ArrayView1`] sharing the memory of the slice.
+    ///
+    /// The byte stride of the slice is converted into a stride expressed in elements of `T`.
+    ///
+    /// Returns `None` if the stride isn't a multiple of `size_of::<T>()`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[1.0_f32, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    /// let slice: Slice<f32> = Slice::new(&data, 4);
+    /// let view = slice.as_array_view().unwrap();
+    /// assert_eq!(view.strides(), &[3]);
+    /// assert_eq!(view[1], 5.0);
+    /// ```
+    pub fn as_array_view(&self) -> Option<ArrayView1<'a, T>> {
+        let stride = element_stride::<T>(self.len(), self.stride(), 1)?;
+        let shape = Ix1(self.len()).strides(Ix1(stride));
+        // Safe because every element is in the slice data, borrowed for `'a`,
+        // and aligned for `T`.
+        Some(unsafe { ArrayView1::from_shape_ptr(shape, view_ptr(self.base().start, self.len())) })
+    }
+
+    /// Create a slice from an [`ndarray::ArrayView1`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::NegativeStride`] if the view goes backward in memory.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ndarray::{s, ArrayView1};
+    /// use pas::Slice;
+    ///
+    /// let data = [0_u32, 1, 2, 3, 4, 5];
+    /// let view = ArrayView1::from(&data);
+    /// let slice = Slice::from_array_view(&view.slice(s![1..;2])).unwrap();
+    /// assert_eq!(slice.iter().copied().collect::<Vec<_>>(), [1, 3, 5]);
+    /// ```
+    pub fn from_array_view(view: &ArrayView1<'a, T>) -> Result<Self, SliceError> {
+        slice_base::<T, T>(view.as_ptr(), view.len(), view.strides()[0]).map(Self::from_base)
+    }
+}
+
+impl<'a, T: Pod, const N: usize> Slice<'a, [T; N]> {
+    /// Create a `len x N` [`ndarray::ArrayView2`], where each row is an element of the slice.
+    ///
+    /// Returns `None` if the stride isn't a multiple of `size_of::<T>()`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [1.0_f32, 2.0, 3.0, 0.0, 4.0, 5.0, 6.0, 0.0];
+    /// let slice: Slice<[f32; 3]> = Slice::strided(&data, 0, 4);
+    /// let view = slice.as_array_view2().unwrap();
+    /// assert_eq!(view.shape(), &[2, 3]);
+    /// assert_eq!(view[(1, 2)], 6.0);
+    /// ```
+    pub fn as_array_view2(&self) -> Option<ArrayView2<'a, T>> {
+        let stride = element_stride::<T>(self.len(), self.stride(), N)?;
+        let shape = Ix2(self.len(), N).strides(Ix2(stride, 1));
+        // Safe because every element is in the slice data, borrowed for `'a`,
+        // and aligned for `T`.
+        Some(unsafe { ArrayView2::from_shape_ptr(shape, view_ptr(self.base().start, self.len())) })
+    }
+
+    /// Create a slice from an [`ndarray::ArrayView2`], where each row is an element.
+    ///
+    /// ## Errors
+    ///
+    /// - [`SliceError::NonContiguousElement`] if rows don't have `N` contiguous columns
+    /// - [`SliceError::NegativeStride`] if rows go backward in memory
+    pub fn from_array_view2(view: &ArrayView2<'a, T>) -> Result<Self, SliceError> {
+        let strides = view.strides();
+        check_rows::<N>(view.ncols(), strides[1])?;
+        slice_base::<T, [T; N]>(view.as_ptr(), view.nrows(), strides[0]).map(Self::from_base)
+    }
+}
+
+impl<'a, T: Pod> SliceMut<'a, T> {
+    /// Mutable version of [`crate::Slice::as_array_view()`].
+    pub fn as_array_view_mut(&mut self) -> Option<ArrayViewMut1<'_, T>> {
+        let stride = element_stride::<T>(self.len(), self.stride(), 1)?;
+        let shape = Ix1(self.len()).strides(Ix1(stride));
+        let ptr = view_ptr::<T>(self.base().start, self.len()).cast_mut();
+        // Safe because elements are in the slice data, mutably borrowed
+        // by `self`, and never overlap.
+        Some(unsafe { ArrayViewMut1::from_shape_ptr(shape, ptr) })
+    }
+
+    /// Mutable version of [`crate::Slice::from_array_view()`].
+    pub fn from_array_view_mut(mut view: ArrayViewMut1<'a, T>) -> Result<Self, SliceError> {
+        let (len, stride) = (view.len(), view.strides()[0]);
+        slice_base::<T, T>(view.as_mut_ptr(), len, stride).map(Self::from_base)
+    }
+}
+
+impl<'a, T: Pod, const N: usize> SliceMut<'a, [T; N]> {
+    /// Mutable version of [`crate::Slice::as_array_view2()`].
+    pub fn as_array_view2_mut(&mut self) -> Option<ArrayViewMut2<'_, T>> {
+        let stride = element_stride::<T>(self.len(), self.stride(), N)?;
+        let shape = Ix2(self.len(), N).strides(Ix2(stride, 1));
+        let ptr = view_ptr::<T>(self.base().start, self.len()).cast_mut();
+        // Safe because elements are in the slice data, mutably borrowed
+        // by `self`, and never overlap.
+        Some(unsafe { ArrayViewMut2::from_shape_ptr(shape, ptr) })
+    }
+
+    /// Mutable version of [`crate::Slice::from_array_view2()`].
+    pub fn from_array_view2_mut(mut view: ArrayViewMut2<'a, T>) -> Result<Self, SliceError> {
+        let (rows, strides) = (view.nrows(), [view.strides()[0], view.strides()[1]]);
+        check_rows::<N>(view.ncols(), strides[1])?;
+        slice_base::<T, [T; N]>(view.as_mut_ptr(), rows, strides[0]).map(Self::from_base)
+    }
+}
@@ -0,0 +1,155 @@
+use bytemuck::Pod;
+use std::fmt::Debug;
+
+use crate::{Slice, SliceError, SliceMut};
+
+/// Read-only slice, with some of its elements replaced by overrides.
+///
+/// Overrides are stored apart from the base slice, sorted by index, such that
+/// variants sharing most of their elements don't need a copy each. Use
+/// [`Self::materialize_into`] to flatten the variant once needed.
+///
+/// Since elements come from either the base or the overrides, they are returned
+/// by value.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{OverlaySlice, Slice};
+///
+/// let colors = [[255_u8, 0, 0, 255]; 4];
+/// let mut variant = OverlaySlice::new(Slice::native(&colors));
+/// variant.set_override(2, [0, 0, 255, 255]).unwrap();
+///
+/// assert_eq!(variant.get(1), Some([255, 0, 0, 255]));
+/// assert_eq!(variant.get(2), Some([0, 0, 255, 255]));
+/// assert_eq!(variant.overrides().len(), 1);
+/// ```
+#[derive(Clone)]
+pub struct OverlaySlice<'a, T: Pod> {
+    base: Slice<'a, T>,
+    overrides: Vec<(usize, T)>,
+}
+
+impl<'a, T: Pod> OverlaySlice<'a, T> {
+    /// Create an overlay over `base`, without any override.
+    pub fn new(base: Slice<'a, T>) -> Self {
+        Self {
+            base,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Slice read for elements without override.
+    pub fn base(&self) -> Slice<'a, T> {
+        self.base
+    }
+
+    /// Number of elements, i.e., the number of elements of the base slice.
+    pub fn len(&self) -> usize {
+        self.base.len()
+    }
+
+    /// `true` if the base slice is empty, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.base.is_empty()
+    }
+
+    /// Get the element at `index`, from the overrides if any, or from the base slice.
+    pub fn get(&self, index: usize) -> Option<T> {
+        match self.find(index) {
+            Ok(position) => Some(self.overrides[position].1),
+            Err(_) => self.base.get(index).copied(),
+        }
+    }
+
+    /// Replace the element at `index` by `value`.
+    ///
+    /// Returns the previous override of this element, if any.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::IndexOutOfBounds`] if `index` is out of the bounds
+    /// of the base slice, with a position of `0`.
+    pub fn set_override(&mut self, index: usize, value: T) -> Result<Option<T>, SliceError> {
+        if index >= self.len() {
+            return Err(SliceError::IndexOutOfBounds {
+                position: 0,
+                index: index as u64,
+                len: self.len(),
+            });
+        }
+        Ok(match self.find(index) {
+            Ok(position) => Some(std::mem::replace(&mut self.overrides[position].1, value)),
+            Err(position) => {
+                self.overrides.insert(position, (index, value));
+                None
+            }
+        })
+    }
+
+    /// Remove the override of the element at `index`, and return it if any.
+    pub fn clear_override(&mut self, index: usize) -> Option<T> {
+        self.find(index)
+            .ok()
+            .map(|position| self.overrides.remove(position).1)
+    }
+
+    /// Remove every override.
+    pub fn clear_overrides(&mut self) {
+        self.overrides.clear();
+    }
+
+    /// Iterate over the index and the value of each override, in increasing index order.
+    pub fn overrides(&self) -> impl ExactSizeIterator<Item = (usize, &T)> {
+        self.overrides.iter().map(|(index, value)| (*index, value))
+    }
+
+    /// Iterate over the elements, with overrides applied.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = T> + '_ {
+        let mut overrides = self.overrides.iter().peekable();
+        (0..self.len()).map(move |index| {
+            match overrides.next_if(|(overridden, _)| *overridden == index) {
+                Some((_, value)) => *value,
+                None => self.base[index],
+            }
+        })
+    }
+
+    /// Copy the elements, with overrides applied, into a [`Vec`].
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().collect()
+    }
+
+    /// Write the elements, with overrides applied, into `dst`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::LengthMismatch`] if `dst` doesn't have the length
+    /// of this slice. Nothing is written in this case.
+    pub fn materialize_into(&self, dst: &mut SliceMut<T>) -> Result<(), SliceError> {
+        if dst.len() != self.len() {
+            return Err(SliceError::LengthMismatch {
+                expected: self.len(),
+                len: dst.len(),
+            });
+        }
+        dst.write_elements(self.base.iter().copied());
+        for (index, value) in &self.overrides {
+            dst[*index] = *value;
+        }
+        Ok(())
+    }
+
+    /// Position of the override of `index`, or the position to insert it at.
+    fn find(&self, index: usize) -> Result<usize, usize> {
+        self.overrides
+            .binary_search_by_key(&index, |(overridden, _)| *overridden)
+    }
+}
+
+impl<'a, T: Pod + Debug> Debug for OverlaySlice<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
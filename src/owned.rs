@@ -0,0 +1,744 @@
+use bytemuck::Pod;
+use std::fmt::{Debug, Display};
+
+use crate::convert::{into_bytes, Conversion};
+use crate::inline::{InlineBytes, SmallBytes, INLINE_CAPACITY};
+use crate::math::checked_next_multiple_of;
+use crate::slice_mut::mut_range;
+use crate::{
+    shared_impl::SliceBase, AttributeError, Slice, SliceError, SliceInfo, SliceIterator, SliceMut,
+    Strict, ViewDesc,
+};
+
+/// Owned snapshot of the elements of a slice.
+///
+/// Elements are stored packed, i.e., with a stride equal to the size of `T`,
+/// and can be viewed as a [`Slice`] or [`SliceMut`] again.
+///
+/// Snapshots of at most 64 bytes, e.g., a single matrix, are stored inline
+/// and aligned to 16 bytes, without allocating. See [`Self::is_inline`].
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{OwnedStrided, Slice};
+///
+/// let data = [[0_u32, 1], [2, 3], [4, 5]];
+/// let slice: Slice<u32> = Slice::new(&data, 4);
+/// let owned = OwnedStrided::from_slice(&slice);
+/// assert_eq!(owned.as_elements(), &[1, 3, 5]);
+/// assert!(owned.is_inline());
+/// ```
+#[derive(Clone, Default)]
+pub struct OwnedStrided<T: Pod> {
+    data: Elements<T>,
+}
+
+/// Storage of an [`OwnedStrided`].
+#[derive(Clone)]
+enum Elements<T> {
+    /// `len` elements stored in the first bytes
+    Inline {
+        bytes: InlineBytes,
+        len: usize,
+    },
+    Heap(Vec<T>),
+}
+
+impl<T> Default for Elements<T> {
+    fn default() -> Self {
+        Self::Heap(Vec::new())
+    }
+}
+
+impl<T: Pod> OwnedStrided<T> {
+    /// Take ownership of packed elements.
+    ///
+    /// The elements stay in `data`, which is already allocated.
+    pub fn new(data: Vec<T>) -> Self {
+        Self {
+            data: Elements::Heap(data),
+        }
+    }
+
+    /// Copy every element of `slice`.
+    ///
+    /// Elements are stored inline if they span at most 64 bytes, and `T` is
+    /// aligned to at most 16 bytes.
+    pub fn from_slice(slice: &SliceInfo<T>) -> Self {
+        let len = slice.len();
+        let size = std::mem::size_of::<T>();
+        let inline = size > 0
+            && std::mem::align_of::<T>() <= std::mem::align_of::<InlineBytes>()
+            && len <= INLINE_CAPACITY / size;
+        if !inline {
+            return Self::new(slice.elements().copied().collect());
+        }
+        let mut bytes = InlineBytes::zeroed();
+        let elements: &mut [T] = bytemuck::cast_slice_mut(&mut bytes.0[..len * size]);
+        for (dst, src) in elements.iter_mut().zip(slice.elements()) {
+            *dst = *src;
+        }
+        Self {
+            data: Elements::Inline { bytes, len },
+        }
+    }
+
+    /// `true` if the elements are stored inline, without allocating, `false` otherwise
+    pub fn is_inline(&self) -> bool {
+        matches!(self.data, Elements::Inline { .. })
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.as_elements().len()
+    }
+
+    /// `true` if there is no element, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.as_elements().is_empty()
+    }
+
+    /// Get the element at index `index`.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_elements().get(index)
+    }
+
+    /// Packed elements.
+    pub fn as_elements(&self) -> &[T] {
+        match &self.data {
+            Elements::Inline { bytes, len } => {
+                bytemuck::cast_slice(&bytes.0[..len * std::mem::size_of::<T>()])
+            }
+            Elements::Heap(data) => data,
+        }
+    }
+
+    /// Mutable version of [`Self::as_elements`].
+    fn as_elements_mut(&mut self) -> &mut [T] {
+        match &mut self.data {
+            Elements::Inline { bytes, len } => {
+                bytemuck::cast_slice_mut(&mut bytes.0[..*len * std::mem::size_of::<T>()])
+            }
+            Elements::Heap(data) => data,
+        }
+    }
+
+    /// View the elements as a [`Slice`].
+    pub fn as_slice(&self) -> Slice<'_, T> {
+        Slice::native(self.as_elements())
+    }
+
+    /// View the elements as a [`SliceMut`].
+    pub fn as_slice_mut(&mut self) -> SliceMut<'_, T> {
+        SliceMut::native(self.as_elements_mut())
+    }
+
+    /// Take back the packed elements.
+    ///
+    /// Inline elements are copied into a new `Vec`.
+    pub fn into_vec(self) -> Vec<T> {
+        match self.data {
+            Elements::Inline { .. } => self.as_elements().to_vec(),
+            Elements::Heap(data) => data,
+        }
+    }
+}
+
+//
+// Traits implementation
+//
+
+impl<T: Pod + Debug> Debug for OwnedStrided<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.as_elements()).finish()
+    }
+}
+
+impl<T: Pod + PartialEq> PartialEq for OwnedStrided<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_elements() == other.as_elements()
+    }
+}
+
+impl<'a, T: Pod> From<Slice<'a, T>> for OwnedStrided<T> {
+    fn from(slice: Slice<'a, T>) -> Self {
+        Self::from_slice(&slice)
+    }
+}
+
+impl<T: Pod> From<Vec<T>> for OwnedStrided<T> {
+    fn from(data: Vec<T>) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<T: Pod> std::ops::Index<usize> for OwnedStrided<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index ouf of bounds")
+    }
+}
+
+/// Elements either borrowed through a [`Slice`], or owned in a [`Vec`], similar to [`std::borrow::Cow`].
+///
+/// This allows a function to return its input untouched when no processing is needed,
+/// and a new `Vec` otherwise, while callers use the same read API.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{MaybeOwnedSlice, Slice};
+///
+/// fn positive(slice: Slice<'_, f32>) -> MaybeOwnedSlice<'_, f32> {
+///     match slice.iter().all(|v| *v >= 0.0) {
+///         true => slice.into(),
+///         false => slice.iter().map(|v| v.max(0.0)).collect::<Vec<_>>().into(),
+///     }
+/// }
+///
+/// let data = [[1.0_f32, 9.0], [-2.0, 9.0]];
+/// let result = positive(Slice::new(&data, 0));
+/// assert!(result.is_owned());
+/// assert_eq!(result.to_vec(), [1.0, 0.0]);
+/// ```
+pub enum MaybeOwnedSlice<'a, T: Pod> {
+    /// Borrowed elements, possibly strided
+    Borrowed(Slice<'a, T>),
+    /// Owned, packed elements
+    Owned(Vec<T>),
+}
+
+/// View the first `len` elements of `slice`, or `len` copies of `default` if there is
+/// no slice, e.g., for an optional attribute.
+///
+/// The slice is returned borrowed, and truncated to `len` elements. Otherwise,
+/// `default` is copied into an owned `Vec`.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{or_broadcast, Slice};
+///
+/// let colors: Option<Slice<[u8; 4]>> = None;
+/// let colors = or_broadcast(colors, &[255, 255, 255, 255], 3).unwrap();
+/// assert_eq!(colors.len(), 3);
+/// assert_eq!(colors[2], [255, 255, 255, 255]);
+/// ```
+///
+/// ## Errors
+///
+/// Returns [`SliceError::TooShort`] if `slice` has fewer than `len` elements.
+pub fn or_broadcast<'a, T: Pod>(
+    slice: Option<Slice<'a, T>>,
+    default: &T,
+    len: usize,
+) -> Result<MaybeOwnedSlice<'a, T>, SliceError> {
+    match slice {
+        Some(slice) if slice.len() < len => Err(SliceError::TooShort {
+            expected: len,
+            len: slice.len(),
+        }),
+        Some(slice) => Ok(MaybeOwnedSlice::Borrowed(slice.split_at(len).0)),
+        None => Ok(MaybeOwnedSlice::Owned(vec![*default; len])),
+    }
+}
+
+impl<'a, T: Pod> MaybeOwnedSlice<'a, T> {
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Borrowed(slice) => slice.len(),
+            Self::Owned(data) => data.len(),
+        }
+    }
+
+    /// `true` if there is no element, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// `true` if the elements are borrowed, `false` otherwise
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, Self::Borrowed(_))
+    }
+
+    /// `true` if the elements are owned, `false` otherwise
+    pub fn is_owned(&self) -> bool {
+        !self.is_borrowed()
+    }
+
+    /// Get the element at index `index`.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match self {
+            Self::Borrowed(slice) => slice.get(index),
+            Self::Owned(data) => data.get(index),
+        }
+    }
+
+    /// Create an iterator over the elements.
+    pub fn iter(&self) -> SliceIterator<'_, T> {
+        match self {
+            Self::Borrowed(slice) => slice.elements(),
+            Self::Owned(data) => SliceIterator::packed(data),
+        }
+    }
+
+    /// View the elements as a [`Slice`].
+    pub fn as_slice(&self) -> Slice<'_, T> {
+        match self {
+            Self::Borrowed(slice) => *slice,
+            Self::Owned(data) => Slice::native(data),
+        }
+    }
+
+    /// Copy the elements into a new `Vec`.
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().copied().collect()
+    }
+
+    /// Take ownership of the elements, copying borrowed elements.
+    pub fn into_owned(self) -> Vec<T> {
+        match self {
+            Self::Borrowed(slice) => slice.elements().copied().collect(),
+            Self::Owned(data) => data,
+        }
+    }
+
+    /// Get the owned elements mutably.
+    ///
+    /// Borrowed elements are first copied, and packed, into an owned `Vec`.
+    pub fn to_mut(&mut self) -> &mut Vec<T> {
+        if let Self::Borrowed(slice) = self {
+            *self = Self::Owned(slice.elements().copied().collect());
+        }
+        match self {
+            Self::Owned(data) => data,
+            Self::Borrowed(_) => unreachable!(),
+        }
+    }
+}
+
+impl<'a, T: Pod> Clone for MaybeOwnedSlice<'a, T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Borrowed(slice) => Self::Borrowed(*slice),
+            Self::Owned(data) => Self::Owned(data.clone()),
+        }
+    }
+}
+
+impl<'a, T: Pod + Debug> Debug for MaybeOwnedSlice<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: Pod + PartialEq> PartialEq for MaybeOwnedSlice<'a, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, T: Pod> From<Slice<'a, T>> for MaybeOwnedSlice<'a, T> {
+    fn from(slice: Slice<'a, T>) -> Self {
+        Self::Borrowed(slice)
+    }
+}
+
+impl<'a, T: Pod> From<Vec<T>> for MaybeOwnedSlice<'a, T> {
+    fn from(data: Vec<T>) -> Self {
+        Self::Owned(data)
+    }
+}
+
+impl<'a, T: Pod> std::ops::Index<usize> for MaybeOwnedSlice<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index ouf of bounds")
+    }
+}
+
+/// Alignment of the buffer of [`OwnedView`], the largest of common attribute types.
+pub(crate) const VIEW_ALIGN: usize = 16;
+
+/// Owned byte buffer, along with descriptions of the attributes it contains.
+///
+/// This solves storing a buffer next to slices borrowing it: the buffer
+/// is owned, and slices are created on demand via [`Self::view`] and
+/// [`Self::view_mut`].
+///
+/// Attributes are addressed by index, in registration order, or by a user
+/// key of type `K` via [`Self::find`].
+///
+/// The buffer is aligned to 16 bytes. Buffers of at most 64 bytes, e.g., a single
+/// matrix, are stored inline without allocating, and larger ones in an [`crate::AlignedBytes`].
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{OwnedView, ViewDesc};
+///
+/// let bytes: Vec<u8> = bytemuck::cast_slice(&[[1.0_f32, 2.0, 3.0, 0.5, 0.5]]).to_vec();
+///
+/// let mut view = OwnedView::new(&bytes);
+/// view.insert("position", ViewDesc::new(0, 20, 1));
+/// view.insert("uv", ViewDesc::new(12, 20, 1));
+///
+/// let uvs = view.view::<[f32; 2]>(view.find(&"uv").unwrap()).unwrap();
+/// assert_eq!(uvs[0], [0.5, 0.5]);
+/// ```
+#[derive(Clone)]
+pub struct OwnedView<K = ()> {
+    bytes: SmallBytes,
+    descs: Vec<(Option<K>, ViewDesc)>,
+}
+
+impl<K> OwnedView<K> {
+    /// Copy `bytes` into an aligned buffer, without any attribute.
+    pub fn new(bytes: &[u8]) -> Self {
+        let mut view = Self::zeroed(bytes.len());
+        view.bytes_mut().copy_from_slice(bytes);
+        view
+    }
+
+    /// Create an aligned buffer of `len` zeroed bytes, without any attribute.
+    pub(crate) fn zeroed(len: usize) -> Self {
+        Self {
+            bytes: SmallBytes::zeroed(len),
+            descs: Vec::new(),
+        }
+    }
+
+    /// `true` if the bytes are stored inline, without allocating, `false` otherwise
+    pub fn is_inline(&self) -> bool {
+        self.bytes.is_inline()
+    }
+
+    /// Register an attribute without key, and return its index.
+    pub fn push(&mut self, desc: ViewDesc) -> usize {
+        self.descs.push((None, desc));
+        self.descs.len() - 1
+    }
+
+    /// Register an attribute with key `key`, and return its index.
+    pub fn insert(&mut self, key: K, desc: ViewDesc) -> usize {
+        self.descs.push((Some(key), desc));
+        self.descs.len() - 1
+    }
+
+    /// Index of the first attribute registered with key `key`.
+    pub fn find(&self, key: &K) -> Option<usize>
+    where
+        K: PartialEq,
+    {
+        self.descs.iter().position(|(k, _)| k.as_ref() == Some(key))
+    }
+
+    /// Description of the attribute at index `index`.
+    pub fn desc(&self, index: usize) -> Option<&ViewDesc> {
+        self.descs.get(index).map(|(_, desc)| desc)
+    }
+
+    /// Number of registered attributes.
+    pub fn attribute_count(&self) -> usize {
+        self.descs.len()
+    }
+
+    /// Owned bytes.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Mutable version of [`Self::bytes()`].
+    pub(crate) fn bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+
+    /// Create a slice over the attribute at index `index`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if no attribute is registered at index `index`.
+    pub fn view<T: Pod>(&self, index: usize) -> Result<Slice<'_, T>, SliceError> {
+        view_desc(self.bytes(), self.descs[index].1)
+    }
+
+    /// Mutable version of [`Self::view()`].
+    pub fn view_mut<T: Pod>(&mut self, index: usize) -> Result<SliceMut<'_, T>, SliceError> {
+        let desc = self.descs[index].1;
+        view_desc_mut(&mut self.bytes, desc)
+    }
+
+    /// Append a packed lane of default elements, registered with key `key`,
+    /// and return a mutable view over it.
+    ///
+    /// The lane has the element count of the registered attributes, or no
+    /// element if none is registered. It starts at the next 16 bytes boundary
+    /// after the end of the buffer, which grows accordingly.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{OwnedView, ViewDesc};
+    ///
+    /// let bytes: Vec<u8> = bytemuck::cast_slice(&[[0.0_f32; 3]; 4]).to_vec();
+    /// let mut view = OwnedView::new(&bytes);
+    /// view.insert("position", ViewDesc::new(0, 12, 4));
+    ///
+    /// // The mesh has no normals.
+    /// let mut normals = view.add_default_lane::<[f32; 3]>("normal").unwrap();
+    /// normals.fill([0.0, 0.0, 1.0]);
+    ///
+    /// let normals = view.view::<[f32; 3]>(view.find(&"normal").unwrap()).unwrap();
+    /// assert_eq!(normals.len(), 4);
+    /// assert!(view.add_default_lane::<[f32; 3]>("normal").is_err());
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`AttributeError::Duplicate`] if an attribute is already registered
+    /// with `key`, and [`AttributeError::CountMismatch`] if the registered attributes
+    /// don't all have the same count. Nothing is added in both cases.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `U` is aligned to more than 16 bytes, or if the buffer size overflows.
+    pub fn add_default_lane<U: Pod + Default>(
+        &mut self,
+        key: K,
+    ) -> Result<SliceMut<'_, U>, AttributeError>
+    where
+        K: PartialEq + Display,
+    {
+        assert!(
+            std::mem::align_of::<U>() <= VIEW_ALIGN,
+            "lanes are aligned to {} bytes",
+            VIEW_ALIGN
+        );
+        if self.find(&key).is_some() {
+            return Err(AttributeError::Duplicate {
+                name: key.to_string(),
+            });
+        }
+        let count = self.lane_count()?;
+        let size = std::mem::size_of::<U>();
+        let (offset, end) = checked_next_multiple_of(self.bytes.len(), VIEW_ALIGN)
+            .zip(count.checked_mul(size))
+            .and_then(|(offset, bytes)| Some((offset, offset.checked_add(bytes)?)))
+            .expect("buffer size overflow");
+        self.bytes.resize(end, 0);
+        let index = self.insert(key, ViewDesc::new(offset, size, count));
+        let mut lane = self
+            .view_mut(index)
+            .expect("lanes are in bounds and aligned");
+        lane.fill(U::default());
+        Ok(lane)
+    }
+
+    /// Common count of the registered attributes.
+    fn lane_count(&self) -> Result<usize, AttributeError>
+    where
+        K: Display,
+    {
+        let expected = match self.descs.first() {
+            Some((_, desc)) => desc.count,
+            None => return Ok(0),
+        };
+        match self
+            .descs
+            .iter()
+            .enumerate()
+            .find(|(_, (_, desc))| desc.count != expected)
+        {
+            // Attributes without key are named by their index.
+            Some((index, (key, desc))) => Err(AttributeError::CountMismatch {
+                name: key
+                    .as_ref()
+                    .map_or_else(|| format!("#{}", index), |key| key.to_string()),
+                expected,
+                count: desc.count,
+            }),
+            None => Ok(expected),
+        }
+    }
+}
+
+/// Owned vector of elements, viewed as bytes, along with descriptions of the
+/// attributes they contain.
+///
+/// Unlike [`OwnedView`], the buffer isn't copied: the vector is kept as is, such
+/// that its bytes are aligned for `V`, and for any attribute aligned to at most
+/// `V`. Attributes are registered and viewed as in [`OwnedView`].
+///
+/// The bytes can be taken back with [`Self::into_vec`], or with [`Self::into_bytes`],
+/// which reuses the allocation when possible, see [`crate::convert::into_bytes`].
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{VecView, ViewDesc};
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+/// struct Vertex {
+///     position: [f32; 3],
+///     uv: [f32; 2],
+/// }
+///
+/// let vertices = vec![Vertex { position: [1.0, 2.0, 3.0], uv: [0.5, 0.5] }; 4];
+/// let mut view = VecView::new(vertices);
+/// view.insert("uv", ViewDesc::new(12, 20, 4));
+///
+/// let uvs = view.view::<[f32; 2]>(view.find(&"uv").unwrap()).unwrap();
+/// assert_eq!(uvs[3], [0.5, 0.5]);
+/// ```
+#[derive(Clone)]
+pub struct VecView<V: Pod, K = ()> {
+    data: Vec<V>,
+    descs: Vec<(Option<K>, ViewDesc)>,
+}
+
+impl<V: Pod, K> VecView<V, K> {
+    /// Take ownership of `data`, without any attribute.
+    pub fn new(data: Vec<V>) -> Self {
+        Self {
+            data,
+            descs: Vec::new(),
+        }
+    }
+
+    /// Register an attribute without key, and return its index.
+    pub fn push(&mut self, desc: ViewDesc) -> usize {
+        self.descs.push((None, desc));
+        self.descs.len() - 1
+    }
+
+    /// Register an attribute with key `key`, and return its index.
+    pub fn insert(&mut self, key: K, desc: ViewDesc) -> usize {
+        self.descs.push((Some(key), desc));
+        self.descs.len() - 1
+    }
+
+    /// Index of the first attribute registered with key `key`.
+    pub fn find(&self, key: &K) -> Option<usize>
+    where
+        K: PartialEq,
+    {
+        self.descs.iter().position(|(k, _)| k.as_ref() == Some(key))
+    }
+
+    /// Description of the attribute at index `index`.
+    pub fn desc(&self, index: usize) -> Option<&ViewDesc> {
+        self.descs.get(index).map(|(_, desc)| desc)
+    }
+
+    /// Number of registered attributes.
+    pub fn attribute_count(&self) -> usize {
+        self.descs.len()
+    }
+
+    /// Owned elements.
+    pub fn as_elements(&self) -> &[V] {
+        &self.data
+    }
+
+    /// Bytes of the owned elements.
+    pub fn bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(&self.data)
+    }
+
+    /// Create a slice over the attribute at index `index`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if no attribute is registered at index `index`.
+    pub fn view<T: Pod>(&self, index: usize) -> Result<Slice<'_, T>, SliceError> {
+        view_desc(self.bytes(), self.descs[index].1)
+    }
+
+    /// Mutable version of [`Self::view()`].
+    pub fn view_mut<T: Pod>(&mut self, index: usize) -> Result<SliceMut<'_, T>, SliceError> {
+        let desc = self.descs[index].1;
+        view_desc_mut(bytemuck::cast_slice_mut(&mut self.data), desc)
+    }
+
+    /// Drop the attributes, and return the elements.
+    pub fn into_vec(self) -> Vec<V> {
+        self.data
+    }
+
+    /// Drop the attributes, and return the bytes of the elements.
+    ///
+    /// See [`crate::convert::into_bytes`] for when the bytes are copied.
+    pub fn into_bytes(self) -> (Vec<u8>, Conversion) {
+        into_bytes(self.data)
+    }
+}
+
+impl<Attr: Pod> SliceInfo<Attr> {
+    /// Allocate `len` default elements of type `U`, e.g., to create a missing
+    /// attribute matching this one.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let positions = [[0.0_f32; 3]; 5];
+    /// let normals: Vec<[f32; 3]> = Slice::native(&positions).make_matching_lane();
+    /// assert_eq!(normals.len(), 5);
+    /// ```
+    pub fn make_matching_lane<U: Pod + Default>(&self) -> Vec<U> {
+        vec![U::default(); self.len()]
+    }
+}
+
+/// Create a slice over the elements described by `desc` in `bytes`.
+pub(crate) fn view_desc<T: Pod>(bytes: &[u8], desc: ViewDesc) -> Result<Slice<'_, T>, SliceError> {
+    let range = desc_range::<T>(desc, bytes.len())?;
+    let bytes = &bytes[..range.end];
+    Ok(Slice::from_base(SliceBase::new_with_policy::<Strict>(
+        bytes.as_ptr_range(),
+        range.start,
+        desc.stride,
+        bytes.len(),
+        None,
+    )?))
+}
+
+/// Mutable version of [`view_desc()`].
+pub(crate) fn view_desc_mut<T: Pod>(
+    bytes: &mut [u8],
+    desc: ViewDesc,
+) -> Result<SliceMut<'_, T>, SliceError> {
+    let range = desc_range::<T>(desc, bytes.len())?;
+    let bytes = &mut bytes[..range.end];
+    let len = bytes.len();
+    Ok(SliceMut::from_base(SliceBase::new_with_policy::<Strict>(
+        mut_range(bytes),
+        range.start,
+        desc.stride,
+        len,
+        None,
+    )?))
+}
+
+/// Byte range spanned by the elements described by `desc`, in a buffer of `len` bytes.
+///
+/// Empty attributes span `0..0`.
+fn desc_range<T: Pod>(desc: ViewDesc, len: usize) -> Result<std::ops::Range<usize>, SliceError> {
+    if desc.count == 0 {
+        return Ok(0..0);
+    }
+    let end = desc.byte_end(std::mem::size_of::<T>())?;
+    if end > len {
+        return Err(SliceError::OffsetOutOfBounds {
+            size: len,
+            offset: end,
+        });
+    }
+    Ok(desc.offset..end)
+}
@@ -0,0 +1,164 @@
+use bytemuck::Pod;
+
+use crate::{AlignedBytes, SliceInfo, SliceMut};
+
+/// Alignment of the buffer of a new [`PackScratch`], the largest of common attribute types.
+const SCRATCH_ALIGN: usize = 16;
+
+/// Reusable aligned buffer, used to pack strided elements contiguously.
+///
+/// See [`SliceMut::with_packed`] and [`SliceInfo::with_packed_ref`]. The buffer
+/// only grows, such that reusing a scratch across calls doesn't allocate.
+#[derive(Clone)]
+pub struct PackScratch {
+    bytes: AlignedBytes,
+}
+
+impl PackScratch {
+    /// Create an empty scratch, without allocating.
+    pub fn new() -> Self {
+        Self {
+            bytes: AlignedBytes::with_capacity_aligned(0, SCRATCH_ALIGN),
+        }
+    }
+
+    /// Create a scratch able to pack `bytes` bytes of elements aligned to at most 16 bytes.
+    pub fn with_capacity(bytes: usize) -> Self {
+        let mut scratch = Self::new();
+        scratch.reserve(bytes);
+        scratch
+    }
+
+    /// Size of the buffer, in **bytes**.
+    pub fn capacity(&self) -> usize {
+        self.bytes.len()
+    }
+
+    fn reserve(&mut self, bytes: usize) {
+        if bytes > self.bytes.len() {
+            self.bytes.resize(bytes, 0);
+        }
+    }
+
+    /// Pointer to `len` initialized elements of `T`, valid until the next mutable access.
+    fn packed<T: Pod>(&mut self, len: usize) -> *mut T {
+        let (size, align) = (std::mem::size_of::<T>(), std::mem::align_of::<T>());
+        if align > self.bytes.align() {
+            self.bytes = AlignedBytes::with_capacity_aligned(0, align);
+        }
+        self.reserve(len * size);
+        self.bytes.as_mut_ptr().cast()
+    }
+}
+
+impl Default for PackScratch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for PackScratch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PackScratch")
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
+
+/// Copy the packed elements back into the view when dropped, including on unwind.
+struct WriteBack<'s, 'a, T: Pod> {
+    packed: *const T,
+    dst: &'s mut SliceMut<'a, T>,
+}
+
+impl<'s, 'a, T: Pod> Drop for WriteBack<'s, 'a, T> {
+    fn drop(&mut self) {
+        // Safe because the scratch holds `len` elements, and is still mutably borrowed.
+        let packed = unsafe { std::slice::from_raw_parts(self.packed, self.dst.len()) };
+        self.dst.write_elements(packed.iter().copied());
+    }
+}
+
+impl<T: Pod> SliceInfo<T> {
+    /// Start of the elements, if they are contiguous.
+    pub(crate) fn contiguous(&self) -> Option<*mut T> {
+        match self.len() {
+            0 => Some(std::ptr::NonNull::dangling().as_ptr()),
+            len if len == 1 || self.stride() == std::mem::size_of::<T>() => {
+                Some(self.base().start as *mut T)
+            }
+            _ => None,
+        }
+    }
+
+    /// Read-only version of [`SliceMut::with_packed`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{PackScratch, Slice};
+    ///
+    /// let data = [[3_u32, 0], [1, 0], [2, 0]];
+    /// let slice: Slice<u32> = Slice::new(&data, 0);
+    /// let mut scratch = PackScratch::new();
+    /// let sorted = slice.with_packed_ref(&mut scratch, |v| v.windows(2).all(|w| w[0] <= w[1]));
+    /// assert!(!sorted);
+    /// ```
+    pub fn with_packed_ref<R>(&self, scratch: &mut PackScratch, f: impl FnOnce(&[T]) -> R) -> R {
+        let len = self.len();
+        if let Some(start) = self.contiguous() {
+            // Safe because the `len` elements are packed, aligned, in bounds, and borrowed.
+            return f(unsafe { std::slice::from_raw_parts(start, len) });
+        }
+        // Safe because the scratch holds `len` initialized elements, and is mutably borrowed.
+        let packed = unsafe { std::slice::from_raw_parts_mut(scratch.packed::<T>(len), len) };
+        packed
+            .iter_mut()
+            .zip(self.elements())
+            .for_each(|(dst, src)| *dst = *src);
+        f(packed)
+    }
+}
+
+impl<'a, T: Pod> SliceMut<'a, T> {
+    /// Run `f` over the elements as a contiguous std slice.
+    ///
+    /// If the elements are already contiguous, `f` directly receives them. Otherwise,
+    /// the elements are packed into `scratch`, and copied back once `f` returns.
+    /// Reusing `scratch` across calls avoids allocating.
+    ///
+    /// If `f` panics, elements are still copied back before unwinding, such that
+    /// both paths leave the view with every write done by `f`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{PackScratch, SliceMut};
+    ///
+    /// let mut data = [[3_u32, 0], [1, 0], [2, 0]];
+    /// let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 0);
+    /// let mut scratch = PackScratch::new();
+    /// slice.with_packed(&mut scratch, |v| v.sort());
+    /// assert_eq!(data, [[1, 0], [2, 0], [3, 0]]);
+    /// ```
+    pub fn with_packed<R>(
+        &mut self,
+        scratch: &mut PackScratch,
+        f: impl FnOnce(&mut [T]) -> R,
+    ) -> R {
+        let len = self.len();
+        if let Some(start) = self.contiguous() {
+            // Safe because the `len` elements are packed, aligned, in bounds, and mutably borrowed.
+            return f(unsafe { std::slice::from_raw_parts_mut(start, len) });
+        }
+        let packed = scratch.packed::<T>(len);
+        let guard = WriteBack { packed, dst: self };
+        // Safe because the scratch holds `len` initialized elements, and is mutably borrowed.
+        let elements = unsafe { std::slice::from_raw_parts_mut(packed, len) };
+        elements
+            .iter_mut()
+            .zip(guard.dst.elements())
+            .for_each(|(dst, src)| *dst = *src);
+        f(elements)
+    }
+}
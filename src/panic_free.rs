@@ -0,0 +1,33 @@
+//! Subset of the API that never panics, e.g., for real-time threads.
+//!
+//! The functions below return an [`Option`] or a [`Result`] instead of panicking,
+//! whatever their arguments. In release builds, they contain no panicking path
+//! at all: this is enforced by the `panic_free` test, that fails to link otherwise:
+//!
+//! ```sh
+//! cargo test --release --test panic_free
+//! ```
+//!
+//! ## Construction
+//!
+//! * [`crate::Slice::with_policy`], [`crate::SliceMut::with_policy`]
+//! * [`crate::Slice::try_transmute_elem`], [`crate::SliceMut::try_transmute_elem`]
+//!
+//! ## Access
+//!
+//! * [`crate::SliceInfo::len`], [`crate::SliceInfo::is_empty`], [`crate::SliceInfo::stride`]
+//! * [`crate::SliceInfo::get`], [`crate::SliceMut::get_mut`]
+//!
+//! ## Iteration
+//!
+//! * [`crate::Slice::iter`], [`crate::SliceMut::iter`]
+//! * [`Iterator::next`] and [`Iterator::nth`] of [`crate::SliceIterator`] and
+//!   [`crate::SliceMutIterator`]
+//!
+//! ## Copy
+//!
+//! * [`crate::SliceMut::try_copy_from_slice`]
+//!
+//! Other functions may panic, and document it in a `Panics` section. For instance,
+//! indexing panics when out of bounds, and [`crate::SliceMut::copy_from_slice`]
+//! panics when `src` doesn't fit.
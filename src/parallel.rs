@@ -0,0 +1,235 @@
+use bytemuck::Pod;
+
+use crate::{Float, FloatLanes, Slice, SliceMut};
+
+/// Run `f` over `chunks` with up to `threads` scoped threads, and return the
+/// results in chunk order, whatever the completion order.
+pub(crate) fn dispatch<C: Send, R: Send>(
+    chunks: Vec<C>,
+    threads: usize,
+    f: &(impl Fn(usize, C) -> R + Sync),
+) -> Vec<R> {
+    let threads = threads.min(chunks.len());
+    if threads <= 1 {
+        return chunks
+            .into_iter()
+            .enumerate()
+            .map(|(i, chunk)| f(i, chunk))
+            .collect();
+    }
+
+    // Distribute chunks round-robin, each thread owning its chunks.
+    let len = chunks.len();
+    let mut queues: Vec<Vec<(usize, C)>> = (0..threads).map(|_| Vec::new()).collect();
+    for (i, chunk) in chunks.into_iter().enumerate() {
+        queues[i % threads].push((i, chunk));
+    }
+    let mut results: Vec<Option<R>> = (0..len).map(|_| None).collect();
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = queues
+            .into_iter()
+            .map(|queue| {
+                scope.spawn(move || {
+                    queue
+                        .into_iter()
+                        .map(|(i, chunk)| (i, f(i, chunk)))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        for handle in handles {
+            // Propagate panics of `f` to the caller.
+            let done = handle
+                .join()
+                .unwrap_or_else(|e| std::panic::resume_unwind(e));
+            for (i, result) in done {
+                results[i] = Some(result);
+            }
+        }
+    });
+    results.into_iter().flatten().collect()
+}
+
+impl<'a, Attr: Pod + Send> SliceMut<'a, Attr> {
+    /// Run `f` in parallel over contiguous chunks of `chunk_elems` elements.
+    ///
+    /// `f` receives the chunk index and a mutable slice over the chunk. Chunks never
+    /// overlap, and the last chunk might be smaller than `chunk_elems`.
+    ///
+    /// Chunks are dispatched over `threads` scoped threads spawned for the call.
+    /// No thread is spawned if `threads <= 1` or if there is a single chunk.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::SliceMut;
+    ///
+    /// let mut data = [0_u32; 10];
+    /// let mut slice: SliceMut<u32> = SliceMut::native(&mut data);
+    /// slice.par_chunks_apply(4, 2, |chunk, mut slice| {
+    ///     for i in 0..slice.len() {
+    ///         slice[i] = chunk as u32;
+    ///     }
+    /// });
+    /// assert_eq!(data, [0, 0, 0, 0, 1, 1, 1, 1, 2, 2]);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `chunk_elems` is `0`.
+    pub fn par_chunks_apply<F>(&mut self, chunk_elems: usize, threads: usize, f: F)
+    where
+        F: Fn(usize, SliceMut<'_, Attr>) + Sync,
+    {
+        assert!(chunk_elems != 0, "chunk size must be non-zero");
+
+        let chunks = self.chunks_mut(chunk_elems);
+        dispatch(chunks, threads, &f);
+    }
+}
+
+impl<'a, Attr: Pod + Sync> Slice<'a, Attr> {
+    /// Fold chunks of `chunk_elems` elements in parallel, and combine the partial
+    /// results in chunk order.
+    ///
+    /// Each chunk is folded with `fold`, starting from a clone of `identity`. Partial
+    /// results are then combined on the calling thread, from the first chunk to the last:
+    /// `combine(combine(identity, chunk0), chunk1)`, etc.
+    ///
+    /// The result only depends on `chunk_elems`, and never on `threads` or on the
+    /// order in which chunks complete. This makes floating point reductions bit-identical
+    /// from one run to another.
+    ///
+    /// Chunks are dispatched like with [`SliceMut::par_chunks_apply`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data: Vec<f32> = (0..1000).map(|i| i as f32 * 0.1).collect();
+    /// let slice = Slice::native(&data);
+    /// let sum = |threads| {
+    ///     slice.par_fold_deterministic(64, threads, 0.0_f32, |acc, v| acc + v, |a, b| a + b)
+    /// };
+    /// assert_eq!(sum(1).to_bits(), sum(8).to_bits());
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `chunk_elems` is `0`.
+    pub fn par_fold_deterministic<R, F, C>(
+        &self,
+        chunk_elems: usize,
+        threads: usize,
+        identity: R,
+        fold: F,
+        combine: C,
+    ) -> R
+    where
+        R: Clone + Send + Sync,
+        F: Fn(R, &Attr) -> R + Sync,
+        C: Fn(R, R) -> R,
+    {
+        assert!(chunk_elems != 0, "chunk size must be non-zero");
+
+        let mut chunks = Vec::with_capacity(crate::math::div_ceil(self.len(), chunk_elems));
+        let mut rest = *self;
+        while !rest.is_empty() {
+            let (chunk, tail) = rest.split_at(chunk_elems.min(rest.len()));
+            chunks.push(chunk);
+            rest = tail;
+        }
+        let partials = dispatch(chunks, threads, &|_, chunk: Slice<'_, Attr>| {
+            chunk.iter().fold(identity.clone(), &fold)
+        });
+        partials.into_iter().fold(identity, combine)
+    }
+}
+
+impl<'a, Attr: FloatLanes + Send + Sync> Slice<'a, Attr> {
+    /// Per-lane sum, computed with [`Self::par_fold_deterministic`].
+    ///
+    /// Lanes are accumulated in `f64`, and the result is bit-identical for a given
+    /// `chunk_elems`, whatever `threads`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[1.0_f32, 0.5], [2.0, 0.5], [3.0, 0.5]];
+    /// let slice: Slice<[f32; 2]> = Slice::native(&data);
+    /// assert_eq!(slice.par_sum(2, 4), [6.0, 1.5]);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `chunk_elems` is `0`.
+    pub fn par_sum(&self, chunk_elems: usize, threads: usize) -> Attr {
+        let sums = self.par_fold_deterministic(
+            chunk_elems,
+            threads,
+            vec![0.0; Attr::LANES],
+            |mut sums, elt| {
+                for (lane, sum) in sums.iter_mut().enumerate() {
+                    *sum += elt.lane(lane).to_f64();
+                }
+                sums
+            },
+            |mut sums, other| {
+                sums.iter_mut().zip(other).for_each(|(sum, v)| *sum += v);
+                sums
+            },
+        );
+        let mut result = Attr::zeroed();
+        for (lane, sum) in sums.into_iter().enumerate() {
+            result.set_lane(lane, Attr::Scalar::from_f64(sum));
+        }
+        result
+    }
+
+    /// Per-lane minimum and maximum, computed with [`Self::par_fold_deterministic`].
+    ///
+    /// NaN values are ignored. Returns `None` if the slice is empty, and infinities
+    /// for lanes only made of NaN.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[1.0_f32, -2.0], [f32::NAN, 4.0], [3.0, 0.5]];
+    /// let slice: Slice<[f32; 2]> = Slice::native(&data);
+    /// assert_eq!(slice.par_minmax(1, 4), Some(([1.0, -2.0], [3.0, 4.0])));
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `chunk_elems` is `0`.
+    pub fn par_minmax(&self, chunk_elems: usize, threads: usize) -> Option<(Attr, Attr)> {
+        if self.is_empty() {
+            return None;
+        }
+        let identity = (
+            Attr::splat(Attr::Scalar::from_f64(f64::INFINITY)),
+            Attr::splat(Attr::Scalar::from_f64(f64::NEG_INFINITY)),
+        );
+        let minmax = |(mut min, mut max): (Attr, Attr), (lo, hi): (&Attr, &Attr)| {
+            for lane in 0..Attr::LANES {
+                let (a, b) = (min.lane(lane).to_f64(), lo.lane(lane).to_f64());
+                min.set_lane(lane, Attr::Scalar::from_f64(a.min(b)));
+                let (a, b) = (max.lane(lane).to_f64(), hi.lane(lane).to_f64());
+                max.set_lane(lane, Attr::Scalar::from_f64(a.max(b)));
+            }
+            (min, max)
+        };
+        Some(self.par_fold_deterministic(
+            chunk_elems,
+            threads,
+            identity,
+            |acc, elt| minmax(acc, (elt, elt)),
+            |acc, (lo, hi)| minmax(acc, (&lo, &hi)),
+        ))
+    }
+}
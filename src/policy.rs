@@ -0,0 +1,150 @@
+use crate::math::is_multiple_of;
+use crate::SliceError;
+
+/// Layout of a slice being constructed, validated by a [`ValidationPolicy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LayoutInfo {
+    /// Type name of the attribute read by the slice
+    pub type_name: &'static str,
+    /// Attribute size, in **bytes**
+    pub attr: usize,
+    /// Attribute alignment, in **bytes**
+    pub align: usize,
+    /// Data size, in **bytes**
+    pub size: usize,
+    /// Byte offset of the first attribute
+    pub offset: usize,
+    /// Stride, in **bytes**
+    pub stride: usize,
+    /// Address of the first attribute
+    pub address: usize,
+    /// Size of the window, in **bytes**, an attribute must fit in.
+    ///
+    /// This is the stride for typed constructors, and `None` for raw byte slices.
+    pub element: Option<usize>,
+}
+
+/// Checks run when constructing a slice via [`crate::Slice::with_policy`],
+/// or [`crate::SliceBuilder::policy`].
+///
+/// Each hook validates a single property of the layout, and defaults to the
+/// [`Strict`] check. Policies can override hooks to relax or extend them.
+///
+/// Relaxing a check never leads to undefined behavior: slices always reject
+/// out-of-bounds and unaligned reads, even if the policy accepts them.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{LayoutInfo, Slice, SliceError, ValidationPolicy};
+///
+/// /// Require strides to be a multiple of 4 bytes.
+/// struct FourBytesStride;
+///
+/// impl ValidationPolicy for FourBytesStride {
+///     fn check_stride_alignment(layout: &LayoutInfo) -> Result<(), SliceError> {
+///         if layout.stride % 4 != 0 {
+///             return Err(SliceError::UnalignedStride {
+///                 type_name: layout.type_name,
+///                 stride: layout.stride,
+///             });
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let data = [0_u16; 6];
+/// assert!(Slice::<u16>::with_policy::<FourBytesStride>(&data, 0, 2).is_ok());
+/// assert!(Slice::<u16>::with_policy::<FourBytesStride>(&data, 0, 3).is_err());
+/// ```
+pub trait ValidationPolicy {
+    /// Check that the attribute isn't larger than the stride.
+    fn check_attribute_size(layout: &LayoutInfo) -> Result<(), SliceError> {
+        if layout.attr > layout.stride {
+            return Err(SliceError::AttributeLargerThanStride {
+                type_name: layout.type_name,
+                attr: layout.attr,
+                stride: layout.stride,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check that the offset is in the bounds of the data.
+    fn check_offset_bounds(layout: &LayoutInfo) -> Result<(), SliceError> {
+        if layout.offset > 0 && layout.offset >= layout.size {
+            return Err(SliceError::OffsetOutOfBounds {
+                size: layout.size,
+                offset: layout.offset,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check that the first attribute is aligned.
+    fn check_alignment(layout: &LayoutInfo) -> Result<(), SliceError> {
+        if !is_multiple_of(layout.address, layout.align) {
+            return Err(SliceError::AlignmentFault {
+                type_name: layout.type_name,
+                offset: layout.offset,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check that the stride is a multiple of the attribute alignment.
+    fn check_stride_alignment(layout: &LayoutInfo) -> Result<(), SliceError> {
+        if !is_multiple_of(layout.stride, layout.align) {
+            return Err(SliceError::UnalignedStride {
+                type_name: layout.type_name,
+                stride: layout.stride,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check that the attribute doesn't cross the boundary of the backing element.
+    fn check_element_boundary(layout: &LayoutInfo) -> Result<(), SliceError> {
+        let element = match layout.element {
+            Some(element) if element > 0 => element,
+            _ => return Ok(()),
+        };
+        if layout.offset % element + layout.attr > element {
+            return Err(SliceError::AttributeCrossesElementBoundary {
+                type_name: layout.type_name,
+                attr: layout.attr,
+                offset: layout.offset,
+                element,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Run every check.
+///
+/// This is the policy used by [`crate::Slice::new`], [`crate::Slice::strided`],
+/// and [`crate::Slice::raw`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Strict;
+
+impl ValidationPolicy for Strict {}
+
+/// Only run the checks required for soundness.
+///
+/// Compared to [`Strict`]:
+/// * Attributes can straddle two backing elements
+/// * The stride only needs to be a multiple of the attribute alignment if
+///   the slice has more than one element
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Permissive;
+
+impl ValidationPolicy for Permissive {
+    fn check_stride_alignment(_: &LayoutInfo) -> Result<(), SliceError> {
+        Ok(())
+    }
+
+    fn check_element_boundary(_: &LayoutInfo) -> Result<(), SliceError> {
+        Ok(())
+    }
+}
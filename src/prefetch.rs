@@ -0,0 +1,112 @@
+use bytemuck::Pod;
+
+use crate::{IndexElement, SliceError, SliceInfo, SliceMut};
+
+/// Size of a cache line, in **bytes**, on the supported architectures.
+const CACHE_LINE: usize = 64;
+
+/// Hint the processor to load the cache line of `ptr`, for reading.
+///
+/// Prefetching never faults, and is a no-op on unsupported architectures.
+#[inline(always)]
+fn prefetch_line(ptr: *const u8) {
+    #[cfg(target_arch = "x86_64")]
+    // Safe because prefetching is only a hint, and never dereferences `ptr`.
+    unsafe {
+        use std::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+        _mm_prefetch::<_MM_HINT_T0>(ptr.cast());
+    }
+    #[cfg(all(target_arch = "aarch64", not(miri)))]
+    // Safe because prefetching is only a hint, and never dereferences `ptr`.
+    unsafe {
+        std::arch::asm!(
+            "prfm pldl1keep, [{ptr}]",
+            ptr = in(reg) ptr,
+            options(nostack, readonly, preserves_flags)
+        );
+    }
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    let _ = ptr;
+}
+
+impl<Attr: Pod> SliceInfo<Attr> {
+    /// Hint the processor to load the element at `index` into the cache, for reading.
+    ///
+    /// Every cache line of the element is prefetched. This is only a hint: it has
+    /// no observable effect, and out-of-bounds indices are ignored. Prefetching is
+    /// a no-op on architectures other than `x86_64` and `aarch64`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[0.0_f32; 4]; 16];
+    /// let slice: Slice<[f32; 4]> = Slice::native(&data);
+    /// slice.prefetch(8);
+    /// slice.prefetch(100);
+    /// ```
+    #[inline(always)]
+    pub fn prefetch(&self, index: usize) {
+        if let Some(ptr) = self.base().get_ptr(index) {
+            self.prefetch_ptr(ptr);
+        }
+    }
+
+    /// Prefetch the element starting at `ptr`.
+    #[inline(always)]
+    fn prefetch_ptr(&self, ptr: *const u8) {
+        let size = std::mem::size_of::<Attr>().max(1);
+        let first = ptr as usize / CACHE_LINE;
+        let last = (ptr as usize + size - 1) / CACHE_LINE;
+        for line in 0..=(last - first) {
+            prefetch_line(ptr.wrapping_add(line * CACHE_LINE));
+        }
+    }
+
+    /// Similar to [`Self::gather_into`], but prefetches the element at `indices[i + lookahead]`
+    /// while copying the element at `indices[i]`.
+    ///
+    /// Prefetching hides the memory latency of random accesses, when the elements don't fit
+    /// in the cache. A good `lookahead` depends on the hardware, and the cost of copying
+    /// an element, e.g., `8` to `32`. Out-of-order processors may already overlap these
+    /// independent loads, such that prefetching doesn't help: measure first, e.g., with
+    /// the `gather` benchmark. The output is the same as [`Self::gather_into`],
+    /// whatever the lookahead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{Slice, SliceMut};
+    ///
+    /// let data = [[0_u32, 10], [1, 11], [2, 12]];
+    /// let slice: Slice<u32> = Slice::new(&data, 4);
+    ///
+    /// let mut out = [0_u32; 4];
+    /// let count = slice.gather_into_prefetched(&[2_u16, 0, 0, 1], &mut SliceMut::native(&mut out), 2);
+    /// assert_eq!(count, Ok(4));
+    /// assert_eq!(out, [12, 10, 10, 11]);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// See [`Self::gather_into`].
+    pub fn gather_into_prefetched<I: IndexElement>(
+        &self,
+        indices: &[I],
+        dst: &mut SliceMut<'_, Attr>,
+        lookahead: usize,
+    ) -> Result<usize, SliceError> {
+        let indices = &indices[..indices.len().min(dst.len())];
+        self.check_indices(indices)?;
+        let (start, stride) = (self.base().start, self.stride());
+        let values = indices.iter().enumerate().map(|(i, index)| {
+            if let Some(next) = i.checked_add(lookahead).and_then(|next| indices.get(next)) {
+                // Indices are validated, and thus in bounds.
+                self.prefetch_ptr(start.wrapping_add(next.to_u64() as usize * stride));
+            }
+            self.get_checked(*index)
+        });
+        Ok(dst.write_elements(values))
+    }
+}
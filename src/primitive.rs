@@ -0,0 +1,344 @@
+use bytemuck::Pod;
+use std::ops::Range;
+
+use crate::{ElementLocation, OwnedStrided, Slice, SliceInfo, SliceMut, TrackedSliceMut};
+
+/// Object-safe view over the layout of a strided attribute.
+///
+/// This allows validating attributes of different types together,
+/// see [`validate_primitive`], and mapping an address back to an element,
+/// see [`locate_in_views`](crate::locate_in_views).
+pub trait StridedInfo {
+    /// Number of elements.
+    fn len(&self) -> usize;
+
+    /// `true` if there is no element, `false` otherwise
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Addresses spanned by the elements, from the first byte of the first element
+    /// to one byte past the last byte of the last element.
+    ///
+    /// Pointers are never dereferenced by this crate, and are only meant for diagnostics.
+    /// Defaults to an empty range, for which [`Self::locate`] always returns `None`.
+    fn ptr_range(&self) -> Range<*const u8> {
+        std::ptr::null()..std::ptr::null()
+    }
+
+    /// Stride, in **bytes**.
+    fn byte_stride(&self) -> usize {
+        0
+    }
+
+    /// Size of an element, in **bytes**.
+    fn element_size(&self) -> usize {
+        0
+    }
+
+    /// Alignment of an element, in **bytes**, or `0` if unknown.
+    fn element_align(&self) -> usize {
+        0
+    }
+
+    /// Byte offset of the first element, from the start of the original buffer.
+    fn byte_offset(&self) -> usize {
+        0
+    }
+
+    /// Name of the element type, if known.
+    fn type_name(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Classify the address `addr` against the layout of the elements.
+    ///
+    /// Returns `None` if `addr` is outside [`Self::ptr_range`]. Otherwise, returns
+    /// the index of the element containing `addr`, or of the element preceding
+    /// the gap containing `addr`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{ElementLocation, Slice, StridedInfo};
+    ///
+    /// let data = [[0_u32, 1, 2], [3, 4, 5]];
+    /// let slice: Slice<u32> = Slice::new(&data, 4);
+    /// let addr: *const u8 = (&data[1][0] as *const u32).cast();
+    /// assert_eq!(
+    ///     slice.locate(addr),
+    ///     Some(ElementLocation { index: 0, offset_within_element: 8, in_gap: true })
+    /// );
+    /// ```
+    fn locate(&self, addr: *const u8) -> Option<ElementLocation> {
+        let range = self.ptr_range();
+        if !range.contains(&addr) {
+            return None;
+        }
+        let delta = addr as usize - range.start as usize;
+        let (index, offset_within_element) = match self.byte_stride() {
+            0 => (0, delta),
+            stride => (delta / stride, delta % stride),
+        };
+        Some(ElementLocation {
+            index,
+            offset_within_element,
+            in_gap: offset_within_element >= self.element_size(),
+        })
+    }
+}
+
+/// Problem found in a primitive, see [`PrimitiveError`].
+#[derive(Clone, PartialEq)]
+pub enum PrimitiveIssue {
+    /// The attribute has fewer elements than referenced by the indices.
+    TooShort {
+        /// Attribute name
+        name: String,
+        /// Number of elements in the attribute
+        len: usize,
+        /// Minimum number of elements, i.e., the maximum index plus one
+        required: usize,
+    },
+    /// The attribute doesn't have the same number of elements as the first one.
+    CountMismatch {
+        /// Attribute name
+        name: String,
+        /// Number of elements in the attribute
+        len: usize,
+        /// Number of elements in the first attribute
+        expected: usize,
+    },
+}
+
+impl std::fmt::Debug for PrimitiveIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooShort {
+                name,
+                len,
+                required,
+            } => write!(
+                f,
+                "Attribute '{}' has {} elements, but indices require at least {}",
+                name, len, required
+            ),
+            Self::CountMismatch {
+                name,
+                len,
+                expected,
+            } => write!(
+                f,
+                "Attribute '{}' has {} elements, but {} were expected",
+                name, len, expected
+            ),
+        }
+    }
+}
+
+/// Primitive validation error, listing every problem found.
+#[derive(Clone, PartialEq)]
+pub struct PrimitiveError {
+    /// Problems, in attribute order
+    pub issues: Vec<PrimitiveIssue>,
+}
+
+impl std::fmt::Debug for PrimitiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, issue) in self.issues.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{:?}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+impl PrimitiveError {
+    fn check(issues: Vec<PrimitiveIssue>) -> Result<(), Self> {
+        match issues.is_empty() {
+            true => Ok(()),
+            false => Err(Self { issues }),
+        }
+    }
+}
+
+/// Check that every attribute has enough elements for `indices`.
+///
+/// The maximum index is computed once, and every attribute with fewer than
+/// `max + 1` elements is reported.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{validate_primitive, PrimitiveIssue, Slice};
+///
+/// let indices = [0_u32, 1, 2, 2, 1, 3];
+/// let positions = [[0.0_f32; 3]; 4];
+/// let uvs = [[0.0_f32; 2]; 3];
+///
+/// let err = validate_primitive(
+///     &Slice::native(&indices),
+///     &[
+///         ("position", &Slice::native(&positions)),
+///         ("uv", &Slice::native(&uvs)),
+///     ],
+/// )
+/// .unwrap_err();
+/// assert_eq!(
+///     err.issues,
+///     [PrimitiveIssue::TooShort {
+///         name: "uv".into(),
+///         len: 3,
+///         required: 4
+///     }]
+/// );
+/// ```
+pub fn validate_primitive(
+    indices: &Slice<u32>,
+    attrs: &[(&str, &dyn StridedInfo)],
+) -> Result<(), PrimitiveError> {
+    let mut max = None;
+    indices.for_each_fast(|index| max = max.max(Some(*index)));
+    let required = max.map_or(0, |max| max as usize + 1);
+
+    let issues = attrs
+        .iter()
+        .filter(|(_, attr)| attr.len() < required)
+        .map(|(name, attr)| PrimitiveIssue::TooShort {
+            name: name.to_string(),
+            len: attr.len(),
+            required,
+        })
+        .collect();
+    PrimitiveError::check(issues)
+}
+
+/// Check that every attribute of a non-indexed primitive has the same number of elements.
+///
+/// Attributes are compared to the first one.
+pub fn validate_non_indexed_primitive(
+    attrs: &[(&str, &dyn StridedInfo)],
+) -> Result<(), PrimitiveError> {
+    let Some((_, first)) = attrs.first() else {
+        return Ok(());
+    };
+    let expected = first.len();
+    let issues = attrs
+        .iter()
+        .filter(|(_, attr)| attr.len() != expected)
+        .map(|(name, attr)| PrimitiveIssue::CountMismatch {
+            name: name.to_string(),
+            len: attr.len(),
+            expected,
+        })
+        .collect();
+    PrimitiveError::check(issues)
+}
+
+//
+// Traits implementation
+//
+
+impl<T: Pod> StridedInfo for SliceInfo<T> {
+    fn len(&self) -> usize {
+        SliceInfo::len(self)
+    }
+
+    fn ptr_range(&self) -> Range<*const u8> {
+        let start = self.base().start;
+        let end = match SliceInfo::len(self) {
+            0 => start,
+            len => start
+                .wrapping_add((len - 1) * self.stride())
+                .wrapping_add(std::mem::size_of::<T>()),
+        };
+        start..end
+    }
+
+    fn byte_stride(&self) -> usize {
+        self.stride()
+    }
+
+    fn element_size(&self) -> usize {
+        std::mem::size_of::<T>()
+    }
+
+    fn element_align(&self) -> usize {
+        std::mem::align_of::<T>()
+    }
+
+    fn byte_offset(&self) -> usize {
+        SliceInfo::byte_offset(self)
+    }
+
+    fn type_name(&self) -> Option<&'static str> {
+        Some(std::any::type_name::<T>())
+    }
+}
+
+macro_rules! impl_strided_info {
+    ($name: ident) => {
+        impl<'a, T: Pod> StridedInfo for $name<'a, T> {
+            fn len(&self) -> usize {
+                SliceInfo::len(self)
+            }
+
+            fn ptr_range(&self) -> Range<*const u8> {
+                StridedInfo::ptr_range(&**self)
+            }
+
+            fn byte_stride(&self) -> usize {
+                self.stride()
+            }
+
+            fn element_size(&self) -> usize {
+                std::mem::size_of::<T>()
+            }
+
+            fn element_align(&self) -> usize {
+                std::mem::align_of::<T>()
+            }
+
+            fn byte_offset(&self) -> usize {
+                SliceInfo::byte_offset(self)
+            }
+
+            fn type_name(&self) -> Option<&'static str> {
+                Some(std::any::type_name::<T>())
+            }
+        }
+    };
+}
+
+impl_strided_info!(Slice);
+impl_strided_info!(SliceMut);
+impl_strided_info!(TrackedSliceMut);
+
+impl<T: Pod> StridedInfo for OwnedStrided<T> {
+    fn len(&self) -> usize {
+        OwnedStrided::len(self)
+    }
+
+    fn ptr_range(&self) -> Range<*const u8> {
+        let range = self.as_elements().as_ptr_range();
+        range.start.cast()..range.end.cast()
+    }
+
+    fn byte_stride(&self) -> usize {
+        std::mem::size_of::<T>()
+    }
+
+    fn element_size(&self) -> usize {
+        std::mem::size_of::<T>()
+    }
+
+    fn element_align(&self) -> usize {
+        std::mem::align_of::<T>()
+    }
+
+    fn type_name(&self) -> Option<&'static str> {
+        Some(std::any::type_name::<T>())
+    }
+}
@@ -0,0 +1,264 @@
+//! Access counters for slices, enabled with the `profiling` feature.
+//!
+//! Wrap a slice with [`crate::Slice::instrumented`] or [`crate::SliceMut::instrumented`],
+//! run your pipeline, and call [`report`] to see how many elements each view served.
+//!
+//! Without the `profiling` feature, [`Instrumented`] forwards every call to the slice
+//! without counting anything, and [`report`] is always empty.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use pas::{profiling, Slice};
+//!
+//! let data = [0_u32, 1, 2, 3];
+//! let slice = Slice::<u32>::native(&data).instrumented("example");
+//! let sum: u32 = slice.iter().sum();
+//!
+//! for stats in profiling::report() {
+//!     println!("{}: {} reads, {} bytes", stats.label, stats.reads, stats.bytes);
+//! }
+//! ```
+
+use bytemuck::Pod;
+use std::ops::Deref;
+
+use crate::{SliceInfo, SliceMut};
+
+/// Counters of all views sharing a label, returned by [`report`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ViewStats {
+    /// Label given to [`crate::Slice::instrumented`]
+    pub label: &'static str,
+    /// Number of elements read
+    pub reads: usize,
+    /// Number of elements written, or mutably borrowed
+    pub writes: usize,
+    /// Number of bytes read and written
+    pub bytes: usize,
+}
+
+#[cfg(feature = "profiling")]
+mod counters {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    };
+
+    use super::ViewStats;
+
+    #[derive(Default)]
+    pub(super) struct Counters {
+        reads: AtomicUsize,
+        writes: AtomicUsize,
+        bytes: AtomicUsize,
+    }
+
+    /// Counters of every label, in creation order.
+    static REGISTRY: Mutex<Vec<(&'static str, Arc<Counters>)>> = Mutex::new(Vec::new());
+
+    /// Handle to the counters of a label.
+    pub(super) struct Probe(Arc<Counters>);
+
+    impl Probe {
+        pub(super) fn new(label: &'static str) -> Self {
+            let mut registry = REGISTRY.lock().unwrap();
+            let counters = match registry.iter().find(|(l, _)| *l == label) {
+                Some((_, counters)) => counters.clone(),
+                None => {
+                    let counters = Arc::new(Counters::default());
+                    registry.push((label, counters.clone()));
+                    counters
+                }
+            };
+            Self(counters)
+        }
+
+        #[inline]
+        pub(super) fn read(&self, count: usize, bytes: usize) {
+            self.0.reads.fetch_add(count, Ordering::Relaxed);
+            self.0.bytes.fetch_add(bytes, Ordering::Relaxed);
+        }
+
+        #[inline]
+        pub(super) fn write(&self, count: usize, bytes: usize) {
+            self.0.writes.fetch_add(count, Ordering::Relaxed);
+            self.0.bytes.fetch_add(bytes, Ordering::Relaxed);
+        }
+    }
+
+    pub(super) fn report() -> Vec<ViewStats> {
+        let registry = REGISTRY.lock().unwrap();
+        registry
+            .iter()
+            .map(|(label, counters)| ViewStats {
+                label,
+                reads: counters.reads.load(Ordering::Relaxed),
+                writes: counters.writes.load(Ordering::Relaxed),
+                bytes: counters.bytes.load(Ordering::Relaxed),
+            })
+            .collect()
+    }
+
+    pub(super) fn reset() {
+        let registry = REGISTRY.lock().unwrap();
+        for (_, counters) in registry.iter() {
+            counters.reads.store(0, Ordering::Relaxed);
+            counters.writes.store(0, Ordering::Relaxed);
+            counters.bytes.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(not(feature = "profiling"))]
+mod counters {
+    use super::ViewStats;
+
+    /// No-op probe, optimized away.
+    pub(super) struct Probe;
+
+    impl Probe {
+        #[inline(always)]
+        pub(super) fn new(_: &'static str) -> Self {
+            Self
+        }
+
+        #[inline(always)]
+        pub(super) fn read(&self, _: usize, _: usize) {}
+
+        #[inline(always)]
+        pub(super) fn write(&self, _: usize, _: usize) {}
+    }
+
+    pub(super) fn report() -> Vec<ViewStats> {
+        Vec::new()
+    }
+
+    pub(super) fn reset() {}
+}
+
+use counters::Probe;
+
+/// Counters of every label, in creation order.
+///
+/// Views created with the same label share their counters.
+pub fn report() -> Vec<ViewStats> {
+    counters::report()
+}
+
+/// Set every counter to `0`.
+pub fn reset() {
+    counters::reset()
+}
+
+/// Slice wrapper counting accesses, see the [`crate::profiling`] module.
+pub struct Instrumented<S> {
+    inner: S,
+    probe: Probe,
+}
+
+impl<S> Instrumented<S> {
+    pub(crate) fn new(inner: S, label: &'static str) -> Self {
+        Self {
+            inner,
+            probe: Probe::new(label),
+        }
+    }
+
+    /// Remove the wrapper.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S, T> Instrumented<S>
+where
+    S: Deref<Target = SliceInfo<T>>,
+    T: Pod,
+{
+    /// Number of elements in the slice.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// `true` if the slice has size `0`, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Counted version of [`crate::SliceInfo::get()`].
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let value = self.inner.get(index)?;
+        self.probe.read(1, std::mem::size_of::<T>());
+        Some(value)
+    }
+
+    /// Iterate over the slice, counting each visited element.
+    pub fn iter(&self) -> InstrumentedIter<'_, T> {
+        InstrumentedIter {
+            inner: &*self.inner,
+            probe: &self.probe,
+            index: 0,
+        }
+    }
+}
+
+impl<'a, T: Pod> Instrumented<SliceMut<'a, T>> {
+    /// Counted version of [`crate::SliceMut::get_mut()`].
+    ///
+    /// The element is counted as written.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let value = self.inner.get_mut(index)?;
+        self.probe.write(1, std::mem::size_of::<T>());
+        Some(value)
+    }
+
+    /// Counted version of [`crate::SliceMut::copy_from_slice()`].
+    pub fn copy_from_slice<V: Pod>(&mut self, src: &[V]) {
+        self.inner.copy_from_slice(src);
+        self.probe.write(src.len(), std::mem::size_of_val(src));
+    }
+
+    /// Counted version of [`crate::SliceMut::fill()`].
+    pub fn fill(&mut self, value: T) {
+        self.inner.fill(value);
+        let len = self.inner.len();
+        self.probe.write(len, len * std::mem::size_of::<T>());
+    }
+}
+
+impl<S, T> std::ops::Index<usize> for Instrumented<S>
+where
+    S: Deref<Target = SliceInfo<T>>,
+    T: Pod,
+{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index ouf of bounds")
+    }
+}
+
+impl<'a, T: Pod> std::ops::IndexMut<usize> for Instrumented<SliceMut<'a, T>> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut(index).expect("index ouf of bounds")
+    }
+}
+
+/// Iterator for the [`Instrumented`] type.
+pub struct InstrumentedIter<'s, T: Pod> {
+    inner: &'s SliceInfo<T>,
+    probe: &'s Probe,
+    index: usize,
+}
+
+impl<'s, T: Pod> Iterator for InstrumentedIter<'s, T> {
+    type Item = &'s T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.inner.get(self.index)?;
+        self.index += 1;
+        self.probe.read(1, std::mem::size_of::<T>());
+        Some(value)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{IndexElement, SliceInfo, SliceMut};
+
+/// Indices are wrapped with a modulus of zero, by [`SliceMut::wrap_indices`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WrapError;
+
+impl<I: IndexElement> SliceInfo<I> {
+    /// Number of indices larger than `max`, i.e., changed by [`SliceMut::clamp_indices`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let indices = [0_u16, 7, 3, 8, 9];
+    /// assert_eq!(Slice::native(&indices).count_out_of_range(7), 2);
+    /// ```
+    pub fn count_out_of_range(&self, max: I) -> usize {
+        let max = max.to_u64();
+        let mut count = 0;
+        self.for_each_fast(|index| count += (index.to_u64() > max) as usize);
+        count
+    }
+}
+
+impl<'a, I: IndexElement> SliceMut<'a, I> {
+    /// Clamp every index in place, such that no index is larger than `max`.
+    ///
+    /// Bytes between indices are left untouched.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::SliceMut;
+    ///
+    /// let mut indices = [0_u32, 7, 3, 8, 9];
+    /// SliceMut::native(&mut indices).clamp_indices(7);
+    /// assert_eq!(indices, [0, 7, 3, 7, 7]);
+    /// ```
+    pub fn clamp_indices(&mut self, max: I) {
+        self.for_each_fast_mut(|index| *index = index.min_index(max));
+    }
+
+    /// Wrap every index in place, such that every index is smaller than `modulus`.
+    ///
+    /// Bytes between indices are left untouched.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::SliceMut;
+    ///
+    /// let mut indices = [0_u32, 7, 3, 8, 9];
+    /// SliceMut::native(&mut indices).wrap_indices(4).unwrap();
+    /// assert_eq!(indices, [0, 3, 3, 0, 1]);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`WrapError`] if `modulus` is zero, without modifying any index.
+    pub fn wrap_indices(&mut self, modulus: I) -> Result<(), WrapError> {
+        if modulus.to_u64() == 0 {
+            return Err(WrapError);
+        }
+        self.for_each_fast_mut(|index| *index = index.rem_index(modulus));
+        Ok(())
+    }
+}
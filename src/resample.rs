@@ -0,0 +1,79 @@
+use crate::{Float, FloatLanes, SliceInfo, SliceMut};
+
+/// Linear interpolation between `a` and `b`, lane by lane, computed in `f64`.
+fn lerp<T: FloatLanes>(a: &T, b: &T, t: f64) -> T {
+    let mut value = *a;
+    for lane in 0..T::LANES {
+        let (a, b) = (a.lane(lane).to_f64(), b.lane(lane).to_f64());
+        value.set_lane(lane, T::Scalar::from_f64(a + (b - a) * t));
+    }
+    value
+}
+
+impl<T: FloatLanes> SliceInfo<T> {
+    /// Create an iterator over `new_len` values, linearly interpolated at evenly spaced
+    /// positions across the elements.
+    ///
+    /// The first and last values are the first and last elements, such that
+    /// `new_len == len()` yields the elements unchanged. Degenerate cases:
+    /// - An empty slice yields no value, whatever `new_len`
+    /// - A slice with a single element yields it `new_len` times
+    /// - `new_len == 1` yields the first element
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[0.0_f32, 0.0], [10.0, 0.0], [20.0, 0.0]];
+    /// let x: Slice<f32> = Slice::new(&data, 0);
+    /// assert!(x.resampled(5).eq([0.0, 5.0, 10.0, 15.0, 20.0]));
+    /// ```
+    pub fn resampled(&self, new_len: usize) -> impl ExactSizeIterator<Item = T> + '_ {
+        let len = self.len();
+        let count = match len {
+            0 => 0,
+            _ => new_len,
+        };
+        // Position `i * (len - 1) / (new_len - 1)`, computed exactly with integers.
+        let (span, steps) = (
+            len.saturating_sub(1) as u128,
+            new_len.saturating_sub(1) as u128,
+        );
+        (0..count).map(move |i| {
+            let position = i as u128 * span;
+            let (index, remainder) = match steps {
+                0 => (0, 0),
+                _ => (position / steps, position % steps),
+            };
+            let a = self.get(index as usize).unwrap();
+            match remainder {
+                0 => *a,
+                _ => {
+                    let b = self.get(index as usize + 1).unwrap();
+                    lerp(a, b, remainder as f64 / steps as f64)
+                }
+            }
+        })
+    }
+
+    /// Write the elements, resampled to `out.len()` values, into `out`.
+    ///
+    /// See [`Self::resampled`]. Returns the number of values written, i.e., `0`
+    /// for an empty slice, `out.len()` otherwise.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{Slice, SliceMut};
+    ///
+    /// let polyline = [[0.0_f32, 0.0], [3.0, 6.0]];
+    /// let mut lod = [[0.0_f32; 2]; 4];
+    /// let count = Slice::native(&polyline).resample_into(&mut SliceMut::native(&mut lod));
+    /// assert_eq!(count, 4);
+    /// assert_eq!(lod, [[0.0, 0.0], [1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+    /// ```
+    pub fn resample_into(&self, out: &mut SliceMut<T>) -> usize {
+        out.write_elements(self.resampled(out.len()))
+    }
+}
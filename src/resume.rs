@@ -0,0 +1,141 @@
+use bytemuck::Pod;
+
+use crate::{Slice, SliceMut};
+
+/// Position where a bulk operation stopped, used to continue it on the
+/// remaining elements.
+///
+/// Returned by [`SliceMut::write_from_iter`], [`SliceMut::assign_from_iter`],
+/// [`SliceMut::try_apply_with_resumable`], and [`crate::ApplyState::resume`].
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::SliceMut;
+///
+/// let mut data = [0_u32; 5];
+/// let mut slice: SliceMut<u32> = SliceMut::native(&mut data);
+///
+/// let resume = slice.write_from_iter([1, 2]);
+/// let second = resume.remaining_mut(&mut slice).write_from_iter([3, 4, 5, 6]);
+/// let resume = resume.then(second);
+/// assert_eq!(resume.next_index(), 5);
+/// assert_eq!(data, [1, 2, 3, 4, 5]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Resume {
+    next: usize,
+}
+
+impl Resume {
+    /// Create a position starting at the element at index `next`.
+    pub fn at(next: usize) -> Self {
+        Self { next }
+    }
+
+    /// Index of the next element to process.
+    pub fn next_index(&self) -> usize {
+        self.next
+    }
+
+    /// `true` if no element of a slice with `len` elements is left, `false` otherwise
+    pub fn is_finished(&self, len: usize) -> bool {
+        self.next >= len
+    }
+
+    /// Position in the original slice, once an operation continued on
+    /// [`Self::remaining_mut`] stopped at `then`.
+    pub fn then(self, then: Resume) -> Resume {
+        Resume::at(self.next + then.next)
+    }
+
+    /// View over the elements left to process in `slice`, the slice the
+    /// operation ran on.
+    ///
+    /// The view is empty if every element was processed.
+    pub fn remaining<'a, Attr: Pod>(&self, slice: &Slice<'a, Attr>) -> Slice<'a, Attr> {
+        slice.split_at(self.next.min(slice.len())).1
+    }
+
+    /// Mutable version of [`Self::remaining`].
+    pub fn remaining_mut<'s, Attr: Pod>(
+        &self,
+        slice: &'s mut SliceMut<'_, Attr>,
+    ) -> SliceMut<'s, Attr> {
+        let mid = self.next.min(slice.len());
+        slice.split_at_mut(mid).1
+    }
+}
+
+impl<'a, Attr: Pod> SliceMut<'a, Attr> {
+    /// Write `values` to consecutive elements, stopping at the end of the slice,
+    /// or once `values` is exhausted.
+    ///
+    /// Returns the position after the last element written. Values past the
+    /// end of the slice are not consumed.
+    pub fn write_from_iter(&mut self, values: impl IntoIterator<Item = Attr>) -> Resume {
+        Resume::at(self.write_elements(values))
+    }
+
+    /// Similar to [`Self::write_from_iter`], but stops at the first error of `values`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns the position of the element that wasn't written, along with the
+    /// error. Elements before it are written, elements after it are left untouched.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::SliceMut;
+    ///
+    /// let mut data = [0_u32; 4];
+    /// let mut slice: SliceMut<u32> = SliceMut::native(&mut data);
+    ///
+    /// let parsed = ["1", "2", "x", "4"].map(|s| s.parse::<u32>());
+    /// let (resume, _) = slice.assign_from_iter(parsed).unwrap_err();
+    /// assert_eq!(resume.next_index(), 2);
+    ///
+    /// let fixed = ["3", "4"].map(|s| s.parse::<u32>());
+    /// resume.remaining_mut(&mut slice).assign_from_iter(fixed).unwrap();
+    /// assert_eq!(data, [1, 2, 3, 4]);
+    /// ```
+    pub fn assign_from_iter<E>(
+        &mut self,
+        values: impl IntoIterator<Item = Result<Attr, E>>,
+    ) -> Result<Resume, (Resume, E)> {
+        let mut error = None;
+        let values = values.into_iter().map_while(|value| match value {
+            Ok(value) => Some(value),
+            Err(err) => {
+                error = Some(err);
+                None
+            }
+        });
+        let resume = self.write_from_iter(values);
+        match error {
+            Some(err) => Err((resume, err)),
+            None => Ok(resume),
+        }
+    }
+
+    /// Similar to [`Self::try_apply_with`], but returns the position of the failing
+    /// element, to continue with [`Resume::remaining_mut`].
+    ///
+    /// Returns the position after the last element processed on success.
+    ///
+    /// ## Errors
+    ///
+    /// Returns the position of the failing element, along with the error returned by `f`.
+    /// The failing element is the first of the remaining ones.
+    pub fn try_apply_with_resumable<A: Pod, E>(
+        &mut self,
+        other: &Slice<A>,
+        f: impl FnMut(usize, &mut Attr, &A) -> Result<(), E>,
+    ) -> Result<Resume, (Resume, E)> {
+        match self.try_apply_with(other, f) {
+            Ok(()) => Ok(Resume::at(self.len().min(other.len()))),
+            Err((index, err)) => Err((Resume::at(index), err)),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use bytemuck::Pod;
+
+use crate::math::is_multiple_of;
+use crate::SliceMut;
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl<'a, Attr: Pod> SliceMut<'a, Attr> {
+    /// Rotate the elements in place, such that the element at index `k` becomes the first one.
+    ///
+    /// Unlike the std `rotate_left`, `k` can be larger than the length, in which case
+    /// the slice is rotated by `k % len` elements. Bytes between elements are left untouched.
+    ///
+    /// Elements are moved one at a time, using a single scratch element.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::SliceMut;
+    ///
+    /// let mut data = [[0_u32, 10], [1, 11], [2, 12], [3, 13]];
+    /// let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 0);
+    /// slice.rotate_left(1);
+    /// assert_eq!(data, [[1, 10], [2, 11], [3, 12], [0, 13]]);
+    /// ```
+    pub fn rotate_left(&mut self, k: usize) {
+        let len = self.len();
+        if len < 2 || is_multiple_of(k, len) {
+            return;
+        }
+        let k = k % len;
+        let (start, stride) = (self.base().start as *mut u8, self.stride());
+        let element = |index: usize| start.wrapping_add(index * stride).cast::<Attr>();
+
+        // Juggling: following `i -> i + k` visits every element in `gcd(len, k)` cycles.
+        for cycle in 0..gcd(len, k) {
+            // Safe because every index is in bounds, and the slice is mutably borrowed.
+            unsafe {
+                let scratch = element(cycle).read();
+                let mut hole = cycle;
+                loop {
+                    let next = (hole + k) % len;
+                    if next == cycle {
+                        break;
+                    }
+                    element(hole).write(element(next).read());
+                    hole = next;
+                }
+                element(hole).write(scratch);
+            }
+        }
+    }
+
+    /// Rotate the elements in place, such that the element at index `len - k` becomes the first one.
+    ///
+    /// See [`Self::rotate_left()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::SliceMut;
+    ///
+    /// let mut data = [0_u32, 1, 2, 3, 4];
+    /// let mut slice: SliceMut<u32> = SliceMut::native(&mut data);
+    /// slice.rotate_right(7);
+    /// assert_eq!(data, [3, 4, 0, 1, 2]);
+    /// ```
+    pub fn rotate_right(&mut self, k: usize) {
+        let len = self.len();
+        if len > 0 {
+            self.rotate_left(len - k % len);
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{Float, FloatLanes, Resume, Slice, SliceError, SliceMut};
+
+/// Error of the sanitized copies, e.g., [`SliceMut::copy_from_slice_sanitized`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SanitizeError {
+    /// The source doesn't fit in the destination, i.e., [`SliceError::TooShort`].
+    Slice(SliceError),
+    /// A float lane is NaN or infinite, with [`SanitizePolicy::Reject`].
+    NonFinite {
+        /// Index of the element in the source
+        index: usize,
+        /// Index of the lane in the element
+        lane: usize,
+    },
+}
+
+/// Handling of NaN and infinite float lanes, applied while writing.
+///
+/// Used by [`SliceMut::copy_from_slice_sanitized`], [`SliceMut::copy_from_strided_sanitized`],
+/// and [`SliceMut::write_from_iter_sanitized`].
+///
+/// Only float attributes can be sanitized, see [`FloatLanes`]:
+///
+/// ```rust,compile_fail
+/// use pas::{SanitizePolicy, SliceMut};
+///
+/// let mut data = [0_u32; 2];
+/// let mut slice: SliceMut<u32> = SliceMut::native(&mut data);
+/// slice.copy_from_slice_sanitized(&[1, 2], SanitizePolicy::Reject);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SanitizePolicy<S> {
+    /// Replace each non-finite lane by the given value
+    ReplaceWith(S),
+    /// Clamp infinities to the largest finite values, and replace NaN by `0`
+    ClampToFinite,
+    /// Stop at the first non-finite lane, and return [`SanitizeError::NonFinite`]
+    Reject,
+}
+
+impl<S: Float> SanitizePolicy<S> {
+    /// Sanitize the lanes of `value` in place.
+    ///
+    /// Returns the index of the first non-finite lane, if rejected.
+    fn apply<T: FloatLanes<Scalar = S>>(self, value: &mut T) -> Result<(), usize> {
+        for lane in 0..T::LANES {
+            let scalar = value.lane(lane).to_f64();
+            if scalar.is_finite() {
+                continue;
+            }
+            let sanitized = match self {
+                Self::ReplaceWith(replacement) => replacement,
+                Self::ClampToFinite if scalar.is_nan() => S::from_f64(0.0),
+                Self::ClampToFinite if scalar > 0.0 => S::MAX,
+                Self::ClampToFinite => S::from_f64(-S::MAX.to_f64()),
+                Self::Reject => return Err(lane),
+            };
+            value.set_lane(lane, sanitized);
+        }
+        Ok(())
+    }
+}
+
+impl<'a, Attr: FloatLanes> SliceMut<'a, Attr> {
+    /// Copy `src` into the first elements, sanitizing non-finite lanes with `policy`.
+    ///
+    /// Lanes are checked while copying, without a second traversal.
+    ///
+    /// ## Errors
+    ///
+    /// * [`SanitizeError::Slice`], with [`SliceError::TooShort`], if the length of `src`
+    ///   is bigger than the length of `self`. Nothing is written in this case
+    /// * [`SanitizeError::NonFinite`] with [`SanitizePolicy::Reject`], for the first
+    ///   non-finite lane. Elements before it are written, the others are left untouched
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{SanitizeError, SanitizePolicy, SliceMut};
+    ///
+    /// let mut data = [[0.0_f32; 3]; 2];
+    /// let mut slice: SliceMut<[f32; 3]> = SliceMut::native(&mut data);
+    /// let src = [[1.0, f32::NAN, 2.0], [f32::INFINITY, 0.0, 0.0]];
+    ///
+    /// slice.copy_from_slice_sanitized(&src, SanitizePolicy::ClampToFinite).unwrap();
+    /// assert_eq!(data, [[1.0, 0.0, 2.0], [f32::MAX, 0.0, 0.0]]);
+    ///
+    /// let mut slice: SliceMut<[f32; 3]> = SliceMut::native(&mut data);
+    /// assert_eq!(
+    ///     slice.copy_from_slice_sanitized(&src, SanitizePolicy::Reject),
+    ///     Err(SanitizeError::NonFinite { index: 0, lane: 1 })
+    /// );
+    /// ```
+    pub fn copy_from_slice_sanitized(
+        &mut self,
+        src: &[Attr],
+        policy: SanitizePolicy<Attr::Scalar>,
+    ) -> Result<(), SanitizeError> {
+        if src.len() > self.len() {
+            return Err(SanitizeError::Slice(SliceError::TooShort {
+                expected: src.len(),
+                len: self.len(),
+            }));
+        }
+        self.write_sanitized(src.iter().copied(), policy)
+            .map_err(|(_, err)| err)?;
+        Ok(())
+    }
+
+    /// Similar to [`Self::copy_from_slice_sanitized`], but copies from a strided view.
+    pub fn copy_from_strided_sanitized(
+        &mut self,
+        src: &Slice<Attr>,
+        policy: SanitizePolicy<Attr::Scalar>,
+    ) -> Result<(), SanitizeError> {
+        if src.len() > self.len() {
+            return Err(SanitizeError::Slice(SliceError::TooShort {
+                expected: src.len(),
+                len: self.len(),
+            }));
+        }
+        self.write_sanitized(src.iter().copied(), policy)
+            .map_err(|(_, err)| err)?;
+        Ok(())
+    }
+
+    /// Similar to [`Self::write_from_iter`], but sanitizes non-finite lanes with `policy`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SanitizeError::NonFinite`] with [`SanitizePolicy::Reject`], along with
+    /// the position of the rejected element, which isn't written. The index of the
+    /// error is relative to the first value of `values`.
+    pub fn write_from_iter_sanitized(
+        &mut self,
+        values: impl IntoIterator<Item = Attr>,
+        policy: SanitizePolicy<Attr::Scalar>,
+    ) -> Result<Resume, (Resume, SanitizeError)> {
+        self.write_sanitized(values, policy)
+    }
+
+    fn write_sanitized(
+        &mut self,
+        values: impl IntoIterator<Item = Attr>,
+        policy: SanitizePolicy<Attr::Scalar>,
+    ) -> Result<Resume, (Resume, SanitizeError)> {
+        let mut written = 0;
+        for (dst, mut value) in self.iter().zip(values) {
+            if let Err(lane) = policy.apply(&mut value) {
+                let err = SanitizeError::NonFinite {
+                    index: written,
+                    lane,
+                };
+                return Err((Resume::at(written), err));
+            }
+            *dst = value;
+            written += 1;
+        }
+        Ok(Resume::at(written))
+    }
+}
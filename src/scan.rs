@@ -0,0 +1,199 @@
+use bytemuck::Pod;
+
+use crate::{private, SliceInfo, SliceMut};
+
+/// Unsigned integer type supporting prefix sums, i.e., `u8`, `u16`, `u32`, `u64`, or `usize`.
+///
+/// This trait is sealed and can't be implemented outside of this crate.
+pub trait ScanElement: Pod + private::Sealed {
+    /// Additive identity.
+    const ZERO: Self;
+
+    /// Sum of `self` and `other`, according to `overflow`.
+    ///
+    /// Returns `None` on overflow, with [`OverflowMode::Checked`].
+    fn add(self, other: Self, overflow: OverflowMode) -> Option<Self>;
+}
+
+macro_rules! impl_scan_element {
+    ($($t: ty),*) => {
+        $(
+            impl ScanElement for $t {
+                const ZERO: Self = 0;
+
+                #[inline(always)]
+                fn add(self, other: Self, overflow: OverflowMode) -> Option<Self> {
+                    match overflow {
+                        OverflowMode::Wrapping => Some(self.wrapping_add(other)),
+                        OverflowMode::Checked => self.checked_add(other),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+// Already sealed as index elements.
+impl_scan_element!(u8, u16, u32, u64, usize);
+
+/// A sum exceeds the maximum value of the element type, with [`OverflowMode::Checked`].
+///
+/// Raised by [`SliceMut::prefix_sum_exclusive`] and similar methods.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScanError {
+    /// Index of the element whose addition overflows
+    pub index: usize,
+}
+
+/// Behavior of sums exceeding the maximum value of the element type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OverflowMode {
+    /// Wrap around the maximum value
+    Wrapping,
+    /// Stop with a [`ScanError`]
+    Checked,
+}
+
+/// Kind of prefix sum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Scan {
+    /// Each element is the sum of the **previous** elements, starting at zero
+    Exclusive,
+    /// Each element is the sum of the previous elements, and itself
+    Inclusive,
+}
+
+/// Write the prefix sum of `src` into `dst`, in a single pass.
+///
+/// `dst` is called with the index, and result of each element. Returns the total sum.
+fn scan<'s, T: ScanElement>(
+    src: impl Iterator<Item = &'s T>,
+    mut dst: impl FnMut(usize, T),
+    kind: Scan,
+    overflow: OverflowMode,
+) -> Result<T, ScanError> {
+    let mut sum = T::ZERO;
+    for (index, value) in src.enumerate() {
+        // Checked before writing, such that the failing element is untouched.
+        let next = sum.add(*value, overflow).ok_or(ScanError { index })?;
+        match kind {
+            Scan::Exclusive => dst(index, sum),
+            Scan::Inclusive => dst(index, next),
+        }
+        sum = next;
+    }
+    Ok(sum)
+}
+
+impl<T: ScanElement> SliceInfo<T> {
+    /// Write the prefix sum of the elements into `out`.
+    ///
+    /// Only the common prefix of both slices is processed. Returns the sum
+    /// of the processed elements.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{OverflowMode, Scan, Slice, SliceMut};
+    ///
+    /// let counts = [[3_u32, 0], [1, 0], [4, 0]];
+    /// let counts: Slice<u32> = Slice::new(&counts, 0);
+    /// let mut offsets = [0_u32; 3];
+    /// let total = counts.scan_into(
+    ///     &mut SliceMut::native(&mut offsets),
+    ///     Scan::Exclusive,
+    ///     OverflowMode::Checked,
+    /// );
+    /// assert_eq!(total, Ok(8));
+    /// assert_eq!(offsets, [0, 3, 4]);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// With [`OverflowMode::Checked`], returns a [`ScanError`] with the index
+    /// of the element whose addition overflows. Elements of `out` before this index
+    /// are written, the others are untouched.
+    pub fn scan_into(
+        &self,
+        out: &mut SliceMut<T>,
+        kind: Scan,
+        overflow: OverflowMode,
+    ) -> Result<T, ScanError> {
+        let (start, stride) = (out.base().start as *mut u8, out.stride());
+        let src = self.elements().take(out.len());
+        scan(
+            src,
+            // Safe because `index` is in bounds of `out`, which is mutably borrowed.
+            |index, value| unsafe { start.add(index * stride).cast::<T>().write(value) },
+            kind,
+            overflow,
+        )
+    }
+}
+
+impl<'a, T: ScanElement> SliceMut<'a, T> {
+    /// Replace each element by the sum of the **previous** elements, starting at zero.
+    ///
+    /// The sum is computed in place, in a single pass. Returns the sum of every element,
+    /// e.g., the total size when building an offset table from counts.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{OverflowMode, SliceMut};
+    ///
+    /// // Offset and count of each cluster.
+    /// let mut clusters = [[0_u32, 3], [0, 1], [0, 4]];
+    /// let mut counts: SliceMut<u32> = SliceMut::new(&mut clusters, 4);
+    /// assert_eq!(counts.prefix_sum_exclusive(OverflowMode::Checked), Ok(8));
+    /// assert_eq!(clusters, [[0, 0], [0, 3], [0, 4]]);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// With [`OverflowMode::Checked`], returns a [`ScanError`] with the index
+    /// of the element whose addition overflows. Elements before this index are updated,
+    /// the others are untouched.
+    pub fn prefix_sum_exclusive(&mut self, overflow: OverflowMode) -> Result<T, ScanError> {
+        self.prefix_sum(Scan::Exclusive, overflow)
+    }
+
+    /// Replace each element by the sum of the previous elements, and itself.
+    ///
+    /// See [`Self::prefix_sum_exclusive`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{OverflowMode, ScanError, SliceMut};
+    ///
+    /// let mut counts = [200_u8, 50, 10, 1];
+    /// let mut slice: SliceMut<u8> = SliceMut::native(&mut counts);
+    /// assert_eq!(
+    ///     slice.prefix_sum_inclusive(OverflowMode::Checked),
+    ///     Err(ScanError { index: 2 })
+    /// );
+    /// assert_eq!(counts, [200, 250, 10, 1]);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// See [`Self::prefix_sum_exclusive`].
+    pub fn prefix_sum_inclusive(&mut self, overflow: OverflowMode) -> Result<T, ScanError> {
+        self.prefix_sum(Scan::Inclusive, overflow)
+    }
+
+    fn prefix_sum(&mut self, kind: Scan, overflow: OverflowMode) -> Result<T, ScanError> {
+        let (start, stride) = (self.base().start as *mut u8, self.stride());
+        let element = |index: usize| start.wrapping_add(index * stride).cast::<T>();
+        // Each element is read before being written, and never read again.
+        // Safe because every index is in bounds, and the slice is mutably borrowed.
+        let src = (0..self.len()).map(|index| unsafe { &*element(index) });
+        scan(
+            src,
+            |index, value| unsafe { element(index).write(value) },
+            kind,
+            overflow,
+        )
+    }
+}
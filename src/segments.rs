@@ -0,0 +1,213 @@
+use crate::shared_impl::SliceBase;
+use crate::{ByteWindowSlice, Slice, SliceError, Strict};
+
+/// Type name reported in the errors of segments.
+const SEGMENT_NAME: &str = "segment";
+
+/// Layout of a segment of packed elements in a byte buffer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SegmentDesc {
+    /// Byte offset of the first element
+    pub byte_offset: usize,
+    /// Number of elements
+    pub count: usize,
+    /// Size of an element, in **bytes**
+    pub elem_size: usize,
+    /// Alignment of an element, in **bytes**
+    pub elem_align: usize,
+}
+
+impl SegmentDesc {
+    /// Create a description of `count` elements of `elem_size` bytes, aligned
+    /// to `elem_align` bytes, starting at byte `byte_offset`.
+    pub fn new(byte_offset: usize, count: usize, elem_size: usize, elem_align: usize) -> Self {
+        Self {
+            byte_offset,
+            count,
+            elem_size,
+            elem_align,
+        }
+    }
+
+    /// Create a description of `count` elements of type `T`, starting at byte `byte_offset`.
+    pub fn of<T>(byte_offset: usize, count: usize) -> Self {
+        Self::new(
+            byte_offset,
+            count,
+            std::mem::size_of::<T>(),
+            std::mem::align_of::<T>(),
+        )
+    }
+
+    /// Byte range spanned by the elements.
+    pub fn byte_range(&self) -> Result<std::ops::Range<usize>, SliceError> {
+        self.count
+            .checked_mul(self.elem_size)
+            .and_then(|bytes| bytes.checked_add(self.byte_offset))
+            .map(|end| self.byte_offset..end)
+            .ok_or(SliceError::Overflow)
+    }
+}
+
+/// Arrays of different types, packed one after the other in a byte buffer.
+///
+/// Segments are validated once, when created, and are then viewed by index with
+/// [`Self::typed`], or [`Self::erased`].
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{AlignedBytes, SegmentDesc, Segments, Slice};
+///
+/// let mut bytes = AlignedBytes::zeroed(0, 4);
+/// bytes.extend_from_slice(bytemuck::cast_slice(&[[0.0_f32, 1.0], [2.0, 3.0]]));
+/// bytes.extend_from_slice(bytemuck::cast_slice(&[0_u16, 1, 1, 0]));
+///
+/// let segments = Segments::new(
+///     &bytes,
+///     vec![SegmentDesc::of::<[f32; 2]>(0, 2), SegmentDesc::of::<u16>(16, 4)],
+/// )
+/// .unwrap();
+/// let positions: Slice<[f32; 2]> = segments.typed(0).unwrap();
+/// let indices: Slice<u16> = segments.typed(1).unwrap();
+/// assert_eq!(positions[usize::from(indices[2])], [2.0, 3.0]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Segments<'a> {
+    data: &'a [u8],
+    descs: Vec<SegmentDesc>,
+}
+
+impl<'a> Segments<'a> {
+    /// Describe the segments `descs` of `data`.
+    ///
+    /// ## Errors
+    ///
+    /// - [`SliceError::OffsetOutOfBounds`] if a segment ends after `data`
+    /// - [`SliceError::AlignmentFault`] if a segment isn't aligned to its elements
+    /// - [`SliceError::OverlappingSegments`] if two segments share a byte
+    /// - [`SliceError::Overflow`] if the end of a segment overflows
+    pub fn new(data: &'a [u8], descs: impl Into<Vec<SegmentDesc>>) -> Result<Self, SliceError> {
+        let segments = Self::overlapping(data, descs)?;
+        segments.check_disjoint()?;
+        Ok(segments)
+    }
+
+    /// Similar to [`Self::new`], but segments are allowed to overlap, e.g., to view
+    /// the same bytes as different types.
+    ///
+    /// ## Errors
+    ///
+    /// See [`Self::new`]. [`SliceError::OverlappingSegments`] is never returned.
+    pub fn overlapping(
+        data: &'a [u8],
+        descs: impl Into<Vec<SegmentDesc>>,
+    ) -> Result<Self, SliceError> {
+        let descs = descs.into();
+        for desc in &descs {
+            let range = desc.byte_range()?;
+            if range.end > data.len() {
+                return Err(SliceError::OffsetOutOfBounds {
+                    size: data.len(),
+                    offset: range.end,
+                });
+            }
+            let address = (data.as_ptr() as usize).wrapping_add(desc.byte_offset);
+            if !desc.elem_align.is_power_of_two() || address % desc.elem_align != 0 {
+                return Err(SliceError::AlignmentFault {
+                    type_name: SEGMENT_NAME,
+                    offset: desc.byte_offset,
+                });
+            }
+        }
+        Ok(Self { data, descs })
+    }
+
+    /// Check that no two non-empty segments share a byte.
+    fn check_disjoint(&self) -> Result<(), SliceError> {
+        // Ranges are validated, and thus don't overflow.
+        let mut ranges: Vec<(usize, std::ops::Range<usize>)> = self
+            .iter()
+            .map(|desc| desc.byte_range().unwrap())
+            .enumerate()
+            .filter(|(_, range)| !range.is_empty())
+            .collect();
+        ranges.sort_by_key(|(_, range)| range.start);
+        match ranges
+            .windows(2)
+            .find(|pair| pair[0].1.end > pair[1].1.start)
+        {
+            Some(pair) => Err(SliceError::OverlappingSegments {
+                first: pair[0].0.min(pair[1].0),
+                second: pair[0].0.max(pair[1].0),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Number of segments.
+    pub fn len(&self) -> usize {
+        self.descs.len()
+    }
+
+    /// `true` if there is no segment, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.descs.is_empty()
+    }
+
+    /// Description of the segment at index `index`.
+    pub fn desc(&self, index: usize) -> Option<&SegmentDesc> {
+        self.descs.get(index)
+    }
+
+    /// Iterate over the description of each segment.
+    pub fn iter(&self) -> std::slice::Iter<'_, SegmentDesc> {
+        self.descs.iter()
+    }
+
+    /// Create a slice over the elements of the segment at index `index`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if there is no segment at index `index`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::ElementMismatch`] if `T` doesn't have the element size,
+    /// or requires a stricter alignment than the element alignment.
+    pub fn typed<T: bytemuck::Pod>(&self, index: usize) -> Result<Slice<'a, T>, SliceError> {
+        let desc = self.descs[index];
+        if std::mem::size_of::<T>() != desc.elem_size || std::mem::align_of::<T>() > desc.elem_align
+        {
+            return Err(SliceError::ElementMismatch {
+                from: SEGMENT_NAME,
+                to: std::any::type_name::<T>(),
+            });
+        }
+        let bytes = self.bytes(index);
+        let inner = SliceBase::new_with_policy::<Strict>(
+            bytes.as_ptr_range(),
+            0,
+            desc.elem_size,
+            bytes.len(),
+            None,
+        )?;
+        Ok(Slice::from_base(inner))
+    }
+
+    /// Create a view over the bytes of each element of the segment at index `index`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if there is no segment at index `index`.
+    pub fn erased(&self, index: usize) -> ByteWindowSlice<'a> {
+        let size = self.descs[index].elem_size;
+        // Valid, since the window is the whole element, and the bytes are in bounds.
+        ByteWindowSlice::new(self.bytes(index), 0, size, size).unwrap()
+    }
+
+    /// Bytes of the segment at index `index`.
+    fn bytes(&self, index: usize) -> &'a [u8] {
+        &self.data[self.descs[index].byte_range().unwrap()]
+    }
+}
@@ -1,10 +1,18 @@
 use std::{marker::PhantomData, ptr::null};
 
+use crate::math::is_multiple_of;
+use crate::policy::{LayoutInfo, Strict, ValidationPolicy};
+use crate::SliceIterator;
+
 /// Slice error
 ///
 /// An error is raised during when creating a slice via [`crate::Slice::new`],
 /// or [`crate::SliceMut::new`].
+///
+/// New variants may be added in minor releases: matches need a wildcard arm, or
+/// can use [`SliceError::kind`] instead.
 #[derive(Copy, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum SliceError {
     /// Provided offset is out of bounds regarding the slice size.
     ///
@@ -60,6 +68,202 @@ pub enum SliceError {
         /// Byte offset
         offset: usize,
     },
+    /// Attribute window crosses the boundary of the backing element.
+    ///
+    /// Raised by typed constructors, such as [`crate::Slice::new`]. Use
+    /// [`crate::Slice::new_unchecked_layout`] to opt-out of this check.
+    ///
+    /// Also raised by [`crate::Slice::rebase`], if the attribute doesn't fit in
+    /// the stride, or in the attribute owned by a view sharing its elements with
+    /// other views, e.g., created by [`crate::split_attr_read_write`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust,should_panic
+    /// use pas::{Slice};
+    ///
+    /// let data: Vec<[u32; 3]> = vec!([0, 1, 2], [3, 4, 5]);
+    /// // Panics, since the attribute would read `[2, 3]`, straddling two elements.
+    /// let slice: Slice<[u32; 2]> = Slice::new(&data, 8);
+    /// ```
+    AttributeCrossesElementBoundary {
+        /// Type name of the attribute read by the slice
+        type_name: &'static str,
+        /// Attribute size, in **bytes**
+        attr: usize,
+        /// Byte offset
+        offset: usize,
+        /// Backing element size, in **bytes**
+        element: usize,
+    },
+    /// Stride isn't a multiple of the attribute alignment, leading to unaligned elements.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,should_panic
+    /// use pas::{Slice};
+    ///
+    /// let data: Vec<u8> = vec![0; 16];
+    /// // Panics, since the second element would be at byte offset 6.
+    /// let slice: Slice<u32> = Slice::raw(&data, 0, 6);
+    /// ```
+    UnalignedStride {
+        /// Type name of the attribute read by the slice
+        type_name: &'static str,
+        /// Slice stride, in **bytes**
+        stride: usize,
+    },
+    /// A size, offset, or stride exceeds `isize::MAX` bytes, or its computation overflows.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,should_panic
+    /// use pas::{Slice};
+    ///
+    /// let data: Vec<u32> = vec!(0_u32, 1, 2);
+    /// // Panics, since the stride in bytes overflows.
+    /// let slice: Slice<u32> = Slice::strided(&data, 0, usize::MAX / 2);
+    /// ```
+    Overflow,
+    /// An index is out of bounds, or doesn't fit in a `usize`.
+    ///
+    /// Raised by index-based operations, such as [`crate::SliceInfo::gather`],
+    /// and by offsets tables, such as [`crate::JaggedView::new`].
+    IndexOutOfBounds {
+        /// Position of the index in the index list
+        position: usize,
+        /// Index value
+        index: u64,
+        /// Number of elements in the slice
+        len: usize,
+    },
+    /// Element types are incompatible for a reinterpretation.
+    ///
+    /// Raised by [`crate::Slice::try_transmute_elem`] when both types
+    /// don't have the same size, or when the target type requires a stricter alignment.
+    /// Raised by [`crate::SliceMut::try_copy_from_slice`] when the source type is
+    /// bigger than the attribute.
+    ElementMismatch {
+        /// Type name of the attribute read by the slice
+        from: &'static str,
+        /// Type name of the requested attribute
+        to: &'static str,
+    },
+    /// Attributes don't have the same number of elements.
+    ///
+    /// Raised by [`crate::InterleaveBuilder::build`].
+    CountMismatch {
+        /// Index of the attribute, in registration order
+        index: usize,
+        /// Number of elements of the first attribute
+        expected: usize,
+        /// Number of elements of the attribute
+        count: usize,
+    },
+    /// No attribute was provided.
+    ///
+    /// Raised by [`crate::InterleaveBuilder::build`].
+    NoAttribute,
+    /// A slice, or a mask, doesn't have the expected length, e.g., the length of
+    /// a fixed-length view.
+    ///
+    /// Raised by [`crate::Slice::try_into_array`], [`crate::SliceMut::try_into_array`],
+    /// [`crate::OverlaySlice::materialize_into`], [`crate::SparseAttribute::materialize_into`],
+    /// [`crate::Transaction::set_bytes`], the [`crate::convert::kernels`],
+    /// [`crate::CopyBatch::execute`], and [`crate::BitMask::and`] and similar methods.
+    LengthMismatch {
+        /// Expected length, e.g., the length of the fixed-length view
+        expected: usize,
+        /// Number of elements of the slice
+        len: usize,
+    },
+    /// A slice has fewer elements than required.
+    ///
+    /// Raised by [`crate::or_broadcast`], [`crate::SliceMut::try_copy_from_slice`],
+    /// and, in a [`crate::SanitizeError`], [`crate::SliceMut::copy_from_slice_sanitized`].
+    TooShort {
+        /// Minimum number of elements
+        expected: usize,
+        /// Number of elements of the slice
+        len: usize,
+    },
+    /// An array view has a negative stride, i.e., goes backward in memory.
+    ///
+    /// Raised by the `ndarray` conversions, such as `Slice::from_array_view`.
+    NegativeStride {
+        /// Stride, in **elements**
+        stride: isize,
+    },
+    /// Rows of a 2D array view aren't made of the contiguous values of an element.
+    ///
+    /// Raised by the `ndarray` conversions, such as `Slice::from_array_view2`.
+    NonContiguousElement {
+        /// Number of columns of the view
+        columns: usize,
+        /// Column stride, in **elements**
+        stride: isize,
+    },
+    /// A written attribute overlaps with a read attribute, in the same element.
+    ///
+    /// Raised by [`crate::split_attr_read_write`] and [`crate::split_attrs_read_write`].
+    OverlappingAttributes {
+        /// Byte offset of the read attribute, in the element
+        read: usize,
+        /// Byte offset of the written attribute, in the element
+        write: usize,
+    },
+    /// An erased slice isn't tagged with the tag of the requested type.
+    ///
+    /// Raised by [`crate::ErasedSlice::downcast_tagged`].
+    TagMismatch {
+        /// Tag of the requested type
+        expected: crate::TypeTag,
+        /// Tag of the slice, `None` if untagged
+        found: Option<crate::TypeTag>,
+    },
+    /// A byte offset isn't the start of an element of the slice.
+    ///
+    /// Raised by [`crate::SliceInfo::index_at_byte_offset`] and
+    /// [`crate::SliceMut::write_at_byte_offset`].
+    NotElementStart {
+        /// Byte offset, from the start of the original buffer
+        offset: usize,
+        /// Byte offset of the first element, from the start of the original buffer
+        byte_offset: usize,
+        /// Slice stride, in **bytes**
+        stride: usize,
+        /// Number of elements of the slice
+        len: usize,
+    },
+    /// Two segments share a byte.
+    ///
+    /// Raised by [`crate::Segments::new`].
+    OverlappingSegments {
+        /// Index of the first segment
+        first: usize,
+        /// Index of the second segment
+        second: usize,
+    },
+    /// An offset of an offsets table is smaller than the previous one.
+    ///
+    /// Raised by [`crate::JaggedView::new`] and [`crate::JaggedViewMut::new`].
+    DecreasingOffset {
+        /// Position of the offset in the table
+        position: usize,
+        /// Offset at `position`
+        offset: usize,
+        /// Offset at `position - 1`
+        previous: usize,
+    },
+    /// Two copies of a batch write to the same byte.
+    ///
+    /// Raised by [`crate::CopyBatch::execute`] and [`crate::CopyBatch::execute_parallel`].
+    OverlappingCopies {
+        /// Index of the first copy, in insertion order
+        first: usize,
+        /// Index of the second copy, in insertion order
+        second: usize,
+    },
 }
 
 impl std::fmt::Debug for SliceError {
@@ -88,56 +292,252 @@ impl std::fmt::Debug for SliceError {
                 "Attribute '{:?}' isn't aligned to the byte offset {}",
                 type_name, offset
             ),
+            Self::AttributeCrossesElementBoundary {
+                type_name,
+                attr,
+                offset,
+                element,
+            } => write!(
+                f,
+                "Attribute '{:?}' with size {} bytes at byte offset {} crosses the boundary of elements with size {} bytes",
+                type_name, attr, offset, element
+            ),
+            Self::UnalignedStride { type_name, stride } => write!(
+                f,
+                "Attribute '{:?}' isn't aligned with a stride of {} bytes",
+                type_name, stride
+            ),
+            Self::Overflow => write!(f, "Layout exceeds the maximum size of `isize::MAX` bytes"),
+            Self::IndexOutOfBounds {
+                position,
+                index,
+                len,
+            } => write!(
+                f,
+                "Index {} at position {} is out of bounds for a slice with {} elements",
+                index, position, len
+            ),
+            Self::ElementMismatch { from, to } => write!(
+                f,
+                "Attribute '{:?}' can't be reinterpreted as '{:?}', size or alignment mismatch",
+                from, to
+            ),
+            Self::CountMismatch {
+                index,
+                expected,
+                count,
+            } => write!(
+                f,
+                "Attribute {} has {} elements, but {} were expected",
+                index, count, expected
+            ),
+            Self::NoAttribute => write!(f, "No attribute was provided"),
+            Self::LengthMismatch { expected, len } => write!(
+                f,
+                "Slice has {} elements, but exactly {} were expected",
+                len, expected
+            ),
+            Self::TooShort { expected, len } => write!(
+                f,
+                "Slice has {} elements, but at least {} were expected",
+                len, expected
+            ),
+            Self::NegativeStride { stride } => {
+                write!(f, "Stride {} is negative, and can't be sliced", stride)
+            }
+            Self::NonContiguousElement { columns, stride } => write!(
+                f,
+                "Rows with {} columns and a column stride of {} aren't contiguous elements",
+                columns, stride
+            ),
+            Self::OverlappingAttributes { read, write } => write!(
+                f,
+                "Written attribute at byte {} overlaps with read attribute at byte {}",
+                write, read
+            ),
+            Self::TagMismatch {
+                expected,
+                found: Some(found),
+            } => write!(
+                f,
+                "Slice is tagged {}, but {} was requested",
+                found, expected
+            ),
+            Self::TagMismatch {
+                expected,
+                found: None,
+            } => write!(f, "Slice is untagged, but {} was requested", expected),
+            Self::NotElementStart {
+                offset,
+                byte_offset,
+                stride,
+                len,
+            } => write!(
+                f,
+                "Byte offset {} isn't the start of any of the {} elements at byte offset {} with a stride of {} bytes",
+                offset, len, byte_offset, stride
+            ),
+            Self::OverlappingSegments { first, second } => {
+                write!(f, "Segments {} and {} overlap", first, second)
+            }
+            Self::DecreasingOffset {
+                position,
+                offset,
+                previous,
+            } => write!(
+                f,
+                "Offset {} at position {} is smaller than the previous offset {}",
+                offset, position, previous
+            ),
+            Self::OverlappingCopies { first, second } => {
+                write!(f, "Destinations of copies {} and {} overlap", first, second)
+            }
         }
     }
 }
 
-/// Slice base implementation.
+/// Slice base implementation, shared by [`crate::Slice`] and [`crate::SliceMut`].
 ///
-/// Do not use this type directly, instead:
-/// - Use the [`crate::slice_attr!`], [`crate::slice_attr_mut!`], [`crate::slice!`], or [`crate::slice_mut!`] macros
-/// - Use the [`crate::Slice`] or [`crate::SliceMut`] types
+/// This type is copyable and doesn't borrow the data, it must thus never be
+/// exposed outside of the crate. Use [`SliceInfo`] instead.
 #[derive(Clone, Copy)]
-pub struct SliceBase<Attr: Sized + 'static> {
+pub(crate) struct SliceBase<Attr: Sized + 'static> {
     /// Start pointer, pointing on the first byte of the slice.
     pub(crate) start: *const u8,
     /// End pointer, pointing one byte **after** the end of the slice.
     pub(crate) end: *const u8,
+    /// Parent pointer, pointing on the first byte of the original buffer.
+    pub(crate) parent: *const u8,
     /// Stride, in **bytes**
     stride: usize,
+    /// Bytes of each element owned by the slice, as the offset of the attribute
+    /// in this window, and the window size. `None` if the slice owns the whole
+    /// original buffer.
+    ///
+    /// Views created from a single borrow, e.g., by [`crate::split_attr_read_write`],
+    /// only own their attribute, such that none can be rebased over another one.
+    window: Option<(usize, usize)>,
     _phantom: PhantomData<Attr>,
 }
 
-impl<Attr: Sized> SliceBase<Attr> {
-    pub(crate) fn new_typed<V: Pod>(
-        data: &[V],
+/// Compile-time version of [`SliceBase::check_transmute`].
+///
+/// Evaluating [`Self::OK`] fails the build if `T` can't be reinterpreted as `U`.
+pub(crate) struct AssertTransmute<T, U>(PhantomData<(T, U)>);
+
+impl<T, U> AssertTransmute<T, U> {
+    pub(crate) const OK: () = assert!(
+        std::mem::size_of::<U>() == std::mem::size_of::<T>()
+            && std::mem::align_of::<U>() <= std::mem::align_of::<T>(),
+        "`U` must have the same size as `T`, and an alignment lower or equal to `T`"
+    );
+}
+
+impl<Attr: Sized + 'static> SliceBase<Attr> {
+    /// Create a slice over the elements in `ptr_range`.
+    ///
+    /// Mutable slices must pass a range derived from a mutable borrow, such
+    /// that writing through the slice is allowed.
+    #[inline]
+    pub(crate) fn new_typed<V: Pod, P: ValidationPolicy>(
+        ptr_range: std::ops::Range<*const V>,
         offset: usize,
         elt_count: usize,
     ) -> Result<Self, SliceError> {
-        let stride = std::mem::size_of::<V>() * elt_count;
-        let bytes = std::mem::size_of_val(data);
-        let ptr = data.as_ptr_range();
-        Self::new(
-            ptr.start as *const u8..ptr.end as *const u8,
+        let stride = std::mem::size_of::<V>()
+            .checked_mul(elt_count)
+            .ok_or(SliceError::Overflow)?;
+        let bytes = ptr_range.end as usize - ptr_range.start as usize;
+        // The attribute must fit in a single backing element (or stride window),
+        // otherwise it would read across two elements.
+        Self::new_with_policy::<P>(
+            ptr_range.start as *const u8..ptr_range.end as *const u8,
             offset,
             stride,
             bytes,
+            Some(stride),
         )
     }
 
+    /// Run the checks of the policy `P`, and create the slice.
+    ///
+    /// `element` is the size of the window an attribute must fit in, if any.
+    #[inline]
+    pub(crate) fn new_with_policy<P: ValidationPolicy>(
+        ptr_range: std::ops::Range<*const u8>,
+        offset: usize,
+        stride: usize,
+        bytes: usize,
+        element: Option<usize>,
+    ) -> Result<Self, SliceError> {
+        Self::check_overflow(bytes, stride)?;
+        let layout = LayoutInfo {
+            type_name: std::any::type_name::<Attr>(),
+            attr: std::mem::size_of::<Attr>(),
+            align: std::mem::align_of::<Attr>(),
+            size: bytes,
+            offset,
+            stride,
+            address: (ptr_range.start as usize).wrapping_add(offset),
+            element,
+        };
+        P::check_attribute_size(&layout)?;
+        P::check_offset_bounds(&layout)?;
+        P::check_alignment(&layout)?;
+        P::check_stride_alignment(&layout)?;
+        P::check_element_boundary(&layout)?;
+        Self::new(ptr_range, offset, stride, bytes)
+    }
+
+    #[inline]
     pub(crate) fn new(
         ptr_range: std::ops::Range<*const u8>,
         offset: usize,
         stride: usize,
         bytes: usize,
     ) -> Result<Self, SliceError> {
+        let count = Self::check_layout(bytes, offset, stride)?;
+        // Safe because the offset is in bounds, or `0` for empty slices.
         let ptr: *const u8 = unsafe { ptr_range.start.add(offset) };
+        let align = std::mem::align_of::<Attr>();
+        if ptr.align_offset(align) != 0 {
+            Err(SliceError::AlignmentFault {
+                type_name: std::any::type_name::<Attr>(),
+                offset,
+            })
+        } else if count > 1 && !is_multiple_of(stride, align) {
+            // Every element but the first one could be unaligned.
+            Err(SliceError::UnalignedStride {
+                type_name: std::any::type_name::<Attr>(),
+                stride,
+            })
+        } else {
+            Ok(Self {
+                start: ptr,
+                end: ptr_range.end,
+                parent: ptr_range.start,
+                stride,
+                window: None,
+                _phantom: PhantomData,
+            })
+        }
+    }
+
+    /// Validate a layout without any data, and return its number of elements.
+    #[inline]
+    pub(crate) fn check_layout(
+        bytes: usize,
+        offset: usize,
+        stride: usize,
+    ) -> Result<usize, SliceError> {
+        let attr = std::mem::size_of::<Attr>();
         // Empty slice are allowed, but we need to ensure that
         // the offset and stride are valid.
-        if std::mem::size_of::<Attr>() > stride {
+        Self::check_overflow(bytes, stride)?;
+        if attr > stride {
             Err(SliceError::AttributeLargerThanStride {
                 type_name: std::any::type_name::<Attr>(),
-                attr: std::mem::size_of::<Attr>(),
+                attr,
                 stride,
             })
         } else if offset > 0 && offset >= bytes {
@@ -145,21 +545,311 @@ impl<Attr: Sized> SliceBase<Attr> {
                 size: bytes,
                 offset,
             })
-        } else if ptr.align_offset(std::mem::align_of::<Attr>()) != 0 {
-            Err(SliceError::AlignmentFault {
-                type_name: std::any::type_name::<Attr>(),
-                offset,
-            })
         } else {
-            Ok(Self {
-                start: ptr,
-                end: ptr_range.end,
-                stride,
-                _phantom: PhantomData,
+            Ok(Self::count(bytes - offset, stride))
+        }
+    }
+
+    /// Sizes and strides are limited to `isize::MAX` bytes, like Rust allocations.
+    fn check_overflow(bytes: usize, stride: usize) -> Result<(), SliceError> {
+        if bytes > isize::MAX as usize || stride > isize::MAX as usize {
+            Err(SliceError::Overflow)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Number of attributes that entirely fit in `bytes`, spaced by `stride`.
+    #[inline]
+    fn count(bytes: usize, stride: usize) -> usize {
+        let attr = std::mem::size_of::<Attr>();
+        if bytes == 0 || bytes < attr {
+            0
+        } else {
+            // A zero stride yields no element, instead of dividing by zero.
+            (bytes - attr).checked_div(stride).map_or(0, |n| n + 1)
+        }
+    }
+
+    #[inline]
+    pub(crate) fn get(&self, index: usize) -> Option<&Attr> {
+        self.get_ptr(index)
+            .map(|ptr| unsafe { &*ptr.cast::<Attr>() })
+    }
+
+    /// Number of elements in the slice.
+    ///
+    /// Only elements whose attribute entirely fits in the slice are counted.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        // Saturating, since `end` is never before `start`.
+        let bytes = (self.end as usize).saturating_sub(self.start as usize);
+        Self::count(bytes, self.stride)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Split the slice into two at `mid`.
+    ///
+    /// The first slice contains elements `[0, mid)`, the second `[mid, len)`.
+    /// No element of the first slice overlaps with an element of the second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub(crate) fn split_at(&self, mid: usize) -> (Self, Self) {
+        let len = self.len();
+        assert!(
+            mid <= len,
+            "mid > len. Found mid {}, but slice has {} elements",
+            mid,
+            len
+        );
+        let middle = if mid == len {
+            self.end
+        } else {
+            unsafe { self.start.add(mid * self.stride) }
+        };
+        let left = Self {
+            start: self.start,
+            end: middle,
+            parent: self.parent,
+            stride: self.stride,
+            window: self.window,
+            _phantom: PhantomData,
+        };
+        let right = Self {
+            start: middle,
+            end: self.end,
+            parent: self.parent,
+            stride: self.stride,
+            window: self.window,
+            _phantom: PhantomData,
+        };
+        (left, right)
+    }
+
+    /// Reinterpret the slice attribute as `U`, keeping the same layout.
+    ///
+    /// This doesn't validate anything, callers must ensure `U` can be read
+    /// at each element pointer.
+    pub(crate) fn cast<U>(&self) -> SliceBase<U> {
+        SliceBase {
+            start: self.start,
+            end: self.end,
+            parent: self.parent,
+            stride: self.stride,
+            window: self.window,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Create a slice reading `U`, at byte offset `offset` of each element.
+    ///
+    /// The stride is unchanged. `U` must fit in the window owned by the slice,
+    /// if any, or in the stride otherwise.
+    pub(crate) fn rebase<U>(&self, offset: usize) -> Result<SliceBase<U>, SliceError> {
+        let attr = std::mem::size_of::<U>();
+        // Offset of `U` in the bytes owned by each element, and their size.
+        let (start, element) = match self.window {
+            Some((attr_offset, size)) => (attr_offset.checked_add(offset), size),
+            None => (Some(offset), self.stride),
+        };
+        match start.and_then(|start| start.checked_add(attr)) {
+            Some(end) if end <= element => SliceBase::new_with_policy::<Strict>(
+                self.parent..self.end,
+                self.byte_offset() + offset,
+                self.stride,
+                self.end as usize - self.parent as usize,
+                None,
+            )
+            .map(|base| SliceBase {
+                window: self.window.map(|(_, size)| (end - attr, size)),
+                ..base
+            }),
+            _ => Err(SliceError::AttributeCrossesElementBoundary {
+                type_name: std::any::type_name::<U>(),
+                attr,
+                offset: start.unwrap_or(offset),
+                element,
+            }),
+        }
+    }
+
+    /// Restrict the bytes owned by the slice to its attribute, in each element.
+    ///
+    /// Used by views sharing the elements of a single borrow, see [`Self::window`].
+    pub(crate) fn owning_attribute(self) -> Self {
+        Self {
+            window: Some((0, std::mem::size_of::<Attr>())),
+            ..self
+        }
+    }
+
+    /// Byte offset of the field selected by `field`, in `Attr`.
+    ///
+    /// `field` receives a pointer to an uninitialized `Attr`, and must only
+    /// compute the address of one of its fields, e.g., via [`std::ptr::addr_of`].
+    pub(crate) fn field_offset<U>(field: impl FnOnce(*const Attr) -> *const U) -> usize {
+        let value = std::mem::MaybeUninit::<Attr>::uninit();
+        let base = value.as_ptr();
+        // Wraps for pointers that don't belong to `value`, which is then
+        // rejected when validating the offset.
+        (field(base) as usize).wrapping_sub(base as usize)
+    }
+
+    /// Check that `Attr` can be reinterpreted as `U`.
+    ///
+    /// `U` must have the same size, and an alignment lower or equal to `Attr`.
+    pub(crate) fn check_transmute<U>() -> Result<(), SliceError> {
+        if std::mem::size_of::<U>() == std::mem::size_of::<Attr>()
+            && std::mem::align_of::<U>() <= std::mem::align_of::<Attr>()
+        {
+            Ok(())
+        } else {
+            Err(SliceError::ElementMismatch {
+                from: std::any::type_name::<Attr>(),
+                to: std::any::type_name::<U>(),
             })
         }
     }
 
+    /// Pointer one stride **after** the last element of the slice.
+    ///
+    /// Used by iterators as an exclusive end bound.
+    pub(crate) fn iter_end(&self) -> *const u8 {
+        self.start.wrapping_add(self.len() * self.stride)
+    }
+
+    /// Byte offset of the first element, from the start of the original buffer.
+    pub(crate) fn byte_offset(&self) -> usize {
+        self.start as usize - self.parent as usize
+    }
+
+    /// Restrict the slice to the byte range `region` of the original buffer.
+    ///
+    /// Elements that don't entirely fit in `region` are removed.
+    pub(crate) fn bounded(&self, region: std::ops::Range<usize>) -> Result<Self, SliceError> {
+        let start = self.byte_offset();
+        if start < region.start || start > region.end {
+            return Err(SliceError::OffsetOutOfBounds {
+                size: region.end,
+                offset: start,
+            });
+        }
+        let end = if region.end < self.end as usize - self.parent as usize {
+            // Safe because the new end is before the current one.
+            unsafe { self.parent.add(region.end) }
+        } else {
+            self.end
+        };
+        Ok(Self { end, ..*self })
+    }
+
+    /// Get a pointer to the element at index `index`
+    #[inline]
+    pub(crate) fn get_ptr(&self, index: usize) -> Option<*const u8> {
+        if index < self.len() {
+            // Safe because the element is in bounds.
+            Some(unsafe { self.start.add(self.stride * index) })
+        } else {
+            None
+        }
+    }
+
+    /// Slice stride, in **bytes**.
+    #[inline]
+    pub(crate) fn stride(&self) -> usize {
+        self.stride
+    }
+}
+
+/// Read-only information about a [`crate::Slice`] or [`crate::SliceMut`].
+///
+/// This is the [`std::ops::Deref`] target of both slice types. It can only be
+/// borrowed from a slice, and never outlives it. Contrary to the slices, it can't
+/// be copied, cloned, or constructed:
+///
+/// ```rust,compile_fail
+/// use pas::{SliceInfo, SliceMut};
+///
+/// let mut data = [0_u32, 1, 2];
+/// let slice: SliceMut<u32> = SliceMut::native(&mut data);
+/// // Doesn't compile, since `SliceInfo` isn't `Copy`.
+/// let info: SliceInfo<u32> = *slice;
+/// ```
+///
+/// ```rust,compile_fail
+/// use pas::{SliceInfo, SliceMut};
+///
+/// let mut data = [0_u32, 1, 2];
+/// let slice: SliceMut<u32> = SliceMut::native(&mut data);
+/// // Doesn't compile, since `SliceInfo` isn't `Clone`.
+/// let info: SliceInfo<u32> = (*slice).clone();
+/// ```
+///
+/// ```rust,compile_fail
+/// // Doesn't compile, since the copyable base type is private.
+/// let base: pas::SliceBase<u32> = Default::default();
+/// ```
+///
+/// ```rust,compile_fail
+/// use pas::SliceMut;
+///
+/// let mut data = [0_u32, 1, 2];
+/// let slice: SliceMut<u32> = SliceMut::native(&mut data);
+/// let info = &*slice;
+/// drop(slice);
+/// // Doesn't compile, since `info` borrows `slice`.
+/// println!("{}", info.len());
+/// ```
+#[repr(transparent)]
+pub struct SliceInfo<Attr: Sized + 'static> {
+    inner: SliceBase<Attr>,
+}
+
+impl<Attr: Sized + 'static> SliceInfo<Attr> {
+    /// Borrow a base as a [`SliceInfo`].
+    #[inline]
+    pub(crate) fn from_base(base: &SliceBase<Attr>) -> &Self {
+        // Safe because `SliceInfo` is a transparent wrapper around `SliceBase`.
+        unsafe { &*(base as *const SliceBase<Attr>).cast::<Self>() }
+    }
+
+    /// Borrow the underlying base.
+    pub(crate) fn base(&self) -> &SliceBase<Attr> {
+        &self.inner
+    }
+
+    /// Validate a layout without any data, and return its number of elements.
+    ///
+    /// - `bytes` is the size of the data, in **bytes**
+    /// - `offset` is the byte offset of the first element
+    /// - `stride` is the distance between two elements, in **bytes**
+    ///
+    /// This runs the same checks as the constructors, except for the alignment
+    /// check which depends on the data pointer. It's useful to validate untrusted layouts
+    /// before even loading data.
+    ///
+    /// Sizes, offsets, and strides are limited to `isize::MAX` bytes, like Rust allocations.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{SliceError, SliceInfo};
+    ///
+    /// assert_eq!(SliceInfo::<[f32; 3]>::check_layout(60, 0, 20), Ok(3));
+    /// assert_eq!(
+    ///     SliceInfo::<[f32; 3]>::check_layout(usize::MAX, 0, 20),
+    ///     Err(SliceError::Overflow)
+    /// );
+    /// ```
+    pub fn check_layout(bytes: usize, offset: usize, stride: usize) -> Result<usize, SliceError> {
+        SliceBase::<Attr>::check_layout(bytes, offset, stride)
+    }
+
     /// Get the reference at index.
     ///
     /// ## Example
@@ -172,17 +862,17 @@ impl<Attr: Sized> SliceBase<Attr> {
     /// println!("{}", slice[0]); // Prints `1`
     /// println!("{}", slice[3]); // Prints `3`
     /// ```
+    #[inline]
     pub fn get(&self, index: usize) -> Option<&Attr> {
-        self.get_ptr(index)
-            .map(|ptr| unsafe { &*ptr.cast::<Attr>() })
+        self.inner.get(index)
     }
 
     /// Number of elements in the slice.
+    ///
+    /// Only elements whose attribute entirely fits in the slice are counted.
+    #[inline]
     pub fn len(&self) -> usize {
-        (self.end as usize)
-            .checked_sub(self.start as usize)
-            .unwrap()
-            .div_ceil(self.stride)
+        self.inner.len()
     }
 
     /// `true` if the slice has size `0`, `false` otherwise
@@ -190,38 +880,186 @@ impl<Attr: Sized> SliceBase<Attr> {
         self.len() == 0
     }
 
-    /// Pointer to the first byte in the slice.
-    pub fn as_ptr(&self) -> *const u8 {
-        self.start
+    /// Slice stride.
+    ///
+    /// <div class="warning">The stride is not in **elements count**, but in **bytes**.</div>
+    #[inline]
+    pub fn stride(&self) -> usize {
+        self.inner.stride
     }
 
-    /// Get a pointer to the element at index `index`
-    pub(crate) fn get_ptr(&self, index: usize) -> Option<*const u8> {
-        if index < self.len() {
-            let start = self.stride * index;
-            Some(unsafe { self.start.add(start) })
-        } else {
-            None
+    /// Byte offset of the first element, from the start of the original buffer.
+    pub fn byte_offset(&self) -> usize {
+        self.inner.byte_offset()
+    }
+
+    /// Byte offset of the element at index `index`, from the start of the original buffer.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[0_u32, 1], [2, 3], [4, 5]];
+    /// let slice: Slice<u32> = Slice::new(&data, 4);
+    /// assert_eq!(slice.byte_offset_of(1), Some(12));
+    /// assert_eq!(slice.byte_offset_of(3), None);
+    /// ```
+    pub fn byte_offset_of(&self, index: usize) -> Option<usize> {
+        (index < self.len()).then(|| self.byte_offset() + index * self.stride())
+    }
+
+    /// Index of the element starting at byte `parent_byte_offset`, from the start
+    /// of the original buffer.
+    ///
+    /// This is the inverse of [`Self::byte_offset_of`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[0_u32, 1], [2, 3], [4, 5]];
+    /// let slice: Slice<u32> = Slice::new(&data, 4);
+    /// assert_eq!(slice.index_at_byte_offset(12), Ok(1));
+    /// // Start of an element of another attribute.
+    /// assert!(slice.index_at_byte_offset(8).is_err());
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::NotElementStart`] if the offset is before the first element,
+    /// after the last one, or within the stride but not at the start of an element.
+    pub fn index_at_byte_offset(&self, parent_byte_offset: usize) -> Result<usize, SliceError> {
+        let (byte_offset, stride, len) = (self.byte_offset(), self.stride(), self.len());
+        let index = match (parent_byte_offset.checked_sub(byte_offset), stride) {
+            (Some(0), _) => Some(0),
+            (Some(delta), stride) if stride > 0 && is_multiple_of(delta, stride) => {
+                Some(delta / stride)
+            }
+            _ => None,
+        };
+        match index {
+            Some(index) if index < len => Ok(index),
+            _ => Err(SliceError::NotElementStart {
+                offset: parent_byte_offset,
+                byte_offset,
+                stride,
+                len,
+            }),
         }
     }
 
-    /// Slice stride.
+    /// Byte range `[start, end)` of the element at index `index`, from the start
+    /// of the original buffer.
     ///
-    /// <div class="warning">The stride is not in **elements count**, but in **bytes**.</div>
-    pub fn stride(&self) -> usize {
-        self.stride
+    /// Returns `None` if `index` is out of bounds.
+    pub fn element_range(&self, index: usize) -> Option<std::ops::Range<usize>> {
+        self.byte_offset_of(index)
+            .map(|start| start..start + std::mem::size_of::<Attr>())
+    }
+
+    /// Iterate over the byte offset of each element, from the start of the original buffer.
+    pub fn iter_byte_offsets(&self) -> impl Iterator<Item = usize> {
+        let (offset, stride) = (self.byte_offset(), self.stride());
+        (0..self.len()).map(move |i| offset + i * stride)
+    }
+
+    /// `true` if every element is in the byte range `region` of the original buffer.
+    ///
+    /// Empty slices are within `region` if their start is.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[0_u32, 1], [2, 3], [4, 5]];
+    /// let slice: Slice<u32> = Slice::new(&data, 4);
+    /// assert!(slice.is_within(4..24));
+    /// assert!(!slice.is_within(4..20));
+    /// ```
+    pub fn is_within(&self, region: std::ops::Range<usize>) -> bool {
+        let start = self.byte_offset();
+        match self.len() {
+            0 => region.start <= start && start <= region.end,
+            len => {
+                let end = start + (len - 1) * self.stride() + std::mem::size_of::<Attr>();
+                region.start <= start && end <= region.end
+            }
+        }
     }
 }
 
-///
-/// Traits implementation
-///
+impl<Attr: Pod> SliceInfo<Attr> {
+    /// Iterate over the elements, by pointer increment.
+    ///
+    /// Used by bulk operations, to avoid a bounds check per element.
+    #[inline]
+    pub(crate) fn elements(&self) -> SliceIterator<'_, Attr> {
+        SliceIterator::from_base(&self.inner)
+    }
+
+    /// Call `f` on each element, in order.
+    ///
+    /// This is semantically identical to `iter().for_each(f)`, but runs
+    /// a counted loop, which is easier for the compiler to vectorize:
+    /// - Packed slices are visited as a std slice
+    /// - Strided slices are visited by pointer increment, unrolled four times
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[1.0_f32, 2.0], [3.0, 4.0], [5.0, 6.0]];
+    /// let slice: Slice<f32> = Slice::new(&data, 4);
+    ///
+    /// let mut sum = 0.0;
+    /// slice.for_each_fast(|v| sum += v);
+    /// assert_eq!(sum, 12.0);
+    /// ```
+    #[inline]
+    pub fn for_each_fast(&self, mut f: impl FnMut(&Attr)) {
+        let (len, stride) = (self.len(), self.stride());
+        if stride == std::mem::size_of::<Attr>() {
+            // Safe because the `len` elements are packed, aligned, and in bounds.
+            let elements = unsafe { std::slice::from_raw_parts(self.inner.start.cast(), len) };
+            elements.iter().for_each(f);
+            return;
+        }
+        let mut ptr = self.inner.start;
+        // Safe because exactly `len` elements are visited.
+        unsafe {
+            for _ in 0..len / 4 {
+                f(&*ptr.cast::<Attr>());
+                f(&*ptr.add(stride).cast::<Attr>());
+                f(&*ptr.add(2 * stride).cast::<Attr>());
+                f(&*ptr.add(3 * stride).cast::<Attr>());
+                // Wrapping, since the pointer past the last element can be out of bounds.
+                ptr = ptr.wrapping_add(4 * stride);
+            }
+            for _ in 0..len % 4 {
+                f(&*ptr.cast::<Attr>());
+                ptr = ptr.wrapping_add(stride);
+            }
+        }
+    }
+}
+
+//
+// Traits implementation
+//
 
 impl<Attr: Sized + 'static> Default for SliceBase<Attr> {
     fn default() -> Self {
         Self {
             start: null(),
             end: null(),
+            parent: null(),
+            window: None,
             stride: 0,
             _phantom: PhantomData,
         }
@@ -230,39 +1068,73 @@ impl<Attr: Sized + 'static> Default for SliceBase<Attr> {
 
 /// Implement [`Iterator`] and related traits for [`SliceIterator`]/[`SliceIteratorMut`].
 macro_rules! impl_iterator {
-    ($name: ident -> $elem: ty) => {
+    ($name: ident -> &'a T) => {
+        impl_iterator!(@impl $name, []);
+    };
+    ($name: ident -> &'a mut T) => {
+        impl_iterator!(@impl $name, [mut]);
+    };
+    (@impl $name: ident, [$($mut: tt)?]) => {
         impl<'a, T: Pod> Iterator for $name<'a, T> {
-            type Item = $elem;
+            type Item = &'a $($mut)? T;
 
-            fn next(&mut self) -> Option<$elem> {
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
                 // `end` is exclusive and points one byte after the end of the slice.
                 if self.start >= self.end {
                     return None;
                 }
                 unsafe {
-                    // Using `transmute` here because `self.start` is always a pointer.
-                    let ret = Some(std::mem::transmute::<_, $elem>(self.start));
-                    self.start = self.start.add(self.stride);
+                    let ret = Some(&$($mut)? *(self.start as *mut T));
+                    self.start = self.start.wrapping_add(self.stride);
                     ret
                 }
             }
 
-            fn nth(&mut self, i: usize) -> Option<$elem> {
-                self.start = unsafe { self.start.add(i * self.stride) };
-                if self.start >= self.end {
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let remaining = self.remaining();
+                (remaining, Some(remaining))
+            }
+
+            #[inline]
+            fn nth(&mut self, i: usize) -> Option<Self::Item> {
+                if i >= self.remaining() {
+                    self.start = self.end;
                     return None;
                 }
-                let ret = unsafe {
-                    // Using `transmute` here because `self.start` is always a pointer.
-                    Some(std::mem::transmute::<_, $elem>(self.start))
-                };
-                ret
+                // Skip `i` elements, and return the next one, such that the
+                // iterator continues after it.
+                self.start = self.start.wrapping_add(i * self.stride);
+                self.next()
+            }
+        }
+
+        impl<'a, T: Pod> ExactSizeIterator for $name<'a, T> {}
+
+        impl<'a, T: Pod> $name<'a, T> {
+            /// Number of elements left, as [`SliceBase::len`] for the remaining bytes.
+            ///
+            /// `end` is one stride after the last element, such that the distance
+            /// is a multiple of the stride. A zero stride never has an element.
+            #[inline]
+            fn remaining(&self) -> usize {
+                (self.end as usize)
+                    .saturating_sub(self.start as usize)
+                    .checked_div(self.stride)
+                    .unwrap_or(0)
             }
         }
 
         impl<'a, T: Pod + Debug> std::fmt::Debug for $name<'a, T> {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                f.debug_list().entries(self.into_iter()).finish()
+                // Remaining elements are only read, while the iterator is borrowed.
+                let (start, end, stride) = (self.start, self.end, self.stride.max(1));
+                let elements = (0..)
+                    .map(|i| start.wrapping_add(i * stride))
+                    .take_while(|ptr| *ptr < end)
+                    .map(|ptr| unsafe { &*ptr.cast::<T>() });
+                f.debug_list().entries(elements).finish()
             }
         }
     };
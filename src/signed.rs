@@ -0,0 +1,111 @@
+use bytemuck::Pod;
+use std::ops::{Bound, Range, RangeBounds};
+
+use crate::{Slice, SliceInfo, SliceMut};
+
+/// Index of the element at signed index `index`, in a slice of `len` elements.
+///
+/// Negative indices count from the end, `-1` being the last element.
+fn signed_index(index: isize, len: usize) -> Option<usize> {
+    if index >= 0 {
+        let index = index as usize;
+        (index < len).then_some(index)
+    } else {
+        len.checked_sub(index.unsigned_abs())
+    }
+}
+
+/// Range of elements selected by the signed `range`, in a slice of `len` elements.
+///
+/// See [`Slice::slice_signed`] for the normalization rules.
+fn signed_range(range: impl RangeBounds<isize>, len: usize) -> Range<usize> {
+    // Position of the bound, before clamping. Can't overflow for any `isize` and `usize`.
+    let position = |index: isize| match index {
+        0.. => index as i128,
+        _ => len as i128 + index as i128,
+    };
+    let clamp = |position: i128| position.clamp(0, len as i128) as usize;
+    let start = match range.start_bound() {
+        Bound::Included(index) => clamp(position(*index)),
+        Bound::Excluded(index) => clamp(position(*index) + 1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(index) => clamp(position(*index) + 1),
+        Bound::Excluded(index) => clamp(position(*index)),
+        Bound::Unbounded => len,
+    };
+    start..end.max(start)
+}
+
+impl<Attr: Pod> SliceInfo<Attr> {
+    /// Get the reference at signed index `index`.
+    ///
+    /// Negative indices count from the end: `-1` is the last element, and `-len`
+    /// the first one.
+    ///
+    /// Returns `None` if `index` is larger than, or equal to, the length, or if
+    /// `-index` is larger than the length. Empty slices always return `None`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [1_u32, 2, 3, 4];
+    /// let slice = Slice::native(&data);
+    /// assert_eq!(slice.get_signed(-1), Some(&4));
+    /// assert_eq!(slice.get_signed(-4), Some(&1));
+    /// assert_eq!(slice.get_signed(-5), None);
+    /// ```
+    pub fn get_signed(&self, index: isize) -> Option<&Attr> {
+        self.get(signed_index(index, self.len())?)
+    }
+}
+
+impl<'a, T: Pod> Slice<'a, T> {
+    /// Sub-slice of the elements in the signed `range`, following Python slicing.
+    ///
+    /// Each bound is normalized as follows:
+    /// * Negative bounds count from the end, i.e., `len` is added to them.
+    /// * Inclusive end bounds, and exclusive start bounds, are then moved by one.
+    /// * Bounds are then clamped to `[0, len]`.
+    ///
+    /// If the end is before the start, the sub-slice is empty. Out of bounds
+    /// ranges never panic.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [0_u32, 1, 2, 3, 4, 5];
+    /// let slice = Slice::native(&data);
+    /// assert_eq!(slice.slice_signed(-2..).iter().copied().collect::<Vec<_>>(), [4, 5]);
+    /// assert_eq!(slice.slice_signed(1..-3).iter().copied().collect::<Vec<_>>(), [1, 2]);
+    /// assert_eq!(slice.slice_signed(..=-2).len(), 5);
+    /// assert_eq!(slice.slice_signed(-100..2).len(), 2);
+    /// assert!(slice.slice_signed(4..-3).is_empty());
+    /// ```
+    pub fn slice_signed(&self, range: impl RangeBounds<isize>) -> Slice<'a, T> {
+        let range = signed_range(range, self.len());
+        let (_, right) = self.base().split_at(range.start);
+        let (window, _) = right.split_at(range.end - range.start);
+        Slice::from_base(window)
+    }
+}
+
+impl<'a, Attr: Pod> SliceMut<'a, Attr> {
+    /// Mutable version of [`SliceInfo::get_signed`].
+    pub fn get_signed_mut(&mut self, index: isize) -> Option<&mut Attr> {
+        self.get_mut(signed_index(index, self.len())?)
+    }
+
+    /// Mutable version of [`Slice::slice_signed`].
+    pub fn slice_signed_mut(&mut self, range: impl RangeBounds<isize>) -> SliceMut<'_, Attr> {
+        let range = signed_range(range, self.len());
+        let (_, right) = self.base().split_at(range.start);
+        let (window, _) = right.split_at(range.end - range.start);
+        SliceMut::from_base(window)
+    }
+}
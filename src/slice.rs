@@ -1,7 +1,9 @@
 use bytemuck::Pod;
 use std::{fmt::Debug, marker::PhantomData, ops::Deref};
 
-use crate::shared_impl::{impl_iterator, SliceBase};
+use crate::policy::{Permissive, Strict, ValidationPolicy};
+use crate::profiling::Instrumented;
+use crate::shared_impl::{impl_iterator, AssertTransmute, SliceBase, SliceError, SliceInfo};
 
 /// Immutable slice with custom stride and start byte offset.
 ///
@@ -18,6 +20,11 @@ use crate::shared_impl::{impl_iterator, SliceBase};
 /// # Important Notes
 ///
 /// - The struct transmust without checking endianness
+/// - The slice is `Copy`: cloning it copies the view, never the data. Copies
+///   borrow the same data, for the same lifetime `'a`
+/// - A slice can't be stored in the same struct as the data it borrows. Use
+///   [`crate::OwnedView`] to keep a buffer and its attribute layouts together,
+///   and create slices on demand
 #[derive(Clone, Copy)]
 pub struct Slice<'a, T: Pod> {
     inner: SliceBase<T>,
@@ -68,8 +75,59 @@ impl<'a, T: Pod> Slice<'a, T> {
     /// - The slice attribute size (`size_of(Attr)`) is bigger than the stride size
     /// - The `byte_offset` is out of the slice range
     /// - The slice with the `byte_offset` is unaligned to the attribute
+    /// - The attribute, starting at `byte_offset`, crosses the boundary of an element of `V`
     pub fn new<V: Pod>(data: &'a [V], byte_offset: usize) -> Self {
-        Self::strided(data, byte_offset, 1)
+        Self::try_new(data, byte_offset).unwrap()
+    }
+
+    /// Similar to [`Self::new`], but returns an error instead of panicking.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use pas::{Slice, SliceError};
+    ///
+    /// let data: [[u32; 3]; 2] = [[0, 1, 2], [3, 4, 5]];
+    /// let error = Slice::<u32>::try_new(&data, 24).unwrap_err();
+    /// assert_eq!(error, SliceError::OffsetOutOfBounds { size: 24, offset: 24 });
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// - [`SliceError::AttributeLargerThanStride`] if the attribute is bigger than `V`
+    /// - [`SliceError::OffsetOutOfBounds`] if `byte_offset` is out of the slice range
+    /// - [`SliceError::AlignmentFault`] if the attribute is unaligned at `byte_offset`
+    /// - [`SliceError::AttributeCrossesElementBoundary`] if the attribute,
+    ///   starting at `byte_offset`, crosses the boundary of an element of `V`
+    /// - [`SliceError::Overflow`] if the layout exceeds `isize::MAX` bytes
+    pub fn try_new<V: Pod>(data: &'a [V], byte_offset: usize) -> Result<Self, SliceError> {
+        Self::try_strided(data, byte_offset, 1)
+    }
+
+    /// Similar to [`Self::new`], but allows the attribute to straddle two elements of `V`.
+    ///
+    /// This is still safe: only the [`crate::SliceError::AttributeCrossesElementBoundary`]
+    /// check is skipped.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data: [[u32; 3]; 2] = [[0, 1, 2], [3, 4, 5]];
+    /// let slice: Slice<[u32; 2]> = Slice::new_unchecked_layout(&data, 8);
+    /// println!("{:?}", slice); // Prints `[[2, 3]]`
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics in a similar way to [`Self::new`], except for the element boundary check.
+    pub fn new_unchecked_layout<V: Pod>(data: &'a [V], byte_offset: usize) -> Self {
+        Self {
+            inner: SliceBase::new_typed::<_, Permissive>(data.as_ptr_range(), byte_offset, 1)
+                .unwrap(),
+            _phantom: PhantomData,
+        }
     }
 
     /// Similar to [`Self::new`], but allows to set a custom stride.
@@ -90,10 +148,48 @@ impl<'a, T: Pod> Slice<'a, T> {
     ///
     /// Panics in a similar way to [`Self::new`].
     pub fn strided<V: Pod>(data: &'a [V], byte_offset: usize, elt_stride: usize) -> Self {
-        Self {
-            inner: SliceBase::new_typed(data, byte_offset, elt_stride).unwrap(),
-            _phantom: PhantomData,
-        }
+        Self::try_strided(data, byte_offset, elt_stride).unwrap()
+    }
+
+    /// Similar to [`Self::strided`], but returns an error instead of panicking.
+    ///
+    /// ## Errors
+    ///
+    /// See [`Self::try_new`]. [`SliceError::UnalignedStride`] is also returned if
+    /// the stride isn't a multiple of the attribute alignment.
+    pub fn try_strided<V: Pod>(
+        data: &'a [V],
+        byte_offset: usize,
+        elt_stride: usize,
+    ) -> Result<Self, SliceError> {
+        Self::with_policy::<Strict>(data, byte_offset, elt_stride)
+    }
+
+    /// Similar to [`Self::strided`], but runs the checks of the policy `P`,
+    /// and returns an error instead of panicking.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use pas::{Permissive, Slice, SliceError, Strict};
+    ///
+    /// let data: [[u32; 3]; 2] = [[0, 1, 2], [3, 4, 5]];
+    /// let strict = Slice::<[u32; 2]>::with_policy::<Strict>(&data, 8, 1);
+    /// assert!(matches!(
+    ///     strict,
+    ///     Err(SliceError::AttributeCrossesElementBoundary { .. })
+    /// ));
+    ///
+    /// let permissive = Slice::<[u32; 2]>::with_policy::<Permissive>(&data, 8, 1).unwrap();
+    /// println!("{:?}", permissive); // Prints `[[2, 3]]`
+    /// ```
+    pub fn with_policy<P: ValidationPolicy>(
+        data: &'a [impl Pod],
+        byte_offset: usize,
+        elt_stride: usize,
+    ) -> Result<Self, SliceError> {
+        SliceBase::new_typed::<_, P>(data.as_ptr_range(), byte_offset, elt_stride)
+            .map(Self::from_base)
     }
 
     /// Create a strided slice starting at the byte offset `offset`.
@@ -108,17 +204,181 @@ impl<'a, T: Pod> Slice<'a, T> {
     ///
     /// Panics in a similar way to [`Self::new`].
     pub fn raw(data: &'a [u8], byte_offset: usize, byte_stride: usize) -> Self {
-        let inner =
-            SliceBase::new(data.as_ptr_range(), byte_offset, byte_stride, data.len()).unwrap();
+        Self::try_raw(data, byte_offset, byte_stride).unwrap()
+    }
+
+    /// Similar to [`Self::raw`], but returns an error instead of panicking,
+    /// e.g., for offsets and strides read from a file.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use pas::{Slice, SliceError};
+    ///
+    /// let data = [0_u8; 32];
+    /// // Stride given in components, instead of bytes.
+    /// let error = Slice::<[f32; 3]>::try_raw(&data, 0, 3).unwrap_err();
+    /// assert!(matches!(error, SliceError::AttributeLargerThanStride { attr: 12, stride: 3, .. }));
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// See [`Self::try_strided`]. Since elements are bytes,
+    /// [`SliceError::AttributeCrossesElementBoundary`] is never returned.
+    pub fn try_raw(
+        data: &'a [u8],
+        byte_offset: usize,
+        byte_stride: usize,
+    ) -> Result<Self, SliceError> {
+        SliceBase::new_with_policy::<Strict>(
+            data.as_ptr_range(),
+            byte_offset,
+            byte_stride,
+            data.len(),
+            None,
+        )
+        .map(Self::from_base)
+    }
+
+    /// Create a slice where the stride is the same as the attribute size.
+    pub fn native(data: &'a [T]) -> Self {
+        Self::new(data, 0)
+    }
+
+    /// Divide the slice into two at index `mid`.
+    ///
+    /// The first slice contains elements `[0, mid)`, the second `[mid, len)`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at(&self, mid: usize) -> (Slice<'a, T>, Slice<'a, T>) {
+        let (left, right) = self.inner.split_at(mid);
+        (Self::from_base(left), Self::from_base(right))
+    }
+
+    /// Create a slice reading `A`, at byte offset `offset` of each element.
+    ///
+    /// The stride is unchanged. This allows to derive a view on a field
+    /// from a view on whole structs, see [`crate::rebase!`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[0_u32, 1, 2], [3, 4, 5]];
+    /// let slice: Slice<[u32; 3]> = Slice::native(&data);
+    /// let last: Slice<u32> = slice.rebase(8).unwrap();
+    /// println!("{:?}", last); // Prints `[2, 5]`
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `A` doesn't fit in the stride at `offset`, or isn't aligned.
+    ///
+    /// Views sharing the elements of a buffer with other views, e.g., created by
+    /// [`crate::split_attr_read_write`], only own the bytes of their attribute:
+    /// `A` must fit in these bytes, such that it never reads another view.
+    pub fn rebase<A: Pod>(&self, offset: usize) -> Result<Slice<'a, A>, SliceError> {
+        self.inner.rebase(offset).map(Slice::from_base)
+    }
+
+    #[doc(hidden)]
+    /// Used by [`crate::rebase!`].
+    pub fn rebase_field<A: Pod>(
+        &self,
+        field: impl FnOnce(*const T) -> *const A,
+    ) -> Result<Slice<'a, A>, SliceError> {
+        self.rebase(SliceBase::<T>::field_offset(field))
+    }
+
+    /// Restrict the slice to the byte range `region` of the original buffer.
+    ///
+    /// This re-derives a valid view after the region owning the data logically
+    /// shrank, e.g., in a pool of sub-allocations. Only elements entirely
+    /// contained in `region` remain accessible.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[0_u32, 1], [2, 3], [4, 5]];
+    /// let slice: Slice<u32> = Slice::new(&data, 4);
+    /// let bounded = slice.bounded(0..16).unwrap();
+    /// println!("{:?}", bounded); // Prints `[1, 3]`
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::OffsetOutOfBounds`] if the start of the slice isn't in `region`.
+    pub fn bounded(self, region: std::ops::Range<usize>) -> Result<Self, SliceError> {
+        self.inner.bounded(region).map(Self::from_base)
+    }
+
+    /// Wrap the slice to count accesses under `label`, see [`crate::profiling`].
+    pub fn instrumented(self, label: &'static str) -> Instrumented<Self> {
+        Instrumented::new(self, label)
+    }
+
+    pub(crate) fn from_base(inner: SliceBase<T>) -> Self {
         Self {
             inner,
             _phantom: PhantomData,
         }
     }
 
-    /// Create a slice where the stride is the same as the attribute size.
-    pub fn native(data: &'a [T]) -> Self {
-        Self::new(data, 0)
+    /// Similar to [`SliceInfo::get`], but borrowing for the lifetime of the data.
+    pub(crate) fn get_ref(self, index: usize) -> Option<&'a T> {
+        // Safe because the data is borrowed for `'a`.
+        self.inner
+            .get_ptr(index)
+            .map(|ptr| unsafe { &*ptr.cast::<T>() })
+    }
+
+    /// Reinterpret the slice attribute as `U`.
+    ///
+    /// This is intended for `#[repr(transparent)]` newtypes, or for types
+    /// sharing the same memory representation.
+    ///
+    /// `U` must have the same size as `T`, and an alignment lower or equal to `T`.
+    /// This is checked at compile time.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// #[repr(transparent)]
+    /// #[derive(Clone, Copy, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+    /// struct Rgba([u8; 4]);
+    ///
+    /// let data = [[255_u8, 0, 0, 255], [0, 255, 0, 255]];
+    /// let slice: Slice<[u8; 4]> = Slice::native(&data);
+    /// let colors: Slice<Rgba> = slice.transmute_elem();
+    /// println!("{:?}", colors[1]); // Prints `Rgba([0, 255, 0, 255])`
+    /// ```
+    ///
+    /// Reinterpreting as a larger type doesn't compile:
+    ///
+    /// ```rust,compile_fail
+    /// use pas::Slice;
+    ///
+    /// let data = [0_u32, 1, 2, 3];
+    /// let slice: Slice<u32> = Slice::native(&data);
+    /// let _: Slice<u64> = slice.transmute_elem();
+    /// ```
+    pub fn transmute_elem<U: Pod>(self) -> Slice<'a, U> {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertTransmute::<T, U>::OK;
+        Slice::from_base(self.inner.cast())
+    }
+
+    /// Similar to [`Self::transmute_elem`], but checked at runtime.
+    pub fn try_transmute_elem<U: Pod>(self) -> Result<Slice<'a, U>, SliceError> {
+        SliceBase::<T>::check_transmute::<U>()?;
+        Ok(Slice::from_base(self.inner.cast()))
     }
 
     /// Create a [`SliceIterator`] for this slice.
@@ -137,15 +397,20 @@ impl<'a, T: Pod> Slice<'a, T> {
     }
 }
 
-///
-/// Traits implementation
-///
+//
+// Traits implementation
+//
+
+// Safe because a `Slice` behaves like a `&'a [T]`.
+unsafe impl<'a, T: Pod + Sync> Send for Slice<'a, T> {}
+unsafe impl<'a, T: Pod + Sync> Sync for Slice<'a, T> {}
 
 impl<'a, Attr: Pod> Deref for Slice<'a, Attr> {
-    type Target = SliceBase<Attr>;
+    type Target = SliceInfo<Attr>;
 
+    #[inline]
     fn deref(&self) -> &Self::Target {
-        &self.inner
+        SliceInfo::from_base(&self.inner)
     }
 }
 
@@ -155,11 +420,38 @@ where
 {
     type Output = T;
 
+    #[inline]
     fn index(&self, index: usize) -> &Self::Output {
         self.get(index).expect("index ouf of bounds")
     }
 }
 
+impl<'a, T: Pod> Slice<'a, [T; 1]> {
+    /// Convert a slice of single-element arrays into a slice of scalars.
+    pub fn into_scalar(self) -> Slice<'a, T> {
+        Slice::from_base(self.inner.cast())
+    }
+}
+
+impl<'a, T: Pod> Slice<'a, T> {
+    /// Convert a slice of scalars into a slice of single-element arrays.
+    pub fn into_array1(self) -> Slice<'a, [T; 1]> {
+        Slice::from_base(self.inner.cast())
+    }
+}
+
+impl<'a, T: Pod> From<Slice<'a, [T; 1]>> for Slice<'a, T> {
+    fn from(slice: Slice<'a, [T; 1]>) -> Self {
+        slice.into_scalar()
+    }
+}
+
+impl<'a, T: Pod> From<Slice<'a, T>> for Slice<'a, [T; 1]> {
+    fn from(slice: Slice<'a, T>) -> Self {
+        slice.into_array1()
+    }
+}
+
 impl<'a, Attr: Pod> From<&'a [Attr]> for Slice<'a, Attr> {
     fn from(item: &'a [Attr]) -> Self {
         Slice::native(item)
@@ -193,9 +485,9 @@ impl<'a, Attr: Pod> Default for Slice<'a, Attr> {
     }
 }
 
-///
-/// Iterator
-///
+//
+// Iterator
+//
 
 /// Iterator for the [`Slice`] type.
 #[derive(Clone, Copy)]
@@ -210,13 +502,28 @@ pub struct SliceIterator<'a, T: Pod> {
 
 impl<'a, T: Pod> SliceIterator<'a, T> {
     fn new(slice: &'a Slice<'a, T>) -> Self {
-        let data = slice.inner;
+        Self::from_base(&slice.inner)
+    }
+
+    /// Iterate over the elements of `data`, by pointer increment.
+    pub(crate) fn from_base(data: &'a SliceBase<T>) -> Self {
         Self {
             start: data.start,
-            end: data.end,
+            end: data.iter_end(),
             stride: data.stride(),
             _phantom_data: PhantomData,
         }
     }
+
+    /// Iterate over the packed elements of `data`.
+    pub(crate) fn packed(data: &'a [T]) -> Self {
+        let range = data.as_ptr_range();
+        Self {
+            start: range.start.cast(),
+            end: range.end.cast(),
+            stride: std::mem::size_of::<T>(),
+            _phantom_data: PhantomData,
+        }
+    }
 }
 impl_iterator!(SliceIterator -> &'a T);
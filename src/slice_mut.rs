@@ -1,7 +1,17 @@
 use bytemuck::Pod;
 use std::{fmt::Debug, marker::PhantomData, ops::Deref};
 
-use crate::shared_impl::{impl_iterator, SliceBase};
+use crate::math::div_ceil;
+use crate::policy::{Permissive, Strict, ValidationPolicy};
+use crate::profiling::Instrumented;
+use crate::shared_impl::{impl_iterator, AssertTransmute, SliceBase, SliceError, SliceInfo};
+use crate::Slice;
+
+/// Pointer range of `data`, keeping the write permission of the mutable borrow.
+pub(crate) fn mut_range<V>(data: &mut [V]) -> std::ops::Range<*const V> {
+    let ptr = data.as_mut_ptr_range();
+    ptr.start.cast_const()..ptr.end.cast_const()
+}
 
 /// Mutable slice
 ///
@@ -13,32 +23,85 @@ pub struct SliceMut<'a, Attr: Pod> {
 
 impl<'a, Attr: Pod + Debug> std::fmt::Debug for SliceMut<'a, Attr> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_list().entries(self.iter()).finish()
+        f.debug_list().entries(self.elements()).finish()
     }
 }
 
 impl<'a, Attr: Pod> SliceMut<'a, Attr> {
     /// Mutable version of [`crate::Slice::new()`].
     pub fn new<V: Pod>(data: &'a mut [V], byte_offset: usize) -> Self {
-        Self::strided(data, byte_offset, 1)
+        Self::try_new(data, byte_offset).unwrap()
     }
 
-    /// Mutable version of [`crate::Slice::strided()`].
-    pub fn strided<V: Pod>(data: &'a [V], byte_offset: usize, elt_stride: usize) -> Self {
+    /// Mutable version of [`crate::Slice::try_new()`].
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use pas::{SliceError, SliceMut};
+    ///
+    /// let mut data: [[u32; 3]; 2] = [[0, 1, 2], [3, 4, 5]];
+    /// let error = SliceMut::<u32>::try_new(&mut data, 24).unwrap_err();
+    /// assert_eq!(error, SliceError::OffsetOutOfBounds { size: 24, offset: 24 });
+    /// ```
+    pub fn try_new<V: Pod>(data: &'a mut [V], byte_offset: usize) -> Result<Self, SliceError> {
+        Self::try_strided(data, byte_offset, 1)
+    }
+
+    /// Mutable version of [`crate::Slice::new_unchecked_layout()`].
+    pub fn new_unchecked_layout<V: Pod>(data: &'a mut [V], byte_offset: usize) -> Self {
         Self {
-            inner: SliceBase::new_typed(data, byte_offset, elt_stride).unwrap(),
+            inner: SliceBase::new_typed::<_, Permissive>(mut_range(data), byte_offset, 1).unwrap(),
             _phantom: PhantomData,
         }
     }
 
+    /// Mutable version of [`crate::Slice::strided()`].
+    ///
+    /// Mutable slices can't be created from shared data:
+    ///
+    /// ```rust,compile_fail
+    /// use pas::SliceMut;
+    ///
+    /// let data = [0_u32, 1, 2, 3];
+    /// let slice: SliceMut<u32> = SliceMut::strided(&data, 0, 2);
+    /// ```
+    pub fn strided<V: Pod>(data: &'a mut [V], byte_offset: usize, elt_stride: usize) -> Self {
+        Self::try_strided(data, byte_offset, elt_stride).unwrap()
+    }
+
+    /// Mutable version of [`crate::Slice::try_strided()`].
+    pub fn try_strided<V: Pod>(
+        data: &'a mut [V],
+        byte_offset: usize,
+        elt_stride: usize,
+    ) -> Result<Self, SliceError> {
+        Self::with_policy::<Strict>(data, byte_offset, elt_stride)
+    }
+
+    /// Mutable version of [`crate::Slice::with_policy()`].
+    pub fn with_policy<P: ValidationPolicy>(
+        data: &'a mut [impl Pod],
+        byte_offset: usize,
+        elt_stride: usize,
+    ) -> Result<Self, SliceError> {
+        SliceBase::new_typed::<_, P>(mut_range(data), byte_offset, elt_stride).map(Self::from_base)
+    }
+
     /// Mutable version of [`crate::Slice::raw()`].
-    pub fn raw(data: &'a [u8], byte_offset: usize, byte_stride: usize) -> Self {
-        let inner =
-            SliceBase::new(data.as_ptr_range(), byte_offset, byte_stride, data.len()).unwrap();
-        Self {
-            inner,
-            _phantom: PhantomData,
-        }
+    pub fn raw(data: &'a mut [u8], byte_offset: usize, byte_stride: usize) -> Self {
+        Self::try_raw(data, byte_offset, byte_stride).unwrap()
+    }
+
+    /// Mutable version of [`crate::Slice::try_raw()`].
+    pub fn try_raw(
+        data: &'a mut [u8],
+        byte_offset: usize,
+        byte_stride: usize,
+    ) -> Result<Self, SliceError> {
+        let len = data.len();
+        SliceBase::new_with_policy::<Strict>(mut_range(data), byte_offset, byte_stride, len, None)
+            .map(Self::from_base)
     }
 
     /// Create a mutable slice where the stride is the same as the attribute size.
@@ -46,7 +109,8 @@ impl<'a, Attr: Pod> SliceMut<'a, Attr> {
         Self::new(data, 0)
     }
 
-    /// Mutable version of [`crate::SliceBase::get()`].
+    /// Mutable version of [`crate::SliceInfo::get()`].
+    #[inline]
     pub fn get_mut(&mut self, index: usize) -> Option<&mut Attr> {
         self.inner
             .get_ptr(index)
@@ -55,6 +119,35 @@ impl<'a, Attr: Pod> SliceMut<'a, Attr> {
             .map(|ptr| unsafe { &mut *ptr.cast::<Attr>().cast_mut() })
     }
 
+    /// Write `value` in the element starting at byte `parent_byte_offset`, from the start
+    /// of the original buffer.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::SliceMut;
+    ///
+    /// let mut data = [[0_u32, 1], [2, 3], [4, 5]];
+    /// let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 4);
+    /// slice.write_at_byte_offset(20, 50).unwrap();
+    /// assert!(slice.write_at_byte_offset(18, 50).is_err());
+    /// assert_eq!(data, [[0, 1], [2, 3], [4, 50]]);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// See [`crate::SliceInfo::index_at_byte_offset`].
+    pub fn write_at_byte_offset(
+        &mut self,
+        parent_byte_offset: usize,
+        value: Attr,
+    ) -> Result<(), SliceError> {
+        let index = self.index_at_byte_offset(parent_byte_offset)?;
+        // Index is validated, and thus in bounds.
+        *self.get_mut(index).unwrap() = value;
+        Ok(())
+    }
+
     /// Copies all elements from `src`` into `self``, using a memcpy.
     ///
     /// At the opposite of the std `copy_from_slice`:
@@ -67,7 +160,7 @@ impl<'a, Attr: Pod> SliceMut<'a, Attr> {
     /// use pas::SliceMut;
     ///
     /// let mut dest = [0_u32, 0, 0, 0];
-    /// let slice: SliceMut<u32> = SliceMut::new(&mut dest, 0);
+    /// let mut slice: SliceMut<u32> = SliceMut::new(&mut dest, 0);
     ///
     /// slice.copy_from_slice(&[1_u8, 2]);
     /// println!("{:?}", slice); // Prints `[1, 2]`
@@ -80,50 +173,328 @@ impl<'a, Attr: Pod> SliceMut<'a, Attr> {
     ///
     /// * Panics if the length of `src` is bigger than the length of `self`
     /// * Panics if the `src` inner format is bigger than the slice attribute format
-    pub fn copy_from_slice<V: Pod>(&self, src: &[V]) {
-        let other_stride = std::mem::size_of::<V>();
+    pub fn copy_from_slice<V: Pod>(&mut self, src: &[V]) {
         // @todo: Checking the size at compile time would be nice.
         assert!(
-            other_stride <= std::mem::size_of::<Attr>(),
+            std::mem::size_of::<V>() <= std::mem::size_of::<Attr>(),
             "`data` type is {} bytes, but slice format expected at most {} bytes",
             std::mem::size_of::<V>(),
             std::mem::size_of::<Attr>()
         );
-
-        let count = self.len();
-        let other_count = src.len();
         assert!(
-            other_count <= count,
+            src.len() <= self.len(),
             "`data` too large. Found slice with {} elements, but expected at most {}",
-            other_count,
-            count
+            src.len(),
+            self.len()
         );
+        self.copy_unchecked(src);
+    }
+
+    /// Similar to [`Self::copy_from_slice`], but returns an error instead of panicking.
+    ///
+    /// This method never panics, see [`crate::panic_free`].
+    ///
+    /// ## Errors
+    ///
+    /// * [`SliceError::ElementMismatch`] if the `src` inner format is bigger than
+    ///   the slice attribute format
+    /// * [`SliceError::TooShort`] if the length of `src` is bigger than the length of `self`
+    ///
+    /// Nothing is written on error.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use pas::{SliceError, SliceMut};
+    ///
+    /// let mut dest = [0_u32, 0];
+    /// let mut slice: SliceMut<u32> = SliceMut::new(&mut dest, 0);
+    ///
+    /// assert_eq!(slice.try_copy_from_slice(&[1_u16, 2]), Ok(()));
+    /// assert_eq!(
+    ///     slice.try_copy_from_slice(&[3_u32, 4, 5]),
+    ///     Err(SliceError::TooShort { expected: 3, len: 2 })
+    /// );
+    /// assert_eq!(dest, [1, 2]);
+    /// ```
+    pub fn try_copy_from_slice<V: Pod>(&mut self, src: &[V]) -> Result<(), SliceError> {
+        if std::mem::size_of::<V>() > std::mem::size_of::<Attr>() {
+            return Err(SliceError::ElementMismatch {
+                from: std::any::type_name::<V>(),
+                to: std::any::type_name::<Attr>(),
+            });
+        }
+        if src.len() > self.len() {
+            return Err(SliceError::TooShort {
+                expected: src.len(),
+                len: self.len(),
+            });
+        }
+        self.copy_unchecked(src);
+        Ok(())
+    }
+
+    /// Copy `src` into the first elements, once sizes and counts are validated.
+    fn copy_unchecked<V: Pod>(&mut self, src: &[V]) {
+        // Elements are reached by pointer increment.
+        let mut ptr = self.inner.start as *mut u8;
+        for value in src {
+            unsafe {
+                ptr.copy_from_nonoverlapping(
+                    bytemuck::bytes_of(value).as_ptr(),
+                    std::mem::size_of::<V>(),
+                );
+            }
+            // Wrapping, since the pointer past the last element can be out of bounds.
+            ptr = ptr.wrapping_add(self.stride());
+        }
+    }
+
+    /// Fill every element of the slice with `value`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use pas::SliceMut;
+    ///
+    /// let mut data = [[0_u32, 1], [2, 3]];
+    /// let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 4);
+    /// slice.fill(9);
+    /// assert_eq!(data, [[0, 9], [2, 9]]);
+    /// ```
+    pub fn fill(&mut self, value: Attr) {
+        self.write_elements(std::iter::repeat(value));
+    }
+
+    /// Write each element of `src` mapped through `f` into `self`.
+    ///
+    /// Only the common prefix of both slices is processed, i.e.,
+    /// `min(self.len(), src.len())` elements.
+    ///
+    /// Returns the number of elements written.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use pas::{Slice, SliceMut};
+    ///
+    /// let src = [1_u32, 2, 3];
+    /// let mut dest = [0.0_f32; 2];
+    /// let mut slice: SliceMut<f32> = SliceMut::new(&mut dest, 0);
+    /// let count = slice.write_mapped_from(&Slice::native(&src), |v| *v as f32 * 0.5);
+    /// assert_eq!(count, 2);
+    /// assert_eq!(dest, [0.5, 1.0]);
+    /// ```
+    pub fn write_mapped_from<V: Pod>(
+        &mut self,
+        src: &Slice<V>,
+        f: impl FnMut(&V) -> Attr,
+    ) -> usize {
+        self.write_elements(src.elements().map(f))
+    }
+
+    /// Mutable version of [`crate::SliceInfo::for_each_fast()`].
+    ///
+    /// This is semantically identical to `iter().for_each(f)`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::SliceMut;
+    ///
+    /// let mut data = [[1.0_f32, 2.0], [3.0, 4.0], [5.0, 6.0]];
+    /// let mut slice: SliceMut<f32> = SliceMut::new(&mut data, 4);
+    /// slice.for_each_fast_mut(|v| *v *= 2.0);
+    /// assert_eq!(data, [[1.0, 4.0], [3.0, 8.0], [5.0, 12.0]]);
+    /// ```
+    #[inline]
+    pub fn for_each_fast_mut(&mut self, mut f: impl FnMut(&mut Attr)) {
+        let (len, stride) = (self.len(), self.stride());
+        let start = self.inner.start as *mut u8;
+        if stride == std::mem::size_of::<Attr>() {
+            // Safe because the `len` elements are packed, aligned, in bounds,
+            // and mutably borrowed.
+            let elements = unsafe { std::slice::from_raw_parts_mut(start.cast(), len) };
+            elements.iter_mut().for_each(f);
+            return;
+        }
+        let mut ptr = start;
+        // Safe because exactly `len` elements are visited, and they never overlap.
+        unsafe {
+            for _ in 0..len / 4 {
+                f(&mut *ptr.cast::<Attr>());
+                f(&mut *ptr.add(stride).cast::<Attr>());
+                f(&mut *ptr.add(2 * stride).cast::<Attr>());
+                f(&mut *ptr.add(3 * stride).cast::<Attr>());
+                // Wrapping, since the pointer past the last element can be out of bounds.
+                ptr = ptr.wrapping_add(4 * stride);
+            }
+            for _ in 0..len % 4 {
+                f(&mut *ptr.cast::<Attr>());
+                ptr = ptr.wrapping_add(stride);
+            }
+        }
+    }
 
-        let bytes: &[u8] = bytemuck::cast_slice(src);
-        for i in 0..other_count {
-            let ptr = self.inner.get_ptr(i).unwrap() as *mut u8;
-            let other_ptr = unsafe { bytes.as_ptr().add(i * other_stride) };
+    /// Call `f` with each pair of consecutive elements, the previous one shared,
+    /// and the current one mutable, for indices `1..len`.
+    ///
+    /// Pairs are visited in order, so the previous element already holds the value
+    /// written by the last call, as in an in-place sweep. Slices with fewer than
+    /// two elements have no pair.
+    ///
+    /// This is a closure instead of an iterator on purpose: items of an [`Iterator`]
+    /// can all be kept alive at once, so the mutable element of a pair would alias
+    /// the shared element of the next pair. Here, both references only live for
+    /// one call, and never point to the same element, since elements can't be
+    /// larger than the stride.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::SliceMut;
+    ///
+    /// let mut data = [[1.0_f32, 0.0], [3.0, 0.0], [5.0, 0.0]];
+    /// let mut slice: SliceMut<f32> = SliceMut::new(&mut data, 0);
+    /// // Prefix sum.
+    /// slice.for_each_pair_mut(|previous, current| *current += *previous);
+    /// assert_eq!(data.map(|v| v[0]), [1.0, 4.0, 9.0]);
+    /// ```
+    pub fn for_each_pair_mut(&mut self, mut f: impl FnMut(&Attr, &mut Attr)) {
+        let stride = self.stride();
+        let mut ptr = self.inner.start as *mut u8;
+        for _ in 1..self.len() {
+            // Safe because both elements are in bounds, and don't overlap. The
+            // references are dropped before the next pair is created.
             unsafe {
-                ptr.copy_from_nonoverlapping(other_ptr, other_stride);
+                let current = ptr.add(stride);
+                f(&*ptr.cast::<Attr>(), &mut *current.cast::<Attr>());
+                ptr = current;
             }
         }
     }
 
+    /// Write `values` to consecutive elements, and return the number of elements written.
+    ///
+    /// Stops at the end of the slice, or once `values` is exhausted. Elements are
+    /// reached by pointer increment, without per-element bounds check.
+    #[inline]
+    pub(crate) fn write_elements(&mut self, values: impl IntoIterator<Item = Attr>) -> usize {
+        let mut ptr = self.inner.start as *mut u8;
+        let mut count = 0;
+        for value in values.into_iter().take(self.len()) {
+            // Safe because at most `len` elements are written.
+            unsafe { ptr.cast::<Attr>().write(value) };
+            // Wrapping, since the pointer past the last element can be out of bounds.
+            ptr = ptr.wrapping_add(self.stride());
+            count += 1;
+        }
+        count
+    }
+
+    /// Divide the mutable slice into two at index `mid`.
+    ///
+    /// The first slice contains elements `[0, mid)`, the second `[mid, len)`.
+    /// Both slices borrow `self` mutably, and never reference the same element.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at_mut(&mut self, mid: usize) -> (SliceMut<'_, Attr>, SliceMut<'_, Attr>) {
+        let (left, right) = self.inner.split_at(mid);
+        (SliceMut::from_base(left), SliceMut::from_base(right))
+    }
+
+    /// Split the slice into non-overlapping chunks of at most `chunk_elems` elements.
+    pub(crate) fn chunks_mut(&mut self, chunk_elems: usize) -> Vec<SliceMut<'_, Attr>> {
+        let mut chunks = Vec::with_capacity(div_ceil(self.len(), chunk_elems));
+        let mut rest = self.inner;
+        while !rest.is_empty() {
+            let (chunk, tail) = rest.split_at(chunk_elems.min(rest.len()));
+            chunks.push(SliceMut::from_base(chunk));
+            rest = tail;
+        }
+        chunks
+    }
+
+    /// Mutable version of [`crate::Slice::rebase()`].
+    pub fn rebase<A: Pod>(self, offset: usize) -> Result<SliceMut<'a, A>, SliceError> {
+        self.inner.rebase(offset).map(SliceMut::from_base)
+    }
+
+    #[doc(hidden)]
+    /// Used by [`crate::rebase!`].
+    pub fn rebase_field<A: Pod>(
+        self,
+        field: impl FnOnce(*const Attr) -> *const A,
+    ) -> Result<SliceMut<'a, A>, SliceError> {
+        self.rebase(SliceBase::<Attr>::field_offset(field))
+    }
+
+    /// Mutable version of [`crate::Slice::bounded()`].
+    pub fn bounded(self, region: std::ops::Range<usize>) -> Result<Self, SliceError> {
+        self.inner.bounded(region).map(Self::from_base)
+    }
+
+    /// Mutable version of [`crate::Slice::instrumented()`].
+    pub fn instrumented(self, label: &'static str) -> Instrumented<Self> {
+        Instrumented::new(self, label)
+    }
+
+    pub(crate) fn from_base(inner: SliceBase<Attr>) -> Self {
+        Self {
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Mutable version of [`crate::Slice::transmute_elem()`].
+    pub fn transmute_elem<U: Pod>(self) -> SliceMut<'a, U> {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertTransmute::<Attr, U>::OK;
+        SliceMut::from_base(self.inner.cast())
+    }
+
+    /// Mutable version of [`crate::Slice::try_transmute_elem()`].
+    pub fn try_transmute_elem<U: Pod>(self) -> Result<SliceMut<'a, U>, SliceError> {
+        SliceBase::<Attr>::check_transmute::<U>()?;
+        Ok(SliceMut::from_base(self.inner.cast()))
+    }
+
     /// Create a [`SliceMutIterator`] for this slice.
-    pub fn iter(&'a self) -> SliceMutIterator<'a, Attr> {
+    ///
+    /// The slice stays mutably borrowed while the iterator is alive, so
+    /// two iterators can't yield the same element:
+    ///
+    /// ```rust,compile_fail,E0499
+    /// use pas::SliceMut;
+    ///
+    /// let mut data = [0_u32, 1, 2, 3];
+    /// let mut slice: SliceMut<u32> = SliceMut::native(&mut data);
+    /// let mut a = slice.iter();
+    /// let mut b = slice.iter();
+    /// let _ = (a.next(), b.next());
+    /// ```
+    pub fn iter(&mut self) -> SliceMutIterator<'_, Attr> {
         SliceMutIterator::new(self)
     }
 }
 
-///
-/// Traits implementation
-///
+//
+// Traits implementation
+//
+
+// Safe because a `SliceMut` behaves like a `&'a mut [T]`.
+//
+// `Sync` isn't implemented, since some methods write through `&self`.
+unsafe impl<'a, T: Pod + Send> Send for SliceMut<'a, T> {}
 
 impl<'a, Attr: Pod> Deref for SliceMut<'a, Attr> {
-    type Target = SliceBase<Attr>;
+    type Target = SliceInfo<Attr>;
 
+    #[inline]
     fn deref(&self) -> &Self::Target {
-        &self.inner
+        SliceInfo::from_base(&self.inner)
     }
 }
 
@@ -133,6 +504,7 @@ where
 {
     type Output = T;
 
+    #[inline]
     fn index(&self, index: usize) -> &Self::Output {
         self.get(index).expect("index ouf of bounds")
     }
@@ -142,11 +514,195 @@ impl<'a, T> std::ops::IndexMut<usize> for SliceMut<'a, T>
 where
     T: Pod,
 {
+    #[inline]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         self.get_mut(index).expect("index ouf of bounds")
     }
 }
 
+impl<'a, T: Pod> SliceMut<'a, [T; 1]> {
+    /// Mutable version of [`crate::Slice::into_scalar()`].
+    pub fn into_scalar(self) -> SliceMut<'a, T> {
+        SliceMut::from_base(self.inner.cast())
+    }
+}
+
+impl<'a, T: Pod> SliceMut<'a, T> {
+    /// Mutable version of [`crate::Slice::into_array1()`].
+    pub fn into_array1(self) -> SliceMut<'a, [T; 1]> {
+        SliceMut::from_base(self.inner.cast())
+    }
+}
+
+/// Compile-time check of the const generics of [`SliceMut::write_extended_from`].
+///
+/// Evaluating [`Self::OK`] fails the build if `M` isn't smaller than `N`.
+struct AssertSmaller<const M: usize, const N: usize>;
+
+impl<const M: usize, const N: usize> AssertSmaller<M, N> {
+    const OK: () = assert!(M < N, "`M` must be smaller than `N`");
+}
+
+impl<'a, T: Pod, const N: usize> SliceMut<'a, [T; N]> {
+    /// Write each element of `src` into the first `M` lanes of `self`,
+    /// and set the remaining `N - M` lanes to `fill`.
+    ///
+    /// Only the common prefix of both slices is processed, i.e.,
+    /// `min(self.len(), src.len())` elements.
+    ///
+    /// Returns the number of elements written.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use pas::{Slice, SliceMut};
+    ///
+    /// let positions = [[1.0_f32, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    /// let mut dest = [[0.0_f32; 4]; 2];
+    /// let mut slice: SliceMut<[f32; 4]> = SliceMut::new(&mut dest, 0);
+    /// slice.write_extended_from(&Slice::native(&positions), 1.0);
+    /// assert_eq!(dest, [[1.0, 2.0, 3.0, 1.0], [4.0, 5.0, 6.0, 1.0]]);
+    /// ```
+    ///
+    /// `M` must be strictly smaller than `N`:
+    ///
+    /// ```rust,compile_fail
+    /// use pas::{Slice, SliceMut};
+    ///
+    /// let src = [[1.0_f32; 4]];
+    /// let mut dest = [[0.0_f32; 4]];
+    /// let mut slice: SliceMut<[f32; 4]> = SliceMut::new(&mut dest, 0);
+    /// slice.write_extended_from(&Slice::native(&src), 1.0);
+    /// ```
+    pub fn write_extended_from<const M: usize>(&mut self, src: &Slice<[T; M]>, fill: T) -> usize {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertSmaller::<M, N>::OK;
+        self.write_mapped_from(src, |v| {
+            let mut out = [fill; N];
+            out[..M].copy_from_slice(v);
+            out
+        })
+    }
+
+    /// Copy the lanes enabled in `lane_mask` of each element of `src` into `self`.
+    ///
+    /// Disabled lanes, and bytes between elements, are left untouched. Only the
+    /// common prefix of both slices is processed, i.e., `min(self.len(), src.len())`
+    /// elements.
+    ///
+    /// Returns the number of elements written.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use pas::{Slice, SliceMut};
+    ///
+    /// let moved = [[1.0_f32, 9.0, 2.0], [3.0, 9.0, 4.0]];
+    /// let mut positions = [[0.0_f32, 5.0, 0.0], [0.0, 6.0, 0.0]];
+    /// let mut slice = SliceMut::native(&mut positions);
+    /// // Keep the heights.
+    /// slice.write_lanes_from(&Slice::native(&moved), [true, false, true]);
+    /// assert_eq!(positions, [[1.0, 5.0, 2.0], [3.0, 6.0, 4.0]]);
+    /// ```
+    pub fn write_lanes_from(&mut self, src: &Slice<[T; N]>, lane_mask: [bool; N]) -> usize {
+        let len = self.len().min(src.len());
+        let mut values = src.elements();
+        self.split_at_mut(len).0.for_each_fast_mut(|element| {
+            if let Some(value) = values.next() {
+                copy_lanes(element, value, &lane_mask);
+            }
+        });
+        len
+    }
+
+    /// Write each element of `src` into the lane `lane` of `self`, leaving other
+    /// lanes untouched.
+    ///
+    /// Only the common prefix of both slices is processed, i.e.,
+    /// `min(self.len(), src.len())` elements.
+    ///
+    /// Returns the number of elements written.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use pas::{Slice, SliceMut};
+    ///
+    /// let heights = [5.0_f32, 6.0];
+    /// let mut positions = [[1.0_f32, 0.0, 2.0], [3.0, 0.0, 4.0]];
+    /// SliceMut::native(&mut positions).write_lane(1, &Slice::native(&heights));
+    /// assert_eq!(positions, [[1.0, 5.0, 2.0], [3.0, 6.0, 4.0]]);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `lane` isn't smaller than `N`.
+    pub fn write_lane(&mut self, lane: usize, src: &Slice<T>) -> usize {
+        assert!(
+            lane < N,
+            "lane {} out of bounds, arrays have {} lanes",
+            lane,
+            N
+        );
+        let len = self.len().min(src.len());
+        let mut values = src.elements();
+        self.split_at_mut(len).0.for_each_fast_mut(|element| {
+            if let Some(value) = values.next() {
+                element[lane] = *value;
+            }
+        });
+        len
+    }
+
+    /// Call `f` with the index and a copy of each element, and write back the
+    /// lanes enabled in `lane_mask`.
+    ///
+    /// Disabled lanes are left untouched, even if `f` modifies them.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use pas::SliceMut;
+    ///
+    /// let mut positions = [[1.0_f32, 5.0, 2.0], [3.0, 6.0, 4.0]];
+    /// SliceMut::native(&mut positions).update_lanes([true, false, true], |i, p| {
+    ///     *p = [p[0] * 2.0, 0.0, i as f32];
+    /// });
+    /// assert_eq!(positions, [[2.0, 5.0, 0.0], [6.0, 6.0, 1.0]]);
+    /// ```
+    pub fn update_lanes(&mut self, lane_mask: [bool; N], mut f: impl FnMut(usize, &mut [T; N])) {
+        let mut index = 0;
+        self.for_each_fast_mut(|element| {
+            let mut value = *element;
+            f(index, &mut value);
+            copy_lanes(element, &value, &lane_mask);
+            index += 1;
+        });
+    }
+}
+
+/// Copy the lanes of `src` enabled in `mask` into `dst`.
+#[inline]
+fn copy_lanes<T: Copy, const N: usize>(dst: &mut [T; N], src: &[T; N], mask: &[bool; N]) {
+    for ((dst, src), enabled) in dst.iter_mut().zip(src).zip(mask) {
+        if *enabled {
+            *dst = *src;
+        }
+    }
+}
+
+impl<'a, T: Pod> From<SliceMut<'a, [T; 1]>> for SliceMut<'a, T> {
+    fn from(slice: SliceMut<'a, [T; 1]>) -> Self {
+        slice.into_scalar()
+    }
+}
+
+impl<'a, T: Pod> From<SliceMut<'a, T>> for SliceMut<'a, [T; 1]> {
+    fn from(slice: SliceMut<'a, T>) -> Self {
+        slice.into_array1()
+    }
+}
+
 impl<'a, Attr: Pod> From<&'a mut [Attr]> for SliceMut<'a, Attr> {
     fn from(item: &'a mut [Attr]) -> Self {
         SliceMut::native(item)
@@ -162,12 +718,13 @@ impl<'a, Attr: Pod> Default for SliceMut<'a, Attr> {
     }
 }
 
-///
-/// Iterator
-///
+//
+// Iterator
+//
 
 /// Iterator for the [`SliceMut`] type.
-#[derive(Clone, Copy)]
+///
+/// Unlike [`crate::SliceIterator`], this iterator isn't `Clone`, since it yields mutable references.
 pub struct SliceMutIterator<'a, T: Pod> {
     start: *const u8,
     end: *const u8,
@@ -176,11 +733,11 @@ pub struct SliceMutIterator<'a, T: Pod> {
 }
 
 impl<'a, T: Pod> SliceMutIterator<'a, T> {
-    fn new(slice: &'a SliceMut<'a, T>) -> Self {
+    fn new(slice: &'a mut SliceMut<'_, T>) -> Self {
         let data = slice.inner;
         Self {
             start: data.start,
-            end: data.end,
+            end: data.iter_end(),
             stride: data.stride(),
             _phantom_data: PhantomData,
         }
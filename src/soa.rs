@@ -0,0 +1,118 @@
+use bytemuck::Pod;
+
+use crate::gather::check_indices;
+use crate::{private, IndexElement, Slice, SliceError, SliceMut};
+
+/// Slices joined by index, where each record holds the elements at the same index.
+///
+/// Created with [`soa`].
+#[derive(Clone, Copy)]
+pub struct Soa<L> {
+    lanes: L,
+    len: usize,
+}
+
+/// Tuple of one to four slices, joined with [`soa`].
+///
+/// This trait is sealed and can't be implemented outside of this crate.
+pub trait SoaLanes: private::Sealed {
+    /// Length of the shortest slice.
+    fn common_len(&self) -> usize;
+}
+
+/// Join up to four slices, of possibly different types and buffers, by index.
+///
+/// Records span the common length, i.e., the length of the shortest slice.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{soa, Slice};
+///
+/// let positions = [[0.0_f32, 1.0], [2.0, 3.0]];
+/// let ids = [7_u32, 8, 9];
+/// let records = soa((Slice::native(&positions), Slice::native(&ids)));
+/// assert_eq!(records.len(), 2);
+/// assert_eq!(records.get(1), Some((&[2.0, 3.0], &8)));
+/// ```
+pub fn soa<L: SoaLanes>(lanes: L) -> Soa<L> {
+    let len = lanes.common_len();
+    Soa { lanes, len }
+}
+
+impl<L> Soa<L> {
+    /// Number of records.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// `true` if there is no record, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Joined slices.
+    pub fn lanes(&self) -> &L {
+        &self.lanes
+    }
+}
+
+macro_rules! impl_soa {
+    ($($t: ident $src: ident $dst: ident),+) => {
+        impl<'a, $($t: Pod),+> private::Sealed for ($(Slice<'a, $t>,)+) {}
+
+        impl<'a, $($t: Pod),+> SoaLanes for ($(Slice<'a, $t>,)+) {
+            fn common_len(&self) -> usize {
+                let ($($src,)+) = self;
+                usize::MAX$(.min($src.len()))+
+            }
+        }
+
+        impl<'a, $($t: Pod),+> Soa<($(Slice<'a, $t>,)+)> {
+            /// Get the record at index `index`.
+            pub fn get(&self, index: usize) -> Option<($(&'a $t,)+)> {
+                if index >= self.len {
+                    return None;
+                }
+                let ($($src,)+) = self.lanes;
+                Some(($($src.get_ref(index).unwrap(),)+))
+            }
+
+            /// Iterate over the records.
+            pub fn iter(&self) -> impl ExactSizeIterator<Item = ($(&'a $t,)+)> {
+                let ($($src,)+) = self.lanes;
+                (0..self.len).map(move |index| ($($src.get_ref(index).unwrap(),)+))
+            }
+
+            /// Copy the records at `indices` into `dst`, lane by lane.
+            ///
+            /// Indices are validated once, against the number of records, for all lanes.
+            /// Only the common prefix of `indices` and every destination is processed.
+            ///
+            /// Returns the number of records written.
+            ///
+            /// ## Errors
+            ///
+            /// Returns [`SliceError::IndexOutOfBounds`] for the first out-of-bounds index,
+            /// without writing anything.
+            pub fn gather_records_into<I: IndexElement>(
+                &self,
+                indices: &[I],
+                dst: ($(&mut SliceMut<'_, $t>,)+),
+            ) -> Result<usize, SliceError> {
+                let ($($dst,)+) = dst;
+                let count = indices.len()$(.min($dst.len()))+;
+                let indices = &indices[..count];
+                check_indices(indices, self.len)?;
+                let ($($src,)+) = self.lanes;
+                $($dst.write_elements(indices.iter().map(|index| $src.get_checked(*index)));)+
+                Ok(count)
+            }
+        }
+    };
+}
+
+impl_soa!(A a dst_a);
+impl_soa!(A a dst_a, B b dst_b);
+impl_soa!(A a dst_a, B b dst_b, C c dst_c);
+impl_soa!(A a dst_a, B b dst_b, C c dst_c, D d dst_d);
@@ -0,0 +1,231 @@
+use bytemuck::Pod;
+use std::fmt::Debug;
+
+use crate::{Float, FloatLanes, Slice, SliceError, SliceMut};
+
+/// Attribute of `len` elements, storing only the elements that differ from a
+/// default value.
+///
+/// Explicit entries are stored sorted by index, such that attributes set on a
+/// handful of elements don't need a dense buffer until exported with
+/// [`Self::materialize_into`] or [`Self::materialize_to_vec`].
+///
+/// Since elements come from either the default or the entries, they are returned
+/// by value.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::SparseAttribute;
+///
+/// let mut selection = SparseAttribute::new(1_000_000, 0.0_f32);
+/// selection.set(42, 1.0).unwrap();
+///
+/// assert_eq!(selection.get(41), Some(0.0));
+/// assert_eq!(selection.get(42), Some(1.0));
+/// assert_eq!(selection.entries().len(), 1);
+/// ```
+#[derive(Clone)]
+pub struct SparseAttribute<T: Pod> {
+    len: usize,
+    default: T,
+    entries: Vec<(usize, T)>,
+}
+
+impl<T: Pod> SparseAttribute<T> {
+    /// Create an attribute of `len` elements, all equal to `default`.
+    pub fn new(len: usize, default: T) -> Self {
+        Self {
+            len,
+            default,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Create an attribute from the elements of `src`, with entries for the elements
+    /// whose bytes differ from the bytes of `default`.
+    pub fn from_dense(src: &Slice<T>, default: T) -> Self {
+        let default_bytes = bytemuck::bytes_of(&default);
+        Self::from_dense_by(src, default, |value| {
+            bytemuck::bytes_of(value) != default_bytes
+        })
+    }
+
+    fn from_dense_by(src: &Slice<T>, default: T, differs: impl Fn(&T) -> bool) -> Self {
+        let entries = src
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| differs(value))
+            .map(|(index, value)| (index, *value))
+            .collect();
+        Self {
+            len: src.len(),
+            default,
+            entries,
+        }
+    }
+
+    /// Number of elements, including the default ones.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// `true` if the attribute has no element, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Value of elements without an explicit entry.
+    pub fn default_value(&self) -> T {
+        self.default
+    }
+
+    /// Change the number of elements to `len`.
+    ///
+    /// Entries past `len` are removed, and new elements are set to the default value.
+    pub fn resize(&mut self, len: usize) {
+        let kept = self.find(len).unwrap_or_else(|position| position);
+        self.entries.truncate(kept);
+        self.len = len;
+    }
+
+    /// Get the element at `index`, from the entries if any, or the default value.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<T> {
+        match self.find(index) {
+            Ok(position) => Some(self.entries[position].1),
+            Err(_) => (index < self.len).then_some(self.default),
+        }
+    }
+
+    /// Set the element at `index` to `value`, with an explicit entry.
+    ///
+    /// Returns the previous entry of this element, if any.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::IndexOutOfBounds`] if `index` is out of bounds,
+    /// with a position of `0`.
+    pub fn set(&mut self, index: usize, value: T) -> Result<Option<T>, SliceError> {
+        if index >= self.len {
+            return Err(SliceError::IndexOutOfBounds {
+                position: 0,
+                index: index as u64,
+                len: self.len,
+            });
+        }
+        Ok(match self.find(index) {
+            Ok(position) => Some(std::mem::replace(&mut self.entries[position].1, value)),
+            Err(position) => {
+                self.entries.insert(position, (index, value));
+                None
+            }
+        })
+    }
+
+    /// Remove the entry of the element at `index`, resetting it to the default value.
+    ///
+    /// Returns the removed entry, if any.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        self.find(index)
+            .ok()
+            .map(|position| self.entries.remove(position).1)
+    }
+
+    /// Iterate over the index and the value of each explicit entry, in increasing
+    /// index order.
+    pub fn entries(&self) -> impl ExactSizeIterator<Item = (usize, &T)> {
+        self.entries.iter().map(|(index, value)| (*index, value))
+    }
+
+    /// Ratio of elements with an explicit entry, between `0` and `1`.
+    ///
+    /// An empty attribute has a density of `0`.
+    pub fn density(&self) -> f64 {
+        match self.len {
+            0 => 0.0,
+            len => self.entries.len() as f64 / len as f64,
+        }
+    }
+
+    /// Iterate over every element, including the default ones.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = T> + '_ {
+        let mut entries = self.entries.iter().peekable();
+        (0..self.len).map(
+            move |index| match entries.next_if(|(explicit, _)| *explicit == index) {
+                Some((_, value)) => *value,
+                None => self.default,
+            },
+        )
+    }
+
+    /// Write every element into `dst`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::LengthMismatch`] if `dst` doesn't have the length
+    /// of this attribute. Nothing is written in this case.
+    pub fn materialize_into(&self, dst: &mut SliceMut<T>) -> Result<(), SliceError> {
+        if dst.len() != self.len {
+            return Err(SliceError::LengthMismatch {
+                expected: self.len,
+                len: dst.len(),
+            });
+        }
+        dst.write_elements(std::iter::repeat(self.default));
+        for (index, value) in &self.entries {
+            dst[*index] = *value;
+        }
+        Ok(())
+    }
+
+    /// Copy every element into a [`Vec`].
+    pub fn materialize_to_vec(&self) -> Vec<T> {
+        let mut result = vec![self.default; self.len];
+        for (index, value) in &self.entries {
+            result[*index] = *value;
+        }
+        result
+    }
+
+    /// Position of the entry of `index`, or the position to insert it at.
+    fn find(&self, index: usize) -> Result<usize, usize> {
+        self.entries
+            .binary_search_by_key(&index, |(explicit, _)| *explicit)
+    }
+}
+
+impl<T: FloatLanes> SparseAttribute<T> {
+    /// Similar to [`Self::from_dense`], but elements with all lanes within `epsilon`
+    /// of the lanes of `default` don't get an entry.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{Slice, SparseAttribute};
+    ///
+    /// let weights = [0.0_f32, 1e-7, 0.5, 0.0];
+    /// let sparse = SparseAttribute::from_dense_approx(&Slice::native(&weights), 0.0, 1e-6);
+    /// assert_eq!(sparse.entries().collect::<Vec<_>>(), [(2, &0.5)]);
+    /// ```
+    pub fn from_dense_approx(src: &Slice<T>, default: T, epsilon: T::Scalar) -> Self {
+        let epsilon = epsilon.to_f64();
+        Self::from_dense_by(src, default, |value| {
+            (0..T::LANES).any(|lane| {
+                let deviation = (value.lane(lane).to_f64() - default.lane(lane).to_f64()).abs();
+                deviation.is_nan() || deviation > epsilon
+            })
+        })
+    }
+}
+
+impl<T: Pod + Debug> Debug for SparseAttribute<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SparseAttribute")
+            .field("len", &self.len)
+            .field("default", &self.default)
+            .field("entries", &self.entries)
+            .finish()
+    }
+}
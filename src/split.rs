@@ -0,0 +1,427 @@
+use bytemuck::Pod;
+use std::marker::PhantomData;
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+
+use crate::shared_impl::SliceBase;
+use crate::slice_mut::mut_range;
+use crate::{Slice, SliceError, SliceMut, Strict};
+
+/// Create a view over the attribute of type `A` at byte `offset` of every element in `range`.
+///
+/// The view only owns the bytes of its attribute, since other views share the elements.
+fn attribute<V: Pod, A: Pod>(
+    range: std::ops::Range<*const V>,
+    offset: usize,
+) -> Result<SliceBase<A>, SliceError> {
+    // Empty data has no byte to offset into.
+    let offset = match range.is_empty() {
+        true => 0,
+        false => offset,
+    };
+    SliceBase::new_typed::<_, Strict>(range, offset, 1).map(SliceBase::owning_attribute)
+}
+
+/// Check that the byte windows of `R` at `read`, and `W` at `write`, don't overlap
+/// in an element of `V`.
+fn check_disjoint<V: Pod, R: Pod, W: Pod>(read: usize, write: usize) -> Result<(), SliceError> {
+    check_windows_disjoint(
+        std::mem::size_of::<V>(),
+        (read, std::mem::size_of::<R>()),
+        (write, std::mem::size_of::<W>()),
+    )
+}
+
+/// Check that the byte windows `read` and `write`, as `(offset, size)`, don't overlap
+/// in an element of `stride` bytes.
+///
+/// Windows are expected to fit in a single element, such that they repeat every element.
+pub(crate) fn check_windows_disjoint(
+    stride: usize,
+    (read, read_size): (usize, usize),
+    (write, write_size): (usize, usize),
+) -> Result<(), SliceError> {
+    if stride == 0 || read_size == 0 || write_size == 0 {
+        return Ok(());
+    }
+    let (read, write) = (read % stride, write % stride);
+    match read < write + write_size && write < read + read_size {
+        true => Err(SliceError::OverlappingAttributes { read, write }),
+        false => Ok(()),
+    }
+}
+
+/// Create a view reading the attribute at byte `read_offset`, and a view writing
+/// the attribute at byte `write_offset`, of every element of `data`.
+///
+/// Both views are alive at the same time, e.g., to compute an attribute from another
+/// one in a single loop.
+///
+/// ## Soundness
+///
+/// Both views are created from the single unique borrow of `data`, which they
+/// hold for `'a`. Attributes are validated with the [`Strict`] policy, such that each
+/// one fits in a single element. Their byte windows are then checked to be disjoint
+/// within an element, and thus in the whole buffer: the read view never observes
+/// a byte the mutable view writes.
+///
+/// Each view only owns the bytes of its attribute, in each element. Deriving
+/// another view, e.g., with [`SliceMut::rebase`], fails outside of these bytes:
+///
+/// ```rust
+/// use pas::{split_attr_read_write, Slice, SliceMut};
+///
+/// let mut data = [[1_u32, 2]; 4];
+/// let (read, write): (Slice<u32>, SliceMut<u32>) =
+///     split_attr_read_write(&mut data, 0, 4).unwrap();
+/// assert!(write.rebase::<u32>(4).is_err());
+/// assert!(read.rebase::<[u16; 2]>(0).is_ok());
+/// assert!(read.rebase::<u16>(4).is_err());
+/// ```
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{split_attr_read_write, Slice, SliceMut};
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy, Default, bytemuck::Pod, bytemuck::Zeroable)]
+/// struct Vertex {
+///     position: [f32; 3],
+///     uv: [f32; 2],
+/// }
+///
+/// let mut vertices = [
+///     Vertex { position: [1.0, 2.0, 3.0], uv: [0.0, 0.0] },
+///     Vertex { position: [4.0, 5.0, 6.0], uv: [0.0, 0.0] },
+/// ];
+/// let (positions, mut uvs): (Slice<[f32; 3]>, SliceMut<[f32; 2]>) =
+///     split_attr_read_write(&mut vertices, 0, 12).unwrap();
+/// for (position, uv) in positions.iter().zip(uvs.iter()) {
+///     *uv = [position[0], position[1]];
+/// }
+/// assert_eq!(vertices[1].uv, [4.0, 5.0]);
+/// ```
+///
+/// ## Errors
+///
+/// Returns [`SliceError::OverlappingAttributes`] if the attributes overlap, or the
+/// errors of [`Slice::with_policy`], with [`Strict`], for either attribute.
+pub fn split_attr_read_write<'a, V: Pod, R: Pod, W: Pod>(
+    data: &'a mut [V],
+    read_offset: usize,
+    write_offset: usize,
+) -> Result<(Slice<'a, R>, SliceMut<'a, W>), SliceError> {
+    let ([read], write) = split_attrs_read_write(data, [read_offset], write_offset)?;
+    Ok((read, write))
+}
+
+/// Similar to [`split_attr_read_write`], but with a view reading each attribute
+/// at `read_offsets`.
+///
+/// Read attributes may overlap with each other, but not with the written attribute.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{split_attrs_read_write, Slice, SliceMut};
+///
+/// // Two inputs, and their sum.
+/// let mut data = [[1_u32, 2, 0], [3, 4, 0]];
+/// let ([a, b], mut sum): ([Slice<u32>; 2], SliceMut<u32>) =
+///     split_attrs_read_write(&mut data, [0, 4], 8).unwrap();
+/// for (index, value) in sum.iter().enumerate() {
+///     *value = a[index] + b[index];
+/// }
+/// assert_eq!(data, [[1, 2, 3], [3, 4, 7]]);
+/// ```
+///
+/// ## Errors
+///
+/// See [`split_attr_read_write`].
+pub fn split_attrs_read_write<'a, V: Pod, R: Pod, W: Pod, const N: usize>(
+    data: &'a mut [V],
+    read_offsets: [usize; N],
+    write_offset: usize,
+) -> Result<([Slice<'a, R>; N], SliceMut<'a, W>), SliceError> {
+    // Every view is created from this range, which keeps the write
+    // permission of the borrow.
+    let range = mut_range(data);
+    let write = attribute::<V, W>(range.clone(), write_offset)?;
+    let mut reads = [Slice::default(); N];
+    for (read, offset) in reads.iter_mut().zip(read_offsets) {
+        *read = Slice::from_base(attribute(range.clone(), offset)?);
+        check_disjoint::<V, R, W>(offset, write_offset)?;
+    }
+    Ok((reads, SliceMut::from_base(write)))
+}
+
+/// Call `f` with the index, the attribute at byte `read_offset`, and the attribute
+/// at byte `write_offset`, mutably, of each element of `data`.
+///
+/// Similar to zipping the views of [`split_attr_read_write`], but attributes are
+/// validated once, and visited by a single loop incrementing both pointers by the
+/// size of `V`. `f` is called in order, from the first element to the last, for
+/// each element where both attributes are in bounds.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::for_each_vertex;
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy, Default, bytemuck::Pod, bytemuck::Zeroable)]
+/// struct Vertex {
+///     position: [f32; 3],
+///     normal: [f32; 3],
+/// }
+///
+/// let mut vertices = [Vertex { position: [0.0, 3.0, 4.0], normal: [0.0; 3] }; 2];
+/// for_each_vertex(&mut vertices, 0, 12, |_, p: &[f32; 3], n: &mut [f32; 3]| {
+///     let length = (p[0] * p[0] + p[1] * p[1] + p[2] * p[2]).sqrt();
+///     *n = [p[0] / length, p[1] / length, p[2] / length];
+/// })
+/// .unwrap();
+/// assert_eq!(vertices[1].normal, [0.0, 0.6, 0.8]);
+/// ```
+///
+/// ## Errors
+///
+/// See [`split_attr_read_write`]. `f` isn't called if an error is returned.
+pub fn for_each_vertex<V: Pod, R: Pod, W: Pod>(
+    data: &mut [V],
+    read_offset: usize,
+    write_offset: usize,
+    mut f: impl FnMut(usize, &R, &mut W),
+) -> Result<(), SliceError> {
+    let range = mut_range(data);
+    let write = attribute::<V, W>(range.clone(), write_offset)?;
+    let read = attribute::<V, R>(range, read_offset)?;
+    check_disjoint::<V, R, W>(read_offset, write_offset)?;
+
+    // Offsets past the first element have fewer elements, similar to zipping views.
+    let (stride, len) = (std::mem::size_of::<V>(), read.len().min(write.len()));
+    for index in 0..len {
+        // Safe because both attributes are in bounds, and disjoint. See the
+        // soundness section of `split_attr_read_write`.
+        unsafe {
+            let read = &*read.start.add(index * stride).cast::<R>();
+            let write = &mut *write.start.add(index * stride).cast::<W>().cast_mut();
+            f(index, read, write);
+        }
+    }
+    Ok(())
+}
+
+/// Similar to [`for_each_vertex`], but with two read attributes, at bytes
+/// `read_offsets`, e.g., to generate tangents from positions and texture coordinates.
+///
+/// Read attributes may overlap with each other, but not with the written attribute.
+///
+/// ## Errors
+///
+/// See [`split_attr_read_write`].
+pub fn for_each_vertex2<V: Pod, R0: Pod, R1: Pod, W: Pod>(
+    data: &mut [V],
+    read_offsets: (usize, usize),
+    write_offset: usize,
+    mut f: impl FnMut(usize, &R0, &R1, &mut W),
+) -> Result<(), SliceError> {
+    let range = mut_range(data);
+    let write = attribute::<V, W>(range.clone(), write_offset)?;
+    let read0 = attribute::<V, R0>(range.clone(), read_offsets.0)?;
+    let read1 = attribute::<V, R1>(range, read_offsets.1)?;
+    check_disjoint::<V, R0, W>(read_offsets.0, write_offset)?;
+    check_disjoint::<V, R1, W>(read_offsets.1, write_offset)?;
+
+    let stride = std::mem::size_of::<V>();
+    let len = read0.len().min(read1.len()).min(write.len());
+    for index in 0..len {
+        // Safe because every attribute is in bounds, and the written one is
+        // disjoint from the read ones.
+        unsafe {
+            let read0 = &*read0.start.add(index * stride).cast::<R0>();
+            let read1 = &*read1.start.add(index * stride).cast::<R1>();
+            let write = &mut *write.start.add(index * stride).cast::<W>().cast_mut();
+            f(index, read0, read1, write);
+        }
+    }
+    Ok(())
+}
+
+/// Liveness of a [`SendableLane`], shared with its [`LaneGuard`].
+struct LaneState {
+    alive: Mutex<bool>,
+    released: Condvar,
+}
+
+/// Mutable view over an attribute lane, that can be moved to any thread,
+/// including non-scoped ones.
+///
+/// Created by [`split_attr_read_send`], and only accessed through [`Self::with_view`].
+/// The buffer stays borrowed until the lane is dropped: see [`LaneGuard`].
+pub struct SendableLane<T: Pod> {
+    inner: SliceBase<T>,
+    state: Arc<LaneState>,
+}
+
+// Safe because the lane is the only view writing its bytes, like a `SliceMut`,
+// and the buffer borrow outlives it, see `LaneGuard`.
+unsafe impl<T: Pod + Send> Send for SendableLane<T> {}
+
+impl<T: Pod> SendableLane<T> {
+    /// Call `f` with a mutable view over the lane.
+    ///
+    /// The view is only borrowed for the call, such that it can't outlive the lane.
+    /// It only owns the bytes of the lane: views derived from it, e.g., with
+    /// [`SliceMut::rebase`], never reach the read attribute.
+    pub fn with_view<R>(&mut self, f: impl FnOnce(SliceMut<'_, T>) -> R) -> R {
+        f(SliceMut::from_base(self.inner))
+    }
+
+    /// Number of elements in the lane.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// `true` if the lane has no element, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl<T: Pod> Drop for SendableLane<T> {
+    fn drop(&mut self) {
+        let mut alive = self
+            .state
+            .alive
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        *alive = false;
+        self.state.released.notify_all();
+    }
+}
+
+/// Originating side of a [`SendableLane`], owned by [`split_attr_read_send`].
+///
+/// When dropped, the guard **blocks** until the lane is dropped, such that the lane
+/// never outlives the buffer borrow. The guard is dropped even if the closure of
+/// [`split_attr_read_send`] panics, and leaking it is impossible.
+///
+/// The guard can't leave the thread it was created on:
+///
+/// ```rust,compile_fail
+/// use pas::split_attr_read_send;
+///
+/// let mut data = [[0_u32; 2]; 4];
+/// split_attr_read_send(&mut data, 0, 4, |_: pas::Slice<u32>, lane: pas::SendableLane<u32>, guard| {
+///     std::thread::scope(|s| {
+///         s.spawn(move || guard.is_released());
+///     });
+///     drop(lane);
+/// })
+/// .unwrap();
+/// ```
+pub struct LaneGuard {
+    state: Arc<LaneState>,
+    // Neither `Send` nor `Sync`.
+    _thread: PhantomData<*const ()>,
+}
+
+impl LaneGuard {
+    /// `true` if the lane was dropped, `false` otherwise
+    pub fn is_released(&self) -> bool {
+        !*self
+            .state
+            .alive
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Block until the lane is dropped.
+    pub fn wait(&self) {
+        let alive = self
+            .state
+            .alive
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let _released = self
+            .state
+            .released
+            .wait_while(alive, |alive| *alive)
+            .unwrap_or_else(PoisonError::into_inner);
+    }
+}
+
+impl Drop for LaneGuard {
+    fn drop(&mut self) {
+        self.wait();
+    }
+}
+
+/// Similar to [`split_attr_read_write`], but the written attribute is a
+/// [`SendableLane`], that can be moved to a thread outliving `f`.
+///
+/// `f` receives the read view, the lane, and its [`LaneGuard`]. Once `f` returns,
+/// this function blocks until the lane is dropped, and only then releases `data`.
+///
+/// <div class="warning">
+///
+/// Returning the lane from `f`, or leaking it, blocks forever.
+///
+/// </div>
+///
+/// ## Soundness
+///
+/// The lane holds raw pointers, and is thus `'static`. The unique borrow of `data`
+/// is instead held by this function, for as long as the lane is alive:
+/// the guard is a local of this function, and its destructor, which always runs,
+/// waits for the lane destructor. The mutex orders every write of the lane before
+/// the return of this function. Read and written attributes are disjoint, as for
+/// [`split_attr_read_write`].
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{split_attr_read_send, Slice, SendableLane};
+///
+/// let mut data = [[1.0_f32, 0.0]; 64];
+/// let sum = split_attr_read_send(&mut data, 0, 4, |read: Slice<f32>, mut lane: SendableLane<f32>, _| {
+///     // Bake the second attribute on a background thread.
+///     std::thread::spawn(move || {
+///         lane.with_view(|mut view| view.fill(0.5));
+///     });
+///     read.iter().sum::<f32>()
+/// })
+/// .unwrap();
+/// assert_eq!(sum, 64.0);
+/// assert_eq!(data[63], [1.0, 0.5]);
+/// ```
+///
+/// ## Errors
+///
+/// See [`split_attr_read_write`]. `f` isn't called if an error is returned.
+pub fn split_attr_read_send<'a, V: Pod, R: Pod, W: Pod, T>(
+    data: &'a mut [V],
+    read_offset: usize,
+    write_offset: usize,
+    f: impl FnOnce(Slice<'a, R>, SendableLane<W>, &LaneGuard) -> T,
+) -> Result<T, SliceError> {
+    let range = mut_range(data);
+    let write = attribute::<V, W>(range.clone(), write_offset)?;
+    let read = attribute::<V, R>(range, read_offset)?;
+    check_disjoint::<V, R, W>(read_offset, write_offset)?;
+
+    let state = Arc::new(LaneState {
+        alive: Mutex::new(true),
+        released: Condvar::new(),
+    });
+    let guard = LaneGuard {
+        state: state.clone(),
+        _thread: PhantomData,
+    };
+    let lane = SendableLane {
+        inner: write,
+        state,
+    };
+    Ok(f(Slice::from_base(read), lane, &guard))
+}
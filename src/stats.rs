@@ -0,0 +1,281 @@
+use bytemuck::Pod;
+use std::fmt::Debug;
+
+use crate::{private, SliceInfo};
+
+/// Number of bytes compared at once by [`SliceInfo::all_equal_to`], for contiguous elements.
+const EQUALITY_CHUNK: usize = 256;
+
+/// Floating point scalar type, i.e., `f32` or `f64`.
+///
+/// This trait is sealed and can't be implemented outside of this crate:
+///
+/// ```rust,compile_fail,E0277
+/// #[repr(transparent)]
+/// #[derive(Clone, Copy, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+/// struct Half(u16);
+///
+/// impl pas::Float for Half {
+///     const MAX: Self = Half(0x7bff);
+///
+///     fn to_f64(self) -> f64 {
+///         0.0
+///     }
+///     fn from_f64(_: f64) -> Self {
+///         Half(0)
+///     }
+/// }
+/// ```
+pub trait Float: Pod + Debug + private::Sealed {
+    /// Largest finite value.
+    const MAX: Self;
+
+    /// Convert to `f64`, used for accumulation.
+    fn to_f64(self) -> f64;
+    /// Convert from `f64`.
+    fn from_f64(value: f64) -> Self;
+}
+
+impl private::Sealed for f32 {}
+impl Float for f32 {
+    const MAX: Self = f32::MAX;
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+}
+
+impl private::Sealed for f64 {}
+impl Float for f64 {
+    const MAX: Self = f64::MAX;
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
+
+/// Attribute made of one or multiple floating point lanes.
+///
+/// Implemented for `f32`, `f64`, and arrays of those.
+///
+/// This trait is sealed and can't be implemented outside of this crate.
+pub trait FloatLanes: Pod + Debug + private::Sealed {
+    /// Scalar type of each lane.
+    type Scalar: Float;
+    /// Number of lanes.
+    const LANES: usize;
+
+    /// Get the lane at index `lane`.
+    fn lane(&self, lane: usize) -> Self::Scalar;
+    /// Set the lane at index `lane`.
+    fn set_lane(&mut self, lane: usize, value: Self::Scalar);
+    /// Create an attribute with all lanes set to `value`.
+    fn splat(value: Self::Scalar) -> Self;
+}
+
+impl<T: Float> FloatLanes for T {
+    type Scalar = T;
+    const LANES: usize = 1;
+
+    fn lane(&self, _: usize) -> T {
+        *self
+    }
+    fn set_lane(&mut self, _: usize, value: T) {
+        *self = value;
+    }
+    fn splat(value: T) -> Self {
+        value
+    }
+}
+
+impl<T: Float, const N: usize> private::Sealed for [T; N] {}
+impl<T: Float, const N: usize> FloatLanes for [T; N] {
+    type Scalar = T;
+    const LANES: usize = N;
+
+    fn lane(&self, lane: usize) -> T {
+        self[lane]
+    }
+    fn set_lane(&mut self, lane: usize, value: T) {
+        self[lane] = value;
+    }
+    fn splat(value: T) -> Self {
+        [value; N]
+    }
+}
+
+/// Per-lane statistics of a slice, computed by [`SliceInfo::summary`].
+///
+/// Non-finite values (NaN and infinities) are excluded from `min`, `max`, and `mean`,
+/// but are counted in `non_finite`. A lane without any finite value reports NaN.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Summary<T> {
+    /// Number of elements in the slice
+    pub count: usize,
+    /// Per-lane minimum
+    pub min: T,
+    /// Per-lane maximum
+    pub max: T,
+    /// Per-lane mean
+    pub mean: T,
+    /// Number of non-finite lanes, over all elements
+    pub non_finite: usize,
+}
+
+impl<T: Debug> std::fmt::Display for Summary<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "count: {}, min: {:?}, max: {:?}, mean: {:?}, non-finite: {}",
+            self.count, self.min, self.max, self.mean, self.non_finite
+        )
+    }
+}
+
+impl<Attr: Pod> SliceInfo<Attr> {
+    /// Returns the first element if every element is bit-identical, `None` otherwise.
+    ///
+    /// Returns `None` for an empty slice.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[0_u32, 1], [0, 2], [0, 3]];
+    /// let slice: Slice<u32> = Slice::new(&data, 0);
+    /// assert_eq!(slice.is_constant(), Some(0));
+    /// ```
+    pub fn is_constant(&self) -> Option<Attr> {
+        let first = *self.get(0)?;
+        self.all_equal_to(&first).then_some(first)
+    }
+
+    /// Returns `true` if every element is bit-identical to `value`, or if the slice is empty.
+    ///
+    /// Elements are compared byte by byte, stopping at the first difference. Contiguous
+    /// elements are compared in chunks, against `value` repeated.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[0_u32, 1], [0, 2], [0, 3]];
+    /// let slice: Slice<u32> = Slice::new(&data, 0);
+    /// assert!(slice.all_equal_to(&0));
+    /// assert!(!Slice::<u32>::new(&data, 4).all_equal_to(&1));
+    /// ```
+    pub fn all_equal_to(&self, value: &Attr) -> bool {
+        let value = bytemuck::bytes_of(value);
+        let (len, size) = (self.len(), value.len());
+        if len == 0 || size == 0 {
+            return true;
+        }
+        if size > EQUALITY_CHUNK || (len > 1 && self.stride() != size) {
+            return self.elements().all(|elt| bytemuck::bytes_of(elt) == value);
+        }
+        // Safe because the `len` elements are packed, and in bounds.
+        let bytes = unsafe { std::slice::from_raw_parts(self.base().start, len * size) };
+        let mut pattern = [0_u8; EQUALITY_CHUNK];
+        let pattern = &mut pattern[..EQUALITY_CHUNK - EQUALITY_CHUNK % size];
+        pattern
+            .chunks_exact_mut(size)
+            .for_each(|chunk| chunk.copy_from_slice(value));
+        let mut chunks = bytes.chunks_exact(pattern.len());
+        let equal = chunks.all(|chunk| chunk == pattern);
+        // The remainder is made of whole elements.
+        equal && chunks.remainder() == &pattern[..chunks.remainder().len()]
+    }
+
+    /// Returns `true` if any element is bit-identical to `value`, `false` otherwise,
+    /// including for an empty slice.
+    ///
+    /// See [`Self::all_equal_to`].
+    pub fn any_equal_to(&self, value: &Attr) -> bool {
+        let value = bytemuck::bytes_of(value);
+        self.elements().any(|elt| bytemuck::bytes_of(elt) == value)
+    }
+}
+
+impl<Attr: FloatLanes> SliceInfo<Attr> {
+    /// Returns `true` if every lane of every element is within `epsilon` of the same
+    /// lane of `value`, or if the slice is empty.
+    ///
+    /// NaN lanes are never equal, use [`Self::all_equal_to`] for bit-identical elements.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let weights = [[0.5_f32, 0.5], [0.5000001, 0.49999997]];
+    /// let slice: Slice<[f32; 2]> = Slice::native(&weights);
+    /// assert!(!slice.all_equal_to(&[0.5, 0.5]));
+    /// assert!(slice.all_approx_equal_to(&[0.5, 0.5], 1e-6));
+    /// ```
+    pub fn all_approx_equal_to(&self, value: &Attr, epsilon: Attr::Scalar) -> bool {
+        let epsilon = epsilon.to_f64();
+        self.elements().all(|elt| {
+            (0..Attr::LANES)
+                .all(|lane| (elt.lane(lane).to_f64() - value.lane(lane).to_f64()).abs() <= epsilon)
+        })
+    }
+
+    /// Compute per-lane minimum, maximum, mean, and count non-finite values in one pass.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let data = [[0.0_f32, 1.0], [2.0, f32::NAN], [4.0, 3.0]];
+    /// let slice: Slice<[f32; 2]> = Slice::new(&data, 0);
+    /// let summary = slice.summary();
+    /// assert_eq!(summary.min, [0.0, 1.0]);
+    /// assert_eq!(summary.max, [4.0, 3.0]);
+    /// assert_eq!(summary.non_finite, 1);
+    /// println!("{}", summary);
+    /// ```
+    pub fn summary(&self) -> Summary<Attr> {
+        let nan = Attr::splat(Attr::Scalar::from_f64(f64::NAN));
+        let mut summary = Summary {
+            count: self.len(),
+            min: nan,
+            max: nan,
+            mean: nan,
+            non_finite: 0,
+        };
+        // Per-lane (min, max, sum, count) of finite values.
+        let mut lanes = vec![(f64::INFINITY, f64::NEG_INFINITY, 0.0, 0_usize); Attr::LANES];
+        for elt in self.elements() {
+            for (lane, (min, max, sum, count)) in lanes.iter_mut().enumerate() {
+                let value = elt.lane(lane).to_f64();
+                if !value.is_finite() {
+                    summary.non_finite += 1;
+                    continue;
+                }
+                *min = min.min(value);
+                *max = max.max(value);
+                *sum += value;
+                *count += 1;
+            }
+        }
+        for (lane, (min, max, sum, count)) in lanes.into_iter().enumerate() {
+            if count > 0 {
+                summary.min.set_lane(lane, Attr::Scalar::from_f64(min));
+                summary.max.set_lane(lane, Attr::Scalar::from_f64(max));
+                summary
+                    .mean
+                    .set_lane(lane, Attr::Scalar::from_f64(sum / count as f64));
+            }
+        }
+        summary
+    }
+}
@@ -0,0 +1,145 @@
+use bytemuck::Pod;
+
+use crate::SliceMut;
+
+/// Minimum number of bytes written for streaming stores to be used.
+///
+/// Smaller writes take the normal path, since the fence and the partial
+/// blocks at both ends would dominate.
+const STREAMING_MIN_BYTES: usize = 4096;
+
+/// Largest repeated pattern used by [`SliceMut::fill_streaming`], in **bytes**.
+const STREAMING_MAX_PATTERN: usize = 4096;
+
+/// Size of a streaming store, in **bytes**.
+const BLOCK: usize = 16;
+
+/// Write `len` bytes to `dst`, where byte `i` is `pattern[i % period]`, using
+/// non-temporal stores.
+///
+/// Returns `false`, without writing anything, on architectures without streaming stores,
+/// and under Miri, which doesn't support them.
+///
+/// ## Safety
+///
+/// - `dst` must be valid for writing `len` bytes
+/// - `pattern` must hold at least `min(len, period + BLOCK)` bytes, and `period`
+///   must be a multiple of [`BLOCK`] if smaller than `len`
+#[inline]
+unsafe fn stream_bytes(dst: *mut u8, len: usize, pattern: &[u8], period: usize) -> bool {
+    #[cfg(all(target_arch = "x86_64", not(miri)))]
+    {
+        use std::arch::x86_64::{__m128i, _mm_loadu_si128, _mm_sfence, _mm_stream_si128};
+
+        let write = |i: usize| dst.add(i).write(pattern[i % period]);
+        // Bytes before the first address aligned for streaming stores.
+        let head = dst.align_offset(BLOCK).min(len);
+        (0..head).for_each(write);
+        let mut i = head;
+        while i + BLOCK <= len {
+            // Aligned store, with `pattern` holding `BLOCK` bytes past any offset
+            // smaller than `period`.
+            let block = _mm_loadu_si128(pattern.as_ptr().add(i % period).cast::<__m128i>());
+            _mm_stream_si128(dst.add(i).cast::<__m128i>(), block);
+            i += BLOCK;
+        }
+        (i..len).for_each(write);
+        // Order the streaming stores before any later store.
+        _mm_sfence();
+        true
+    }
+    #[cfg(not(all(target_arch = "x86_64", not(miri))))]
+    {
+        let _ = (dst, len, pattern, period);
+        false
+    }
+}
+
+/// Least common multiple of `size` and [`BLOCK`].
+fn pattern_period(size: usize) -> usize {
+    let mut gcd = (size, BLOCK);
+    while gcd.1 != 0 {
+        gcd = (gcd.1, gcd.0 % gcd.1);
+    }
+    size / gcd.0 * BLOCK
+}
+
+impl<'a, Attr: Pod> SliceMut<'a, Attr> {
+    /// `true` if `bytes` bytes of packed elements should be written with streaming stores.
+    fn use_streaming(&self, bytes: usize) -> bool {
+        self.stride() == std::mem::size_of::<Attr>() && bytes >= STREAMING_MIN_BYTES
+    }
+
+    /// Similar to [`Self::copy_from_slice`], but uses non-temporal stores, bypassing
+    /// the cache.
+    ///
+    /// Streaming stores are faster when writing large amounts of data that isn't read
+    /// back soon, in particular to write-combined memory, e.g., a GPU upload heap.
+    /// They are slower otherwise: measure first, e.g., with the `bulk` benchmark.
+    ///
+    /// Streaming stores are only used on `x86_64`, for packed elements of the same size
+    /// as `V`, when writing at least a few kilobytes. Other cases take the normal path.
+    /// The result is always the same as [`Self::copy_from_slice`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::SliceMut;
+    ///
+    /// let mut dest = vec![[0_u32; 4]; 1024];
+    /// let src: Vec<[u32; 4]> = (0..1024).map(|i| [i; 4]).collect();
+    /// SliceMut::native(&mut dest).copy_from_slice_streaming(&src);
+    /// assert_eq!(dest, src);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// See [`Self::copy_from_slice`].
+    pub fn copy_from_slice_streaming<V: Pod>(&mut self, src: &[V]) {
+        let bytes: &[u8] = bytemuck::cast_slice(src);
+        let streamed = std::mem::size_of::<V>() == std::mem::size_of::<Attr>()
+            && src.len() <= self.len()
+            && self.use_streaming(bytes.len())
+            // Safe because the elements are packed, and `self` holds at least `src.len()`.
+            && unsafe {
+                stream_bytes(self.base().start.cast_mut(), bytes.len(), bytes, bytes.len())
+            };
+        if !streamed {
+            self.copy_from_slice(src);
+        }
+    }
+
+    /// Similar to [`Self::fill`], but uses non-temporal stores, bypassing the cache.
+    ///
+    /// See [`Self::copy_from_slice_streaming`] for when streaming stores are used.
+    /// The result is always the same as [`Self::fill`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::SliceMut;
+    ///
+    /// let mut data = vec![[0.0_f32; 3]; 1024];
+    /// SliceMut::native(&mut data).fill_streaming([1.0, 2.0, 3.0]);
+    /// assert!(data.iter().all(|v| *v == [1.0, 2.0, 3.0]));
+    /// ```
+    pub fn fill_streaming(&mut self, value: Attr) {
+        let size = std::mem::size_of::<Attr>();
+        let period = pattern_period(size);
+        let bytes = self.len() * size;
+        if self.use_streaming(bytes) && period <= STREAMING_MAX_PATTERN {
+            let pattern: Vec<u8> = bytemuck::bytes_of(&value)
+                .iter()
+                .copied()
+                .cycle()
+                .take(period + BLOCK)
+                .collect();
+            // Safe because the elements are packed, and `period` is a multiple of `BLOCK`.
+            let start = self.base().start.cast_mut();
+            if unsafe { stream_bytes(start, bytes, &pattern, period) } {
+                return;
+            }
+        }
+        self.fill(value);
+    }
+}
@@ -0,0 +1,231 @@
+use bytemuck::Pod;
+use std::fmt::Debug;
+use std::ops::Neg;
+
+use crate::{Slice, SliceError, SliceMut};
+
+/// Check that each lane of `order` is smaller than `N`.
+fn validate<const N: usize>(order: &[usize; N]) -> Result<(), SliceError> {
+    match order.iter().position(|lane| *lane >= N) {
+        Some(position) => Err(SliceError::IndexOutOfBounds {
+            position,
+            index: order[position] as u64,
+            len: N,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Lane `i` of the result is lane `order[i]` of `element`, negated if `signs[i]` is set.
+#[inline]
+fn swizzle<T: Copy, const N: usize>(
+    element: &[T; N],
+    order: &[usize; N],
+    signs: &[bool; N],
+    negate: fn(T) -> T,
+) -> [T; N] {
+    std::array::from_fn(|lane| match signs[lane] {
+        true => negate(element[order[lane]]),
+        false => element[order[lane]],
+    })
+}
+
+/// Read-only view over array elements, with their lanes reordered, and
+/// optionally negated.
+///
+/// Lane `i` of each element is lane `order[i]` of the element of the base slice.
+/// `order` doesn't need to be a permutation: a lane can be read more than once,
+/// e.g., `[0, 0, 0]` broadcasts the first lane.
+///
+/// Elements are swizzled on access, and returned by value. Use
+/// [`Self::materialize_into`] to write them all, or [`SliceMut::swizzle_in_place`]
+/// to swizzle a buffer without a copy.
+///
+/// Created with [`Slice::swizzled`].
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::Slice;
+///
+/// let bgra = [[255_u8, 128, 0, 255], [10, 20, 30, 40]];
+/// let rgba = Slice::native(&bgra).swizzled([2, 1, 0, 3]).unwrap();
+/// assert_eq!(rgba.get(0), Some([0, 128, 255, 255]));
+/// assert_eq!(rgba.get(1), Some([30, 20, 10, 40]));
+/// ```
+#[derive(Clone, Copy)]
+pub struct SwizzledSlice<'a, T: Pod, const N: usize> {
+    slice: Slice<'a, [T; N]>,
+    order: [usize; N],
+    signs: [bool; N],
+    negate: fn(T) -> T,
+}
+
+impl<'a, T: Pod, const N: usize> Slice<'a, [T; N]> {
+    /// Convert into a view whose lane `i` is lane `order[i]` of each element.
+    ///
+    /// See [`SwizzledSlice`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::IndexOutOfBounds`] if a lane of `order` isn't smaller
+    /// than `N`, with the position of the lane in `order`.
+    pub fn swizzled(self, order: [usize; N]) -> Result<SwizzledSlice<'a, T, N>, SliceError> {
+        validate(&order)?;
+        Ok(SwizzledSlice {
+            slice: self,
+            order,
+            signs: [false; N],
+            negate: |value| value,
+        })
+    }
+}
+
+impl<'a, T: Pod, const N: usize> SwizzledSlice<'a, T, N> {
+    /// Negate lane `i` of the swizzled elements if `signs[i]` is `true`,
+    /// e.g., to flip an axis.
+    ///
+    /// Signs apply to the lanes of the result, after reordering.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// // Z-up to Y-up: (x, y, z) becomes (x, z, -y).
+    /// let z_up = [[1.0_f32, 2.0, 3.0]];
+    /// let y_up = Slice::native(&z_up)
+    ///     .swizzled([0, 2, 1])
+    ///     .unwrap()
+    ///     .with_signs([false, false, true]);
+    /// assert_eq!(y_up.get(0), Some([1.0, 3.0, -2.0]));
+    /// ```
+    pub fn with_signs(self, signs: [bool; N]) -> Self
+    where
+        T: Neg<Output = T>,
+    {
+        Self {
+            signs,
+            negate: T::neg,
+            ..self
+        }
+    }
+
+    /// Lane of the base elements read by each lane.
+    pub fn order(&self) -> [usize; N] {
+        self.order
+    }
+
+    /// Lanes negated after reordering.
+    pub fn signs(&self) -> [bool; N] {
+        self.signs
+    }
+
+    /// Number of elements, i.e., the number of elements of the base slice.
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// `true` if the base slice is empty, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+
+    /// Get the swizzled element at `index`.
+    pub fn get(&self, index: usize) -> Option<[T; N]> {
+        self.slice
+            .get(index)
+            .map(|element| swizzle(element, &self.order, &self.signs, self.negate))
+    }
+
+    /// Iterate over the swizzled elements.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = [T; N]> + '_ {
+        self.slice
+            .iter()
+            .map(|element| swizzle(element, &self.order, &self.signs, self.negate))
+    }
+
+    /// Copy the swizzled elements into a [`Vec`].
+    pub fn to_vec(&self) -> Vec<[T; N]> {
+        self.iter().collect()
+    }
+
+    /// Write the swizzled elements into `dst`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::LengthMismatch`] if `dst` doesn't have the length
+    /// of this slice. Nothing is written in this case.
+    pub fn materialize_into(&self, dst: &mut SliceMut<[T; N]>) -> Result<(), SliceError> {
+        if dst.len() != self.len() {
+            return Err(SliceError::LengthMismatch {
+                expected: self.len(),
+                len: dst.len(),
+            });
+        }
+        dst.write_elements(self.iter());
+        Ok(())
+    }
+
+    /// Convert back into the base slice, without swizzling.
+    pub fn into_slice(self) -> Slice<'a, [T; N]> {
+        self.slice
+    }
+}
+
+impl<'a, T: Pod + Debug, const N: usize> Debug for SwizzledSlice<'a, T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: Pod, const N: usize> SliceMut<'a, [T; N]> {
+    /// Reorder the lanes of each element, such that lane `i` becomes the previous
+    /// lane `order[i]`.
+    ///
+    /// Elements are swizzled in a single pass, on the stack, without allocating.
+    /// As for [`Slice::swizzled`], lanes can be repeated to broadcast them.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::SliceMut;
+    ///
+    /// let mut colors = [[255_u8, 128, 0, 255]];
+    /// SliceMut::native(&mut colors).swizzle_in_place([2, 1, 0, 3]).unwrap();
+    /// assert_eq!(colors, [[0, 128, 255, 255]]);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::IndexOutOfBounds`] if a lane of `order` isn't smaller
+    /// than `N`, with the position of the lane in `order`. Nothing is written in
+    /// this case.
+    pub fn swizzle_in_place(&mut self, order: [usize; N]) -> Result<(), SliceError> {
+        validate(&order)?;
+        let signs = [false; N];
+        self.for_each_fast_mut(|element| *element = swizzle(element, &order, &signs, |v| v));
+        Ok(())
+    }
+
+    /// Reorder the lanes of each element, as [`Self::swizzle_in_place`], and
+    /// negate lane `i` of the result if `signs[i]` is `true`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::IndexOutOfBounds`] if a lane of `order` isn't smaller
+    /// than `N`, with the position of the lane in `order`. Nothing is written in
+    /// this case.
+    pub fn swizzle_in_place_signed(
+        &mut self,
+        order: [usize; N],
+        signs: [bool; N],
+    ) -> Result<(), SliceError>
+    where
+        T: Neg<Output = T>,
+    {
+        validate(&order)?;
+        self.for_each_fast_mut(|element| *element = swizzle(element, &order, &signs, T::neg));
+        Ok(())
+    }
+}
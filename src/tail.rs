@@ -0,0 +1,138 @@
+use bytemuck::Pod;
+use std::fmt::Debug;
+
+use crate::Slice;
+
+/// How a [`TailPolicySlice`] exposes a final element cut by the end of the buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TailPolicy {
+    /// The final element is read as its available bytes, followed by zeros
+    ZeroPad,
+    /// The final element is dropped, as with a [`Slice`]
+    Truncate,
+}
+
+/// Read-only view over the elements of a slice, with an explicit policy for
+/// a final element truncated by the end of the buffer.
+///
+/// A [`Slice`] only counts elements whose attribute entirely fits in the
+/// buffer. Some formats instead count every started stride window, and expect
+/// readers to pad the last element with zeros. With [`TailPolicy::ZeroPad`],
+/// this element is copied over zeros, once, into storage owned by the view,
+/// such that [`Self::get`] returns a reference valid as long as the view.
+///
+/// Created with [`Slice::with_tail_policy`].
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{Slice, TailPolicy};
+///
+/// // Two and a half `[u16; 2]`.
+/// let data = [1_u16, 2, 3, 4, 5];
+/// let slice: Slice<[u16; 2]> = Slice::raw(bytemuck::cast_slice(&data), 0, 4);
+/// assert_eq!(slice.len(), 2);
+///
+/// let padded = slice.with_tail_policy(TailPolicy::ZeroPad);
+/// assert_eq!(padded.len(), 3);
+/// assert_eq!(padded.get(2), Some(&[5, 0]));
+/// ```
+#[derive(Clone, Copy)]
+pub struct TailPolicySlice<'a, T: Pod> {
+    slice: Slice<'a, T>,
+    policy: TailPolicy,
+    /// Zero-padded copy of the truncated final element, if any.
+    tail: Option<T>,
+}
+
+impl<'a, T: Pod> Slice<'a, T> {
+    /// Convert into a view exposing a truncated final element according to `policy`.
+    ///
+    /// See [`TailPolicySlice`].
+    pub fn with_tail_policy(self, policy: TailPolicy) -> TailPolicySlice<'a, T> {
+        let tail = match policy {
+            TailPolicy::ZeroPad => self.partial_tail(),
+            TailPolicy::Truncate => None,
+        };
+        TailPolicySlice {
+            slice: self,
+            policy,
+            tail,
+        }
+    }
+
+    /// Copy of the element starting after the last one, padded with zeros,
+    /// if the buffer ends inside of it.
+    fn partial_tail(&self) -> Option<T> {
+        let base = self.base();
+        if std::mem::size_of::<T>() == 0 {
+            return None;
+        }
+        let start = base.stride().checked_mul(self.len())?;
+        let available = (base.end as usize).saturating_sub(base.start as usize);
+        let count = available.checked_sub(start).filter(|count| *count > 0)?;
+        // Safe because the bytes are in `start..end`, which is borrowed by the slice.
+        let bytes = unsafe { std::slice::from_raw_parts(base.start.add(start), count) };
+        let mut tail = T::zeroed();
+        bytemuck::bytes_of_mut(&mut tail)[..count].copy_from_slice(bytes);
+        Some(tail)
+    }
+}
+
+impl<'a, T: Pod> TailPolicySlice<'a, T> {
+    /// Policy applied to the final element.
+    pub fn policy(&self) -> TailPolicy {
+        self.policy
+    }
+
+    /// `true` if the final element is a zero-padded copy, `false` otherwise
+    pub fn is_padded(&self) -> bool {
+        self.tail.is_some()
+    }
+
+    /// Number of elements, including the zero-padded final element, if any.
+    pub fn len(&self) -> usize {
+        self.slice.len() + self.tail.is_some() as usize
+    }
+
+    /// `true` if the view has no element, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the element at `index`.
+    ///
+    /// The zero-padded final element is borrowed from the view, other elements
+    /// from the buffer.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match index.checked_sub(self.slice.len()) {
+            None => self.slice.get(index),
+            Some(0) => self.tail.as_ref(),
+            Some(_) => None,
+        }
+    }
+
+    /// Iterate over the elements, including the zero-padded final element, if any.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        (0..self.len()).filter_map(|index| self.get(index))
+    }
+
+    /// Convert back into the base slice, which never has a truncated element.
+    pub fn into_slice(self) -> Slice<'a, T> {
+        self.slice
+    }
+}
+
+impl<'a, T: Pod + Debug> Debug for TailPolicySlice<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: Pod> std::ops::Index<usize> for TailPolicySlice<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index ouf of bounds")
+    }
+}
@@ -0,0 +1,117 @@
+use bytemuck::Pod;
+
+use crate::{Float, FloatLanes, SliceInfo, SliceMut};
+
+/// splitmix64 generator, see <https://prng.di.unimi.it/splitmix64.c>.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// Stream of bytes, made of the little-endian bytes of each splitmix64 output.
+struct ByteStream {
+    rng: SplitMix64,
+    bytes: [u8; 8],
+    next: usize,
+}
+
+impl ByteStream {
+    fn new(seed: u64) -> Self {
+        Self {
+            rng: SplitMix64(seed),
+            bytes: [0; 8],
+            next: 8,
+        }
+    }
+
+    fn fill(&mut self, dst: &mut [u8]) {
+        for byte in dst {
+            if self.next == 8 {
+                self.bytes = self.rng.next().to_le_bytes();
+                self.next = 0;
+            }
+            *byte = self.bytes[self.next];
+            self.next += 1;
+        }
+    }
+}
+
+/// FNV-1a offset basis and prime, for 64-bit hashes.
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+impl<Attr: Pod> SliceInfo<Attr> {
+    /// 64-bit FNV-1a hash of the bytes of every element, in order.
+    ///
+    /// Bytes between elements are ignored. Pairs with [`SliceMut::fill_deterministic`]
+    /// for golden-value assertions.
+    pub fn checksum(&self) -> u64 {
+        let mut hash = FNV_OFFSET;
+        self.for_each_fast(|v| {
+            for byte in bytemuck::bytes_of(v) {
+                hash = (hash ^ *byte as u64).wrapping_mul(FNV_PRIME);
+            }
+        });
+        hash
+    }
+}
+
+impl<'a, Attr: Pod> SliceMut<'a, Attr> {
+    /// Fill the bytes of every element from a pseudo-random stream seeded with `seed`.
+    ///
+    /// The stream is made of the little-endian bytes of consecutive
+    /// [splitmix64](https://prng.di.unimi.it/splitmix64.c) outputs, and elements
+    /// consume it in order. A seed and a layout thus always yield the same bytes,
+    /// whatever the platform or endianness. Bytes between elements are left untouched.
+    ///
+    /// Bytes are random, float lanes may thus be NaN or infinite,
+    /// see [`Self::fill_deterministic_finite`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "testing")]
+    /// # {
+    /// use pas::SliceMut;
+    ///
+    /// let mut data = [[0_u32; 2]; 4];
+    /// let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 0);
+    /// slice.fill_deterministic(7);
+    /// let checksum = slice.checksum();
+    /// assert!(data.iter().all(|v| v[1] == 0));
+    ///
+    /// // Same seed and layout, same content.
+    /// let mut other = [0_u32; 4];
+    /// let mut slice: SliceMut<u32> = SliceMut::native(&mut other);
+    /// slice.fill_deterministic(7);
+    /// assert_eq!(slice.checksum(), checksum);
+    /// # }
+    /// ```
+    pub fn fill_deterministic(&mut self, seed: u64) {
+        let mut stream = ByteStream::new(seed);
+        self.for_each_fast_mut(|v| stream.fill(bytemuck::bytes_of_mut(v)));
+    }
+}
+
+impl<'a, Attr: FloatLanes> SliceMut<'a, Attr> {
+    /// Similar to [`Self::fill_deterministic`], but with finite lanes in `[-1, 1]`.
+    ///
+    /// Each lane consumes a splitmix64 output, whose 53 most significant bits
+    /// are mapped to `[-1, 1)`, before being converted to the lane type.
+    pub fn fill_deterministic_finite(&mut self, seed: u64) {
+        let mut rng = SplitMix64(seed);
+        self.for_each_fast_mut(|v| {
+            for lane in 0..Attr::LANES {
+                let unit = (rng.next() >> 11) as f64 / (1_u64 << 53) as f64;
+                v.set_lane(lane, Attr::Scalar::from_f64(unit * 2.0 - 1.0));
+            }
+        });
+    }
+}
@@ -0,0 +1,164 @@
+use bytemuck::Pod;
+use std::ops::{Deref, DerefMut, Range};
+
+use crate::{SliceInfo, SliceMut};
+
+/// Sorted set of non-overlapping, non-adjacent element ranges.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct RangeSet {
+    ranges: Vec<Range<usize>>,
+}
+
+impl RangeSet {
+    /// Insert `range`, merging it with overlapping or adjacent ranges.
+    pub(crate) fn insert(&mut self, range: Range<usize>) {
+        if range.is_empty() {
+            return;
+        }
+        // First range that could be merged, i.e., ending at or after the new start.
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        // One past the last range that could be merged, i.e., starting at or before the new end.
+        let last = first + self.ranges[first..].partition_point(|r| r.start <= range.end);
+        if first == last {
+            self.ranges.insert(first, range);
+        } else {
+            let start = range.start.min(self.ranges[first].start);
+            let end = range.end.max(self.ranges[last - 1].end);
+            self.ranges.splice(first..last, std::iter::once(start..end));
+        }
+    }
+
+    pub(crate) fn as_slice(&self) -> &[Range<usize>] {
+        &self.ranges
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.ranges.clear();
+    }
+}
+
+/// Mutable slice recording which elements are written.
+///
+/// Written elements are stored as coalesced ranges, which stays cheap
+/// for large slices, e.g., to only upload dirty ranges to the GPU.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{SliceMut, TrackedSliceMut};
+///
+/// let mut data = [0_u32; 8];
+/// let mut slice = TrackedSliceMut::new(SliceMut::native(&mut data));
+/// *slice.get_mut(1).unwrap() = 1;
+/// *slice.get_mut(2).unwrap() = 2;
+/// *slice.get_mut(6).unwrap() = 6;
+/// assert_eq!(slice.dirty_ranges(), [1..3, 6..7]);
+/// ```
+pub struct TrackedSliceMut<'a, Attr: Pod> {
+    inner: SliceMut<'a, Attr>,
+    dirty: RangeSet,
+}
+
+impl<'a, Attr: Pod> TrackedSliceMut<'a, Attr> {
+    /// Start tracking writes into `slice`.
+    pub fn new(slice: SliceMut<'a, Attr>) -> Self {
+        Self {
+            inner: slice,
+            dirty: RangeSet::default(),
+        }
+    }
+
+    /// Get a guard to the element at index `index`.
+    ///
+    /// The element is marked as dirty when the guard is dropped, only
+    /// if it was mutably dereferenced.
+    pub fn get_mut(&mut self, index: usize) -> Option<DirtyGuard<'_, Attr>> {
+        let value = self.inner.get_mut(index)?;
+        Some(DirtyGuard {
+            value,
+            index,
+            written: false,
+            dirty: &mut self.dirty,
+        })
+    }
+
+    /// Mark the elements in `range` as dirty.
+    ///
+    /// The range is clamped to the slice length.
+    pub fn mark_dirty(&mut self, range: Range<usize>) {
+        let len = self.inner.len();
+        self.dirty.insert(range.start.min(len)..range.end.min(len));
+    }
+
+    /// Sorted, non-overlapping, and non-adjacent ranges of written elements.
+    pub fn dirty_ranges(&self) -> &[Range<usize>] {
+        self.dirty.as_slice()
+    }
+
+    /// Reset the tracking state.
+    pub fn clear_dirty(&mut self) {
+        self.dirty.clear();
+    }
+
+    /// Tracked version of [`SliceMut::copy_from_slice`].
+    ///
+    /// Marks the range `[0, src.len())` as dirty.
+    pub fn copy_from_slice<V: Pod>(&mut self, src: &[V]) {
+        self.inner.copy_from_slice(src);
+        self.mark_dirty(0..src.len());
+    }
+
+    /// Tracked version of [`SliceMut::fill`].
+    ///
+    /// Marks the entire slice as dirty.
+    pub fn fill(&mut self, value: Attr) {
+        self.inner.fill(value);
+        self.mark_dirty(0..self.inner.len());
+    }
+
+    /// Stop tracking, and return the underlying slice.
+    pub fn into_inner(self) -> SliceMut<'a, Attr> {
+        self.inner
+    }
+}
+
+impl<'a, Attr: Pod> Deref for TrackedSliceMut<'a, Attr> {
+    type Target = SliceInfo<Attr>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+/// Guard returned by [`TrackedSliceMut::get_mut`].
+///
+/// Marks the element as dirty on drop if it was mutably dereferenced.
+pub struct DirtyGuard<'t, Attr> {
+    value: &'t mut Attr,
+    index: usize,
+    written: bool,
+    dirty: &'t mut RangeSet,
+}
+
+impl<'t, Attr> Deref for DirtyGuard<'t, Attr> {
+    type Target = Attr;
+
+    fn deref(&self) -> &Attr {
+        self.value
+    }
+}
+
+impl<'t, Attr> DerefMut for DirtyGuard<'t, Attr> {
+    fn deref_mut(&mut self) -> &mut Attr {
+        self.written = true;
+        self.value
+    }
+}
+
+impl<'t, Attr> Drop for DirtyGuard<'t, Attr> {
+    fn drop(&mut self) {
+        if self.written {
+            self.dirty.insert(self.index..self.index + 1);
+        }
+    }
+}
@@ -0,0 +1,379 @@
+use bytemuck::Pod;
+use std::any::TypeId;
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+
+use crate::{private, SliceError, SliceInfo, SliceMut};
+
+/// Transaction error
+///
+/// An error is raised by [`Transaction::commit`]. Nothing is written in this case.
+#[derive(Clone, PartialEq)]
+pub enum TransactionError {
+    /// The number of views doesn't match the number of lanes.
+    LaneCountMismatch {
+        /// Number of lanes of the transaction
+        expected: usize,
+        /// Number of views
+        found: usize,
+    },
+    /// The view of a lane doesn't have the element type of the lane.
+    TypeMismatch {
+        /// Index of the lane
+        lane: usize,
+        /// Type name of the lane elements
+        expected: &'static str,
+        /// Type name of the view elements
+        found: &'static str,
+    },
+    /// The view of a lane doesn't have the length the lane was created with.
+    LengthChanged {
+        /// Index of the lane
+        lane: usize,
+        /// Length of the lane
+        expected: usize,
+        /// Length of the view
+        len: usize,
+    },
+    /// Staged elements of a lane were rejected by a validation hook.
+    ValidationFailed {
+        /// Index of the first failing lane
+        lane: usize,
+        /// Rejected element indices, in increasing order
+        indices: Vec<usize>,
+    },
+}
+
+impl std::fmt::Debug for TransactionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LaneCountMismatch { expected, found } => write!(
+                f,
+                "Transaction has {} lanes, but {} views were provided",
+                expected, found
+            ),
+            Self::TypeMismatch {
+                lane,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Lane {} has elements of type '{}', but the view has elements of type '{}'",
+                lane, expected, found
+            ),
+            Self::LengthChanged {
+                lane,
+                expected,
+                len,
+            } => write!(
+                f,
+                "Lane {} has {} elements, but the view now has {} elements",
+                lane, expected, len
+            ),
+            Self::ValidationFailed { lane, indices } => write!(
+                f,
+                "Lane {} failed validation at indices {:?}",
+                lane, indices
+            ),
+        }
+    }
+}
+
+/// Mutable view receiving the staged writes of a lane, see [`Transaction::commit`].
+///
+/// This trait is sealed and implemented for [`SliceMut`] only.
+pub trait TransactionView: private::Sealed {
+    #[doc(hidden)]
+    fn view_len(&self) -> usize;
+    #[doc(hidden)]
+    fn element_type(&self) -> (TypeId, &'static str);
+    /// Write `bytes`, holding exactly one element, at index `index`.
+    #[doc(hidden)]
+    fn write_element(&mut self, index: usize, bytes: &[u8]);
+}
+
+impl<'a, T: Pod> private::Sealed for SliceMut<'a, T> {}
+
+impl<'a, T: Pod> TransactionView for SliceMut<'a, T> {
+    fn view_len(&self) -> usize {
+        SliceInfo::len(self)
+    }
+
+    fn element_type(&self) -> (TypeId, &'static str) {
+        (TypeId::of::<T>(), std::any::type_name::<T>())
+    }
+
+    fn write_element(&mut self, index: usize, bytes: &[u8]) {
+        self[index] = bytemuck::pod_read_unaligned(bytes);
+    }
+}
+
+/// Handle to a lane of a [`Transaction`], holding elements of type `T`.
+pub struct Lane<T> {
+    index: usize,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> Lane<T> {
+    /// Index of the lane, in creation order.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl<T> Clone for Lane<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Lane<T> {}
+
+impl<T> std::fmt::Debug for Lane<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Lane").field(&self.index).finish()
+    }
+}
+
+/// Hook checking a staged element, from its index and unaligned bytes.
+type Validator<'a> = Box<dyn FnMut(usize, &[u8]) -> bool + 'a>;
+
+/// Staged writes and validation hooks of a lane.
+struct StagedLane<'a> {
+    len: usize,
+    size: usize,
+    type_id: TypeId,
+    type_name: &'static str,
+    /// Offset of each staged element in `bytes`, by element index
+    staged: BTreeMap<usize, usize>,
+    bytes: Vec<u8>,
+    validators: Vec<Validator<'a>>,
+}
+
+impl<'a> StagedLane<'a> {
+    fn element(&self, offset: usize) -> &[u8] {
+        &self.bytes[offset..offset + self.size]
+    }
+
+    /// Indices of the staged elements rejected by a hook.
+    fn validate(&mut self) -> Vec<usize> {
+        let mut rejected = Vec::new();
+        for (index, offset) in &self.staged {
+            let bytes = &self.bytes[*offset..*offset + self.size];
+            if !self.validators.iter_mut().all(|f| f(*index, bytes)) {
+                rejected.push(*index);
+            }
+        }
+        rejected
+    }
+}
+
+/// Batch of element writes over several lanes, applied all at once, or not at all.
+///
+/// Lanes are created from views with [`Self::lane`], which only records their
+/// length and element type: the views stay usable while writes are staged.
+/// Writes are staged per element with [`Self::set`], and checked by the hooks
+/// of [`Self::validate`]. [`Self::commit`] then writes every staged element
+/// into the views, if every check passes.
+///
+/// Lane handles are only meaningful for the transaction that created them.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{SliceMut, Transaction, TransactionError};
+///
+/// let mut positions = [[0.0_f32; 3]; 4];
+/// let mut normals = [[0.0_f32, 0.0, 1.0]; 4];
+///
+/// let mut transaction = Transaction::new();
+/// let position = transaction.lane(&SliceMut::native(&mut positions));
+/// let normal = transaction.lane(&SliceMut::native(&mut normals));
+/// transaction.validate(normal, |_, n: &[f32; 3]| {
+///     (n[0] * n[0] + n[1] * n[1] + n[2] * n[2] - 1.0).abs() < 1e-4
+/// });
+///
+/// // Move a vertex, with an invalid normal.
+/// transaction.set(position, 2, [1.0, 2.0, 3.0]).unwrap();
+/// transaction.set(normal, 2, [0.0, 2.0, 0.0]).unwrap();
+/// let result = transaction.commit(&mut [
+///     &mut SliceMut::native(&mut positions),
+///     &mut SliceMut::native(&mut normals),
+/// ]);
+/// assert_eq!(result, Err(TransactionError::ValidationFailed { lane: 1, indices: vec![2] }));
+/// assert_eq!(positions[2], [0.0; 3]);
+/// ```
+#[derive(Default)]
+pub struct Transaction<'a> {
+    lanes: Vec<StagedLane<'a>>,
+}
+
+impl<'a> Transaction<'a> {
+    /// Create a transaction without any lane.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a lane for `view`, recording its length and element type.
+    ///
+    /// The view passed to [`Self::commit`] for this lane must have the same length,
+    /// and the same element type.
+    pub fn lane<T: Pod>(&mut self, view: &SliceInfo<T>) -> Lane<T> {
+        self.lanes.push(StagedLane {
+            len: view.len(),
+            size: std::mem::size_of::<T>(),
+            type_id: TypeId::of::<T>(),
+            type_name: std::any::type_name::<T>(),
+            staged: BTreeMap::new(),
+            bytes: Vec::new(),
+            validators: Vec::new(),
+        });
+        Lane {
+            index: self.lanes.len() - 1,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Number of lanes.
+    pub fn lane_count(&self) -> usize {
+        self.lanes.len()
+    }
+
+    /// Number of staged elements, over every lane.
+    pub fn staged_count(&self) -> usize {
+        self.lanes.iter().map(|lane| lane.staged.len()).sum()
+    }
+
+    /// Stage `value` for the element at index `index` of `lane`.
+    ///
+    /// Staging an element again replaces the previous value.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::IndexOutOfBounds`] if `index` is out of bounds.
+    pub fn set<T: Pod>(&mut self, lane: Lane<T>, index: usize, value: T) -> Result<(), SliceError> {
+        self.set_bytes(lane, index, bytemuck::bytes_of(&value))
+    }
+
+    /// Similar to [`Self::set`], but with the bytes of the element.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::IndexOutOfBounds`] if `index` is out of bounds, and
+    /// [`SliceError::LengthMismatch`] if `bytes` doesn't have the size of `T`.
+    pub fn set_bytes<T: Pod>(
+        &mut self,
+        lane: Lane<T>,
+        index: usize,
+        bytes: &[u8],
+    ) -> Result<(), SliceError> {
+        let lane = &mut self.lanes[lane.index];
+        if index >= lane.len {
+            return Err(SliceError::IndexOutOfBounds {
+                position: 0,
+                index: index as u64,
+                len: lane.len,
+            });
+        }
+        if bytes.len() != lane.size {
+            return Err(SliceError::LengthMismatch {
+                expected: lane.size,
+                len: bytes.len(),
+            });
+        }
+        match lane.staged.get(&index) {
+            Some(offset) => {
+                let offset = *offset;
+                lane.bytes[offset..offset + bytes.len()].copy_from_slice(bytes);
+            }
+            None => {
+                lane.staged.insert(index, lane.bytes.len());
+                lane.bytes.extend_from_slice(bytes);
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the value staged for the element at index `index` of `lane`, if any.
+    pub fn staged<T: Pod>(&self, lane: Lane<T>, index: usize) -> Option<T> {
+        let lane = &self.lanes[lane.index];
+        let offset = lane.staged.get(&index)?;
+        Some(bytemuck::pod_read_unaligned(lane.element(*offset)))
+    }
+
+    /// Add a hook checking each staged element of `lane` on commit, from its
+    /// index and value.
+    ///
+    /// An element is rejected if any hook of its lane returns `false`.
+    pub fn validate<T: Pod>(&mut self, lane: Lane<T>, mut f: impl FnMut(usize, &T) -> bool + 'a) {
+        self.lanes[lane.index]
+            .validators
+            .push(Box::new(move |index, bytes| {
+                f(index, &bytemuck::pod_read_unaligned(bytes))
+            }));
+    }
+
+    /// Validate every staged element, and write them into `views`, holding the
+    /// view of each lane in creation order.
+    ///
+    /// ## Errors
+    ///
+    /// Nothing is written if an error is returned:
+    /// * [`TransactionError::LaneCountMismatch`] if `views` doesn't have one view per lane
+    /// * [`TransactionError::TypeMismatch`] if a view doesn't have the element type of its lane
+    /// * [`TransactionError::LengthChanged`] if a view doesn't have the length of its lane
+    /// * [`TransactionError::ValidationFailed`] with the rejected elements of the first
+    ///   lane failing validation
+    pub fn commit(
+        mut self,
+        views: &mut [&mut dyn TransactionView],
+    ) -> Result<(), TransactionError> {
+        if views.len() != self.lanes.len() {
+            return Err(TransactionError::LaneCountMismatch {
+                expected: self.lanes.len(),
+                found: views.len(),
+            });
+        }
+        for (index, (lane, view)) in self.lanes.iter().zip(views.iter()).enumerate() {
+            let (type_id, type_name) = view.element_type();
+            if type_id != lane.type_id {
+                return Err(TransactionError::TypeMismatch {
+                    lane: index,
+                    expected: lane.type_name,
+                    found: type_name,
+                });
+            }
+            if view.view_len() != lane.len {
+                return Err(TransactionError::LengthChanged {
+                    lane: index,
+                    expected: lane.len,
+                    len: view.view_len(),
+                });
+            }
+        }
+        for (index, lane) in self.lanes.iter_mut().enumerate() {
+            let indices = lane.validate();
+            if !indices.is_empty() {
+                return Err(TransactionError::ValidationFailed {
+                    lane: index,
+                    indices,
+                });
+            }
+        }
+        for (lane, view) in self.lanes.iter().zip(views.iter_mut()) {
+            for (index, offset) in &lane.staged {
+                view.write_element(*index, lane.element(*offset));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> std::fmt::Debug for Transaction<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Transaction")
+            .field("lanes", &self.lanes.len())
+            .field("staged", &self.staged_count())
+            .finish()
+    }
+}
@@ -0,0 +1,56 @@
+use bytemuck::Pod;
+
+use crate::SliceMut;
+
+macro_rules! impl_write_unzipped {
+    ($(#[$meta: meta])* $name: ident, $(($t: ident, $lane: ident, $value: ident)),+) => {
+        $(#[$meta])*
+        pub fn $name<$($t: Pod),+>(
+            values: impl IntoIterator<Item = ($($t,)+)>,
+            $($lane: &mut SliceMut<'_, $t>),+
+        ) -> usize {
+            let len = [$($lane.len()),+].into_iter().min().unwrap();
+            let mut count = 0;
+            for ($($value,)+) in values.into_iter().take(len) {
+                $(
+                    // Safe because `count < len`, and each lane is mutably borrowed.
+                    unsafe {
+                        let ptr = $lane.base().start.add(count * $lane.stride());
+                        ptr.cast::<$t>().cast_mut().write($value);
+                    }
+                )+
+                count += 1;
+            }
+            count
+        }
+    };
+}
+
+impl_write_unzipped!(
+    /// Write each pair of `values` into `a` and `b`, at the same index.
+    ///
+    /// The iterator is driven once, and stops at the end of the shortest lane.
+    /// Values past it are not consumed. Returns the number of elements written.
+    ///
+    /// See [`crate::write_unzipped!`].
+    write_unzipped2,
+    (A, a, value_a),
+    (B, b, value_b)
+);
+
+impl_write_unzipped!(
+    /// Similar to [`write_unzipped2`], for three lanes.
+    write_unzipped3,
+    (A, a, value_a),
+    (B, b, value_b),
+    (C, c, value_c)
+);
+
+impl_write_unzipped!(
+    /// Similar to [`write_unzipped2`], for four lanes.
+    write_unzipped4,
+    (A, a, value_a),
+    (B, b, value_b),
+    (C, c, value_c),
+    (D, d, value_d)
+);
@@ -0,0 +1,220 @@
+use bytemuck::Pod;
+use std::fmt::Debug;
+use std::ops::{Deref, DerefMut};
+
+use crate::owned::{view_desc, view_desc_mut, VIEW_ALIGN};
+use crate::{AlignedBytes, Slice, SliceError, SliceMut, ViewDesc};
+
+/// Owned byte buffer, with a generation counter bumped when its content changes.
+///
+/// Unlike the address of the buffer, the generation survives reallocations, and
+/// catches writes into the buffer, e.g., to invalidate a cached GPU upload.
+///
+/// The buffer has a single counter, shared by every attribute it contains. The
+/// generation is bumped:
+/// * Once per [`VersionedSliceMut`] mutably accessed, when it's dropped, or when
+///   [`VersionedSliceMut::commit`] is called, whichever comes first. Writing again
+///   after a commit bumps the generation again.
+/// * On each call to [`Self::bytes_mut`] and [`Self::resize`].
+///
+/// Reads never bump the generation.
+///
+/// The buffer is an [`AlignedBytes`], aligned to 16 bytes.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::{VersionedBuffer, ViewDesc};
+///
+/// let desc = ViewDesc::new(0, 4, 3);
+/// let mut buffer = VersionedBuffer::zeroed(12);
+/// let uploaded = buffer.generation();
+///
+/// buffer.view_mut::<f32>(desc).unwrap()[1] = 1.0;
+/// assert!(buffer.changed_since(uploaded));
+///
+/// let uploaded = buffer.generation();
+/// assert_eq!(buffer.view::<f32>(desc).unwrap()[1], 1.0);
+/// assert!(!buffer.changed_since(uploaded));
+/// ```
+#[derive(Clone)]
+pub struct VersionedBuffer {
+    bytes: AlignedBytes,
+    generation: u64,
+}
+
+impl VersionedBuffer {
+    /// Copy `bytes` into an aligned buffer, at generation `0`.
+    pub fn new(bytes: &[u8]) -> Self {
+        Self {
+            bytes: AlignedBytes::from_slice_aligned(bytes, VIEW_ALIGN),
+            generation: 0,
+        }
+    }
+
+    /// Create an aligned buffer of `len` zeroed bytes, at generation `0`.
+    pub fn zeroed(len: usize) -> Self {
+        Self {
+            bytes: AlignedBytes::zeroed(len, VIEW_ALIGN),
+            generation: 0,
+        }
+    }
+
+    /// Current generation.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// `true` if the content may have changed since generation `generation`,
+    /// `false` otherwise
+    pub fn changed_since(&self, generation: u64) -> bool {
+        self.generation > generation
+    }
+
+    /// Number of bytes.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// `true` if the buffer has no byte, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Owned bytes.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Mutable version of [`Self::bytes()`].
+    ///
+    /// Bumps the generation.
+    pub fn bytes_mut(&mut self) -> &mut [u8] {
+        self.generation += 1;
+        &mut self.bytes
+    }
+
+    /// Resize the buffer to `len` bytes, filling new bytes with `0`.
+    ///
+    /// Bumps the generation, even if the length doesn't change.
+    pub fn resize(&mut self, len: usize) {
+        self.bytes.resize(len, 0);
+        self.generation += 1;
+    }
+
+    /// Create a slice over the elements described by `desc`, recording the
+    /// current generation.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::OffsetOutOfBounds`] if `desc` spans past the end of
+    /// the buffer, [`SliceError::Overflow`] if its end overflows, and the layout
+    /// errors of [`Slice::new`], e.g., [`SliceError::AlignmentFault`].
+    pub fn view<T: Pod>(&self, desc: ViewDesc) -> Result<VersionedSlice<'_, T>, SliceError> {
+        Ok(VersionedSlice {
+            slice: view_desc(&self.bytes, desc)?,
+            generation: self.generation,
+        })
+    }
+
+    /// Mutable version of [`Self::view()`].
+    ///
+    /// The generation is bumped once the returned slice is dropped or committed,
+    /// if it was mutably accessed.
+    pub fn view_mut<T: Pod>(
+        &mut self,
+        desc: ViewDesc,
+    ) -> Result<VersionedSliceMut<'_, T>, SliceError> {
+        Ok(VersionedSliceMut {
+            slice: view_desc_mut(&mut self.bytes, desc)?,
+            generation: &mut self.generation,
+            written: false,
+        })
+    }
+}
+
+/// Slice returned by [`VersionedBuffer::view`].
+///
+/// Dereferences to the underlying [`Slice`].
+#[derive(Clone, Copy)]
+pub struct VersionedSlice<'a, T: Pod> {
+    slice: Slice<'a, T>,
+    generation: u64,
+}
+
+impl<'a, T: Pod> VersionedSlice<'a, T> {
+    /// Generation of the buffer when this slice was created.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Return the underlying slice.
+    pub fn into_inner(self) -> Slice<'a, T> {
+        self.slice
+    }
+}
+
+impl<'a, T: Pod + Debug> Debug for VersionedSlice<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.slice.fmt(f)
+    }
+}
+
+impl<'a, T: Pod> Deref for VersionedSlice<'a, T> {
+    type Target = Slice<'a, T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.slice
+    }
+}
+
+/// Mutable slice returned by [`VersionedBuffer::view_mut`].
+///
+/// Dereferences to the underlying [`SliceMut`]. Mutably dereferencing it marks
+/// the slice as written, and the generation of the buffer is bumped on drop
+/// or on [`Self::commit`].
+pub struct VersionedSliceMut<'a, T: Pod> {
+    slice: SliceMut<'a, T>,
+    generation: &'a mut u64,
+    written: bool,
+}
+
+impl<'a, T: Pod> VersionedSliceMut<'a, T> {
+    /// Bump the generation of the buffer if the slice was written since it was
+    /// created, or since the last commit.
+    ///
+    /// Returns the generation of the buffer.
+    pub fn commit(&mut self) -> u64 {
+        if std::mem::take(&mut self.written) {
+            *self.generation += 1;
+        }
+        *self.generation
+    }
+}
+
+impl<'a, T: Pod + Debug> Debug for VersionedSliceMut<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.slice.fmt(f)
+    }
+}
+
+impl<'a, T: Pod> Deref for VersionedSliceMut<'a, T> {
+    type Target = SliceMut<'a, T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.slice
+    }
+}
+
+impl<'a, T: Pod> DerefMut for VersionedSliceMut<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.written = true;
+        &mut self.slice
+    }
+}
+
+impl<'a, T: Pod> Drop for VersionedSliceMut<'a, T> {
+    fn drop(&mut self) {
+        self.commit();
+    }
+}
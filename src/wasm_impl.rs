@@ -0,0 +1,196 @@
+use bytemuck::{Pod, Zeroable};
+use js_sys::{Float32Array, Uint16Array, Uint32Array, Uint8Array};
+
+use crate::{private, SliceInfo, SliceMut};
+
+/// Number of scalars buffered on the stack when copying strided slices.
+const BATCH: usize = 256;
+
+/// Attribute made of one or multiple lanes, copied to and from JavaScript
+/// typed arrays with lanes flattened.
+///
+/// Implemented for `f32`, `u32`, `u16`, `u8`, and arrays of those.
+///
+/// This trait is sealed and can't be implemented outside of this crate.
+pub trait TypedArrayElement: Pod + private::Sealed {
+    /// Scalar type of each lane.
+    type Scalar: Pod;
+    /// Number of lanes.
+    const LANES: usize;
+}
+
+/// JavaScript typed array holding `Scalar` items.
+trait TypedArray: Sized {
+    type Scalar: Pod;
+
+    fn len(&self) -> usize;
+    fn sub(&self, begin: usize, end: usize) -> Self;
+    fn write(&self, src: &[Self::Scalar]);
+    fn read(&self, dst: &mut [Self::Scalar]);
+}
+
+macro_rules! impl_typed_array {
+    ($($scalar: ty => $array: ty),*) => {
+        $(
+            impl TypedArrayElement for $scalar {
+                type Scalar = $scalar;
+                const LANES: usize = 1;
+            }
+            impl<const N: usize> TypedArrayElement for [$scalar; N] {
+                type Scalar = $scalar;
+                const LANES: usize = N;
+            }
+
+            impl TypedArray for $array {
+                type Scalar = $scalar;
+
+                fn len(&self) -> usize {
+                    self.length() as usize
+                }
+                fn sub(&self, begin: usize, end: usize) -> Self {
+                    self.subarray(begin as u32, end as u32)
+                }
+                fn write(&self, src: &[$scalar]) {
+                    self.copy_from(src)
+                }
+                fn read(&self, dst: &mut [$scalar]) {
+                    self.copy_to(dst)
+                }
+            }
+        )*
+    };
+}
+
+impl_typed_array!(
+    f32 => Float32Array,
+    u32 => Uint32Array,
+    u16 => Uint16Array,
+    u8 => Uint8Array
+);
+
+/// Number of elements copied between a slice of `len` elements, and an array of `array_len` scalars.
+fn copy_count<T: TypedArrayElement>(len: usize, array_len: usize) -> usize {
+    match T::LANES {
+        0 => 0,
+        lanes => len.min(array_len / lanes),
+    }
+}
+
+/// `true` if the first `count` elements are packed, and can be copied at once.
+fn is_packed<T: TypedArrayElement>(slice: &SliceInfo<T>, count: usize) -> bool {
+    count <= 1 || slice.stride() == std::mem::size_of::<T>()
+}
+
+fn copy_to<T, A>(src: &SliceInfo<T>, dst: &A) -> usize
+where
+    T: TypedArrayElement,
+    A: TypedArray<Scalar = T::Scalar>,
+{
+    let count = copy_count::<T>(src.len(), dst.len());
+    if count == 0 {
+        return 0;
+    }
+    if is_packed(src, count) {
+        // Safe because the `count` first elements are in bounds, and packed.
+        let scalars = unsafe {
+            std::slice::from_raw_parts(src.base().start.cast::<T::Scalar>(), count * T::LANES)
+        };
+        dst.sub(0, scalars.len()).write(scalars);
+        return count;
+    }
+    let batch = BATCH / T::LANES;
+    if batch == 0 {
+        // Elements are larger than the buffer, but each one is contiguous.
+        for (i, element) in src.elements().take(count).enumerate() {
+            let lanes: &[T::Scalar] = bytemuck::cast_slice(std::slice::from_ref(element));
+            dst.sub(i * T::LANES, (i + 1) * T::LANES).write(lanes);
+        }
+        return count;
+    }
+    let mut buffer = [T::Scalar::zeroed(); BATCH];
+    let mut values = src.elements();
+    for start in (0..count).step_by(batch) {
+        let end = (start + batch).min(count);
+        let scalars = &mut buffer[..(end - start) * T::LANES];
+        let elements: &mut [T] = bytemuck::cast_slice_mut(scalars);
+        for (element, value) in elements.iter_mut().zip(&mut values) {
+            *element = *value;
+        }
+        dst.sub(start * T::LANES, end * T::LANES).write(scalars);
+    }
+    count
+}
+
+fn copy_from<T, A>(dst: &mut SliceMut<'_, T>, src: &A) -> usize
+where
+    T: TypedArrayElement,
+    A: TypedArray<Scalar = T::Scalar>,
+{
+    let count = copy_count::<T>(dst.len(), src.len());
+    if count == 0 {
+        return 0;
+    }
+    if is_packed(dst, count) {
+        // Safe because the `count` first elements are in bounds, packed,
+        // and mutably borrowed.
+        let scalars = unsafe {
+            std::slice::from_raw_parts_mut(dst.base().start as *mut T::Scalar, count * T::LANES)
+        };
+        src.sub(0, scalars.len()).read(scalars);
+        return count;
+    }
+    let batch = BATCH / T::LANES;
+    if batch == 0 {
+        for i in 0..count {
+            let lanes: &mut [T::Scalar] =
+                bytemuck::cast_slice_mut(std::slice::from_mut(&mut dst[i]));
+            src.sub(i * T::LANES, (i + 1) * T::LANES).read(lanes);
+        }
+        return count;
+    }
+    let mut buffer = [T::Scalar::zeroed(); BATCH];
+    for start in (0..count).step_by(batch) {
+        let end = (start + batch).min(count);
+        let scalars = &mut buffer[..(end - start) * T::LANES];
+        src.sub(start * T::LANES, end * T::LANES).read(scalars);
+        let elements: &[T] = bytemuck::cast_slice(scalars);
+        dst.split_at_mut(start)
+            .1
+            .write_elements(elements.iter().copied());
+    }
+    count
+}
+
+macro_rules! impl_typed_array_copy {
+    ($($scalar: ty => $array: ty, $to: ident, $from: ident);*) => {
+        $(
+            impl<T: TypedArrayElement<Scalar = $scalar>> SliceInfo<T> {
+                #[doc = concat!("Copy the slice into `dst`, a JavaScript [`", stringify!($array), "`], with lanes flattened.")]
+                ///
+                /// Packed slices are copied at once, strided ones in batches.
+                /// Only the common prefix of the slice and `dst` is copied, and
+                /// the number of copied elements is returned.
+                pub fn $to(&self, dst: &$array) -> usize {
+                    copy_to(self, dst)
+                }
+            }
+
+            impl<'a, T: TypedArrayElement<Scalar = $scalar>> SliceMut<'a, T> {
+                #[doc = concat!("Copy `src`, a JavaScript [`", stringify!($array), "`] with flattened lanes, into the slice.")]
+                ///
+                /// Only the common prefix of the slice and `src` is copied, and
+                /// the number of copied elements is returned.
+                pub fn $from(&mut self, src: &$array) -> usize {
+                    copy_from(self, src)
+                }
+            }
+        )*
+    };
+}
+
+impl_typed_array_copy!(
+    f32 => Float32Array, copy_to_float32array, copy_from_float32array;
+    u32 => Uint32Array, copy_to_uint32array, copy_from_uint32array;
+    u16 => Uint16Array, copy_to_uint16array, copy_from_uint16array;
+    u8 => Uint8Array, copy_to_uint8array, copy_from_uint8array
+);
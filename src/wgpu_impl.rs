@@ -0,0 +1,63 @@
+use wgpu_types::VertexFormat;
+
+use crate::Format;
+
+impl Format {
+    /// Vertex format of the elements, for a [`wgpu_types::VertexAttribute`].
+    ///
+    /// Returns `None` for formats without vertex format, i.e., single-lane and
+    /// three-lane 8-bit and 16-bit formats.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Format;
+    ///
+    /// assert_eq!(Format::F32x3.to_wgpu(), Some(wgpu_types::VertexFormat::Float32x3));
+    /// assert_eq!(Format::U16x3.to_wgpu(), None);
+    /// ```
+    pub fn to_wgpu(self) -> Option<VertexFormat> {
+        use Format::*;
+        Some(match self {
+            U8x2 => VertexFormat::Uint8x2,
+            U8x4 => VertexFormat::Uint8x4,
+            I8x2 => VertexFormat::Sint8x2,
+            I8x4 => VertexFormat::Sint8x4,
+            Unorm8x2 => VertexFormat::Unorm8x2,
+            Unorm8x4 => VertexFormat::Unorm8x4,
+            Snorm8x2 => VertexFormat::Snorm8x2,
+            Snorm8x4 => VertexFormat::Snorm8x4,
+            U16x2 => VertexFormat::Uint16x2,
+            U16x4 => VertexFormat::Uint16x4,
+            I16x2 => VertexFormat::Sint16x2,
+            I16x4 => VertexFormat::Sint16x4,
+            Unorm16x2 => VertexFormat::Unorm16x2,
+            Unorm16x4 => VertexFormat::Unorm16x4,
+            Snorm16x2 => VertexFormat::Snorm16x2,
+            Snorm16x4 => VertexFormat::Snorm16x4,
+            U32 => VertexFormat::Uint32,
+            U32x2 => VertexFormat::Uint32x2,
+            U32x3 => VertexFormat::Uint32x3,
+            U32x4 => VertexFormat::Uint32x4,
+            I32 => VertexFormat::Sint32,
+            I32x2 => VertexFormat::Sint32x2,
+            I32x3 => VertexFormat::Sint32x3,
+            I32x4 => VertexFormat::Sint32x4,
+            F32 => VertexFormat::Float32,
+            F32x2 => VertexFormat::Float32x2,
+            F32x3 => VertexFormat::Float32x3,
+            F32x4 => VertexFormat::Float32x4,
+            _ => return None,
+        })
+    }
+
+    /// Format of a vertex format.
+    ///
+    /// Returns `None` for half and double precision floats.
+    pub fn from_wgpu(format: VertexFormat) -> Option<Format> {
+        Format::ALL
+            .iter()
+            .copied()
+            .find(|f| f.to_wgpu() == Some(format))
+    }
+}
@@ -0,0 +1,311 @@
+use bytemuck::Pod;
+
+use crate::shared_impl::SliceBase;
+use crate::slice_mut::mut_range;
+use crate::{Slice, SliceError, SliceMut, Strict};
+
+/// Type name reported in the errors of byte windows.
+const WINDOW_NAME: &str = "byte window";
+
+/// Layout of a window of `window` bytes, at byte `offset` of each element of `stride` bytes.
+#[derive(Clone, Copy, Debug)]
+struct WindowLayout {
+    offset: usize,
+    stride: usize,
+    window: usize,
+    count: usize,
+}
+
+impl WindowLayout {
+    fn new(bytes: usize, offset: usize, stride: usize, window: usize) -> Result<Self, SliceError> {
+        if bytes > isize::MAX as usize || stride > isize::MAX as usize {
+            return Err(SliceError::Overflow);
+        }
+        if window > stride {
+            return Err(SliceError::AttributeLargerThanStride {
+                type_name: WINDOW_NAME,
+                attr: window,
+                stride,
+            });
+        }
+        if offset > stride - window {
+            return Err(SliceError::AttributeCrossesElementBoundary {
+                type_name: WINDOW_NAME,
+                attr: window,
+                offset,
+                element: stride,
+            });
+        }
+        if offset > 0 && offset >= bytes {
+            return Err(SliceError::OffsetOutOfBounds {
+                size: bytes,
+                offset,
+            });
+        }
+        // Only windows that entirely fit in the data are counted.
+        let count = match bytes.checked_sub(offset + window) {
+            Some(remaining) if bytes > 0 => remaining / stride.max(1) + 1,
+            _ => 0,
+        };
+        Ok(Self {
+            offset,
+            stride,
+            window,
+            count,
+        })
+    }
+
+    /// Byte range of the window at index `index`.
+    fn range(&self, index: usize) -> Option<std::ops::Range<usize>> {
+        (index < self.count).then(|| {
+            let start = self.offset + index * self.stride;
+            start..start + self.window
+        })
+    }
+
+    /// Check that the windows can be read as `T`.
+    fn check_reinterpret<T: Pod>(&self) -> Result<(), SliceError> {
+        match std::mem::size_of::<T>() == self.window {
+            true => Ok(()),
+            false => Err(SliceError::ElementMismatch {
+                from: WINDOW_NAME,
+                to: std::any::type_name::<T>(),
+            }),
+        }
+    }
+}
+
+/// Immutable view over a window of bytes in each element, without element type.
+///
+/// Elements are `&[u8]` of exactly [`Self::window_len`] bytes. Use [`Self::reinterpret`]
+/// to read them as a type, once known.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::ByteWindowSlice;
+///
+/// // Bytes `4..8` of each 8-byte element.
+/// let data: Vec<u8> = (0..16).collect();
+/// let window = ByteWindowSlice::new(&data, 4, 8, 4).unwrap();
+/// assert_eq!(window.len(), 2);
+/// assert_eq!(window.get(1), Some(&[12_u8, 13, 14, 15][..]));
+/// assert_eq!(window.to_packed_vec(), [4, 5, 6, 7, 12, 13, 14, 15]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct ByteWindowSlice<'a> {
+    data: &'a [u8],
+    layout: WindowLayout,
+}
+
+impl<'a> ByteWindowSlice<'a> {
+    /// Create a view over the window of `window_len` bytes, at byte `byte_offset` of
+    /// each element of `byte_stride` bytes.
+    ///
+    /// Only windows that entirely fit in `data` are viewed, such that a last
+    /// truncated element is ignored.
+    ///
+    /// ## Errors
+    ///
+    /// - [`SliceError::AttributeLargerThanStride`] if the window is larger than the stride
+    /// - [`SliceError::AttributeCrossesElementBoundary`] if the window ends after the stride
+    /// - [`SliceError::OffsetOutOfBounds`] if the offset is out of `data`
+    /// - [`SliceError::Overflow`] if the stride exceeds `isize::MAX`
+    pub fn new(
+        data: &'a [u8],
+        byte_offset: usize,
+        byte_stride: usize,
+        window_len: usize,
+    ) -> Result<Self, SliceError> {
+        let layout = WindowLayout::new(data.len(), byte_offset, byte_stride, window_len)?;
+        Ok(Self { data, layout })
+    }
+
+    /// Number of windows.
+    pub fn len(&self) -> usize {
+        self.layout.count
+    }
+
+    /// `true` if there is no window, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Stride, in **bytes**.
+    pub fn stride(&self) -> usize {
+        self.layout.stride
+    }
+
+    /// Byte offset of the window in each element.
+    pub fn byte_offset(&self) -> usize {
+        self.layout.offset
+    }
+
+    /// Size of each window, in **bytes**.
+    pub fn window_len(&self) -> usize {
+        self.layout.window
+    }
+
+    /// Get the bytes of the window at index `index`.
+    pub fn get(&self, index: usize) -> Option<&'a [u8]> {
+        self.layout.range(index).map(|range| &self.data[range])
+    }
+
+    /// Create an iterator over the bytes of each window.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &'a [u8]> + 'a {
+        let layout = self.layout;
+        let data = self.data;
+        (0..layout.count).map(move |index| &data[layout.range(index).unwrap()])
+    }
+
+    /// Copy the bytes of each window, one after the other, into a new `Vec`.
+    pub fn to_packed_vec(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.len() * self.window_len());
+        self.iter().for_each(|window| out.extend_from_slice(window));
+        out
+    }
+
+    /// Read the windows as elements of type `T`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{ByteWindowSlice, Slice};
+    ///
+    /// let data: Vec<u32> = vec![0, 10, 1, 11];
+    /// let bytes: &[u8] = bytemuck::cast_slice(&data);
+    /// let window = ByteWindowSlice::new(bytes, 4, 8, 4).unwrap();
+    /// let ids: Slice<u32> = window.reinterpret().unwrap();
+    /// assert_eq!(ids[1], 11);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SliceError::ElementMismatch`] if `T` doesn't have the size of the
+    /// window, and the alignment errors of [`Slice::raw`] otherwise.
+    pub fn reinterpret<T: Pod>(&self) -> Result<Slice<'a, T>, SliceError> {
+        self.layout.check_reinterpret::<T>()?;
+        let inner = SliceBase::new_with_policy::<Strict>(
+            self.data.as_ptr_range(),
+            self.layout.offset,
+            self.layout.stride,
+            self.data.len(),
+            None,
+        )?;
+        Ok(Slice::from_base(inner))
+    }
+}
+
+impl<'a> std::fmt::Debug for ByteWindowSlice<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Mutable version of [`ByteWindowSlice`].
+///
+/// Only the bytes of the windows can be written, leaving the rest of each
+/// element untouched.
+///
+/// ## Example
+///
+/// ```rust
+/// use pas::ByteWindowSliceMut;
+///
+/// let mut data = [0_u8; 12];
+/// let mut window = ByteWindowSliceMut::new(&mut data, 1, 6, 2).unwrap();
+/// for bytes in window.iter_mut() {
+///     bytes.copy_from_slice(&[0xAB, 0xCD]);
+/// }
+/// assert_eq!(data, [0, 0xAB, 0xCD, 0, 0, 0, 0, 0xAB, 0xCD, 0, 0, 0]);
+/// ```
+pub struct ByteWindowSliceMut<'a> {
+    data: &'a mut [u8],
+    layout: WindowLayout,
+}
+
+impl<'a> ByteWindowSliceMut<'a> {
+    /// Mutable version of [`ByteWindowSlice::new`].
+    ///
+    /// ## Errors
+    ///
+    /// See [`ByteWindowSlice::new`].
+    pub fn new(
+        data: &'a mut [u8],
+        byte_offset: usize,
+        byte_stride: usize,
+        window_len: usize,
+    ) -> Result<Self, SliceError> {
+        let layout = WindowLayout::new(data.len(), byte_offset, byte_stride, window_len)?;
+        Ok(Self { data, layout })
+    }
+
+    /// Immutable view over the same windows.
+    pub fn as_window(&self) -> ByteWindowSlice<'_> {
+        ByteWindowSlice {
+            data: self.data,
+            layout: self.layout,
+        }
+    }
+
+    /// Number of windows.
+    pub fn len(&self) -> usize {
+        self.layout.count
+    }
+
+    /// `true` if there is no window, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the bytes of the window at index `index`.
+    pub fn get(&self, index: usize) -> Option<&[u8]> {
+        self.layout.range(index).map(|range| &self.data[range])
+    }
+
+    /// Get the bytes of the window at index `index`, mutably.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut [u8]> {
+        self.layout.range(index).map(|range| &mut self.data[range])
+    }
+
+    /// Create an iterator over the bytes of each window, mutably.
+    pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = &mut [u8]> + '_ {
+        let layout = self.layout;
+        let data = match layout.count {
+            0 => &mut [],
+            _ => &mut self.data[layout.offset..],
+        };
+        data.chunks_mut(layout.stride.max(1))
+            .take(layout.count)
+            .map(move |element| &mut element[..layout.window])
+    }
+
+    /// Copy the bytes of each window, one after the other, into a new `Vec`.
+    pub fn to_packed_vec(&self) -> Vec<u8> {
+        self.as_window().to_packed_vec()
+    }
+
+    /// Mutable version of [`ByteWindowSlice::reinterpret`].
+    ///
+    /// ## Errors
+    ///
+    /// See [`ByteWindowSlice::reinterpret`].
+    pub fn reinterpret<T: Pod>(self) -> Result<SliceMut<'a, T>, SliceError> {
+        self.layout.check_reinterpret::<T>()?;
+        let bytes = self.data.len();
+        let inner = SliceBase::new_with_policy::<Strict>(
+            mut_range(self.data),
+            self.layout.offset,
+            self.layout.stride,
+            bytes,
+            None,
+        )?;
+        Ok(SliceMut::from_base(inner))
+    }
+}
+
+impl<'a> std::fmt::Debug for ByteWindowSliceMut<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_window().fmt(f)
+    }
+}
@@ -0,0 +1,86 @@
+use bytemuck::Pod;
+
+use crate::{Slice, SliceInfo, SliceMut};
+
+impl<Attr: Pod> SliceInfo<Attr> {
+    /// Call `f` on each pair of elements of `self` and `other`, along with their index,
+    /// stopping at the first error.
+    ///
+    /// Only the common prefix of both slices is visited.
+    ///
+    /// ## Errors
+    ///
+    /// Returns the index of the failing pair, along with the error returned by `f`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::Slice;
+    ///
+    /// let positions = [[0.0_f32, 1.0], [2.0, f32::NAN], [4.0, 5.0]];
+    /// let weights = [1.0_f32, 0.5, 0.25];
+    /// let positions: Slice<[f32; 2]> = Slice::native(&positions);
+    /// let result = positions.try_for_each_zip(&Slice::native(&weights), |_, p, _| {
+    ///     match p.iter().all(|v| v.is_finite()) {
+    ///         true => Ok(()),
+    ///         false => Err("not finite"),
+    ///     }
+    /// });
+    /// assert_eq!(result, Err((1, "not finite")));
+    /// ```
+    pub fn try_for_each_zip<B: Pod, E>(
+        &self,
+        other: &SliceInfo<B>,
+        mut f: impl FnMut(usize, &Attr, &B) -> Result<(), E>,
+    ) -> Result<(), (usize, E)> {
+        self.elements()
+            .zip(other.elements())
+            .enumerate()
+            .try_for_each(|(index, (a, b))| f(index, a, b).map_err(|err| (index, err)))
+    }
+}
+
+impl<'a, Attr: Pod> SliceMut<'a, Attr> {
+    /// Mutable version of [`SliceInfo::try_for_each_zip()`].
+    ///
+    /// Elements after the failing one are left untouched.
+    ///
+    /// ## Errors
+    ///
+    /// Returns the index of the failing pair, along with the error returned by `f`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pas::{Slice, SliceMut};
+    ///
+    /// let mut positions = [0.0_f32, 1.0, 2.0];
+    /// let velocities = [1.0_f32, f32::NAN, 1.0];
+    /// let mut slice: SliceMut<f32> = SliceMut::native(&mut positions);
+    /// let result = slice.try_apply_with(&Slice::native(&velocities), |_, p, v| {
+    ///     *p += v;
+    ///     match p.is_nan() {
+    ///         true => Err("NaN"),
+    ///         false => Ok(()),
+    ///     }
+    /// });
+    /// assert_eq!(result, Err((1, "NaN")));
+    /// assert_eq!(positions[2], 2.0);
+    /// ```
+    pub fn try_apply_with<A: Pod, E>(
+        &mut self,
+        other: &Slice<A>,
+        mut f: impl FnMut(usize, &mut Attr, &A) -> Result<(), E>,
+    ) -> Result<(), (usize, E)> {
+        let (start, stride) = (self.base().start as *mut u8, self.stride());
+        other
+            .elements()
+            .take(self.len())
+            .enumerate()
+            .try_for_each(|(index, b)| {
+                // Safe because `index` is in bounds, and the slice is mutably borrowed.
+                let a = unsafe { &mut *start.add(index * stride).cast::<Attr>() };
+                f(index, a, b).map_err(|err| (index, err))
+            })
+    }
+}
@@ -0,0 +1,520 @@
+//! Aliasing matrix: every pair of views created from the same buffer, and views
+//! split from a single borrow.
+//!
+//! The matrix is rendered into `tests/aliasing.md`, whose code blocks run as doctests:
+//! pairs with a mutable view must be rejected by the borrow checker, and pairs of
+//! shared views must compile and run. Views borrowing the buffer like another one
+//! are only tested through it. Split views must be usable together, and
+//! reject views reaching another attribute. Run `PAS_BLESS=1 cargo test --test aliasing_tests`
+//! after changing the lists below.
+
+use pas::{Slice, SliceMut};
+
+/// Views created from the same `setup`.
+struct Group {
+    title: &'static str,
+    setup: &'static str,
+    /// `(name, type, expression)` of views reading the buffer
+    shared: &'static [(&'static str, &'static str, &'static str)],
+    /// `(name, type, expression)` of views writing the buffer
+    mutable: &'static [(&'static str, &'static str, &'static str)],
+    /// `true` if views move the slice created by `setup`
+    consume: bool,
+    /// `(view, representative)` of views borrowing the buffer like another one,
+    /// listed in the table but not tested
+    equivalent: &'static [(&'static str, &'static str)],
+}
+
+const GROUPS: &[Group] = &[
+    Group {
+        title: "Views over a buffer",
+        setup: "let mut data = [0_u32; 8];",
+        shared: &[
+            ("&[T]", "&[u32]", "data.as_slice()"),
+            ("Slice::new", "Slice<u32>", "Slice::new(&data, 0)"),
+            (
+                "Slice::try_new",
+                "Slice<u32>",
+                "Slice::try_new(&data, 0).unwrap()",
+            ),
+            (
+                "Slice::new_unchecked_layout",
+                "Slice<u32>",
+                "Slice::new_unchecked_layout(&data, 0)",
+            ),
+            ("Slice::strided", "Slice<u32>", "Slice::strided(&data, 0, 2)"),
+            (
+                "Slice::try_strided",
+                "Slice<u32>",
+                "Slice::try_strided(&data, 0, 2).unwrap()",
+            ),
+            (
+                "Slice::with_policy",
+                "Slice<u32>",
+                "Slice::with_policy::<Strict>(&data, 0, 1).unwrap()",
+            ),
+            (
+                "Slice::raw",
+                "Slice<u32>",
+                "Slice::raw(bytemuck::cast_slice(&data), 0, 4)",
+            ),
+            (
+                "Slice::try_raw",
+                "Slice<u32>",
+                "Slice::try_raw(bytemuck::cast_slice(&data), 0, 4).unwrap()",
+            ),
+            ("Slice::native", "Slice<u32>", "Slice::native(&data)"),
+            ("Slice::from", "Slice<u32>", "Slice::from(&data)"),
+            (
+                "SliceBuilder::build",
+                "Slice<u32>",
+                "SliceBuilder::new(&data[0], 1).build(&data)",
+            ),
+            (
+                "SliceBuilder::try_build",
+                "Slice<u32>",
+                "SliceBuilder::new(&data[0], 1).try_build(&data).unwrap()",
+            ),
+            ("slice!", "Slice<u32>", "slice!(data, [0])"),
+            ("slice_attr!", "Slice<u32>", "slice_attr!(data, [0])"),
+        ],
+        mutable: &[
+            ("&mut [T]", "&mut [u32]", "data.as_mut_slice()"),
+            ("SliceMut::new", "SliceMut<u32>", "SliceMut::new(&mut data, 0)"),
+            (
+                "SliceMut::try_new",
+                "SliceMut<u32>",
+                "SliceMut::try_new(&mut data, 0).unwrap()",
+            ),
+            (
+                "SliceMut::new_unchecked_layout",
+                "SliceMut<u32>",
+                "SliceMut::new_unchecked_layout(&mut data, 0)",
+            ),
+            (
+                "SliceMut::strided",
+                "SliceMut<u32>",
+                "SliceMut::strided(&mut data, 0, 2)",
+            ),
+            (
+                "SliceMut::try_strided",
+                "SliceMut<u32>",
+                "SliceMut::try_strided(&mut data, 0, 2).unwrap()",
+            ),
+            (
+                "SliceMut::with_policy",
+                "SliceMut<u32>",
+                "SliceMut::with_policy::<Strict>(&mut data, 0, 1).unwrap()",
+            ),
+            (
+                "SliceMut::raw",
+                "SliceMut<u32>",
+                "SliceMut::raw(bytemuck::cast_slice_mut(&mut data), 0, 4)",
+            ),
+            (
+                "SliceMut::try_raw",
+                "SliceMut<u32>",
+                "SliceMut::try_raw(bytemuck::cast_slice_mut(&mut data), 0, 4).unwrap()",
+            ),
+            ("SliceMut::native", "SliceMut<u32>", "SliceMut::native(&mut data)"),
+            ("SliceMut::from", "SliceMut<u32>", "SliceMut::from(&mut data[..])"),
+            (
+                "SliceBuilder::build_mut",
+                "SliceMut<u32>",
+                "SliceBuilder::new(&data[0], 1).build_mut(&mut data)",
+            ),
+            (
+                "SliceBuilder::try_build_mut",
+                "SliceMut<u32>",
+                "SliceBuilder::new(&data[0], 1).try_build_mut(&mut data).unwrap()",
+            ),
+            ("slice_mut!", "SliceMut<u32>", "slice_mut!(data, [0])"),
+            ("slice_attr_mut!", "SliceMut<u32>", "slice_attr_mut!(data, [0])"),
+            // Split views borrow the buffer mutably, even the read ones.
+            (
+                "split_attr_read_write",
+                "SliceMut<u16>",
+                "split_attr_read_write::<_, u16, u16>(&mut data, 0, 2).unwrap().1",
+            ),
+            (
+                "split_attrs_read_write",
+                "SliceMut<u16>",
+                "split_attrs_read_write::<_, u16, u16, 1>(&mut data, [0], 2).unwrap().1",
+            ),
+        ],
+        consume: false,
+        equivalent: &[
+            ("Slice::try_new", "Slice::new"),
+            ("Slice::new_unchecked_layout", "Slice::new"),
+            ("Slice::strided", "Slice::new"),
+            ("Slice::try_strided", "Slice::new"),
+            ("Slice::with_policy", "Slice::new"),
+            ("Slice::raw", "Slice::new"),
+            ("Slice::try_raw", "Slice::new"),
+            ("Slice::native", "Slice::new"),
+            ("Slice::from", "Slice::new"),
+            ("SliceBuilder::try_build", "SliceBuilder::build"),
+            ("SliceMut::try_new", "SliceMut::new"),
+            ("SliceMut::new_unchecked_layout", "SliceMut::new"),
+            ("SliceMut::strided", "SliceMut::new"),
+            ("SliceMut::try_strided", "SliceMut::new"),
+            ("SliceMut::with_policy", "SliceMut::new"),
+            ("SliceMut::raw", "SliceMut::new"),
+            ("SliceMut::try_raw", "SliceMut::new"),
+            ("SliceMut::native", "SliceMut::new"),
+            ("SliceMut::from", "SliceMut::new"),
+            ("SliceBuilder::try_build_mut", "SliceBuilder::build_mut"),
+            ("split_attrs_read_write", "split_attr_read_write"),
+        ],
+    },
+    Group {
+        title: "Views derived from a mutable slice",
+        setup: "let mut data = [0_u32; 8];\nlet mut slice: SliceMut<u32> = SliceMut::native(&mut data);",
+        shared: &[
+            ("SliceInfo", "&SliceInfo<u32>", "&*slice"),
+            ("SliceInfo::get", "&u32", "slice.get(0).unwrap()"),
+            ("Index", "&u32", "&slice[0]"),
+        ],
+        mutable: &[
+            ("SliceMut::iter", "SliceMutIterator<u32>", "slice.iter()"),
+            ("SliceMut::get_mut", "&mut u32", "slice.get_mut(0).unwrap()"),
+            ("IndexMut", "&mut u32", "&mut slice[0]"),
+            (
+                "SliceMut::split_at_mut",
+                "SliceMut<u32>",
+                "slice.split_at_mut(4).1",
+            ),
+        ],
+        consume: false,
+        equivalent: &[],
+    },
+    Group {
+        title: "Views consuming a mutable slice",
+        setup: "let mut data = [0_u32; 8];\nlet slice: SliceMut<u32> = SliceMut::native(&mut data);",
+        shared: &[],
+        mutable: &[
+            (
+                "SliceMut::rebase",
+                "SliceMut<u16>",
+                "slice.rebase(2).unwrap()",
+            ),
+            (
+                "SliceMut::bounded",
+                "SliceMut<u32>",
+                "slice.bounded(0..16).unwrap()",
+            ),
+            (
+                "SliceMut::transmute_elem",
+                "SliceMut<[u8; 4]>",
+                "slice.transmute_elem()",
+            ),
+            (
+                "SliceMut::into_array1",
+                "SliceMut<[u32; 1]>",
+                "slice.into_array1()",
+            ),
+            (
+                "TrackedSliceMut::new",
+                "TrackedSliceMut<u32>",
+                "TrackedSliceMut::new(slice)",
+            ),
+        ],
+        consume: true,
+        equivalent: &[
+            ("SliceMut::bounded", "SliceMut::rebase"),
+            ("SliceMut::transmute_elem", "SliceMut::rebase"),
+            ("SliceMut::into_array1", "SliceMut::rebase"),
+        ],
+    },
+    Group {
+        title: "Views over an `OwnedStrided`",
+        setup: "let mut owned = OwnedStrided::new(vec![0_u32; 8]);",
+        shared: &[
+            ("OwnedStrided::as_elements", "&[u32]", "owned.as_elements()"),
+            ("OwnedStrided::as_slice", "Slice<u32>", "owned.as_slice()"),
+        ],
+        mutable: &[(
+            "OwnedStrided::as_slice_mut",
+            "SliceMut<u32>",
+            "owned.as_slice_mut()",
+        )],
+        consume: false,
+        equivalent: &[],
+    },
+    Group {
+        title: "Views over an `OwnedView`",
+        setup: "let mut owned = OwnedView::<()>::new(&[0; 32]);\nowned.push(ViewDesc::new(0, 4, 8));",
+        shared: &[
+            ("OwnedView::bytes", "&[u8]", "owned.bytes()"),
+            ("OwnedView::view", "Slice<u32>", "owned.view(0).unwrap()"),
+        ],
+        mutable: &[(
+            "OwnedView::view_mut",
+            "SliceMut<u32>",
+            "owned.view_mut(0).unwrap()",
+        )],
+        consume: false,
+        equivalent: &[],
+    },
+];
+
+/// Views split from a single borrow, used together.
+struct Split {
+    title: &'static str,
+    /// Feature the views are gated behind
+    feature: Option<&'static str>,
+    code: &'static str,
+}
+
+const SPLITS: &[Split] = &[
+    Split {
+        title: "split_attr_read_write",
+        feature: None,
+        code: "\
+let mut data = [[1_u32, 0]; 4];
+let (read, mut write): (Slice<u32>, SliceMut<u32>) =
+    split_attr_read_write(&mut data, 0, 4).unwrap();
+for (index, value) in write.iter().enumerate() {
+    *value = read[index] + 1;
+}
+assert!(matches!(
+    write.rebase::<u32>(4),
+    Err(SliceError::AttributeCrossesElementBoundary { .. })
+));
+assert!(matches!(
+    split_attr_read_write::<_, u32, u16>(&mut data, 0, 2),
+    Err(SliceError::OverlappingAttributes { .. })
+));
+assert_eq!(data, [[1, 2]; 4]);",
+    },
+    Split {
+        title: "split_attrs_read_write",
+        feature: None,
+        code: "\
+let mut data = [[1_u32, 2, 0]; 4];
+let ([a, b], mut sum): ([Slice<u32>; 2], SliceMut<u32>) =
+    split_attrs_read_write(&mut data, [0, 4], 8).unwrap();
+for (index, value) in sum.iter().enumerate() {
+    *value = a[index] + b[index];
+}
+let bytes: SliceMut<[u8; 4]> = sum.rebase(0).unwrap();
+assert!(matches!(
+    bytes.rebase::<u32>(4),
+    Err(SliceError::AttributeCrossesElementBoundary { .. })
+));
+assert!(matches!(
+    split_attrs_read_write::<_, u32, u32, 2>(&mut data, [0, 8], 8),
+    Err(SliceError::OverlappingAttributes { .. })
+));
+assert_eq!(data, [[1, 2, 3]; 4]);",
+    },
+    Split {
+        title: "split_attr_read_send",
+        feature: None,
+        code: "\
+let mut data = [[1_u32, 0]; 4];
+split_attr_read_send(&mut data, 0, 4, |read: Slice<u32>, mut lane: SendableLane<u32>, _| {
+    let value = read[0] + 1;
+    std::thread::spawn(move || {
+        lane.with_view(|view| {
+            let bytes: SliceMut<[u8; 4]> = view.rebase(0).unwrap();
+            assert!(bytes.rebase::<u32>(4).is_err());
+        });
+        lane.with_view(|mut view| view.fill(value));
+    });
+})
+.unwrap();
+assert_eq!(data, [[1, 2]; 4]);",
+    },
+    Split {
+        title: "#[derive(Attributes)]",
+        feature: Some("derive"),
+        code: "\
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable, Attributes)]
+struct Vertex {
+    position: [u32; 3],
+    id: u32,
+}
+
+let mut vertices = [Vertex { position: [1, 2, 3], id: 0 }; 4];
+let mut views = VertexAttributesMut::of(&mut vertices);
+for (position, id) in views.position.iter().zip(views.id.iter()) {
+    *id = position[0];
+}
+assert!(matches!(
+    views.position.rebase::<u32>(12),
+    Err(SliceError::AttributeCrossesElementBoundary { .. })
+));
+assert!(vertices.iter().all(|v| v.id == 1));",
+    },
+];
+
+const HEADER: &str = "\
+# Aliasing matrix
+
+<!-- Generated by `tests/aliasing_tests.rs`, don't edit. -->
+
+Every pair of views created from the same buffer, in both orders. Both views are
+alive at the same time:
+* Pairs with a mutable view are rejected by the borrow checker, each case is a
+  `compile_fail` test checking the error code
+* Pairs of shared views compile and run
+
+Views borrowing the buffer like another view, e.g., `Slice::try_new` like
+`Slice::new`, are listed with their representative: only pairs of representatives
+are tested.
+
+Methods consuming a mutable slice, e.g., `rebase` or `transmute_elem`, move its
+borrow into the new view: the slice can't be used again.
+
+No pair relies on a runtime check: `Pod` types have no interior mutability, and
+mutable views borrow their data mutably for their whole lifetime. Views created
+under the `nalgebra`, `ndarray`, and `wasm` features borrow a slice the same way,
+and aren't listed.
+
+The only exception are views split from a single borrow, listed last: they are
+alive together, and rely on runtime checks. Their attributes must be disjoint,
+and each view only owns the bytes of its attribute, such that views derived
+from it, e.g., with `rebase`, are rejected if they reach another attribute.
+";
+
+fn render() -> String {
+    let mut out = String::from(HEADER);
+    for group in GROUPS {
+        let views: Vec<_> = group
+            .shared
+            .iter()
+            .map(|view| (view, false))
+            .chain(group.mutable.iter().map(|view| (view, true)))
+            .collect();
+
+        out += &format!(
+            "\n## {}\n\n```rust\n# use pas::*;\n{}\n```\n",
+            group.title, group.setup
+        );
+        out += "\n| First \\ Second |";
+        for i in 0..views.len() {
+            out += &format!(" {} |", i);
+        }
+        out += "\n|---|";
+        out += &"---|".repeat(views.len());
+        let representative = |name: &str| {
+            let found = group.equivalent.iter().find(|(view, _)| *view == name);
+            found.map(|(_, representative)| *representative)
+        };
+        for (view, representative) in group.equivalent {
+            assert!(views.iter().any(|((name, ..), _)| name == view));
+            assert!(views.iter().any(|((name, ..), _)| name == representative));
+        }
+
+        for (i, ((name, ..), _)) in views.iter().enumerate() {
+            out += &format!("\n| {}: `{}`", i, name);
+            if let Some(representative) = representative(name) {
+                out += &format!(", as `{}`", representative);
+            }
+            out += " |";
+            for (_, mutable) in &views {
+                let rejected = group.consume || *mutable || views[i].1;
+                out += if rejected { " ✗ |" } else { " ✓ |" };
+            }
+        }
+        out += "\n";
+
+        let tested: Vec<_> = views
+            .iter()
+            .filter(|((name, ..), _)| representative(name).is_none())
+            .collect();
+        for ((first, ty_a, a), first_mut) in &tested {
+            for ((second, ty_b, b), second_mut) in &tested {
+                let attributes = match (first_mut, second_mut) {
+                    _ if group.consume => ",compile_fail,E0382",
+                    (false, false) => "",
+                    (true, true) => ",compile_fail,E0499",
+                    _ => ",compile_fail,E0502",
+                };
+                out += &format!(
+                    "\n`{}` then `{}`:\n\n```rust{}\n\
+                     # use pas::*;\n\
+                     {}\n\
+                     let a: {} = {};\n\
+                     let b: {} = {};\n\
+                     drop((a, b));\n\
+                     ```\n",
+                    first, second, attributes, group.setup, ty_a, a, ty_b, b
+                );
+            }
+        }
+    }
+
+    out += "\n## Views split from a single borrow\n";
+    for split in SPLITS {
+        out += &format!("\n`{}`", split.title);
+        let (open, close) = match split.feature {
+            Some(feature) => {
+                out += &format!(", behind the `{}` feature", feature);
+                (format!("# #[cfg(feature = \"{}\")] {{\n", feature), "# }\n")
+            }
+            None => (String::new(), ""),
+        };
+        out += &format!(
+            ":\n\n```rust\n# use pas::*;\n{}{}\n{}```\n",
+            open, split.code, close
+        );
+    }
+    out
+}
+
+#[test]
+fn matrix_is_up_to_date() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/aliasing.md");
+    let expected = render();
+    if std::env::var_os("PAS_BLESS").is_some() {
+        std::fs::write(path, &expected).unwrap();
+    }
+    let found = std::fs::read_to_string(path).unwrap_or_default();
+    assert!(
+        found == expected,
+        "`tests/aliasing.md` is outdated, run `PAS_BLESS=1 cargo test --test aliasing_tests`"
+    );
+}
+
+#[test]
+fn shared_views_read_the_same_data() {
+    let data: Vec<u32> = (0..8).collect();
+    let a: Slice<u32> = Slice::strided(&data, 0, 2);
+    let b: Slice<u32> = Slice::native(&data);
+
+    // Interleaved iteration never observes a change.
+    let mut iter = b.iter();
+    for value in a.iter() {
+        assert_eq!(iter.next(), Some(value));
+        iter.next();
+    }
+}
+
+#[test]
+fn disjoint_mutable_views() {
+    let mut data: Vec<u32> = (0..8).collect();
+    let mut slice: SliceMut<u32> = SliceMut::native(&mut data);
+
+    // Each half is written while the other one is iterated.
+    let (mut left, mut right) = slice.split_at_mut(4);
+    for (l, r) in left.iter().zip(right.iter()) {
+        std::mem::swap(l, r);
+    }
+    assert_eq!(data, [4, 5, 6, 7, 0, 1, 2, 3]);
+}
+
+#[test]
+fn sequential_mutable_views() {
+    let mut data = [0_u32; 4];
+
+    // Each view ends before the next one is created.
+    let mut a: SliceMut<u32> = SliceMut::native(&mut data);
+    a.fill(1);
+    let mut b: SliceMut<u32> = SliceMut::strided(&mut data, 0, 2);
+    b.copy_from_slice(&[2_u32, 3]);
+    let c: Slice<u32> = Slice::native(&data);
+    assert!(c.iter().eq([2, 1, 3, 1].iter()));
+}
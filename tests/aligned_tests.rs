@@ -0,0 +1,102 @@
+use pas::{AlignedBytes, Slice};
+
+fn is_aligned(bytes: &AlignedBytes, align: usize) -> bool {
+    bytes.as_ptr() as usize % align == 0 && bytes.align() == align
+}
+
+#[test]
+fn alignment() {
+    for align in [1, 2, 4, 8, 16, 64, 256, 4096] {
+        for len in [0, 1, 3, 100] {
+            let bytes = AlignedBytes::zeroed(len, align);
+            assert!(is_aligned(&bytes, align));
+            assert_eq!(bytes.len(), len);
+            assert!(bytes.iter().all(|b| *b == 0));
+        }
+        assert!(is_aligned(
+            &AlignedBytes::with_capacity_aligned(0, align),
+            align
+        ));
+    }
+}
+
+#[test]
+#[should_panic(expected = "alignment must be a power of two")]
+fn alignment_not_power_of_two() {
+    AlignedBytes::with_capacity_aligned(16, 12);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn capacity_overflow() {
+    AlignedBytes::with_capacity_aligned(isize::MAX as usize, 16);
+}
+
+#[test]
+fn from_slice() {
+    let data: Vec<u8> = (0..=255).collect();
+    for offset in [0, 1, 3] {
+        let bytes = AlignedBytes::from_slice_aligned(&data[offset..], 32);
+        assert!(is_aligned(&bytes, 32));
+        assert_eq!(&bytes[..], &data[offset..]);
+    }
+
+    let bytes = AlignedBytes::from_slice_aligned(&[], 8);
+    assert!(bytes.is_empty());
+    assert_eq!(bytes.clone(), bytes);
+
+    // Unaligned source, viewed once realigned.
+    let source: Vec<u8> = bytemuck::cast_slice(&[0_u32, 1, 2, 3]).to_vec();
+    let bytes = AlignedBytes::from_slice_aligned(&source[4..], 4);
+    let slice: Slice<u32> = Slice::raw(&bytes, 0, 4);
+    assert!(slice.iter().eq([1, 2, 3].iter()));
+}
+
+#[test]
+fn resize() {
+    let mut bytes = AlignedBytes::with_capacity_aligned(2, 64);
+    assert_eq!(bytes.capacity(), 2);
+    bytes.extend_from_slice(&[1, 2]);
+    bytes.resize(5, 7);
+    assert_eq!(&bytes[..], [1, 2, 7, 7, 7]);
+    assert!(bytes.capacity() >= 5);
+    assert!(is_aligned(&bytes, 64));
+
+    // Growing keeps the content and the alignment.
+    for len in [100, 1000, 10_000] {
+        bytes.resize(len, 9);
+        assert!(is_aligned(&bytes, 64));
+        assert_eq!(&bytes[..5], [1, 2, 7, 7, 7]);
+        assert_eq!(bytes[len - 1], 9);
+    }
+
+    // Shrinking keeps the capacity.
+    let capacity = bytes.capacity();
+    bytes.resize(3, 0);
+    assert_eq!(&bytes[..], [1, 2, 7]);
+    bytes.clear();
+    assert!(bytes.is_empty());
+    assert_eq!(bytes.capacity(), capacity);
+
+    bytes[..].fill(0);
+    let mut clone = bytes.clone();
+    clone.resize(2, 3);
+    assert!(is_aligned(&clone, 64));
+    assert_eq!(&clone[..], [3, 3]);
+}
+
+#[test]
+fn zero_length() {
+    let mut bytes = AlignedBytes::default();
+    assert_eq!((bytes.len(), bytes.capacity(), bytes.align()), (0, 0, 1));
+    assert!(!bytes.as_ptr().is_null());
+    bytes.resize(0, 1);
+    bytes.extend_from_slice(&[]);
+    assert_eq!(bytes.capacity(), 0);
+    drop(bytes);
+
+    let mut bytes = AlignedBytes::with_capacity_aligned(0, 16);
+    bytes.extend_from_slice(&[1]);
+    assert!(is_aligned(&bytes, 16));
+    assert_eq!(&bytes[..], [1]);
+}
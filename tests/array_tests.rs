@@ -0,0 +1,93 @@
+use pas::{Slice, SliceArray, SliceArrayMut, SliceError, SliceMut};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Influence {
+    pub bone: u32,
+    pub weight: f32,
+}
+
+fn influences() -> [Influence; 4] {
+    [0, 1, 2, 3].map(|i| Influence {
+        bone: i,
+        weight: i as f32 * 0.25,
+    })
+}
+
+#[test]
+fn try_into_array() {
+    let data = influences();
+    let bones: Slice<u32> = Slice::new(&data, 0);
+    let array: SliceArray<u32, 4> = bones.try_into_array().unwrap();
+    assert_eq!(*array.get::<0>(), 0);
+    assert_eq!(*array.get::<3>(), 3);
+    assert!(array.iter().copied().eq(0..4));
+
+    let [a, b, c, d] = array.to_array();
+    assert_eq!((a, b, c, d), (0, 1, 2, 3));
+
+    let bones: Slice<u32> = array.into();
+    assert_eq!(bones.len(), 4);
+
+    let empty: SliceArray<u32, 0> = Slice::<u32>::native(&[]).try_into_array().unwrap();
+    assert_eq!(empty.to_array(), [0_u32; 0]);
+}
+
+#[test]
+fn try_into_array_mismatch() {
+    let data = influences();
+    let bones: Slice<u32> = Slice::new(&data, 0);
+    assert_eq!(
+        bones.try_into_array::<3>().unwrap_err(),
+        SliceError::LengthMismatch {
+            expected: 3,
+            len: 4
+        }
+    );
+    assert_eq!(
+        bones.try_into_array::<5>().unwrap_err(),
+        SliceError::LengthMismatch {
+            expected: 5,
+            len: 4
+        }
+    );
+
+    let mut data = influences();
+    let weights: SliceMut<f32> = SliceMut::new(&mut data, 4);
+    assert!(weights.try_into_array::<2>().is_err());
+}
+
+#[test]
+fn to_array_round_trip() {
+    let mut data = influences();
+    let weights: SliceMut<f32> = SliceMut::new(&mut data, 4);
+    let mut array: SliceArrayMut<f32, 4> = weights.try_into_array().unwrap();
+    assert_eq!(array.to_array(), [0.0, 0.25, 0.5, 0.75]);
+
+    *array.get_mut::<1>() = 1.0;
+    assert_eq!(*array.get::<1>(), 1.0);
+    array.iter_mut().for_each(|w| *w *= 2.0);
+    assert_eq!(array.to_array(), [0.0, 2.0, 1.0, 1.5]);
+
+    array.copy_from_array(&[4.0, 3.0, 2.0, 1.0]);
+    assert_eq!(array.to_array(), [4.0, 3.0, 2.0, 1.0]);
+    assert!(array.iter().copied().eq([4.0, 3.0, 2.0, 1.0]));
+
+    let weights: SliceMut<f32> = array.into_slice();
+    assert_eq!(weights.len(), 4);
+
+    // Bones are untouched.
+    assert!(data.iter().map(|v| v.bone).eq(0..4));
+    assert!(data.iter().map(|v| v.weight).eq([4.0, 3.0, 2.0, 1.0]));
+}
+
+#[test]
+// trybuild spawns the compiler, which Miri's isolation forbids.
+#[cfg_attr(miri, ignore)]
+fn const_index() {
+    // Out of bounds indices fail after monomorphization, which requires
+    // a pass test so that trybuild builds instead of only checking.
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui-array/get_in_range.rs");
+    t.compile_fail("tests/ui-array/get_out_of_range.rs");
+}
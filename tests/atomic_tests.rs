@@ -0,0 +1,93 @@
+use pas::{AtomicLane, SliceMut};
+use std::sync::atomic::Ordering;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct Particle {
+    position: [f32; 3],
+    flags: u32,
+}
+
+const READY: u32 = 1 << 31;
+
+#[test]
+fn producer_consumer() {
+    let mut particles = vec![
+        Particle {
+            position: [0.0; 3],
+            flags: 0
+        };
+        64
+    ];
+    let flags = AtomicLane::new(SliceMut::<u32>::new(&mut particles, 12)).unwrap();
+    assert_eq!(flags.len(), 64);
+
+    let consumed = std::thread::scope(|s| {
+        s.spawn(|| {
+            for index in 0..flags.len() {
+                flags.store(index, index as u32, Ordering::Relaxed);
+                flags.fetch_or(index, READY, Ordering::Release);
+            }
+        });
+        let consumer = s.spawn(|| {
+            let mut values = Vec::new();
+            for index in 0..flags.len() {
+                let value = loop {
+                    let value = flags.load(index, Ordering::Acquire);
+                    if value & READY != 0 {
+                        break value;
+                    }
+                    std::hint::spin_loop();
+                };
+                values.push(value & !READY);
+            }
+            values
+        });
+        consumer.join().unwrap()
+    });
+    assert_eq!(consumed, (0..64).collect::<Vec<_>>());
+
+    // Other lanes are untouched.
+    assert!(particles.iter().all(|p| p.position == [0.0; 3]));
+    assert!(particles
+        .iter()
+        .enumerate()
+        .all(|(index, p)| p.flags == index as u32 | READY));
+}
+
+#[test]
+fn shared_counters() {
+    let mut data = [[0_u16; 2]; 4];
+    let counters = AtomicLane::new(SliceMut::<u16>::new(&mut data, 2)).unwrap();
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for index in 0..counters.len() {
+                    counters.fetch_add(index, 1, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+    assert_eq!(counters.swap(0, 10, Ordering::Relaxed), 4);
+    assert_eq!(counters.fetch_and(1, 0b110, Ordering::Relaxed), 4);
+    assert_eq!(counters.get(1).unwrap().load(Ordering::Relaxed), 4);
+    assert!(counters.get(4).is_none());
+    assert_eq!(data, [[0, 10], [0, 4], [0, 4], [0, 4]]);
+}
+
+#[test]
+#[cfg(target_has_atomic = "64")]
+fn wide_atomics() {
+    let mut data = [0_u64; 4];
+    let lane = AtomicLane::new(SliceMut::native(&mut data)).unwrap();
+    lane.store(3, u64::MAX, Ordering::SeqCst);
+    assert_eq!(lane.fetch_add(3, 2, Ordering::SeqCst), u64::MAX);
+    assert_eq!(data, [0, 0, 0, 1]);
+}
+
+#[test]
+fn composite_elements() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui-atomic/integer_lane.rs");
+    t.compile_fail("tests/ui-atomic/composite_lane.rs");
+}
@@ -0,0 +1,146 @@
+use pas::{AttributeError, AttributeSet, Slice, SliceError, TaggedPod};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub normal: [f32; 3],
+}
+
+fn vertices() -> Vec<Vertex> {
+    vec![
+        Vertex {
+            position: [0.0, 1.0, 2.0],
+            normal: [0.0, 0.0, 1.0],
+        },
+        Vertex {
+            position: [3.0, 4.0, 5.0],
+            normal: [0.0, 1.0, 0.0],
+        },
+        Vertex {
+            position: [6.0, 7.0, 8.0],
+            normal: [1.0, 0.0, 0.0],
+        },
+    ]
+}
+
+#[test]
+fn typed_retrieval() {
+    let vertices = vertices();
+    let uvs = [[0.0_f32, 0.0], [0.5, 0.5], [1.0, 1.0]];
+
+    let mut set = AttributeSet::new();
+    assert!(set.is_empty());
+    set.insert::<[f32; 3]>("POSITION", Slice::new(&vertices, 0));
+    set.insert::<[f32; 3]>("NORMAL", Slice::new(&vertices, 12));
+    set.insert("TEXCOORD_0", Slice::native(&uvs));
+    assert_eq!(set.len(), 3);
+
+    let positions = set.get::<[f32; 3]>("POSITION").unwrap();
+    assert!(std::ptr::eq(&positions[2], &vertices[2].position));
+    let normals = set.get::<[f32; 3]>("NORMAL").unwrap();
+    assert_eq!(normals[1], [0.0, 1.0, 0.0]);
+    let uvs = set.get::<[f32; 2]>("TEXCOORD_0").unwrap();
+    assert_eq!(uvs[2], [1.0, 1.0]);
+    assert_eq!(set.validate_counts(), Ok(3));
+}
+
+#[test]
+fn retrieval_errors() {
+    let vertices = vertices();
+    let mut set = AttributeSet::new();
+    set.insert::<[f32; 3]>("POSITION", Slice::new(&vertices, 0));
+
+    assert_eq!(
+        set.get::<[u32; 3]>("POSITION").unwrap_err(),
+        AttributeError::TypeMismatch {
+            name: "POSITION".into(),
+            error: SliceError::TagMismatch {
+                expected: <[u32; 3]>::TAG,
+                found: Some(<[f32; 3]>::TAG),
+            }
+        }
+    );
+    assert_eq!(
+        set.get::<[f32; 3]>("NORMAL").unwrap_err(),
+        AttributeError::Missing {
+            name: "NORMAL".into()
+        }
+    );
+}
+
+#[test]
+fn count_mismatch() {
+    let vertices = vertices();
+    let ids = [0_u32, 1];
+
+    let mut set = AttributeSet::new();
+    assert_eq!(set.validate_counts(), Ok(0));
+    set.insert::<[f32; 3]>("POSITION", Slice::new(&vertices, 0));
+    set.insert("_ID", Slice::native(&ids));
+    assert_eq!(
+        set.validate_counts(),
+        Err(AttributeError::CountMismatch {
+            name: "_ID".into(),
+            expected: 3,
+            count: 2,
+        })
+    );
+}
+
+#[test]
+fn iteration_order() {
+    let vertices = vertices();
+    let ids = [0_u32, 1, 2];
+
+    let mut set = AttributeSet::new();
+    set.insert::<[f32; 3]>("NORMAL", Slice::new(&vertices, 12));
+    set.insert("_ID", Slice::native(&ids));
+    set.insert::<[f32; 3]>("POSITION", Slice::new(&vertices, 0));
+
+    let names = |set: &AttributeSet| {
+        set.iter()
+            .map(|(name, _)| name.to_owned())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(names(&set), ["NORMAL", "_ID", "POSITION"]);
+
+    // Replacing keeps the position.
+    let previous = set.insert::<[u32; 3]>("NORMAL", Slice::new(&vertices, 12));
+    assert_eq!(previous.unwrap().tag(), Some(<[f32; 3]>::TAG));
+    assert_eq!(names(&set), ["NORMAL", "_ID", "POSITION"]);
+
+    let (name, info) = set.iter().nth(1).unwrap();
+    assert_eq!(name, "_ID");
+    assert_eq!((info.len(), info.stride()), (3, 4));
+}
+
+#[test]
+fn get_or_broadcast() {
+    let positions = [[0.5_f32; 3]; 3];
+    let ids = [1_u32, 2];
+    let mut set = AttributeSet::new();
+    set.insert("position", Slice::native(&positions));
+    set.insert("id", Slice::native(&ids));
+
+    let present = set.get_or_broadcast("position", &[0.0_f32; 3], 3).unwrap();
+    assert!(present.is_borrowed());
+    assert!(std::ptr::eq(present.get(0).unwrap(), &positions[0]));
+
+    let colors = set.get_or_broadcast("color", &[1.0_f32; 4], 3).unwrap();
+    assert!(colors.is_owned());
+    assert_eq!(colors.to_vec(), [[1.0; 4]; 3]);
+
+    assert_eq!(
+        set.get_or_broadcast("id", &0_u32, 3).unwrap_err(),
+        AttributeError::CountMismatch {
+            name: "id".into(),
+            expected: 3,
+            count: 2
+        }
+    );
+    assert!(matches!(
+        set.get_or_broadcast("position", &0_u32, 3),
+        Err(AttributeError::TypeMismatch { .. })
+    ));
+}
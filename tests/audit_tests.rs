@@ -0,0 +1,175 @@
+use pas::{audit_views, AuditWarning, Slice, StridedInfo};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub uv: [f32; 2],
+    pub color: [u8; 4],
+}
+
+/// Layout without data, e.g., read from a file.
+struct Desc {
+    offset: usize,
+    stride: usize,
+    size: usize,
+    align: usize,
+    count: usize,
+}
+
+impl StridedInfo for Desc {
+    fn len(&self) -> usize {
+        self.count
+    }
+
+    fn byte_stride(&self) -> usize {
+        self.stride
+    }
+
+    fn element_size(&self) -> usize {
+        self.size
+    }
+
+    fn element_align(&self) -> usize {
+        self.align
+    }
+
+    fn byte_offset(&self) -> usize {
+        self.offset
+    }
+}
+
+fn desc(offset: usize, stride: usize, size: usize, count: usize) -> Desc {
+    Desc {
+        offset,
+        stride,
+        size,
+        align: 4,
+        count,
+    }
+}
+
+#[test]
+fn clean_layout() {
+    let vertices = [Vertex {
+        position: [0.0; 3],
+        uv: [0.0; 2],
+        color: [0; 4],
+    }; 8];
+    let positions: Slice<[f32; 3]> = Slice::new(&vertices, 0);
+    let uvs: Slice<[f32; 2]> = Slice::new(&vertices, 12);
+    let colors: Slice<[u8; 4]> = Slice::new(&vertices, 20);
+    let ids = [0_u32; 8];
+    let views: [(&str, &dyn StridedInfo); 4] = [
+        ("position", &positions),
+        ("uv", &uvs),
+        ("color", &colors),
+        ("id", &Slice::native(&ids)),
+    ];
+    assert_eq!(audit_views(&views, std::mem::size_of_val(&vertices)), []);
+
+    // Descriptions of the same layout.
+    let position = desc(0, 24, 12, 8);
+    let uv = desc(12, 24, 8, 8);
+    let empty = desc(192, 24, 12, 0);
+    let views: [(&str, &dyn StridedInfo); 3] =
+        [("position", &position), ("uv", &uv), ("empty", &empty)];
+    assert_eq!(audit_views(&views, 192), []);
+}
+
+#[test]
+fn stride_smaller_than_element() {
+    // The stride is a component count, which isn't aligned either.
+    let position = Desc {
+        align: 0,
+        ..desc(0, 3, 12, 8)
+    };
+    assert_eq!(
+        audit_views(&[("position", &position)], 1000),
+        [AuditWarning::StrideSmallerThanElement {
+            name: "position".into(),
+            stride: 3,
+            element_size: 12
+        }]
+    );
+}
+
+#[test]
+fn span_exceeds_buffer() {
+    let vertices = [Vertex {
+        position: [0.0; 3],
+        uv: [0.0; 2],
+        color: [0; 4],
+    }; 8];
+    let uvs: Slice<[f32; 2]> = Slice::new(&vertices, 12);
+    // Element count passed as the buffer length.
+    assert_eq!(
+        audit_views(&[("uv", &uvs)], vertices.len()),
+        [AuditWarning::SpanExceedsBuffer {
+            name: "uv".into(),
+            end: Some(7 * 24 + 20),
+            buffer_len: 8
+        }]
+    );
+
+    let huge = desc(0, usize::MAX / 2 + 1, 4, 4);
+    assert_eq!(
+        audit_views(&[("huge", &huge)], 1000),
+        [AuditWarning::SpanExceedsBuffer {
+            name: "huge".into(),
+            end: None,
+            buffer_len: 1000
+        }]
+    );
+}
+
+#[test]
+fn unaligned() {
+    let offset = desc(2, 12, 12, 4);
+    assert_eq!(
+        audit_views(&[("offset", &offset)], 1000),
+        [AuditWarning::UnalignedOffset {
+            name: "offset".into(),
+            offset: 2,
+            align: 4
+        }]
+    );
+    let stride = desc(0, 14, 12, 4);
+    assert_eq!(
+        audit_views(&[("stride", &stride)], 1000),
+        [AuditWarning::UnalignedStride {
+            name: "stride".into(),
+            stride: 14,
+            align: 4
+        }]
+    );
+    // Unknown alignment.
+    let unknown = Desc { align: 0, ..offset };
+    assert_eq!(audit_views(&[("unknown", &unknown)], 1000), []);
+}
+
+#[test]
+fn duplicate() {
+    let vertices = [[0.0_f32; 3]; 4];
+    let first = Slice::native(&vertices);
+    let second = Slice::native(&vertices);
+    // Same layout in another buffer.
+    let normals = [[0.0_f32; 3]; 4];
+    let views: [(&str, &dyn StridedInfo); 3] = [
+        ("position", &first),
+        ("normal", &Slice::native(&normals)),
+        ("position_again", &second),
+    ];
+    assert_eq!(
+        audit_views(&views, 48),
+        [AuditWarning::Duplicate {
+            name: "position_again".into(),
+            first: "position".into(),
+            offset: 0,
+            element_size: 12
+        }]
+    );
+    let warning = audit_views(&views, 48).remove(0).to_string();
+    assert!(warning.contains("'position_again'"));
+    assert!(warning.contains("'position'"));
+}
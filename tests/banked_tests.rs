@@ -0,0 +1,116 @@
+use pas::{BankPolicy, Slice, SliceMut, SplitSlice, SplitSliceMut};
+
+/// Merge `first` and `second` one element at a time, following `policy`.
+fn merged(first: &[u32], second: &[u32], policy: BankPolicy) -> Vec<u32> {
+    let period = match policy {
+        BankPolicy::Concatenated => return [first, second].concat(),
+        BankPolicy::Interleaved { period } => period,
+    };
+    let (mut first, mut second) = (first.iter(), second.iter());
+    let mut result = vec![];
+    loop {
+        let before = result.len();
+        result.extend(first.by_ref().take(period));
+        result.extend(second.by_ref().take(period));
+        if result.len() == before {
+            return result;
+        }
+    }
+}
+
+#[test]
+fn split_slice_matches_merged() {
+    let policies = [
+        BankPolicy::Concatenated,
+        BankPolicy::Interleaved { period: 1 },
+        BankPolicy::Interleaved { period: 2 },
+        BankPolicy::Interleaved { period: 3 },
+        BankPolicy::Interleaved { period: 100 },
+    ];
+    for first_len in 0..9 {
+        for second_len in 0..9 {
+            let first: Vec<u32> = (0..first_len).collect();
+            let second: Vec<u32> = (100..100 + second_len).collect();
+            for policy in policies {
+                let split = SplitSlice::new(Slice::native(&first), Slice::native(&second), policy);
+                let expected = merged(&first, &second, policy);
+                let context = (first_len, second_len, policy);
+
+                assert_eq!(split.len(), expected.len(), "{:?}", context);
+                assert_eq!(split.is_empty(), expected.is_empty());
+                assert_eq!(split.iter().len(), expected.len());
+                assert_eq!(split.to_vec(), expected, "{:?}", context);
+                for (index, value) in expected.iter().enumerate() {
+                    assert_eq!(split.get(index), Some(value), "{:?} {}", context, index);
+                }
+                assert_eq!(split.get(expected.len()), None);
+                assert_eq!(format!("{:?}", split), format!("{:?}", expected));
+            }
+        }
+    }
+}
+
+#[test]
+fn split_slice_seam() {
+    // Unequal banks: the first one runs out in the middle of a run.
+    let data = [[0_u32, 1], [2, 3], [4, 5]];
+    let first: Slice<u32> = Slice::new(&data, 0);
+    let second = [10_u32, 11, 12, 13, 14, 15, 16];
+    let split = SplitSlice::new(
+        first,
+        Slice::native(&second),
+        BankPolicy::Interleaved { period: 2 },
+    );
+    assert_eq!(split.to_vec(), [0, 2, 10, 11, 4, 12, 13, 14, 15, 16]);
+    assert_eq!((split.get(4), split.get(5)), (Some(&4), Some(&12)));
+
+    let mut iter = split.iter();
+    assert_eq!(iter.len(), 10);
+    iter.nth(4);
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.next(), Some(&12));
+    assert_eq!(iter.count(), 4);
+
+    let empty: [u32; 0] = [];
+    let split = SplitSlice::new(
+        Slice::native(&empty),
+        Slice::native(&empty),
+        BankPolicy::Concatenated,
+    );
+    assert!(split.is_empty());
+    assert_eq!(split.iter().next(), None);
+}
+
+#[test]
+#[should_panic(expected = "period must be non-zero")]
+fn split_slice_zero_period() {
+    let data = [0_u32];
+    SplitSlice::new(
+        Slice::native(&data),
+        Slice::native(&data),
+        BankPolicy::Interleaved { period: 0 },
+    );
+}
+
+#[test]
+fn split_slice_mut_writes() {
+    let mut bank_a = [[0_u32; 2]; 4];
+    let mut bank_b = [0_u32; 3];
+    let mut split = SplitSliceMut::new(
+        SliceMut::new(&mut bank_a, 4),
+        SliceMut::native(&mut bank_b),
+        BankPolicy::Interleaved { period: 1 },
+    );
+    assert_eq!(split.len(), 7);
+    for (index, value) in split.iter_mut().enumerate() {
+        *value = index as u32 + 1;
+    }
+    *split.get_mut(6).unwrap() = 70;
+    assert_eq!(split.get_mut(7), None);
+    assert_eq!(split.to_vec(), [1, 2, 3, 4, 5, 6, 70]);
+    assert_eq!(split.as_split_slice().get(3), Some(&4));
+    assert_eq!(format!("{:?}", split), "[1, 2, 3, 4, 5, 6, 70]");
+
+    assert_eq!(bank_a, [[0, 1], [0, 3], [0, 5], [0, 70]]);
+    assert_eq!(bank_b, [2, 4, 6]);
+}
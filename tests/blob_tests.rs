@@ -0,0 +1,152 @@
+use pas::{BlobError, OwnedStrided, Slice, SliceMut};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub uv: [f32; 2],
+}
+
+fn vertices(count: usize) -> Vec<Vertex> {
+    (0..count)
+        .map(|i| Vertex {
+            position: [i as f32, i as f32 + 1.0, i as f32 + 2.0],
+            uv: [i as f32 * 0.5, i as f32 * 0.25],
+        })
+        .collect()
+}
+
+fn blob(slice: Slice<[f32; 3]>) -> Vec<u8> {
+    let mut out = Vec::new();
+    slice.write_blob(&mut out).unwrap();
+    out
+}
+
+#[test]
+fn round_trip() {
+    // Packed, and larger than a read chunk.
+    let positions: Vec<[f32; 3]> = vertices(10_000).iter().map(|v| v.position).collect();
+    let bytes = blob(Slice::native(&positions));
+    assert_eq!(bytes.len(), 32 + positions.len() * 12);
+    assert_eq!(&bytes[..4], b"PASB");
+    let owned = OwnedStrided::<[f32; 3]>::read_blob(&mut bytes.as_slice()).unwrap();
+    assert_eq!(owned.as_elements(), positions.as_slice());
+
+    // Strided
+    let mut data = vertices(3);
+    let slice: SliceMut<[f32; 2]> = SliceMut::new(&mut data, 12);
+    let mut bytes = Vec::new();
+    slice.write_blob(&mut bytes).unwrap();
+    let owned = OwnedStrided::<[f32; 2]>::read_blob(&mut bytes.as_slice()).unwrap();
+    assert!(owned.as_elements().iter().eq(data.iter().map(|v| &v.uv)));
+
+    // Empty
+    let bytes = blob(Slice::default());
+    assert_eq!(bytes.len(), 32);
+    let owned = OwnedStrided::<[f32; 3]>::read_blob(&mut bytes.as_slice()).unwrap();
+    assert!(owned.is_empty());
+
+    // Reading leaves the rest of the reader untouched.
+    let mut bytes = blob(Slice::native(&[[1.0, 2.0, 3.0]]));
+    bytes.extend_from_slice(&[7, 8]);
+    let mut reader = bytes.as_slice();
+    OwnedStrided::<[f32; 3]>::read_blob(&mut reader).unwrap();
+    assert_eq!(reader, &[7, 8]);
+}
+
+#[test]
+fn corrupt_header() {
+    let positions = [[0.0_f32; 3]; 4];
+    let bytes = blob(Slice::native(&positions));
+
+    let mut corrupt = bytes.clone();
+    corrupt[0] = b'X';
+    assert!(matches!(
+        OwnedStrided::<[f32; 3]>::read_blob(&mut corrupt.as_slice()),
+        Err(BlobError::BadMagic([b'X', b'A', b'S', b'B']))
+    ));
+
+    let mut corrupt = bytes.clone();
+    corrupt[4] = 2;
+    assert!(matches!(
+        OwnedStrided::<[f32; 3]>::read_blob(&mut corrupt.as_slice()),
+        Err(BlobError::UnsupportedVersion(2))
+    ));
+
+    // Written on a target of the other endianness.
+    let mut foreign = bytes.clone();
+    foreign[6] = if cfg!(target_endian = "big") { 0 } else { 1 };
+    assert!(matches!(
+        OwnedStrided::<[f32; 3]>::read_blob(&mut foreign.as_slice()),
+        Err(BlobError::EndiannessMismatch)
+    ));
+
+    assert!(matches!(
+        OwnedStrided::<[f32; 2]>::read_blob(&mut bytes.as_slice()),
+        Err(BlobError::ElementSizeMismatch {
+            expected: 8,
+            found: 12
+        })
+    ));
+
+    // Same size, but another type.
+    assert!(matches!(
+        OwnedStrided::<[u32; 3]>::read_blob(&mut bytes.as_slice()),
+        Err(BlobError::TypeMismatch { .. })
+    ));
+    // Blobs without type hash are accepted.
+    let mut untyped = bytes.clone();
+    untyped[24..32].fill(0);
+    let owned = OwnedStrided::<[u32; 3]>::read_blob(&mut untyped.as_slice()).unwrap();
+    assert_eq!(owned.len(), 4);
+
+    // A huge count doesn't allocate upfront.
+    let mut corrupt = bytes.clone();
+    corrupt[16..24].copy_from_slice(&(1_u64 << 40).to_le_bytes());
+    assert!(matches!(
+        OwnedStrided::<[f32; 3]>::read_blob(&mut corrupt.as_slice()),
+        Err(BlobError::Truncated { found: 48, .. })
+    ));
+
+    let mut corrupt = bytes.clone();
+    corrupt[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(matches!(
+        OwnedStrided::<[f32; 3]>::read_blob(&mut corrupt.as_slice()),
+        Err(BlobError::Overflow)
+    ));
+}
+
+#[test]
+fn truncated() {
+    let positions = [[0.0_f32; 3]; 4];
+    let bytes = blob(Slice::native(&positions));
+
+    assert!(matches!(
+        OwnedStrided::<[f32; 3]>::read_blob(&mut &bytes[..10]),
+        Err(BlobError::Truncated {
+            expected: 32,
+            found: 10
+        })
+    ));
+    assert!(matches!(
+        OwnedStrided::<[f32; 3]>::read_blob(&mut &bytes[..bytes.len() - 1]),
+        Err(BlobError::Truncated {
+            expected: 48,
+            found: 47
+        })
+    ));
+}
+
+#[test]
+fn io_error() {
+    struct Failing;
+    impl std::io::Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::PermissionDenied.into())
+        }
+    }
+    assert!(matches!(
+        OwnedStrided::<u32>::read_blob(&mut Failing),
+        Err(BlobError::Io(_))
+    ));
+}
@@ -0,0 +1,120 @@
+use pas::{ApplyProgress, ApplyState, Slice, SliceMut, StaleStateError};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [u32; 3],
+    pub visits: u32,
+}
+
+fn data(count: u32) -> Vec<Vertex> {
+    (0..count)
+        .map(|i| Vertex {
+            position: [i, i + 1, i + 2],
+            visits: 0,
+        })
+        .collect()
+}
+
+#[test]
+fn visit_every_element_once() {
+    let mut vertices = data(10_007);
+    let mut slice: SliceMut<u32> = SliceMut::new(&mut vertices, 12);
+
+    let mut state = ApplyState::new();
+    let mut calls = 0;
+    loop {
+        let progress = slice
+            .apply_budgeted(&mut state, 64, |index, visits| {
+                assert_eq!(*visits, 0);
+                *visits = index as u32 + 1;
+            })
+            .unwrap();
+        calls += 1;
+        match progress {
+            ApplyProgress::InProgress {
+                processed,
+                remaining,
+            } => {
+                assert_eq!(processed, 64);
+                assert_eq!(remaining, 10_007 - calls * 64);
+                assert_eq!(state.next_index(), calls * 64);
+            }
+            ApplyProgress::Finished { processed } => {
+                assert_eq!(processed, 10_007 % 64);
+                break;
+            }
+        }
+    }
+    assert_eq!(calls, 10_007 / 64 + 1);
+    assert!(vertices
+        .iter()
+        .enumerate()
+        .all(|(i, v)| v.visits == i as u32 + 1));
+}
+
+#[test]
+fn resume() {
+    let vertices = data(10);
+    let slice: Slice<[u32; 3]> = Slice::new(&vertices, 0);
+
+    let mut state = ApplyState::new();
+    let mut visited = Vec::new();
+    for budget in [3, 0, 1, 4] {
+        slice
+            .visit_budgeted(&mut state, budget, |i, v| visited.push((i, v[0])))
+            .unwrap();
+    }
+    assert_eq!(visited, (0..8).map(|i| (i, i as u32)).collect::<Vec<_>>());
+
+    let progress = slice.visit_budgeted(&mut state, 100, |i, v| visited.push((i, v[0])));
+    assert_eq!(progress, Ok(ApplyProgress::Finished { processed: 2 }));
+    assert_eq!(visited.len(), 10);
+
+    // Finished passes don't process anything.
+    let progress = slice.visit_budgeted(&mut state, 100, |_, _| panic!());
+    assert_eq!(progress, Ok(ApplyProgress::Finished { processed: 0 }));
+
+    // Restart
+    state.reset();
+    let mut count = 0;
+    let progress = slice.visit_budgeted(&mut state, 100, |_, _| count += 1);
+    assert_eq!(progress, Ok(ApplyProgress::Finished { processed: 10 }));
+    assert_eq!(count, 10);
+
+    // Empty
+    let slice: Slice<u32> = Slice::default();
+    let progress = slice.visit_budgeted(&mut ApplyState::new(), 0, |_, _| panic!());
+    assert_eq!(progress, Ok(ApplyProgress::Finished { processed: 0 }));
+}
+
+#[test]
+fn stale_state() {
+    let mut vertices = data(10);
+
+    let mut state = ApplyState::new();
+    let mut slice: SliceMut<u32> = SliceMut::new(&mut vertices, 12);
+    slice.apply_budgeted(&mut state, 4, |_, v| *v += 1).unwrap();
+
+    // The view length changes between calls.
+    let mut slice: SliceMut<u32> = SliceMut::new(&mut vertices[..6], 12);
+    assert_eq!(
+        slice.apply_budgeted(&mut state, 4, |_, v| *v += 1),
+        Err(StaleStateError {
+            expected: 10,
+            len: 6
+        })
+    );
+    // The state stays invalid.
+    assert!(slice.visit_budgeted(&mut state, 4, |_, _| {}).is_err());
+    assert_eq!(state.next_index(), 4);
+
+    state.reset();
+    assert_eq!(
+        slice.apply_budgeted(&mut state, 10, |_, v| *v += 1),
+        Ok(ApplyProgress::Finished { processed: 6 })
+    );
+    assert!(vertices[..4].iter().all(|v| v.visits == 2));
+    assert!(vertices[4..6].iter().all(|v| v.visits == 1));
+    assert!(vertices[6..].iter().all(|v| v.visits == 0));
+}
@@ -0,0 +1,106 @@
+use pas::{ChunkedReader, OwnedStrided, Slice};
+use std::io::Cursor;
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [u32; 3],
+    pub uv: [u32; 2],
+}
+
+fn data(count: u32) -> Vec<Vertex> {
+    (0..count)
+        .map(|i| Vertex {
+            position: [i, i + 1, i + 2],
+            uv: [i * 10, i * 10 + 1],
+        })
+        .collect()
+}
+
+fn file(vertices: &[Vertex]) -> Cursor<Vec<u8>> {
+    Cursor::new(bytemuck::cast_slice(vertices).to_vec())
+}
+
+#[test]
+fn visit_every_element_once() {
+    let vertices = data(1000);
+    let stride = std::mem::size_of::<Vertex>();
+
+    // Chunks smaller than a stride, not aligned on strides, and larger than the file.
+    for chunk_bytes in [1, 7, stride, 3 * stride + 5, 4096, 1 << 20] {
+        let mut reader =
+            ChunkedReader::<_, [u32; 2]>::new(file(&vertices), 12, stride, 1000, chunk_bytes)
+                .unwrap();
+        let mut visited = vec![0; 1000];
+        reader
+            .for_each_element(|i, uv| {
+                assert_eq!(*uv, vertices[i].uv, "chunk_bytes {}", chunk_bytes);
+                visited[i] += 1;
+            })
+            .unwrap();
+        assert!(
+            visited.iter().all(|&v| v == 1),
+            "chunk_bytes {}",
+            chunk_bytes
+        );
+    }
+}
+
+#[test]
+fn chunks_never_split_elements() {
+    let vertices = data(64);
+    let stride = std::mem::size_of::<Vertex>();
+
+    let reader =
+        ChunkedReader::<_, [u32; 3]>::new(file(&vertices), 0, stride, 64, 3 * stride + 5).unwrap();
+    let chunks: Vec<OwnedStrided<[u32; 3]>> = reader.map(|c| c.unwrap()).collect();
+    assert!(chunks.iter().all(|c| !c.is_empty()));
+    assert_eq!(chunks.iter().map(|c| c.len()).sum::<usize>(), 64);
+
+    let positions: Vec<[u32; 3]> = chunks
+        .iter()
+        .flat_map(|c| c.as_elements().to_vec())
+        .collect();
+    let expected: Slice<[u32; 3]> = Slice::new(&vertices, 0);
+    assert_eq!(positions, expected.iter().copied().collect::<Vec<_>>());
+}
+
+#[test]
+fn partial_last_stride() {
+    let vertices = data(4);
+    // The file ends right after the last `uv`, without the stride padding.
+    let mut bytes = bytemuck::cast_slice::<_, u8>(&vertices).to_vec();
+    bytes.truncate(bytes.len() - 8);
+    let stride = std::mem::size_of::<Vertex>();
+
+    let mut reader =
+        ChunkedReader::<_, [u32; 3]>::new(Cursor::new(bytes), 0, stride, 4, 7).unwrap();
+    let mut values = Vec::new();
+    reader.for_each_element(|_, v| values.push(*v)).unwrap();
+    assert_eq!(values, [[0, 1, 2], [1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+}
+
+#[test]
+fn truncated_file() {
+    let vertices = data(4);
+    let stride = std::mem::size_of::<Vertex>();
+
+    let mut reader = ChunkedReader::<_, [u32; 3]>::new(file(&vertices), 0, stride, 5, 16).unwrap();
+    let err = reader.for_each_element(|_, _| {}).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn invalid_layouts() {
+    let vertices = data(4);
+
+    // The last element is `usize::MAX / 2` strides after the first one.
+    let mut reader =
+        ChunkedReader::<_, u32>::new(file(&vertices), 0, usize::MAX / 2, 4, 16).unwrap();
+    let err = reader.next_chunk().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    let mut reader = ChunkedReader::<_, ()>::new(file(&vertices), 0, 0, 4, 16).unwrap();
+    let err = reader.next_chunk().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,208 @@
+use pas::{Slice, SliceError, SliceMut};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub uv: [f32; 2],
+}
+
+fn data() -> [Vertex; 2] {
+    [
+        Vertex {
+            position: [1.0, 2.0, 3.0],
+            uv: [0.0, 0.5],
+        },
+        Vertex {
+            position: [4.0, 5.0, 6.0],
+            uv: [1.0, 0.5],
+        },
+    ]
+}
+
+// Only the variants raised while creating a slice are covered, other
+// variants come from operations on existing slices.
+
+#[test]
+fn valid_layouts() {
+    let vertices = data();
+    let uvs = Slice::<[f32; 2]>::try_new(&vertices, 12).unwrap();
+    assert_eq!(uvs[1], [1.0, 0.5]);
+    let positions = Slice::<[f32; 3]>::try_strided(&vertices, 0, 2).unwrap();
+    assert_eq!(positions.len(), 1);
+    let bytes: &[u8] = bytemuck::cast_slice(&vertices);
+    let xs = Slice::<f32>::try_raw(bytes, 0, 20).unwrap();
+    assert!(xs.iter().eq(&[1.0, 4.0]));
+    // Empty buffers.
+    assert!(Slice::<u32>::try_new(&[] as &[u32], 0).unwrap().is_empty());
+}
+
+#[test]
+fn attribute_larger_than_stride() {
+    let vertices = data();
+    let bytes: &[u8] = bytemuck::cast_slice(&vertices);
+    assert!(matches!(
+        Slice::<[f32; 3]>::try_raw(bytes, 0, 3),
+        Err(SliceError::AttributeLargerThanStride {
+            attr: 12,
+            stride: 3,
+            ..
+        })
+    ));
+    let small = [[0_u32; 2]; 4];
+    assert!(matches!(
+        Slice::<[u32; 3]>::try_new(&small, 0),
+        Err(SliceError::AttributeLargerThanStride {
+            attr: 12,
+            stride: 8,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn offset_out_of_bounds() {
+    let vertices = data();
+    assert_eq!(
+        Slice::<f32>::try_new(&vertices, 40).err(),
+        Some(SliceError::OffsetOutOfBounds {
+            size: 40,
+            offset: 40
+        })
+    );
+    let bytes: &[u8] = bytemuck::cast_slice(&vertices);
+    assert_eq!(
+        Slice::<u8>::try_raw(bytes, 100, 20).err(),
+        Some(SliceError::OffsetOutOfBounds {
+            size: 40,
+            offset: 100
+        })
+    );
+}
+
+#[test]
+fn alignment_fault() {
+    let vertices = data();
+    assert!(matches!(
+        Slice::<f32>::try_new(&vertices, 2),
+        Err(SliceError::AlignmentFault { offset: 2, .. })
+    ));
+    let bytes: &[u8] = bytemuck::cast_slice(&vertices);
+    assert!(matches!(
+        Slice::<f32>::try_raw(bytes, 1, 20),
+        Err(SliceError::AlignmentFault { offset: 1, .. })
+    ));
+}
+
+#[test]
+fn unaligned_stride() {
+    let vertices = data();
+    let bytes: &[u8] = bytemuck::cast_slice(&vertices);
+    assert!(matches!(
+        Slice::<f32>::try_raw(bytes, 0, 6),
+        Err(SliceError::UnalignedStride { stride: 6, .. })
+    ));
+}
+
+#[test]
+fn attribute_crosses_element_boundary() {
+    let vertices = data();
+    assert!(matches!(
+        Slice::<[f32; 3]>::try_new(&vertices, 12),
+        Err(SliceError::AttributeCrossesElementBoundary {
+            attr: 12,
+            offset: 12,
+            element: 20,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn overflow() {
+    let vertices = data();
+    assert_eq!(
+        Slice::<f32>::try_strided(&vertices, 0, usize::MAX).err(),
+        Some(SliceError::Overflow)
+    );
+    let bytes: &[u8] = bytemuck::cast_slice(&vertices);
+    assert_eq!(
+        Slice::<u8>::try_raw(bytes, 0, usize::MAX).err(),
+        Some(SliceError::Overflow)
+    );
+}
+
+#[test]
+fn panics_with_the_error() {
+    let vertices = data();
+    let error = Slice::<[f32; 3]>::try_new(&vertices, 12).unwrap_err();
+    let panic = std::panic::catch_unwind(|| Slice::<[f32; 3]>::new(&vertices, 12)).unwrap_err();
+    let message = panic.downcast_ref::<String>().unwrap();
+    assert!(message.contains(&format!("{:?}", error)));
+}
+
+#[test]
+fn mutable_valid_layouts() {
+    let mut vertices = data();
+    let mut uvs = SliceMut::<[f32; 2]>::try_new(&mut vertices, 12).unwrap();
+    uvs[1] = [2.0, 3.0];
+    assert_eq!(vertices[1].uv, [2.0, 3.0]);
+    let mut ys = SliceMut::<f32>::try_strided(&mut vertices, 4, 1).unwrap();
+    ys.fill(0.0);
+    assert_eq!(vertices[1].position, [4.0, 0.0, 6.0]);
+    let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut vertices);
+    let mut zs = SliceMut::<f32>::try_raw(bytes, 8, 20).unwrap();
+    zs[0] = -1.0;
+    assert_eq!(vertices[0].position, [1.0, 0.0, -1.0]);
+}
+
+#[test]
+fn mutable_errors_leave_buffer_untouched() {
+    let mut vertices = data();
+    let expected: Vec<u8> = bytemuck::cast_slice(&vertices).to_vec();
+
+    assert!(matches!(
+        SliceMut::<[f32; 3]>::try_new(&mut vertices, 12),
+        Err(SliceError::AttributeCrossesElementBoundary { .. })
+    ));
+    assert_eq!(
+        SliceMut::<f32>::try_new(&mut vertices, 40).err(),
+        Some(SliceError::OffsetOutOfBounds {
+            size: 40,
+            offset: 40
+        })
+    );
+    assert!(matches!(
+        SliceMut::<f32>::try_new(&mut vertices, 2),
+        Err(SliceError::AlignmentFault { offset: 2, .. })
+    ));
+    assert_eq!(
+        SliceMut::<f32>::try_strided(&mut vertices, 0, usize::MAX).err(),
+        Some(SliceError::Overflow)
+    );
+    let mut small = [[0_u32; 2]; 4];
+    assert!(matches!(
+        SliceMut::<[u32; 3]>::try_new(&mut small, 0),
+        Err(SliceError::AttributeLargerThanStride { .. })
+    ));
+    assert_eq!(small, [[0; 2]; 4]);
+
+    let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut vertices);
+    assert!(matches!(
+        SliceMut::<[f32; 3]>::try_raw(bytes, 0, 3),
+        Err(SliceError::AttributeLargerThanStride { .. })
+    ));
+    assert!(matches!(
+        SliceMut::<f32>::try_raw(bytes, 0, 6),
+        Err(SliceError::UnalignedStride { stride: 6, .. })
+    ));
+    assert!(matches!(
+        SliceMut::<f32>::try_raw(bytes, 1, 20),
+        Err(SliceError::AlignmentFault { offset: 1, .. })
+    ));
+    assert!(matches!(
+        SliceMut::<u8>::try_raw(bytes, 41, 20),
+        Err(SliceError::OffsetOutOfBounds { .. })
+    ));
+    assert_eq!(bytes, expected.as_slice());
+}
@@ -0,0 +1,249 @@
+use pas::convert::kernels::{
+    f16_bits_to_f32, f32_to_f16_bits, f32_to_unorm8, f32x3_to_f32x4, unorm8_to_f32,
+};
+use pas::convert::{
+    f16_to_f32, f32_to_f16, max_index, narrow_indices, narrow_indices_u16, NarrowError,
+};
+use pas::{Slice, SliceError, SliceMut};
+
+#[test]
+fn unorm8_round_trip() {
+    let bytes: Vec<u8> = (0..=255).collect();
+    let mut floats = vec![0.0_f32; 256];
+    unorm8_to_f32(&Slice::native(&bytes), &mut SliceMut::native(&mut floats)).unwrap();
+    assert!(floats
+        .iter()
+        .enumerate()
+        .all(|(i, v)| *v == i as f32 / 255.0));
+
+    let mut back = [0_u8; 256];
+    f32_to_unorm8(&Slice::native(&floats), &mut SliceMut::native(&mut back)).unwrap();
+    assert_eq!(back[..], bytes[..]);
+
+    // Strided destination.
+    let mut interleaved = [[0_u8; 4]; 256];
+    f32_to_unorm8(
+        &Slice::native(&floats),
+        &mut SliceMut::new(&mut interleaved, 1),
+    )
+    .unwrap();
+    assert!(interleaved
+        .iter()
+        .enumerate()
+        .all(|(i, v)| *v == [0, i as u8, 0, 0]));
+}
+
+#[test]
+fn unorm8_rounding() {
+    let src = [
+        -1.0,
+        -0.0,
+        0.4 / 255.0,
+        0.6 / 255.0,
+        0.5,
+        1.0,
+        1.5,
+        f32::NAN,
+        f32::INFINITY,
+    ];
+    let mut dst = [0_u8; 9];
+    f32_to_unorm8(&Slice::native(&src), &mut SliceMut::native(&mut dst)).unwrap();
+    assert_eq!(dst, [0, 0, 0, 1, 128, 255, 255, 0, 255]);
+}
+
+#[test]
+fn expand_to_f32x4() {
+    let vertices = [
+        [1.0_f32, 2.0, 3.0, 9.0],
+        [4.0, 5.0, 6.0, 9.0],
+        [7.0, 8.0, 9.0, 9.0],
+    ];
+    let positions: Slice<[f32; 3]> = Slice::new(&vertices, 0);
+    let mut dst = [[0.0_f32; 4]; 3];
+    f32x3_to_f32x4(&positions, &mut SliceMut::native(&mut dst), 1.0).unwrap();
+    assert_eq!(
+        dst,
+        [
+            [1.0, 2.0, 3.0, 1.0],
+            [4.0, 5.0, 6.0, 1.0],
+            [7.0, 8.0, 9.0, 1.0]
+        ]
+    );
+}
+
+#[test]
+fn length_mismatch() {
+    let src = [0.5_f32; 4];
+    let mut dst = [7_u8; 3];
+    assert_eq!(
+        f32_to_unorm8(&Slice::native(&src), &mut SliceMut::native(&mut dst)),
+        Err(SliceError::LengthMismatch {
+            expected: 4,
+            len: 3
+        })
+    );
+    assert_eq!(dst, [7; 3]);
+
+    let mut halves = [0_u16; 5];
+    assert!(f32_to_f16_bits(&Slice::native(&src), &mut SliceMut::native(&mut halves)).is_err());
+    let empty: [u16; 0] = [];
+    let mut floats: [f32; 0] = [];
+    assert_eq!(
+        f16_bits_to_f32(&Slice::native(&empty), &mut SliceMut::native(&mut floats)),
+        Ok(())
+    );
+}
+
+#[test]
+fn f16_special_values() {
+    let cases: [(f32, u16); 18] = [
+        (0.0, 0x0000),
+        (-0.0, 0x8000),
+        (1.0, 0x3c00),
+        (-2.0, 0xc000),
+        (0.5, 0x3800),
+        (1.0 / 3.0, 0x3555),
+        (65504.0, 0x7bff),
+        (-65504.0, 0xfbff),
+        // Halfway to the next power of two rounds to infinity.
+        (65520.0, 0x7c00),
+        (65519.996, 0x7bff),
+        (f32::INFINITY, 0x7c00),
+        (f32::NEG_INFINITY, 0xfc00),
+        (f32::MAX, 0x7c00),
+        // Smallest normal, largest and smallest subnormals.
+        (2.0_f32.powi(-14), 0x0400),
+        (1023.0 * 2.0_f32.powi(-24), 0x03ff),
+        (2.0_f32.powi(-24), 0x0001),
+        // Halfway to the smallest subnormal rounds to even, i.e., zero.
+        (2.0_f32.powi(-25), 0x0000),
+        (-1e-10, 0x8000),
+    ];
+    for (value, bits) in cases {
+        assert_eq!(f32_to_f16(value), bits, "{:e}", value);
+    }
+
+    assert_eq!(f32_to_f16(f32::NAN) & 0x7e00, 0x7e00);
+    assert_eq!(f32_to_f16(-f32::NAN) & 0x8000, 0x8000);
+    assert!(f16_to_f32(0x7e00).is_nan());
+    assert!(f16_to_f32(0x7c01).is_nan());
+    assert_eq!(f16_to_f32(0x7c00), f32::INFINITY);
+    assert_eq!(f16_to_f32(0x8001), -(2.0_f32.powi(-24)));
+}
+
+#[test]
+fn f16_exhaustive_round_trip() {
+    let halves: Vec<u16> = (0..=u16::MAX).collect();
+    let mut floats = vec![0.0_f32; halves.len()];
+    f16_bits_to_f32(&Slice::native(&halves), &mut SliceMut::native(&mut floats)).unwrap();
+    let mut back = vec![0_u16; halves.len()];
+    f32_to_f16_bits(&Slice::native(&floats), &mut SliceMut::native(&mut back)).unwrap();
+
+    for ((half, float), back) in halves.iter().zip(&floats).zip(&back) {
+        if half & 0x7c00 == 0x7c00 && half & 0x3ff != 0 {
+            // NaN, quieted.
+            assert!(float.is_nan());
+            assert_eq!(*back, half | 0x200);
+        } else {
+            assert_eq!(back, half, "{:#06x} {:e}", half, float);
+        }
+    }
+}
+
+#[test]
+fn f16_rounding_between_neighbors() {
+    // Each value halfway between two consecutive halves rounds to the even one,
+    // and values just around it to the closest one.
+    for low in 0..0x7bff_u16 {
+        let (a, b) = (f16_to_f32(low), f16_to_f32(low + 1));
+        let middle = (a + b) / 2.0;
+        let even = if low % 2 == 0 { low } else { low + 1 };
+        assert_eq!(f32_to_f16(middle), even, "{:#06x}", low);
+        assert_eq!(f32_to_f16(f32::from_bits(middle.to_bits() - 1)), low);
+        assert_eq!(f32_to_f16(f32::from_bits(middle.to_bits() + 1)), low + 1);
+        assert_eq!(f32_to_f16(-middle), even | 0x8000);
+    }
+}
+
+#[test]
+fn narrow_fitting_indices() {
+    let src: Vec<u64> = (0..1000).map(|i| i * 4_000_000).collect();
+    let mut dst = vec![0_u32; src.len()];
+    narrow_indices(&Slice::native(&src), &mut SliceMut::native(&mut dst)).unwrap();
+    assert!(dst.iter().zip(&src).all(|(d, s)| *d as u64 == *s));
+    assert_eq!(max_index(&Slice::native(&src)), Some(999 * 4_000_000));
+
+    let src: Vec<u32> = (0..=u16::MAX as u32).collect();
+    let mut dst = vec![0_u16; src.len()];
+    narrow_indices_u16(&Slice::native(&src), &mut SliceMut::native(&mut dst)).unwrap();
+    assert!(dst.iter().zip(&src).all(|(d, s)| *d as u32 == *s));
+}
+
+#[test]
+fn narrow_overflowing_index() {
+    // Overflow in the middle of the second chunk.
+    let mut src: Vec<u64> = (0..1000).collect();
+    src[300] = u32::MAX as u64 + 1;
+    src[400] = u64::MAX;
+    let mut dst = vec![7_u32; src.len()];
+    assert_eq!(
+        narrow_indices(&Slice::native(&src), &mut SliceMut::native(&mut dst)),
+        Err(NarrowError {
+            index: 300,
+            value: u32::MAX as u64 + 1
+        })
+    );
+    // Indices before the overflow are converted, the others untouched.
+    assert!((0..300).all(|i| dst[i] == i as u32));
+    assert!(dst[300..].iter().all(|i| *i == 7));
+
+    let src = [0, 1, 65_536];
+    let mut dst = [0_u16; 3];
+    let err = narrow_indices_u16(&Slice::native(&src), &mut SliceMut::native(&mut dst));
+    assert_eq!(
+        err,
+        Err(NarrowError {
+            index: 2,
+            value: 65_536
+        })
+    );
+}
+
+#[test]
+fn narrow_empty_indices() {
+    let src: [u64; 0] = [];
+    let mut dst: [u32; 0] = [];
+    narrow_indices(&Slice::native(&src), &mut SliceMut::native(&mut dst)).unwrap();
+    assert_eq!(max_index(&Slice::native(&src)), None);
+}
+
+#[test]
+fn narrow_interleaved_indices() {
+    // Index next to a padding lane, which must not be validated.
+    let src: Vec<[u64; 2]> = (0..600).map(|i| [i * 3, u64::MAX]).collect();
+    let mut dst = vec![[0_u32; 3]; src.len()];
+    let indices: Slice<u64> = Slice::new(&src, 0);
+    assert_eq!(max_index(&indices), Some(599 * 3));
+    narrow_indices(&indices, &mut SliceMut::new(&mut dst, 4)).unwrap();
+    assert!(dst
+        .iter()
+        .enumerate()
+        .all(|(i, d)| *d == [0, i as u32 * 3, 0]));
+
+    let padding: Slice<u64> = Slice::new(&src, 8);
+    let err = narrow_indices(&padding, &mut SliceMut::new(&mut dst, 8));
+    assert_eq!(
+        err,
+        Err(NarrowError {
+            index: 0,
+            value: u64::MAX
+        })
+    );
+}
+
+#[test]
+#[should_panic]
+fn narrow_length_mismatch() {
+    let mut dst = [0_u32; 2];
+    let _ = narrow_indices(&Slice::native(&[0, 1, 2]), &mut SliceMut::native(&mut dst));
+}
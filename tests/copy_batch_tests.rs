@@ -0,0 +1,229 @@
+use pas::{CopyBatch, CopyStats, JaggedView, JaggedViewMut, Slice, SliceError, SliceMut};
+
+type Vertex = [u32; 4];
+
+/// Copy of `len` elements, from lane `src_lane` of the source elements starting
+/// at `src_start`, to lane `dst_lane` of consecutive destination elements.
+#[derive(Clone, Copy, Debug)]
+struct Job {
+    len: usize,
+    dst_lane: usize,
+    src_start: usize,
+    src_lane: usize,
+}
+
+/// Linear congruential generator, for reproducible jobs.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self, max: usize) -> usize {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) as usize % max
+    }
+}
+
+/// Jobs covering `len` destination elements, in destination order.
+fn random_jobs(rng: &mut Rng, len: usize, src_len: usize) -> Vec<Job> {
+    let mut jobs = Vec::new();
+    let mut start = 0;
+    while start < len {
+        let count = (1 + rng.next(12)).min(len - start);
+        jobs.push(Job {
+            len: count,
+            dst_lane: rng.next(4),
+            src_start: rng.next(src_len - count + 1),
+            src_lane: rng.next(4),
+        });
+        start += count;
+    }
+    jobs
+}
+
+/// Split `dst` into the destinations of `jobs`.
+fn split<'a>(mut dst: &'a mut [Vertex], jobs: &[Job]) -> Vec<Option<SliceMut<'a, u32>>> {
+    jobs.iter()
+        .map(|job| {
+            let (head, tail) = std::mem::take(&mut dst).split_at_mut(job.len);
+            dst = tail;
+            Some(SliceMut::new(head, job.dst_lane * 4))
+        })
+        .collect()
+}
+
+fn source<'a>(src: &'a [Vertex], job: &Job) -> Slice<'a, u32> {
+    Slice::new(
+        &src[job.src_start..job.src_start + job.len],
+        job.src_lane * 4,
+    )
+}
+
+fn batched(
+    dst: &mut [Vertex],
+    src: &[Vertex],
+    jobs: &[Job],
+    order: &[usize],
+    threads: Option<usize>,
+) -> CopyStats {
+    let mut dsts = split(dst, jobs);
+    let mut batch = CopyBatch::new();
+    for &i in order {
+        batch.add(dsts[i].take().unwrap(), source(src, &jobs[i]));
+    }
+    assert_eq!(batch.len(), jobs.len());
+    let stats = match threads {
+        Some(threads) => batch.execute_parallel(threads),
+        None => batch.execute(),
+    };
+    assert!(batch.is_empty());
+    stats.unwrap()
+}
+
+#[test]
+fn matches_individual_copies() {
+    let mut rng = Rng(7);
+    let src: Vec<Vertex> = (0..300)
+        .map(|i| [i, i + 1000, i + 2000, i + 3000])
+        .collect();
+    for _ in 0..8 {
+        let jobs = random_jobs(&mut rng, 200, src.len());
+        let mut order: Vec<usize> = (0..jobs.len()).collect();
+        for i in (1..order.len()).rev() {
+            order.swap(i, rng.next(i + 1));
+        }
+
+        let mut expected = vec![[0; 4]; 200];
+        for (mut dst, job) in split(&mut expected, &jobs).into_iter().flatten().zip(&jobs) {
+            dst.write_mapped_from(&source(&src, job), |v| *v);
+        }
+
+        let mut actual = vec![[0; 4]; 200];
+        let stats = batched(&mut actual, &src, &jobs, &order, None);
+        assert_eq!(actual, expected);
+        assert_eq!(stats.copies, jobs.len());
+        assert_eq!(stats.jobs + stats.merged, jobs.len());
+        assert_eq!(stats.bytes, 200 * 4);
+
+        let mut actual = vec![[0; 4]; 200];
+        assert_eq!(batched(&mut actual, &src, &jobs, &order, Some(4)), stats);
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn merge_adjacent() {
+    let src: Vec<Vertex> = (0..64).map(|i| [i; 4]).collect();
+    let other = src.clone();
+    let mut dst = vec![[0; 4]; 64];
+    let offsets: Vec<u32> = (0..=8).map(|i| i * 8).collect();
+    let offsets = Slice::native(&offsets);
+
+    // Consecutive ranges, in both buffers, of the same lanes.
+    let sources = JaggedView::new(Slice::<u32>::new(&src, 4), offsets).unwrap();
+    let mut dsts = JaggedViewMut::new(SliceMut::<u32>::new(&mut dst, 8), offsets).unwrap();
+    let mut batch = CopyBatch::new();
+    for (i, dst) in dsts
+        .groups_mut()
+        .enumerate()
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+    {
+        batch.add(dst, sources.group(i));
+    }
+    assert_eq!(
+        batch.execute().unwrap(),
+        CopyStats {
+            copies: 8,
+            merged: 7,
+            jobs: 1,
+            bytes: 256,
+        }
+    );
+    assert!(dst
+        .iter()
+        .enumerate()
+        .all(|(i, v)| *v == [0, 0, i as u32, 0]));
+
+    // A source from another buffer breaks the chain, even if it continues
+    // the previous source.
+    let others = JaggedView::new(Slice::<u32>::new(&other, 4), offsets).unwrap();
+    let mut dsts = JaggedViewMut::new(SliceMut::<u32>::new(&mut dst, 8), offsets).unwrap();
+    let mut batch = CopyBatch::new();
+    for (i, dst) in dsts.groups_mut().enumerate() {
+        batch.add(
+            dst,
+            if i == 4 {
+                others.group(i)
+            } else {
+                sources.group(i)
+            },
+        );
+    }
+    let stats = batch.execute_parallel(2).unwrap();
+    assert_eq!((stats.merged, stats.jobs), (5, 3));
+
+    // Packed copies, from two separate borrows.
+    let (staging_a, staging_b) = ([1_u16; 32], [2_u16; 32]);
+    let mut persistent = [0_u16; 64];
+    let (low, high) = persistent.split_at_mut(32);
+    let mut batch = CopyBatch::new();
+    batch.add(SliceMut::native(high), Slice::native(&staging_b));
+    batch.add(SliceMut::native(low), Slice::native(&staging_a));
+    batch.add(SliceMut::<u16>::native(&mut []), Slice::native(&[]));
+    let stats = batch.execute().unwrap();
+    assert_eq!((stats.copies, stats.merged, stats.jobs), (3, 0, 2));
+    assert_eq!(stats.bytes, 128);
+    assert_eq!(persistent[..32], [1; 32]);
+    assert_eq!(persistent[32..], [2; 32]);
+}
+
+#[test]
+fn invalid_copies() {
+    let src = [[7_u32; 4]; 8];
+    let mut dst = [[0_u32; 4]; 8];
+    let (low, high) = dst.split_at_mut(4);
+    let mut batch = CopyBatch::new();
+    batch.add(SliceMut::<u32>::new(low, 0), Slice::new(&src[..4], 0));
+    batch.add(SliceMut::<u32>::new(high, 0), Slice::new(&src[..3], 0));
+    assert_eq!(
+        batch.execute(),
+        Err(SliceError::LengthMismatch {
+            expected: 4,
+            len: 3
+        })
+    );
+    assert_eq!(batch.len(), 2);
+    drop(batch);
+    assert_eq!(dst, [[0; 4]; 8]);
+}
+
+#[test]
+fn overlapping_destinations() {
+    let src = [[1_u8; 8]; 4];
+    let mut dst = [[0_u8; 8]; 4];
+    let splitter = pas::__private::FieldSplitter::new(&mut dst);
+
+    // Distinct fields interleave, but never share a byte.
+    let mut batch = CopyBatch::new();
+    batch.add(unsafe { splitter.field::<[u8; 4]>(0) }, Slice::new(&src, 0));
+    batch.add(unsafe { splitter.field::<[u8; 4]>(4) }, Slice::new(&src, 4));
+    assert_eq!(batch.execute().unwrap().bytes, 32);
+
+    // Byte 1 of each element is written twice. Views are only created to be
+    // rejected, nothing is written through them.
+    let mut batch = CopyBatch::new();
+    batch.add(unsafe { splitter.field::<[u8; 4]>(4) }, Slice::new(&src, 0));
+    batch.add(unsafe { splitter.field::<[u8; 2]>(0) }, Slice::new(&src, 0));
+    batch.add(unsafe { splitter.field::<[u8; 2]>(1) }, Slice::new(&src, 0));
+    let error = SliceError::OverlappingCopies {
+        first: 1,
+        second: 2,
+    };
+    assert_eq!(batch.execute(), Err(error));
+    assert_eq!(batch.execute_parallel(4), Err(error));
+    drop(batch);
+    assert_eq!(dst, [[1; 8]; 4]);
+}
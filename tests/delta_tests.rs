@@ -0,0 +1,101 @@
+use pas::{OwnedStrided, Slice, SliceError, SliceMut};
+
+#[test]
+fn delta_restores_captured_elements() {
+    let mut data: Vec<[u32; 4]> = (0..500).map(|i| [i; 4]).collect();
+    let original = data.clone();
+    let mut slice: SliceMut<[u32; 4]> = SliceMut::native(&mut data);
+    let baseline = OwnedStrided::from_slice(&slice);
+
+    // Scattered edits, including the first and last elements.
+    for index in [0, 3, 64, 65, 250, 499] {
+        slice[index] = [u32::MAX; 4];
+    }
+    let delta = slice.capture_delta(&baseline);
+    assert_eq!(delta.len(), 6);
+    assert_eq!(delta.byte_size(), 6 * (std::mem::size_of::<usize>() + 16));
+    assert_eq!(
+        delta.iter().map(|(index, _)| index).collect::<Vec<_>>(),
+        [0, 3, 64, 65, 250, 499]
+    );
+    assert!(delta.iter().all(|(index, old)| *old == original[index]));
+
+    // Further edits, restored only where captured.
+    slice[3] = [7; 4];
+    slice[100] = [8; 4];
+    slice.apply_delta(&delta).unwrap();
+    for (index, element) in data.iter().enumerate() {
+        match index {
+            100 => assert_eq!(*element, [8; 4]),
+            _ => assert_eq!(*element, original[index]),
+        }
+    }
+}
+
+#[test]
+fn delta_strided() {
+    let mut data = [[1.0_f32, 0.0], [2.0, 0.0], [3.0, 0.0]];
+    let mut slice: SliceMut<f32> = SliceMut::new(&mut data, 0);
+    let baseline = OwnedStrided::from_slice(&slice);
+
+    slice[1] = 2.5;
+    let delta = slice.capture_delta(&baseline);
+    assert_eq!(delta.len(), 1);
+    slice.apply_delta(&delta).unwrap();
+    assert_eq!(data, [[1.0, 0.0], [2.0, 0.0], [3.0, 0.0]]);
+
+    // Bit-exact comparison: `-0.0 == 0.0`, but their bits differ.
+    let zeros = [0.0_f32; 2];
+    let baseline = OwnedStrided::from_slice(&Slice::native(&zeros));
+    let negative = [-0.0_f32, 0.0];
+    let delta = Slice::native(&negative).capture_delta(&baseline);
+    assert_eq!(
+        delta.iter().map(|(index, _)| index).collect::<Vec<_>>(),
+        [0]
+    );
+}
+
+#[test]
+fn delta_empty() {
+    let data: Vec<u16> = (0..1000).collect();
+    let slice = Slice::native(&data);
+    let delta = slice.capture_delta(&OwnedStrided::from_slice(&slice));
+    assert!(delta.is_empty());
+    assert_eq!(delta.byte_size(), 0);
+
+    // Only the common prefix is compared.
+    let shorter = OwnedStrided::new(data[..10].to_vec());
+    assert!(slice.capture_delta(&shorter).is_empty());
+}
+
+#[test]
+fn delta_out_of_range() {
+    let mut data = [0_u32; 8];
+    let baseline = OwnedStrided::new(vec![1_u32; 8]);
+    let delta = Slice::native(&data).capture_delta(&baseline);
+    assert_eq!(delta.len(), 8);
+
+    let mut slice: SliceMut<u32> = SliceMut::native(&mut data[..5]);
+    assert_eq!(
+        slice.apply_delta(&delta),
+        Err(SliceError::IndexOutOfBounds {
+            position: 5,
+            index: 5,
+            len: 5
+        })
+    );
+    // Nothing is written on error.
+    assert_eq!(data, [0; 8]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn delta_serde() {
+    let data = [[1_u8, 2, 3], [4, 5, 6]];
+    let baseline = OwnedStrided::new(vec![[1_u8, 2, 3], [0, 0, 0]]);
+    let delta = Slice::native(&data).capture_delta(&baseline);
+
+    let json = serde_json::to_string(&delta).unwrap();
+    let restored: pas::Delta<[u8; 3]> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, delta);
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "derive")]
+
+use pas::{AttributeLayout, Attributes, SliceError, ViewDesc};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable, Attributes)]
+pub struct Vertex {
+    pub position: [u32; 3],
+    pub uv: [u32; 2],
+}
+
+pub fn data() -> Vec<Vertex> {
+    vec![
+        Vertex {
+            position: [0, 1, 2],
+            uv: [3, 4],
+        },
+        Vertex {
+            position: [5, 6, 7],
+            uv: [8, 9],
+        },
+        Vertex {
+            position: [10, 11, 12],
+            uv: [13, 14],
+        },
+    ]
+}
+
+#[test]
+fn layout() {
+    assert_eq!(Vertex::STRIDE, 20);
+    assert_eq!(
+        Vertex::ATTRIBUTES,
+        &[
+            AttributeLayout {
+                name: "position",
+                offset: 0,
+                size: 12,
+                align: 4
+            },
+            AttributeLayout {
+                name: "uv",
+                offset: 12,
+                size: 8,
+                align: 4
+            },
+        ]
+    );
+    assert_eq!(Vertex::ATTRIBUTES[1].desc(20, 3), ViewDesc::new(12, 20, 3));
+}
+
+#[test]
+fn read() {
+    let vertices = data();
+    let views = VertexAttributes::of(&vertices);
+    assert!(views
+        .position
+        .iter()
+        .eq([[0, 1, 2], [5, 6, 7], [10, 11, 12]].iter()));
+    assert!(views.uv.iter().eq([[3, 4], [8, 9], [13, 14]].iter()));
+
+    // Empty
+    let views = VertexAttributes::of(&[]);
+    assert!(views.position.is_empty());
+    assert!(views.uv.is_empty());
+}
+
+#[test]
+fn mutate() {
+    let mut vertices = data();
+    let mut views = VertexAttributesMut::of(&mut vertices);
+
+    // Both views are alive at the same time.
+    for (position, uv) in views.position.iter().zip(views.uv.iter()) {
+        uv[0] += position[0];
+        position[2] = 0;
+    }
+    views.uv[2][1] = 20;
+    assert_eq!(
+        vertices,
+        [
+            Vertex {
+                position: [0, 1, 0],
+                uv: [3, 4],
+            },
+            Vertex {
+                position: [5, 6, 0],
+                uv: [13, 9],
+            },
+            Vertex {
+                position: [10, 11, 0],
+                uv: [23, 20],
+            },
+        ]
+    );
+
+    // Empty
+    let views = VertexAttributesMut::of(&mut []);
+    assert!(views.uv.is_empty());
+}
+
+#[test]
+fn rebase_within_field() {
+    let mut vertices = data();
+    let views = VertexAttributesMut::of(&mut vertices);
+    let mut uv = views.uv;
+
+    // The position view can't reach the `uv` field, still borrowed by `uv`.
+    assert!(matches!(
+        views.position.rebase::<[u32; 2]>(12),
+        Err(SliceError::AttributeCrossesElementBoundary { offset: 12, .. })
+    ));
+    uv.fill([1, 2]);
+
+    let views = VertexAttributesMut::of(&mut vertices);
+    let mut z = views.position.rebase::<u32>(8).unwrap();
+    z.fill(0);
+    assert!(z.rebase::<u32>(4).is_err());
+    assert!(vertices
+        .iter()
+        .all(|v| v.position[2] == 0 && v.uv == [1, 2]));
+}
+
+#[test]
+fn rejected_structs() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
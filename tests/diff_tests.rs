@@ -0,0 +1,107 @@
+use pas::{Deviation, Slice};
+
+#[test]
+fn identical() {
+    let data: [[u32; 2]; 3] = [[0, 1], [2, 3], [4, 5]];
+    let slice: Slice<[u32; 2]> = Slice::native(&data);
+    let diff = slice.diff(&slice, 10);
+    assert!(diff.is_identical());
+    assert!(diff.indices.is_empty());
+    assert_eq!(diff.first, None);
+    assert_eq!(diff.to_string(), "0 of 3 elements differ");
+
+    // Strided view against a packed copy.
+    let copy = [1_u32, 3, 5];
+    let slice: Slice<u32> = Slice::new(&data, 4);
+    assert!(slice.diff(&Slice::native(&copy), 10).is_identical());
+}
+
+#[test]
+fn single_element() {
+    let a: [[u32; 2]; 3] = [[0, 1], [2, 3], [4, 5]];
+    let b: [[u32; 2]; 3] = [[0, 1], [2, 7], [4, 5]];
+    let diff = Slice::<[u32; 2]>::native(&a).diff(&Slice::native(&b), 10);
+    assert!(!diff.is_identical());
+    assert_eq!(diff.count, 1);
+    assert_eq!(diff.indices, [1]);
+    assert_eq!(diff.first, Some((1, [2, 3], [2, 7])));
+    assert_eq!(diff.max_deviation, None);
+    assert_eq!(
+        diff.to_string(),
+        "1 of 3 elements differ; first at 1: [2, 3] vs [2, 7]"
+    );
+}
+
+#[test]
+fn max_reported() {
+    let a = [0_u32; 100];
+    let b: Vec<u32> = (0..100).map(|i| i % 2).collect();
+    let diff = Slice::<u32>::native(&a).diff(&Slice::native(&b), 3);
+    assert_eq!(diff.count, 50);
+    assert_eq!(diff.indices, [1, 3, 5]);
+    assert_eq!(diff.first, Some((1, 0, 1)));
+}
+
+#[test]
+fn approx() {
+    let a: [[f32; 3]; 3] = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+    let b: [[f32; 3]; 3] = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0001], [7.0, 8.5, 9.0]];
+    let (a, b) = (Slice::<[f32; 3]>::native(&a), Slice::native(&b));
+
+    // Near-miss inside epsilon
+    let diff = a.diff_approx(&b, 0.01, 10);
+    assert_eq!(diff.indices, [2]);
+    let max = diff.max_deviation.unwrap();
+    assert_eq!((max.index, max.lane, max.value), (2, 1, 0.5));
+    assert_eq!(
+        diff.to_string(),
+        "1 of 3 elements differ; first at 2: [7.0, 8.0, 9.0] vs [7.0, 8.5, 9.0]; max dev 0.5 at 2 lane 1"
+    );
+
+    // Near-miss outside epsilon
+    let diff = a.diff_approx(&b, 0.00001, 10);
+    assert_eq!(diff.indices, [1, 2]);
+    assert_eq!(diff.max_deviation.unwrap().index, 2);
+
+    // Byte-exact comparison catches both
+    assert_eq!(a.diff(&b, 10).indices, [1, 2]);
+
+    assert!(a.diff_approx(&b, 1.0, 10).is_identical());
+}
+
+#[test]
+fn approx_nan() {
+    let a = [f64::NAN, 1.0, 2.0];
+    let b = [f64::NAN, f64::NAN, 2.0];
+    let diff = Slice::<f64>::native(&a).diff_approx(&Slice::native(&b), 0.1, 10);
+    assert_eq!(diff.indices, [1]);
+    assert_eq!(
+        diff.max_deviation,
+        Some(Deviation {
+            value: f64::INFINITY,
+            index: 1,
+            lane: 0
+        })
+    );
+}
+
+#[test]
+fn mismatched_len() {
+    let a = [0_u32, 1, 2, 3];
+    let b = [0_u32, 1, 5];
+    let diff = Slice::<u32>::native(&a).diff(&Slice::native(&b), 10);
+    assert!(!diff.is_identical());
+    assert_eq!((diff.len_a, diff.len_b, diff.count), (4, 3, 1));
+    assert_eq!(
+        diff.to_string(),
+        "1 of 3 elements differ; first at 2: 2 vs 5; lengths differ: 4 vs 3"
+    );
+
+    let diff = Slice::<u32>::native(&a[..2]).diff(&Slice::native(&b), 10);
+    assert!(!diff.is_identical());
+    assert_eq!(diff.count, 0);
+    assert_eq!(
+        diff.to_string(),
+        "0 of 2 elements differ; lengths differ: 2 vs 3"
+    );
+}
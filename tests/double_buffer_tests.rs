@@ -0,0 +1,89 @@
+use pas::{DoubleBuffered, InterleaveBuilder, ViewDesc};
+
+#[test]
+fn write_swap_read() {
+    let mut buffers = DoubleBuffered::<[u32; 2]>::new(ViewDesc::new(4, 12, 3)).unwrap();
+    assert_eq!(buffers.len(), 3);
+    for generation in 1..=4 {
+        buffers
+            .write_view()
+            .iter()
+            .enumerate()
+            .for_each(|(i, v)| *v = [generation, i as u32]);
+        // Not visible until swapped.
+        assert!(buffers.read_view().iter().all(|v| v[0] == generation - 1));
+        buffers.swap();
+        assert!(buffers
+            .read_view()
+            .iter()
+            .enumerate()
+            .all(|(i, v)| *v == [generation, i as u32]));
+    }
+}
+
+#[test]
+fn swap_targets_previous_front() {
+    let mut buffers = DoubleBuffered::<f32>::new(ViewDesc::new(0, 4, 8)).unwrap();
+    let front: *const f32 = &buffers.read_view()[0];
+    let back: *const f32 = &buffers.write_view()[0];
+    assert_ne!(front, back);
+
+    buffers.swap();
+    assert!(std::ptr::eq(&buffers.write_view()[0], front));
+    assert!(std::ptr::eq(&buffers.read_view()[0], back));
+    buffers.swap();
+    assert!(std::ptr::eq(&buffers.write_view()[0], back));
+}
+
+#[test]
+fn resize() {
+    let mut buffers = DoubleBuffered::<u16>::new(ViewDesc::new(0, 2, 3)).unwrap();
+    buffers.write_view().copy_from_slice(&[1_u16, 2, 3]);
+    buffers.swap();
+
+    buffers.resize(5).unwrap();
+    assert_eq!(buffers.len(), 5);
+    assert!(buffers.read_view().iter().eq([1, 2, 3, 0, 0].iter()));
+    assert_eq!(buffers.write_view().len(), 5);
+    assert!(buffers.write_view().iter().all(|v| *v == 0));
+
+    buffers.resize(2).unwrap();
+    assert!(buffers.read_view().iter().eq([1, 2].iter()));
+    buffers.resize(0).unwrap();
+    assert!(buffers.is_empty() && buffers.read_view().is_empty());
+
+    assert!(buffers.resize(usize::MAX).is_err());
+    assert!(buffers.read_view().is_empty());
+}
+
+#[test]
+fn interleaved() {
+    let positions = [[0.0_f32, 0.0, 0.0], [1.0, 0.0, 0.0]];
+    let colors = [[255_u8, 0, 0, 255], [0, 255, 0, 255]];
+    let view = InterleaveBuilder::new()
+        .push(&positions)
+        .push(&colors)
+        .build()
+        .unwrap();
+
+    let mut buffers = DoubleBuffered::<[u8; 4]>::from_view(&view, 1).unwrap();
+    // Both sides start with the content of the view.
+    assert!(buffers.read_view().iter().eq(colors.iter()));
+    assert!(buffers.write_view().iter().eq(colors.iter()));
+
+    buffers.write_view()[0] = [1, 2, 3, 4];
+    buffers.write_attribute::<[f32; 3]>(0).unwrap()[1] = [5.0, 6.0, 7.0];
+    buffers.swap();
+    assert_eq!(buffers.read_view()[0], [1, 2, 3, 4]);
+    let read = buffers.read_attribute::<[f32; 3]>(0).unwrap();
+    assert_eq!(read[1], [5.0, 6.0, 7.0]);
+    assert!(buffers.read_attribute::<[f32; 5]>(0).is_err());
+
+    buffers.resize(3).unwrap();
+    assert_eq!(buffers.read_attribute::<[f32; 3]>(0).unwrap().len(), 3);
+    assert_eq!(buffers.read_view()[2], [0; 4]);
+    assert_eq!(buffers.read_view()[0], [1, 2, 3, 4]);
+
+    assert!(DoubleBuffered::<[u8; 4]>::from_view(&view, 0).is_ok());
+    assert!(DoubleBuffered::<[u8; 20]>::from_view(&view, 1).is_err());
+}
@@ -0,0 +1,195 @@
+use pas::{tagged_pod, ErasedSlice, Format, Slice, SliceError, TagFormat, TaggedPod, TypeTag};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub joints: [u32; 3],
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Position([f32; 3]);
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Joints([u32; 3]);
+
+tagged_pod!(Position => F32x3, Joints => "skin::Joints");
+
+fn vertices() -> Vec<Vertex> {
+    (0..4)
+        .map(|i| Vertex {
+            position: [i as f32, 0.5, -1.0],
+            joints: [i, i + 1, i + 2],
+        })
+        .collect()
+}
+
+#[test]
+fn tags() {
+    assert_eq!(
+        <[f32; 3]>::TAG,
+        TypeTag {
+            size: 12,
+            align: 4,
+            format: TagFormat::Standard(Format::F32x3)
+        }
+    );
+    assert_eq!(u8::TAG.format, TagFormat::Standard(Format::U8));
+    assert_eq!(<[i16; 4]>::TAG.size, 8);
+    assert_eq!(Position::TAG, <[f32; 3]>::TAG);
+    assert_eq!(
+        Joints::TAG,
+        TypeTag {
+            size: 12,
+            align: 4,
+            format: TagFormat::Named("skin::Joints")
+        }
+    );
+    assert_eq!(Position::TAG.to_string(), "F32x3 (12 bytes, align 4)");
+    assert_eq!(
+        Joints::TAG.to_string(),
+        "'skin::Joints' (12 bytes, align 4)"
+    );
+}
+
+#[test]
+fn downcast_tagged() {
+    let data = vertices();
+    let positions: Slice<[f32; 3]> = Slice::new(&data, 0);
+    let erased = ErasedSlice::tagged(positions);
+    assert_eq!(erased.len(), 4);
+    assert_eq!(erased.stride(), 24);
+    assert_eq!(erased.element_size(), 12);
+    assert_eq!(erased.tag(), Some(<[f32; 3]>::TAG));
+
+    let typed: Slice<[f32; 3]> = erased.downcast_tagged().unwrap();
+    assert!(typed.iter().eq(positions.iter()));
+    // Newtypes with the same standard format share the tag.
+    let typed: Slice<Position> = erased.downcast_tagged().unwrap();
+    assert_eq!(typed[3], Position([3.0, 0.5, -1.0]));
+
+    let joints = ErasedSlice::tagged(Slice::<Joints>::new(&data, 12));
+    let typed: Slice<Joints> = joints.downcast_tagged().unwrap();
+    assert_eq!(typed[1], Joints([1, 2, 3]));
+}
+
+#[test]
+fn tag_mismatch() {
+    let data = vertices();
+    let erased = ErasedSlice::tagged(Slice::<[f32; 3]>::new(&data, 0));
+
+    // Same size and alignment, different format.
+    let err = erased.downcast_tagged::<[u32; 3]>().unwrap_err();
+    assert_eq!(
+        err,
+        SliceError::TagMismatch {
+            expected: <[u32; 3]>::TAG,
+            found: Some(<[f32; 3]>::TAG),
+        }
+    );
+    assert_eq!(
+        format!("{:?}", err),
+        "Slice is tagged F32x3 (12 bytes, align 4), but U32x3 (12 bytes, align 4) was requested"
+    );
+    assert!(erased.downcast_tagged::<Joints>().is_err());
+
+    // Untagged slices are never downcast by tag.
+    let untagged = ErasedSlice::new(Slice::<[f32; 3]>::new(&data, 0));
+    assert_eq!(
+        untagged.downcast_tagged::<[f32; 3]>().unwrap_err(),
+        SliceError::TagMismatch {
+            expected: <[f32; 3]>::TAG,
+            found: None,
+        }
+    );
+}
+
+#[test]
+fn downcast_layout() {
+    let data = vertices();
+    let erased: ErasedSlice = Slice::<[u32; 3]>::new(&data, 12).into();
+    assert_eq!(erased.tag(), None);
+    assert_eq!(erased.byte_offset(), 12);
+
+    let joints: Slice<[u32; 3]> = erased.downcast().unwrap();
+    assert_eq!(joints[2], [2, 3, 4]);
+    // The tag is ignored, only the layout matters.
+    let bits: Slice<[f32; 3]> = erased.downcast().unwrap();
+    assert_eq!(bits[0][1], f32::from_bits(1));
+    assert!(matches!(
+        erased.downcast::<[u32; 2]>(),
+        Err(SliceError::ElementMismatch { .. })
+    ));
+    assert!(matches!(
+        erased.downcast::<[u64; 1]>(),
+        Err(SliceError::ElementMismatch { .. })
+    ));
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Word(u32);
+
+// Wrong on purpose: the tag claims the alignment of `[u8; 4]`.
+unsafe impl TaggedPod for Word {
+    const TAG: TypeTag = <[u8; 4]>::TAG;
+}
+
+#[test]
+fn downcast_tagged_layout() {
+    let bytes = [0_u8; 9];
+    let erased = ErasedSlice::tagged(Slice::<[u8; 4]>::raw(&bytes, 1, 4));
+    // The tags match, but `Word` can't be read at an odd byte offset.
+    assert!(matches!(
+        erased.downcast_tagged::<Word>(),
+        Err(SliceError::ElementMismatch { .. })
+    ));
+    assert!(erased.downcast_tagged::<[u8; 4]>().is_ok());
+}
+
+/// Plugin interface, implemented in a separately compiled crate.
+trait MeshSource {
+    fn attribute(&self, name: &str) -> Option<ErasedSlice<'_>>;
+}
+
+struct Mesh {
+    vertices: Vec<Vertex>,
+}
+
+impl MeshSource for Mesh {
+    fn attribute(&self, name: &str) -> Option<ErasedSlice<'_>> {
+        match name {
+            "position" => Some(ErasedSlice::tagged(Slice::<Position>::new(
+                &self.vertices,
+                0,
+            ))),
+            "joints" => Some(ErasedSlice::tagged(Slice::<Joints>::new(
+                &self.vertices,
+                12,
+            ))),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn plugin_boundary() {
+    let source: Box<dyn MeshSource> = Box::new(Mesh {
+        vertices: vertices(),
+    });
+
+    let positions: Slice<[f32; 3]> = source
+        .attribute("position")
+        .unwrap()
+        .downcast_tagged()
+        .unwrap();
+    assert!(positions.iter().map(|p| p[0]).eq([0.0, 1.0, 2.0, 3.0]));
+
+    let joints = source.attribute("joints").unwrap();
+    assert!(joints.downcast_tagged::<[u32; 3]>().is_err());
+    let joints: Slice<Joints> = joints.downcast_tagged().unwrap();
+    assert_eq!(joints[3], Joints([3, 4, 5]));
+    assert!(source.attribute("uv").is_none());
+}
@@ -0,0 +1,291 @@
+use std::error::Error;
+use std::io;
+
+use pas::convert::NarrowError;
+
+use pas::{
+    AttributeError, AttributeSet, BlobError, Format, OwnedStrided, SanitizeError, ScanError, Slice,
+    SliceError, SliceErrorKind, StaleStateError, TagFormat, TypeTag, WrapError,
+};
+
+fn every_slice_error() -> Vec<SliceError> {
+    let tag = TypeTag {
+        size: 12,
+        align: 4,
+        format: TagFormat::Standard(Format::F32x3),
+    };
+    vec![
+        SliceError::OffsetOutOfBounds { size: 8, offset: 9 },
+        SliceError::AttributeLargerThanStride {
+            type_name: "u32",
+            attr: 4,
+            stride: 2,
+        },
+        SliceError::AlignmentFault {
+            type_name: "u32",
+            offset: 1,
+        },
+        SliceError::AttributeCrossesElementBoundary {
+            type_name: "u32",
+            attr: 4,
+            offset: 6,
+            element: 8,
+        },
+        SliceError::UnalignedStride {
+            type_name: "u32",
+            stride: 6,
+        },
+        SliceError::Overflow,
+        SliceError::IndexOutOfBounds {
+            position: 1,
+            index: 7,
+            len: 4,
+        },
+        SliceError::ElementMismatch {
+            from: "u32",
+            to: "u64",
+        },
+        SliceError::CountMismatch {
+            index: 1,
+            expected: 3,
+            count: 2,
+        },
+        SliceError::NoAttribute,
+        SliceError::LengthMismatch {
+            expected: 3,
+            len: 2,
+        },
+        SliceError::TooShort {
+            expected: 3,
+            len: 2,
+        },
+        SliceError::NegativeStride { stride: -1 },
+        SliceError::NonContiguousElement {
+            columns: 3,
+            stride: 2,
+        },
+        SliceError::OverlappingAttributes { read: 0, write: 4 },
+        SliceError::TagMismatch {
+            expected: tag,
+            found: None,
+        },
+        SliceError::NotElementStart {
+            offset: 5,
+            byte_offset: 0,
+            stride: 4,
+            len: 3,
+        },
+        SliceError::OverlappingSegments {
+            first: 0,
+            second: 1,
+        },
+        SliceError::DecreasingOffset {
+            position: 2,
+            offset: 1,
+            previous: 3,
+        },
+    ]
+}
+
+#[test]
+fn slice_error_to_io() {
+    for error in every_slice_error() {
+        assert_eq!(error.to_string(), format!("{:?}", error));
+        let io_error = io::Error::from(error);
+        assert_eq!(io_error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(io_error.to_string(), error.to_string());
+        let inner = io_error.get_ref().unwrap();
+        assert_eq!(inner.downcast_ref::<SliceError>(), Some(&error));
+        assert!(inner.source().is_none());
+    }
+}
+
+#[test]
+fn narrow_error_to_io() {
+    let error = NarrowError {
+        index: 1,
+        value: 1 << 32,
+    };
+    assert_eq!(
+        error.to_string(),
+        "Index 4294967296 at position 1 doesn't fit in the destination type"
+    );
+    let io_error = io::Error::from(error);
+    assert_eq!(io_error.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(io_error.to_string(), error.to_string());
+    let inner = io_error.get_ref().unwrap();
+    assert_eq!(inner.downcast_ref::<NarrowError>(), Some(&error));
+    assert!(inner.source().is_none());
+}
+
+/// Convert `error` to an [`io::Error`], and downcast it back.
+fn round_trip<E>(error: E)
+where
+    E: Error + Clone + PartialEq + Send + Sync + 'static,
+    io::Error: From<E>,
+{
+    let io_error = io::Error::from(error.clone());
+    assert_eq!(io_error.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(io_error.to_string(), error.to_string());
+    let inner = io_error.get_ref().unwrap();
+    assert_eq!(inner.downcast_ref::<E>(), Some(&error));
+}
+
+#[test]
+fn operation_errors_to_io() {
+    assert_eq!(
+        WrapError.to_string(),
+        "Indices can't be wrapped with a modulus of zero"
+    );
+    round_trip(WrapError);
+
+    let error = ScanError { index: 2 };
+    assert_eq!(
+        error.to_string(),
+        "Sum overflows when adding the element at index 2"
+    );
+    round_trip(error);
+
+    let error = StaleStateError {
+        expected: 3,
+        len: 2,
+    };
+    assert_eq!(
+        error.to_string(),
+        "State was created for a slice with 3 elements, but slice has 2 elements"
+    );
+    round_trip(error);
+
+    let error = SanitizeError::NonFinite { index: 1, lane: 2 };
+    assert_eq!(error.to_string(), "Lane 2 of element 1 isn't finite");
+    assert!(error.source().is_none());
+    round_trip(error);
+
+    // Layout errors are kept as the source.
+    let slice_error = SliceError::TooShort {
+        expected: 3,
+        len: 2,
+    };
+    let error = SanitizeError::Slice(slice_error);
+    assert_eq!(error.to_string(), slice_error.to_string());
+    let source = error.source().unwrap();
+    assert_eq!(source.downcast_ref::<SliceError>(), Some(&slice_error));
+    round_trip(error);
+}
+
+#[test]
+fn slice_error_kind() {
+    let data = [0_u32; 4];
+    let error = Slice::<u32>::try_new(&data, 16).unwrap_err();
+    assert_eq!(error.kind(), SliceErrorKind::OutOfBounds);
+    let error = Slice::<u32>::try_new(&data, 2).unwrap_err();
+    assert_eq!(error.kind(), SliceErrorKind::Alignment);
+    assert_eq!(SliceError::Overflow.kind(), SliceErrorKind::Overflow);
+    assert_eq!(SliceError::NoAttribute.kind(), SliceErrorKind::InvalidInput);
+
+    let kinds: Vec<SliceErrorKind> = every_slice_error().iter().map(|e| e.kind()).collect();
+    assert_eq!(
+        kinds
+            .iter()
+            .filter(|k| **k == SliceErrorKind::Layout)
+            .count(),
+        6
+    );
+    assert_eq!(
+        kinds
+            .iter()
+            .filter(|k| **k == SliceErrorKind::Mismatch)
+            .count(),
+        5
+    );
+}
+
+#[test]
+fn attribute_error_source() {
+    let data = [0_u32; 4];
+    let mut set = AttributeSet::new();
+    set.insert::<u32>("id", Slice::native(&data));
+    let error = set.get::<f32>("id").unwrap_err();
+    let source = error.source().unwrap();
+    assert!(matches!(
+        source.downcast_ref::<SliceError>(),
+        Some(SliceError::TagMismatch { .. })
+    ));
+
+    let io_error: io::Error = error.clone().into();
+    let inner = io_error.get_ref().unwrap();
+    assert_eq!(inner.downcast_ref::<AttributeError>(), Some(&error));
+    assert!(inner.source().unwrap().is::<SliceError>());
+
+    let missing = set.get::<u32>("missing").unwrap_err();
+    assert!(missing.source().is_none());
+}
+
+#[test]
+fn blob_error_source() {
+    let error = OwnedStrided::<u32>::read_blob(&mut &b"NOPE"[..]).unwrap_err();
+    assert!(error.source().is_none());
+    let io_error = io::Error::from(error);
+    assert_eq!(io_error.kind(), io::ErrorKind::InvalidData);
+    assert!(io_error.get_ref().unwrap().is::<BlobError>());
+
+    // Reader errors keep their kind, and are the source.
+    let error = BlobError::Io(io::Error::new(io::ErrorKind::UnexpectedEof, "eof"));
+    let source = error.source().unwrap();
+    assert_eq!(
+        source.downcast_ref::<io::Error>().unwrap().kind(),
+        io::ErrorKind::UnexpectedEof
+    );
+    let io_error = io::Error::from(error);
+    assert_eq!(io_error.kind(), io::ErrorKind::UnexpectedEof);
+    assert!(io_error
+        .into_inner()
+        .unwrap()
+        .downcast::<BlobError>()
+        .is_ok());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn import_error_source() {
+    use pas::{ImportError, LayoutManifest, ManifestAttribute};
+
+    let manifest = LayoutManifest {
+        buffer_count: 1,
+        attributes: vec![
+            ManifestAttribute {
+                name: "position".into(),
+                format: "F99".into(),
+                element_size: 12,
+                offset: 0,
+                stride: 12,
+                count: 1,
+                buffer: 0,
+            },
+            ManifestAttribute {
+                name: "uv".into(),
+                format: "F32x2".into(),
+                element_size: 8,
+                offset: 64,
+                stride: 8,
+                count: 1,
+                buffer: 0,
+            },
+        ],
+    };
+    let data = [0_u32; 4];
+    let error = AttributeSet::import_layout(&manifest, &[bytemuck::cast_slice(&data)]).unwrap_err();
+    assert_eq!(error.issues.len(), 2);
+    // The unknown format has no source, the invalid view has one.
+    assert!(error.issues[0].source().is_none());
+    assert!(matches!(
+        error.source().unwrap().downcast_ref::<SliceError>(),
+        Some(SliceError::OffsetOutOfBounds { .. })
+    ));
+    let io_error = io::Error::from(error.clone());
+    assert_eq!(io_error.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(
+        io_error.get_ref().unwrap().downcast_ref::<ImportError>(),
+        Some(&error)
+    );
+}
@@ -0,0 +1,177 @@
+use pas::{Format, Slice, SliceError, SliceMut};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub color: [u8; 4],
+    pub uv: [u16; 2],
+    pub joints: [i16; 4],
+}
+
+fn data() -> Vec<Vertex> {
+    vec![bytemuck::Zeroable::zeroed(); 3]
+}
+
+#[test]
+fn fetch_info() {
+    let mut vertices = data();
+
+    let slice: Slice<[f32; 3]> = Slice::new(&vertices, 0);
+    assert_eq!(
+        slice.fetch_info().to_std140_words(),
+        Ok([0, 28, 3, Format::F32x3 as u32])
+    );
+    assert_eq!(slice.fetch_info().normalized(), None);
+
+    let slice: Slice<[u8; 4]> = Slice::new(&vertices, 12);
+    let info = slice.fetch_info().normalized().unwrap();
+    assert_eq!(info.format, Format::Unorm8x4);
+    assert_eq!(
+        info.to_push_constant_bytes(),
+        Ok([12, 0, 0, 0, 28, 0, 0, 0, 3, 0, 0, 0, 11, 0, 0, 0])
+    );
+
+    let slice: Slice<[u16; 2]> = Slice::new(&vertices[1..], 16);
+    assert_eq!(
+        slice.fetch_info().to_push_constant_bytes(),
+        Ok([16, 0, 0, 0, 28, 0, 0, 0, 2, 0, 0, 0, 17, 0, 0, 0])
+    );
+
+    // Mutable slices, with the byte offset kept from the original buffer.
+    let mut slice: SliceMut<[i16; 4]> = SliceMut::new(&mut vertices, 20);
+    let (_, tail) = slice.split_at_mut(1);
+    let info = tail.fetch_info().normalized().unwrap();
+    assert_eq!(info.to_std140_words(), Ok([48, 28, 2, 31]));
+    assert_eq!(info.format, Format::Snorm16x4);
+
+    // Empty
+    let slice: Slice<u32> = Slice::default();
+    assert_eq!(
+        slice.fetch_info().to_std140_words(),
+        Ok([0, 0, 0, Format::U32 as u32])
+    );
+}
+
+#[test]
+fn overflow() {
+    let slice: Slice<f32> = Slice::default();
+    let mut info = slice.fetch_info();
+    info.offset = u32::MAX as usize;
+    assert!(info.to_std140_words().is_ok());
+    info.count = u32::MAX as usize + 1;
+    assert_eq!(info.to_std140_words(), Err(SliceError::Overflow));
+    assert_eq!(info.to_push_constant_bytes(), Err(SliceError::Overflow));
+}
+
+#[test]
+fn formats() {
+    for (code, format) in Format::ALL.iter().enumerate() {
+        assert_eq!(*format as usize, code);
+        assert_eq!(Format::from_code(code as u32), Some(*format));
+    }
+    assert_eq!(Format::from_code(Format::ALL.len() as u32), None);
+
+    let normalized: Vec<_> = Format::ALL
+        .iter()
+        .filter_map(|f| f.normalized().map(|n| (*f, n)))
+        .collect();
+    assert_eq!(normalized.len(), 16);
+    assert!(normalized.contains(&(Format::U8, Format::Unorm8)));
+    assert!(normalized.contains(&(Format::I8x3, Format::Snorm8x3)));
+    assert!(normalized.contains(&(Format::U16x4, Format::Unorm16x4)));
+    assert!(normalized.contains(&(Format::I16x2, Format::Snorm16x2)));
+    assert_eq!(Format::Unorm8x4.normalized(), None);
+    assert_eq!(Format::I32x4.normalized(), None);
+}
+
+#[test]
+fn format_layout() {
+    for format in Format::ALL {
+        let lane = format.lane_format();
+        assert_eq!(lane.lanes(), 1);
+        assert_eq!(lane.lane_format(), lane);
+        assert_eq!(lane.is_normalized(), format.is_normalized());
+        assert_eq!(format.size(), lane.size() * format.lanes());
+        assert_eq!(format.align(), lane.size());
+        assert_eq!(format.normalized().is_some(), lane.normalized().is_some());
+    }
+    let sizes: Vec<_> = [Format::U8, Format::I8, Format::Unorm8, Format::Snorm8]
+        .iter()
+        .chain(&[Format::U16, Format::I16, Format::Unorm16, Format::Snorm16])
+        .chain(&[Format::U32, Format::I32, Format::F32])
+        .map(|f| f.size())
+        .collect();
+    assert_eq!(sizes, [1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4]);
+    assert_eq!(Format::ALL.iter().filter(|f| f.is_normalized()).count(), 16);
+    assert_eq!(Format::Snorm16x3.size(), 6);
+    assert_eq!(Format::Snorm16x3.lane_format(), Format::Snorm16);
+    assert_eq!(Format::U32x4.size(), 16);
+}
+
+#[test]
+fn format_of() {
+    fn check<T: pas::FormatElement>() {
+        let format = Format::of::<T>();
+        assert_eq!(format.size(), std::mem::size_of::<T>());
+        assert_eq!(format.align(), std::mem::align_of::<T>());
+        assert!(!format.is_normalized());
+        assert_eq!(format.validate_against::<T>(), Ok(()));
+        assert_eq!(
+            format
+                .normalized()
+                .map(|n| n.validate_against::<T>())
+                .unwrap_or(Ok(())),
+            Ok(())
+        );
+    }
+    macro_rules! check_all {
+        ($($t: ty),*) => {
+            $(
+                check::<$t>();
+                check::<[$t; 2]>();
+                check::<[$t; 3]>();
+                check::<[$t; 4]>();
+            )*
+        };
+    }
+    check_all!(u8, i8, u16, i16, u32, i32, f32);
+
+    assert_eq!(Format::of::<[f32; 3]>(), Format::F32x3);
+    assert_eq!(Format::of::<[u8; 1]>(), Format::U8);
+    // Same size, but a stricter alignment.
+    assert_eq!(
+        Format::Unorm8x4.validate_against::<u32>(),
+        Err(SliceError::ElementMismatch {
+            from: "format",
+            to: "u32"
+        })
+    );
+    assert!(Format::U32.validate_against::<[u8; 4]>().is_ok());
+    assert!(Format::F32x3.validate_against::<[f32; 4]>().is_err());
+}
+
+#[test]
+fn format_gltf() {
+    for format in Format::ALL {
+        match format.to_gltf() {
+            Some((component_type, ty, normalized)) => {
+                assert_eq!(
+                    Format::from_gltf(component_type, ty, normalized),
+                    Some(format)
+                )
+            }
+            None => assert_eq!(format.lane_format(), Format::I32),
+        }
+    }
+    assert_eq!(Format::from_gltf(5123, "SCALAR", false), Some(Format::U16));
+    assert_eq!(
+        Format::from_gltf(5120, "VEC2", true),
+        Some(Format::Snorm8x2)
+    );
+    assert_eq!(Format::from_gltf(5125, "VEC3", false), Some(Format::U32x3));
+    assert_eq!(Format::from_gltf(5126, "VEC4", true), None);
+    assert_eq!(Format::from_gltf(5125, "SCALAR", true), None);
+    assert_eq!(Format::from_gltf(5124, "SCALAR", false), None);
+    assert_eq!(Format::from_gltf(5126, "MAT3", false), None);
+}
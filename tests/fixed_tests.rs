@@ -0,0 +1,96 @@
+use pas::{Fixed16, Fixed32, Slice, SliceMut, Q16_16, Q2_14};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [Q16_16; 3],
+    pub id: u32,
+}
+
+#[test]
+fn round_trip() {
+    for value in [0.0, 1.0, -1.0, 1.5, -0.25, 32767.0, -32768.0, 1.0 / 65536.0] {
+        let fixed = Q16_16::from_f32(value);
+        assert_eq!(fixed.to_f32(), value);
+        assert_eq!(Q16_16::from_f64(fixed.to_f64()), fixed);
+    }
+    assert_eq!(Q16_16::from_f32(1.0).to_bits(), 0x10000);
+    assert_eq!(Q16_16::from_bits(-0x18000).to_f64(), -1.5);
+    assert_eq!(Q16_16::EPSILON.to_f64(), 1.0 / 65536.0);
+
+    for value in [1.0, -2.0, 0.5, 1.0 / 16384.0, 2.0 - 1.0 / 16384.0] {
+        assert_eq!(Q2_14::from_f32(value).to_f32(), value);
+    }
+    assert_eq!(Q2_14::from_f32(1.0).to_bits(), 1 << 14);
+    assert_eq!(Fixed32::<0>::from_f32(-7.0).to_bits(), -7);
+}
+
+#[test]
+fn saturation() {
+    assert_eq!(Q16_16::from_f32(40000.0), Q16_16::MAX);
+    assert_eq!(Q16_16::from_f32(-40000.0), Q16_16::MIN);
+    assert_eq!(Q16_16::from_f64(f64::INFINITY), Q16_16::MAX);
+    assert_eq!(Q16_16::from_f64(f64::NEG_INFINITY), Q16_16::MIN);
+    assert_eq!(Q16_16::from_f32(-32768.0), Q16_16::MIN);
+    assert_eq!(Q16_16::from_f32(f32::NAN).to_bits(), 0);
+    assert_eq!(Q2_14::from_f32(2.0), Q2_14::MAX);
+    assert_eq!(Q2_14::from_f32(-3.0), Q2_14::MIN);
+    assert_eq!(Q2_14::MIN.to_f32(), -2.0);
+
+    let max = Q16_16::MAX;
+    assert_eq!(max.checked_add(Q16_16::EPSILON), None);
+    assert_eq!(max.saturating_add(Q16_16::EPSILON), max);
+    assert_eq!(Q16_16::MIN.saturating_sub(Q16_16::EPSILON), Q16_16::MIN);
+    let big = Q16_16::from_f32(300.0);
+    assert_eq!(big.checked_mul(big), None);
+    assert_eq!(big.saturating_mul(big), max);
+    assert_eq!(big.saturating_mul(Q16_16::from_f32(-300.0)), Q16_16::MIN);
+}
+
+#[test]
+fn rounding_ties_to_even() {
+    let half = 0.5 / 256.0;
+    assert_eq!(Fixed32::<8>::from_f64(half).to_bits(), 0);
+    assert_eq!(Fixed32::<8>::from_f64(3.0 * half).to_bits(), 2);
+    assert_eq!(Fixed32::<8>::from_f64(5.0 * half).to_bits(), 2);
+    assert_eq!(Fixed32::<8>::from_f64(-half).to_bits(), 0);
+    assert_eq!(Fixed32::<8>::from_f64(-3.0 * half).to_bits(), -2);
+    assert_eq!(Fixed32::<8>::from_f64(-5.0 * half).to_bits(), -2);
+    // Not a tie.
+    assert_eq!(Fixed32::<8>::from_f64(1.1 * half).to_bits(), 1);
+    assert_eq!(Fixed16::<0>::from_f32(2.5).to_bits(), 2);
+    assert_eq!(Fixed16::<0>::from_f32(3.5).to_bits(), 4);
+
+    // Products round the same way.
+    let epsilon = Fixed32::<1>::EPSILON;
+    assert_eq!(epsilon.checked_mul(epsilon).unwrap().to_bits(), 0);
+    let three = Fixed32::<1>::from_bits(3);
+    assert_eq!(three.checked_mul(epsilon).unwrap().to_bits(), 2);
+    assert_eq!(
+        Fixed32::<1>::from_bits(-3)
+            .saturating_mul(epsilon)
+            .to_bits(),
+        -2
+    );
+}
+
+#[test]
+fn interleaved_views() {
+    let mut vertices = [Vertex {
+        position: [Q16_16::default(); 3],
+        id: 7,
+    }; 3];
+    let mut xs: SliceMut<Q16_16> = SliceMut::new(&mut vertices, 0);
+    assert_eq!(xs.write_from_f32_slice(&[1.5, -2.25, 1e6, 4.0]), 3);
+    let mut zs: SliceMut<Q16_16> = SliceMut::new(&mut vertices, 8);
+    assert_eq!(zs.write_from_f32_slice(&[0.5]), 1);
+
+    let xs: Slice<Q16_16> = Slice::new(&vertices, 0);
+    assert!(xs.iter_f32().eq([1.5, -2.25, 32768.0 - 1.0 / 65536.0]));
+    let zs: Slice<Q16_16> = Slice::new(&vertices, 8);
+    assert!(zs.iter_f32().eq([0.5, 0.0, 0.0]));
+    for vertex in vertices {
+        assert_eq!(vertex.position[1], Q16_16::default());
+        assert_eq!(vertex.id, 7);
+    }
+}
@@ -0,0 +1,105 @@
+use pas::forensics::{infer_float_runs, infer_stride, FloatRun, StrideScore};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub normal: [f32; 3],
+    pub uv: [u16; 2],
+    pub color: [u8; 4],
+    pub id: u32,
+}
+
+fn vertices(count: usize) -> Vec<Vertex> {
+    (0..count)
+        .map(|i| {
+            let t = i as f32 * 0.1;
+            Vertex {
+                position: [t.cos() * 4.0, t * 0.5, t.sin() * 4.0],
+                normal: [t.cos(), 0.0, t.sin()],
+                uv: [(i * 977 % 65536) as u16, (i * 331 % 65536) as u16],
+                color: [255, 128, 0, 255],
+                id: i as u32,
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn stride_ranks_first() {
+    let data = vertices(256);
+    let bytes: &[u8] = bytemuck::cast_slice(&data);
+    let scores = infer_stride(bytes, 1..97);
+    assert_eq!(scores[0].stride, 36);
+    assert_eq!(scores[0].multiple_of, None);
+    assert_eq!(scores.len(), 96);
+
+    // Multiples of the real stride come after every other stride.
+    let double = scores.iter().position(|s| s.stride == 72).unwrap();
+    assert_eq!(scores[double].multiple_of, Some(36));
+    assert!(scores[double..].iter().all(|s| s.multiple_of.is_some()));
+
+    // Deterministic.
+    assert_eq!(infer_stride(bytes, 1..97), scores);
+}
+
+#[test]
+fn stride_packed_and_small_buffers() {
+    // A single packed attribute.
+    let positions: Vec<[f32; 3]> = (0..100)
+        .map(|i| [i as f32, 2.0, -(i as f32) * 0.5])
+        .collect();
+    let bytes: &[u8] = bytemuck::cast_slice(&positions);
+    assert_eq!(infer_stride(bytes, 8..40)[0].stride, 12);
+
+    // Strides without two elements are skipped.
+    let scores = infer_stride(&bytes[..30], 0..20);
+    assert!(scores.iter().all(|s| s.stride > 0 && s.stride <= 15));
+    assert!(infer_stride(&[], 1..8).is_empty());
+}
+
+#[test]
+fn float_runs_find_positions() {
+    let data = vertices(256);
+    let bytes: &[u8] = bytemuck::cast_slice(&data);
+    let runs = infer_float_runs(bytes, 36);
+    // Positions and normals are adjacent, and merge in a single run.
+    assert_eq!(runs[0].range(), 0..24);
+    assert_eq!(runs[0].plausible, 1.0);
+    // Colors are NaN, and small ids are subnormal.
+    assert!(runs.iter().all(|run| run.range().end <= 28));
+
+    // The planted position field, alone with integers.
+    let mut bytes = Vec::new();
+    for (i, vertex) in data.iter().enumerate() {
+        bytes.extend_from_slice(&(i as u32).to_ne_bytes());
+        bytes.extend_from_slice(bytemuck::bytes_of(&vertex.position));
+        bytes.extend_from_slice(&[0xff; 4]);
+    }
+    assert_eq!(
+        infer_float_runs(&bytes, 20),
+        [FloatRun {
+            offset: 4,
+            count: 3,
+            plausible: 1.0
+        }]
+    );
+    assert!(infer_float_runs(&bytes, 3).is_empty());
+    assert!(infer_float_runs(&bytes[..19], 20).is_empty());
+}
+
+#[test]
+fn display() {
+    let score = StrideScore {
+        stride: 72,
+        score: 0.8125,
+        multiple_of: Some(36),
+    };
+    assert_eq!(score.to_string(), "stride   72: 0.812 (multiple of 36)");
+    let run = FloatRun {
+        offset: 4,
+        count: 3,
+        plausible: 0.95,
+    };
+    assert_eq!(run.to_string(), "bytes 4..16: 3 x f32 (95% plausible)");
+}
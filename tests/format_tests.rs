@@ -0,0 +1,157 @@
+use std::fmt::Write;
+
+use pas::{Slice, SliceMut};
+
+/// Fixed-size buffer, failing once full.
+struct Buffer<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Buffer<N> {
+    fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+impl<const N: usize> Write for Buffer<N> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.len + s.len();
+        if end > N {
+            return Err(std::fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[test]
+fn format_element() {
+    let data: [[f32; 3]; 2] = [[1.0, 2.0, 3.0], [4.0, 5.5, -6.0]];
+    let slice: Slice<[f32; 3]> = Slice::native(&data);
+    let mut out = Buffer::<32>::new();
+    slice.format_element(1, &mut out).unwrap();
+    assert_eq!(out.as_str(), "[4.0, 5.5, -6.0]");
+
+    let slice: Slice<f32> = Slice::new(&data, 4);
+    let mut out = Buffer::<32>::new();
+    slice.format_element(0, &mut out).unwrap();
+    assert_eq!(out.as_str(), "2.0");
+
+    // Out of bounds
+    assert!(slice.format_element(2, &mut Buffer::<32>::new()).is_err());
+    // Buffer too small
+    assert!(slice.format_element(0, &mut Buffer::<2>::new()).is_err());
+}
+
+#[test]
+fn format_prefix() {
+    let mut data: [u16; 4] = [0, 1, 2, 3];
+
+    let slice: Slice<u16> = Slice::native(&data);
+    let mut out = Buffer::<32>::new();
+    slice.format_prefix(2, &mut out).unwrap();
+    assert_eq!(out.as_str(), "[0, 1, ...]");
+
+    let mut out = Buffer::<32>::new();
+    slice.format_prefix(10, &mut out).unwrap();
+    assert_eq!(out.as_str(), "[0, 1, 2, 3]");
+
+    let mut out = Buffer::<32>::new();
+    slice.format_prefix(0, &mut out).unwrap();
+    assert_eq!(out.as_str(), "[...]");
+
+    let slice: SliceMut<u16> = SliceMut::native(&mut data[..0]);
+    let mut out = Buffer::<32>::new();
+    slice.format_prefix(2, &mut out).unwrap();
+    assert_eq!(out.as_str(), "[]");
+}
+
+#[test]
+fn render_truncated() {
+    let data: [u16; 10] = [1000; 10];
+    let slice: Slice<u16> = Slice::native(&data);
+    let full = format!("[{}]", ["1000"; 10].join(", "));
+    assert_eq!(full.len(), 60);
+    assert_eq!(slice.render_truncated(60), full);
+    assert_eq!(slice.render_truncated(usize::MAX), full);
+
+    // One byte short, the suffix takes the room of three elements.
+    let cut = "[1000, 1000, 1000, 1000, 1000, 1000, 1000, … (+3 more)]";
+    assert_eq!(cut.len(), 57);
+    assert_eq!(slice.render_truncated(59), cut);
+    assert_eq!(slice.render_truncated(57), cut);
+    assert_eq!(
+        slice.render_truncated(56),
+        "[1000, 1000, 1000, 1000, 1000, 1000, … (+4 more)]"
+    );
+    // The remaining count grows to two digits.
+    assert_eq!(slice.render_truncated(21), "[1000, … (+9 more)]");
+    assert_eq!(slice.render_truncated(20), "[… (+10 more)]");
+
+    // Before the first element, the output can't be shorter.
+    assert_eq!(slice.render_truncated(0), "[… (+10 more)]");
+
+    // Every budget renders a prefix, and counts every other element.
+    for max_bytes in 0..64 {
+        let out = slice.render_truncated(max_bytes);
+        assert!(out.len() <= max_bytes.max("[… (+10 more)]".len()));
+        let shown = out.matches("1000").count();
+        let remaining = match out.rsplit_once("(+") {
+            Some((_, rest)) => rest.trim_end_matches(" more)]").parse().unwrap(),
+            None => 0,
+        };
+        assert_eq!(shown + remaining, data.len());
+    }
+
+    let data: [u16; 5] = [10, 20, 30, 40, 50];
+    let slice: Slice<u16> = Slice::native(&data);
+    assert_eq!(slice.render_truncated(20), "[10, 20, 30, 40, 50]");
+    assert_eq!(slice.render_truncated(19), "[10, … (+4 more)]");
+    assert_eq!(slice.render_truncated(18), "[… (+5 more)]");
+
+    let empty: Slice<u16> = Slice::native(&data[..0]);
+    assert_eq!(empty.render_truncated(0), "[]");
+}
+
+#[test]
+fn render_truncated_arrays() {
+    let data: [[u8; 4]; 3] = [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+    let slice: Slice<[u8; 4]> = Slice::native(&data);
+    assert_eq!(
+        slice.render_truncated(64),
+        "[[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]"
+    );
+    assert_eq!(
+        slice.render_truncated(43),
+        "[[1, 2, 3, 4], [5, 6, 7, 8], … (+1 more)]"
+    );
+
+    // Each element is longer than the whole budget.
+    let data = [[100_u8; 16]; 4];
+    let slice: Slice<[u8; 16]> = Slice::native(&data);
+    assert_eq!(slice.render_truncated(28), "[<…>, <…>, <…>, <…>]");
+    assert_eq!(slice.render_truncated(27), "[<…>, … (+3 more)]");
+    assert_eq!(slice.render_truncated(21), "[… (+4 more)]");
+}
+
+#[test]
+fn fmt_truncated_matches_render() {
+    let data: Vec<u32> = (0..100).map(|i| i * 37).collect();
+    let slice: Slice<u32> = Slice::native(&data);
+    for max_bytes in [0, 5, 13, 14, 40, 100, 1000] {
+        let mut out = Buffer::<1024>::new();
+        slice.fmt_truncated(&mut out, max_bytes).unwrap();
+        assert_eq!(out.as_str(), slice.render_truncated(max_bytes));
+    }
+    // A failing writer is reported.
+    assert!(slice.fmt_truncated(&mut Buffer::<8>::new(), 1000).is_err());
+}
@@ -0,0 +1,220 @@
+use pas::{IndexElement, Slice, SliceError, SliceMut, WrapError};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [u32; 3],
+    pub uv: [u32; 2],
+}
+
+fn data() -> Vec<Vertex> {
+    (0..8)
+        .map(|i| Vertex {
+            position: [i, i + 1, i + 2],
+            uv: [i * 10, i * 10 + 1],
+        })
+        .collect()
+}
+
+// Boundary indices for each index type.
+macro_rules! boundary_tests {
+    ($($t: ty),*) => { paste::paste! { $(
+        #[test]
+        fn [<boundaries_ $t>]() {
+            let vertices = data();
+            let uvs: Slice<[u32; 2]> = Slice::new(&vertices, 12);
+
+            // Exactly `len - 1`.
+            let indices: [$t; 3] = [7, 0, 7];
+            assert_eq!(uvs.gather(&indices), Ok(vec![[70, 71], [0, 1], [70, 71]]));
+
+            // Exactly `len`.
+            let indices: [$t; 3] = [7, 8, 0];
+            assert_eq!(
+                uvs.gather(&indices),
+                Err(SliceError::IndexOutOfBounds { position: 1, index: 8, len: 8 })
+            );
+
+            // Largest value of the index type.
+            let indices: [$t; 2] = [0, <$t>::MAX];
+            assert_eq!(
+                uvs.check_indices(&indices),
+                Err(SliceError::IndexOutOfBounds { position: 1, index: <$t>::MAX as u64, len: 8 })
+            );
+        }
+    )* }};
+}
+
+boundary_tests!(u8, u16, u32, u64, usize);
+
+#[test]
+fn first_invalid_index_across_chunks() {
+    let vertices = data();
+    let uvs: Slice<[u32; 2]> = Slice::new(&vertices, 12);
+
+    let mut indices = vec![3_u64; 2000];
+    indices[1500] = 9;
+    indices[1700] = u64::MAX;
+    assert_eq!(
+        uvs.check_indices(&indices),
+        Err(SliceError::IndexOutOfBounds {
+            position: 1500,
+            index: 9,
+            len: 8
+        })
+    );
+}
+
+#[test]
+fn gather_into() {
+    let vertices = data();
+    let positions: Slice<[u32; 3]> = Slice::new(&vertices, 0);
+
+    let mut out = [[0_u32; 3]; 3];
+    let count = positions.gather_into(&[5_u32, 1, 5, 2], &mut SliceMut::native(&mut out));
+    assert_eq!(count, Ok(3));
+    assert_eq!(out, [[5, 6, 7], [1, 2, 3], [5, 6, 7]]);
+
+    // Nothing is written on error.
+    let mut out = [[0_u32; 3]; 2];
+    let result = positions.gather_into(&[1_u32, 100], &mut SliceMut::native(&mut out));
+    assert!(result.is_err());
+    assert_eq!(out, [[0; 3]; 2]);
+}
+
+#[test]
+fn gather_into_prefetched() {
+    let vertices = data();
+    let uvs: Slice<[u32; 2]> = Slice::new(&vertices, 12);
+    let indices: Vec<u32> = (0..64).map(|i| (i * 5 + 3) % 8).collect();
+
+    let mut expected = [[0_u32; 2]; 64];
+    let count = uvs.gather_into(&indices, &mut SliceMut::native(&mut expected));
+    assert_eq!(count, Ok(64));
+    for lookahead in [0, 1, 7, 63, 64, 1000, usize::MAX] {
+        let mut out = [[0_u32; 2]; 64];
+        let count =
+            uvs.gather_into_prefetched(&indices, &mut SliceMut::native(&mut out), lookahead);
+        assert_eq!(count, Ok(64));
+        assert_eq!(out, expected);
+
+        // Shorter destination.
+        let mut out = [0_u64; 9];
+        let mut dst: SliceMut<[u32; 2]> = SliceMut::new(&mut out, 0);
+        assert_eq!(
+            uvs.gather_into_prefetched(&indices, &mut dst, lookahead),
+            Ok(9)
+        );
+        assert!(dst.iter().zip(&expected).all(|(a, b)| a == b));
+    }
+
+    // Nothing is written on error.
+    let mut out = [[0_u32; 2]; 2];
+    let result = uvs.gather_into_prefetched(&[1_u32, 100], &mut SliceMut::native(&mut out), 1);
+    assert!(result.is_err());
+    assert_eq!(out, [[0; 2]; 2]);
+
+    // Prefetching is only a hint, out-of-bounds indices included.
+    uvs.prefetch(0);
+    uvs.prefetch(8);
+    uvs.prefetch(usize::MAX);
+}
+
+#[test]
+fn scatter_from() {
+    let mut vertices = data();
+    let src = [[100_u32, 101], [200, 201]];
+
+    let mut uvs: SliceMut<[u32; 2]> = SliceMut::new(&mut vertices, 12);
+    assert_eq!(
+        uvs.scatter_from(&[6_u16, 2, 4], &Slice::native(&src)),
+        Ok(2)
+    );
+    assert_eq!(uvs[6], [100, 101]);
+    assert_eq!(uvs[2], [200, 201]);
+    assert_eq!(uvs[4], [40, 41]);
+    assert!(uvs.scatter_from(&[8_u16], &Slice::native(&src)).is_err());
+
+    // Positions shouldn't be affected.
+    assert_eq!(vertices[6].position, [6, 7, 8]);
+}
+
+#[test]
+fn gather_triangles() {
+    let vertices = data();
+    let positions: Slice<[u32; 3]> = Slice::new(&vertices, 0);
+
+    // Trailing index is ignored.
+    let indices = [0_u64, 1, 2, 2, 1, 3, 7];
+    let triangles: Vec<_> = positions.gather_triangles(&indices).unwrap().collect();
+    assert_eq!(triangles.len(), 2);
+    assert_eq!(triangles[1], [[2, 3, 4], [1, 2, 3], [3, 4, 5]]);
+
+    let indices = [0_u8, 1, 8];
+    assert!(positions.gather_triangles(&indices).is_err());
+}
+
+#[test]
+fn checked_narrowing() {
+    assert_eq!(3_u64.to_usize(), Some(3));
+    assert_eq!(u8::MAX.to_usize(), Some(255));
+    assert_eq!(usize::MAX.to_u64(), usize::MAX as u64);
+}
+
+// Clamping and wrapping at and beyond the boundary, for each index type.
+macro_rules! remap_tests {
+    ($($t: ty),*) => { paste::paste! { $(
+        #[test]
+        fn [<remap_ $t>]() {
+            let max = <$t>::MAX;
+            let source: [$t; 7] = [0, 4, 5, 6, 11, max - 1, max];
+
+            let mut indices = source;
+            let slice = Slice::<$t>::native(&indices);
+            assert_eq!(slice.count_out_of_range(5), 4);
+            assert_eq!(slice.count_out_of_range(max), 0);
+            SliceMut::native(&mut indices).clamp_indices(5);
+            assert_eq!(indices, [0, 4, 5, 5, 5, 5, 5]);
+
+            let mut indices = source;
+            SliceMut::native(&mut indices).wrap_indices(5).unwrap();
+            let expected = source.map(|i| i % 5);
+            assert_eq!(indices, expected);
+
+            // Every index is already in range.
+            let mut indices = source;
+            SliceMut::native(&mut indices).clamp_indices(max);
+            SliceMut::native(&mut indices).wrap_indices(max).unwrap();
+            assert_eq!(indices, [0, 4, 5, 6, 11, max - 1, 0]);
+
+            let mut indices = source;
+            assert_eq!(
+                SliceMut::native(&mut indices).wrap_indices(0),
+                Err(WrapError)
+            );
+            assert_eq!(indices, source);
+        }
+    )* }};
+}
+
+remap_tests!(u16, u32, u64);
+
+#[test]
+fn remap_interleaved() {
+    // Index and a marker, which must be left untouched.
+    let mut data: Vec<[u32; 2]> = (0..9).map(|i| [i * 3, 0xdead]).collect();
+    let slice: Slice<u32> = Slice::new(&data, 0);
+    assert_eq!(slice.count_out_of_range(10), 5);
+
+    let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 0);
+    slice.clamp_indices(10);
+    assert!(data
+        .iter()
+        .map(|v| v[0])
+        .eq([0, 3, 6, 9, 10, 10, 10, 10, 10]));
+
+    let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 0);
+    slice.wrap_indices(4).unwrap();
+    assert!(data.iter().map(|v| v[0]).eq([0, 3, 2, 1, 2, 2, 2, 2, 2]));
+    assert!(data.iter().all(|v| v[1] == 0xdead));
+}
@@ -0,0 +1,116 @@
+use pas::{Slice, SliceError, SliceMut};
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Header {
+    pub magic: [u8; 3],
+    pub count: u32,
+}
+
+/// Buffer with a 7 bytes packed header, followed by `[u16; 2]` elements
+/// starting at byte 8, i.e., after one byte of padding.
+fn buffer() -> [u16; 12] {
+    let mut data = [0_u16; 12];
+    let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut data);
+    bytes[..3].copy_from_slice(b"PAS");
+    bytes[3..7].copy_from_slice(&2_u32.to_ne_bytes());
+    bytes[7] = 0xff;
+    let elements: &mut [u16] = bytemuck::cast_slice_mut(&mut bytes[8..]);
+    elements.copy_from_slice(&[10, 11, 20, 21, 30, 31, 40, 41]);
+    data
+}
+
+#[test]
+fn typed_header() {
+    let data = buffer();
+    let bytes: &[u8] = bytemuck::cast_slice(&data);
+
+    let (header, slice) = Slice::<[u16; 2]>::after_header::<Header>(bytes, 1, 4).unwrap();
+    assert_eq!(header.magic, *b"PAS");
+    assert_eq!({ header.count }, 2);
+    assert_eq!(slice.len(), 4);
+    assert!(slice
+        .iter()
+        .eq([[10, 11], [20, 21], [30, 31], [40, 41]].iter()));
+
+    // Odd offset after the header, with a larger stride.
+    let (_, slice) = Slice::<u16>::after_header::<Header>(bytes, 3, 8).unwrap();
+    assert!(slice.iter().eq([11, 31].iter()));
+    // Offsets are relative to the end of the header.
+    assert_eq!(slice.byte_offset(), 3);
+}
+
+#[test]
+fn opaque_header() {
+    let data = buffer();
+    let bytes: &[u8] = bytemuck::cast_slice(&data);
+
+    let (header, slice) = Slice::<u16>::after_opaque_header(bytes, 7, 1, 4).unwrap();
+    assert_eq!(header, &bytes[..7]);
+    assert!(slice.iter().eq([10, 20, 30, 40].iter()));
+
+    let (header, slice) = Slice::<u16>::after_opaque_header(bytes, 0, 8, 4).unwrap();
+    assert!(header.is_empty());
+    assert!(slice.iter().eq([10, 20, 30, 40].iter()));
+
+    // Header only, without any element.
+    let (header, slice) = Slice::<u16>::after_opaque_header(bytes, bytes.len(), 0, 4).unwrap();
+    assert_eq!(header.len(), 24);
+    assert!(slice.is_empty());
+}
+
+#[test]
+fn header_larger_than_buffer() {
+    let data = [0_u8; 5];
+    assert_eq!(
+        Slice::<u8>::after_header::<Header>(&data, 0, 1).err(),
+        Some(SliceError::OffsetOutOfBounds { size: 5, offset: 7 })
+    );
+    let mut data = [0_u8; 5];
+    assert_eq!(
+        SliceMut::<u8>::after_opaque_header(&mut data, 6, 0, 1).err(),
+        Some(SliceError::OffsetOutOfBounds { size: 5, offset: 6 })
+    );
+}
+
+#[test]
+fn remaining_region_validation() {
+    let data = buffer();
+    let bytes: &[u8] = bytemuck::cast_slice(&data);
+
+    // Unaligned start, once the header is skipped.
+    assert!(matches!(
+        Slice::<u16>::after_header::<Header>(bytes, 0, 4),
+        Err(SliceError::AlignmentFault { offset: 0, .. })
+    ));
+    // Offset out of the remaining 17 bytes.
+    assert_eq!(
+        Slice::<u16>::after_header::<Header>(bytes, 19, 4).err(),
+        Some(SliceError::OffsetOutOfBounds {
+            size: 17,
+            offset: 19
+        })
+    );
+    assert!(matches!(
+        Slice::<[u16; 2]>::after_header::<Header>(bytes, 1, 2),
+        Err(SliceError::AttributeLargerThanStride { .. })
+    ));
+}
+
+#[test]
+fn mutable_header() {
+    let mut data = buffer();
+    let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut data);
+
+    let (header, mut slice) = SliceMut::<u16>::after_header::<Header>(bytes, 3, 4).unwrap();
+    assert_eq!({ header.count }, 2);
+    slice.iter().for_each(|v| *v += 1);
+
+    let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut data);
+    let (header, mut slice) = SliceMut::<u16>::after_opaque_header(bytes, 8, 0, 4).unwrap();
+    assert_eq!(&header[..7], b"PAS\x02\0\0\0");
+    assert_eq!(header[7], 0xff);
+    assert!(slice.iter().eq([10, 20, 30, 40].iter()));
+    let elements: &[u16] = bytemuck::cast_slice(&bytemuck::cast_slice::<_, u8>(&data)[8..]);
+    assert_eq!(elements, [10, 12, 20, 22, 30, 32, 40, 42]);
+}
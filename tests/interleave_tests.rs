@@ -0,0 +1,108 @@
+use pas::{InterleaveBuilder, SliceError, ViewDesc};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [u32; 3],
+    pub uv: [u32; 2],
+}
+
+fn data() -> Vec<Vertex> {
+    vec![
+        Vertex {
+            position: [0, 1, 2],
+            uv: [3, 4],
+        },
+        Vertex {
+            position: [5, 6, 7],
+            uv: [8, 9],
+        },
+        Vertex {
+            position: [10, 11, 12],
+            uv: [13, 14],
+        },
+    ]
+}
+
+#[test]
+fn vertex() {
+    let vertices = data();
+    let positions: Vec<[u32; 3]> = vertices.iter().map(|v| v.position).collect();
+    let uvs: Vec<[u32; 2]> = vertices.iter().map(|v| v.uv).collect();
+
+    let view = InterleaveBuilder::new()
+        .push(&positions)
+        .push(&uvs)
+        .build()
+        .unwrap();
+    assert_eq!(view.bytes(), bytemuck::cast_slice::<Vertex, u8>(&vertices));
+
+    let stride = std::mem::size_of::<Vertex>();
+    assert_eq!(view.attribute_count(), 2);
+    assert_eq!(view.desc(0), Some(&ViewDesc::new(0, stride, 3)));
+    assert_eq!(view.desc(1), Some(&ViewDesc::new(12, stride, 3)));
+    assert!(view.view::<[u32; 2]>(1).unwrap().iter().eq(uvs.iter()));
+}
+
+#[test]
+fn mixed_alignment() {
+    let flags: Vec<u8> = vec![1, 2, 3];
+    let weights: Vec<f64> = vec![0.5, 1.5, 2.5];
+    let indices: Vec<[u16; 3]> = vec![[0, 1, 2], [3, 4, 5], [6, 7, 8]];
+
+    let view = InterleaveBuilder::keyed()
+        .insert("flag", &flags)
+        .insert("weight", &weights)
+        .insert("indices", &indices)
+        .build()
+        .unwrap();
+
+    // Laid out like `#[repr(C)] struct { u8, f64, [u16; 3] }`.
+    assert_eq!(view.bytes().len(), 3 * 24);
+    assert_eq!(view.desc(1), Some(&ViewDesc::new(8, 24, 3)));
+    assert_eq!(view.desc(2), Some(&ViewDesc::new(16, 24, 3)));
+
+    let index = view.find(&"flag").unwrap();
+    assert!(view.view::<u8>(index).unwrap().iter().eq(flags.iter()));
+    let index = view.find(&"weight").unwrap();
+    assert!(view.view::<f64>(index).unwrap().iter().eq(weights.iter()));
+    let index = view.find(&"indices").unwrap();
+    assert!(view
+        .view::<[u16; 3]>(index)
+        .unwrap()
+        .iter()
+        .eq(indices.iter()));
+    // Padding is zeroed.
+    assert_eq!(view.bytes()[1..8], [0; 7]);
+}
+
+#[test]
+fn empty() {
+    let positions: Vec<[f32; 3]> = Vec::new();
+    let view = InterleaveBuilder::new().push(&positions).build().unwrap();
+    assert!(view.bytes().is_empty());
+    assert!(view.view::<[f32; 3]>(0).unwrap().is_empty());
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        InterleaveBuilder::new().build().err(),
+        Some(SliceError::NoAttribute)
+    );
+
+    let positions = [[0.0_f32; 3]; 3];
+    let uvs = [[0.0_f32; 2]; 2];
+    assert_eq!(
+        InterleaveBuilder::new()
+            .push(&positions)
+            .push(&uvs)
+            .build()
+            .err(),
+        Some(SliceError::CountMismatch {
+            index: 1,
+            expected: 3,
+            count: 2
+        })
+    );
+}
@@ -0,0 +1,112 @@
+use pas::{JaggedView, JaggedViewMut, Slice, SliceError, SliceMut};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub meshlet: u32,
+}
+
+fn vertices(count: usize) -> Vec<Vertex> {
+    (0..count)
+        .map(|i| Vertex {
+            position: [i as f32, 0.0, -(i as f32)],
+            meshlet: u32::MAX,
+        })
+        .collect()
+}
+
+#[test]
+fn invalid_offsets() {
+    let data = vertices(6);
+    let positions: Slice<[f32; 3]> = Slice::new(&data, 0);
+    let offsets = [0_u32, 3, 2, 6];
+    assert_eq!(
+        JaggedView::new(positions, Slice::native(&offsets)).err(),
+        Some(SliceError::DecreasingOffset {
+            position: 2,
+            offset: 2,
+            previous: 3
+        })
+    );
+    let offsets = [0_u32, 3, 7, 2];
+    assert_eq!(
+        JaggedView::new(positions, Slice::native(&offsets)).err(),
+        Some(SliceError::IndexOutOfBounds {
+            position: 2,
+            index: 7,
+            len: 6
+        })
+    );
+    let error = JaggedView::new(positions, Slice::native(&[u32::MAX])).err();
+    assert!(format!("{:?}", error.unwrap()).contains("position 0"));
+
+    let mut data = vertices(6);
+    let positions: SliceMut<[f32; 3]> = SliceMut::new(&mut data, 0);
+    let offsets = [1_u32, 0];
+    assert!(JaggedViewMut::new(positions, Slice::native(&offsets)).is_err());
+}
+
+#[test]
+fn groups() {
+    let data = vertices(7);
+    let positions: Slice<[f32; 3]> = Slice::new(&data, 0);
+    // The first and last elements belong to no group.
+    let offsets = [1_u32, 3, 3, 6];
+    let view = JaggedView::new(positions, Slice::native(&offsets)).unwrap();
+    assert_eq!(view.group_count(), 3);
+    assert_eq!(
+        (0..3).map(|k| view.group_len(k)).collect::<Vec<_>>(),
+        [2, 0, 3]
+    );
+    assert!(view
+        .group(0)
+        .iter()
+        .eq(&[[1.0, 0.0, -1.0], [2.0, 0.0, -2.0]]));
+    assert!(view.group(1).is_empty());
+    assert!(view
+        .group(2)
+        .iter()
+        .map(|position| position[0])
+        .eq([3.0, 4.0, 5.0]));
+    let lens: Vec<usize> = view.groups().map(|group| group.len()).collect();
+    assert_eq!(lens, [2, 0, 3]);
+
+    // Tables without groups.
+    let empty = JaggedView::new(positions, Slice::native(&[7_u32])).unwrap();
+    assert_eq!(empty.group_count(), 0);
+    assert_eq!(empty.groups().len(), 0);
+    let empty = JaggedView::new(positions, Slice::native(&[] as &[u32])).unwrap();
+    assert_eq!(empty.group_count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "index ouf of bounds")]
+fn group_out_of_bounds() {
+    let data = vertices(4);
+    let offsets = [0_u32, 4];
+    let view = JaggedView::new(Slice::native(&data), Slice::native(&offsets)).unwrap();
+    view.group(1);
+}
+
+#[test]
+fn mutable_groups() {
+    let mut data = vertices(6);
+    let offsets = [0_u32, 2, 2, 5];
+    let meshlets: SliceMut<u32> = SliceMut::new(&mut data, 12);
+    let mut view = JaggedViewMut::new(meshlets, Slice::native(&offsets)).unwrap();
+    assert_eq!(view.groups_mut().len(), 3);
+    for (meshlet, mut group) in view.groups_mut().enumerate() {
+        group.fill(meshlet as u32);
+    }
+    view.group_mut(2)[0] = 20;
+    assert!(view.group(2).iter().eq(&[20, 2, 2]));
+    assert_eq!(view.into_view().group_len(1), 0);
+
+    let meshlets: Vec<u32> = data.iter().map(|vertex| vertex.meshlet).collect();
+    assert_eq!(meshlets, [0, 0, 20, 2, 2, u32::MAX]);
+    assert!(data
+        .iter()
+        .enumerate()
+        .all(|(i, vertex)| vertex.position == [i as f32, 0.0, -(i as f32)]));
+}
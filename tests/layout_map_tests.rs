@@ -0,0 +1,76 @@
+use pas::{render_layout, Slice};
+
+#[repr(C)]
+#[derive(Clone, Copy, Default, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub uv: [f32; 2],
+}
+
+#[test]
+fn render_vertex() {
+    let vertices = [Vertex::default(); 3];
+    let positions: Slice<[f32; 3]> = Slice::new(&vertices, 0);
+    let uvs: Slice<[f32; 2]> = Slice::new(&vertices, 12);
+    let map = render_layout(&[("position", &positions), ("uv", &uvs)], 20);
+    assert_eq!(
+        map,
+        "\
+stride: 20 bytes
+|PPPPPPPPPPPP|UUUUUUUU|
+0            12       20
+P  position  offset 0   size 12  [f32; 3]
+U  uv        offset 12  size 8   [f32; 2]
+"
+    );
+}
+
+#[test]
+fn render_padding() {
+    let data = [[0_u8; 16]; 2];
+    let normals: Slice<[u8; 3]> = Slice::new(&data, 1);
+    let nx: Slice<u8> = Slice::new(&data, 8);
+    let ny: Slice<u16> = Slice::new(&data, 10);
+    // Letters already taken fall back to the first available one.
+    let map = render_layout(&[("normal", &normals), ("n", &nx), ("nn", &ny)], 16);
+    assert_eq!(
+        map,
+        "\
+stride: 16 bytes
+|.|NNN|....|A|.|BB|....|
+0 1   4    8 9 10 12   16
+N  normal   offset 1   size 3  [u8; 3]
+A  n        offset 8   size 1  u8
+B  nn       offset 10  size 2  u16
+.  padding  10 bytes
+"
+    );
+}
+
+#[test]
+fn render_overlap() {
+    let data = [[0_u32; 4]; 2];
+    let color: Slice<[u32; 2]> = Slice::new(&data, 0);
+    let weights: Slice<[u32; 2]> = Slice::new(&data, 4);
+    let id: Slice<u32> = Slice::new(&data, 12);
+    let map = render_layout(&[("color", &color), ("weights", &weights), ("id", &id)], 16);
+    assert_eq!(
+        map,
+        "\
+stride: 16 bytes
+|CCCC|####|WWWW|IIII|
+0    4    8    12   16
+C  color    offset 0   size 8  [u32; 2]
+W  weights  offset 4   size 8  [u32; 2]
+I  id       offset 12  size 4  u32
+#  overlap  color and weights
+"
+    );
+}
+
+#[test]
+fn render_without_stride() {
+    let data = [0_u32; 4];
+    let map = render_layout(&[("value", &Slice::native(&data))], 0);
+    assert_eq!(map, "stride: 0 bytes\nV  value    offset 0  size 4  u32\n");
+}
@@ -0,0 +1,92 @@
+use pas::{locate_in_views, ElementLocation, Slice, SliceMut, StridedInfo};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub uv: [f32; 2],
+}
+
+fn vertices() -> Vec<Vertex> {
+    vec![
+        Vertex {
+            position: [0.0; 3],
+            uv: [0.0; 2],
+        };
+        3
+    ]
+}
+
+fn location(index: usize, offset_within_element: usize, in_gap: bool) -> Option<ElementLocation> {
+    Some(ElementLocation {
+        index,
+        offset_within_element,
+        in_gap,
+    })
+}
+
+#[test]
+fn boundaries() {
+    let data = vertices();
+    let base: *const u8 = data.as_ptr().cast();
+    let at = |offset: usize| base.wrapping_add(offset);
+
+    // Elements at bytes 12..20, 32..40, 52..60.
+    let uvs: Slice<[f32; 2]> = Slice::new(&data, 12);
+    assert_eq!(uvs.ptr_range(), at(12)..at(60));
+    assert_eq!(uvs.locate(at(11)), None);
+    assert_eq!(uvs.locate(at(12)), location(0, 0, false));
+    assert_eq!(uvs.locate(at(19)), location(0, 7, false));
+    assert_eq!(uvs.locate(at(20)), location(0, 8, true));
+    assert_eq!(uvs.locate(at(31)), location(0, 19, true));
+    assert_eq!(uvs.locate(at(32)), location(1, 0, false));
+    assert_eq!(uvs.locate(at(59)), location(2, 7, false));
+    // One past the end, before the end of the buffer.
+    assert_eq!(uvs.locate(at(60)), None);
+
+    // Elements at bytes 0..12, 20..32, 40..52.
+    let positions: Slice<[f32; 3]> = Slice::new(&data, 0);
+    assert_eq!(positions.locate(at(0)), location(0, 0, false));
+    assert_eq!(positions.locate(at(11)), location(0, 11, false));
+    assert_eq!(positions.locate(at(12)), location(0, 12, true));
+    assert_eq!(positions.locate(at(51)), location(2, 11, false));
+    assert_eq!(positions.locate(at(52)), None);
+    assert_eq!(positions.locate(at(60)), None);
+}
+
+#[test]
+fn empty_and_mutable() {
+    let mut data = vertices();
+    let base: *const u8 = data.as_ptr().cast();
+
+    let empty: Slice<f32> = Slice::new(&data[..0], 0);
+    assert!(empty.ptr_range().is_empty());
+    assert_eq!(empty.locate(base), None);
+
+    let xs: SliceMut<f32> = SliceMut::new(&mut data, 0);
+    assert_eq!(xs.locate(base.wrapping_add(24)), location(1, 4, true));
+    assert_eq!(xs.byte_stride(), 20);
+    assert_eq!(xs.element_size(), 4);
+}
+
+#[test]
+fn multiple_views() {
+    let data = vertices();
+    let base: *const u8 = data.as_ptr().cast();
+    let positions: Slice<[f32; 3]> = Slice::new(&data, 0);
+    let uvs: Slice<[f32; 2]> = Slice::new(&data, 12);
+    let views: [(&str, &dyn StridedInfo); 2] = [("position", &positions), ("uv", &uvs)];
+
+    let found = locate_in_views(base.wrapping_add(36), &views);
+    assert_eq!(
+        found,
+        [
+            ("position", location(1, 16, true).unwrap()),
+            ("uv", location(1, 4, false).unwrap()),
+        ]
+    );
+    let found = locate_in_views(base.wrapping_add(4), &views);
+    assert_eq!(found, [("position", location(0, 4, false).unwrap())]);
+    assert!(locate_in_views(base.wrapping_add(60), &views).is_empty());
+    assert!(locate_in_views(std::ptr::null(), &views).is_empty());
+}
@@ -0,0 +1,111 @@
+use pas::{PrefixReport, Slice, SliceMut};
+
+fn values<T: bytemuck::Pod>(slice: &Slice<T>) -> Vec<T> {
+    slice.iter().copied().collect()
+}
+
+#[test]
+fn exact_fit() {
+    let data = [1_u32, 2, 3, 4];
+    let bytes: &[u8] = bytemuck::cast_slice(&data);
+    let (slice, report) = Slice::<u32>::longest_valid_prefix(bytes, 0, 4);
+    assert_eq!(values(&slice), [1, 2, 3, 4]);
+    assert_eq!(report, PrefixReport::default());
+    assert!(report.is_complete());
+}
+
+#[test]
+fn trailing_byte() {
+    let data = [1_u32, 2, 3, 4];
+    let bytes: &[u8] = bytemuck::cast_slice(&data);
+    let (slice, report) = Slice::<u32>::longest_valid_prefix(&bytes[..13], 0, 4);
+    assert_eq!(values(&slice), [1, 2, 3]);
+    assert_eq!(report.dropped_bytes, 1);
+    assert!(!report.offset_clamped);
+    assert!(!report.is_complete());
+}
+
+#[test]
+fn offset_beyond_buffer() {
+    let data = [1_u32, 2];
+    let bytes: &[u8] = bytemuck::cast_slice(&data);
+
+    let (slice, report) = Slice::<u32>::longest_valid_prefix(bytes, 12, 4);
+    assert!(slice.is_empty());
+    assert!(report.offset_clamped);
+    assert_eq!(report.dropped_bytes, 0);
+
+    // Offset at the end of the buffer, nothing to clamp.
+    let (slice, report) = Slice::<u32>::longest_valid_prefix(bytes, 8, 4);
+    assert!(slice.is_empty());
+    assert!(report.is_complete());
+
+    let (slice, report) = Slice::<u32>::new_lossy(&data, 9);
+    assert!(slice.is_empty());
+    assert!(report.offset_clamped);
+}
+
+#[test]
+fn stride_larger_than_buffer() {
+    let data = [1_u32, 2, 3];
+    let bytes: &[u8] = bytemuck::cast_slice(&data);
+    let (slice, report) = Slice::<u32>::longest_valid_prefix(bytes, 4, 64);
+    assert_eq!(values(&slice), [2]);
+    // The next element would start past the end, no partial element is dropped.
+    assert_eq!(report.dropped_bytes, 0);
+}
+
+#[test]
+fn matches_strict_view() {
+    // Interleaved position and index, the last vertex misses its index.
+    let data: [u32; 11] = [0, 1, 2, 100, 3, 4, 5, 101, 6, 7, 8];
+    let bytes: &[u8] = bytemuck::cast_slice(&data);
+    for len in 0..=bytes.len() {
+        let truncated = &bytes[..len];
+        let (positions, report) = Slice::<[u32; 3]>::longest_valid_prefix(truncated, 0, 16);
+        if !positions.is_empty() {
+            let strict = Slice::<[u32; 3]>::raw(&truncated[..len - report.dropped_bytes], 0, 16);
+            assert_eq!(values(&positions), values(&strict));
+        }
+        let (indices, report) = Slice::<u32>::longest_valid_prefix(truncated, 12, 16);
+        if !indices.is_empty() {
+            let strict = Slice::<u32>::raw(&truncated[..len - report.dropped_bytes], 12, 16);
+            assert_eq!(values(&indices), values(&strict));
+        }
+    }
+}
+
+#[test]
+fn mutable_prefix() {
+    let mut data = [0_u32; 4];
+    let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut data);
+    let (mut slice, report) = SliceMut::<u32>::longest_valid_prefix(&mut bytes[..15], 0, 4);
+    assert_eq!(slice.len(), 3);
+    assert_eq!(report.dropped_bytes, 3);
+    for i in 0..slice.len() {
+        slice[i] = i as u32 + 1;
+    }
+    assert_eq!(data, [1, 2, 3, 0]);
+
+    let mut bytes = [0_u8; 8];
+    let (slice, report) = SliceMut::<u32>::longest_valid_prefix(&mut bytes, 10, 4);
+    assert!(slice.is_empty());
+    assert!(report.offset_clamped);
+}
+
+#[test]
+fn typed_lossy() {
+    let mut data = [[1_u32, 2, 3], [4, 5, 6]];
+    let (slice, report) = Slice::<u32>::new_lossy(&data, 8);
+    assert_eq!(values(&slice), [3, 6]);
+    assert_eq!(report.dropped_bytes, 0);
+
+    let (mut slice, report) = SliceMut::<[u32; 2]>::new_lossy(&mut data, 4);
+    assert!(report.is_complete());
+    slice[1] = [0, 0];
+    assert_eq!(data, [[1, 2, 3], [4, 0, 0]]);
+
+    let (slice, report) = SliceMut::<u32>::new_lossy(&mut data, 28);
+    assert!(slice.is_empty());
+    assert!(report.offset_clamped);
+}
@@ -1,4 +1,6 @@
-use pas::{slice, slice_attr, slice_attr_mut, slice_mut, Slice, SliceMut};
+use pas::{
+    slice, slice_attr, slice_attr_mut, slice_components, slice_mut, ComponentSlice, Slice, SliceMut,
+};
 
 #[repr(C)]
 #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
@@ -33,11 +35,14 @@ macro_rules! slice_attr_tests {
                 #[allow(unused_mut)]
                 let mut vertices = data();
 
-                let slice = $slice_attr!(vertices, [0].position);
+                #[allow(unused_mut)]
+                let mut slice = $slice_attr!(vertices, [0].position);
                 assert!(slice.iter().eq([[0, 1, 2], [5, 6, 7], [10, 11, 12]].iter()));
-                let slice = $slice_attr!(vertices, [1].position);
+                #[allow(unused_mut)]
+                let mut slice = $slice_attr!(vertices, [1].position);
                 assert!(slice.iter().eq([[5, 6, 7], [10, 11, 12]].iter()));
-                let slice = $slice_attr!(vertices, [2].position);
+                #[allow(unused_mut)]
+                let mut slice = $slice_attr!(vertices, [2].position);
                 assert!(slice.iter().eq([[10, 11, 12]].iter()));
 
                 let slice = slice_attr!(vertices, [0].uv);
@@ -53,10 +58,12 @@ macro_rules! slice_attr_tests {
                 #[allow(unused_mut)]
                 let mut values = vec![0, 1, 2, 3, 4, 5];
 
-                let slice = $slice_attr!(1, values, [0]);
+                #[allow(unused_mut)]
+                let mut slice = $slice_attr!(1, values, [0]);
                 assert!(slice.iter().eq([0, 1, 2, 3, 4, 5].iter()));
 
-                let slice = $slice_attr!(2, values, [1]);
+                #[allow(unused_mut)]
+                let mut slice = $slice_attr!(2, values, [1]);
                 assert!(slice.iter().eq([1, 3, 5].iter()));
             }
         }
@@ -72,15 +79,18 @@ macro_rules! slice_tests {
                 #[allow(unused_mut)]
                 let mut vertices = data();
 
-                let slice: $type<u32> = $slice!(vertices, [1].position);
+                #[allow(unused_mut)]
+                let mut slice: $type<u32> = $slice!(vertices, [1].position);
                 assert_eq!(slice.len(), 2);
                 assert!(slice.iter().eq([5, 10].iter()));
 
-                let slice: $type<u32> = $slice!(vertices, [1].position[1]);
+                #[allow(unused_mut)]
+                let mut slice: $type<u32> = $slice!(vertices, [1].position[1]);
                 assert_eq!(slice.len(), 2);
                 assert!(slice.iter().eq([6, 11].iter()));
 
-                let slice: $type<u32> = $slice!(vertices, [0].uv);
+                #[allow(unused_mut)]
+                let mut slice: $type<u32> = $slice!(vertices, [0].uv);
                 assert_eq!(slice.len(), 3);
                 assert!(slice.iter().eq([3, 8, 13].iter()));
             }
@@ -90,10 +100,12 @@ macro_rules! slice_tests {
                 #[allow(unused_mut)]
                 let mut values = vec![0, 1, 2, 3, 4, 5];
 
-                let slice: $type<u32> = $slice!(1, values, [0]);
+                #[allow(unused_mut)]
+                let mut slice: $type<u32> = $slice!(1, values, [0]);
                 assert!(slice.iter().eq([0, 1, 2, 3, 4, 5].iter()));
 
-                let slice: $type<u32> = $slice!(2, values, [1]);
+                #[allow(unused_mut)]
+                let mut slice: $type<u32> = $slice!(2, values, [1]);
                 assert!(slice.iter().eq([1, 3, 5].iter()));
             }
         }
@@ -114,3 +126,224 @@ fn from_slice() {
     let _ = slice_attr!(&mut values, [0]);
     let _: SliceMut<u32> = slice_mut!(&mut values, [0]);
 }
+
+#[test]
+fn byte_offsets() {
+    let vertices = data();
+    let stride = std::mem::size_of::<Vertex>();
+    let uv_offset = std::mem::offset_of!(Vertex, uv);
+
+    let uvs = slice_attr!(vertices, [0].uv);
+    assert_eq!(uvs.byte_offset(), uv_offset);
+    assert!(uvs
+        .iter_byte_offsets()
+        .eq((0..3).map(|i| i * stride + uv_offset)));
+    for i in 0..3 {
+        assert_eq!(uvs.byte_offset_of(i), Some(i * stride + uv_offset));
+        let start = i * stride + uv_offset;
+        assert_eq!(uvs.element_range(i), Some(start..start + 8));
+    }
+    assert_eq!(uvs.byte_offset_of(3), None);
+    assert_eq!(uvs.element_range(3), None);
+
+    // Offsets are relative to the original buffer, not the view start.
+    let uvs = slice_attr!(vertices, [1].uv);
+    assert_eq!(uvs.byte_offset_of(0), Some(stride + uv_offset));
+    assert_eq!(uvs.byte_offset_of(2), None);
+
+    let empty: Slice<u32> = Slice::default();
+    assert_eq!(empty.iter_byte_offsets().count(), 0);
+    assert_eq!(empty.byte_offset_of(0), None);
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Transform {
+    pub translation: [f32; 3],
+    pub scale: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Bone {
+    pub index: u32,
+    pub weight: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Pair(pub u32, pub [u16; 2]);
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Skinned {
+    pub transform: Transform,
+    pub bones: [Bone; 3],
+    pub pair: Pair,
+}
+
+fn skinned() -> Vec<Skinned> {
+    (0..4)
+        .map(|i| {
+            let f = i as f32;
+            Skinned {
+                transform: Transform {
+                    translation: [f, f + 0.1, f + 0.2],
+                    scale: f * 10.0,
+                },
+                bones: [
+                    Bone {
+                        index: i,
+                        weight: f + 0.25,
+                    },
+                    Bone {
+                        index: i + 10,
+                        weight: f + 0.5,
+                    },
+                    Bone {
+                        index: i + 20,
+                        weight: f + 0.75,
+                    },
+                ],
+                pair: Pair(i * 100, [i as u16, i as u16 + 1]),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn nested_struct_fields() {
+    let mut data = skinned();
+    let stride = std::mem::size_of::<Skinned>();
+
+    let translations = slice_attr!(data, [0].transform.translation);
+    let _: &Slice<[f32; 3]> = &translations;
+    assert_eq!(translations.stride(), stride);
+    assert_eq!(translations.len(), 4);
+    assert_eq!(translations[3], [3.0, 3.1, 3.2]);
+
+    let scales = slice_attr!(data, [1].transform.scale);
+    assert_eq!(
+        scales.byte_offset(),
+        stride + std::mem::offset_of!(Transform, scale)
+    );
+    assert!(scales.iter().eq([10.0, 20.0, 30.0].iter()));
+
+    // Three levels deep
+    let y: Slice<f32> = slice!(data, [0].transform.translation[1]);
+    assert!(y.iter().eq([0.1, 1.1, 2.1, 3.1].iter()));
+
+    let mut scales = slice_attr_mut!(data, [0].transform.scale);
+    scales[2] = -1.0;
+    assert_eq!(data[2].transform.scale, -1.0);
+    assert_eq!(data[2].transform.translation, [2.0, 2.1, 2.2]);
+}
+
+#[test]
+fn tuple_struct_fields() {
+    let mut data = skinned();
+
+    let first = slice_attr!(data, [0].pair.0);
+    assert!(first.iter().eq([0, 100, 200, 300].iter()));
+
+    let second = slice_attr!(data, [0].pair.1);
+    assert_eq!(second[1], [1, 2]);
+    let component = slice_attr!(data, [0].pair.1[1]);
+    assert!(component.iter().eq([1, 2, 3, 4].iter()));
+
+    let mut first = slice_attr_mut!(data, [1].pair.0);
+    first[0] = 7;
+    assert_eq!(data[1].pair.0, 7);
+}
+
+#[test]
+fn nested_array_fields() {
+    let mut data = skinned();
+    let stride = std::mem::size_of::<Skinned>();
+
+    // Literal inner index
+    let weights = slice_attr!(data, [0].bones[2].weight);
+    let _: &Slice<f32> = &weights;
+    assert_eq!(weights.stride(), stride);
+    assert_eq!(
+        weights.byte_offset(),
+        std::mem::offset_of!(Skinned, bones) + 2 * std::mem::size_of::<Bone>() + 4
+    );
+    assert!(weights.iter().eq([0.75, 1.75, 2.75, 3.75].iter()));
+
+    // Runtime inner index
+    for bone in 0..3 {
+        let indices = slice_attr!(data, [1].bones[bone].index);
+        assert_eq!(indices.len(), 3);
+        let expected: Vec<u32> = (1..4).map(|i| i + bone as u32 * 10).collect();
+        assert!(indices.iter().eq(expected.iter()));
+    }
+
+    // Whole sub-struct
+    let bones = slice_attr!(data, [0].bones[1]);
+    assert_eq!(bones[3].index, 13);
+
+    let start = 2;
+    let mut weights = slice_attr_mut!(data, [start].bones[start - 1].weight);
+    weights[1] = 42.0;
+    assert_eq!(data[3].bones[1].weight, 42.0);
+    assert_eq!(data[3].bones[0].weight, 3.25);
+    assert_eq!(data[3].bones[2].weight, 3.75);
+
+    // Strided, skipping every other element
+    let scales = slice_attr!(2, data, [0].transform.scale);
+    assert!(scales.iter().eq([0.0, 20.0].iter()));
+}
+
+mod qualified_paths {
+    // Macros must work without being imported, and without `pas` items in scope.
+    #[test]
+    fn macros_without_imports() {
+        let mut data = super::skinned();
+        let scales = pas::slice_attr!(data, [0].transform.scale);
+        assert_eq!(scales.len(), 4);
+        let scales: pas::Slice<f32> = pas::slice!(data, [0].transform.scale);
+        assert_eq!(scales.len(), 4);
+        let scales = pas::slice_attr_mut!(data, [0].transform.scale);
+        assert_eq!(scales.len(), 4);
+        let scales: pas::SliceMut<f32> = pas::slice_mut!(data, [0].transform.scale);
+        assert_eq!(scales.len(), 4);
+        let view = pas::Slice::native(&data);
+        let scales = pas::rebase!(view, transform.scale).unwrap();
+        assert_eq!(scales.len(), 4);
+    }
+}
+
+#[test]
+fn slice_field_size() {
+    // Larger types fail after monomorphization, which requires
+    // a pass test so that trybuild builds instead of only checking.
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui-macros/slice_narrowing.rs");
+    t.compile_fail("tests/ui-macros/slice_larger_than_field.rs");
+}
+
+#[test]
+fn slice_components() {
+    let vertices = data();
+
+    let positions: ComponentSlice<u32, 3> = slice_components!(vertices, [0].position);
+    assert_eq!(positions.len(), 3 * vertices.len());
+    assert!(positions.iter().copied().eq([0, 1, 2, 5, 6, 7, 10, 11, 12]));
+    assert_eq!(positions.get(4), Some(&6));
+    assert_eq!(positions.get(8), Some(&12));
+    assert_eq!(positions.get(9), None);
+    assert_eq!(format!("{:?}", positions), "[0, 1, 2, 5, 6, 7, 10, 11, 12]");
+
+    let uvs = slice_components!(vertices, [1].uv);
+    assert_eq!(uvs.len(), 4);
+    assert!(uvs.iter().copied().eq([8, 9, 13, 14]));
+
+    let every_other = slice_components!(2, vertices, [0].uv);
+    assert!(every_other.iter().copied().eq([3, 4, 13, 14]));
+
+    let view = every_other.into_slice();
+    assert!(view.iter().eq([[3, 4], [13, 14]].iter()));
+    let empty: ComponentSlice<u32, 2> = Slice::<[u32; 2]>::default().components();
+    assert!(empty.is_empty() && empty.iter().next().is_none());
+}
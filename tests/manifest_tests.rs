@@ -0,0 +1,194 @@
+#![cfg(feature = "serde")]
+
+use pas::{
+    tagged_pod, AttributeSet, ImportError, ImportIssue, LayoutManifest, ManifestAttribute, Slice,
+    SliceError,
+};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub uv: [f32; 2],
+}
+
+fn vertices() -> Vec<Vertex> {
+    (0..5)
+        .map(|i| Vertex {
+            position: [i as f32, 1.0, 2.0],
+            uv: [0.5, i as f32],
+        })
+        .collect()
+}
+
+#[test]
+fn round_trip() {
+    let vertices = vertices();
+    let ids = [3_u16, 1, 4, 1, 5];
+
+    let mut set = AttributeSet::new();
+    set.insert::<[f32; 3]>("position", Slice::new(&vertices, 0));
+    set.insert::<u16>("id", Slice::native(&ids));
+    set.insert::<[f32; 2]>("uv", Slice::new(&vertices, 12));
+
+    let manifest = set.export_layout();
+    assert_eq!(manifest.buffer_count, 2);
+    assert_eq!(
+        manifest.attributes[2],
+        ManifestAttribute {
+            name: "uv".into(),
+            format: "F32x2".into(),
+            element_size: 8,
+            offset: 12,
+            stride: 20,
+            count: 5,
+            buffer: 0
+        }
+    );
+    assert_eq!(manifest.attributes[1].format, "U16");
+    assert_eq!(manifest.attributes[1].buffer, 1);
+
+    let json = serde_json::to_string(&manifest).unwrap();
+    let manifest: LayoutManifest = serde_json::from_str(&json).unwrap();
+    let buffers: [&[u8]; 2] = [bytemuck::cast_slice(&vertices), bytemuck::cast_slice(&ids)];
+    let imported = AttributeSet::import_layout(&manifest, &buffers).unwrap();
+    assert_eq!(imported.export_layout(), manifest);
+
+    let names: Vec<&str> = imported.iter().map(|(name, _)| name).collect();
+    assert_eq!(names, ["position", "id", "uv"]);
+    for (name, slice) in set.iter() {
+        let other = imported.get_erased(name).unwrap();
+        assert_eq!(other.tag(), slice.tag());
+    }
+    let positions = imported.get::<[f32; 3]>("position").unwrap();
+    assert!(positions.iter().eq(vertices.iter().map(|v| &v.position)));
+    let ids_view = imported.get::<u16>("id").unwrap();
+    assert!(ids_view.iter().eq(ids.iter()));
+    let uvs = imported.get::<[f32; 2]>("uv").unwrap();
+    assert!(uvs.iter().eq(vertices.iter().map(|v| &v.uv)));
+    assert_eq!(uvs.byte_offset(), 12);
+}
+
+#[test]
+fn export_formats() {
+    #[repr(transparent)]
+    #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+    struct Color([u8; 4]);
+    #[repr(transparent)]
+    #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+    struct Joint(u32);
+    tagged_pod!(Color => Unorm8x4, Joint => "skin::Joint");
+
+    let colors = [Color([255; 4]); 2];
+    let joints = [Joint(0); 2];
+    let mut set = AttributeSet::new();
+    set.insert("color", Slice::native(&colors));
+    set.insert("joint", Slice::native(&joints));
+    set.insert_erased("raw", Slice::native(&[0_u64; 2]).into());
+    let manifest = set.export_layout();
+    let formats: Vec<&str> = manifest
+        .attributes
+        .iter()
+        .map(|attribute| attribute.format.as_str())
+        .collect();
+    assert_eq!(formats, ["Unorm8x4", "skin::Joint", "u64"]);
+
+    // Normalized views are only retrieved with a normalized type.
+    let bytes: &[u8] = bytemuck::cast_slice(&colors);
+    let mut manifest = manifest;
+    manifest.attributes.truncate(1);
+    let imported = AttributeSet::import_layout(&manifest, &[bytes]).unwrap();
+    assert!(imported.get::<Color>("color").is_ok());
+    assert!(imported.get::<[u8; 4]>("color").is_err());
+}
+
+#[test]
+fn corrupt_manifest() {
+    let vertices = vertices();
+    let mut set = AttributeSet::new();
+    set.insert::<[f32; 3]>("position", Slice::new(&vertices, 0));
+    set.insert::<[f32; 2]>("uv", Slice::new(&vertices, 12));
+    let valid = set.export_layout();
+
+    let mut manifest = valid.clone();
+    let uv = manifest.attributes[1].clone();
+    manifest.attributes = vec![
+        ManifestAttribute {
+            format: "F32x5".into(),
+            ..uv.clone()
+        },
+        ManifestAttribute {
+            name: "overflow".into(),
+            offset: usize::MAX - 4,
+            ..uv.clone()
+        },
+        ManifestAttribute {
+            name: "buffer".into(),
+            buffer: 1,
+            ..uv.clone()
+        },
+        ManifestAttribute {
+            name: "size".into(),
+            element_size: 12,
+            ..uv.clone()
+        },
+        ManifestAttribute {
+            name: "bounds".into(),
+            count: 6,
+            ..uv.clone()
+        },
+        valid.attributes[0].clone(),
+        valid.attributes[0].clone(),
+    ];
+
+    let bytes: &[u8] = bytemuck::cast_slice(&vertices);
+    let err = AttributeSet::import_layout(&manifest, &[bytes]).unwrap_err();
+    assert_eq!(
+        err,
+        ImportError {
+            issues: vec![
+                ImportIssue::UnknownFormat {
+                    name: "uv".into(),
+                    format: "F32x5".into()
+                },
+                ImportIssue::InvalidView {
+                    name: "overflow".into(),
+                    error: SliceError::Overflow
+                },
+                ImportIssue::UnknownBuffer {
+                    name: "buffer".into(),
+                    buffer: 1,
+                    count: 1
+                },
+                ImportIssue::ElementSizeMismatch {
+                    name: "size".into(),
+                    element_size: 12,
+                    expected: 8
+                },
+                ImportIssue::InvalidView {
+                    name: "bounds".into(),
+                    error: SliceError::OffsetOutOfBounds {
+                        size: 100,
+                        offset: 120
+                    }
+                },
+                ImportIssue::Duplicate {
+                    name: "position".into()
+                },
+            ]
+        }
+    );
+
+    // Misaligned offsets are rejected like any view.
+    let mut manifest = valid;
+    manifest.attributes[1].offset = 14;
+    manifest.attributes[1].count = 4;
+    let err = AttributeSet::import_layout(&manifest, &[bytes]).unwrap_err();
+    assert!(matches!(
+        err.issues[..],
+        [ImportIssue::InvalidView {
+            error: SliceError::AlignmentFault { .. },
+            ..
+        }]
+    ));
+}
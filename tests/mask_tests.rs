@@ -0,0 +1,135 @@
+use pas::{filter_gather, BitMask, Slice, SliceError, SliceMut};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub uv: [f32; 2],
+}
+
+fn vertices(count: usize) -> Vec<Vertex> {
+    (0..count)
+        .map(|i| Vertex {
+            position: [i as f32, (i % 7) as f32, 0.0],
+            uv: [(i % 3) as f32, 1.0],
+        })
+        .collect()
+}
+
+#[test]
+fn from_predicate() {
+    let data = vertices(200);
+    let positions: Slice<[f32; 3]> = Slice::new(&data, 0);
+    let mask = BitMask::from_predicate(&positions, |p| p[1] == 0.0);
+    assert_eq!(mask.len(), 200);
+    for (i, vertex) in data.iter().enumerate() {
+        assert_eq!(mask.get(i), vertex.position[1] == 0.0);
+    }
+    assert_eq!(
+        mask.count_ones(),
+        data.iter().filter(|v| v.position[1] == 0.0).count()
+    );
+
+    let empty = BitMask::from_predicate(&Slice::<u32>::native(&[]), |_| true);
+    assert!(empty.is_empty());
+    assert_eq!(empty.iter_ones().count(), 0);
+}
+
+#[test]
+fn filter_gather_reference() {
+    let data = vertices(300);
+    let uvs: Slice<[f32; 2]> = Slice::new(&data, 12);
+    let mask = BitMask::from_predicate(&uvs, |uv| uv[0] == 1.0);
+    let expected: Vec<[f32; 2]> = data
+        .iter()
+        .map(|v| v.uv)
+        .filter(|uv| uv[0] == 1.0)
+        .collect();
+
+    // Interleaved destination.
+    let mut dst = vec![[[0.0_f32; 2]; 2]; mask.count_ones()];
+    let count = filter_gather(&uvs, &mask, &mut SliceMut::new(&mut dst, 8));
+    assert_eq!(count, expected.len());
+    assert!(dst.iter().map(|d| d[1]).eq(expected.iter().copied()));
+    assert!(dst.iter().all(|d| d[0] == [0.0; 2]));
+
+    // Stops once the destination is full.
+    let mut dst = [[0.0_f32; 2]; 3];
+    let count = filter_gather(&uvs, &mask, &mut SliceMut::native(&mut dst));
+    assert_eq!(count, 3);
+    assert_eq!(dst, expected[..3]);
+}
+
+#[test]
+#[should_panic]
+fn filter_gather_length_mismatch() {
+    let data = [1_u32, 2, 3];
+    let mut dst = [0_u32; 3];
+    filter_gather(
+        &Slice::native(&data),
+        &BitMask::new(2),
+        &mut SliceMut::native(&mut dst),
+    );
+}
+
+#[test]
+fn bitwise() {
+    let values: Vec<u32> = (0..130).collect();
+    let values = Slice::native(&values);
+    let even = BitMask::from_predicate(&values, |v| v % 2 == 0);
+    let small = BitMask::from_predicate(&values, |v| *v < 70);
+
+    let and = even.and(&small).unwrap();
+    assert!(and.iter_ones().eq((0..70).step_by(2)));
+    let or = even.or(&small).unwrap();
+    assert!(or.iter_ones().eq((0..70).chain((70..130).step_by(2))));
+    let xor = even.xor(&small).unwrap();
+    assert!(xor
+        .iter_ones()
+        .eq((1..70).step_by(2).chain((70..130).step_by(2))));
+
+    // Bits past the length stay cleared.
+    let odd = even.not();
+    assert_eq!(odd.count_ones(), 65);
+    assert!(odd.iter_ones().eq((1..130).step_by(2)));
+    assert_eq!(odd.not(), even);
+    assert_eq!(BitMask::new(130).not().count_ones(), 130);
+
+    let short = BitMask::new(129);
+    let mismatch = Err(SliceError::LengthMismatch {
+        expected: 130,
+        len: 129,
+    });
+    assert_eq!(even.and(&short), mismatch);
+    assert_eq!(even.or(&short), mismatch);
+    assert_eq!(even.xor(&short), mismatch);
+}
+
+#[test]
+fn set_indices_across_words() {
+    let mut mask = BitMask::new(200);
+    let indices = [0, 1, 62, 63, 64, 65, 127, 128, 191, 192, 199];
+    for index in indices {
+        mask.set(index, true);
+    }
+    assert!(mask.iter_ones().eq(indices));
+    assert_eq!(mask.count_ones(), indices.len());
+
+    mask.set(64, false);
+    mask.set(64, false);
+    mask.set(199, false);
+    assert!(!mask.get(64));
+    assert!(mask.iter_ones().eq([0, 1, 62, 63, 65, 127, 128, 191, 192]));
+
+    // Full words.
+    let mut full = BitMask::new(128);
+    (0..128).for_each(|i| full.set(i, true));
+    assert!(full.iter_ones().eq(0..128));
+}
+
+#[test]
+#[should_panic]
+fn get_out_of_bounds() {
+    // The last word has room for more bits, which are still out of bounds.
+    BitMask::new(10).get(10);
+}
@@ -0,0 +1,154 @@
+#![cfg(feature = "nalgebra")]
+
+use nalgebra::{Matrix3, Point3, Vector3};
+use pas::{slice_attr, slice_attr_mut, split_attr_read_write, Permissive, Slice, SliceMut};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub uv: [f32; 2],
+}
+
+pub fn data() -> Vec<Vertex> {
+    vec![
+        Vertex {
+            position: [0.0, 1.0, 2.0],
+            uv: [3.0, 4.0],
+        },
+        Vertex {
+            position: [5.0, 6.0, 7.0],
+            uv: [8.0, 9.0],
+        },
+        Vertex {
+            position: [10.0, 11.0, 12.0],
+            uv: [13.0, 14.0],
+        },
+    ]
+}
+
+#[test]
+fn iter_points_and_vectors() {
+    let vertices = data();
+    let positions = slice_attr!(vertices, [0].position);
+
+    let points: Vec<Point3<f32>> = positions.iter_points().collect();
+    assert_eq!(
+        points,
+        [
+            Point3::new(0.0, 1.0, 2.0),
+            Point3::new(5.0, 6.0, 7.0),
+            Point3::new(10.0, 11.0, 12.0)
+        ]
+    );
+    let vectors: Vec<Vector3<f32>> = positions.iter_vectors().collect();
+    assert_eq!(vectors[1], Vector3::new(5.0, 6.0, 7.0));
+}
+
+#[test]
+fn copy_from_points() {
+    let mut vertices = data();
+    let mut positions = slice_attr_mut!(vertices, [0].position);
+    positions.copy_from_points(&[Point3::new(20.0, 21.0, 22.0), Point3::new(23.0, 24.0, 25.0)]);
+    positions.copy_from_vectors(&[Vector3::new(30.0, 31.0, 32.0)]);
+
+    assert_eq!(vertices[0].position, [30.0, 31.0, 32.0]);
+    assert_eq!(vertices[1].position, [23.0, 24.0, 25.0]);
+    assert_eq!(vertices[2].position, [10.0, 11.0, 12.0]);
+    // Uvs shouldn't be affected
+    assert_eq!(vertices[0].uv, [3.0, 4.0]);
+    assert_eq!(vertices[1].uv, [8.0, 9.0]);
+
+    // Round trip
+    let points: Vec<Point3<f32>> = slice_attr!(vertices, [0].position).iter_points().collect();
+    let mut other = data();
+    slice_attr_mut!(other, [0].position).copy_from_points(&points);
+    assert!(slice_attr!(other, [0].position)
+        .iter()
+        .eq(slice_attr!(vertices, [0].position).iter()));
+}
+
+#[test]
+#[should_panic]
+fn copy_from_points_too_large() {
+    let mut vertices = data();
+    let mut positions = slice_attr_mut!(vertices, [2].position);
+    positions.copy_from_points(&[Point3::origin(), Point3::origin()]);
+}
+
+#[test]
+fn matrix_view() {
+    let vertices = data();
+    let positions = slice_attr!(vertices, [0].position);
+
+    let matrix = positions.as_matrix_view().unwrap();
+    assert_eq!(matrix.shape(), (3, 3));
+    assert_eq!(matrix.column(1), Vector3::new(5.0, 6.0, 7.0));
+
+    let scale = Matrix3::from_diagonal(&Vector3::new(2.0, 3.0, 4.0));
+    let scaled = scale * matrix;
+    assert_eq!(scaled.column(0), Vector3::new(0.0, 3.0, 8.0));
+    assert_eq!(scaled.column(2), Vector3::new(20.0, 33.0, 48.0));
+
+    let uvs = slice_attr!(vertices, [1].uv);
+    let matrix = uvs.as_matrix_view().unwrap();
+    assert_eq!(matrix.shape(), (2, 2));
+    assert_eq!(matrix[(1, 1)], 14.0);
+
+    let empty: Slice<[f32; 3]> = Slice::default();
+    assert_eq!(empty.as_matrix_view().unwrap().shape(), (3, 0));
+}
+
+#[test]
+fn matrix_view_mut() {
+    let mut vertices = data();
+    let mut positions = slice_attr_mut!(vertices, [0].position);
+    let mut matrix = positions.as_matrix_view_mut().unwrap();
+    matrix.row_mut(1).fill(-1.0);
+
+    assert_eq!(vertices[0].position, [0.0, -1.0, 2.0]);
+    assert_eq!(vertices[2].position, [10.0, -1.0, 12.0]);
+    assert_eq!(vertices[2].uv, [13.0, 14.0]);
+}
+
+#[test]
+fn matrix_view_split() {
+    let mut vertices = data();
+    let (positions, mut uvs): (Slice<[f32; 3]>, SliceMut<[f32; 2]>) =
+        split_attr_read_write(&mut vertices, 0, 12).unwrap();
+    // Both matrices are alive at the same time, over interleaved columns.
+    let read = positions.as_matrix_view().unwrap();
+    let mut write = uvs.as_matrix_view_mut().unwrap();
+    for (mut uv, position) in write.column_iter_mut().zip(read.column_iter()) {
+        uv[0] = position[2];
+    }
+    write[(1, 2)] = read[(0, 2)];
+    assert_eq!(read.column(2)[1], 11.0);
+
+    assert_eq!(vertices[1].uv, [7.0, 9.0]);
+    assert_eq!(vertices[2].uv, [12.0, 10.0]);
+
+    // Empty views have no column.
+    let mut empty: Vec<Vertex> = Vec::new();
+    let (positions, mut uvs): (Slice<[f32; 3]>, SliceMut<[f32; 2]>) =
+        split_attr_read_write(&mut empty, 0, 12).unwrap();
+    assert_eq!(positions.as_matrix_view().unwrap().ncols(), 0);
+    assert_eq!(uvs.as_matrix_view_mut().unwrap().ncols(), 0);
+}
+
+#[test]
+fn matrix_view_incompatible_stride() {
+    let mut data = [0_u16; 8];
+    let bytes: &[u8] = bytemuck::cast_slice(&data);
+    let slice: Slice<[u16; 2]> = Slice::raw(bytes, 0, 6);
+    assert!(slice.as_matrix_view().is_some());
+    // Can't be aligned for `u16` elements with an odd stride. Only single
+    // element slices are allowed to have such a stride.
+    let slice: Slice<[u16; 2]> = Slice::with_policy::<Permissive>(&bytes[..5], 0, 5).unwrap();
+    assert!(slice.as_matrix_view().is_none());
+
+    let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut data);
+    let mut slice: SliceMut<[u16; 2]> =
+        SliceMut::with_policy::<Permissive>(&mut bytes[..5], 0, 5).unwrap();
+    assert!(slice.as_matrix_view_mut().is_none());
+}
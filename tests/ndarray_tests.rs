@@ -0,0 +1,139 @@
+#![cfg(feature = "ndarray")]
+
+use ndarray::{s, Array2, ArrayView1, ArrayView2, ArrayViewMut1};
+use pas::{Slice, SliceError, SliceMut};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub uv: [f32; 2],
+}
+
+pub fn data() -> Vec<Vertex> {
+    (0..4)
+        .map(|i| Vertex {
+            position: [i as f32, i as f32 + 0.25, i as f32 + 0.5],
+            uv: [i as f32 * 10.0, i as f32 * 20.0],
+        })
+        .collect()
+}
+
+#[test]
+fn round_trip_1d() {
+    let vertices = data();
+    let slice: Slice<f32> = Slice::new(&vertices, 4);
+    let view = slice.as_array_view().unwrap();
+    assert_eq!(view.len(), 4);
+    assert_eq!(view.strides(), &[5]);
+    assert!(view.iter().eq(slice.iter()));
+
+    let back = Slice::from_array_view(&view).unwrap();
+    assert_eq!(back.stride(), slice.stride());
+    assert!(back.iter().eq(slice.iter()));
+
+    // Empty
+    let slice: Slice<f32> = Slice::default();
+    let view = slice.as_array_view().unwrap();
+    assert!(view.is_empty());
+    assert!(Slice::from_array_view(&view).unwrap().is_empty());
+}
+
+#[test]
+fn round_trip_2d() {
+    let vertices = data();
+    let slice: Slice<[f32; 3]> = Slice::new(&vertices, 0);
+    let view = slice.as_array_view2().unwrap();
+    assert_eq!(view.shape(), &[4, 3]);
+    assert_eq!(view.strides(), &[5, 1]);
+    assert_eq!(view[(2, 1)], 2.25);
+    assert_eq!(view.column(2).to_vec(), [0.5, 1.5, 2.5, 3.5]);
+
+    let back = Slice::<[f32; 3]>::from_array_view2(&view).unwrap();
+    assert!(back.iter().eq(slice.iter()));
+
+    // Every other row of an owned array.
+    let array = Array2::from_shape_fn((6, 2), |(i, j)| (i * 2 + j) as u32);
+    let rows = array.slice(s![..;2, ..]);
+    let slice = Slice::<[u32; 2]>::from_array_view2(&rows).unwrap();
+    assert_eq!(slice.stride(), 16);
+    assert!(slice.iter().eq([[0, 1], [4, 5], [8, 9]].iter()));
+}
+
+#[test]
+fn mutable() {
+    let mut vertices = data();
+    let mut slice: SliceMut<[f32; 2]> = SliceMut::new(&mut vertices, 12);
+    slice.as_array_view2_mut().unwrap().column_mut(1).fill(-1.0);
+    let mut slice: SliceMut<f32> = SliceMut::new(&mut vertices, 12);
+    slice
+        .as_array_view_mut()
+        .unwrap()
+        .map_inplace(|v| *v += 1.0);
+    assert!(vertices
+        .iter()
+        .enumerate()
+        .all(|(i, v)| v.uv == [i as f32 * 10.0 + 1.0, -1.0]));
+
+    let mut values = [0_u32; 6];
+    let view = ArrayViewMut1::from(&mut values).slice_move(s![1..;2]);
+    let mut slice = SliceMut::from_array_view_mut(view).unwrap();
+    slice.fill(7);
+    assert_eq!(values, [0, 7, 0, 7, 0, 7]);
+}
+
+#[test]
+fn disjoint_columns() {
+    let mut array = Array2::<f32>::zeros((4, 2));
+    let (x, y) = array.multi_slice_mut((s![.., 0], s![.., 1]));
+    let x = SliceMut::from_array_view_mut(x).unwrap();
+    let mut y = SliceMut::from_array_view_mut(y).unwrap();
+    // The next `f32` of each row belongs to the other column.
+    assert!(matches!(
+        x.rebase::<f32>(4),
+        Err(SliceError::AttributeCrossesElementBoundary { .. })
+    ));
+    y.fill(1.0);
+    assert_eq!(array.column(1).sum(), 4.0);
+}
+
+#[test]
+fn rejected_layouts() {
+    // Stride isn't a multiple of the element size.
+    let bytes = [0_u8; 12];
+    let slice: Slice<[u8; 2]> = Slice::raw(&bytes, 0, 3);
+    assert!(slice.as_array_view().is_none());
+    let slice: Slice<[[u8; 2]; 2]> = Slice::raw(&bytes, 0, 5);
+    assert!(slice.as_array_view2().is_none());
+    let slice: Slice<[[u8; 2]; 2]> = Slice::raw(&bytes, 0, 6);
+    assert_eq!(slice.as_array_view2().unwrap().strides(), &[3, 1]);
+
+    // Negative strides
+    let values = [0_u32, 1, 2, 3];
+    let view = ArrayView1::from(&values);
+    assert_eq!(
+        Slice::from_array_view(&view.slice(s![..;-1])).err(),
+        Some(SliceError::NegativeStride { stride: -1 })
+    );
+    // A single element can't go backward.
+    let slice = Slice::from_array_view(&view.slice(s![2..3;-1])).unwrap();
+    assert!(slice.iter().eq([2].iter()));
+
+    // Rows must be made of contiguous elements.
+    let array = Array2::from_shape_fn((3, 3), |(i, j)| (i * 3 + j) as u32);
+    assert_eq!(
+        Slice::<[u32; 2]>::from_array_view2(&array.view()).err(),
+        Some(SliceError::NonContiguousElement {
+            columns: 3,
+            stride: 1
+        })
+    );
+    let transposed: ArrayView2<u32> = array.t();
+    assert_eq!(
+        Slice::<[u32; 3]>::from_array_view2(&transposed).err(),
+        Some(SliceError::NonContiguousElement {
+            columns: 3,
+            stride: 3
+        })
+    );
+}
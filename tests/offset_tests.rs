@@ -0,0 +1,47 @@
+use pas::__private::{offset_of, offset_of_fallback};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub color: [u8; 4],
+    pub uv: [f32; 2],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Padded {
+    pub flag: u8,
+    pub value: u64,
+    pub tail: u16,
+}
+
+// Usable in constants, as required by `#[derive(Attributes)]`.
+const UV_OFFSET: usize = offset_of_fallback!(Vertex, uv);
+
+#[test]
+fn fallback_matches_active_path() {
+    assert_eq!(offset_of_fallback!(Vertex, position), 0);
+    assert_eq!(offset_of_fallback!(Vertex, color), 12);
+    assert_eq!(UV_OFFSET, 16);
+
+    assert_eq!(
+        offset_of!(Vertex, position),
+        offset_of_fallback!(Vertex, position)
+    );
+    assert_eq!(
+        offset_of!(Vertex, color),
+        offset_of_fallback!(Vertex, color)
+    );
+    assert_eq!(offset_of!(Vertex, uv), offset_of_fallback!(Vertex, uv));
+}
+
+#[test]
+fn fallback_padding() {
+    assert_eq!(offset_of_fallback!(Padded, flag), 0);
+    assert_eq!(
+        offset_of_fallback!(Padded, value),
+        std::mem::align_of::<u64>()
+    );
+    assert_eq!(offset_of!(Padded, tail), offset_of_fallback!(Padded, tail));
+}
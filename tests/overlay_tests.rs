@@ -0,0 +1,87 @@
+use pas::{OverlaySlice, Slice, SliceError, SliceMut};
+
+#[test]
+fn overlay_reads() {
+    let data = [[1_u32, 0], [2, 0], [3, 0], [4, 0], [5, 0]];
+    let base: Slice<u32> = Slice::new(&data, 0);
+    let mut overlay = OverlaySlice::new(base);
+    assert_eq!(overlay.len(), 5);
+    assert_eq!(overlay.to_vec(), [1, 2, 3, 4, 5]);
+    assert_eq!(overlay.overrides().len(), 0);
+
+    assert_eq!(overlay.set_override(3, 40), Ok(None));
+    assert_eq!(overlay.set_override(0, 10), Ok(None));
+    assert_eq!(overlay.set_override(4, 50), Ok(None));
+    // Most recent override takes precedence.
+    assert_eq!(overlay.set_override(3, 400), Ok(Some(40)));
+
+    assert_eq!(overlay.get(0), Some(10));
+    assert_eq!(overlay.get(1), Some(2));
+    assert_eq!(overlay.get(3), Some(400));
+    assert_eq!(overlay.get(5), None);
+    assert_eq!(overlay.iter().len(), 5);
+    assert_eq!(overlay.to_vec(), [10, 2, 3, 400, 50]);
+    assert_eq!(format!("{:?}", overlay), "[10, 2, 3, 400, 50]");
+    assert_eq!(
+        overlay.overrides().collect::<Vec<_>>(),
+        [(0, &10), (3, &400), (4, &50)]
+    );
+
+    assert_eq!(overlay.clear_override(3), Some(400));
+    assert_eq!(overlay.clear_override(3), None);
+    assert_eq!(overlay.get(3), Some(4));
+    overlay.clear_overrides();
+    assert_eq!(overlay.to_vec(), [1, 2, 3, 4, 5]);
+    // The base data is never written.
+    assert_eq!(data[3], [4, 0]);
+}
+
+#[test]
+fn overlay_out_of_bounds() {
+    let data = [0_u8; 3];
+    let mut overlay = OverlaySlice::new(Slice::native(&data));
+    assert_eq!(
+        overlay.set_override(3, 1),
+        Err(SliceError::IndexOutOfBounds {
+            position: 0,
+            index: 3,
+            len: 3
+        })
+    );
+    assert_eq!(overlay.overrides().len(), 0);
+
+    let empty: [u8; 0] = [];
+    let mut overlay = OverlaySlice::new(Slice::native(&empty));
+    assert!(overlay.set_override(0, 1).is_err());
+    assert!(overlay.is_empty());
+    assert_eq!(overlay.iter().next(), None);
+}
+
+#[test]
+fn overlay_materialize() {
+    let base: Vec<[f32; 2]> = (0..100).map(|i| [i as f32, 0.0]).collect();
+    let mut overlay = OverlaySlice::new(Slice::native(&base));
+    let mut reference = base.clone();
+    for index in [99, 0, 42, 7, 43] {
+        let value = [index as f32, 1.0];
+        overlay.set_override(index, value).unwrap();
+        reference[index] = value;
+    }
+    assert_eq!(overlay.to_vec(), reference);
+
+    let mut dst = vec![[[0.0_f32; 2]; 2]; 100];
+    let mut slice: SliceMut<[f32; 2]> = SliceMut::new(&mut dst, 8);
+    overlay.materialize_into(&mut slice).unwrap();
+    assert!(dst.iter().zip(&reference).all(|(d, r)| d[1] == *r));
+    assert!(dst.iter().all(|d| d[0] == [0.0; 2]));
+
+    let mut short = vec![[0.0_f32; 2]; 99];
+    assert_eq!(
+        overlay.materialize_into(&mut SliceMut::native(&mut short)),
+        Err(SliceError::LengthMismatch {
+            expected: 100,
+            len: 99
+        })
+    );
+    assert!(short.iter().all(|v| *v == [0.0; 2]));
+}
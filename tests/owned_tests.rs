@@ -0,0 +1,326 @@
+use pas::{
+    or_broadcast, AttributeError, MaybeOwnedSlice, OwnedStrided, OwnedView, Slice, SliceError,
+    ViewDesc,
+};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [u32; 3],
+    pub uv: [u32; 2],
+}
+
+fn data() -> Vec<Vertex> {
+    vec![
+        Vertex {
+            position: [0, 1, 2],
+            uv: [3, 4],
+        },
+        Vertex {
+            position: [5, 6, 7],
+            uv: [8, 9],
+        },
+        Vertex {
+            position: [10, 11, 12],
+            uv: [13, 14],
+        },
+    ]
+}
+
+/// Simulate loading a file, returning the buffer and its views.
+fn load() -> OwnedView<&'static str> {
+    let blob: Vec<u8> = bytemuck::cast_slice(&data()).to_vec();
+    let stride = std::mem::size_of::<Vertex>();
+    let mut view = OwnedView::new(&blob);
+    view.insert("position", ViewDesc::new(0, stride, 3));
+    view.insert("uv", ViewDesc::new(12, stride, 3));
+    view
+}
+
+#[test]
+fn views() {
+    let mut view = load();
+    assert_eq!(view.attribute_count(), 2);
+    assert_eq!(view.find(&"uv"), Some(1));
+    assert_eq!(view.find(&"normal"), None);
+
+    let positions = view.view::<[u32; 3]>(0).unwrap();
+    assert_eq!(positions.len(), 3);
+    assert_eq!(positions[2], [10, 11, 12]);
+    let uvs = view.view::<[u32; 2]>(1).unwrap();
+    assert_eq!(uvs[1], [8, 9]);
+
+    let mut uvs = view.view_mut::<[u32; 2]>(1).unwrap();
+    uvs[1] = [100, 101];
+    // Positions shouldn't be affected.
+    let positions = view.view::<[u32; 3]>(0).unwrap();
+    assert_eq!(positions[1], [5, 6, 7]);
+    assert_eq!(view.view::<[u32; 2]>(1).unwrap()[1], [100, 101]);
+}
+
+#[test]
+fn move_across_thread() {
+    let view = load();
+    let view = std::thread::spawn(move || {
+        let positions = view.view::<[u32; 3]>(view.find(&"position").unwrap());
+        assert_eq!(positions.unwrap()[1], [5, 6, 7]);
+        view
+    })
+    .join()
+    .unwrap();
+    let uvs = view.view::<[u32; 2]>(view.find(&"uv").unwrap()).unwrap();
+    assert_eq!(uvs[2], [13, 14]);
+}
+
+#[test]
+fn invalid_desc() {
+    let mut view: OwnedView = OwnedView::new(&[0; 32]);
+    let too_long = view.push(ViewDesc::new(0, 8, 5));
+    let empty = view.push(ViewDesc::new(0, 8, 0));
+    let unaligned = view.push(ViewDesc::new(2, 8, 2));
+
+    assert!(matches!(
+        view.view::<u32>(too_long),
+        Err(SliceError::OffsetOutOfBounds {
+            size: 32,
+            offset: 36
+        })
+    ));
+    assert!(view.view::<u32>(empty).unwrap().is_empty());
+    assert!(matches!(
+        view.view_mut::<u32>(unaligned),
+        Err(SliceError::AlignmentFault { offset: 2, .. })
+    ));
+}
+
+#[test]
+fn maybe_owned_borrowed() {
+    let data = data();
+    let uvs: MaybeOwnedSlice<[u32; 2]> = Slice::new(&data, 12).into();
+    assert!(uvs.is_borrowed());
+    // No copy: elements are read from the input.
+    assert!(std::ptr::eq(uvs.get(0).unwrap(), &data[0].uv));
+    assert!(std::ptr::eq(&uvs[2], &data[2].uv));
+}
+
+#[test]
+fn maybe_owned_clone_on_write() {
+    let data = data();
+    let mut uvs: MaybeOwnedSlice<[u32; 2]> = Slice::new(&data, 12).into();
+    uvs.to_mut().push([15, 16]);
+    assert!(uvs.is_owned());
+    assert_eq!(uvs.to_mut(), &vec![[3, 4], [8, 9], [13, 14], [15, 16]]);
+
+    uvs.to_mut()[0] = [0, 0];
+    assert_eq!(uvs[0], [0, 0]);
+    assert_eq!(data[0].uv, [3, 4]);
+}
+
+#[test]
+fn maybe_owned_parity() {
+    let data = data();
+    let borrowed: MaybeOwnedSlice<[u32; 3]> = Slice::new(&data, 0).into();
+    let owned: MaybeOwnedSlice<[u32; 3]> = borrowed.to_vec().into();
+    assert!(owned.is_owned());
+
+    assert_eq!(borrowed.len(), owned.len());
+    assert_eq!(borrowed.is_empty(), owned.is_empty());
+    for index in 0..4 {
+        assert_eq!(borrowed.get(index), owned.get(index));
+    }
+    assert_eq!(borrowed[1], owned[1]);
+    assert!(borrowed.iter().eq(owned.iter()));
+    assert_eq!(borrowed.to_vec(), owned.to_vec());
+    assert_eq!(format!("{:?}", borrowed), format!("{:?}", owned));
+    assert_eq!(borrowed.as_slice().len(), owned.as_slice().len());
+    assert_eq!(borrowed, owned);
+    assert_eq!(borrowed.clone().into_owned(), owned.into_owned());
+
+    let empty: MaybeOwnedSlice<u32> = Slice::default().into();
+    assert!(empty.is_empty());
+    assert_eq!(empty, Vec::new().into());
+}
+
+#[test]
+fn or_broadcast_present() {
+    let data = [[1_u32, 10], [2, 20], [3, 30]];
+    let slice: Slice<u32> = Slice::new(&data, 4);
+
+    let result = or_broadcast(Some(slice), &0, 2).unwrap();
+    assert!(result.is_borrowed());
+    assert_eq!(result.to_vec(), [10, 20]);
+    assert!(std::ptr::eq(result.get(0).unwrap(), &data[0][1]));
+    assert_eq!(or_broadcast(Some(slice), &0, 3).unwrap().len(), 3);
+    assert_eq!(
+        or_broadcast(Some(slice), &0, 4),
+        Err(SliceError::TooShort {
+            expected: 4,
+            len: 3
+        })
+    );
+}
+
+#[test]
+fn or_broadcast_missing() {
+    let result = or_broadcast(None, &[1.0_f32, 1.0, 1.0, 1.0], 5).unwrap();
+    assert!(result.is_owned());
+    assert_eq!(result.len(), 5);
+    assert!(result.iter().all(|v| *v == [1.0; 4]));
+    assert!(or_broadcast::<u8>(None, &7, 0).unwrap().is_empty());
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Color([f32; 4]);
+
+impl Default for Color {
+    fn default() -> Self {
+        Self([1.0; 4])
+    }
+}
+
+#[test]
+fn make_matching_lane() {
+    let vertices = data();
+    let positions: Slice<[u32; 3]> = Slice::new(&vertices, 0);
+    let colors: Vec<Color> = positions.make_matching_lane();
+    assert_eq!(colors, [Color::default(); 3]);
+
+    let empty: Vec<u32> = Slice::<u32>::native(&[]).make_matching_lane();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn add_default_lane() {
+    let mut view = load();
+    let mut colors = view.add_default_lane::<Color>("color").unwrap();
+    assert_eq!(colors.len(), 3);
+    assert!(colors.iter().all(|color| *color == Color::default()));
+    colors[1] = Color([0.5; 4]);
+
+    let index = view.find(&"color").unwrap();
+    assert_eq!(index, 2);
+    // Appended after the 60 bytes of vertices, at the next 16 bytes boundary.
+    assert_eq!(*view.desc(index).unwrap(), ViewDesc::new(64, 16, 3));
+    assert_eq!(view.bytes().len(), 64 + 48);
+    assert_eq!(view.view::<Color>(index).unwrap()[1], Color([0.5; 4]));
+    // Existing attributes are kept.
+    assert_eq!(view.view::<[u32; 3]>(0).unwrap()[2], [10, 11, 12]);
+    assert_eq!(view.view::<[u32; 2]>(1).unwrap()[1], [8, 9]);
+
+    let mut ids = view.add_default_lane::<u8>("id").unwrap();
+    ids.fill(7);
+    assert_eq!(view.view::<u8>(3).unwrap().len(), 3);
+}
+
+#[test]
+fn add_default_lane_errors() {
+    let mut view = load();
+    assert_eq!(
+        view.add_default_lane::<Color>("uv").err(),
+        Some(AttributeError::Duplicate {
+            name: "uv".to_owned()
+        })
+    );
+    assert_eq!(view.attribute_count(), 2);
+
+    view.insert("truncated", ViewDesc::new(0, 20, 2));
+    assert_eq!(
+        view.add_default_lane::<Color>("color").err(),
+        Some(AttributeError::CountMismatch {
+            name: "truncated".to_owned(),
+            expected: 3,
+            count: 2
+        })
+    );
+    assert_eq!(view.attribute_count(), 3);
+    assert_eq!(view.bytes().len(), 60);
+
+    // Without any attribute, the lane is empty.
+    let mut view = OwnedView::<String>::new(&[0; 5]);
+    assert!(view
+        .add_default_lane::<u32>("id".into())
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn owned_strided_inline_parity() {
+    let vertices = data();
+    let positions: Slice<[u32; 3]> = Slice::new(&vertices, 0);
+    let inline = OwnedStrided::from_slice(&positions);
+    let heap = OwnedStrided::new(positions.iter().copied().collect());
+    assert!(inline.is_inline());
+    assert!(!heap.is_inline());
+    assert_eq!(inline, heap);
+    assert_eq!(format!("{:?}", inline), format!("{:?}", heap));
+
+    for mut owned in [inline, heap] {
+        assert_eq!(owned.len(), 3);
+        assert_eq!(owned.get(1), Some(&[5, 6, 7]));
+        assert_eq!(owned.get(3), None);
+        assert_eq!(owned.as_slice()[2], [10, 11, 12]);
+        owned.as_slice_mut()[0] = [9, 9, 9];
+        assert_eq!(owned[0], [9, 9, 9]);
+        let copy = owned.clone();
+        assert_eq!(
+            owned.into_vec(),
+            [[9, 9, 9], [5, 6, 7], [10, 11, 12]].to_vec()
+        );
+        assert_eq!(copy.as_elements()[0], [9, 9, 9]);
+    }
+}
+
+#[test]
+fn owned_strided_inline_alignment() {
+    let vectors = [[1.0_f32; 4]; 3];
+    let owned = OwnedStrided::from_slice(&Slice::native(&vectors));
+    assert!(owned.is_inline());
+    assert_eq!(owned.as_elements().as_ptr() as usize % 16, 0);
+
+    // A single matrix is exactly 64 bytes.
+    let matrix = [[[2.0_f32; 4]; 4]];
+    let mut owned = OwnedStrided::from_slice(&Slice::native(&matrix));
+    assert!(owned.is_inline());
+    assert_eq!(owned.as_elements().as_ptr() as usize % 16, 0);
+    assert_eq!(owned.as_slice_mut()[0], matrix[0]);
+}
+
+#[test]
+fn owned_strided_inline_boundary() {
+    let floats = [1.0_f32; 17];
+    let owned = OwnedStrided::from_slice(&Slice::native(&floats[..16]));
+    assert!(owned.is_inline());
+    assert_eq!(owned.as_elements(), &floats[..16]);
+    let owned = OwnedStrided::from_slice(&Slice::native(&floats));
+    assert!(!owned.is_inline());
+    assert_eq!(owned.as_elements(), &floats);
+
+    let empty = OwnedStrided::from_slice(&Slice::<u32>::native(&[]));
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn owned_view_inline() {
+    for len in [0, 1, 63, 64, 65, 200] {
+        let bytes: Vec<u8> = (0..len as u8).collect();
+        let mut view = OwnedView::<()>::new(&bytes);
+        assert_eq!(view.is_inline(), len <= 64);
+        assert_eq!(view.bytes(), &bytes[..]);
+        assert_eq!(view.bytes().as_ptr() as usize % 16, 0);
+        if len >= 16 {
+            let index = view.push(ViewDesc::new(0, 16, len / 16));
+            let matrix = view.view_mut::<[f32; 4]>(index).unwrap();
+            assert_eq!(matrix.len(), len / 16);
+        }
+    }
+
+    // Growing past 64 bytes moves the bytes to the heap.
+    let mut view = OwnedView::new(&[7; 48]);
+    view.insert("position", ViewDesc::new(0, 12, 4));
+    assert!(view.is_inline());
+    view.add_default_lane::<[f32; 3]>("normal").unwrap();
+    assert!(!view.is_inline());
+    assert_eq!(&view.bytes()[..48], &[7; 48]);
+    assert_eq!(view.view::<[u8; 12]>(0).unwrap()[3], [7; 12]);
+}
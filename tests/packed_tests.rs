@@ -0,0 +1,130 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use pas::{PackScratch, Slice, SliceMut};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub id: u32,
+}
+
+fn data() -> Vec<Vertex> {
+    (0..5)
+        .map(|i| Vertex {
+            position: [i as f32; 3],
+            id: 10 - i,
+        })
+        .collect()
+}
+
+#[test]
+fn contiguous_fast_path() {
+    let mut ids = [3_u32, 1, 2];
+    let expected = ids.as_ptr();
+    let mut scratch = PackScratch::new();
+
+    let mut slice: SliceMut<u32> = SliceMut::native(&mut ids);
+    let ptr = slice.with_packed(&mut scratch, |v| {
+        v.sort();
+        v.as_ptr()
+    });
+    assert_eq!(ptr, expected);
+    assert_eq!(ids, [1, 2, 3]);
+
+    let ptr = Slice::native(&ids).with_packed_ref(&mut scratch, |v| v.as_ptr());
+    assert_eq!(ptr, expected);
+    assert_eq!(scratch.capacity(), 0);
+
+    // Empty and single element views are contiguous, whatever the stride.
+    let mut vertices = data();
+    let mut slice: SliceMut<u32> = SliceMut::new(&mut vertices[..1], 12);
+    let ptr = slice.with_packed(&mut scratch, |v| v.as_ptr());
+    assert_eq!(ptr, &vertices[0].id as *const u32);
+    let mut slice: SliceMut<u32> = SliceMut::new(&mut vertices[..0], 0);
+    assert_eq!(slice.with_packed(&mut scratch, |v| v.len()), 0);
+    assert_eq!(scratch.capacity(), 0);
+}
+
+#[test]
+fn strided_round_trip() {
+    let mut vertices = data();
+    let mut scratch = PackScratch::new();
+
+    let ids: Slice<u32> = Slice::new(&vertices, 12);
+    let packed = ids.with_packed_ref(&mut scratch, |v| v.to_vec());
+    assert_eq!(packed, [10, 9, 8, 7, 6]);
+
+    let mut ids: SliceMut<u32> = SliceMut::new(&mut vertices, 12);
+    ids.with_packed(&mut scratch, |v| {
+        assert_eq!(v, [10, 9, 8, 7, 6]);
+        v.sort();
+    });
+    assert!(vertices.iter().map(|v| v.id).eq(6..11));
+    // Positions are untouched.
+    assert!(vertices
+        .iter()
+        .enumerate()
+        .all(|(i, v)| v.position == [i as f32; 3]));
+}
+
+#[test]
+fn panic_writes_back() {
+    let mut vertices = data();
+    let mut scratch = PackScratch::new();
+
+    let mut ids: SliceMut<u32> = SliceMut::new(&mut vertices, 12);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        ids.with_packed(&mut scratch, |v| {
+            v[0] = 0;
+            v[1] = 1;
+            panic!("interrupted");
+        })
+    }));
+    assert!(result.is_err());
+    // Writes done before the panic are visible, as for contiguous views.
+    assert!(vertices.iter().map(|v| v.id).eq([0, 1, 8, 7, 6]));
+}
+
+#[test]
+fn scratch_reuse() {
+    let mut vertices = data();
+    let mut scratch = PackScratch::with_capacity(64);
+    assert_eq!(scratch.capacity(), 64);
+
+    let mut ids: SliceMut<u32> = SliceMut::new(&mut vertices, 12);
+    ids.with_packed(&mut scratch, |v| v.reverse());
+    assert_eq!(scratch.capacity(), 64);
+
+    // Grows when needed, and is reused afterward.
+    let positions: Slice<[f32; 3]> = Slice::new(&vertices, 0);
+    let sum = positions.with_packed_ref(&mut scratch, |v| v.iter().map(|p| p[0]).sum::<f32>());
+    assert_eq!(sum, 10.0);
+    assert_eq!(scratch.capacity(), 64);
+
+    let ids: Slice<u32> = Slice::new(&vertices, 12);
+    assert!(ids.iter().copied().eq(6..11));
+
+    let mut large = vec![[0_u64, 0]; 10];
+    let mut slice: SliceMut<u64> = SliceMut::new(&mut large, 8);
+    slice.with_packed(&mut scratch, |v| v.fill(1));
+    assert_eq!(scratch.capacity(), 80);
+    assert!(large.iter().all(|v| *v == [0, 1]));
+}
+
+#[test]
+fn over_aligned() {
+    #[repr(C, align(64))]
+    #[derive(Clone, Copy, Debug, PartialEq, bytemuck::Zeroable)]
+    struct Wide([u8; 64]);
+    unsafe impl bytemuck::Pod for Wide {}
+
+    let mut data = vec![[Wide([1; 64]), Wide([2; 64])]; 3];
+    let mut scratch = PackScratch::new();
+    let mut slice: SliceMut<Wide> = SliceMut::new(&mut data, 64);
+    slice.with_packed(&mut scratch, |v| {
+        assert_eq!(v.as_ptr() as usize % 64, 0);
+        v.iter_mut().for_each(|w| w.0[0] = 7);
+    });
+    assert!(data.iter().all(|v| v[1].0[0] == 7 && v[0].0[0] == 1));
+}
@@ -0,0 +1,149 @@
+//! Checks the functions listed in `pas::panic_free`.
+//!
+//! In release builds, every call is wrapped in a guard whose destructor calls
+//! an undefined function. The destructor only runs when unwinding, so the test
+//! fails to link if the optimizer can't remove every panicking path of a call:
+//!
+//! ```sh
+//! cargo test --release --test panic_free
+//! ```
+//!
+//! In debug builds, the functions are only called.
+
+use std::hint::black_box;
+
+use pas::{Permissive, Slice, SliceError, SliceMut, Strict};
+
+#[cfg(not(debug_assertions))]
+fn no_panic<R>(f: impl FnOnce() -> R) -> R {
+    struct Guard;
+    extern "C" {
+        fn pas_panic_free_function_may_panic() -> !;
+    }
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            unsafe { pas_panic_free_function_may_panic() }
+        }
+    }
+    let guard = Guard;
+    let result = f();
+    std::mem::forget(guard);
+    result
+}
+
+#[cfg(debug_assertions)]
+fn no_panic<R>(f: impl FnOnce() -> R) -> R {
+    f()
+}
+
+#[inline(never)]
+fn with_policy(data: &[[u32; 3]], offset: usize, stride: usize) -> Option<usize> {
+    no_panic(|| {
+        let strict = Slice::<u32>::with_policy::<Strict>(data, offset, stride).ok()?;
+        let permissive = Slice::<[u32; 2]>::with_policy::<Permissive>(data, offset, stride);
+        Some(strict.len() + permissive.map_or(0, |s| s.len()))
+    })
+}
+
+#[inline(never)]
+fn with_policy_mut(data: &mut [[u16; 2]], offset: usize, stride: usize) -> Option<usize> {
+    no_panic(|| {
+        SliceMut::<u16>::with_policy::<Strict>(data, offset, stride)
+            .ok()
+            .map(|s| s.len())
+    })
+}
+
+#[inline(never)]
+fn try_transmute(data: &[[u8; 4]]) -> Result<usize, SliceError> {
+    no_panic(|| {
+        let slice = Slice::<[u8; 4]>::with_policy::<Strict>(data, 0, 1)?;
+        let slice = slice.try_transmute_elem::<u32>()?;
+        slice.try_transmute_elem::<u64>().map(|s| s.len())
+    })
+}
+
+#[inline(never)]
+fn accessors(slice: &Slice<u32>, index: usize) -> (usize, bool, usize, Option<u32>) {
+    no_panic(|| {
+        (
+            slice.len(),
+            slice.is_empty(),
+            slice.stride(),
+            slice.get(index).copied(),
+        )
+    })
+}
+
+#[inline(never)]
+fn get_mut(slice: &mut SliceMut<u32>, index: usize) -> bool {
+    no_panic(|| match slice.get_mut(index) {
+        Some(value) => {
+            *value = 42;
+            true
+        }
+        None => false,
+    })
+}
+
+#[inline(never)]
+fn iterate(slice: &Slice<u32>, skip: usize) -> u32 {
+    no_panic(|| {
+        let sum = slice.iter().fold(0_u32, |acc, v| acc.wrapping_add(*v));
+        let nth = slice.iter().nth(skip).copied().unwrap_or_default();
+        sum.wrapping_add(nth)
+    })
+}
+
+#[inline(never)]
+fn iterate_mut(slice: &mut SliceMut<u32>) {
+    no_panic(|| {
+        for value in slice.iter() {
+            *value = value.wrapping_add(1);
+        }
+    })
+}
+
+#[inline(never)]
+fn try_copy(slice: &mut SliceMut<u32>, wide: &[u64], narrow: &[u16]) -> usize {
+    no_panic(|| {
+        let a = slice.try_copy_from_slice(wide).is_ok();
+        let b = slice.try_copy_from_slice(narrow).is_ok();
+        a as usize + b as usize
+    })
+}
+
+#[test]
+fn panic_free_subset() {
+    let data = [[1_u32, 2, 3], [4, 5, 6], [7, 8, 9]];
+    assert_eq!(with_policy(black_box(&data), 4, 1), Some(6));
+    assert_eq!(with_policy(black_box(&data), 4, 0), None);
+    assert_eq!(with_policy(black_box(&data), 400, 1), None);
+    assert_eq!(with_policy(black_box(&data), 4, usize::MAX), None);
+
+    let mut data = [[0_u16; 2]; 4];
+    assert_eq!(with_policy_mut(black_box(&mut data), 2, 1), Some(4));
+    assert_eq!(with_policy_mut(black_box(&mut data), 1, 1), None);
+
+    let bytes = [[0_u8; 4]; 5];
+    assert!(matches!(
+        try_transmute(black_box(&bytes)),
+        Err(SliceError::ElementMismatch { .. })
+    ));
+
+    let data = [[1_u32, 0], [2, 0], [3, 0]];
+    let slice = Slice::<u32>::new(&data, 0);
+    assert_eq!(accessors(&slice, 1), (3, false, 8, Some(2)));
+    assert_eq!(accessors(&slice, usize::MAX), (3, false, 8, None));
+    assert_eq!(iterate(&slice, 1), 8);
+    assert_eq!(iterate(&slice, usize::MAX), 6);
+
+    let mut data = [0_u32; 3];
+    let mut slice = SliceMut::native(&mut data);
+    assert!(get_mut(&mut slice, 2));
+    assert!(!get_mut(&mut slice, usize::MAX));
+    iterate_mut(&mut slice);
+    assert_eq!(try_copy(&mut slice, &[1, 2], &[5, 6, 7, 8]), 0);
+    assert_eq!(try_copy(&mut slice, &[1, 2], &[5, 6]), 1);
+    assert_eq!(data, [5, 6, 43]);
+}
@@ -0,0 +1,170 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use pas::{Slice, SliceMut};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [u32; 3],
+    pub uv: [u32; 2],
+}
+
+#[test]
+fn par_chunks_apply() {
+    let mut vertices: Vec<Vertex> = (0..1000)
+        .map(|i| Vertex {
+            position: [i, i, i],
+            uv: [i, i],
+        })
+        .collect();
+
+    let mut positions: SliceMut<[u32; 3]> = SliceMut::new(&mut vertices, 0);
+    let calls = AtomicUsize::new(0);
+    positions.par_chunks_apply(64, 4, |_, mut chunk| {
+        calls.fetch_add(1, Ordering::Relaxed);
+        for i in 0..chunk.len() {
+            chunk[i][0] += 1;
+        }
+    });
+    assert_eq!(calls.load(Ordering::Relaxed), 1000_usize.div_ceil(64));
+
+    for (i, v) in vertices.iter().enumerate() {
+        let i = i as u32;
+        assert_eq!(v.position, [i + 1, i, i]);
+        assert_eq!(v.uv, [i, i]);
+    }
+}
+
+#[test]
+fn par_chunks_apply_uneven() {
+    let mut data: Vec<u32> = vec![0; 10];
+    let mut slice = SliceMut::native(&mut data);
+    slice.par_chunks_apply(3, 4, |chunk, mut slice| {
+        for i in 0..slice.len() {
+            slice[i] = chunk as u32 * 10 + slice.len() as u32;
+        }
+    });
+    assert_eq!(data, [3, 3, 3, 13, 13, 13, 23, 23, 23, 31]);
+}
+
+#[test]
+fn par_chunks_apply_degenerate() {
+    let mut data: Vec<u32> = vec![0; 5];
+
+    // Single thread runs inline, in order.
+    let order = std::sync::Mutex::new(Vec::new());
+    SliceMut::native(&mut data).par_chunks_apply(2, 1, |chunk, _| {
+        order.lock().unwrap().push(chunk);
+    });
+    assert_eq!(*order.lock().unwrap(), [0, 1, 2]);
+
+    // Chunk larger than the slice.
+    let calls = AtomicUsize::new(0);
+    SliceMut::native(&mut data).par_chunks_apply(100, 8, |chunk, slice| {
+        calls.fetch_add(1, Ordering::Relaxed);
+        assert_eq!((chunk, slice.len()), (0, 5));
+    });
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+    // Empty slice.
+    let mut empty: Vec<u32> = Vec::new();
+    SliceMut::native(&mut empty).par_chunks_apply(4, 4, |_, _| panic!("unexpected call"));
+}
+
+#[test]
+fn split_at_mut() {
+    let mut data: Vec<[u32; 2]> = vec![[0, 1], [2, 3], [4, 5]];
+    let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 4);
+    let (mut left, mut right) = slice.split_at_mut(1);
+    assert_eq!((left.len(), right.len()), (1, 2));
+    left[0] = 10;
+    right[0] = 30;
+    right[1] = 50;
+    assert_eq!(data, [[0, 10], [2, 30], [4, 50]]);
+}
+
+fn noisy_floats(count: usize) -> Vec<[f32; 3]> {
+    // Values of very different magnitudes, such that the summation order matters.
+    (0..count)
+        .map(|i| {
+            let v = (i as f32 * 0.37).sin() * 10_f32.powi(i as i32 % 7 - 3);
+            [v, -v * 0.5, i as f32 * 1e-3]
+        })
+        .collect()
+}
+
+#[test]
+fn par_fold_deterministic_across_threads() {
+    let data = noisy_floats(10_000);
+    let slice: Slice<[f32; 3]> = Slice::native(&data);
+    let sum = |threads| {
+        slice.par_fold_deterministic(128, threads, 0.0_f32, |acc, v| acc + v[0], |a, b| a + b)
+    };
+    let reference = sum(1);
+    for threads in [1, 2, 3, 8, 16] {
+        for _ in 0..5 {
+            assert_eq!(sum(threads).to_bits(), reference.to_bits(), "{threads}");
+        }
+    }
+
+    // Partial results are combined in chunk order.
+    let order = slice.par_fold_deterministic(
+        1000,
+        4,
+        vec![],
+        |mut acc, v| {
+            if acc.is_empty() {
+                acc.push(v[2]);
+            }
+            acc
+        },
+        |mut a, b| {
+            a.extend(b);
+            a
+        },
+    );
+    assert_eq!(order.len(), 10);
+    assert!(order.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn par_fold_deterministic_matches_serial() {
+    let data: Vec<u64> = (0..1001).collect();
+    let slice = Slice::native(&data);
+    for chunk in [1, 7, 1000, 1001, 5000] {
+        let sum = slice.par_fold_deterministic(chunk, 4, 0, |acc, v| acc + v, |a, b| a + b);
+        assert_eq!(sum, data.iter().sum::<u64>());
+    }
+
+    let empty: [u64; 0] = [];
+    let sum = Slice::native(&empty).par_fold_deterministic(4, 4, 3, |a, v| a + v, |a, b| a + b);
+    assert_eq!(sum, 3);
+}
+
+#[test]
+fn par_sum_minmax() {
+    let data = noisy_floats(5000);
+    let slice: Slice<[f32; 3]> = Slice::native(&data);
+
+    let sum = slice.par_sum(256, 8);
+    assert_eq!(
+        bytemuck::bytes_of(&sum),
+        bytemuck::bytes_of(&slice.par_sum(256, 1))
+    );
+    for lane in 0..3 {
+        let serial: f64 = data.iter().map(|v| v[lane] as f64).sum();
+        assert!((sum[lane] as f64 - serial).abs() <= serial.abs() * 1e-6);
+    }
+
+    let (min, max) = slice.par_minmax(100, 8).unwrap();
+    assert_eq!(Some((min, max)), slice.par_minmax(100, 1));
+    for lane in 0..3 {
+        let values = data.iter().map(|v| v[lane]);
+        assert_eq!(min[lane], values.clone().fold(f32::INFINITY, f32::min));
+        assert_eq!(max[lane], values.fold(f32::NEG_INFINITY, f32::max));
+    }
+
+    let empty: [f32; 0] = [];
+    assert_eq!(Slice::native(&empty).par_minmax(4, 4), None);
+    assert_eq!(Slice::native(&empty).par_sum(4, 4), 0.0);
+}
@@ -0,0 +1,106 @@
+use pas::{Permissive, Slice, SliceBuilder, SliceError, SliceMut, Strict, ValidationPolicy};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [u32; 3],
+    pub uv: [u32; 2],
+}
+
+fn data() -> Vec<Vertex> {
+    vec![
+        Vertex {
+            position: [0, 1, 2],
+            uv: [3, 4],
+        },
+        Vertex {
+            position: [5, 6, 7],
+            uv: [8, 9],
+        },
+    ]
+}
+
+/// `(byte_offset, elt_stride, strict, permissive)`, where `strict` and `permissive`
+/// are the expected slice length, or `Err` if construction fails.
+type Layout = (usize, usize, Result<usize, ()>, Result<usize, ()>);
+
+/// Borderline layouts on [`data`], read as `[u32; 2]`.
+fn layouts() -> Vec<Layout> {
+    vec![
+        // Attribute fits in the element.
+        (0, 1, Ok(2), Ok(2)),
+        (12, 1, Ok(2), Ok(2)),
+        // Attribute crosses the element boundary.
+        (16, 1, Err(()), Ok(1)),
+        (36, 1, Err(()), Ok(0)),
+        // Offset out of bounds.
+        (40, 1, Err(()), Err(())),
+        // Unaligned offset.
+        (2, 1, Err(()), Err(())),
+    ]
+}
+
+fn check<P: ValidationPolicy>(expected: impl Fn(&Layout) -> Result<usize, ()>) {
+    let mut vertices = data();
+    for layout in layouts() {
+        let (offset, stride) = (layout.0, layout.1);
+        let result = Slice::<[u32; 2]>::with_policy::<P>(&vertices, offset, stride);
+        assert_eq!(
+            result.map(|s| s.len()).map_err(|_| ()),
+            expected(&layout),
+            "offset {}, stride {}",
+            offset,
+            stride
+        );
+        let result = SliceMut::<[u32; 2]>::with_policy::<P>(&mut vertices, offset, stride);
+        assert_eq!(result.map(|s| s.len()).map_err(|_| ()), expected(&layout));
+    }
+}
+
+#[test]
+fn strict() {
+    check::<Strict>(|layout| layout.2);
+}
+
+#[test]
+fn permissive() {
+    check::<Permissive>(|layout| layout.3);
+}
+
+#[test]
+fn stride_alignment() {
+    let data = [0_u32; 4];
+    let data: &[u8] = bytemuck::cast_slice(&data);
+
+    // A single element doesn't need an aligned stride.
+    let strict = Slice::<u32>::with_policy::<Strict>(&data[..6], 0, 6);
+    assert!(matches!(
+        strict,
+        Err(SliceError::UnalignedStride { stride: 6, .. })
+    ));
+    let permissive = Slice::<u32>::with_policy::<Permissive>(&data[..6], 0, 6).unwrap();
+    assert_eq!(permissive.len(), 1);
+
+    // Multiple elements, the second one would be unaligned.
+    let permissive = Slice::<u32>::with_policy::<Permissive>(data, 0, 6);
+    assert!(matches!(
+        permissive,
+        Err(SliceError::UnalignedStride { stride: 6, .. })
+    ));
+}
+
+#[test]
+fn builder() {
+    let vertices = data();
+    let flat: &[u32] = bytemuck::cast_slice(&vertices);
+    // `[u32; 2]` starting at `uv[1]`, crossing into the next vertex.
+    let attr: &[u32; 2] = flat[4..6].try_into().unwrap();
+
+    let builder = SliceBuilder::new(attr, 1);
+    assert!(matches!(
+        builder.try_build(&vertices),
+        Err(SliceError::AttributeCrossesElementBoundary { offset: 16, .. })
+    ));
+    let slice = builder.policy::<Permissive>().try_build(&vertices).unwrap();
+    assert_eq!(slice[0], [4, 5]);
+}
@@ -0,0 +1,122 @@
+use pas::{
+    validate_non_indexed_primitive, validate_primitive, OwnedStrided, PrimitiveIssue, Slice,
+    SliceMut, StridedInfo,
+};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub uv: [f32; 2],
+}
+
+#[test]
+fn valid() {
+    let vertices = [Vertex {
+        position: [0.0; 3],
+        uv: [0.0; 2],
+    }; 4];
+    let positions: Slice<[f32; 3]> = Slice::new(&vertices, 0);
+    let uvs: Slice<[f32; 2]> = Slice::new(&vertices, 12);
+    let indices = [0_u32, 1, 2, 2, 1, 3];
+
+    let attrs: [(&str, &dyn StridedInfo); 2] = [("position", &positions), ("uv", &uvs)];
+    assert!(validate_primitive(&Slice::native(&indices), &attrs).is_ok());
+    assert!(validate_non_indexed_primitive(&attrs).is_ok());
+
+    // No index, and no attribute.
+    assert!(validate_primitive(&Slice::native(&[]), &attrs).is_ok());
+    assert!(validate_non_indexed_primitive(&[]).is_ok());
+}
+
+#[test]
+fn short_attribute() {
+    let indices = [0_u32, 5, 2];
+    let positions = [[0.0_f32; 3]; 6];
+    let normals = OwnedStrided::new(vec![[0.0_f32; 3]; 5]);
+
+    let err = validate_primitive(
+        &Slice::native(&indices),
+        &[
+            ("position", &Slice::native(&positions)),
+            ("normal", &normals),
+        ],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.issues,
+        [PrimitiveIssue::TooShort {
+            name: "normal".into(),
+            len: 5,
+            required: 6
+        }]
+    );
+    assert_eq!(
+        format!("{:?}", err),
+        "Attribute 'normal' has 5 elements, but indices require at least 6"
+    );
+}
+
+#[test]
+fn multiple_short_attributes() {
+    let indices = [0_u32, 1, 2, 3, 4, 5, 6, 7];
+    let mut positions = [[0.0_f32; 3]; 8];
+    let uvs = [[0.0_f32; 2]; 7];
+    let colors = [[0_u8; 4]; 0];
+
+    let err = validate_primitive(
+        &Slice::native(&indices),
+        &[
+            ("uv", &Slice::native(&uvs)),
+            ("position", &SliceMut::native(&mut positions)),
+            ("color", &Slice::native(&colors)),
+        ],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.issues,
+        [
+            PrimitiveIssue::TooShort {
+                name: "uv".into(),
+                len: 7,
+                required: 8
+            },
+            PrimitiveIssue::TooShort {
+                name: "color".into(),
+                len: 0,
+                required: 8
+            }
+        ]
+    );
+}
+
+#[test]
+fn non_indexed_count_mismatch() {
+    let positions = [[0.0_f32; 3]; 6];
+    let uvs = [[0.0_f32; 2]; 6];
+    let normals = [[0.0_f32; 3]; 5];
+    let colors = [[0_u8; 4]; 9];
+
+    let err = validate_non_indexed_primitive(&[
+        ("position", &Slice::native(&positions)),
+        ("normal", &Slice::native(&normals)),
+        ("uv", &Slice::native(&uvs)),
+        ("color", &Slice::native(&colors)),
+    ])
+    .unwrap_err();
+    assert_eq!(
+        err.issues,
+        [
+            PrimitiveIssue::CountMismatch {
+                name: "normal".into(),
+                len: 5,
+                expected: 6
+            },
+            PrimitiveIssue::CountMismatch {
+                name: "color".into(),
+                len: 9,
+                expected: 6
+            }
+        ]
+    );
+}
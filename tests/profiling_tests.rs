@@ -0,0 +1,71 @@
+#![cfg(feature = "profiling")]
+
+use pas::{profiling, Slice, SliceMut};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [u32; 3],
+    pub uv: [u32; 2],
+}
+
+fn data() -> Vec<Vertex> {
+    (0..8)
+        .map(|i| Vertex {
+            position: [i, i, i],
+            uv: [i, i],
+        })
+        .collect()
+}
+
+fn stats_of(label: &str) -> profiling::ViewStats {
+    profiling::report()
+        .into_iter()
+        .find(|s| s.label == label)
+        .unwrap()
+}
+
+// Counters are global, a single test avoids races with `reset()`.
+#[test]
+fn pipeline() {
+    let mut vertices = data();
+
+    // Read every position twice: once via iteration, once via indexing.
+    let positions = Slice::<[u32; 3]>::new(&vertices, 0).instrumented("positions");
+    let sum: u32 = positions.iter().map(|p| p[0]).sum();
+    assert_eq!(sum, 28);
+    for i in 0..positions.len() {
+        assert_eq!(positions[i], [i as u32; 3]);
+    }
+    assert_eq!(positions.get(8), None);
+
+    let stats = stats_of("positions");
+    assert_eq!(stats.reads, 16);
+    assert_eq!(stats.writes, 0);
+    assert_eq!(stats.bytes, 16 * 12);
+
+    // Write uvs.
+    let mut uvs = SliceMut::<[u32; 2]>::new(&mut vertices, 12).instrumented("uvs");
+    uvs.copy_from_slice(&[[1, 1], [2, 2], [3, 3]]);
+    uvs[7] = [7, 7];
+    uvs.fill([0, 0]);
+    let uvs = uvs.into_inner();
+    assert_eq!(uvs[3], [0, 0]);
+
+    let stats = stats_of("uvs");
+    assert_eq!(stats.reads, 0);
+    assert_eq!(stats.writes, 3 + 1 + 8);
+    assert_eq!(stats.bytes, (3 + 1 + 8) * 8);
+
+    // Views with the same label share their counters.
+    let positions = Slice::<[u32; 3]>::new(&vertices, 0).instrumented("positions");
+    assert_eq!(positions.iter().count(), 8);
+    assert_eq!(stats_of("positions").reads, 24);
+
+    profiling::reset();
+    assert!(profiling::report()
+        .iter()
+        .all(|s| s.reads == 0 && s.writes == 0 && s.bytes == 0));
+    positions.get(0);
+    assert_eq!(stats_of("positions").reads, 1);
+}
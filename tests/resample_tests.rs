@@ -0,0 +1,111 @@
+use pas::{Slice, SliceMut};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Point {
+    pub position: [f32; 2],
+    pub id: u32,
+}
+
+fn polyline(len: usize) -> Vec<Point> {
+    (0..len)
+        .map(|i| Point {
+            position: [i as f32 * 4.0, 100.0 - i as f32 * 2.0],
+            id: i as u32,
+        })
+        .collect()
+}
+
+#[test]
+fn downsample() {
+    let points = polyline(9);
+    let positions: Slice<[f32; 2]> = Slice::new(&points, 0);
+    // Positions 0, 8/3, 16/3, and 8.
+    let expected = [
+        [0.0, 100.0],
+        [32.0 / 3.0, 100.0 - 16.0 / 3.0],
+        [64.0 / 3.0, 100.0 - 32.0 / 3.0],
+        [32.0, 84.0],
+    ];
+    let values: Vec<[f32; 2]> = positions.resampled(4).collect();
+    assert_eq!(values, expected);
+    // Exact endpoints, with evenly spaced values.
+    assert!(positions.resampled(5).eq([
+        [0.0, 100.0],
+        [8.0, 96.0],
+        [16.0, 92.0],
+        [24.0, 88.0],
+        [32.0, 84.0]
+    ]));
+}
+
+#[test]
+fn upsample() {
+    let data = [[1.0_f64, 0.0], [3.0, 0.0], [-1.0, 0.0]];
+    let x: Slice<f64> = Slice::new(&data, 0);
+    assert_eq!(x.resampled(5).len(), 5);
+    assert!(x.resampled(5).eq([1.0, 2.0, 3.0, 1.0, -1.0]));
+    // Thirds aren't exact in `f64`.
+    let expected = [1.0, 5.0 / 3.0, 7.0 / 3.0, 3.0, 5.0 / 3.0, 1.0 / 3.0, -1.0];
+    assert_eq!(x.resampled(7).len(), 7);
+    assert!(x
+        .resampled(7)
+        .zip(expected)
+        .all(|(v, e)| (v - e).abs() < 1e-12));
+}
+
+#[test]
+fn same_len() {
+    let points = polyline(17);
+    let positions: Slice<[f32; 2]> = Slice::new(&points, 0);
+    assert!(positions.resampled(17).eq(positions.iter().copied()));
+}
+
+#[test]
+fn degenerate() {
+    let points = polyline(0);
+    let empty: Slice<[f32; 2]> = Slice::new(&points, 0);
+    assert_eq!(empty.resampled(4).len(), 0);
+
+    let points = polyline(1);
+    let single: Slice<[f32; 2]> = Slice::new(&points, 0);
+    assert!(single.resampled(3).eq([[0.0, 100.0]; 3]));
+
+    // A single value is the first element.
+    let points = polyline(5);
+    let positions: Slice<[f32; 2]> = Slice::new(&points, 0);
+    assert!(positions.resampled(1).eq([[0.0, 100.0]]));
+    assert_eq!(positions.resampled(0).len(), 0);
+}
+
+#[test]
+fn resample_into() {
+    let points = polyline(5);
+    let positions: Slice<[f32; 2]> = Slice::new(&points, 0);
+
+    let mut lod = polyline(3);
+    let mut out: SliceMut<[f32; 2]> = SliceMut::new(&mut lod, 0);
+    assert_eq!(positions.resample_into(&mut out), 3);
+    assert_eq!(
+        lod,
+        [
+            Point {
+                position: [0.0, 100.0],
+                id: 0
+            },
+            Point {
+                position: [8.0, 96.0],
+                id: 1
+            },
+            Point {
+                position: [16.0, 92.0],
+                id: 2
+            },
+        ]
+    );
+
+    // Empty source, nothing is written.
+    let mut out: SliceMut<[f32; 2]> = SliceMut::new(&mut lod, 0);
+    assert_eq!(Slice::<[f32; 2]>::default().resample_into(&mut out), 0);
+    assert_eq!(lod[2].position, [16.0, 92.0]);
+}
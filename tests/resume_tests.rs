@@ -0,0 +1,119 @@
+use pas::{ApplyProgress, ApplyState, Resume, Slice, SliceMut};
+
+/// Fill `data` with `1..`, stopping the first phase after `first` values.
+fn two_phase_fill(data: &mut [[u32; 2]], first: usize) {
+    let len = data.len();
+    let mut slice: SliceMut<u32> = SliceMut::new(data, 4);
+    let mut values = 1_u32..;
+
+    let resume = slice.write_from_iter(values.by_ref().take(first));
+    assert_eq!(resume.next_index(), first.min(len));
+    let second = resume
+        .remaining_mut(&mut slice)
+        .write_from_iter(values.by_ref());
+    let resume = resume.then(second);
+    assert_eq!(resume.next_index(), len);
+    assert!(resume.is_finished(slice.len()));
+    assert_eq!(resume.remaining_mut(&mut slice).len(), 0);
+}
+
+#[test]
+fn resume_write_from_iter() {
+    for first in [0, 1, 3, 4, 5, 10] {
+        let mut data = [[0_u32; 2]; 5];
+        two_phase_fill(&mut data, first);
+        assert_eq!(data, [[0, 1], [0, 2], [0, 3], [0, 4], [0, 5]], "{first}");
+    }
+
+    // Values past the end of the slice are left in the iterator.
+    let mut data = [0_u32; 2];
+    let mut values = [1, 2, 3].into_iter();
+    let resume = SliceMut::native(&mut data).write_from_iter(values.by_ref());
+    assert_eq!(resume, Resume::at(2));
+    assert_eq!(values.next(), Some(3));
+}
+
+#[test]
+fn resume_assign_from_iter() {
+    let mut data = [0_u32; 4];
+    let mut slice: SliceMut<u32> = SliceMut::native(&mut data);
+
+    let values = [Ok(1), Ok(2), Err("invalid"), Ok(4)];
+    let (resume, err) = slice.assign_from_iter(values).unwrap_err();
+    assert_eq!((resume.next_index(), err), (2, "invalid"));
+
+    let mut remaining = resume.remaining_mut(&mut slice);
+    assert_eq!(remaining.len(), 2);
+    let second = remaining.assign_from_iter([Ok::<_, &str>(3), Ok(4)]);
+    assert_eq!(resume.then(second.unwrap()), Resume::at(4));
+    assert_eq!(data, [1, 2, 3, 4]);
+
+    // Error on the last element.
+    let mut data = [0_u32; 2];
+    let result = SliceMut::native(&mut data).assign_from_iter([Ok(1), Err(())]);
+    assert_eq!(result, Err((Resume::at(1), ())));
+    assert_eq!(data, [1, 0]);
+}
+
+#[test]
+fn resume_try_apply_with() {
+    let mut data = [0_u32; 4];
+    let increments = [1_u32, 2, 3, 4];
+    let increments = Slice::native(&increments);
+    let mut slice: SliceMut<u32> = SliceMut::native(&mut data);
+
+    let mut visited = vec![];
+    let mut apply = |index: usize, v: &mut u32, inc: &u32| {
+        if *inc == 3 && visited.len() < 3 {
+            visited.push(index);
+            return Err(index);
+        }
+        visited.push(index);
+        *v += inc;
+        Ok(())
+    };
+    let (resume, index) = slice
+        .try_apply_with_resumable(&increments, &mut apply)
+        .unwrap_err();
+    assert_eq!((resume, index), (Resume::at(2), 2));
+
+    let second = resume
+        .remaining_mut(&mut slice)
+        .try_apply_with_resumable(&resume.remaining(&increments), &mut apply)
+        .unwrap();
+    assert_eq!(resume.then(second), Resume::at(4));
+    assert_eq!(data, [1, 2, 3, 4]);
+    // The failing element is processed again, and no other one.
+    assert_eq!(visited, [0, 1, 2, 0, 1]);
+}
+
+#[test]
+fn resume_apply_budgeted() {
+    let mut data = [0_u32; 5];
+    let mut slice: SliceMut<u32> = SliceMut::native(&mut data);
+
+    let mut state = ApplyState::new();
+    let progress = slice.apply_budgeted(&mut state, 3, |_, v| *v += 1);
+    assert!(matches!(progress, Ok(ApplyProgress::InProgress { .. })));
+    let resume = state.resume();
+    assert_eq!(resume.next_index(), 3);
+    resume.remaining_mut(&mut slice).fill(7);
+    assert_eq!(data, [1, 1, 1, 7, 7]);
+}
+
+#[test]
+fn resume_remaining() {
+    let data = [1_u32, 2, 3];
+    let slice = Slice::native(&data);
+    assert_eq!(
+        Resume::at(1)
+            .remaining(&slice)
+            .iter()
+            .copied()
+            .collect::<Vec<_>>(),
+        [2, 3]
+    );
+    assert_eq!(Resume::at(3).remaining(&slice).len(), 0);
+    assert_eq!(Resume::at(10).remaining(&slice).len(), 0);
+    assert_eq!(Resume::default().remaining(&slice).len(), 3);
+}
@@ -0,0 +1,104 @@
+use pas::{Resume, SanitizeError, SanitizePolicy, Slice, SliceError, SliceMut};
+
+const NAN: f32 = f32::NAN;
+const INF: f32 = f32::INFINITY;
+
+fn source() -> Vec<[f32; 3]> {
+    let mut values: Vec<[f32; 3]> = (0..8).map(|i| [i as f32; 3]).collect();
+    values[2][1] = NAN;
+    values[5][0] = INF;
+    values[7][2] = -INF;
+    values
+}
+
+#[test]
+fn sanitize_replace() {
+    let mut data = [[9.0_f32; 3]; 10];
+    let mut slice: SliceMut<[f32; 3]> = SliceMut::native(&mut data);
+    slice
+        .copy_from_slice_sanitized(&source(), SanitizePolicy::ReplaceWith(-1.0))
+        .unwrap();
+    assert_eq!(data[2], [2.0, -1.0, 2.0]);
+    assert_eq!(data[5], [-1.0, 5.0, 5.0]);
+    assert_eq!(data[7], [7.0, 7.0, -1.0]);
+    assert_eq!(data[6], [6.0; 3]);
+    // Elements past the source are left untouched.
+    assert_eq!(data[8..], [[9.0; 3]; 2]);
+}
+
+#[test]
+fn sanitize_clamp() {
+    let mut data = [[0.0_f64, 0.0], [0.0, 0.0], [0.0, 0.0]];
+    let src = [
+        [f64::NAN, 1.0],
+        [f64::INFINITY, f64::NEG_INFINITY],
+        [2.0, 3.0],
+    ];
+    let mut slice: SliceMut<f64> = SliceMut::new(&mut data, 8);
+    let lanes: Vec<f64> = src.iter().map(|v| v[0]).collect();
+    slice
+        .copy_from_slice_sanitized(&lanes, SanitizePolicy::ClampToFinite)
+        .unwrap();
+    assert_eq!(data, [[0.0, 0.0], [0.0, f64::MAX], [0.0, 2.0]]);
+
+    let mut data = [[0.0_f32; 2]; 2];
+    let mut slice: SliceMut<[f32; 2]> = SliceMut::native(&mut data);
+    slice
+        .copy_from_slice_sanitized(&[[-INF, 1.0], [NAN, INF]], SanitizePolicy::ClampToFinite)
+        .unwrap();
+    assert_eq!(data, [[f32::MIN, 1.0], [0.0, f32::MAX]]);
+}
+
+#[test]
+fn sanitize_reject() {
+    let mut data = [[0.0_f32; 3]; 8];
+    let mut slice: SliceMut<[f32; 3]> = SliceMut::native(&mut data);
+    assert_eq!(
+        slice.copy_from_slice_sanitized(&source(), SanitizePolicy::Reject),
+        Err(SanitizeError::NonFinite { index: 2, lane: 1 })
+    );
+    // Elements before the rejected one are written.
+    assert_eq!(data[..3], [[0.0; 3], [1.0; 3], [0.0; 3]]);
+
+    let mut slice: SliceMut<[f32; 3]> = SliceMut::native(&mut data);
+    assert_eq!(
+        slice.copy_from_slice_sanitized(&[[0.0; 3]; 9], SanitizePolicy::Reject),
+        Err(SanitizeError::Slice(SliceError::TooShort {
+            expected: 9,
+            len: 8
+        }))
+    );
+}
+
+#[test]
+fn sanitize_strided_and_iter() {
+    let src = source();
+    let xs: Slice<f32> = Slice::new(&src, 0);
+    let mut data = [0.0_f32; 8];
+    let mut slice = SliceMut::native(&mut data);
+    slice
+        .copy_from_strided_sanitized(&xs, SanitizePolicy::ReplaceWith(0.5))
+        .unwrap();
+    assert_eq!(data, [0.0, 1.0, 2.0, 3.0, 4.0, 0.5, 6.0, 7.0]);
+
+    // Resume after a rejected value.
+    let mut data = [0.0_f32; 4];
+    let mut slice = SliceMut::native(&mut data);
+    let values = [1.0, 2.0, NAN, 4.0];
+    let (resume, err) = slice
+        .write_from_iter_sanitized(values, SanitizePolicy::Reject)
+        .unwrap_err();
+    assert_eq!(
+        (resume, err),
+        (
+            Resume::at(2),
+            SanitizeError::NonFinite { index: 2, lane: 0 }
+        )
+    );
+    let second = resume
+        .remaining_mut(&mut slice)
+        .write_from_iter_sanitized([3.0, 4.0, 5.0], SanitizePolicy::Reject)
+        .unwrap();
+    assert_eq!(second, Resume::at(2));
+    assert_eq!(data, [1.0, 2.0, 3.0, 4.0]);
+}
@@ -0,0 +1,141 @@
+use pas::{OverflowMode, Scan, ScanError, Slice, SliceMut};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Cluster {
+    pub offset: u32,
+    pub count: u32,
+    pub id: u32,
+}
+
+fn clusters(counts: &[u32]) -> Vec<Cluster> {
+    counts
+        .iter()
+        .enumerate()
+        .map(|(i, count)| Cluster {
+            offset: 0xffff,
+            count: *count,
+            id: i as u32,
+        })
+        .collect()
+}
+
+fn reference(counts: &[u32], kind: Scan) -> Vec<u32> {
+    let mut sum = 0;
+    counts
+        .iter()
+        .map(|count| {
+            let previous = sum;
+            sum += count;
+            match kind {
+                Scan::Exclusive => previous,
+                Scan::Inclusive => sum,
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn prefix_sum() {
+    // Empty views are tested with `prefix_sum_single`, since an offset
+    // can't be applied to an empty buffer.
+    for len in 1..12 {
+        let counts: Vec<u32> = (0..len).map(|i| (i * 7 + 3) % 5).collect();
+        let total: u32 = counts.iter().sum();
+        for kind in [Scan::Exclusive, Scan::Inclusive] {
+            let mut data = clusters(&counts);
+            let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 4);
+            let result = match kind {
+                Scan::Exclusive => slice.prefix_sum_exclusive(OverflowMode::Checked),
+                Scan::Inclusive => slice.prefix_sum_inclusive(OverflowMode::Checked),
+            };
+            assert_eq!(result, Ok(total));
+            assert!(data.iter().map(|c| c.count).eq(reference(&counts, kind)));
+
+            // Neighbors are untouched.
+            assert!(data.iter().all(|c| c.offset == 0xffff));
+            assert!(data.iter().map(|c| c.id).eq(0..len));
+        }
+    }
+}
+
+#[test]
+fn prefix_sum_single() {
+    let mut data = [42_u64];
+    let mut slice = SliceMut::native(&mut data);
+    assert_eq!(slice.prefix_sum_inclusive(OverflowMode::Checked), Ok(42));
+    assert_eq!(data, [42]);
+
+    let mut slice = SliceMut::native(&mut data);
+    assert_eq!(slice.prefix_sum_exclusive(OverflowMode::Checked), Ok(42));
+    assert_eq!(data, [0]);
+
+    let mut empty: [usize; 0] = [];
+    let mut slice = SliceMut::native(&mut empty);
+    assert_eq!(slice.prefix_sum_exclusive(OverflowMode::Checked), Ok(0));
+}
+
+#[test]
+fn prefix_sum_overflow() {
+    let counts = [100_u8, 100, 50, 6, 1, 1];
+
+    // The sum reaches 256 when adding the element at index 3.
+    let mut data = counts;
+    let mut slice = SliceMut::native(&mut data);
+    assert_eq!(
+        slice.prefix_sum_exclusive(OverflowMode::Checked),
+        Err(ScanError { index: 3 })
+    );
+    assert_eq!(data, [0, 100, 200, 6, 1, 1]);
+
+    let mut data = counts;
+    let mut slice = SliceMut::native(&mut data);
+    assert_eq!(
+        slice.prefix_sum_inclusive(OverflowMode::Checked),
+        Err(ScanError { index: 3 })
+    );
+    assert_eq!(data, [100, 200, 250, 6, 1, 1]);
+
+    let mut data = counts;
+    let mut slice = SliceMut::native(&mut data);
+    assert_eq!(slice.prefix_sum_inclusive(OverflowMode::Wrapping), Ok(2));
+    assert_eq!(data, [100, 200, 250, 0, 1, 2]);
+}
+
+#[test]
+fn scan_into() {
+    let counts = [3_u32, 1, 4, 1, 5];
+    let data = clusters(&counts);
+    let src: Slice<u32> = Slice::new(&data, 4);
+
+    for kind in [Scan::Exclusive, Scan::Inclusive] {
+        let mut out = clusters(&[7; 5]);
+        let mut slice: SliceMut<u32> = SliceMut::new(&mut out, 0);
+        assert_eq!(
+            src.scan_into(&mut slice, kind, OverflowMode::Checked),
+            Ok(14)
+        );
+        assert!(out.iter().map(|c| c.offset).eq(reference(&counts, kind)));
+        assert!(out.iter().all(|c| c.count == 7));
+    }
+
+    // Only the common prefix is processed.
+    let mut out = [0_u32; 3];
+    let result = src.scan_into(
+        &mut SliceMut::native(&mut out),
+        Scan::Exclusive,
+        OverflowMode::Checked,
+    );
+    assert_eq!(result, Ok(8));
+    assert_eq!(out, [0, 3, 4]);
+
+    let counts = [u16::MAX, 1];
+    let mut out = [9_u16; 2];
+    let result = Slice::native(&counts).scan_into(
+        &mut SliceMut::native(&mut out),
+        Scan::Inclusive,
+        OverflowMode::Checked,
+    );
+    assert_eq!(result, Err(ScanError { index: 1 }));
+    assert_eq!(out, [u16::MAX, 9]);
+}
@@ -0,0 +1,119 @@
+use pas::{AlignedBytes, SegmentDesc, Segments, Slice, SliceError};
+
+/// Positions, then `u16` indices, then `u8` flags.
+fn buffer() -> AlignedBytes {
+    let mut bytes = AlignedBytes::zeroed(0, 16);
+    bytes.extend_from_slice(bytemuck::cast_slice(&[
+        [0.0_f32, 1.0, 2.0],
+        [3.0, 4.0, 5.0],
+        [6.0, 7.0, 8.0],
+    ]));
+    bytes.extend_from_slice(bytemuck::cast_slice(&[0_u16, 1, 2, 2, 1]));
+    bytes.extend_from_slice(&[1, 0, 1]);
+    bytes
+}
+
+fn descs() -> Vec<SegmentDesc> {
+    vec![
+        SegmentDesc::of::<[f32; 3]>(0, 3),
+        SegmentDesc::of::<u16>(36, 5),
+        SegmentDesc::new(46, 3, 1, 1),
+    ]
+}
+
+#[test]
+fn typed_segments() {
+    let bytes = buffer();
+    let segments = Segments::new(&bytes, descs()).unwrap();
+    assert_eq!(segments.len(), 3);
+
+    let positions: Slice<[f32; 3]> = segments.typed(0).unwrap();
+    let indices: Slice<u16> = segments.typed(1).unwrap();
+    let flags: Slice<u8> = segments.typed(2).unwrap();
+    assert_eq!(positions.len(), 3);
+    assert_eq!(positions[1], [3.0, 4.0, 5.0]);
+    assert!(indices.iter().eq([0, 1, 2, 2, 1].iter()));
+    assert!(flags.iter().eq([1, 0, 1].iter()));
+
+    let erased = segments.erased(1);
+    assert_eq!(erased.len(), 5);
+    assert_eq!(erased.get(2), Some(&2_u16.to_ne_bytes()[..]));
+
+    let sizes: Vec<usize> = segments.iter().map(|desc| desc.elem_size).collect();
+    assert_eq!(sizes, [12, 2, 1]);
+    assert_eq!(segments.desc(2).unwrap().byte_range(), Ok(46..49));
+    assert_eq!(segments.desc(3), None);
+}
+
+#[test]
+fn overlapping_segments() {
+    let bytes = buffer();
+    let mut descs = descs();
+    // Last position, as four `u32`.
+    descs.push(SegmentDesc::of::<u32>(24, 4));
+    assert_eq!(
+        Segments::new(&bytes, descs.clone()).unwrap_err(),
+        SliceError::OverlappingSegments {
+            first: 0,
+            second: 3
+        }
+    );
+
+    let segments = Segments::overlapping(&bytes, descs).unwrap();
+    let bits: Slice<u32> = segments.typed(3).unwrap();
+    assert_eq!(bits[0], 6.0_f32.to_bits());
+
+    // Adjacent and empty segments don't overlap.
+    let descs = [
+        SegmentDesc::of::<u16>(0, 2),
+        SegmentDesc::of::<u16>(4, 2),
+        SegmentDesc::of::<u16>(2, 0),
+    ];
+    assert!(Segments::new(&bytes, descs).is_ok());
+}
+
+#[test]
+fn invalid_segments() {
+    let bytes = buffer();
+    assert_eq!(
+        Segments::new(&bytes, [SegmentDesc::of::<u16>(40, 5)]).unwrap_err(),
+        SliceError::OffsetOutOfBounds {
+            size: 49,
+            offset: 50
+        }
+    );
+    assert_eq!(
+        Segments::new(&bytes, [SegmentDesc::of::<u32>(2, 1)]).unwrap_err(),
+        SliceError::AlignmentFault {
+            type_name: "segment",
+            offset: 2
+        }
+    );
+    assert_eq!(
+        Segments::new(&bytes, [SegmentDesc::of::<u32>(4, usize::MAX)]).unwrap_err(),
+        SliceError::Overflow
+    );
+}
+
+#[test]
+fn type_mismatch() {
+    let bytes = buffer();
+    let segments = Segments::new(&bytes, descs()).unwrap();
+    assert_eq!(
+        segments.typed::<u32>(1).unwrap_err(),
+        SliceError::ElementMismatch {
+            from: "segment",
+            to: "u32"
+        }
+    );
+    // Same size, but stricter alignment.
+    assert_eq!(
+        segments.typed::<u16>(2).unwrap_err(),
+        SliceError::ElementMismatch {
+            from: "segment",
+            to: "u16"
+        }
+    );
+    // Same size, and looser alignment.
+    assert!(segments.typed::<[u8; 2]>(1).is_ok());
+}
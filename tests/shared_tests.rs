@@ -1,6 +1,6 @@
 use std::borrow::{Borrow, BorrowMut};
 
-use pas::{Slice, SliceMut};
+use pas::{rebase, Slice, SliceError, SliceMut};
 
 #[repr(C)]
 #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
@@ -26,6 +26,10 @@ pub fn data() -> Vec<Vertex> {
     ]
 }
 
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Rgba([u8; 4]);
+
 // Test for [`Slice`] and [`SliceMut`] as well as [`SliceIterator`] and [`SliceIteratorMut`].
 macro_rules! tests {
     ($slice: ident, $name: ident, $borrow: ident) => { paste::expr! {
@@ -60,10 +64,12 @@ macro_rules! tests {
             ];
             let stride: usize = 6;
 
-            let positions: $slice<[u32; 3]> = $slice::strided(data.$borrow(), 0, stride);
+            #[allow(unused_mut)]
+            let mut positions: $slice<[u32; 3]> = $slice::strided(data.$borrow(), 0, stride);
             assert!(positions.iter().eq([[1, 2, 3], [7, 8, 9]].iter()));
 
-            let normals: $slice<[u32; 3]> = $slice::strided(data.$borrow(), 3 * std::mem::size_of::<u32>(), stride);
+            #[allow(unused_mut)]
+            let mut normals: $slice<[u32; 3]> = $slice::strided(data.$borrow(), 3 * std::mem::size_of::<u32>(), stride);
             assert!(normals.iter().eq([[4, 5, 6], [10, 11, 12]].iter()));
         }
 
@@ -89,14 +95,16 @@ macro_rules! tests {
             #[allow(unused_mut)]
             let mut vertices = data();
 
-            let slice: $slice<[u32; 3]> = $slice::new(vertices.$borrow(), 0);
+            #[allow(unused_mut)]
+            let mut slice: $slice<[u32; 3]> = $slice::new(vertices.$borrow(), 0);
             let mut iter = slice.iter();
             assert_eq!(*iter.next().unwrap(), [0, 1, 2]);
             assert_eq!(*iter.next().unwrap(), [5, 6, 7]);
             assert_eq!(*iter.next().unwrap(), [10, 11, 12]);
             assert_eq!(iter.next(), None);
 
-            let slice: $slice<[u32; 2]> = $slice::new(vertices.$borrow(), std::mem::size_of::<[f32; 3]>());
+            #[allow(unused_mut)]
+            let mut slice: $slice<[u32; 2]> = $slice::new(vertices.$borrow(), std::mem::size_of::<[f32; 3]>());
             let mut iter = slice.iter();
             assert_eq!(*iter.next().unwrap(), [3, 4]);
             assert_eq!(*iter.next().unwrap(), [8, 9]);
@@ -108,9 +116,48 @@ macro_rules! tests {
             assert_eq!(iter.nth(4), None);
             let mut iter = slice.iter();
             assert_eq!(iter.nth(0), Some([3, 4].$borrow()));
-            assert_eq!(iter.nth(0), Some([3, 4].$borrow()));
-            assert_eq!(iter.nth(2), Some([13, 14].$borrow()));
+            assert_eq!(iter.len(), 2);
+            assert_eq!(iter.next(), Some([8, 9].$borrow()));
             assert_eq!(iter.nth(0), Some([13, 14].$borrow()));
+            assert_eq!(iter.nth(0), None);
+            let mut iter = slice.iter();
+            assert_eq!(iter.nth(1), Some([8, 9].$borrow()));
+            assert_eq!(iter.size_hint(), (1, Some(1)));
+            assert_eq!(iter.nth(1), None);
+            assert!(slice.iter().skip(1).map(|v| *v).eq([[8, 9], [13, 14]]));
+            assert!(slice.iter().step_by(2).map(|v| *v).eq([[3, 4], [13, 14]]));
+        }
+
+        #[test]
+        fn [<iter_exact_size_$name>]() {
+            #[allow(unused_mut)]
+            let mut positions = [[0_u32; 3]; 1000];
+            #[allow(unused_mut)]
+            let mut slice: $slice<[u32; 2]> = $slice::new(positions.$borrow(), 4);
+            assert_eq!(slice.iter().len(), 1000);
+            assert_eq!(slice.iter().size_hint(), (1000, Some(1000)));
+            let collected: Vec<_> = slice.iter().map(|v| *v).collect();
+            assert_eq!(collected.len(), 1000);
+            assert_eq!(collected.capacity(), 1000);
+
+            let mut iter = slice.iter();
+            for remaining in (0..1000).rev() {
+                iter.next().unwrap();
+                assert_eq!(iter.len(), remaining);
+            }
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.len(), 0);
+
+            let mut iter = slice.iter();
+            assert!(iter.nth(1000).is_none());
+            assert_eq!(iter.len(), 0);
+
+            #[allow(unused_mut)]
+            let mut empty: [u32; 0] = [];
+            #[allow(unused_mut)]
+            let mut slice: $slice<u32> = $slice::native(empty.$borrow());
+            assert_eq!(slice.iter().len(), 0);
+            assert_eq!(slice.iter().size_hint(), (0, Some(0)));
         }
 
         #[test]
@@ -130,6 +177,197 @@ macro_rules! tests {
             let _: $slice<[u32; 3]> = $slice::new(vertices.$borrow(), bytes);
         }
 
+        #[test]
+        #[should_panic]
+        fn [<attr_crosses_element_$name>]() {
+            #[allow(unused_mut)]
+            let mut vertices = data();
+            // 16 + 8 bytes doesn't fit in a 20 bytes vertex.
+            let _: $slice<[u32; 2]> = $slice::new(vertices.$borrow(), 16);
+        }
+
+        #[test]
+        fn [<attr_fits_element_$name>]() {
+            #[allow(unused_mut)]
+            let mut vertices = data();
+            #[allow(unused_mut)]
+            let mut slice: $slice<[u32; 2]> = $slice::new(vertices.$borrow(), 12);
+            assert!(slice.iter().eq([[3, 4], [8, 9], [13, 14]].iter()));
+            #[allow(unused_mut)]
+            let mut slice: $slice<u32> = $slice::new(vertices.$borrow(), 16);
+            assert!(slice.iter().eq([4, 9, 14].iter()));
+        }
+
+        #[test]
+        fn [<unchecked_layout_$name>]() {
+            #[allow(unused_mut)]
+            let mut vertices = data();
+            #[allow(unused_mut)]
+            let mut slice: $slice<[u32; 2]> = $slice::new_unchecked_layout(vertices.$borrow(), 16);
+            assert_eq!(slice.len(), 2);
+            assert!(slice.iter().eq([[4, 5], [9, 10]].iter()));
+        }
+
+        #[test]
+        fn [<scalar_array1_round_trip_$name>]() {
+            #[allow(unused_mut)]
+            let mut data: [[u32; 1]; 3] = [[1], [2], [3]];
+            let slice: $slice<[u32; 1]> = $slice::new(data.$borrow(), 0);
+            #[allow(unused_mut)]
+            let mut scalars: $slice<u32> = slice.into();
+            assert_eq!(scalars.len(), 3);
+            assert!(scalars.iter().eq([1, 2, 3].iter()));
+
+            #[allow(unused_mut)]
+            let mut arrays: $slice<[u32; 1]> = scalars.into_array1();
+            assert!(arrays.iter().eq([[1], [2], [3]].iter()));
+            assert_eq!(arrays.into_scalar()[2], 3);
+        }
+
+        #[test]
+        fn [<transmute_elem_$name>]() {
+            #[allow(unused_mut)]
+            let mut data: [[u8; 4]; 2] = [[255, 0, 0, 255], [0, 255, 0, 128]];
+            let slice: $slice<[u8; 4]> = $slice::new(data.$borrow(), 0);
+            let colors: $slice<Rgba> = slice.transmute_elem();
+            assert_eq!(colors[0], Rgba([255, 0, 0, 255]));
+            assert_eq!(colors[1], Rgba([0, 255, 0, 128]));
+
+            let slice: $slice<[u8; 4]> = $slice::new(data.$borrow(), 0);
+            let colors: $slice<Rgba> = slice.try_transmute_elem().unwrap();
+            assert_eq!(colors.len(), 2);
+        }
+
+        #[test]
+        fn [<try_transmute_elem_mismatch_$name>]() {
+            #[allow(unused_mut)]
+            let mut data: [u32; 4] = [0, 1, 2, 3];
+            // Larger type
+            let slice: $slice<u32> = $slice::new(data.$borrow(), 0);
+            assert_eq!(
+                slice.try_transmute_elem::<u64>().err(),
+                Some(SliceError::ElementMismatch { from: "u32", to: "u64" })
+            );
+            // Same size, but more aligned
+            let slice: $slice<[u16; 2]> = $slice::new(data.$borrow(), 0);
+            assert!(slice.try_transmute_elem::<u32>().is_err());
+            // Same size, less aligned
+            let slice: $slice<u32> = $slice::new(data.$borrow(), 0);
+            assert!(slice.try_transmute_elem::<[u8; 4]>().is_ok());
+        }
+
+        #[test]
+        fn [<rebase_$name>]() {
+            let expected = data();
+            let positions: Slice<[u32; 3]> = Slice::new(&expected, 0);
+            let uvs: Slice<[u32; 2]> = Slice::new(&expected, 12);
+
+            #[allow(unused_mut)]
+            let mut vertices = data();
+            let view: $slice<Vertex> = $slice::new(vertices.$borrow(), 0);
+            #[allow(unused_mut)]
+            let mut rebased: $slice<[u32; 2]> = view.rebase(12).unwrap();
+            assert_eq!(rebased.stride(), std::mem::size_of::<Vertex>());
+            assert!(rebased.iter().eq(uvs.iter()));
+
+            let view: $slice<Vertex> = $slice::new(vertices.$borrow(), 0);
+            #[allow(unused_mut)]
+            let mut rebased = rebase!(view, position).unwrap();
+            assert!(rebased.iter().eq(positions.iter()));
+
+            let view: $slice<Vertex> = $slice::new(vertices.$borrow(), 0);
+            #[allow(unused_mut)]
+            let mut rebased = rebase!(view, uv[1]).unwrap();
+            assert!(rebased.iter().eq([4, 9, 14].iter()));
+        }
+
+        #[test]
+        fn [<rebase_invalid_$name>]() {
+            #[allow(unused_mut)]
+            let mut vertices = data();
+            let view: $slice<Vertex> = $slice::new(vertices.$borrow(), 0);
+            assert_eq!(
+                view.rebase::<[u32; 2]>(16).err(),
+                Some(SliceError::AttributeCrossesElementBoundary {
+                    type_name: "[u32; 2]",
+                    attr: 8,
+                    offset: 16,
+                    element: 20
+                })
+            );
+            let view: $slice<Vertex> = $slice::new(vertices.$borrow(), 0);
+            assert!(matches!(
+                view.rebase::<u32>(2),
+                Err(SliceError::AlignmentFault { offset: 2, .. })
+            ));
+        }
+
+        #[test]
+        fn [<bounded_$name>]() {
+            #[allow(unused_mut)]
+            let mut vertices = data();
+            let stride = std::mem::size_of::<Vertex>();
+
+            // The pool shrinks the region to the first two vertices, and a half.
+            let region = 0..2 * stride + 10;
+            let slice: $slice<[u32; 2]> = $slice::new(vertices.$borrow(), 12);
+            assert!(slice.is_within(0..3 * stride));
+            assert!(!slice.is_within(region.clone()));
+            #[allow(unused_mut)]
+            let mut slice = slice.bounded(region.clone()).unwrap();
+            assert!(slice.is_within(region.clone()));
+            assert_eq!(slice.len(), 2);
+            assert_eq!(slice.byte_offset(), 12);
+            assert!(slice.get(2).is_none());
+            assert!(slice.iter().eq([[3, 4], [8, 9]].iter()));
+
+            // The region ends right after the second `uv`.
+            let slice: $slice<[u32; 2]> = $slice::new(vertices.$borrow(), 12);
+            assert_eq!(slice.bounded(0..2 * stride).unwrap().len(), 2);
+            let slice: $slice<[u32; 2]> = $slice::new(vertices.$borrow(), 12);
+            assert_eq!(slice.bounded(0..2 * stride - 1).unwrap().len(), 1);
+
+            // Bounding never grows the slice.
+            let slice: $slice<[u32; 2]> = $slice::strided(vertices.$borrow(), 12, 2);
+            assert_eq!(slice.bounded(0..1000).unwrap().len(), 2);
+
+            // Region excluding the start.
+            let slice: $slice<[u32; 2]> = $slice::new(vertices.$borrow(), 12);
+            assert_eq!(
+                slice.bounded(16..60).err(),
+                Some(SliceError::OffsetOutOfBounds { size: 60, offset: 12 })
+            );
+            let slice: $slice<[u32; 2]> = $slice::new(vertices.$borrow(), 12);
+            assert!(slice.bounded(0..8).is_err());
+            let slice: $slice<[u32; 2]> = $slice::new(vertices.$borrow(), 12);
+            let empty = slice.bounded(0..12).unwrap();
+            assert!(empty.is_empty());
+            assert!(empty.is_within(0..12));
+        }
+
+        #[test]
+        fn [<for_each_fast_$name>]() {
+            // Lengths not divisible by the unroll factor, including empty views.
+            for len in 0..10_u32 {
+                #[allow(unused_mut)]
+                let mut data: Vec<[u32; 2]> = (0..len).map(|i| [i, i * 10]).collect();
+
+                // Interleaved
+                #[allow(unused_mut)]
+                let mut slice: $slice<u32> = $slice::new(data.$borrow(), 0);
+                let mut visited = Vec::new();
+                slice.for_each_fast(|v| visited.push(*v));
+                assert!(visited.iter().eq(slice.iter()));
+
+                // Packed
+                #[allow(unused_mut)]
+                let mut slice: $slice<[u32; 2]> = $slice::native(data.$borrow());
+                let mut visited = Vec::new();
+                slice.for_each_fast(|v| visited.push(*v));
+                assert!(visited.iter().eq(slice.iter()));
+            }
+        }
+
         #[test]
         #[should_panic]
         fn [<unaligned_attr_$name>]() {
@@ -142,3 +380,82 @@ macro_rules! tests {
 
 tests!(Slice, immutable, borrow);
 tests!(SliceMut, mutable, borrow_mut);
+
+#[test]
+fn check_layout() {
+    type Base = pas::SliceInfo<[f32; 3]>;
+    assert_eq!(Base::check_layout(0, 0, 12), Ok(0));
+    assert_eq!(Base::check_layout(60, 0, 20), Ok(3));
+    assert_eq!(Base::check_layout(60, 20, 20), Ok(2));
+    // Trailing bytes not large enough for an attribute aren't counted.
+    assert_eq!(Base::check_layout(51, 0, 20), Ok(2));
+    assert_eq!(Base::check_layout(52, 0, 20), Ok(3));
+    assert_eq!(Base::check_layout(60, 59, 20), Ok(0));
+    assert_eq!(
+        Base::check_layout(60, 60, 20),
+        Err(SliceError::OffsetOutOfBounds {
+            size: 60,
+            offset: 60
+        })
+    );
+    assert!(matches!(
+        Base::check_layout(60, 0, 8),
+        Err(SliceError::AttributeLargerThanStride { .. })
+    ));
+}
+
+/// Run on a 32-bit target with `cargo test --target i686-unknown-linux-gnu --test shared_tests`,
+/// or with `cargo miri test` and the same target, which doesn't need a 32-bit linker.
+#[test]
+fn check_layout_32_bits_boundaries() {
+    type Base = pas::SliceInfo<[f32; 3]>;
+    // Largest buffer addressable on 32-bit targets (`isize::MAX` there).
+    let bytes = (u32::MAX as usize).min(isize::MAX as usize);
+    let count = Base::check_layout(bytes, 0, 12).unwrap();
+    assert_eq!(count, (bytes - 12) / 12 + 1);
+    assert_eq!(Base::check_layout(bytes, bytes - 12, 12), Ok(1));
+    assert_eq!(Base::check_layout(bytes, bytes - 11, 12), Ok(0));
+    assert_eq!(Base::check_layout(bytes, 0, bytes), Ok(1));
+    assert!(matches!(
+        Base::check_layout(bytes, bytes, 12),
+        Err(SliceError::OffsetOutOfBounds { .. })
+    ));
+
+    // Allocations are limited to `isize::MAX` bytes.
+    let max = isize::MAX as usize;
+    assert_eq!(Base::check_layout(max, max - 12, max), Ok(1));
+    assert_eq!(
+        Base::check_layout(max + 1, 0, 12),
+        Err(SliceError::Overflow)
+    );
+    assert_eq!(
+        Base::check_layout(max, 0, max + 1),
+        Err(SliceError::Overflow)
+    );
+    assert_eq!(
+        Base::check_layout(usize::MAX, usize::MAX - 1, usize::MAX),
+        Err(SliceError::Overflow)
+    );
+}
+
+#[test]
+#[should_panic(expected = "isize::MAX")]
+fn strided_overflow() {
+    let data = [0_u32, 1, 2, 3];
+    let _: Slice<u32> = Slice::strided(&data, 0, usize::MAX / 2);
+}
+
+#[test]
+fn iter_nth_overflow() {
+    let data = [0_u32, 1, 2, 3];
+    let slice: Slice<u32> = Slice::native(&data);
+    let mut iter = slice.iter();
+    assert_eq!(iter.nth(usize::MAX), None);
+    assert_eq!(iter.next(), None);
+
+    let mut iter = slice.iter();
+    assert_eq!(iter.nth(usize::MAX / 2 + 1), None);
+    let mut iter = slice.iter();
+    assert_eq!(iter.nth(3), Some(&3));
+    assert_eq!(iter.nth(1), None);
+}
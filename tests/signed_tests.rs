@@ -0,0 +1,89 @@
+use pas::{Slice, SliceMut};
+
+fn window(slice: Slice<u32>) -> Vec<u32> {
+    slice.iter().copied().collect()
+}
+
+#[test]
+fn get_signed() {
+    let data = [[0_u32, 10], [1, 11], [2, 12], [3, 13]];
+    let slice: Slice<u32> = Slice::new(&data, 4);
+    assert_eq!(slice.get_signed(0), Some(&10));
+    assert_eq!(slice.get_signed(3), Some(&13));
+    assert_eq!(slice.get_signed(4), None);
+    assert_eq!(slice.get_signed(-1), Some(&13));
+    assert_eq!(slice.get_signed(-2), Some(&12));
+    // Magnitude equal to the length is the first element.
+    assert_eq!(slice.get_signed(-4), Some(&10));
+    assert_eq!(slice.get_signed(-5), None);
+    assert_eq!(slice.get_signed(isize::MAX), None);
+    assert_eq!(slice.get_signed(isize::MIN), None);
+
+    let empty: [u32; 0] = [];
+    let empty = Slice::native(&empty);
+    for index in [-1, 0, 1, isize::MIN] {
+        assert_eq!(empty.get_signed(index), None);
+    }
+}
+
+#[test]
+fn get_signed_mut() {
+    let mut data = [0_u32; 4];
+    let mut slice = SliceMut::native(&mut data);
+    *slice.get_signed_mut(-1).unwrap() = 3;
+    *slice.get_signed_mut(-4).unwrap() = 1;
+    assert!(slice.get_signed_mut(-5).is_none());
+    assert!(slice.get_signed_mut(4).is_none());
+    assert_eq!(data, [1, 0, 0, 3]);
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn slice_signed() {
+    let data: Vec<u32> = (0..6).collect();
+    let slice = Slice::native(&data);
+    assert_eq!(window(slice.slice_signed(..)), [0, 1, 2, 3, 4, 5]);
+    assert_eq!(window(slice.slice_signed(-2..)), [4, 5]);
+    assert_eq!(window(slice.slice_signed(..-2)), [0, 1, 2, 3]);
+    assert_eq!(window(slice.slice_signed(1..-1)), [1, 2, 3, 4]);
+    assert_eq!(window(slice.slice_signed(-4..-2)), [2, 3]);
+    assert_eq!(window(slice.slice_signed(-4..5)), [2, 3, 4]);
+    assert_eq!(window(slice.slice_signed(2..=3)), [2, 3]);
+    assert_eq!(window(slice.slice_signed(..=-1)), [0, 1, 2, 3, 4, 5]);
+    assert_eq!(window(slice.slice_signed(-1..=-1)), [5]);
+    // Magnitudes equal to, or larger than, the length are clamped.
+    assert_eq!(window(slice.slice_signed(-6..)), [0, 1, 2, 3, 4, 5]);
+    assert_eq!(window(slice.slice_signed(-10..2)), [0, 1]);
+    assert_eq!(window(slice.slice_signed(3..100)), [3, 4, 5]);
+    assert_eq!(window(slice.slice_signed(isize::MIN..isize::MAX)), data);
+    // Inverted or out of bounds ranges are empty.
+    assert!(slice.slice_signed(4..2).is_empty());
+    assert!(slice.slice_signed(-1..-2).is_empty());
+    assert!(slice.slice_signed(6..).is_empty());
+    assert!(slice.slice_signed(..-6).is_empty());
+    assert!(slice.slice_signed(..=-7).is_empty());
+
+    // Sub-slices keep the stride.
+    let interleaved = [[0_u32, 10], [1, 11], [2, 12]];
+    let odd: Slice<u32> = Slice::new(&interleaved, 4);
+    assert_eq!(window(odd.slice_signed(-2..)), [11, 12]);
+    assert_eq!(odd.slice_signed(-2..).stride(), 8);
+
+    let empty: [u32; 0] = [];
+    let empty = Slice::native(&empty);
+    assert!(empty.slice_signed(-10..).is_empty());
+    assert!(empty.slice_signed(..=0).is_empty());
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn slice_signed_mut() {
+    let mut data = [[0_u32, 0]; 20];
+    let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 4);
+    slice.slice_signed_mut(-10..).fill(1);
+    slice.slice_signed_mut(..2).fill(2);
+    assert_eq!(slice.slice_signed_mut(5..-20).len(), 0);
+    assert!(data[..2].iter().all(|v| *v == [0, 2]));
+    assert!(data[2..10].iter().all(|v| *v == [0, 0]));
+    assert!(data[10..].iter().all(|v| *v == [0, 1]));
+}
@@ -1,4 +1,4 @@
-use pas::SliceMut;
+use pas::{Slice, SliceError, SliceMut};
 
 #[repr(C)]
 #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
@@ -58,7 +58,7 @@ fn mutable_indexing() {
 #[test]
 fn copy_from_slice() {
     let mut vertices = data();
-    let slice: SliceMut<[u32; 3]> = SliceMut::new(&mut vertices, 0);
+    let mut slice: SliceMut<[u32; 3]> = SliceMut::new(&mut vertices, 0);
 
     slice.copy_from_slice(&[[20, 21, 22]]);
     assert_eq!(slice[0], [20, 21, 22]);
@@ -66,7 +66,8 @@ fn copy_from_slice() {
     assert_eq!(slice[0], [30, 31, 32]);
     assert_eq!(slice[1], [33, 34, 35]);
 
-    let slice: SliceMut<[u32; 2]> = SliceMut::new(&mut vertices, std::mem::size_of::<[f32; 3]>());
+    let mut slice: SliceMut<[u32; 2]> =
+        SliceMut::new(&mut vertices, std::mem::size_of::<[f32; 3]>());
     slice.copy_from_slice(&[[101, 102]]);
     assert_eq!(slice[0], [101, 102]);
     slice.copy_from_slice(&[[103, 104], [105, 106]]);
@@ -78,3 +79,388 @@ fn copy_from_slice() {
     assert_eq!(slice[0], [30, 31, 32]);
     assert_eq!(slice[1], [33, 34, 35]);
 }
+
+#[test]
+fn write_extended_from() {
+    let vertices = data();
+    let positions: Slice<[u32; 3]> = Slice::new(&vertices, 0);
+
+    // Destination is interleaved, with a `[u32; 4]` attribute followed by a `u32`.
+    let mut dest = [[100_u32; 5]; 3];
+    let mut slice: SliceMut<[u32; 4]> = SliceMut::new(&mut dest, 0);
+    assert_eq!(slice.write_extended_from(&positions, 1), 3);
+    assert_eq!(
+        dest,
+        [[0, 1, 2, 1, 100], [5, 6, 7, 1, 100], [10, 11, 12, 1, 100]]
+    );
+}
+
+#[test]
+fn write_extended_from_mismatched_len() {
+    let vertices = data();
+    let positions: Slice<[u32; 3]> = Slice::new(&vertices, 0);
+
+    // Destination shorter than the source.
+    let mut dest = [[100_u32; 4]; 2];
+    let mut slice: SliceMut<[u32; 4]> = SliceMut::new(&mut dest, 0);
+    assert_eq!(slice.write_extended_from(&positions, 1), 2);
+    assert_eq!(dest, [[0, 1, 2, 1], [5, 6, 7, 1]]);
+
+    // Destination longer than the source.
+    let mut dest = [[100_u32; 4]; 4];
+    let mut slice: SliceMut<[u32; 4]> = SliceMut::new(&mut dest, 0);
+    assert_eq!(slice.write_extended_from(&positions, 1), 3);
+    assert_eq!(dest[2], [10, 11, 12, 1]);
+    assert_eq!(dest[3], [100, 100, 100, 100]);
+}
+
+#[test]
+fn write_mapped_from() {
+    let vertices = data();
+    let positions: Slice<[u32; 3]> = Slice::new(&vertices, 0);
+
+    let mut dest = [[0.0_f32; 4]; 3];
+    let mut slice: SliceMut<[f32; 4]> = SliceMut::new(&mut dest, 0);
+    let count =
+        slice.write_mapped_from(&positions, |p| [p[0] as f32, p[1] as f32, p[2] as f32, 1.0]);
+    assert_eq!(count, 3);
+    assert_eq!(dest[1], [5.0, 6.0, 7.0, 1.0]);
+    assert_eq!(dest[2], [10.0, 11.0, 12.0, 1.0]);
+}
+
+// Bulk writes walk elements by pointer increment, run with Miri to check
+// they stay in bounds.
+#[test]
+fn bulk_write_boundaries() {
+    // Empty
+    let mut data: [u32; 0] = [];
+    let mut slice: SliceMut<u32> = SliceMut::native(&mut data);
+    slice.fill(1);
+    slice.copy_from_slice::<u32>(&[]);
+    assert_eq!(
+        slice.write_mapped_from(&Slice::<u32>::native(&[1]), |v| *v),
+        0
+    );
+
+    let mut slice: SliceMut<u32> = SliceMut::default();
+    slice.fill(1);
+
+    // Single element, with a stride larger than the buffer.
+    let mut data = [0_u32; 2];
+    let mut slice: SliceMut<u32> = SliceMut::strided(&mut data, 4, 4);
+    assert_eq!(slice.len(), 1);
+    slice.fill(7);
+    slice.copy_from_slice(&[8_u32]);
+    assert_eq!(data, [0, 8]);
+
+    // Exact fit: the last element ends the buffer, past the end of the final stride.
+    let mut data = [0_u32; 7];
+    let mut slice: SliceMut<u32> = SliceMut::strided(&mut data, 0, 3);
+    assert_eq!(slice.len(), 3);
+    slice.fill(1);
+    assert_eq!(data, [1, 0, 0, 1, 0, 0, 1]);
+    let mut slice: SliceMut<u32> = SliceMut::strided(&mut data, 0, 3);
+    slice.copy_from_slice(&[2_u32, 3, 4]);
+    assert_eq!(data, [2, 0, 0, 3, 0, 0, 4]);
+    let mut slice: SliceMut<u32> = SliceMut::strided(&mut data, 0, 3);
+    let src = [5_u32, 6, 7, 8];
+    assert_eq!(slice.write_mapped_from(&Slice::native(&src), |v| *v), 3);
+    assert_eq!(data, [5, 0, 0, 6, 0, 0, 7]);
+}
+
+#[test]
+fn for_each_fast_mut() {
+    for len in 0..10_u32 {
+        let mut data: Vec<[u32; 2]> = (0..len).map(|i| [i, i * 10]).collect();
+        let mut expected = data.clone();
+
+        // Interleaved
+        let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 0);
+        slice.for_each_fast_mut(|v| *v += 1);
+        let mut slice: SliceMut<u32> = SliceMut::new(&mut expected, 0);
+        slice.iter().for_each(|v| *v += 1);
+        assert_eq!(data, expected);
+
+        // Packed
+        let mut slice: SliceMut<[u32; 2]> = SliceMut::native(&mut data);
+        slice.for_each_fast_mut(|v| v[0] *= 3);
+        let mut slice: SliceMut<[u32; 2]> = SliceMut::native(&mut expected);
+        slice.iter().for_each(|v| v[0] *= 3);
+        assert_eq!(data, expected);
+    }
+}
+
+#[test]
+fn for_each_pair_mut() {
+    // In-place smoothing of the first component, each element reading the
+    // already smoothed previous one.
+    let mut data = [[0.0_f32, 9.0], [4.0, 9.0], [8.0, 9.0], [0.0, 9.0]];
+    let mut slice: SliceMut<f32> = SliceMut::new(&mut data, 0);
+    slice.for_each_pair_mut(|previous, current| *current = (*previous + *current) * 0.5);
+    assert_eq!(data, [[0.0, 9.0], [2.0, 9.0], [5.0, 9.0], [2.5, 9.0]]);
+
+    let mut pairs = Vec::new();
+    let mut data: Vec<[u32; 3]> = (0..5).map(|i| [i, i * 10, i * 100]).collect();
+    let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 4);
+    slice.for_each_pair_mut(|previous, current| pairs.push((*previous, *current)));
+    assert_eq!(pairs, [(0, 10), (10, 20), (20, 30), (30, 40)]);
+
+    // Fewer than two elements, no pair.
+    for len in 0..2 {
+        let mut data = vec![0_u32; len];
+        let mut slice = SliceMut::native(&mut data);
+        slice.for_each_pair_mut(|_, _| panic!("unexpected pair"));
+    }
+}
+
+#[test]
+fn rotate() {
+    for len in 0..8_u32 {
+        let vertices: Vec<Vertex> = (0..len)
+            .map(|i| Vertex {
+                position: [i, i + 1, i + 2],
+                uv: [i * 10, i * 20],
+            })
+            .collect();
+        for k in 0..2 * len as usize + 2 {
+            let mut expected: Vec<[u32; 3]> = vertices.iter().map(|v| v.position).collect();
+            let mut left = vertices.clone();
+            let mut slice: SliceMut<[u32; 3]> = SliceMut::new(&mut left, 0);
+            slice.rotate_left(k);
+            if len > 0 {
+                expected.rotate_left(k % len as usize);
+            }
+            assert!(left.iter().map(|v| v.position).eq(expected.iter().copied()));
+
+            let mut right = left.clone();
+            let mut slice: SliceMut<[u32; 3]> = SliceMut::new(&mut right, 0);
+            slice.rotate_right(k);
+            assert!(right
+                .iter()
+                .zip(&vertices)
+                .all(|(a, b)| a.position == b.position));
+
+            // Uvs are untouched.
+            assert!(left.iter().zip(&vertices).all(|(a, b)| a.uv == b.uv));
+        }
+    }
+}
+
+#[test]
+fn try_apply_with() {
+    let velocities = [[1_u32, 0], [2, 0], [3, 0], [4, 0]];
+    let velocities: Slice<u32> = Slice::new(&velocities, 0);
+    let add = |_: usize, p: &mut u32, v: &u32| {
+        *p += v;
+        match *p > 10 {
+            true => Err(*p),
+            false => Ok(()),
+        }
+    };
+
+    // Success
+    let mut data = [[0_u32, 7], [1, 7], [2, 7], [3, 7]];
+    let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 0);
+    assert_eq!(slice.try_apply_with(&velocities, add), Ok(()));
+    assert_eq!(data, [[1, 7], [3, 7], [5, 7], [7, 7]]);
+
+    // Failure at index 0
+    let mut data = [[10_u32, 7], [1, 7], [2, 7], [3, 7]];
+    let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 0);
+    assert_eq!(slice.try_apply_with(&velocities, add), Err((0, 11)));
+    assert_eq!(data, [[11, 7], [1, 7], [2, 7], [3, 7]]);
+
+    // Failure mid-way, the suffix is untouched
+    let mut data = [[0_u32, 7], [9, 7], [2, 7], [3, 7]];
+    let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 0);
+    assert_eq!(slice.try_apply_with(&velocities, add), Err((1, 11)));
+    assert_eq!(data, [[1, 7], [11, 7], [2, 7], [3, 7]]);
+
+    // Mismatched lengths, only the prefix is processed
+    let mut data = [0_u32; 6];
+    let mut slice: SliceMut<u32> = SliceMut::native(&mut data);
+    let mut visited = Vec::new();
+    let result = slice.try_apply_with(&velocities, |i, p, v| {
+        visited.push(i);
+        add(i, p, v)
+    });
+    assert_eq!(result, Ok(()));
+    assert_eq!(visited, [0, 1, 2, 3]);
+    assert_eq!(data, [1, 2, 3, 4, 0, 0]);
+
+    let mut data = [[0_u32, 7], [0, 7]];
+    let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 4);
+    assert_eq!(
+        slice.try_apply_with(&velocities, |_, p, v| add(0, p, v)),
+        Ok(())
+    );
+    assert_eq!(data, [[0, 8], [0, 9]]);
+}
+
+#[test]
+fn try_for_each_zip() {
+    let a = [[1_u32, 0], [2, 0], [3, 0]];
+    let a: Slice<u32> = Slice::new(&a, 0);
+    let b = [1.0_f32, 2.0, f32::NAN, 4.0, f32::NAN];
+    let b: Slice<f32> = Slice::native(&b);
+    let check = |_: usize, a: &u32, b: &f32| match *a as f32 == *b {
+        true => Ok(()),
+        false => Err(*a),
+    };
+
+    assert_eq!(a.try_for_each_zip(&b, check), Err((2, 3)));
+    assert_eq!(
+        b.try_for_each_zip(&a, |i, b, a| check(i, a, b)),
+        Err((2, 3))
+    );
+
+    // Only the common prefix is visited.
+    let mut visited = Vec::new();
+    let result = a.try_for_each_zip(&b.split_at(2).0, |i, a, b| {
+        visited.push(i);
+        check(i, a, b)
+    });
+    assert_eq!(result, Ok(()));
+    assert_eq!(visited, [0, 1]);
+}
+
+#[test]
+fn byte_offset_patch() {
+    let mut vertices = data();
+    let mut uvs: SliceMut<[u32; 2]> = SliceMut::new(&mut vertices, 12);
+    for (index, offset) in [12, 32, 52].into_iter().enumerate() {
+        assert_eq!(uvs.index_at_byte_offset(offset), Ok(index));
+    }
+    uvs.write_at_byte_offset(12, [30, 40]).unwrap();
+    uvs.write_at_byte_offset(52, [130, 140]).unwrap();
+
+    let not_element_start = |offset| SliceError::NotElementStart {
+        offset,
+        byte_offset: 12,
+        stride: 20,
+        len: 3,
+    };
+    // Before the first element.
+    assert_eq!(
+        uvs.write_at_byte_offset(4, [0, 0]),
+        Err(not_element_start(4))
+    );
+    // Middle of an element.
+    assert_eq!(
+        uvs.write_at_byte_offset(16, [0, 0]),
+        Err(not_element_start(16))
+    );
+    // Position of the next vertex, in the window of another attribute.
+    assert_eq!(
+        uvs.write_at_byte_offset(20, [0, 0]),
+        Err(not_element_start(20))
+    );
+    // After the last element.
+    assert_eq!(
+        uvs.write_at_byte_offset(72, [0, 0]),
+        Err(not_element_start(72))
+    );
+
+    assert_eq!(vertices[0].uv, [30, 40]);
+    assert_eq!(vertices[1].uv, [8, 9]);
+    assert_eq!(vertices[2].uv, [130, 140]);
+    assert_eq!(vertices[1].position, [5, 6, 7]);
+}
+
+#[test]
+fn byte_offset_gap() {
+    let data = [[0_u32, 1, 2], [3, 4, 5]];
+    // Elements at bytes 0 and 12, with a gap of 8 bytes.
+    let slice: Slice<u32> = Slice::new(&data, 0);
+    assert_eq!(slice.index_at_byte_offset(12), Ok(1));
+    assert!(slice.index_at_byte_offset(4).is_err());
+    assert!(slice.index_at_byte_offset(8).is_err());
+    assert!(slice.index_at_byte_offset(24).is_err());
+
+    let empty: Slice<u32> = Slice::native(&[]);
+    assert!(empty.index_at_byte_offset(0).is_err());
+}
+
+#[test]
+fn write_lanes_masks() {
+    let src = [[100_u32, 101, 102], [103, 104, 105], [106, 107, 108]];
+    let masks = [
+        [true, false, true],
+        [false, true, false],
+        [true, true, true],
+        [false, false, false],
+    ];
+    for mask in masks {
+        let mut vertices = data();
+        let count = SliceMut::<[u32; 3]>::new(&mut vertices, 0)
+            .write_lanes_from(&Slice::native(&src), mask);
+        assert_eq!(count, 3);
+        for ((vertex, old), new) in vertices.iter().zip(data()).zip(src) {
+            for lane in 0..3 {
+                let expected = if mask[lane] {
+                    new[lane]
+                } else {
+                    old.position[lane]
+                };
+                assert_eq!(vertex.position[lane], expected);
+            }
+            assert_eq!(vertex.uv, old.uv);
+        }
+    }
+}
+
+#[test]
+fn write_lane_read_back() {
+    let heights = [40_u32, 41, 42];
+    let mut vertices = data();
+    let mut positions: SliceMut<[u32; 3]> = SliceMut::new(&mut vertices, 0);
+    assert_eq!(positions.write_lane(1, &Slice::native(&heights)), 3);
+
+    let positions: Slice<[u32; 3]> = Slice::new(&vertices, 0);
+    let components: Vec<u32> = positions.components().iter().copied().collect();
+    assert_eq!(components, [0, 40, 2, 5, 41, 7, 10, 42, 12]);
+    assert!(vertices.iter().zip(data()).all(|(v, old)| v.uv == old.uv));
+}
+
+#[test]
+fn write_lanes_length_mismatch() {
+    // Shorter source: the remaining elements are untouched.
+    let mut vertices = data();
+    let mut positions: SliceMut<[u32; 3]> = SliceMut::new(&mut vertices, 0);
+    assert_eq!(positions.write_lane(2, &Slice::native(&[50_u32])), 1);
+    let src = [[60_u32; 3]; 2];
+    assert_eq!(
+        positions.write_lanes_from(&Slice::native(&src), [true, false, false]),
+        2
+    );
+    let positions: Vec<[u32; 3]> = vertices.iter().map(|v| v.position).collect();
+    assert_eq!(positions, [[60, 1, 50], [60, 6, 7], [10, 11, 12]]);
+
+    // Shorter destination.
+    let mut dst = [[0_u32; 3]; 2];
+    let heights = [1_u32, 2, 3, 4];
+    assert_eq!(
+        SliceMut::native(&mut dst).write_lane(0, &Slice::native(&heights)),
+        2
+    );
+    assert_eq!(dst, [[1, 0, 0], [2, 0, 0]]);
+}
+
+#[test]
+#[should_panic]
+fn write_lane_out_of_bounds() {
+    let mut dst = [[0_u32; 3]; 2];
+    SliceMut::native(&mut dst).write_lane(3, &Slice::native(&[0_u32; 2]));
+}
+
+#[test]
+fn update_lanes_keeps_disabled() {
+    let mut vertices = data();
+    let mut positions: SliceMut<[u32; 3]> = SliceMut::new(&mut vertices, 0);
+    positions.update_lanes([true, false, true], |index, p| {
+        *p = [index as u32, 99, p[2] * 2];
+    });
+    let positions: Vec<[u32; 3]> = vertices.iter().map(|v| v.position).collect();
+    assert_eq!(positions, [[0, 1, 4], [1, 6, 14], [2, 11, 24]]);
+    assert!(vertices.iter().zip(data()).all(|(v, old)| v.uv == old.uv));
+}
@@ -0,0 +1,95 @@
+use pas::{soa, Slice, SliceError, SliceMut};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub color: [u8; 4],
+}
+
+fn vertices() -> Vec<Vertex> {
+    (0..4)
+        .map(|i| Vertex {
+            position: [i as f32, 0.0, 1.0],
+            color: [i as u8; 4],
+        })
+        .collect()
+}
+
+#[test]
+fn join() {
+    let vertices = vertices();
+    let ids = [10_u32, 11, 12, 13, 14];
+    let positions: Slice<[f32; 3]> = Slice::new(&vertices, 0);
+    let colors: Slice<[u8; 4]> = Slice::new(&vertices, 12);
+    let ids: Slice<u32> = Slice::native(&ids);
+
+    let records = soa((positions, colors, ids));
+    assert_eq!(records.len(), 4);
+    assert_eq!(records.get(2), Some((&[2.0, 0.0, 1.0], &[2; 4], &12)));
+    assert_eq!(records.get(4), None);
+
+    let iter = records.iter();
+    assert_eq!(iter.len(), 4);
+    let ids: Vec<u32> = iter.map(|(_, _, id)| *id).collect();
+    assert_eq!(ids, [10, 11, 12, 13]);
+
+    let empty = soa((positions, Slice::<u32>::native(&[])));
+    assert!(empty.is_empty());
+    assert_eq!(empty.iter().next(), None);
+}
+
+#[test]
+fn gather_records_into() {
+    let vertices = vertices();
+    let ids = [10_u32, 11, 12, 13];
+    let records = soa((
+        Slice::<[f32; 3]>::new(&vertices, 0),
+        Slice::<[u8; 4]>::new(&vertices, 12),
+        Slice::<u32>::native(&ids),
+    ));
+
+    let mut positions = [[0.0_f32; 3]; 5];
+    let mut colors = [[0_u8; 4]; 5];
+    let mut ids = [0_u32; 6];
+    let mut dst_positions = SliceMut::native(&mut positions);
+    let mut dst_colors = SliceMut::native(&mut colors);
+    let mut dst_ids = SliceMut::native(&mut ids);
+
+    let written = records.gather_records_into(
+        &[3_u16, 0, 3, 1, 2],
+        (&mut dst_positions, &mut dst_colors, &mut dst_ids),
+    );
+    assert_eq!(written, Ok(5));
+    assert_eq!(positions.map(|p| p[0]), [3.0, 0.0, 3.0, 1.0, 2.0]);
+    assert_eq!(colors.map(|c| c[0]), [3, 0, 3, 1, 2]);
+    assert_eq!(ids, [13, 10, 13, 11, 12, 0]);
+}
+
+#[test]
+fn gather_records_into_out_of_bounds() {
+    let vertices = vertices();
+    let ids = [10_u32, 11, 12, 13, 14, 15];
+    let records = soa((Slice::<[f32; 3]>::new(&vertices, 0), Slice::native(&ids)));
+
+    let mut positions = [[0.0_f32; 3]; 3];
+    let mut ids = [0_u32; 3];
+    // Index `4` is valid for the ids, but not for the records.
+    let result = records.gather_records_into(
+        &[1_u32, 4, 0],
+        (
+            &mut SliceMut::native(&mut positions),
+            &mut SliceMut::native(&mut ids),
+        ),
+    );
+    assert_eq!(
+        result,
+        Err(SliceError::IndexOutOfBounds {
+            position: 1,
+            index: 4,
+            len: 4
+        })
+    );
+    assert_eq!(positions, [[0.0; 3]; 3]);
+    assert_eq!(ids, [0; 3]);
+}
@@ -0,0 +1,187 @@
+use pas::{AttributeError, AttributeSet, Slice, SliceError, SliceMut, SparseAttribute, TaggedPod};
+
+#[test]
+fn sparse_reads_writes() {
+    let mut sparse = SparseAttribute::new(6, [0_u8, 1]);
+    assert_eq!((sparse.len(), sparse.density()), (6, 0.0));
+    assert_eq!(sparse.default_value(), [0, 1]);
+
+    assert_eq!(sparse.set(4, [4, 4]), Ok(None));
+    assert_eq!(sparse.set(1, [1, 1]), Ok(None));
+    assert_eq!(sparse.set(4, [40, 40]), Ok(Some([4, 4])));
+    assert_eq!(sparse.get(0), Some([0, 1]));
+    assert_eq!(sparse.get(4), Some([40, 40]));
+    assert_eq!(sparse.get(6), None);
+    assert_eq!(
+        sparse.entries().collect::<Vec<_>>(),
+        [(1, &[1, 1]), (4, &[40, 40])]
+    );
+    assert_eq!(sparse.density(), 2.0 / 6.0);
+    assert_eq!(
+        sparse.set(6, [0, 0]),
+        Err(SliceError::IndexOutOfBounds {
+            position: 0,
+            index: 6,
+            len: 6
+        })
+    );
+
+    assert_eq!(sparse.remove(1), Some([1, 1]));
+    assert_eq!(sparse.remove(1), None);
+    assert_eq!(sparse.get(1), Some([0, 1]));
+
+    // Growing uses the default, shrinking drops the entries past the end.
+    sparse.resize(8);
+    sparse.set(7, [7, 7]).unwrap();
+    assert_eq!(sparse.get(6), Some([0, 1]));
+    sparse.resize(4);
+    assert_eq!(sparse.entries().len(), 0);
+    sparse.resize(5);
+    assert_eq!(sparse.get(4), Some([0, 1]));
+
+    let empty = SparseAttribute::new(0, 0.0_f32);
+    assert!(empty.is_empty());
+    assert_eq!(empty.density(), 0.0);
+    assert_eq!(empty.iter().next(), None);
+}
+
+#[test]
+fn sparse_materialize() {
+    let mut reference = vec![[0.0_f32; 3]; 1000];
+    let mut sparse = SparseAttribute::new(1000, [0.0_f32; 3]);
+    for index in [999, 0, 512, 3, 4] {
+        let value = [index as f32, 1.0, 2.0];
+        sparse.set(index, value).unwrap();
+        reference[index] = value;
+    }
+    assert_eq!(sparse.materialize_to_vec(), reference);
+    assert_eq!(sparse.iter().len(), 1000);
+    assert_eq!(sparse.iter().collect::<Vec<_>>(), reference);
+
+    let mut vertices = vec![[[9.0_f32; 3]; 2]; 1000];
+    let mut dst: SliceMut<[f32; 3]> = SliceMut::new(&mut vertices, 12);
+    sparse.materialize_into(&mut dst).unwrap();
+    assert!(vertices.iter().zip(&reference).all(|(v, r)| v[1] == *r));
+    assert!(vertices.iter().all(|v| v[0] == [9.0; 3]));
+
+    let mut short = vec![[9.0_f32; 3]; 999];
+    assert_eq!(
+        sparse.materialize_into(&mut SliceMut::native(&mut short)),
+        Err(SliceError::LengthMismatch {
+            expected: 1000,
+            len: 999
+        })
+    );
+    assert!(short.iter().all(|v| *v == [9.0; 3]));
+}
+
+#[test]
+fn sparse_from_dense() {
+    let dense = [[0_u32, 7], [1, 0], [0, 8], [0, 9], [3, 0]];
+    let lane: Slice<u32> = Slice::new(&dense, 0);
+    let sparse = SparseAttribute::from_dense(&lane, 0);
+    assert_eq!(sparse.entries().collect::<Vec<_>>(), [(1, &1), (4, &3)]);
+    assert_eq!(sparse.materialize_to_vec(), [0, 1, 0, 0, 3]);
+
+    // Exact comparison tells `-0.0` from `0.0`.
+    let weights = [0.0_f32, -0.0, 1e-7, 0.5, f32::NAN, 0.0];
+    let src = Slice::native(&weights);
+    let exact = SparseAttribute::from_dense(&src, 0.0);
+    assert_eq!(
+        exact.entries().map(|(index, _)| index).collect::<Vec<_>>(),
+        [1, 2, 3, 4]
+    );
+    let round_trip = exact.materialize_to_vec();
+    assert_eq!(
+        bytemuck::cast_slice::<f32, u32>(&round_trip),
+        bytemuck::cast_slice::<f32, u32>(&weights)
+    );
+
+    let approx = SparseAttribute::from_dense_approx(&src, 0.0, 1e-6);
+    assert_eq!(
+        approx.entries().map(|(index, _)| index).collect::<Vec<_>>(),
+        [3, 4]
+    );
+    let round_trip = approx.materialize_to_vec();
+    assert!(round_trip
+        .iter()
+        .zip(&weights)
+        .all(|(a, b)| (a - b).abs() <= 1e-6 || (a.is_nan() && b.is_nan())));
+
+    let normals = [
+        [0.0_f32, 0.0, 1.0],
+        [0.0, 1e-3, 1.0],
+        [0.0, 0.0, 1.0 + 1e-5],
+    ];
+    let approx =
+        SparseAttribute::from_dense_approx(&Slice::native(&normals), [0.0, 0.0, 1.0], 1e-4);
+    assert_eq!(approx.entries().collect::<Vec<_>>(), [(1, &normals[1])]);
+}
+
+#[test]
+fn sparse_in_attribute_set() {
+    let positions = [[0.0_f32; 3]; 4];
+    let mut weights = SparseAttribute::new(4, 0.0_f32);
+    weights.set(1, 0.5).unwrap();
+    let short = SparseAttribute::new(3, 0_u8);
+
+    let mut set = AttributeSet::new();
+    set.insert("position", Slice::native(&positions));
+    assert!(!set.insert_sparse("weight", &weights));
+    assert_eq!(set.len(), 2);
+    assert_eq!(set.validate_counts(), Ok(4));
+
+    let found = set.get_sparse::<f32>("weight").unwrap();
+    assert!(std::ptr::eq(found, &weights));
+    assert_eq!(
+        set.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+        ["position"]
+    );
+    assert_eq!(
+        set.iter_sparse().collect::<Vec<_>>(),
+        [("weight", f32::TAG)]
+    );
+    assert!(set.get_erased("weight").is_none());
+
+    assert_eq!(
+        set.get::<f32>("weight").unwrap_err(),
+        AttributeError::KindMismatch {
+            name: "weight".into(),
+            sparse: true
+        }
+    );
+    assert_eq!(
+        set.get_sparse::<[f32; 3]>("position").unwrap_err(),
+        AttributeError::KindMismatch {
+            name: "position".into(),
+            sparse: false
+        }
+    );
+    assert_eq!(
+        set.get_sparse::<u32>("weight").unwrap_err(),
+        AttributeError::TypeMismatch {
+            name: "weight".into(),
+            error: SliceError::TagMismatch {
+                expected: u32::TAG,
+                found: Some(f32::TAG),
+            }
+        }
+    );
+    assert!(matches!(
+        set.get_or_broadcast("weight", &0.0_f32, 4),
+        Err(AttributeError::KindMismatch { .. })
+    ));
+
+    // Sparse attributes are checked with their logical length, and replace
+    // attributes of any kind.
+    assert!(set.insert_sparse("position", &short));
+    assert_eq!(set.iter().count(), 0);
+    assert_eq!(
+        set.validate_counts(),
+        Err(AttributeError::CountMismatch {
+            name: "weight".into(),
+            expected: 3,
+            count: 4
+        })
+    );
+}
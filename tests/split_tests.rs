@@ -0,0 +1,370 @@
+use pas::{
+    for_each_vertex, for_each_vertex2, split_attr_read_send, split_attr_read_write,
+    split_attrs_read_write, SendableLane, Slice, SliceError, SliceMut,
+};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub uv: [f32; 2],
+}
+
+fn vertices() -> Vec<Vertex> {
+    (0..4)
+        .map(|i| Vertex {
+            position: [i as f32, i as f32 + 0.5, -(i as f32)],
+            uv: [0.0; 2],
+        })
+        .collect()
+}
+
+#[test]
+fn read_while_writing() {
+    let mut data = vertices();
+    let (positions, mut uvs): (Slice<[f32; 3]>, SliceMut<[f32; 2]>) =
+        split_attr_read_write(&mut data, 0, 12).unwrap();
+    assert_eq!((positions.len(), uvs.len()), (4, 4));
+    for (index, uv) in uvs.iter().enumerate() {
+        let p = positions[index];
+        *uv = [p[0] * 2.0, p[1] + p[2]];
+        // Reads interleaved with writes still see the positions.
+        assert_eq!(positions[index][0], index as f32);
+    }
+    for (i, v) in data.iter().enumerate() {
+        let i = i as f32;
+        assert_eq!(v.position, [i, i + 0.5, -i]);
+        assert_eq!(v.uv, [i * 2.0, 0.5]);
+    }
+}
+
+#[test]
+fn multiple_reads() {
+    let mut data = vertices();
+    // Read attributes may overlap, e.g., `x` twice.
+    let ([x, y, z, x_again], mut v): ([Slice<f32>; 4], SliceMut<f32>) =
+        split_attrs_read_write(&mut data, [0, 4, 8, 0], 16).unwrap();
+    for (index, value) in v.iter().enumerate() {
+        *value = x[index] + y[index] - z[index] + x_again[index];
+    }
+    assert!(data
+        .iter()
+        .enumerate()
+        .all(|(i, v)| v.uv == [0.0, 4.0 * i as f32 + 0.5]));
+
+    // No read view.
+    let ([], uvs): ([Slice<f32>; 0], SliceMut<[f32; 2]>) =
+        split_attrs_read_write(&mut data, [], 12).unwrap();
+    assert_eq!(uvs.len(), 4);
+}
+
+#[test]
+fn overlapping_attributes() {
+    let mut data = vertices();
+    assert_eq!(
+        split_attr_read_write::<_, [f32; 3], [f32; 2]>(&mut data, 0, 8).err(),
+        Some(SliceError::OverlappingAttributes { read: 0, write: 8 })
+    );
+    assert_eq!(
+        split_attr_read_write::<_, [f32; 2], f32>(&mut data, 12, 12).err(),
+        Some(SliceError::OverlappingAttributes {
+            read: 12,
+            write: 12
+        })
+    );
+    // Offsets past the first element are compared within the element.
+    assert_eq!(
+        split_attr_read_write::<_, [f32; 3], f32>(&mut data, 0, 20 + 4).err(),
+        Some(SliceError::OverlappingAttributes { read: 0, write: 4 })
+    );
+    assert_eq!(
+        split_attrs_read_write::<_, f32, [f32; 2], 3>(&mut data, [0, 4, 16], 12).err(),
+        Some(SliceError::OverlappingAttributes {
+            read: 16,
+            write: 12
+        })
+    );
+
+    // Adjacent attributes don't overlap.
+    let (x, y) = split_attr_read_write::<_, f32, f32>(&mut data, 20, 4).unwrap();
+    assert_eq!((x.len(), y.len()), (3, 4));
+    assert_eq!(x[0], 1.0);
+}
+
+#[test]
+fn invalid_attributes() {
+    let mut data = vertices();
+    assert!(matches!(
+        split_attr_read_write::<_, [f32; 2], f32>(&mut data, 16, 0),
+        Err(SliceError::AttributeCrossesElementBoundary { offset: 16, .. })
+    ));
+    assert!(matches!(
+        split_attr_read_write::<_, f32, f32>(&mut data, 0, 2),
+        Err(SliceError::AlignmentFault { offset: 2, .. })
+    ));
+    assert!(matches!(
+        split_attr_read_write::<_, f32, f32>(&mut data, 0, 80),
+        Err(SliceError::OffsetOutOfBounds { .. })
+    ));
+
+    let mut empty: Vec<Vertex> = Vec::new();
+    let (positions, uvs) =
+        split_attr_read_write::<_, [f32; 3], [f32; 2]>(&mut empty, 0, 12).unwrap();
+    assert!(positions.is_empty() && uvs.is_empty());
+    assert!(split_attr_read_write::<_, [f32; 3], [f32; 2]>(&mut empty, 0, 4).is_err());
+}
+
+/// Views split from a buffer can't be rebased over each other. Also run under Miri.
+#[test]
+fn rebase_within_attribute() {
+    let mut data = [[1_u32, 2]; 4];
+    let (read, write): (Slice<u32>, SliceMut<u32>) =
+        split_attr_read_write(&mut data, 0, 4).unwrap();
+    let r = &read[1];
+    // Only the second `u32` of each element is owned by the write view.
+    assert_eq!(
+        write.rebase::<u32>(4).err(),
+        Some(SliceError::AttributeCrossesElementBoundary {
+            type_name: "u32",
+            attr: 4,
+            offset: 4,
+            element: 4,
+        })
+    );
+    assert!(read.rebase::<u32>(4).is_err());
+    assert_eq!(*r, 1);
+
+    // Views derived from a split view own the same bytes.
+    let mut data = vertices();
+    let (positions, uvs): (Slice<[f32; 3]>, SliceMut<[f32; 2]>) =
+        split_attr_read_write(&mut data, 0, 12).unwrap();
+    let yz = positions.split_at(2).1.rebase::<f32>(4).unwrap();
+    assert_eq!(yz.rebase::<[f32; 2]>(0).unwrap()[1], [3.5, -3.0]);
+    assert!(yz.rebase::<[f32; 2]>(4).is_err());
+    assert!(positions.bounded(0..60).unwrap().rebase::<f32>(12).is_err());
+    let mut v = uvs.bounded(0..60).unwrap().rebase::<f32>(4).unwrap();
+    v.fill(7.0);
+    assert!(v.rebase::<f32>(4).is_err());
+    assert_eq!(positions[3], [3.0, 3.5, -3.0]);
+    assert!(data[..3].iter().all(|v| v.uv == [0.0, 7.0]));
+}
+
+#[test]
+fn fused_loop() {
+    let mut data = vertices();
+    let mut visited = Vec::new();
+    for_each_vertex(
+        &mut data,
+        0,
+        12,
+        |index, p: &[f32; 3], uv: &mut [f32; 2]| {
+            visited.push(index);
+            *uv = [p[0] * 2.0, p[1] + p[2]];
+        },
+    )
+    .unwrap();
+    assert_eq!(visited, [0, 1, 2, 3]);
+    for (i, v) in data.iter().enumerate() {
+        let i = i as f32;
+        assert_eq!(v.position, [i, i + 0.5, -i]);
+        assert_eq!(v.uv, [i * 2.0, 0.5]);
+    }
+
+    // Read attribute starting in the second element.
+    let mut count = 0;
+    for_each_vertex(&mut data, 20, 12, |index, x: &f32, uv: &mut [f32; 2]| {
+        *uv = [*x, 0.0];
+        count = index + 1;
+    })
+    .unwrap();
+    assert_eq!(count, 3);
+    assert_eq!(data[2].uv, [3.0, 0.0]);
+    assert_eq!(data[3].uv, [6.0, 0.5]);
+}
+
+#[test]
+fn fused_loop_two_reads() {
+    let mut data = vertices();
+    for_each_vertex2(
+        &mut data,
+        (0, 8),
+        16,
+        |index, xy: &[f32; 2], z: &f32, v: &mut f32| {
+            *v = xy[0] + xy[1] + z + index as f32;
+        },
+    )
+    .unwrap();
+    for (i, v) in data.iter().enumerate() {
+        let i = i as f32;
+        assert_eq!(v.position, [i, i + 0.5, -i]);
+        assert_eq!(v.uv, [0.0, 2.0 * i + 0.5]);
+    }
+
+    let mut empty: Vec<Vertex> = Vec::new();
+    for_each_vertex2(
+        &mut empty,
+        (0, 4),
+        12,
+        |_, _: &f32, _: &f32, _: &mut f32| unreachable!(),
+    )
+    .unwrap();
+}
+
+#[test]
+fn fused_loop_overlap() {
+    let mut data = vertices();
+    let result = for_each_vertex(
+        &mut data,
+        0,
+        8,
+        |_, _: &[f32; 3], _: &mut f32| unreachable!(),
+    );
+    assert_eq!(
+        result,
+        Err(SliceError::OverlappingAttributes { read: 0, write: 8 })
+    );
+    let result = for_each_vertex2(
+        &mut data,
+        (0, 12),
+        16,
+        |_, _: &f32, _: &[f32; 2], _: &mut f32| unreachable!(),
+    );
+    assert_eq!(
+        result,
+        Err(SliceError::OverlappingAttributes {
+            read: 12,
+            write: 16
+        })
+    );
+    assert!(data.iter().all(|v| v.uv == [0.0; 2]));
+}
+
+#[test]
+fn sendable_lane_scoped() {
+    let mut vertices = vertices();
+    let sum = split_attr_read_send(
+        &mut vertices,
+        0,
+        12,
+        |positions: Slice<[f32; 3]>, mut uvs: SendableLane<[f32; 2]>, guard| {
+            assert_eq!(uvs.len(), 4);
+            let sum = std::thread::scope(|scope| {
+                // Bake the lane in the background, while reading the positions.
+                scope.spawn(move || {
+                    uvs.with_view(|mut view| {
+                        for (index, uv) in view.iter().enumerate() {
+                            *uv = [index as f32, 1.0];
+                        }
+                    });
+                });
+                positions.iter().map(|p| p[1]).sum::<f32>()
+            });
+            // The lane was moved into the thread, and dropped when it finished.
+            assert!(guard.is_released());
+            sum
+        },
+    )
+    .unwrap();
+    assert_eq!(sum, 8.0);
+    for (index, vertex) in vertices.iter().enumerate() {
+        assert_eq!(vertex.uv, [index as f32, 1.0]);
+        assert_eq!(vertex.position[0], index as f32);
+    }
+}
+
+#[test]
+fn sendable_lane_outlives_closure() {
+    let mut data = [[0_u32; 2]; 256];
+    let released = split_attr_read_send(
+        &mut data,
+        0,
+        4,
+        |_: Slice<u32>, mut lane: SendableLane<u32>, guard| {
+            let (sender, receiver) = std::sync::mpsc::channel::<()>();
+            // Non-scoped thread, only writing once the closure returned.
+            std::thread::spawn(move || {
+                let _ = receiver.recv();
+                std::thread::sleep(std::time::Duration::from_millis(20));
+                lane.with_view(|mut view| view.fill(7));
+            });
+            let released = guard.is_released();
+            drop(sender);
+            released
+        },
+    )
+    .unwrap();
+    // The buffer is only released once the lane is dropped.
+    assert!(!released);
+    assert!(data.iter().all(|v| *v == [0, 7]));
+}
+
+#[test]
+fn sendable_lane_rebase() {
+    let mut data = [[1_u32, 0]; 64];
+    let sum = split_attr_read_send(
+        &mut data,
+        0,
+        4,
+        |read: Slice<u32>, mut lane: SendableLane<u32>, _| {
+            let writer = std::thread::spawn(move || {
+                lane.with_view(|view| {
+                    // The next `u32` is the read attribute of the next element.
+                    assert!(view.rebase::<[u8; 4]>(0).unwrap().rebase::<u32>(4).is_err());
+                });
+                lane.with_view(|view| view.rebase::<[u16; 2]>(0).unwrap().fill([2, 0]));
+            });
+            let sum = read.iter().sum::<u32>();
+            writer.join().unwrap();
+            sum
+        },
+    )
+    .unwrap();
+    assert_eq!(sum, 64);
+    assert!(data.iter().all(|v| *v == [1, 2]));
+}
+
+#[test]
+fn sendable_lane_guard() {
+    let mut data = [[0_u16; 4]; 3];
+    split_attr_read_send(
+        &mut data,
+        0,
+        2,
+        |_: Slice<u16>, mut lane: SendableLane<u16>, guard| {
+            assert!(!guard.is_released());
+            lane.with_view(|mut view| view.fill(1));
+            drop(lane);
+            assert!(guard.is_released());
+            guard.wait();
+        },
+    )
+    .unwrap();
+    assert_eq!(data, [[0, 1, 0, 0]; 3]);
+
+    // The guard still waits for the lane when the closure panics.
+    let result = std::panic::catch_unwind(move || {
+        let mut data = [[0_u16; 4]; 3];
+        split_attr_read_send(
+            &mut data,
+            0,
+            4,
+            |_: Slice<u16>, mut lane: SendableLane<u16>, _| {
+                std::thread::spawn(move || lane.with_view(|mut view| view.fill(2)));
+                panic!("bake failed");
+            },
+        )
+        .unwrap();
+    });
+    assert!(result.is_err());
+
+    let result = split_attr_read_send(
+        &mut data,
+        0,
+        0,
+        |_: Slice<u16>, _: SendableLane<u16>, _| unreachable!(),
+    );
+    assert_eq!(
+        result,
+        Err(SliceError::OverlappingAttributes { read: 0, write: 0 })
+    );
+}
@@ -0,0 +1,152 @@
+use pas::{Slice, SliceMut};
+
+#[test]
+fn summary() {
+    let data: [[f32; 3]; 4] = [
+        [0.0, 1.0, -2.0],
+        [4.0, f32::NAN, 2.0],
+        [f32::INFINITY, 3.0, 6.0],
+        [2.0, 5.0, f32::NEG_INFINITY],
+    ];
+    let slice: Slice<[f32; 3]> = Slice::new(&data, 0);
+    let summary = slice.summary();
+    assert_eq!(summary.count, 4);
+    assert_eq!(summary.non_finite, 3);
+    assert_eq!(summary.min, [0.0, 1.0, -2.0]);
+    assert_eq!(summary.max, [4.0, 5.0, 6.0]);
+    assert_eq!(summary.mean, [2.0, 3.0, 2.0]);
+    assert_eq!(
+        summary.to_string(),
+        "count: 4, min: [0.0, 1.0, -2.0], max: [4.0, 5.0, 6.0], mean: [2.0, 3.0, 2.0], non-finite: 3"
+    );
+
+    // Strided scalar view over the second lane.
+    let slice: Slice<f32> = Slice::new(&data, 4);
+    let summary = slice.summary();
+    assert_eq!((summary.min, summary.max, summary.mean), (1.0, 5.0, 3.0));
+    assert_eq!(summary.non_finite, 1);
+
+    let data = [1.0_f64, 2.0, 6.0];
+    let summary = Slice::native(&data).summary();
+    assert_eq!((summary.min, summary.max, summary.mean), (1.0, 6.0, 3.0));
+}
+
+#[test]
+fn summary_empty_and_non_finite() {
+    let data: [f32; 0] = [];
+    let summary = Slice::native(&data).summary();
+    assert_eq!(summary.count, 0);
+    assert_eq!(summary.non_finite, 0);
+    assert!(summary.min.is_nan() && summary.max.is_nan() && summary.mean.is_nan());
+
+    let data = [f32::NAN, f32::INFINITY];
+    let summary = Slice::native(&data).summary();
+    assert_eq!(summary.non_finite, 2);
+    assert!(summary.min.is_nan() && summary.max.is_nan() && summary.mean.is_nan());
+}
+
+#[test]
+fn is_constant() {
+    let mut data: [[u32; 2]; 3] = [[0, 1], [0, 2], [0, 3]];
+    let slice: Slice<u32> = Slice::new(&data, 0);
+    assert_eq!(slice.is_constant(), Some(0));
+    let slice: Slice<u32> = Slice::new(&data, 4);
+    assert_eq!(slice.is_constant(), None);
+    let slice: Slice<[u32; 2]> = Slice::new(&data, 0);
+    assert_eq!(slice.is_constant(), None);
+
+    let mut slice: SliceMut<u32> = SliceMut::new(&mut data, 4);
+    slice.copy_from_slice(&[7_u32, 7, 7]);
+    assert_eq!(slice.is_constant(), Some(7));
+
+    // Bit-identical comparison: `-0.0 == 0.0` but isn't constant.
+    let data = [0.0_f32, -0.0];
+    assert_eq!(Slice::native(&data).is_constant(), None);
+    let data = [f32::NAN, f32::NAN];
+    assert!(Slice::native(&data).is_constant().unwrap().is_nan());
+
+    let empty: Slice<u32> = Slice::default();
+    assert_eq!(empty.is_constant(), None);
+}
+
+// Tests contiguous and strided views over the same values.
+macro_rules! equal_to_tests {
+    ($($t: ty),*) => { paste::paste! { $(
+        #[test]
+        fn [<all_equal_to_ $t>]() {
+            let value: $t = bytemuck::cast([0x5a_u8; std::mem::size_of::<$t>()]);
+            let other: $t = bytemuck::Zeroable::zeroed();
+            // Lengths around the chunk size, for the remainder.
+            for len in [1, 2, 3, 63, 64, 65, 200, 1000] {
+                let mut packed = vec![value; len];
+                let mut strided = vec![[value, other]; len];
+                for changed in [None, Some(0), Some(len / 2), Some(len - 1)] {
+                    if let Some(index) = changed {
+                        packed[index] = other;
+                        strided[index][0] = other;
+                    }
+                    let expected = changed.is_none();
+                    let packed_view = Slice::native(&packed);
+                    let strided_view: Slice<$t> = Slice::new(&strided, 0);
+                    assert_eq!(packed_view.all_equal_to(&value), expected);
+                    assert_eq!(strided_view.all_equal_to(&value), expected);
+                    assert_eq!(packed_view.any_equal_to(&value), expected || len > 1);
+                    assert_eq!(strided_view.any_equal_to(&value), expected || len > 1);
+                    assert_eq!(packed_view.any_equal_to(&other), !expected);
+                    assert_eq!(strided_view.any_equal_to(&other), !expected);
+                    if let Some(index) = changed {
+                        packed[index] = value;
+                        strided[index][0] = value;
+                    }
+                }
+            }
+        }
+    )* } };
+}
+
+equal_to_tests!(u8, u16, u32, u64);
+
+#[test]
+fn equal_to_arrays() {
+    // Elements larger than a chunk.
+    let data = vec![[3_u32; 100]; 5];
+    assert!(Slice::native(&data).all_equal_to(&[3; 100]));
+    assert!(!Slice::native(&data).all_equal_to(&[4; 100]));
+
+    // Elements whose size doesn't divide a chunk.
+    let mut data = vec![[1_u8, 2, 3]; 100];
+    assert!(Slice::native(&data).all_equal_to(&[1, 2, 3]));
+    data[99] = [1, 2, 4];
+    assert!(!Slice::native(&data).all_equal_to(&[1, 2, 3]));
+    assert!(Slice::native(&data).any_equal_to(&[1, 2, 4]));
+
+    // Bit-identical comparison.
+    let data = [0.0_f32, -0.0];
+    assert!(!Slice::native(&data).all_equal_to(&0.0));
+    assert!(Slice::native(&data).any_equal_to(&-0.0));
+    let data = [f32::NAN; 3];
+    assert!(Slice::native(&data).all_equal_to(&f32::NAN));
+}
+
+#[test]
+fn equal_to_empty() {
+    let empty: Slice<u32> = Slice::default();
+    assert!(empty.all_equal_to(&0));
+    assert!(!empty.any_equal_to(&0));
+    let empty: Slice<[f32; 2]> = Slice::default();
+    assert!(empty.all_approx_equal_to(&[0.0; 2], 0.0));
+}
+
+#[test]
+fn all_approx_equal_to() {
+    let data = [[1.0_f64, 2.0, 9.0], [1.001, 1.999, 9.0], [0.9995, 2.0, 9.0]];
+    let slice: Slice<[f64; 2]> = Slice::new(&data, 0);
+    assert!(slice.all_approx_equal_to(&[1.0, 2.0], 0.001 + 1e-12));
+    assert!(!slice.all_approx_equal_to(&[1.0, 2.0], 0.0009));
+    assert!(!slice.all_equal_to(&[1.0, 2.0]));
+
+    let slice: Slice<f64> = Slice::new(&data, 16);
+    assert!(slice.all_approx_equal_to(&9.0, 0.0));
+    let data = [1.0_f32, f32::NAN];
+    assert!(!Slice::native(&data).all_approx_equal_to(&1.0, f32::INFINITY));
+}
@@ -0,0 +1,87 @@
+use bytemuck::Pod;
+use pas::{AlignedBytes, SliceMut};
+
+/// Element counts around the size at which streaming stores are used.
+const COUNTS: [usize; 6] = [0, 1, 17, 255, 1024, 1500];
+
+fn values<T: Pod>(count: usize) -> Vec<T> {
+    let size = std::mem::size_of::<T>();
+    let bytes: Vec<u8> = (0..count * size).map(|i| (i * 7 + 3) as u8).collect();
+    (0..count)
+        .map(|i| bytemuck::pod_read_unaligned(&bytes[i * size..(i + 1) * size]))
+        .collect()
+}
+
+/// Buffer aligned to 16 bytes, with non-zero bytes around the elements.
+fn buffer(offset: usize, count: usize, stride: usize) -> AlignedBytes {
+    let bytes: Vec<u8> = (0..offset + count * stride + 32)
+        .map(|i| (i % 251) as u8 | 0x80)
+        .collect();
+    AlignedBytes::from_slice_aligned(&bytes, 16)
+}
+
+/// Compare the streaming and normal paths for every count, at `offset` bytes
+/// of a buffer aligned to 16 bytes.
+fn compare<T: Pod + PartialEq + std::fmt::Debug>(offset: usize, stride: usize) {
+    for count in COUNTS {
+        let src = values::<T>(count);
+        let mut expected = buffer(offset, count, stride);
+        let mut actual = expected.clone();
+
+        SliceMut::<T>::raw(&mut expected, offset, stride).copy_from_slice(&src);
+        SliceMut::<T>::raw(&mut actual, offset, stride).copy_from_slice_streaming(&src);
+        assert_eq!(actual, expected, "copy, offset {offset}, count {count}");
+
+        let value = values::<T>(1)[0];
+        SliceMut::<T>::raw(&mut expected, offset, stride).fill(value);
+        SliceMut::<T>::raw(&mut actual, offset, stride).fill_streaming(value);
+        assert_eq!(actual, expected, "fill, offset {offset}, count {count}");
+    }
+}
+
+#[test]
+fn streaming_packed_unaligned_elements() {
+    for offset in 0..16 {
+        compare::<u8>(offset, 1);
+        compare::<[u8; 3]>(offset, 3);
+        compare::<[u8; 20]>(offset, 20);
+    }
+}
+
+#[test]
+fn streaming_packed_aligned_elements() {
+    for offset in [0, 4, 8, 12] {
+        compare::<u32>(offset, 4);
+        compare::<[f32; 3]>(offset, 12);
+        compare::<[u32; 4]>(offset, 16);
+    }
+    compare::<[u64; 4]>(0, 32);
+    compare::<[u64; 4]>(8, 32);
+}
+
+#[test]
+fn streaming_interleaved() {
+    compare::<[f32; 3]>(0, 32);
+    compare::<[u8; 3]>(5, 7);
+}
+
+#[test]
+fn streaming_partial_copy() {
+    let mut data = vec![[1_u32; 4]; 1024];
+    let src = vec![[2_u32; 4]; 1000];
+    SliceMut::native(&mut data).copy_from_slice_streaming(&src);
+    assert!(data[..1000].iter().all(|v| *v == [2; 4]));
+    assert!(data[1000..].iter().all(|v| *v == [1; 4]));
+
+    // Smaller source elements take the normal path.
+    let mut data = vec![0_u32; 2048];
+    SliceMut::native(&mut data).copy_from_slice_streaming(&vec![7_u16; 2048]);
+    assert!(data.iter().all(|v| *v == 7));
+}
+
+#[test]
+#[should_panic]
+fn streaming_copy_too_large() {
+    let mut data = vec![0_u32; 2048];
+    SliceMut::native(&mut data).copy_from_slice_streaming(&vec![0_u32; 2049]);
+}
@@ -0,0 +1,129 @@
+use pas::{Slice, SliceError, SliceMut};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub color: [u8; 4],
+}
+
+fn vertices() -> [Vertex; 3] {
+    [
+        Vertex {
+            position: [1.0, 2.0, 3.0],
+            color: [255, 128, 0, 255],
+        },
+        Vertex {
+            position: [-4.0, 0.5, 6.0],
+            color: [10, 20, 30, 40],
+        },
+        Vertex {
+            position: [0.0, -1.0, 0.0],
+            color: [1, 2, 3, 4],
+        },
+    ]
+}
+
+#[test]
+fn bgra_to_rgba() {
+    let vertices = vertices();
+    let bgra: Slice<[u8; 4]> = Slice::new(&vertices, 12);
+    let rgba = bgra.swizzled([2, 1, 0, 3]).unwrap();
+    assert_eq!(rgba.len(), 3);
+    assert_eq!(
+        rgba.to_vec(),
+        [[0, 128, 255, 255], [30, 20, 10, 40], [3, 2, 1, 4]]
+    );
+    assert_eq!(rgba.get(3), None);
+    assert_eq!(format!("{:?}", rgba.into_slice()), format!("{:?}", bgra));
+
+    // Repeated lanes broadcast.
+    let gray = bgra.swizzled([1, 1, 1, 3]).unwrap();
+    assert_eq!(gray.get(1), Some([20, 20, 20, 40]));
+}
+
+#[test]
+fn z_up_to_y_up() {
+    let vertices = vertices();
+    let positions: Slice<[f32; 3]> = Slice::new(&vertices, 0);
+    let y_up = positions
+        .swizzled([0, 2, 1])
+        .unwrap()
+        .with_signs([false, false, true]);
+    assert_eq!(y_up.signs(), [false, false, true]);
+    assert!(y_up
+        .iter()
+        .eq([[1.0, 3.0, -2.0], [-4.0, 6.0, -0.5], [0.0, 0.0, 1.0]]));
+}
+
+#[test]
+fn in_place_matches_materialized() {
+    let mut expected = vertices();
+    let mut actual = vertices();
+    {
+        let source = vertices();
+        let positions: Slice<[f32; 3]> = Slice::new(&source, 0);
+        let colors: Slice<[u8; 4]> = Slice::new(&source, 12);
+        let mut dst: SliceMut<[f32; 3]> = SliceMut::new(&mut expected, 0);
+        positions
+            .swizzled([2, 0, 1])
+            .unwrap()
+            .with_signs([true, false, true])
+            .materialize_into(&mut dst)
+            .unwrap();
+        let mut dst: SliceMut<[u8; 4]> = SliceMut::new(&mut expected, 12);
+        colors
+            .swizzled([3, 2, 1, 0])
+            .unwrap()
+            .materialize_into(&mut dst)
+            .unwrap();
+    }
+
+    let mut positions: SliceMut<[f32; 3]> = SliceMut::new(&mut actual, 0);
+    positions
+        .swizzle_in_place_signed([2, 0, 1], [true, false, true])
+        .unwrap();
+    let mut colors: SliceMut<[u8; 4]> = SliceMut::new(&mut actual, 12);
+    colors.swizzle_in_place([3, 2, 1, 0]).unwrap();
+    assert_eq!(actual, expected);
+    assert_eq!(actual[0].position, [-3.0, 1.0, -2.0]);
+    assert_eq!(actual[0].color, [255, 0, 128, 255]);
+
+    // Length mismatch.
+    let source = vertices();
+    let colors: Slice<[u8; 4]> = Slice::new(&source, 12);
+    let mut dst = [[0_u8; 4]; 2];
+    assert_eq!(
+        colors
+            .swizzled([0, 1, 2, 3])
+            .unwrap()
+            .materialize_into(&mut SliceMut::native(&mut dst)),
+        Err(SliceError::LengthMismatch {
+            expected: 3,
+            len: 2
+        })
+    );
+    assert_eq!(dst, [[0; 4]; 2]);
+}
+
+#[test]
+fn invalid_order() {
+    let mut vertices = vertices();
+    let colors: Slice<[u8; 4]> = Slice::new(&vertices, 12);
+    let error = SliceError::IndexOutOfBounds {
+        position: 2,
+        index: 4,
+        len: 4,
+    };
+    assert_eq!(colors.swizzled([0, 1, 4, 3]).err(), Some(error));
+
+    let mut colors: SliceMut<[u8; 4]> = SliceMut::new(&mut vertices, 12);
+    assert_eq!(colors.swizzle_in_place([0, 1, 4, 3]), Err(error));
+    assert_eq!(vertices[0].color, [255, 128, 0, 255]);
+
+    let mut positions: SliceMut<[f32; 3]> = SliceMut::new(&mut vertices, 0);
+    assert!(positions
+        .swizzle_in_place_signed([usize::MAX, 0, 1], [true; 3])
+        .is_err());
+    assert_eq!(vertices[0].position, [1.0, 2.0, 3.0]);
+}
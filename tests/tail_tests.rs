@@ -0,0 +1,84 @@
+use pas::{Slice, TailPolicy};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub color: [u8; 4],
+}
+
+/// Bytes of two vertices, followed by the first 8 bytes of a third one.
+fn truncated() -> Vec<u32> {
+    let vertices = [Vertex {
+        position: [1.0, 2.0, 3.0],
+        color: [4, 5, 6, 7],
+    }; 3];
+    let words: &[u32] = bytemuck::cast_slice(&vertices);
+    words[..10].to_vec()
+}
+
+#[test]
+fn half_element_tail() {
+    let data = truncated();
+    let bytes: &[u8] = bytemuck::cast_slice(&data);
+    let positions: Slice<[f32; 3]> = Slice::raw(bytes, 0, 16);
+    assert_eq!(positions.len(), 2);
+
+    let truncate = positions.with_tail_policy(TailPolicy::Truncate);
+    assert_eq!(truncate.policy(), TailPolicy::Truncate);
+    assert!(!truncate.is_padded());
+    assert_eq!(truncate.len(), 2);
+    assert_eq!(truncate.get(2), None);
+
+    let padded = positions.with_tail_policy(TailPolicy::ZeroPad);
+    assert!(padded.is_padded());
+    assert_eq!(padded.len(), 3);
+    assert_eq!(padded[1], [1.0, 2.0, 3.0]);
+    assert_eq!(padded.get(2), Some(&[1.0, 2.0, 0.0]));
+    assert_eq!(padded.get(3), None);
+
+    assert_eq!(padded.iter().count(), truncate.iter().count() + 1);
+    assert_eq!(
+        format!("{:?}", padded),
+        "[[1.0, 2.0, 3.0], [1.0, 2.0, 3.0], [1.0, 2.0, 0.0]]"
+    );
+
+    // The padded element is borrowed from the view, not from the buffer.
+    let last = padded.get(2).unwrap() as *const _ as usize;
+    let buffer = bytes.as_ptr_range();
+    assert!(!(buffer.start as usize..buffer.end as usize).contains(&last));
+}
+
+#[test]
+fn zero_padded_bytes() {
+    let data: Vec<u8> = (1..=10).collect();
+    let slice: Slice<[u8; 4]> = Slice::raw(&data, 0, 4);
+    let padded = slice.with_tail_policy(TailPolicy::ZeroPad);
+    assert_eq!(padded.len(), 3);
+    assert_eq!(bytemuck::bytes_of(&padded[2]), [9, 10, 0, 0]);
+
+    // Only the bytes after the last whole element are copied.
+    let slice: Slice<[u8; 4]> = Slice::raw(&data, 2, 4);
+    let padded = slice.with_tail_policy(TailPolicy::ZeroPad);
+    assert_eq!(padded.len(), 2);
+    assert_eq!(padded[1], [7, 8, 9, 10]);
+    assert!(!padded.is_padded());
+}
+
+#[test]
+fn no_tail() {
+    let data = [Vertex {
+        position: [1.0, 2.0, 3.0],
+        color: [4, 5, 6, 7],
+    }; 2];
+    let colors: Slice<[u8; 4]> = Slice::new(&data, 12);
+    for policy in [TailPolicy::ZeroPad, TailPolicy::Truncate] {
+        let view = colors.with_tail_policy(policy);
+        assert!(!view.is_padded());
+        assert_eq!(view.len(), 2);
+        assert_eq!(view.into_slice().len(), 2);
+    }
+
+    let empty: Slice<u32> = Slice::native(&[]);
+    assert!(empty.with_tail_policy(TailPolicy::ZeroPad).is_empty());
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "testing")]
+
+use pas::{Slice, SliceMut};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub color: [u8; 4],
+    pub id: u16,
+    pub padding: u16,
+}
+
+#[test]
+fn splitmix64_stream() {
+    // First outputs of splitmix64 seeded with `0`.
+    let mut data = [0_u64; 2];
+    SliceMut::native(&mut data).fill_deterministic(0);
+    let bytes: &[u8] = bytemuck::cast_slice(&data);
+    assert_eq!(
+        u64::from_le_bytes(bytes[..8].try_into().unwrap()),
+        0xe220_a839_7b1d_cdaf
+    );
+    assert_eq!(
+        u64::from_le_bytes(bytes[8..].try_into().unwrap()),
+        0x6e78_9e6a_a1b9_65f4
+    );
+}
+
+#[test]
+fn golden_checksums() {
+    let mut vertices = [bytemuck::Zeroable::zeroed(); 16];
+
+    let mut colors: SliceMut<[u8; 4]> = SliceMut::new(&mut vertices, 12);
+    colors.fill_deterministic(42);
+    assert_eq!(colors.checksum(), 0xfc4d_6fbf_b30f_fa67);
+
+    let mut ids: SliceMut<u16> = SliceMut::new(&mut vertices, 16);
+    ids.fill_deterministic(7);
+    assert_eq!(ids.checksum(), 0xc725_bcbe_8ce6_8542);
+
+    let mut all: SliceMut<Vertex> = SliceMut::native(&mut vertices);
+    all.fill_deterministic(1234);
+    assert_eq!(all.checksum(), 0x2540_ffbd_d4be_b555);
+}
+
+#[test]
+fn fill_keeps_gaps() {
+    let mut vertices = [Vertex {
+        position: [1.0, 2.0, 3.0],
+        color: [4, 5, 6, 7],
+        id: 8,
+        padding: 9,
+    }; 5];
+    let mut positions: SliceMut<[f32; 3]> = SliceMut::new(&mut vertices, 0);
+    positions.fill_deterministic(3);
+    for v in &vertices {
+        assert_eq!(v.color, [4, 5, 6, 7]);
+        assert_eq!((v.id, v.padding), (8, 9));
+    }
+}
+
+#[test]
+fn fill_is_reproducible() {
+    let mut a = [[0_u32; 3]; 32];
+    let mut b = [0_u32; 32];
+    let mut strided: SliceMut<u32> = SliceMut::new(&mut a, 4);
+    let mut packed: SliceMut<u32> = SliceMut::native(&mut b);
+
+    strided.fill_deterministic(99);
+    packed.fill_deterministic(99);
+    assert_eq!(strided.checksum(), packed.checksum());
+
+    packed.fill_deterministic(100);
+    assert_ne!(strided.checksum(), packed.checksum());
+    assert!(a.iter().all(|v| v[0] == 0 && v[2] == 0));
+}
+
+#[test]
+fn fill_finite() {
+    let mut vertices = [bytemuck::Zeroable::zeroed(); 64];
+    let mut positions: SliceMut<[f32; 3]> = SliceMut::new(&mut vertices, 0);
+    positions.fill_deterministic_finite(5);
+    let positions: Slice<[f32; 3]> = Slice::new(&vertices, 0);
+    for lane in positions.iter().flatten() {
+        assert!(lane.is_finite() && (-1.0..=1.0).contains(lane));
+    }
+    assert!(vertices.iter().all(|v: &Vertex| v.color == [0; 4]));
+
+    let mut values = [0.0_f64; 256];
+    SliceMut::native(&mut values).fill_deterministic_finite(5);
+    assert!(values
+        .iter()
+        .all(|v| v.is_finite() && (-1.0..=1.0).contains(v)));
+    assert!(values.iter().any(|v| *v < 0.0) && values.iter().any(|v| *v > 0.0));
+
+    // Bytes-based filling produces non-finite lanes for some seeds.
+    let mut raw = [0.0_f32; 4096];
+    SliceMut::native(&mut raw).fill_deterministic(5);
+    assert!(raw.iter().any(|v| !v.is_finite()));
+}
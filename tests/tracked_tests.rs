@@ -0,0 +1,177 @@
+// Dirty ranges are compared against arrays of ranges.
+#![allow(clippy::single_range_in_vec_init)]
+
+use pas::{Slice, SliceMut, StaleStateError, TrackedSliceMut};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [u32; 3],
+    pub uv: [u32; 2],
+}
+
+fn data(count: u32) -> Vec<Vertex> {
+    (0..count)
+        .map(|i| Vertex {
+            position: [i, i, i],
+            uv: [i, i],
+        })
+        .collect()
+}
+
+#[test]
+fn scattered_writes() {
+    let mut vertices = data(32);
+    let mut slice = TrackedSliceMut::new(SliceMut::<[u32; 2]>::new(&mut vertices, 12));
+
+    for i in [10, 3, 5, 4, 20, 11, 30, 31, 0] {
+        *slice.get_mut(i).unwrap() = [100, 100];
+    }
+    assert_eq!(slice.dirty_ranges(), [0..1, 3..6, 10..12, 20..21, 30..32]);
+
+    // Filling a gap merges ranges.
+    for i in [1, 2] {
+        slice.get_mut(i).unwrap()[0] = 1;
+    }
+    assert_eq!(slice.dirty_ranges(), [0..6, 10..12, 20..21, 30..32]);
+
+    // Range covering multiple existing ranges.
+    slice.mark_dirty(9..25);
+    assert_eq!(slice.dirty_ranges(), [0..6, 9..25, 30..32]);
+    // Clamped to the slice length.
+    slice.mark_dirty(31..100);
+    assert_eq!(slice.dirty_ranges(), [0..6, 9..25, 30..32]);
+
+    assert!(slice.get_mut(32).is_none());
+    drop(slice);
+    assert_eq!(vertices[4].uv, [100, 100]);
+    assert_eq!(vertices[4].position, [4, 4, 4]);
+    assert_eq!(vertices[1].uv, [1, 1]);
+}
+
+#[test]
+fn reads_do_not_mark() {
+    let mut vertices = data(4);
+    let mut slice = TrackedSliceMut::new(SliceMut::<[u32; 3]>::new(&mut vertices, 0));
+    let value = *slice.get_mut(2).unwrap();
+    assert_eq!(value, [2, 2, 2]);
+    assert_eq!(slice.get_mut(1).unwrap()[0], 1);
+    assert_eq!(slice.get(3), Some(&[3, 3, 3]));
+    assert_eq!(slice.len(), 4);
+    assert!(slice.dirty_ranges().is_empty());
+}
+
+#[test]
+fn bulk_writes() {
+    let mut vertices = data(8);
+    let mut slice = TrackedSliceMut::new(SliceMut::<[u32; 3]>::new(&mut vertices, 0));
+
+    slice.copy_from_slice(&[[9_u32, 9, 9], [8, 8, 8], [7, 7, 7]]);
+    assert_eq!(slice.dirty_ranges(), [0..3]);
+    *slice.get_mut(6).unwrap() = [0, 0, 0];
+    assert_eq!(slice.dirty_ranges(), [0..3, 6..7]);
+
+    slice.fill([1, 2, 3]);
+    assert_eq!(slice.dirty_ranges(), [0..8]);
+
+    slice.clear_dirty();
+    assert!(slice.dirty_ranges().is_empty());
+    *slice.get_mut(4).unwrap() = [4, 4, 4];
+    assert_eq!(slice.dirty_ranges(), [4..5]);
+
+    let slice = slice.into_inner();
+    assert_eq!(slice[4], [4, 4, 4]);
+    assert_eq!(slice[5], [1, 2, 3]);
+}
+
+#[test]
+fn fill() {
+    let mut vertices = data(3);
+    let mut slice: SliceMut<[u32; 2]> = SliceMut::new(&mut vertices, 12);
+    slice.fill([7, 8]);
+    for (i, v) in vertices.iter().enumerate() {
+        let i = i as u32;
+        assert_eq!(v.uv, [7, 8]);
+        assert_eq!(v.position, [i, i, i]);
+    }
+}
+
+#[test]
+fn fingerprint_scattered_edits() {
+    let mut vertices = data(1000);
+    let mut expected = data(1000);
+    let tree = {
+        let uvs: SliceMut<[u32; 2]> = SliceMut::new(&mut vertices, 12);
+        let mut uvs = TrackedSliceMut::new(uvs);
+        let tree = uvs.fingerprint_tree(64);
+
+        // Unchanged lanes keep their fingerprint.
+        let same = uvs.fingerprint_incremental(tree.clone()).unwrap();
+        assert_eq!(same.fingerprint(), tree.fingerprint());
+
+        for index in [3, 4, 200, 511, 999] {
+            *uvs.get_mut(index).unwrap() = [7, index as u32];
+        }
+        let updated = uvs.fingerprint_incremental(tree.clone()).unwrap();
+        assert_ne!(updated.fingerprint(), tree.fingerprint());
+        assert_eq!(updated, uvs.fingerprint_tree(64));
+        updated
+    };
+
+    // Same content, written without tracking.
+    for index in [3, 4, 200, 511, 999] {
+        expected[index].uv = [7, index as u32];
+    }
+    let uvs: Slice<[u32; 2]> = Slice::new(&expected, 12);
+    assert_eq!(tree, uvs.fingerprint_tree(64));
+    // Positions aren't part of the lane.
+    expected[0].position = [9; 3];
+    let uvs: Slice<[u32; 2]> = Slice::new(&expected, 12);
+    assert_eq!(tree.fingerprint(), uvs.fingerprint_tree(64).fingerprint());
+}
+
+#[test]
+fn fingerprint_block_boundaries() {
+    let mut ids: Vec<u32> = (0..100).collect();
+    let mut slice = TrackedSliceMut::new(SliceMut::native(&mut ids));
+    let tree = slice.fingerprint_tree(10);
+    assert_eq!(tree.block_size(), 10);
+    assert_eq!(tree.len(), 100);
+
+    // Last element of a block, first of the next, and a range spanning blocks.
+    *slice.get_mut(9).unwrap() = 0;
+    *slice.get_mut(10).unwrap() = 0;
+    slice.mark_dirty(95..100);
+    let tree = slice.fingerprint_incremental(tree).unwrap();
+    assert_eq!(tree, slice.fingerprint_tree(10));
+
+    // Partial last block.
+    let mut slice = TrackedSliceMut::new(slice.into_inner());
+    let tree = slice.fingerprint_tree(7);
+    *slice.get_mut(99).unwrap() = 1;
+    let tree = slice.fingerprint_incremental(tree).unwrap();
+    assert_eq!(tree, slice.fingerprint_tree(7));
+    assert_ne!(tree.fingerprint(), slice.fingerprint_tree(10).fingerprint());
+}
+
+#[test]
+fn fingerprint_resized() {
+    let mut ids = [0_u32; 16];
+    let tree = Slice::native(&ids).fingerprint_tree(4);
+    let slice = TrackedSliceMut::new(SliceMut::native(&mut ids[..12]));
+    assert_eq!(
+        slice.fingerprint_incremental(tree),
+        Err(StaleStateError {
+            expected: 16,
+            len: 12
+        })
+    );
+
+    // Same bytes, different lengths.
+    let empty = Slice::native(&ids[..0]).fingerprint_tree(4);
+    assert!(empty.is_empty());
+    assert_ne!(
+        empty.fingerprint(),
+        Slice::native(&ids[..1]).fingerprint_tree(4).fingerprint()
+    );
+}
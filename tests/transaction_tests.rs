@@ -0,0 +1,193 @@
+use pas::{Slice, SliceError, SliceMut, Transaction, TransactionError};
+
+fn is_unit(n: &[f32; 3]) -> bool {
+    (n[0] * n[0] + n[1] * n[1] + n[2] * n[2] - 1.0).abs() < 1e-4
+}
+
+#[test]
+fn commit_multiple_lanes() {
+    let mut positions = [[0.0_f32; 3]; 6];
+    // Normals interleaved with tangents.
+    let mut frames = [[[0.0_f32, 0.0, 1.0], [1.0, 0.0, 0.0]]; 6];
+    let mut ids = [0_u16; 6];
+
+    let mut transaction = Transaction::new();
+    let position = transaction.lane(&Slice::native(&positions));
+    let normal = transaction.lane::<[f32; 3]>(&Slice::new(&frames, 0));
+    let id = transaction.lane(&Slice::native(&ids));
+    transaction.validate(normal, |_, n| is_unit(n));
+    transaction.set(position, 1, [9.0, 8.0, 7.0]).unwrap();
+    transaction.set(normal, 1, [0.0, 1.0, 0.0]).unwrap();
+    transaction.set(position, 4, [4.5; 3]).unwrap();
+    transaction.set_bytes(id, 5, &7_u16.to_ne_bytes()).unwrap();
+    assert_eq!(transaction.lane_count(), 3);
+    assert_eq!(transaction.staged_count(), 4);
+
+    transaction
+        .commit(&mut [
+            &mut SliceMut::native(&mut positions),
+            &mut SliceMut::<[f32; 3]>::new(&mut frames, 0),
+            &mut SliceMut::native(&mut ids),
+        ])
+        .unwrap();
+    let mut expected = [[0.0; 3]; 6];
+    expected[1] = [9.0, 8.0, 7.0];
+    expected[4] = [4.5; 3];
+    assert_eq!(positions, expected);
+    let mut expected = [[[0.0, 0.0, 1.0], [1.0, 0.0, 0.0]]; 6];
+    expected[1][0] = [0.0, 1.0, 0.0];
+    assert_eq!(frames, expected);
+    assert_eq!(ids, [0, 0, 0, 0, 0, 7]);
+}
+
+#[test]
+fn failed_validation_writes_nothing() {
+    let mut positions = [[0.0_f32; 3]; 6];
+    let mut normals = [[0.0_f32, 0.0, 1.0]; 6];
+
+    let mut transaction = Transaction::new();
+    let position = transaction.lane(&Slice::native(&positions));
+    let normal = transaction.lane(&Slice::native(&normals));
+    transaction.validate(position, |_, p| p.iter().all(|v| v.is_finite()));
+    transaction.validate(normal, |_, n| is_unit(n));
+    transaction.validate(normal, |index, _| index != 3);
+    transaction.set(position, 0, [1.0; 3]).unwrap();
+    transaction.set(normal, 0, [0.0, 1.0, 0.0]).unwrap();
+    transaction.set(normal, 2, [0.0, 0.0, 2.0]).unwrap();
+    transaction.set(normal, 3, [0.0, 1.0, 0.0]).unwrap();
+    transaction.set(normal, 5, [0.0; 3]).unwrap();
+
+    assert_eq!(
+        transaction.commit(&mut [
+            &mut SliceMut::native(&mut positions),
+            &mut SliceMut::native(&mut normals)
+        ]),
+        Err(TransactionError::ValidationFailed {
+            lane: 1,
+            indices: vec![2, 3, 5]
+        })
+    );
+    assert_eq!(positions, [[0.0; 3]; 6]);
+    assert_eq!(normals, [[0.0, 0.0, 1.0]; 6]);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn commit_derived_views() {
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable, pas::Attributes)]
+    pub struct Vertex {
+        pub position: [f32; 3],
+        pub normal: [f32; 3],
+    }
+
+    let mut vertices = [Vertex {
+        position: [0.0; 3],
+        normal: [0.0, 0.0, 1.0],
+    }; 3];
+    let before = vertices;
+
+    let mut transaction = Transaction::new();
+    let views = VertexAttributes::of(&vertices);
+    let position = transaction.lane(&views.position);
+    let normal = transaction.lane(&views.normal);
+    transaction.validate(normal, |_, n| is_unit(n));
+    transaction.set(position, 2, [1.0; 3]).unwrap();
+    transaction.set(normal, 2, [0.5; 3]).unwrap();
+
+    // Both lanes of the same buffer are borrowed mutably at once.
+    let mut views = VertexAttributesMut::of(&mut vertices);
+    let result = transaction.commit(&mut [&mut views.position, &mut views.normal]);
+    assert!(matches!(
+        result,
+        Err(TransactionError::ValidationFailed { lane: 1, .. })
+    ));
+    assert_eq!(vertices, before);
+}
+
+#[test]
+fn last_write_wins() {
+    let mut data = [0_u32; 4];
+    let mut seen = Vec::new();
+    let mut transaction = Transaction::new();
+    let lane = transaction.lane(&Slice::native(&data));
+    transaction.set(lane, 2, 1).unwrap();
+    transaction.set(lane, 2, 2).unwrap();
+    transaction
+        .set_bytes(lane, 2, &3_u32.to_ne_bytes())
+        .unwrap();
+    assert_eq!(transaction.staged(lane, 2), Some(3));
+    assert_eq!(transaction.staged(lane, 1), None);
+    assert_eq!(transaction.staged_count(), 1);
+
+    // Hooks only see the last staged value.
+    transaction.validate(lane, |index, value| {
+        seen.push((index, *value));
+        true
+    });
+    transaction
+        .commit(&mut [&mut SliceMut::native(&mut data)])
+        .unwrap();
+    assert_eq!(data, [0, 0, 3, 0]);
+    assert_eq!(seen, [(2, 3)]);
+}
+
+#[test]
+fn staging_errors() {
+    let data = [0_u32; 4];
+    let mut transaction = Transaction::new();
+    let lane = transaction.lane(&Slice::native(&data));
+    assert_eq!(
+        transaction.set(lane, 4, 1),
+        Err(SliceError::IndexOutOfBounds {
+            position: 0,
+            index: 4,
+            len: 4
+        })
+    );
+    assert_eq!(
+        transaction.set_bytes(lane, 0, &[0; 2]),
+        Err(SliceError::LengthMismatch {
+            expected: 4,
+            len: 2
+        })
+    );
+    assert_eq!(transaction.staged_count(), 0);
+}
+
+#[test]
+fn commit_rejects_changed_views() {
+    let mut data = vec![0_u32; 4];
+    let mut transaction = Transaction::new();
+    let lane = transaction.lane(&Slice::native(&data));
+    transaction.set(lane, 3, 1).unwrap();
+
+    data.push(0);
+    assert_eq!(
+        transaction.commit(&mut [&mut SliceMut::native(&mut data)]),
+        Err(TransactionError::LengthChanged {
+            lane: 0,
+            expected: 4,
+            len: 5
+        })
+    );
+    assert_eq!(data, [0; 5]);
+
+    let mut transaction = Transaction::new();
+    let lane = transaction.lane(&Slice::native(&data));
+    transaction.set(lane, 0, 1).unwrap();
+    let mut floats = [0.0_f32; 5];
+    assert!(matches!(
+        transaction.commit(&mut [&mut SliceMut::native(&mut floats)]),
+        Err(TransactionError::TypeMismatch { lane: 0, .. })
+    ));
+
+    let transaction = Transaction::new();
+    assert_eq!(
+        transaction.commit(&mut [&mut SliceMut::native(&mut data)]),
+        Err(TransactionError::LaneCountMismatch {
+            expected: 0,
+            found: 1
+        })
+    );
+}
@@ -0,0 +1,7 @@
+use pas::{Slice, SliceArray};
+
+fn main() {
+    let data = [0_u32, 1, 2, 3];
+    let array: SliceArray<u32, 4> = Slice::native(&data).try_into_array().unwrap();
+    array.get::<4>();
+}
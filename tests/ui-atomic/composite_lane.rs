@@ -0,0 +1,6 @@
+use pas::{AtomicLane, SliceMut};
+
+fn main() {
+    let mut data = [[0.0_f32; 3]; 2];
+    let _lane = AtomicLane::new(SliceMut::<[f32; 3]>::native(&mut data));
+}
@@ -0,0 +1,6 @@
+use pas::{AtomicLane, SliceMut};
+
+fn main() {
+    let mut data = [[0_u32; 2]; 2];
+    let _lane = AtomicLane::new(SliceMut::<u32>::new(&mut data, 4)).unwrap();
+}
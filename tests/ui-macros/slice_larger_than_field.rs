@@ -0,0 +1,16 @@
+use pas::{slice, Slice};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct Vertex {
+    position: [f32; 3],
+    padding: [f32; 3],
+}
+
+fn main() {
+    let vertices = [Vertex {
+        position: [0.0, 1.0, 2.0],
+        padding: [0.0; 3],
+    }; 2];
+    let _: Slice<f64> = slice!(vertices, [0].position[1]);
+}
@@ -0,0 +1,23 @@
+use pas::{slice, slice_mut, Slice, SliceMut};
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct Vertex {
+    position: [f32; 3],
+    uv: [f32; 2],
+}
+
+fn main() {
+    let mut vertices = [Vertex {
+        position: [0.0, 1.0, 2.0],
+        uv: [3.0, 4.0],
+    }; 2];
+    let x: Slice<f32> = slice!(vertices, [0].position);
+    let yz: Slice<[f32; 2]> = slice!(vertices, [0].position);
+    let y: Slice<f32> = slice!(vertices, [0].position[1]);
+    assert_eq!((x[1], yz[1], y[1]), (0.0, [0.0, 1.0], 1.0));
+
+    let mut uvs: SliceMut<[f32; 2]> = slice_mut!(vertices, [0].uv);
+    uvs[0] = [5.0, 6.0];
+    assert_eq!(vertices[0].uv, [5.0, 6.0]);
+}
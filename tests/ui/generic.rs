@@ -0,0 +1,9 @@
+use pas::Attributes;
+
+#[repr(C)]
+#[derive(Clone, Copy, Attributes)]
+struct Vertex<T> {
+    position: [T; 3],
+}
+
+fn main() {}
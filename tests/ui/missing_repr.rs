@@ -0,0 +1,9 @@
+use pas::Attributes;
+
+#[derive(Clone, Copy, Attributes)]
+struct Vertex {
+    position: [f32; 3],
+    uv: [f32; 2],
+}
+
+fn main() {}
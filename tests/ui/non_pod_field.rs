@@ -0,0 +1,17 @@
+use pas::Attributes;
+
+#[derive(Clone, Copy)]
+struct Color([u8; 4]);
+
+#[repr(C)]
+#[derive(Clone, Copy, Attributes)]
+struct Vertex {
+    position: [f32; 3],
+    color: Color,
+}
+
+// Only the field is rejected.
+unsafe impl bytemuck::Zeroable for Vertex {}
+unsafe impl bytemuck::Pod for Vertex {}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use pas::Attributes;
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Attributes)]
+struct Vertex {
+    position: [f32; 3],
+    flag: u8,
+}
+
+fn main() {}
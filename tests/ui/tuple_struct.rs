@@ -0,0 +1,7 @@
+use pas::Attributes;
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable, Attributes)]
+struct Vertex([f32; 3], [f32; 2]);
+
+fn main() {}
@@ -0,0 +1,114 @@
+use pas::{write_unzipped, write_unzipped2, write_unzipped4, SliceMut};
+
+fn generate(count: usize) -> impl Iterator<Item = ([f32; 3], [f32; 2], [u8; 4])> {
+    (0..count).map(|i| {
+        let v = i as f32;
+        ([v, v + 1.0, v + 2.0], [v * 0.5, 1.0], [i as u8, 0, 0, 255])
+    })
+}
+
+#[cfg(feature = "derive")]
+mod interleaved {
+    use super::generate;
+    use pas::{write_unzipped, Attributes};
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Default, bytemuck::Pod, bytemuck::Zeroable, Attributes)]
+    pub struct Vertex {
+        pub position: [f32; 3],
+        pub uv: [f32; 2],
+        pub color: [u8; 4],
+    }
+
+    #[test]
+    fn unzip_interleaved() {
+        let mut vertices = vec![Vertex::default(); 300];
+        let mut views = VertexAttributesMut::of(&mut vertices);
+        let count = write_unzipped!(generate(300), views.position, views.uv, views.color);
+        assert_eq!(count, 300);
+        for (vertex, (position, uv, color)) in vertices.iter().zip(generate(300)) {
+            assert_eq!(vertex.position, position);
+            assert_eq!(vertex.uv, uv);
+            assert_eq!(vertex.color, color);
+        }
+    }
+
+    #[test]
+    fn unzip_interleaved_short_iterator() {
+        let mut vertices = vec![Vertex::default(); 300];
+        let mut views = VertexAttributesMut::of(&mut vertices);
+        let count = write_unzipped!(generate(120), views.position, views.uv, views.color);
+        assert_eq!(count, 120);
+        assert_eq!(vertices[119].position, [119.0, 120.0, 121.0]);
+        assert!(vertices[120..]
+            .iter()
+            .all(|v| v.position == [0.0; 3] && v.color == [0; 4]));
+    }
+}
+
+#[test]
+fn unzip_shortest_lane() {
+    let mut positions = vec![[0.0_f32; 3]; 300];
+    let mut uvs = vec![[0.0_f32; 2]; 250];
+    let mut colors = vec![[0_u8; 4]; 300];
+    let (mut p, mut u, mut c) = (
+        SliceMut::native(&mut positions),
+        SliceMut::native(&mut uvs),
+        SliceMut::native(&mut colors),
+    );
+
+    let mut values = generate(300);
+    let count = write_unzipped!(values.by_ref(), p, u, c);
+    assert_eq!(count, 250);
+    // Tuples past the shortest lane are left in the iterator.
+    assert_eq!(values.next().map(|v| v.0), Some([250.0, 251.0, 252.0]));
+
+    for (i, (position, uv, color)) in generate(250).enumerate() {
+        assert_eq!((positions[i], uvs[i], colors[i]), (position, uv, color));
+    }
+    assert!(positions[250..].iter().all(|v| *v == [0.0; 3]));
+    assert!(colors[250..].iter().all(|v| *v == [0; 4]));
+}
+
+#[test]
+fn unzip_arities() {
+    let mut xs = [0_u32; 8];
+    let mut ys = [0_u16; 8];
+    let count = write_unzipped2(
+        (0..5).map(|i| (i, i as u16 * 2)),
+        &mut SliceMut::native(&mut xs),
+        &mut SliceMut::native(&mut ys),
+    );
+    assert_eq!(count, 5);
+    assert_eq!(xs[..6], [0, 1, 2, 3, 4, 0]);
+    assert_eq!(ys[..6], [0, 2, 4, 6, 8, 0]);
+
+    let mut xs = [0_u8; 3];
+    let mut ys = [0_i32; 4];
+    let mut zs = [0.0_f32; 4];
+    let mut ws = [[0_u16; 2]; 4];
+    let values = (0..4).map(|i| (i as u8, -i, i as f32, [i as u16; 2]));
+    let count = write_unzipped4(
+        values,
+        &mut SliceMut::native(&mut xs),
+        &mut SliceMut::native(&mut ys),
+        &mut SliceMut::native(&mut zs),
+        &mut SliceMut::native(&mut ws),
+    );
+    assert_eq!(count, 3);
+    assert_eq!(
+        (xs, ys, zs),
+        ([0, 1, 2], [0, -1, -2, 0], [0.0, 1.0, 2.0, 0.0])
+    );
+    assert_eq!(ws[2..], [[2, 2], [0, 0]]);
+
+    let mut empty: [u32; 0] = [];
+    let mut other = [0_u32; 2];
+    let count = write_unzipped!(
+        [(1, 1)],
+        SliceMut::<u32>::native(&mut empty),
+        SliceMut::<u32>::native(&mut other)
+    );
+    assert_eq!(count, 0);
+    assert_eq!(other, [0, 0]);
+}
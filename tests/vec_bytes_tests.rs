@@ -0,0 +1,145 @@
+use pas::convert::{from_bytes, into_bytes, Conversion, ConversionError};
+use pas::{SliceError, VecView, ViewDesc};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub color: [u8; 4],
+}
+
+fn vertices() -> Vec<Vertex> {
+    (0..5)
+        .map(|i| Vertex {
+            position: [i as f32, -(i as f32), 0.5],
+            color: [i as u8, 2, 3, 255],
+        })
+        .collect()
+}
+
+#[test]
+fn round_trip() {
+    let (bytes, conversion) = into_bytes(vertices());
+    // `Vertex` is aligned to 4 bytes, the allocation can't be reused.
+    assert_eq!(conversion, Conversion::Copied);
+    assert_eq!(bytes.len(), 5 * 16);
+    assert_eq!(bytes, bytemuck::cast_slice::<Vertex, u8>(&vertices()));
+
+    let (back, conversion) = from_bytes::<Vertex>(bytes).unwrap();
+    assert_eq!(conversion, Conversion::Copied);
+    assert_eq!(back, vertices());
+
+    assert_eq!(into_bytes(Vec::<Vertex>::new()).0, []);
+    assert_eq!(from_bytes::<Vertex>(Vec::new()).unwrap().0, []);
+}
+
+#[test]
+fn in_place() {
+    let mut colors = Vec::with_capacity(6);
+    colors.extend([[1_u8, 2, 3], [4, 5, 6]]);
+    let ptr = colors.as_ptr() as *const u8;
+
+    let (bytes, conversion) = into_bytes(colors);
+    assert_eq!(conversion, Conversion::InPlace);
+    assert_eq!(bytes.as_ptr(), ptr);
+    assert_eq!(bytes, [1, 2, 3, 4, 5, 6]);
+    assert_eq!(bytes.capacity(), 18);
+
+    let (colors, conversion) = from_bytes::<[u8; 3]>(bytes).unwrap();
+    assert_eq!(conversion, Conversion::InPlace);
+    assert_eq!(colors.as_ptr() as *const u8, ptr);
+    assert_eq!(colors, [[1, 2, 3], [4, 5, 6]]);
+
+    // The capacity isn't a multiple of the element size.
+    let mut bytes = Vec::with_capacity(7);
+    bytes.extend([1_u8, 2, 3, 4, 5, 6]);
+    let (colors, conversion) = from_bytes::<[u8; 3]>(bytes).unwrap();
+    assert_eq!(conversion, Conversion::Copied);
+    assert_eq!(colors, [[1, 2, 3], [4, 5, 6]]);
+}
+
+/// Reused allocations must be freed with the layout they were allocated with,
+/// which Miri checks: run in CI with `cargo miri test --test vec_bytes_tests`.
+#[test]
+fn round_trip_allocations() {
+    // Aligned elements are always copied, in both directions.
+    let mut aligned = vertices();
+    aligned.reserve_exact(3);
+    let (bytes, conversion) = into_bytes(aligned);
+    assert_eq!(conversion, Conversion::Copied);
+    let (back, conversion) = from_bytes::<Vertex>(bytes).unwrap();
+    assert_eq!(conversion, Conversion::Copied);
+    assert_eq!(back, vertices());
+
+    // Elements aligned to a byte, with a capacity of 7 bytes once converted.
+    let colors: Vec<[u8; 3]> = vec![[1, 2, 3], [4, 5, 6]];
+    let (mut bytes, conversion) = into_bytes(colors);
+    assert_eq!(conversion, Conversion::InPlace);
+    bytes.push(7);
+    bytes.shrink_to_fit();
+    bytes.pop();
+    assert_eq!((bytes.len(), bytes.capacity()), (6, 7));
+    let (colors, conversion) = from_bytes::<[u8; 3]>(bytes).unwrap();
+    assert_eq!(conversion, Conversion::Copied);
+    assert_eq!(colors, [[1, 2, 3], [4, 5, 6]]);
+
+    let (bytes, conversion) = into_bytes(colors);
+    assert_eq!(conversion, Conversion::InPlace);
+    assert_eq!(from_bytes::<[u8; 3]>(bytes).unwrap().1, Conversion::InPlace);
+}
+
+#[test]
+fn invalid_bytes() {
+    let (mut bytes, _) = into_bytes(vertices());
+    bytes.pop();
+    assert_eq!(
+        from_bytes::<Vertex>(bytes),
+        Err(ConversionError::LengthNotMultiple { len: 79, size: 16 })
+    );
+
+    // Starting one byte in is both misaligned and an odd length.
+    let (bytes, _) = into_bytes(vertices());
+    assert_eq!(
+        from_bytes::<u32>(bytes[1..].to_vec()),
+        Err(ConversionError::LengthNotMultiple { len: 79, size: 4 })
+    );
+
+    assert_eq!(
+        from_bytes::<()>(vec![0; 4]),
+        Err(ConversionError::ZeroSizedElement)
+    );
+}
+
+#[test]
+fn vec_view() {
+    let mut view = VecView::new(vertices());
+    let positions = view.insert("position", ViewDesc::new(0, 16, 5));
+    let colors = view.insert("color", ViewDesc::new(12, 16, 5));
+    assert_eq!(view.attribute_count(), 2);
+    assert_eq!(view.find(&"color"), Some(colors));
+    assert_eq!(
+        view.bytes(),
+        bytemuck::cast_slice::<Vertex, u8>(&vertices())
+    );
+
+    let expected = vertices();
+    let slice = view.view::<[f32; 3]>(positions).unwrap();
+    assert!(slice.iter().eq(expected.iter().map(|v| &v.position)));
+    let slice = view.view::<[u8; 4]>(colors).unwrap();
+    assert!(slice.iter().eq(expected.iter().map(|v| &v.color)));
+
+    view.view_mut::<[u8; 4]>(colors).unwrap().fill([0; 4]);
+    assert!(view.as_elements().iter().all(|v| v.color == [0; 4]));
+
+    // Views are validated against the bytes.
+    let index = view.push(ViewDesc::new(2, 16, 5));
+    assert!(matches!(
+        view.view::<u32>(index),
+        Err(SliceError::AlignmentFault { .. })
+    ));
+
+    let (bytes, _) = view.into_bytes();
+    let (back, _) = from_bytes::<Vertex>(bytes).unwrap();
+    assert!(back.iter().all(|v| v.color == [0; 4]));
+    assert_eq!(back[4].position, [4.0, -4.0, 0.5]);
+}
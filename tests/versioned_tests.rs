@@ -0,0 +1,104 @@
+use pas::{SliceError, VersionedBuffer, ViewDesc};
+
+const POSITIONS: ViewDesc = ViewDesc {
+    offset: 0,
+    stride: 20,
+    count: 3,
+};
+const UVS: ViewDesc = ViewDesc {
+    offset: 12,
+    stride: 20,
+    count: 3,
+};
+
+#[test]
+fn reads_dont_bump() {
+    let vertices = [[1.0_f32, 2.0, 3.0, 0.5, 0.5]; 3];
+    let mut buffer = VersionedBuffer::new(bytemuck::cast_slice(&vertices));
+    assert_eq!(buffer.generation(), 0);
+
+    let positions = buffer.view::<[f32; 3]>(POSITIONS).unwrap();
+    assert_eq!(positions.generation(), 0);
+    assert_eq!(positions[2], [1.0, 2.0, 3.0]);
+    assert_eq!(buffer.bytes().len(), 60);
+
+    // Mutable views only read from aren't written.
+    let uvs = buffer.view_mut::<[f32; 2]>(UVS).unwrap();
+    assert_eq!(uvs.len(), 3);
+    assert_eq!(uvs[0], [0.5, 0.5]);
+    drop(uvs);
+    assert_eq!(buffer.generation(), 0);
+    assert!(!buffer.changed_since(0));
+}
+
+#[test]
+fn writes_bump_once() {
+    let mut buffer = VersionedBuffer::zeroed(60);
+    let mut positions = buffer.view_mut::<[f32; 3]>(POSITIONS).unwrap();
+    positions[0] = [1.0; 3];
+    positions[1] = [2.0; 3];
+    positions.fill([3.0; 3]);
+    drop(positions);
+    assert_eq!(buffer.generation(), 1);
+    assert!(buffer.changed_since(0));
+    assert!(!buffer.changed_since(1));
+
+    // Commits bump once per batch of writes, and drop doesn't bump again.
+    let mut positions = buffer.view_mut::<[f32; 3]>(POSITIONS).unwrap();
+    positions[0] = [4.0; 3];
+    assert_eq!(positions.commit(), 2);
+    assert_eq!(positions.commit(), 2);
+    positions[1] = [5.0; 3];
+    assert_eq!(positions.commit(), 3);
+    drop(positions);
+    assert_eq!(buffer.generation(), 3);
+
+    let view = buffer.view::<[f32; 3]>(POSITIONS).unwrap();
+    assert_eq!(view.generation(), 3);
+    let positions: Vec<[f32; 3]> = view.into_inner().iter().copied().collect();
+    assert_eq!(positions, [[4.0; 3], [5.0; 3], [3.0; 3]]);
+
+    buffer.bytes_mut()[0] = 1;
+    assert_eq!(buffer.generation(), 4);
+}
+
+#[test]
+fn lanes_share_the_counter() {
+    let mut buffer = VersionedBuffer::zeroed(60);
+    let cached = buffer.generation();
+
+    buffer.view_mut::<[f32; 2]>(UVS).unwrap()[1] = [1.0, 1.0];
+    // Writing the uvs also invalidates the positions, stored in the same buffer.
+    assert!(buffer.changed_since(cached));
+    let positions = buffer.view::<[f32; 3]>(POSITIONS).unwrap();
+    assert_eq!(positions.generation(), 1);
+
+    buffer.view_mut::<[f32; 3]>(POSITIONS).unwrap()[0] = [1.0; 3];
+    assert_eq!(buffer.generation(), 2);
+}
+
+#[test]
+fn resize_preserves_contents() {
+    let mut buffer = VersionedBuffer::zeroed(20);
+    let first = ViewDesc::new(0, 20, 1);
+    buffer.view_mut::<[f32; 3]>(first).unwrap()[0] = [1.0, 2.0, 3.0];
+    assert_eq!(buffer.generation(), 1);
+
+    buffer.resize(20 * 1024);
+    assert_eq!(buffer.generation(), 2);
+    // Whether the buffer moved or not, the content is the same.
+    let positions = buffer.view::<[f32; 3]>(ViewDesc::new(0, 20, 1024)).unwrap();
+    assert_eq!(positions[0], [1.0, 2.0, 3.0]);
+    assert_eq!(positions[1023], [0.0; 3]);
+    assert_eq!(buffer.bytes().as_ptr() as usize % 16, 0);
+
+    buffer.resize(20);
+    assert_eq!(
+        buffer.view::<[f32; 3]>(POSITIONS).err(),
+        Some(SliceError::OffsetOutOfBounds {
+            size: 20,
+            offset: 52
+        })
+    );
+    assert_eq!(buffer.generation(), 3);
+}
@@ -0,0 +1,91 @@
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use js_sys::{Float32Array, Uint16Array, Uint32Array, Uint8Array};
+use pas::{slice_attr, slice_attr_mut, Slice, SliceMut};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub uv: [f32; 2],
+}
+
+fn data() -> Vec<Vertex> {
+    (0..300)
+        .map(|i| {
+            let f = i as f32;
+            Vertex {
+                position: [f, f + 0.25, f + 0.5],
+                uv: [-f, f * 2.0],
+            }
+        })
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn packed() {
+    let values: Vec<[f32; 3]> = data().iter().map(|v| v.position).collect();
+    let slice: Slice<[f32; 3]> = Slice::native(&values);
+    let dst = Float32Array::new_with_length(values.len() as u32 * 3);
+    assert_eq!(slice.copy_to_float32array(&dst), values.len());
+    let expected: Vec<f32> = slice.iter().flatten().copied().collect();
+    assert_eq!(dst.to_vec(), expected);
+
+    let values: Vec<u16> = (0..10).collect();
+    let dst = Uint16Array::new_with_length(10);
+    assert_eq!(Slice::native(&values).copy_to_uint16array(&dst), 10);
+    assert_eq!(dst.to_vec(), values);
+}
+
+#[wasm_bindgen_test]
+fn interleaved() {
+    let vertices = data();
+
+    // Spans multiple batches.
+    let positions = slice_attr!(vertices, [0].position);
+    let dst = Float32Array::new_with_length(vertices.len() as u32 * 3);
+    assert_eq!(positions.copy_to_float32array(&dst), vertices.len());
+    let expected: Vec<f32> = positions.iter().flatten().copied().collect();
+    assert_eq!(dst.to_vec(), expected);
+
+    // Scalar lane, with a shorter destination.
+    let u: Slice<f32> = pas::slice!(vertices, [0].uv[0]);
+    let dst = Float32Array::new_with_length(5);
+    assert_eq!(u.copy_to_float32array(&dst), 5);
+    let expected: Vec<f32> = u.iter().take(5).copied().collect();
+    assert_eq!(dst.to_vec(), expected);
+
+    let pairs: Vec<[u32; 2]> = (0..8).map(|i| [i, i * 10]).collect();
+    let first: Slice<u32> = Slice::new(&pairs, 0);
+    let dst = Uint32Array::new_with_length(8);
+    assert_eq!(first.copy_to_uint32array(&dst), 8);
+    assert_eq!(dst.to_vec(), (0..8).collect::<Vec<u32>>());
+
+    let bytes: Vec<[u8; 4]> = (0..4).map(|i| [i, i + 1, i + 2, i + 3]).collect();
+    let rg: Slice<[u8; 2]> = Slice::new(&bytes, 2);
+    let dst = Uint8Array::new_with_length(8);
+    assert_eq!(rg.copy_to_uint8array(&dst), 4);
+    assert_eq!(dst.to_vec(), [2, 3, 3, 4, 4, 5, 5, 6]);
+}
+
+#[wasm_bindgen_test]
+fn copy_from() {
+    let mut vertices = data();
+    let src: Vec<f32> = (0..vertices.len() * 2).map(|i| i as f32).collect();
+    let array = Float32Array::from(src.as_slice());
+
+    let mut uvs = slice_attr_mut!(vertices, [0].uv);
+    assert_eq!(uvs.copy_from_float32array(&array), vertices.len());
+    let uvs = slice_attr!(vertices, [0].uv);
+    let result: Vec<f32> = uvs.iter().flatten().copied().collect();
+    assert_eq!(result, src);
+    // Other attributes are untouched.
+    assert_eq!(vertices[3].position, [3.0, 3.25, 3.5]);
+
+    let mut values = [0_u16; 4];
+    let array = Uint16Array::from([7_u16, 8].as_slice());
+    let mut slice: SliceMut<u16> = SliceMut::native(&mut values);
+    assert_eq!(slice.copy_from_uint16array(&array), 2);
+    assert_eq!(values, [7, 8, 0, 0]);
+}
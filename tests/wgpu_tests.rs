@@ -0,0 +1,22 @@
+#![cfg(feature = "wgpu")]
+
+use pas::Format;
+use wgpu_types::VertexFormat;
+
+#[test]
+fn wgpu_formats() {
+    let mut count = 0;
+    for format in Format::ALL {
+        if let Some(vertex) = format.to_wgpu() {
+            assert_eq!(vertex.size(), format.size() as u64);
+            assert_eq!(Format::from_wgpu(vertex), Some(format));
+            count += 1;
+        }
+    }
+    assert_eq!(count, 28);
+    assert_eq!(Format::Unorm8x4.to_wgpu(), Some(VertexFormat::Unorm8x4));
+    assert_eq!(Format::U8.to_wgpu(), None);
+    assert_eq!(Format::Snorm16x3.to_wgpu(), None);
+    assert_eq!(Format::from_wgpu(VertexFormat::Float16x2), None);
+    assert_eq!(Format::from_wgpu(VertexFormat::Float64), None);
+}
@@ -0,0 +1,119 @@
+use pas::{ByteWindowSlice, ByteWindowSliceMut, Slice, SliceError};
+
+/// Elements of 16 bytes, where byte `i` has value `i`.
+fn data() -> Vec<u8> {
+    (0..48).collect()
+}
+
+#[test]
+fn window_bytes() {
+    let data = data();
+    let window = ByteWindowSlice::new(&data, 4, 16, 4).unwrap();
+    assert_eq!(window.len(), 3);
+    assert_eq!(window.get(0), Some(&[4_u8, 5, 6, 7][..]));
+    assert_eq!(window.get(2), Some(&[36_u8, 37, 38, 39][..]));
+    assert_eq!(window.get(3), None);
+    assert!(window
+        .iter()
+        .eq([&data[4..8], &data[20..24], &data[36..40]]));
+    assert_eq!(
+        window.to_packed_vec(),
+        [4, 5, 6, 7, 20, 21, 22, 23, 36, 37, 38, 39]
+    );
+
+    // Last element is truncated, but still holds the window.
+    let window = ByteWindowSlice::new(&data[..40], 4, 16, 4).unwrap();
+    assert_eq!(window.len(), 3);
+    // Last element is truncated before the end of the window.
+    let window = ByteWindowSlice::new(&data[..38], 4, 16, 4).unwrap();
+    assert_eq!(window.len(), 2);
+    assert_eq!(window.iter().len(), 2);
+
+    let window = ByteWindowSlice::new(&[], 0, 16, 4).unwrap();
+    assert!(window.is_empty());
+    assert!(window.to_packed_vec().is_empty());
+}
+
+#[test]
+fn window_validation() {
+    let data = data();
+    assert_eq!(
+        ByteWindowSlice::new(&data, 0, 4, 8).unwrap_err(),
+        SliceError::AttributeLargerThanStride {
+            type_name: "byte window",
+            attr: 8,
+            stride: 4,
+        }
+    );
+    assert_eq!(
+        ByteWindowSlice::new(&data, 12, 16, 8).unwrap_err(),
+        SliceError::AttributeCrossesElementBoundary {
+            type_name: "byte window",
+            attr: 8,
+            offset: 12,
+            element: 16,
+        }
+    );
+    assert_eq!(
+        ByteWindowSlice::new(&data[..8], 12, 16, 4).unwrap_err(),
+        SliceError::OffsetOutOfBounds {
+            size: 8,
+            offset: 12,
+        }
+    );
+    assert!(ByteWindowSlice::new(&data, 12, 16, 4).is_ok());
+}
+
+#[test]
+fn window_reinterpret() {
+    let data: Vec<u32> = vec![0, 10, 20, 1, 11, 21];
+    let bytes: &[u8] = bytemuck::cast_slice(&data);
+
+    let window = ByteWindowSlice::new(bytes, 4, 12, 8).unwrap();
+    let pairs: Slice<[u32; 2]> = window.reinterpret().unwrap();
+    assert!(pairs.iter().eq([[10, 20], [11, 21]].iter()));
+
+    // Size mismatch.
+    assert_eq!(
+        window.reinterpret::<u32>().unwrap_err(),
+        SliceError::ElementMismatch {
+            from: "byte window",
+            to: "u32",
+        }
+    );
+    // Alignment mismatch.
+    let window = ByteWindowSlice::new(bytes, 2, 12, 4).unwrap();
+    assert!(matches!(
+        window.reinterpret::<u32>(),
+        Err(SliceError::AlignmentFault { .. })
+    ));
+    assert!(window.reinterpret::<[u16; 2]>().is_ok());
+}
+
+#[test]
+fn window_mut() {
+    let mut data = data();
+    let mut window = ByteWindowSliceMut::new(&mut data, 8, 16, 2).unwrap();
+    assert_eq!(window.len(), 3);
+    assert_eq!(window.get(1), Some(&[24_u8, 25][..]));
+    window.get_mut(1).unwrap().copy_from_slice(&[0, 0]);
+    for bytes in window.iter_mut() {
+        bytes[1] = 0xFF;
+    }
+    assert_eq!(window.to_packed_vec(), [8, 0xFF, 0, 0xFF, 40, 0xFF]);
+
+    let expected: Vec<u8> = (0..48)
+        .map(|i| match i {
+            9 | 25 | 41 => 0xFF,
+            24 => 0,
+            i => i,
+        })
+        .collect();
+    assert_eq!(data, expected);
+
+    let mut data = vec![0_u32; 4];
+    let window = ByteWindowSliceMut::new(bytemuck::cast_slice_mut(&mut data), 4, 8, 4).unwrap();
+    let mut ids = window.reinterpret::<u32>().unwrap();
+    ids.fill(7);
+    assert_eq!(data, [0, 7, 0, 7]);
+}